@@ -0,0 +1,184 @@
+//! Drives `Num2Words` from arbitrary bytes
+//!
+//! Shared by the `builder` fuzz target and by `tests/fuzz.rs`, which runs it
+//! over a fixed seed corpus. Only checks that the conversion does not panic
+//! and that it never succeeds with an empty string.
+
+use num2words::{Currency, Lang, Num2Words, Rounding, Unit};
+
+const LANGS: &[&str] = &[
+    "am", "ar", "hy", "eu", "bn", "my", "zh", "cs", "nl", "en", "fi", "fr", "fr_BE", "fr_CH",
+    "fr_CA", "hu", "is", "it", "lo", "la", "lv", "mt", "mn", "ne", "pl", "pt", "pt_BR", "ro", "sr",
+    "si", "sk", "sl", "es", "sw", "tl", "ta", "te", "tr", "uk", "uz", "vi", "zu",
+];
+
+const CURRENCIES: &[&str] = &[
+    "AED", "AMD", "ARS", "AUD", "BDT", "BHD", "BRL", "CAD", "CHF", "CLP", "CNY", "COP", "CRC",
+    "CZK", "DINAR", "DKK", "DOLLAR", "DZD", "ETB", "EUR", "GBP", "HKD", "HUF", "IDR", "ILS", "INR",
+    "ISK", "JPY", "KES", "KRW", "KWD", "KZT", "LAK", "LKR", "MMK", "MNT", "MXN", "MYR", "NOK",
+    "NPR", "NZD", "PEN", "PESO", "PHP", "PLN", "QAR", "RIYAL", "RON", "RSD", "RUB", "SAR", "SEK",
+    "SGD", "THB", "TRY", "TWD", "TZS", "UAH", "USD", "UYU", "UZS", "VND", "ZAR",
+];
+
+const UNITS: &[&str] = &["m", "km", "g", "kg", "l", "s", "min", "h"];
+
+// a sample of the preferences of every language
+const PREFERENCES: &[&str] = &[
+    "oh",
+    "nil",
+    "nought",
+    "us",
+    "uk",
+    "no-and",
+    "long-scale",
+    "lakh",
+    "informal",
+    "negative",
+    "bce",
+    "ad",
+    "formal-year",
+    "oh-years",
+    "no-hyphen",
+    "hyphen-all",
+    "fraction",
+    "collective",
+    "comma",
+    "animate",
+    "check",
+    "hundreds",
+    "f",
+    "feminine",
+    "neuter",
+    "plural",
+    "genitive",
+    "locative",
+    "partitive",
+    "reformed",
+    "second",
+    "short",
+    "point",
+    "grouped-decimals",
+    "octante",
+    "sous",
+    "cent",
+    "abrégé",
+    "notre-ère",
+    "ère",
+    "zero-major",
+    "always-cents",
+    "no-leading-zero",
+    "group-digits",
+    "strict-config",
+    "accounting-negative",
+    "parentheses",
+    "nato",
+    "pairs",
+    "suffix",
+    "eastern-digits",
+    "traditional",
+    "latin",
+    "linh",
+    "tư",
+    "wa",
+    "spanish",
+    "жіночий",
+    "орудний",
+    "множина",
+];
+
+/// Reads the input byte by byte, giving zeros once exhausted
+struct Input<'a>(&'a [u8]);
+
+impl Input<'_> {
+    fn byte(&mut self) -> u8 {
+        match self.0.split_first() {
+            Some((byte, rest)) => {
+                self.0 = rest;
+                *byte
+            }
+            None => 0,
+        }
+    }
+
+    fn u64(&mut self) -> u64 {
+        (0..8).fold(0, |acc, _| (acc << 8) | u64::from(self.byte()))
+    }
+
+    fn pick<'t>(&mut self, table: &[&'t str]) -> &'t str {
+        table[usize::from(self.byte()) % table.len()]
+    }
+
+    fn string(&mut self) -> String {
+        let len = usize::from(self.byte() % 16);
+        let bytes: Vec<u8> = (0..len).map(|_| self.byte()).collect();
+        String::from_utf8_lossy(&bytes).into_owned()
+    }
+
+    fn number(&mut self) -> Option<Num2Words> {
+        Some(match self.byte() % 8 {
+            0 => Num2Words::new(f64::NAN),
+            1 => Num2Words::new(f64::INFINITY),
+            2 => Num2Words::new(f64::NEG_INFINITY),
+            // every bit pattern, subnormals included
+            3 => Num2Words::new(f64::from_bits(self.u64())),
+            4 => Num2Words::new(self.u64() as i64),
+            // huge exponents, far beyond the range of a f64
+            5 => {
+                let mantissa = self.u64() as i64;
+                let exponent = i16::from_be_bytes([self.byte(), self.byte()]);
+                Num2Words::parse(&format!("{}e{}", mantissa, exponent))?
+            }
+            6 => Num2Words::parse(&self.string())?,
+            _ => Num2Words::new(i64::from(self.byte()) - 128),
+        })
+    }
+}
+
+fn builder(data: &[u8]) -> Option<Num2Words> {
+    let mut input = Input(data);
+    let mut n2w = input
+        .number()?
+        .lang(input.pick(LANGS).parse::<Lang>().ok()?);
+
+    n2w = match input.byte() % 11 {
+        0 => n2w.cardinal(),
+        1 => n2w.ordinal(),
+        2 => n2w.ordinal_num(),
+        3 => n2w.year(),
+        4 => n2w.currency(input.pick(CURRENCIES).parse::<Currency>().ok()?),
+        5 => n2w.percent(),
+        6 => n2w.unit(input.pick(UNITS).parse::<Unit>().ok()?),
+        7 => n2w.reference(input.string()),
+        8 => n2w.year_range(f64::from_bits(input.u64())),
+        9 => n2w.precision(usize::from(input.byte() % 8)),
+        _ => n2w.rounding(match input.byte() % 3 {
+            0 => Rounding::Truncate,
+            1 => Rounding::HalfUp,
+            _ => Rounding::HalfEven,
+        }),
+    };
+
+    for _ in 0..input.byte() % 5 {
+        n2w = if input.byte() % 4 == 0 {
+            n2w.prefer(input.string())
+        } else {
+            n2w.prefer(input.pick(PREFERENCES))
+        };
+    }
+
+    Some(n2w)
+}
+
+/// Converts whatever the bytes describe, panicking on a broken invariant
+pub fn run(data: &[u8]) {
+    if let Some(n2w) = builder(data) {
+        n2w.lints();
+        if let Ok(words) = n2w.to_words() {
+            assert!(!words.is_empty(), "empty output");
+            assert!(std::str::from_utf8(words.as_bytes()).is_ok());
+        }
+    }
+    if let Some(n2w) = builder(data) {
+        let _ = n2w.agreement();
+    }
+}
@@ -0,0 +1,169 @@
+use crate::{Declension, Gender, GrammaticalNumber, PluralCategory};
+
+/// Form a counted noun takes after a number
+///
+/// Built by [`Num2Words::agreement`](crate::Num2Words::agreement), for
+/// applications that spell the noun themselves, e.g. `42 {noun}`. Languages
+/// without genders or cases leave them to `None`.
+///
+/// Example:
+/// ```
+/// use num2words::{Declension, GrammaticalNumber, Lang, Num2Words, PluralCategory};
+/// let agreement = Num2Words::new(5).lang(Lang::Ukrainian).agreement().unwrap();
+/// assert_eq!(agreement.category, PluralCategory::Many);
+/// assert_eq!(agreement.number, GrammaticalNumber::Plural);
+/// assert_eq!(agreement.case, Some(Declension::Genitive));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Agreement {
+    /// Plural category of the number, as defined by the Unicode CLDR
+    pub category: PluralCategory,
+    /// Grammatical number of the noun, e.g. `dollars` is plural
+    pub number: GrammaticalNumber,
+    /// Grammatical gender of the noun, as set by the preferences
+    pub gender: Option<Gender>,
+    /// Grammatical case of the noun, e.g. `гривень` is genitive
+    pub case: Option<Declension>,
+}
+
+impl Agreement {
+    /// Agreement of languages only telling the singular from the plural
+    pub(crate) fn from_category(category: PluralCategory) -> Self {
+        Self {
+            category,
+            number: match category {
+                PluralCategory::One => GrammaticalNumber::Singular,
+                _ => GrammaticalNumber::Plural,
+            },
+            gender: None,
+            case: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn agreement(num: f64, lang: Lang, preferences: &[&str]) -> Agreement {
+        let mut n2w = Num2Words::new(num).lang(lang);
+        for preference in preferences {
+            n2w = n2w.prefer(*preference);
+        }
+        n2w.agreement().unwrap()
+    }
+
+    #[test]
+    fn test_english() {
+        use GrammaticalNumber::*;
+        use PluralCategory::*;
+
+        for (num, category, number) in [
+            (1.0, One, Singular),
+            (2.0, Other, Plural),
+            (5.0, Other, Plural),
+            (21.0, Other, Plural),
+            (1.5, Other, Plural),
+        ] {
+            assert_eq!(
+                agreement(num, Lang::English, &[]),
+                Agreement {
+                    category,
+                    number,
+                    gender: None,
+                    case: None
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn test_french() {
+        use GrammaticalNumber::*;
+        use PluralCategory::*;
+
+        for (num, category, number) in [
+            (0.0, One, Singular),
+            (1.0, One, Singular),
+            (2.0, Other, Plural),
+            (5.0, Other, Plural),
+            (21.0, Other, Plural),
+            (1.5, One, Singular),
+            (2.5, Other, Plural),
+        ] {
+            assert_eq!(
+                agreement(num, Lang::French, &["f"]),
+                Agreement {
+                    category,
+                    number,
+                    gender: Some(Gender::Feminine),
+                    case: None
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn test_ukrainian() {
+        use Declension::*;
+        use GrammaticalNumber::*;
+        use PluralCategory::*;
+
+        for (num, category, number, case) in [
+            (1.0, One, Singular, Nominative),
+            (2.0, Few, Plural, Nominative),
+            (5.0, Many, Plural, Genitive),
+            (21.0, One, Singular, Nominative),
+            (1.5, Other, Singular, Genitive),
+        ] {
+            assert_eq!(
+                agreement(num, Lang::Ukrainian, &["жіночий"]),
+                Agreement {
+                    category,
+                    number,
+                    gender: Some(Gender::Feminine),
+                    case: Some(case)
+                }
+            );
+        }
+        // the counted noun follows the case of the sentence: пʼятьом гривням
+        assert_eq!(
+            agreement(5.0, Lang::Ukrainian, &["давальний"]).case,
+            Some(Dative)
+        );
+    }
+
+    #[test]
+    fn test_slovenian() {
+        use Declension::*;
+        use GrammaticalNumber::*;
+        use PluralCategory::*;
+
+        for (num, category, number, case) in [
+            (1.0, One, Singular, Nominative),
+            (2.0, Two, Dual, Nominative),
+            (3.0, Few, Plural, Nominative),
+            (5.0, Other, Plural, Genitive),
+            (102.0, Two, Dual, Nominative),
+        ] {
+            assert_eq!(
+                agreement(num, Lang::Slovenian, &[]),
+                Agreement {
+                    category,
+                    number,
+                    gender: None,
+                    case: Some(case)
+                }
+            );
+        }
+        assert_eq!(agreement(2.0, Lang::Arabic, &[]).number, Dual);
+    }
+
+    #[test]
+    fn test_cannot_convert() {
+        assert_eq!(
+            Num2Words::new(1e100).agreement(),
+            Err(Num2Err::CannotConvert)
+        );
+    }
+}
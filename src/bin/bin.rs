@@ -1,4 +1,4 @@
-use ::num2words::{Currency, Lang, Num2Words};
+use ::num2words::{Currency, Lang, Num2Words, Unit};
 use std::env;
 use std::str::FromStr;
 
@@ -21,11 +21,48 @@ GLOBAL OPTIONS:
     -v, --version               print the version
 
 AVAILABLE LANGUAGES:
+    am:      Amharic
+    ar:      Arabic
+    bn:      Bengali
+    cs:      Czech
     en:      English
-    fr:      French (France and Canada)
+    es:      Spanish
+    eu:      Basque
+    fi:      Finnish
+    fr:      French (France)
     fr_BE:   French (Belgium and the Democratic Republic of the Congo)
     fr_CH:   French (Swiss Confederation and Aosta Valley)
+    fr_CA:   French (Canada)
+    hu:      Hungarian
+    hy:      Armenian
+    is:      Icelandic
+    it:      Italian
+    la:      Latin
+    lo:      Lao
+    lv:      Latvian
+    mn:      Mongolian
+    mt:      Maltese
+    my:      Burmese
+    ne:      Nepali
+    nl:      Dutch
+    pl:      Polish
+    pt:      Portuguese (Portugal and other Lusophone countries)
+    pt_BR:   Portuguese (Brazil)
+    ro:      Romanian
+    si:      Sinhala
+    sk:      Slovak
+    sl:      Slovenian
+    sr:      Serbian
+    sw:      Swahili
+    ta:      Tamil
+    te:      Telugu
+    tl:      Tagalog
+    tr:      Turkish
     uk:      Ukrainian
+    uz:      Uzbek
+    vi:      Vietnamese
+    zh:      Chinese
+    zu:      Zulu
 
 AVAILABLE OUTPUTS:
     cardinal:      forty-two (42)
@@ -33,10 +70,17 @@ AVAILABLE OUTPUTS:
     ordinal_num:   42nd (42)
     year:          nineteen oh-one (1901)
     currency:      forty-two dollars and one cent (42.01)
+    percent:       forty-two percent (42)
 
 AVAILABLE CURRENCIES:
     ISO 4217 code      - USD, EUR, GBP, etc.
-    generic currencies - DINAR, DOLLAR, PESO, RIYAL"#;
+    generic currencies - DINAR, DOLLAR, PESO, RIYAL
+
+AVAILABLE UNITS:
+    length - m, km
+    mass   - g, kg
+    volume - l
+    time   - s, min, h"#;
 
 fn get_version() -> String {
     let version = env!("CARGO_PKG_VERSION");
@@ -86,6 +130,8 @@ fn handle_cmd(n: String, mut args: std::env::Args) {
                     Some(t) => {
                         if let Ok(v) = Currency::from_str(t.as_str()) {
                             num = num.currency(v);
+                        } else if let Ok(v) = Unit::from_str(t.as_str()) {
+                            num = num.unit(v);
                         } else {
                             match t.as_str() {
                                 "cardinal" => {
@@ -100,6 +146,9 @@ fn handle_cmd(n: String, mut args: std::env::Args) {
                                 "year" => {
                                     num = num.year();
                                 }
+                                "percent" => {
+                                    num = num.percent();
+                                }
                                 _ => {
                                     eprintln!("Error: invalid to tag");
                                     return;
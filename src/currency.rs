@@ -10,10 +10,16 @@ use std::str::FromStr;
 pub enum Currency {
     /// Dirham
     AED,
+    /// Armenian dram
+    AMD,
     /// Argentine peso
     ARS,
     /// Australian dollar
     AUD,
+    /// Bangladeshi taka
+    BDT,
+    /// Bahraini dinar
+    BHD,
     /// Brazilian real
     BRL,
     /// Canadian dollar
@@ -28,38 +34,60 @@ pub enum Currency {
     COP,
     /// Costa Rican colón
     CRC,
+    /// Czech koruna
+    CZK,
     /// Dinar
     DINAR,
+    /// Danish krone
+    DKK,
     /// Dollar
     DOLLAR,
     /// Algerian dinar
     DZD,
+    /// Ethiopian birr
+    ETB,
     /// Euro
     EUR,
     /// British pound
     GBP,
     /// Hong Kong dollar
     HKD,
+    /// Hungarian forint
+    HUF,
     /// Indonesian rupiah
     IDR,
     /// Israeli new shekel
     ILS,
     /// Indian rupee
     INR,
+    /// Icelandic króna
+    ISK,
     /// Japanese yen
     JPY,
+    /// Kenyan shilling
+    KES,
     /// South Korean won
     KRW,
     /// Kuwaiti dinar
     KWD,
     /// Kazakhstani tenge
     KZT,
+    /// Lao kip
+    LAK,
+    /// Sri Lankan rupee
+    LKR,
+    /// Myanmar kyat
+    MMK,
+    /// Mongolian tögrög
+    MNT,
     /// Mexican peso
     MXN,
     /// Malaysian ringgit
     MYR,
     /// Norwegian krone
     NOK,
+    /// Nepalese rupee
+    NPR,
     /// New Zealand dollar
     NZD,
     /// Peruvian sol
@@ -74,10 +102,16 @@ pub enum Currency {
     QAR,
     /// Riyal
     RIYAL,
+    /// Romanian leu
+    RON,
+    /// Serbian dinar
+    RSD,
     /// Russian ruble
     RUB,
     /// Saudi riyal
     SAR,
+    /// Swedish krona
+    SEK,
     /// Singapore dollar
     SGD,
     /// Thai baht
@@ -86,12 +120,16 @@ pub enum Currency {
     TRY,
     /// Taiwan dollar
     TWD,
+    /// Tanzanian shilling
+    TZS,
     /// Ukrainian hryvnia
     UAH,
     /// US dollar
     USD,
     /// Uruguayan peso
     UYU,
+    /// Uzbekistani som
+    UZS,
     /// Vietnamese dong
     VND,
     /// South African rand
@@ -99,6 +137,142 @@ pub enum Currency {
 }
 
 impl Currency {
+    /// Every available currency
+    pub(crate) const ALL: [Currency; 63] = [
+        Currency::AED,
+        Currency::AMD,
+        Currency::ARS,
+        Currency::AUD,
+        Currency::BDT,
+        Currency::BHD,
+        Currency::BRL,
+        Currency::CAD,
+        Currency::CHF,
+        Currency::CLP,
+        Currency::CNY,
+        Currency::COP,
+        Currency::CRC,
+        Currency::CZK,
+        Currency::DINAR,
+        Currency::DKK,
+        Currency::DOLLAR,
+        Currency::DZD,
+        Currency::ETB,
+        Currency::EUR,
+        Currency::GBP,
+        Currency::HKD,
+        Currency::HUF,
+        Currency::IDR,
+        Currency::ILS,
+        Currency::INR,
+        Currency::ISK,
+        Currency::JPY,
+        Currency::KES,
+        Currency::KRW,
+        Currency::KWD,
+        Currency::KZT,
+        Currency::LAK,
+        Currency::LKR,
+        Currency::MMK,
+        Currency::MNT,
+        Currency::MXN,
+        Currency::MYR,
+        Currency::NOK,
+        Currency::NPR,
+        Currency::NZD,
+        Currency::PEN,
+        Currency::PESO,
+        Currency::PHP,
+        Currency::PLN,
+        Currency::QAR,
+        Currency::RIYAL,
+        Currency::RON,
+        Currency::RSD,
+        Currency::RUB,
+        Currency::SAR,
+        Currency::SEK,
+        Currency::SGD,
+        Currency::THB,
+        Currency::TRY,
+        Currency::TWD,
+        Currency::TZS,
+        Currency::UAH,
+        Currency::USD,
+        Currency::UYU,
+        Currency::UZS,
+        Currency::VND,
+        Currency::ZAR,
+    ];
+
+    /// Returns the code used to refer to the currency (ISO 4217 or generic)
+    pub(crate) fn code(&self) -> &'static str {
+        match self {
+            Currency::AED => "AED",
+            Currency::AMD => "AMD",
+            Currency::ARS => "ARS",
+            Currency::AUD => "AUD",
+            Currency::BDT => "BDT",
+            Currency::BHD => "BHD",
+            Currency::BRL => "BRL",
+            Currency::CAD => "CAD",
+            Currency::CHF => "CHF",
+            Currency::CLP => "CLP",
+            Currency::CNY => "CNY",
+            Currency::COP => "COP",
+            Currency::CRC => "CRC",
+            Currency::CZK => "CZK",
+            Currency::DINAR => "DINAR",
+            Currency::DKK => "DKK",
+            Currency::DOLLAR => "DOLLAR",
+            Currency::DZD => "DZD",
+            Currency::ETB => "ETB",
+            Currency::EUR => "EUR",
+            Currency::GBP => "GBP",
+            Currency::HKD => "HKD",
+            Currency::HUF => "HUF",
+            Currency::IDR => "IDR",
+            Currency::ILS => "ILS",
+            Currency::INR => "INR",
+            Currency::ISK => "ISK",
+            Currency::JPY => "JPY",
+            Currency::KES => "KES",
+            Currency::KRW => "KRW",
+            Currency::KWD => "KWD",
+            Currency::KZT => "KZT",
+            Currency::LAK => "LAK",
+            Currency::LKR => "LKR",
+            Currency::MMK => "MMK",
+            Currency::MNT => "MNT",
+            Currency::MXN => "MXN",
+            Currency::MYR => "MYR",
+            Currency::NOK => "NOK",
+            Currency::NPR => "NPR",
+            Currency::NZD => "NZD",
+            Currency::PEN => "PEN",
+            Currency::PESO => "PESO",
+            Currency::PHP => "PHP",
+            Currency::PLN => "PLN",
+            Currency::QAR => "QAR",
+            Currency::RIYAL => "RIYAL",
+            Currency::RON => "RON",
+            Currency::RSD => "RSD",
+            Currency::RUB => "RUB",
+            Currency::SAR => "SAR",
+            Currency::SEK => "SEK",
+            Currency::SGD => "SGD",
+            Currency::THB => "THB",
+            Currency::TRY => "TRY",
+            Currency::TWD => "TWD",
+            Currency::TZS => "TZS",
+            Currency::UAH => "UAH",
+            Currency::USD => "USD",
+            Currency::UYU => "UYU",
+            Currency::UZS => "UZS",
+            Currency::VND => "VND",
+            Currency::ZAR => "ZAR",
+        }
+    }
+
     /// Returns a default string representation for the currency
     ///
     /// Since many languages share the same work for a specific currency (like
@@ -106,8 +280,11 @@ impl Currency {
     pub fn default_string(&self, plural_form: bool) -> String {
         match self {
             Currency::AED => "dirham{}",
+            Currency::AMD => "dram{}",
             Currency::ARS => "argentine peso{}",
             Currency::AUD => "australian dollar{}",
+            Currency::BDT => "taka",
+            Currency::BHD => "bahraini dinar{}",
             Currency::BRL => {
                 if plural_form {
                     "reais"
@@ -127,22 +304,45 @@ impl Currency {
                     "colón"
                 }
             }
+            Currency::CZK => "koruna{}",
             Currency::DINAR => "dinar{}",
+            Currency::DKK => {
+                if plural_form {
+                    "danish kroner"
+                } else {
+                    "danish krone"
+                }
+            }
             Currency::DOLLAR => "dollar{}",
             Currency::DZD => "algerian dinar{}",
+            Currency::ETB => "birr",
             Currency::EUR => "euro{}",
             Currency::GBP => "pound{}",
             Currency::HKD => "hong kong dollar{}",
+            Currency::HUF => "forint{}",
             Currency::IDR => "indonesian rupiah{}",
             Currency::ILS => "new shekel{}",
             Currency::INR => "rupee{}",
+            Currency::ISK => {
+                if plural_form {
+                    "icelandic krónur"
+                } else {
+                    "icelandic króna"
+                }
+            }
             Currency::JPY => "yen{}",
+            Currency::KES => "kenyan shilling{}",
             Currency::KRW => "won{}",
             Currency::KWD => "kuwaiti dinar{}",
             Currency::KZT => "tenge{}",
+            Currency::LAK => "kip",
+            Currency::LKR => "sri lankan rupee{}",
+            Currency::MMK => "kyat{}",
+            Currency::MNT => "tugrik{}",
             Currency::MXN => "mexican peso{}",
             Currency::MYR => "ringgit{}",
             Currency::NOK => "norwegian krone{}",
+            Currency::NPR => "nepalese rupee{}",
             Currency::NZD => "new zealand dollar{}",
             Currency::PEN => {
                 if plural_form {
@@ -156,15 +356,32 @@ impl Currency {
             Currency::PLN => "zloty{}",
             Currency::QAR => "qatari riyal{}",
             Currency::RIYAL => "riyal{}",
+            Currency::RON => {
+                if plural_form {
+                    "lei"
+                } else {
+                    "leu"
+                }
+            }
+            Currency::RSD => "serbian dinar{}",
             Currency::RUB => "ruble{}",
             Currency::SAR => "saudi riyal{}",
+            Currency::SEK => {
+                if plural_form {
+                    "swedish kronor"
+                } else {
+                    "swedish krona"
+                }
+            }
             Currency::SGD => "singapore dollar{}",
             Currency::THB => "baht{}",
             Currency::TRY => "lira{}",
             Currency::TWD => "taiwan dollar{}",
+            Currency::TZS => "tanzanian shilling{}",
             Currency::UAH => "hryvnia{}",
             Currency::USD => "US dollar{}",
             Currency::UYU => "uruguayan peso{}",
+            Currency::UZS => "som",
             Currency::VND => "dong{}",
             Currency::ZAR => "rand{}",
         }
@@ -173,24 +390,42 @@ impl Currency {
 
     /// Returns a default string representation for the cents of the currency
     pub fn default_subunit_string(&self, cent: &str, plural_form: bool) -> String {
-        String::from(
-            match self {
-                Currency::AED | Currency::KWD => "fils",
-                Currency::ARS | Currency::BRL | Currency::CLP | Currency::COP | Currency::MXN => {
-                    "centavo{}"
+        match self {
+            Currency::AED | Currency::KWD => "fils",
+            Currency::AMD => "luma{}",
+            Currency::ARS | Currency::BRL | Currency::CLP | Currency::COP | Currency::MXN => {
+                "centavo{}"
+            }
+            Currency::BDT => "poisha",
+            Currency::CRC => "céntimo{}",
+            Currency::CZK => "haler{}",
+            Currency::DKK => "øre",
+            Currency::ETB => "santim",
+            Currency::HUF => "filler{}",
+            Currency::IDR | Currency::MYR => "sen{}",
+            Currency::KRW => "jeon{}",
+            Currency::LAK => "att",
+            Currency::MMK => "pya{}",
+            Currency::MNT => "möngö",
+            Currency::NPR => "paisa",
+            Currency::RON => {
+                if plural_form {
+                    "bani"
+                } else {
+                    "ban"
                 }
-                Currency::CRC => "céntimo{}",
-                Currency::IDR | Currency::MYR => "sen{}",
-                Currency::KRW => "jeon{}",
-                Currency::SAR => "halalat{}",
-                Currency::THB => "satang{}",
-                Currency::UAH => "kopiyok{}",
-                Currency::UYU => "centesimo{}",
-                Currency::VND => "xu{}",
-                _ => cent,
             }
-            .replace("{}", if plural_form { "s" } else { "" }),
-        )
+            Currency::RSD => "para",
+            Currency::SAR => "halalat{}",
+            Currency::SEK => "öre",
+            Currency::THB => "satang{}",
+            Currency::UAH => "kopiyok{}",
+            Currency::UYU => "centesimo{}",
+            Currency::UZS => "tiyin{}",
+            Currency::VND => "xu{}",
+            _ => cent,
+        }
+        .replace("{}", if plural_form { "s" } else { "" })
     }
 }
 
@@ -200,8 +435,11 @@ impl FromStr for Currency {
     fn from_str(currency: &str) -> Result<Self, Self::Err> {
         match currency {
             "AED" => Ok(Currency::AED),
+            "AMD" => Ok(Currency::AMD),
             "ARS" => Ok(Currency::ARS),
             "AUD" => Ok(Currency::AUD),
+            "BDT" => Ok(Currency::BDT),
+            "BHD" => Ok(Currency::BHD),
             "BRL" => Ok(Currency::BRL),
             "CAD" => Ok(Currency::CAD),
             "CHF" => Ok(Currency::CHF),
@@ -209,22 +447,33 @@ impl FromStr for Currency {
             "CNY" => Ok(Currency::CNY),
             "COP" => Ok(Currency::COP),
             "CRC" => Ok(Currency::CRC),
+            "CZK" => Ok(Currency::CZK),
             "DINAR" => Ok(Currency::DINAR),
+            "DKK" => Ok(Currency::DKK),
             "DOLLAR" => Ok(Currency::DOLLAR),
             "DZD" => Ok(Currency::DZD),
+            "ETB" => Ok(Currency::ETB),
             "EUR" => Ok(Currency::EUR),
             "GBP" => Ok(Currency::GBP),
             "HKD" => Ok(Currency::HKD),
+            "HUF" => Ok(Currency::HUF),
             "IDR" => Ok(Currency::IDR),
             "ILS" => Ok(Currency::ILS),
             "INR" => Ok(Currency::INR),
+            "ISK" => Ok(Currency::ISK),
             "JPY" => Ok(Currency::JPY),
+            "KES" => Ok(Currency::KES),
             "KRW" => Ok(Currency::KRW),
             "KWD" => Ok(Currency::KWD),
             "KZT" => Ok(Currency::KZT),
+            "LAK" => Ok(Currency::LAK),
+            "LKR" => Ok(Currency::LKR),
+            "MMK" => Ok(Currency::MMK),
+            "MNT" => Ok(Currency::MNT),
             "MXN" => Ok(Currency::MXN),
             "MYR" => Ok(Currency::MYR),
             "NOK" => Ok(Currency::NOK),
+            "NPR" => Ok(Currency::NPR),
             "NZD" => Ok(Currency::NZD),
             "PEN" => Ok(Currency::PEN),
             "PESO" => Ok(Currency::PESO),
@@ -232,15 +481,20 @@ impl FromStr for Currency {
             "PLN" => Ok(Currency::PLN),
             "QAR" => Ok(Currency::QAR),
             "RIYAL" => Ok(Currency::RIYAL),
+            "RON" => Ok(Currency::RON),
+            "RSD" => Ok(Currency::RSD),
             "RUB" => Ok(Currency::RUB),
             "SAR" => Ok(Currency::SAR),
+            "SEK" => Ok(Currency::SEK),
             "SGD" => Ok(Currency::SGD),
             "THB" => Ok(Currency::THB),
             "TRY" => Ok(Currency::TRY),
             "TWD" => Ok(Currency::TWD),
+            "TZS" => Ok(Currency::TZS),
             "UAH" => Ok(Currency::UAH),
             "USD" => Ok(Currency::USD),
             "UYU" => Ok(Currency::UYU),
+            "UZS" => Ok(Currency::UZS),
             "VND" => Ok(Currency::VND),
             "ZAR" => Ok(Currency::ZAR),
             _ => Err(()),
@@ -0,0 +1,435 @@
+use crate::{num2words::Num2Err, Currency, Language, PluralCategory, Unit};
+use num_bigfloat::BigFloat;
+
+pub struct Amharic {
+    zero_major: bool,
+}
+
+const UNITS: [&str; 9] = [
+    "አንድ",
+    "ሁለት",
+    "ሶስት",
+    "አራት",
+    "አምስት",
+    "ስድስት",
+    "ሰባት",
+    "ስምንት",
+    "ዘጠኝ",
+];
+
+const TENS: [&str; 9] = ["አስር", "ሃያ", "ሰላሳ", "አርባ", "ሃምሳ", "ስልሳ", "ሰባ", "ሰማንያ", "ዘጠና"];
+
+// 10 is "አስር" on its own but "አስራ" when followed by units, e.g. አስራ አንድ
+const TEN_PREFIX: &str = "አስራ";
+
+const MEGAS: [&str; 10] = [
+    "ሺህ",
+    "ሚሊዮን",
+    "ቢሊዮን",
+    "ትሪሊዮን",
+    "ኳድሪሊዮን",
+    "ኩንቲሊዮን",
+    "ሴክስቲሊዮን",
+    "ሴፕቲሊዮን",
+    "ኦክቲሊዮን",
+    "ኖኒሊዮን",
+];
+
+impl Amharic {
+    pub fn new() -> Self {
+        Self { zero_major: false }
+    }
+
+    /// Keeps the major unit clause for amounts below one, e.g. `ዜሮ ብር ከሃያ
+    /// ሳንቲም`
+    pub fn zero_major(self, zero_major: bool) -> Self {
+        Self { zero_major }
+    }
+
+    fn currencies(&self, currency: Currency) -> String {
+        match currency {
+            Currency::CAD => String::from("የካናዳ ዶላር"),
+            Currency::CNY => String::from("ዩዋን"),
+            Currency::DOLLAR => String::from("ዶላር"),
+            Currency::ETB => String::from("ብር"),
+            Currency::EUR => String::from("ዩሮ"),
+            Currency::GBP => String::from("ፓውንድ"),
+            Currency::JPY => String::from("የን"),
+            Currency::USD => String::from("የአሜሪካ ዶላር"),
+            _ => currency.default_string(false),
+        }
+    }
+
+    fn cents(&self, currency: Currency) -> String {
+        match currency {
+            Currency::GBP => String::from("ፔኒ"),
+            _ => String::from("ሳንቲም"),
+        }
+    }
+
+    fn units(&self, unit: Unit) -> &'static str {
+        match unit {
+            Unit::Meter => "ሜትር",
+            Unit::Kilometer => "ኪሎ ሜትር",
+            Unit::Gram => "ግራም",
+            Unit::Kilogram => "ኪሎ ግራም",
+            Unit::Liter => "ሊትር",
+            Unit::Second => "ሰከንድ",
+            Unit::Minute => "ደቂቃ",
+            Unit::Hour => "ሰዓት",
+        }
+    }
+
+    fn split_thousands(&self, mut num: BigFloat) -> Vec<u64> {
+        let mut thousands = Vec::new();
+        let bf_1000 = BigFloat::from(1000);
+
+        while !num.is_zero() {
+            thousands.push((num % bf_1000).to_u64().unwrap());
+            num = (num / bf_1000).int();
+        }
+
+        thousands
+    }
+
+    fn int_to_cardinal(&self, mut num: BigFloat) -> Result<String, Num2Err> {
+        // special case zero
+        if num.is_zero() {
+            return Ok(String::from("ዜሮ"));
+        }
+
+        // handling negative values
+        let mut words = vec![];
+        if num.is_negative() {
+            words.push(String::from("አሉታዊ"));
+            num = -num;
+        }
+
+        // iterate over thousands
+        for (i, triplet) in self.split_thousands(num).iter().enumerate().rev() {
+            let hundreds = (triplet / 100 % 10) as usize;
+            let tens = (triplet / 10 % 10) as usize;
+            let units = (triplet % 10) as usize;
+
+            if hundreds > 1 {
+                words.push(String::from(UNITS[hundreds - 1]));
+            }
+            if hundreds > 0 {
+                // one hundred is simply መቶ
+                words.push(String::from("መቶ"));
+            }
+
+            match (tens, units) {
+                (0, 0) => {}
+                (0, _) => words.push(String::from(UNITS[units - 1])),
+                (1, 0) => words.push(String::from(TENS[0])),
+                (1, _) => {
+                    words.push(String::from(TEN_PREFIX));
+                    words.push(String::from(UNITS[units - 1]));
+                }
+                (_, 0) => words.push(String::from(TENS[tens - 1])),
+                _ => {
+                    words.push(String::from(TENS[tens - 1]));
+                    words.push(String::from(UNITS[units - 1]));
+                }
+            }
+
+            if i != 0 && triplet != &0 {
+                if i > MEGAS.len() {
+                    return Err(Num2Err::CannotConvert);
+                }
+                words.push(String::from(MEGAS[i - 1]));
+            }
+        }
+
+        Ok(words.join(" "))
+    }
+
+    fn float_to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let integral_part = num.int();
+        let mut words: Vec<String> = vec![];
+
+        if !integral_part.is_zero() {
+            let integral_word = self.int_to_cardinal(integral_part)?;
+            words.push(integral_word);
+        } else if num.is_negative() {
+            words.push(String::from("አሉታዊ"));
+        }
+        if integral_part.is_zero() {
+            words.push(String::from("ዜሮ"));
+        }
+
+        let mut ordinal_part = num.frac().abs();
+        if !ordinal_part.is_zero() {
+            words.push(String::from("ነጥብ"));
+        }
+        while !ordinal_part.is_zero() {
+            let digit = (ordinal_part * BigFloat::from(10)).int();
+            ordinal_part = (ordinal_part * BigFloat::from(10)).frac();
+            words.push(match digit.to_u64().unwrap() {
+                0 => String::from("ዜሮ"),
+                i => String::from(UNITS[i as usize - 1]),
+            });
+        }
+        Ok(words.join(" "))
+    }
+}
+
+impl Default for Amharic {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Language for Amharic {
+    fn to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num.is_inf_pos() {
+            Ok(String::from("ማለቂያ የሌለው"))
+        } else if num.is_inf_neg() {
+            Ok(String::from("አሉታዊ ማለቂያ የሌለው"))
+        } else if num.frac().is_zero() {
+            self.int_to_cardinal(num)
+        } else {
+            self.float_to_cardinal(num)
+        }
+    }
+
+    fn to_ordinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let mut cardinal_word = self.to_cardinal(num)?;
+        let last = cardinal_word.pop().unwrap();
+
+        // ኛ follows the first order of a final consonant, e.g. ሁለት => ሁለተኛ,
+        // አስር => አስረኛ, but is simply appended after a vowel, e.g. ሃያኛ
+        let stem = match last {
+            'ኝ' => String::from("ነ"),
+            'ህ' => String::new(),
+            c if ('\u{1200}'..='\u{137f}').contains(&c) && (c as u32 - 0x1200) % 8 == 5 => {
+                String::from(char::from_u32(c as u32 - 5).unwrap())
+            }
+            c => String::from(c),
+        };
+
+        Ok(format!("{}{}ኛ", cardinal_word, stem))
+    }
+
+    fn to_ordinal_num(&self, num: BigFloat) -> Result<String, Num2Err> {
+        Ok(format!("{}ኛ", num.to_u128().unwrap()))
+    }
+
+    fn to_year(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if !num.frac().is_zero() {
+            return Err(Num2Err::FloatingYear);
+        }
+
+        let words = self.int_to_cardinal(num.abs())?;
+        if num.is_negative() {
+            Ok(format!("{} ዓ.ዓ.", words))
+        } else {
+            Ok(words)
+        }
+    }
+
+    fn to_currency(&self, num: BigFloat, currency: Currency) -> Result<String, Num2Err> {
+        if num.is_inf() {
+            Ok(format!(
+                "{} {}",
+                self.to_cardinal(num)?,
+                self.currencies(currency)
+            ))
+        } else if num.frac().is_zero() {
+            let words = self.int_to_cardinal(num)?;
+            Ok(format!("{} {}", words, self.currencies(currency)))
+        } else {
+            let integral_part = num.int();
+            let cents_nb = (num * BigFloat::from(100)).int() % BigFloat::from(100);
+            let cents_words = self.int_to_cardinal(cents_nb)?;
+            let cents_suffix = self.cents(currency);
+            let integral_word = self.to_currency(integral_part, currency)?;
+
+            if cents_nb.is_zero() {
+                Ok(integral_word)
+            } else if integral_part.is_zero() && !self.zero_major {
+                Ok(format!("{} {}", cents_words, cents_suffix))
+            } else {
+                // cents are introduced by the prefix ከ rather than by a
+                // conjunction, e.g. አርባ ሁለት ብር ከአንድ ሳንቲም
+                Ok(format!(
+                    "{} ከ{} {}",
+                    integral_word, cents_words, cents_suffix
+                ))
+            }
+        }
+    }
+
+    fn to_percent(&self, num: BigFloat) -> Result<String, Num2Err> {
+        Ok(format!("{} በመቶ", self.to_cardinal(num)?))
+    }
+
+    fn to_unit(&self, num: BigFloat, unit: Unit) -> Result<String, Num2Err> {
+        Ok(format!("{} {}", self.to_cardinal(num)?, self.units(unit)))
+    }
+
+    fn plural_category(&self, num: BigFloat) -> PluralCategory {
+        // CLDR: one is i = 0 or n = 1
+        if num.abs() <= BigFloat::from(1) {
+            PluralCategory::One
+        } else {
+            PluralCategory::Other
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_cardinal() {
+        assert_eq!(
+            Num2Words::new(1).lang(Lang::Amharic).to_words(),
+            Ok(String::from("አንድ"))
+        );
+        assert_eq!(
+            Num2Words::new(2).lang(Lang::Amharic).to_words(),
+            Ok(String::from("ሁለት"))
+        );
+        assert_eq!(
+            Num2Words::new(15).lang(Lang::Amharic).to_words(),
+            Ok(String::from("አስራ አምስት"))
+        );
+        assert_eq!(
+            Num2Words::new(42).lang(Lang::Amharic).to_words(),
+            Ok(String::from("አርባ ሁለት"))
+        );
+        assert_eq!(
+            Num2Words::new(100).lang(Lang::Amharic).to_words(),
+            Ok(String::from("መቶ"))
+        );
+        assert_eq!(
+            Num2Words::new(-1991).lang(Lang::Amharic).to_words(),
+            Ok(String::from("አሉታዊ አንድ ሺህ ዘጠኝ መቶ ዘጠና አንድ"))
+        );
+        assert_eq!(
+            Num2Words::new(2.25).lang(Lang::Amharic).to_words(),
+            Ok(String::from("ሁለት ነጥብ ሁለት አምስት"))
+        );
+        assert_eq!(
+            Num2Words::new(0.5).lang(Lang::Amharic).to_words(),
+            Ok(String::from("ዜሮ ነጥብ አምስት"))
+        );
+        assert_eq!(
+            Num2Words::new(-0.25).lang(Lang::Amharic).to_words(),
+            Ok(String::from("አሉታዊ ዜሮ ነጥብ ሁለት አምስት"))
+        );
+    }
+
+    #[test]
+    fn test_ordinal() {
+        assert_eq!(
+            Num2Words::new(1).lang(Lang::Amharic).ordinal().to_words(),
+            Ok(String::from("አንደኛ"))
+        );
+        assert_eq!(
+            Num2Words::new(2).lang(Lang::Amharic).ordinal().to_words(),
+            Ok(String::from("ሁለተኛ"))
+        );
+        assert_eq!(
+            Num2Words::new(9).lang(Lang::Amharic).ordinal().to_words(),
+            Ok(String::from("ዘጠነኛ"))
+        );
+        assert_eq!(
+            Num2Words::new(10).lang(Lang::Amharic).ordinal().to_words(),
+            Ok(String::from("አስረኛ"))
+        );
+        assert_eq!(
+            Num2Words::new(20).lang(Lang::Amharic).ordinal().to_words(),
+            Ok(String::from("ሃያኛ"))
+        );
+        assert_eq!(
+            Num2Words::new(42).lang(Lang::Amharic).ordinal().to_words(),
+            Ok(String::from("አርባ ሁለተኛ"))
+        );
+        assert_eq!(
+            Num2Words::new(100).lang(Lang::Amharic).ordinal().to_words(),
+            Ok(String::from("መቶኛ"))
+        );
+        assert_eq!(
+            Num2Words::new(1000)
+                .lang(Lang::Amharic)
+                .ordinal()
+                .to_words(),
+            Ok(String::from("አንድ ሺኛ"))
+        );
+    }
+
+    #[test]
+    fn test_ordinal_num() {
+        assert_eq!(
+            Num2Words::new(42)
+                .lang(Lang::Amharic)
+                .ordinal_num()
+                .to_words(),
+            Ok(String::from("42ኛ"))
+        );
+    }
+
+    #[test]
+    fn test_year() {
+        assert_eq!(
+            Num2Words::new(2016).lang(Lang::Amharic).year().to_words(),
+            Ok(String::from("ሁለት ሺህ አስራ ስድስት"))
+        );
+        assert_eq!(
+            Num2Words::new(-44).lang(Lang::Amharic).year().to_words(),
+            Ok(String::from("አርባ አራት ዓ.ዓ."))
+        );
+    }
+
+    #[test]
+    fn test_currency() {
+        assert_eq!(
+            Num2Words::new(42)
+                .lang(Lang::Amharic)
+                .currency(Currency::ETB)
+                .to_words(),
+            Ok(String::from("አርባ ሁለት ብር"))
+        );
+        assert_eq!(
+            Num2Words::new(42.01)
+                .lang(Lang::Amharic)
+                .currency(Currency::ETB)
+                .to_words(),
+            Ok(String::from("አርባ ሁለት ብር ከአንድ ሳንቲም"))
+        );
+        assert_eq!(
+            Num2Words::new(0.5)
+                .lang(Lang::Amharic)
+                .currency(Currency::ETB)
+                .to_words(),
+            Ok(String::from("ሃምሳ ሳንቲም"))
+        );
+        assert_eq!(
+            Num2Words::new(0.2)
+                .lang(Lang::Amharic)
+                .currency(Currency::ETB)
+                .prefer("zero-major")
+                .to_words(),
+            Ok(String::from("ዜሮ ብር ከሃያ ሳንቲም"))
+        );
+    }
+
+    #[test]
+    fn test_percent_and_unit() {
+        assert_eq!(
+            Num2Words::new(42).lang(Lang::Amharic).percent().to_words(),
+            Ok(String::from("አርባ ሁለት በመቶ"))
+        );
+        assert_eq!(
+            Num2Words::new(3)
+                .lang(Lang::Amharic)
+                .unit(Unit::Hour)
+                .to_words(),
+            Ok(String::from("ሶስት ሰዓት"))
+        );
+    }
+}
@@ -0,0 +1,657 @@
+use crate::{
+    num2words::Num2Err, Agreement, Currency, Gender, GrammaticalNumber, Language, PluralCategory,
+    Unit,
+};
+use num_bigfloat::BigFloat;
+
+pub struct Arabic {
+    feminine: bool,
+    eastern_digits: bool,
+    zero_major: bool,
+}
+
+// Numbers from 3 to 10 take the opposite gender of their noun, so the
+// masculine forms below (used with masculine nouns) end with a tāʾ marbūṭa.
+const UNITS_MASCULINE: [&str; 10] = [
+    "واحد",
+    "اثنان",
+    "ثلاثة",
+    "أربعة",
+    "خمسة",
+    "ستة",
+    "سبعة",
+    "ثمانية",
+    "تسعة",
+    "عشرة",
+];
+const UNITS_FEMININE: [&str; 10] = [
+    "واحدة",
+    "اثنتان",
+    "ثلاث",
+    "أربع",
+    "خمس",
+    "ست",
+    "سبع",
+    "ثماني",
+    "تسع",
+    "عشر",
+];
+
+const TENS: [&str; 8] = [
+    "عشرون",
+    "ثلاثون",
+    "أربعون",
+    "خمسون",
+    "ستون",
+    "سبعون",
+    "ثمانون",
+    "تسعون",
+];
+
+const HUNDREDS: [&str; 9] = [
+    "مئة",
+    "مئتان",
+    "ثلاثمئة",
+    "أربعمئة",
+    "خمسمئة",
+    "ستمئة",
+    "سبعمئة",
+    "ثمانمئة",
+    "تسعمئة",
+];
+
+// (singular, dual, plural), all of them masculine
+const MEGAS: [(&str, &str, &str); 5] = [
+    ("ألف", "ألفان", "آلاف"),
+    ("مليون", "مليونان", "ملايين"),
+    ("مليار", "ملياران", "مليارات"),
+    ("تريليون", "تريليونان", "تريليونات"),
+    ("كوادريليون", "كوادريليونان", "كوادريليونات"),
+];
+
+const ORDINALS_MASCULINE: [&str; 10] = [
+    "الأول",
+    "الثاني",
+    "الثالث",
+    "الرابع",
+    "الخامس",
+    "السادس",
+    "السابع",
+    "الثامن",
+    "التاسع",
+    "العاشر",
+];
+const ORDINALS_FEMININE: [&str; 10] = [
+    "الأولى",
+    "الثانية",
+    "الثالثة",
+    "الرابعة",
+    "الخامسة",
+    "السادسة",
+    "السابعة",
+    "الثامنة",
+    "التاسعة",
+    "العاشرة",
+];
+
+const EASTERN_DIGITS: [char; 10] = ['٠', '١', '٢', '٣', '٤', '٥', '٦', '٧', '٨', '٩'];
+
+/// Forms of a counted noun
+struct Noun {
+    /// After 1, 0 and from 100 onwards, e.g. `مئة دينار`
+    singular: &'static str,
+    /// Stands alone for 2, e.g. `ديناران`
+    dual: &'static str,
+    /// After 3 to 10, e.g. `ثلاثة دنانير`
+    plural: &'static str,
+    /// After 11 to 99, e.g. `أحد عشر دينارًا`
+    accusative: &'static str,
+    gender: Gender,
+}
+
+const fn noun(
+    singular: &'static str,
+    dual: &'static str,
+    plural: &'static str,
+    accusative: &'static str,
+    gender: Gender,
+) -> Noun {
+    Noun {
+        singular,
+        dual,
+        plural,
+        accusative,
+        gender,
+    }
+}
+
+const DINAR: Noun = noun("دينار", "ديناران", "دنانير", "دينارًا", Gender::Masculine);
+const DIRHAM: Noun = noun("درهم", "درهمان", "دراهم", "درهمًا", Gender::Masculine);
+const RIYAL: Noun = noun("ريال", "ريالان", "ريالات", "ريالًا", Gender::Masculine);
+const DOLLAR: Noun = noun("دولار", "دولاران", "دولارات", "دولارًا", Gender::Masculine);
+const EURO: Noun = noun("يورو", "يوروان", "يوروات", "يورو", Gender::Masculine);
+const POUND: Noun = noun("جنيه", "جنيهان", "جنيهات", "جنيهًا", Gender::Masculine);
+const LIRA: Noun = noun("ليرة", "ليرتان", "ليرات", "ليرةً", Gender::Feminine);
+const FILS: Noun = noun("فلس", "فلسان", "فلوس", "فلسًا", Gender::Masculine);
+const HALALA: Noun = noun("هللة", "هللتان", "هللات", "هللةً", Gender::Feminine);
+const SANTEEM: Noun = noun("سنتيم", "سنتيمان", "سنتيمات", "سنتيمًا", Gender::Masculine);
+const CENT: Noun = noun("سنت", "سنتان", "سنتات", "سنتًا", Gender::Masculine);
+
+impl Arabic {
+    pub fn new(feminine: bool, eastern_digits: bool) -> Self {
+        Self {
+            feminine,
+            eastern_digits,
+            zero_major: false,
+        }
+    }
+
+    /// Keeps the major unit clause for amounts below one, e.g. `صفر دينار
+    /// وعشرون فلسًا`
+    pub fn zero_major(self, zero_major: bool) -> Self {
+        Self { zero_major, ..self }
+    }
+
+    fn gender(&self) -> Gender {
+        if self.feminine {
+            Gender::Feminine
+        } else {
+            Gender::Masculine
+        }
+    }
+
+    fn currencies(&self, currency: Currency) -> Noun {
+        match currency {
+            Currency::DINAR | Currency::DZD | Currency::KWD => DINAR,
+            Currency::AED => DIRHAM,
+            Currency::QAR | Currency::RIYAL | Currency::SAR => RIYAL,
+            Currency::AUD
+            | Currency::CAD
+            | Currency::DOLLAR
+            | Currency::HKD
+            | Currency::NZD
+            | Currency::SGD
+            | Currency::TWD
+            | Currency::USD => DOLLAR,
+            Currency::EUR => EURO,
+            Currency::GBP => POUND,
+            Currency::TRY => LIRA,
+            _ => {
+                let code = currency.code();
+                noun(code, code, code, code, Gender::Masculine)
+            }
+        }
+    }
+
+    fn cents(&self, currency: Currency) -> Noun {
+        match currency {
+            Currency::AED | Currency::DINAR | Currency::KWD => FILS,
+            Currency::SAR | Currency::RIYAL => HALALA,
+            Currency::QAR => DIRHAM,
+            Currency::DZD => SANTEEM,
+            _ => CENT,
+        }
+    }
+
+    fn units(&self, unit: Unit) -> Noun {
+        match unit {
+            Unit::Meter => noun("متر", "متران", "أمتار", "مترًا", Gender::Masculine),
+            Unit::Kilometer => noun(
+                "كيلومتر",
+                "كيلومتران",
+                "كيلومترات",
+                "كيلومترًا",
+                Gender::Masculine,
+            ),
+            Unit::Gram => noun("غرام", "غرامان", "غرامات", "غرامًا", Gender::Masculine),
+            Unit::Kilogram => noun(
+                "كيلوغرام",
+                "كيلوغرامان",
+                "كيلوغرامات",
+                "كيلوغرامًا",
+                Gender::Masculine,
+            ),
+            Unit::Liter => noun("لتر", "لتران", "لترات", "لترًا", Gender::Masculine),
+            Unit::Second => noun("ثانية", "ثانيتان", "ثوانٍ", "ثانيةً", Gender::Feminine),
+            Unit::Minute => noun("دقيقة", "دقيقتان", "دقائق", "دقيقةً", Gender::Feminine),
+            Unit::Hour => noun("ساعة", "ساعتان", "ساعات", "ساعةً", Gender::Feminine),
+        }
+    }
+
+    fn split_thousands(&self, mut num: BigFloat) -> Vec<u64> {
+        let mut thousands = Vec::new();
+        let bf_1000 = BigFloat::from(1000);
+
+        while !num.is_zero() {
+            thousands.push((num % bf_1000).to_u64().unwrap());
+            num /= bf_1000;
+        }
+
+        thousands
+    }
+
+    // units come before the tens, e.g. "خمسة وعشرون"
+    fn triplet_words(&self, triplet: u64, gender: Gender) -> Vec<String> {
+        let hundreds = (triplet / 100) as usize;
+        let rest = (triplet % 100) as usize;
+        let (units, eleven, twelve, ten) = match gender {
+            Gender::Feminine => (UNITS_FEMININE, "إحدى", "اثنتا", "عشرة"),
+            _ => (UNITS_MASCULINE, "أحد", "اثنا", "عشر"),
+        };
+        let mut words = vec![];
+
+        if hundreds > 0 {
+            words.push(String::from(HUNDREDS[hundreds - 1]));
+        }
+
+        match rest {
+            0 => (),
+            1..=10 => words.push(String::from(units[rest - 1])),
+            11 => words.push(format!("{} {}", eleven, ten)),
+            12 => words.push(format!("{} {}", twelve, ten)),
+            13..=19 => words.push(format!("{} {}", units[rest - 11], ten)),
+            _ if rest.is_multiple_of(10) => words.push(String::from(TENS[rest / 10 - 2])),
+            _ => {
+                let unit = match (rest % 10, gender) {
+                    (1, Gender::Feminine) => "إحدى",
+                    (unit, _) => units[unit - 1],
+                };
+                words.push(format!("{} و{}", unit, TENS[rest / 10 - 2]));
+            }
+        }
+
+        words
+    }
+
+    fn int_to_cardinal(&self, num: BigFloat, gender: Gender) -> Result<String, Num2Err> {
+        // special case zero
+        if num.is_zero() {
+            return Ok(String::from("صفر"));
+        }
+
+        let mut words = vec![];
+        for (i, triplet) in self.split_thousands(num.abs()).iter().enumerate().rev() {
+            if *triplet == 0 {
+                continue;
+            }
+
+            if i == 0 {
+                words.extend(self.triplet_words(*triplet, gender));
+                continue;
+            }
+
+            let (singular, dual, plural) = MEGAS.get(i - 1).ok_or(Num2Err::CannotConvert)?;
+            match triplet {
+                1 => words.push(String::from(*singular)),
+                2 => words.push(String::from(*dual)),
+                3..=10 => words.push(format!(
+                    "{} {}",
+                    self.triplet_words(*triplet, Gender::Masculine).join(" و"),
+                    plural
+                )),
+                _ => words.push(format!(
+                    "{} {}",
+                    self.triplet_words(*triplet, Gender::Masculine).join(" و"),
+                    singular
+                )),
+            }
+        }
+
+        let words = words.join(" و");
+        // handling negative values
+        if num.is_negative() {
+            Ok(format!("سالب {}", words))
+        } else {
+            Ok(words)
+        }
+    }
+
+    fn float_to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let mut words = vec![];
+        if num.is_negative() {
+            words.push(String::from("سالب"));
+        }
+        words.push(self.int_to_cardinal(num.int().abs(), self.gender())?);
+        words.push(String::from("فاصلة"));
+
+        let mut ordinal_part = num.frac().abs();
+        while !ordinal_part.is_zero() {
+            let digit = (ordinal_part * BigFloat::from(10)).int();
+            ordinal_part = (ordinal_part * BigFloat::from(10)).frac();
+            words.push(self.int_to_cardinal(digit, self.gender())?);
+        }
+
+        Ok(words.join(" "))
+    }
+
+    // a number with its counted noun, whose form depends on the number, e.g.
+    // "دينار واحد", "ديناران", "ثلاثة دنانير", "أحد عشر دينارًا", "مئة دينار"
+    fn with_noun(&self, num: BigFloat, noun: Noun) -> Result<String, Num2Err> {
+        if num.is_inf() {
+            return Ok(format!(
+                "{}عدد لا نهائي من {}",
+                if num.is_negative() { "سالب " } else { "" },
+                noun.plural
+            ));
+        }
+        if !num.frac().is_zero() {
+            return Ok(format!(
+                "{} {}",
+                self.float_to_cardinal(num)?,
+                noun.singular
+            ));
+        }
+
+        let sign = if num.is_negative() { "سالب " } else { "" };
+        let number = self.int_to_cardinal(num, noun.gender)?;
+        Ok(match self.plural_category(num) {
+            PluralCategory::One => format!(
+                "{}{} {}",
+                sign,
+                noun.singular,
+                self.int_to_cardinal(num.abs(), noun.gender)?
+            ),
+            PluralCategory::Two => format!("{}{}", sign, noun.dual),
+            PluralCategory::Few => format!("{} {}", number, noun.plural),
+            PluralCategory::Many => format!("{} {}", number, noun.accusative),
+            _ => format!("{} {}", number, noun.singular),
+        })
+    }
+
+    fn to_digits(&self, num: u128) -> String {
+        let digits = num.to_string();
+        if self.eastern_digits {
+            digits
+                .chars()
+                .map(|c| EASTERN_DIGITS[c.to_digit(10).unwrap() as usize])
+                .collect()
+        } else {
+            digits
+        }
+    }
+}
+
+impl Language for Arabic {
+    fn to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num.is_inf_pos() {
+            Ok(String::from("ما لا نهاية"))
+        } else if num.is_inf_neg() {
+            Ok(String::from("سالب ما لا نهاية"))
+        } else if num.frac().is_zero() {
+            self.int_to_cardinal(num, self.gender())
+        } else {
+            self.float_to_cardinal(num)
+        }
+    }
+
+    fn to_ordinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        // there is no ordinal of zero, see `Lang::Arabic`
+        if num.is_zero() {
+            return Err(Num2Err::CannotConvert);
+        }
+
+        let (ordinals, eleven, ten) = if self.feminine {
+            (ORDINALS_FEMININE, "الحادية", "عشرة")
+        } else {
+            (ORDINALS_MASCULINE, "الحادي", "عشر")
+        };
+
+        let rest = (num % BigFloat::from(100)).to_u64().unwrap() as usize;
+        let rest_word = match rest {
+            0 => None,
+            1..=10 => Some(String::from(ordinals[rest - 1])),
+            11 => Some(format!("{} {}", eleven, ten)),
+            12..=19 => Some(format!("{} {}", ordinals[rest - 11], ten)),
+            _ => {
+                let unit = match rest % 10 {
+                    0 => None,
+                    1 => Some(eleven),
+                    unit => Some(ordinals[unit - 1]),
+                };
+                let tens = format!("ال{}", TENS[rest / 10 - 2]);
+                Some(match unit {
+                    Some(unit) => format!("{} و{}", unit, tens),
+                    None => tens,
+                })
+            }
+        };
+
+        // above one hundred, the rest is appended to the definite cardinal,
+        // e.g. "المئة والثاني والأربعون"
+        let hundreds = num - BigFloat::from(rest as u64);
+        if hundreds.is_zero() {
+            return rest_word.ok_or(Num2Err::CannotConvert);
+        }
+        let hundreds_word = format!("ال{}", self.int_to_cardinal(hundreds, self.gender())?);
+        Ok(match rest_word {
+            Some(rest_word) => format!("{} و{}", hundreds_word, rest_word),
+            None => hundreds_word,
+        })
+    }
+
+    fn to_ordinal_num(&self, num: BigFloat) -> Result<String, Num2Err> {
+        Ok(format!("الـ{}", self.to_digits(num.to_u128().unwrap())))
+    }
+
+    fn to_year(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if !num.frac().is_zero() {
+            return Err(Num2Err::FloatingYear);
+        }
+
+        let year_word = self.int_to_cardinal(num.abs(), Gender::Masculine)?;
+        if num.is_negative() {
+            Ok(format!("{} قبل الميلاد", year_word))
+        } else {
+            Ok(year_word)
+        }
+    }
+
+    fn to_currency(&self, num: BigFloat, currency: Currency) -> Result<String, Num2Err> {
+        if num.is_inf() || num.frac().is_zero() {
+            return self.with_noun(num, self.currencies(currency));
+        }
+
+        let integral_part = num.int();
+        let cents_nb = (num * BigFloat::from(100)).int() % BigFloat::from(100);
+        let cents_words = self.with_noun(cents_nb, self.cents(currency))?;
+        let integral_word = self.to_currency(integral_part, currency)?;
+
+        if cents_nb.is_zero() {
+            Ok(integral_word)
+        } else if integral_part.is_zero() && !self.zero_major {
+            Ok(cents_words)
+        } else {
+            Ok(format!("{} و{}", integral_word, cents_words))
+        }
+    }
+
+    fn to_percent(&self, num: BigFloat) -> Result<String, Num2Err> {
+        Ok(format!("{} بالمئة", self.to_cardinal(num)?))
+    }
+
+    fn to_unit(&self, num: BigFloat, unit: Unit) -> Result<String, Num2Err> {
+        self.with_noun(num, self.units(unit))
+    }
+
+    fn plural_category(&self, num: BigFloat) -> PluralCategory {
+        if !num.frac().is_zero() || num.is_inf() {
+            return PluralCategory::Other;
+        }
+        match (num.abs() % BigFloat::from(100)).to_u64() {
+            _ if num.is_zero() => PluralCategory::Zero,
+            _ if num.abs() == BigFloat::from(1) => PluralCategory::One,
+            _ if num.abs() == BigFloat::from(2) => PluralCategory::Two,
+            Some(3..=10) => PluralCategory::Few,
+            Some(11..=99) => PluralCategory::Many,
+            _ => PluralCategory::Other,
+        }
+    }
+
+    fn agreement(&self, num: BigFloat) -> Agreement {
+        let category = self.plural_category(num);
+        Agreement {
+            // only 3 to 10 are followed by a plural, 2 by a dual
+            number: match category {
+                PluralCategory::Two => GrammaticalNumber::Dual,
+                PluralCategory::Few => GrammaticalNumber::Plural,
+                _ => GrammaticalNumber::Singular,
+            },
+            gender: Some(self.gender()),
+            ..Agreement::from_category(category)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Currency, Lang, Num2Err, Num2Words};
+
+    #[test]
+    fn test_cardinal() {
+        for (num, words) in [
+            (0, "صفر"),
+            (1, "واحد"),
+            (3, "ثلاثة"),
+            (11, "أحد عشر"),
+            (12, "اثنا عشر"),
+            (15, "خمسة عشر"),
+            (20, "عشرون"),
+            (42, "اثنان وأربعون"),
+            (100, "مئة"),
+            (200, "مئتان"),
+            (325, "ثلاثمئة وخمسة وعشرون"),
+            (1000, "ألف"),
+            (2000, "ألفان"),
+            (3000, "ثلاثة آلاف"),
+            (11000, "أحد عشر ألف"),
+            (2024, "ألفان وأربعة وعشرون"),
+            (1000000, "مليون"),
+            (-7, "سالب سبعة"),
+        ] {
+            assert_eq!(
+                Num2Words::new(num).lang(Lang::Arabic).to_words(),
+                Ok(String::from(words))
+            );
+        }
+        for (num, words) in [(3, "ثلاث"), (11, "إحدى عشرة"), (21, "إحدى وعشرون")]
+        {
+            assert_eq!(
+                Num2Words::new(num)
+                    .lang(Lang::Arabic)
+                    .prefer("f")
+                    .to_words(),
+                Ok(String::from(words))
+            );
+        }
+    }
+
+    #[test]
+    fn test_ordinal() {
+        for (num, masculine, feminine) in [
+            (1, "الأول", "الأولى"),
+            (2, "الثاني", "الثانية"),
+            (11, "الحادي عشر", "الحادية عشرة"),
+            (12, "الثاني عشر", "الثانية عشرة"),
+            (20, "العشرون", "العشرون"),
+            (42, "الثاني والأربعون", "الثانية والأربعون"),
+            (100, "المئة", "المئة"),
+        ] {
+            assert_eq!(
+                Num2Words::new(num).lang(Lang::Arabic).ordinal().to_words(),
+                Ok(String::from(masculine))
+            );
+            assert_eq!(
+                Num2Words::new(num)
+                    .lang(Lang::Arabic)
+                    .prefer("مؤنث")
+                    .ordinal()
+                    .to_words(),
+                Ok(String::from(feminine))
+            );
+        }
+        assert_eq!(
+            Num2Words::new(0).lang(Lang::Arabic).ordinal().to_words(),
+            Err(Num2Err::CannotConvert)
+        );
+    }
+
+    #[test]
+    fn test_ordinal_num() {
+        assert_eq!(
+            Num2Words::new(42)
+                .lang(Lang::Arabic)
+                .ordinal_num()
+                .to_words(),
+            Ok(String::from("الـ42"))
+        );
+        assert_eq!(
+            Num2Words::new(42)
+                .lang(Lang::Arabic)
+                .prefer("eastern-digits")
+                .ordinal_num()
+                .to_words(),
+            Ok(String::from("الـ٤٢"))
+        );
+    }
+
+    #[test]
+    fn test_currency() {
+        for (num, words) in [
+            (1.0, "دينار واحد"),
+            (2.0, "ديناران"),
+            (3.0, "ثلاثة دنانير"),
+            (10.0, "عشرة دنانير"),
+            (11.0, "أحد عشر دينارًا"),
+            (99.0, "تسعة وتسعون دينارًا"),
+            (100.0, "مئة دينار"),
+            (103.0, "مئة وثلاثة دنانير"),
+            (1000.0, "ألف دينار"),
+            (2.5, "ديناران وخمسون فلسًا"),
+            (0.01, "فلس واحد"),
+        ] {
+            assert_eq!(
+                Num2Words::new(num)
+                    .lang(Lang::Arabic)
+                    .currency(Currency::KWD)
+                    .to_words(),
+                Ok(String::from(words))
+            );
+        }
+        assert_eq!(
+            Num2Words::new(3)
+                .lang(Lang::Arabic)
+                .currency(Currency::TRY)
+                .to_words(),
+            Ok(String::from("ثلاث ليرات"))
+        );
+        assert_eq!(
+            Num2Words::new(2)
+                .lang(Lang::Arabic)
+                .currency(Currency::USD)
+                .to_words(),
+            Ok(String::from("دولاران"))
+        );
+        assert_eq!(
+            Num2Words::new(11)
+                .lang(Lang::Arabic)
+                .currency(Currency::SAR)
+                .to_words(),
+            Ok(String::from("أحد عشر ريالًا"))
+        );
+        assert_eq!(
+            Num2Words::new(5)
+                .lang(Lang::Arabic)
+                .currency(Currency::AED)
+                .to_words(),
+            Ok(String::from("خمسة دراهم"))
+        );
+    }
+
+    #[test]
+    fn test_year() {
+        assert_eq!(
+            Num2Words::new(2024).lang(Lang::Arabic).year().to_words(),
+            Ok(String::from("ألفان وأربعة وعشرون"))
+        );
+    }
+}
@@ -0,0 +1,543 @@
+use super::ne::split_indian_groups;
+use crate::{num2words::Num2Err, Currency, Language, PluralCategory, Unit};
+use num_bigfloat::BigFloat;
+
+pub struct Bengali {
+    zero_major: bool,
+}
+
+// Every number below a hundred is its own word, e.g. বিয়াল্লিশ (42)
+const NUMBERS: [&str; 100] = [
+    "শূন্য",
+    "এক",
+    "দুই",
+    "তিন",
+    "চার",
+    "পাঁচ",
+    "ছয়",
+    "সাত",
+    "আট",
+    "নয়",
+    "দশ",
+    "এগারো",
+    "বারো",
+    "তেরো",
+    "চোদ্দ",
+    "পনেরো",
+    "ষোলো",
+    "সতেরো",
+    "আঠারো",
+    "উনিশ",
+    "বিশ",
+    "একুশ",
+    "বাইশ",
+    "তেইশ",
+    "চব্বিশ",
+    "পঁচিশ",
+    "ছাব্বিশ",
+    "সাতাশ",
+    "আটাশ",
+    "ঊনত্রিশ",
+    "ত্রিশ",
+    "একত্রিশ",
+    "বত্রিশ",
+    "তেত্রিশ",
+    "চৌত্রিশ",
+    "পঁয়ত্রিশ",
+    "ছত্রিশ",
+    "সাঁইত্রিশ",
+    "আটত্রিশ",
+    "ঊনচল্লিশ",
+    "চল্লিশ",
+    "একচল্লিশ",
+    "বিয়াল্লিশ",
+    "তেতাল্লিশ",
+    "চুয়াল্লিশ",
+    "পঁয়তাল্লিশ",
+    "ছেচল্লিশ",
+    "সাতচল্লিশ",
+    "আটচল্লিশ",
+    "ঊনপঞ্চাশ",
+    "পঞ্চাশ",
+    "একান্ন",
+    "বাহান্ন",
+    "তিপ্পান্ন",
+    "চুয়ান্ন",
+    "পঞ্চান্ন",
+    "ছাপ্পান্ন",
+    "সাতান্ন",
+    "আটান্ন",
+    "ঊনষাট",
+    "ষাট",
+    "একষট্টি",
+    "বাষট্টি",
+    "তেষট্টি",
+    "চৌষট্টি",
+    "পঁয়ষট্টি",
+    "ছেষট্টি",
+    "সাতষট্টি",
+    "আটষট্টি",
+    "ঊনসত্তর",
+    "সত্তর",
+    "একাত্তর",
+    "বাহাত্তর",
+    "তিয়াত্তর",
+    "চুয়াত্তর",
+    "পঁচাত্তর",
+    "ছিয়াত্তর",
+    "সাতাত্তর",
+    "আটাত্তর",
+    "ঊনআশি",
+    "আশি",
+    "একাশি",
+    "বিরাশি",
+    "তিরাশি",
+    "চুরাশি",
+    "পঁচাশি",
+    "ছিয়াশি",
+    "সাতাশি",
+    "অষ্টাশি",
+    "ঊননব্বই",
+    "নব্বই",
+    "একানব্বই",
+    "বিরানব্বই",
+    "তিরানব্বই",
+    "চুরানব্বই",
+    "পঁচানব্বই",
+    "ছিয়ানব্বই",
+    "সাতানব্বই",
+    "আটানব্বই",
+    "নিরানব্বই",
+];
+
+const ORDINALS: [&str; 10] = [
+    "প্রথম",
+    "দ্বিতীয়",
+    "তৃতীয়",
+    "চতুর্থ",
+    "পঞ্চম",
+    "ষষ্ঠ",
+    "সপ্তম",
+    "অষ্টম",
+    "নবম",
+    "দশম",
+];
+
+// Indian numbering system, up to কোটি (10^7) which is then repeated, e.g.
+// একশো কোটি for 10^9
+const MEGAS: [&str; 2] = ["হাজার", "লাখ"];
+const CRORE: &str = "কোটি";
+
+const DIGITS: [char; 10] = ['০', '১', '২', '৩', '৪', '৫', '৬', '৭', '৮', '৯'];
+
+impl Bengali {
+    pub fn new() -> Self {
+        Self { zero_major: false }
+    }
+
+    /// Keeps the major unit clause for amounts below one, e.g. `শূন্য টাকা
+    /// বিশ পয়সা`
+    pub fn zero_major(self, zero_major: bool) -> Self {
+        Self { zero_major }
+    }
+
+    fn currencies(&self, currency: Currency) -> String {
+        match currency {
+            Currency::AUD => String::from("অস্ট্রেলিয়ান ডলার"),
+            Currency::BDT => String::from("টাকা"),
+            Currency::CAD => String::from("কানাডিয়ান ডলার"),
+            Currency::CNY => String::from("ইউয়ান"),
+            Currency::DOLLAR => String::from("ডলার"),
+            Currency::EUR => String::from("ইউরো"),
+            Currency::GBP => String::from("পাউন্ড"),
+            Currency::INR => String::from("রুপি"),
+            Currency::JPY => String::from("ইয়েন"),
+            Currency::RUB => String::from("রুবল"),
+            Currency::USD => String::from("মার্কিন ডলার"),
+            _ => currency.default_string(false),
+        }
+    }
+
+    fn cents(&self, currency: Currency) -> String {
+        match currency {
+            Currency::BDT | Currency::INR => String::from("পয়সা"),
+            Currency::GBP => String::from("পেনি"),
+            _ => String::from("সেন্ট"),
+        }
+    }
+
+    fn units(&self, unit: Unit) -> &'static str {
+        match unit {
+            Unit::Meter => "মিটার",
+            Unit::Kilometer => "কিলোমিটার",
+            Unit::Gram => "গ্রাম",
+            Unit::Kilogram => "কিলোগ্রাম",
+            Unit::Liter => "লিটার",
+            Unit::Second => "সেকেন্ড",
+            Unit::Minute => "মিনিট",
+            Unit::Hour => "ঘণ্টা",
+        }
+    }
+
+    fn below_crore(&self, num: BigFloat, words: &mut Vec<String>) {
+        for (i, group) in split_indian_groups(num).iter().enumerate().rev() {
+            if group == &0 {
+                continue;
+            }
+
+            let hundreds = (group / 100) as usize;
+            let below_hundred = (group % 100) as usize;
+
+            // hundreds are written as a single word, e.g. দুইশো (200)
+            if hundreds > 0 {
+                words.push(format!("{}শো", NUMBERS[hundreds]));
+            }
+            if below_hundred > 0 {
+                words.push(String::from(NUMBERS[below_hundred]));
+            }
+
+            if i != 0 {
+                words.push(String::from(MEGAS[i - 1]));
+            }
+        }
+    }
+
+    fn int_to_cardinal(&self, mut num: BigFloat) -> Result<String, Num2Err> {
+        // special case zero
+        if num.is_zero() {
+            return Ok(String::from(NUMBERS[0]));
+        }
+
+        // handling negative values
+        let mut words = vec![];
+        if num.is_negative() {
+            words.push(String::from("ঋণাত্মক"));
+            num = -num;
+        }
+
+        let bf_crore = BigFloat::from(10_000_000);
+        let crores = (num / bf_crore).int();
+        if !crores.is_zero() {
+            words.push(self.int_to_cardinal(crores)?);
+            words.push(String::from(CRORE));
+        }
+        self.below_crore(num % bf_crore, &mut words);
+
+        Ok(words.join(" "))
+    }
+
+    fn float_to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let integral_part = num.int();
+        let mut words: Vec<String> = vec![];
+
+        if !integral_part.is_zero() {
+            let integral_word = self.int_to_cardinal(integral_part)?;
+            words.push(integral_word);
+        } else if num.is_negative() {
+            words.push(String::from("ঋণাত্মক"));
+        }
+        if integral_part.is_zero() {
+            words.push(String::from(NUMBERS[0]));
+        }
+
+        let mut ordinal_part = num.frac().abs();
+        if !ordinal_part.is_zero() {
+            words.push(String::from("দশমিক"));
+        }
+        while !ordinal_part.is_zero() {
+            let digit = (ordinal_part * BigFloat::from(10)).int();
+            ordinal_part = (ordinal_part * BigFloat::from(10)).frac();
+            words.push(String::from(NUMBERS[digit.to_u64().unwrap() as usize]));
+        }
+        Ok(words.join(" "))
+    }
+
+    fn to_bengali_digits(&self, num: BigFloat) -> String {
+        num.to_u128()
+            .unwrap()
+            .to_string()
+            .chars()
+            .map(|c| DIGITS[c.to_digit(10).unwrap() as usize])
+            .collect()
+    }
+}
+
+impl Default for Bengali {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Language for Bengali {
+    fn to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num.is_inf_pos() {
+            Ok(String::from("অসীম"))
+        } else if num.is_inf_neg() {
+            Ok(String::from("ঋণাত্মক অসীম"))
+        } else if num.frac().is_zero() {
+            self.int_to_cardinal(num)
+        } else {
+            self.float_to_cardinal(num)
+        }
+    }
+
+    fn to_ordinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        // the first ten ordinals are borrowed from Sanskrit
+        match num.to_u64() {
+            Some(i @ 1..=10) => Ok(String::from(ORDINALS[i as usize - 1])),
+            _ => Ok(format!("{}তম", self.to_cardinal(num)?)),
+        }
+    }
+
+    fn to_ordinal_num(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let suffix = match num.to_u64() {
+            Some(1) | Some(5) | Some(7..=10) => "ম",
+            Some(2) | Some(3) => "য়",
+            Some(4) => "র্থ",
+            Some(6) => "ষ্ঠ",
+            _ => "তম",
+        };
+
+        Ok(format!("{}{}", self.to_bengali_digits(num), suffix))
+    }
+
+    fn to_year(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if !num.frac().is_zero() {
+            return Err(Num2Err::FloatingYear);
+        }
+
+        let year = num.abs();
+        let century = (year / BigFloat::from(100)).int();
+        let mut words = vec![];
+        if num.is_negative() {
+            words.push(String::from("খ্রিস্টপূর্ব"));
+        }
+
+        // years are read in hundreds when they do not fall on a round
+        // thousand, e.g. উনিশশো একানব্বই (1991) but দুই হাজার চব্বিশ (2024)
+        if year > BigFloat::from(1000)
+            && year < BigFloat::from(10000)
+            && !(century % BigFloat::from(10)).is_zero()
+        {
+            let below_hundred = (year % BigFloat::from(100)).to_u64().unwrap();
+            words.push(format!("{}শো", NUMBERS[century.to_u64().unwrap() as usize]));
+            if below_hundred != 0 {
+                words.push(String::from(NUMBERS[below_hundred as usize]));
+            }
+        } else {
+            words.push(self.int_to_cardinal(year)?);
+        }
+
+        Ok(words.join(" "))
+    }
+
+    fn to_currency(&self, num: BigFloat, currency: Currency) -> Result<String, Num2Err> {
+        // nouns stay in the singular after a numeral, e.g. বিয়াল্লিশ টাকা
+        if num.is_inf() {
+            Ok(format!(
+                "{}অসীম {}",
+                if num.is_negative() {
+                    "ঋণাত্মক "
+                } else {
+                    ""
+                },
+                self.currencies(currency)
+            ))
+        } else if num.frac().is_zero() {
+            let words = self.int_to_cardinal(num)?;
+            Ok(format!("{} {}", words, self.currencies(currency)))
+        } else {
+            let integral_part = num.int();
+            let cents_nb = (num * BigFloat::from(100)).int() % BigFloat::from(100);
+            let cents_words = self.int_to_cardinal(cents_nb)?;
+            let cents_suffix = self.cents(currency);
+            let integral_word = self.to_currency(integral_part, currency)?;
+
+            if cents_nb.is_zero() {
+                Ok(integral_word)
+            } else if integral_part.is_zero() && !self.zero_major {
+                Ok(format!("{} {}", cents_words, cents_suffix))
+            } else {
+                Ok(format!(
+                    "{} {} {}",
+                    integral_word, cents_words, cents_suffix
+                ))
+            }
+        }
+    }
+
+    fn to_percent(&self, num: BigFloat) -> Result<String, Num2Err> {
+        Ok(format!("{} শতাংশ", self.to_cardinal(num)?))
+    }
+
+    fn to_unit(&self, num: BigFloat, unit: Unit) -> Result<String, Num2Err> {
+        if num.is_inf() {
+            Ok(format!(
+                "{}অসীম {}",
+                if num.is_negative() {
+                    "ঋণাত্মক "
+                } else {
+                    ""
+                },
+                self.units(unit)
+            ))
+        } else {
+            Ok(format!("{} {}", self.to_cardinal(num)?, self.units(unit)))
+        }
+    }
+
+    fn plural_category(&self, num: BigFloat) -> PluralCategory {
+        // CLDR: one is i = 0 or n = 1
+        if num.abs() <= BigFloat::from(1) {
+            PluralCategory::One
+        } else {
+            PluralCategory::Other
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_cardinal() {
+        assert_eq!(
+            Num2Words::new(0).lang(Lang::Bengali).to_words(),
+            Ok(String::from("শূন্য"))
+        );
+        assert_eq!(
+            Num2Words::new(42).lang(Lang::Bengali).to_words(),
+            Ok(String::from("বিয়াল্লিশ"))
+        );
+        assert_eq!(
+            Num2Words::new(-99).lang(Lang::Bengali).to_words(),
+            Ok(String::from("ঋণাত্মক নিরানব্বই"))
+        );
+        assert_eq!(
+            Num2Words::new(205).lang(Lang::Bengali).to_words(),
+            Ok(String::from("দুইশো পাঁচ"))
+        );
+        assert_eq!(
+            Num2Words::new(100_000).lang(Lang::Bengali).to_words(),
+            Ok(String::from("এক লাখ"))
+        );
+        assert_eq!(
+            Num2Words::new(10_000_000).lang(Lang::Bengali).to_words(),
+            Ok(String::from("এক কোটি"))
+        );
+        assert_eq!(
+            Num2Words::new(1_234_567_890).lang(Lang::Bengali).to_words(),
+            Ok(String::from(
+                "একশো তেইশ কোটি পঁয়তাল্লিশ লাখ সাতষট্টি হাজার আটশো নব্বই"
+            ))
+        );
+        assert_eq!(
+            Num2Words::new(2.25).lang(Lang::Bengali).to_words(),
+            Ok(String::from("দুই দশমিক দুই পাঁচ"))
+        );
+        assert_eq!(
+            Num2Words::new(0.5).lang(Lang::Bengali).to_words(),
+            Ok(String::from("শূন্য দশমিক পাঁচ"))
+        );
+        assert_eq!(
+            Num2Words::new(-0.25).lang(Lang::Bengali).to_words(),
+            Ok(String::from("ঋণাত্মক শূন্য দশমিক দুই পাঁচ"))
+        );
+    }
+
+    #[test]
+    fn test_ordinal() {
+        assert_eq!(
+            Num2Words::new(1).lang(Lang::Bengali).ordinal().to_words(),
+            Ok(String::from("প্রথম"))
+        );
+        assert_eq!(
+            Num2Words::new(2).lang(Lang::Bengali).ordinal().to_words(),
+            Ok(String::from("দ্বিতীয়"))
+        );
+        assert_eq!(
+            Num2Words::new(10).lang(Lang::Bengali).ordinal().to_words(),
+            Ok(String::from("দশম"))
+        );
+        assert_eq!(
+            Num2Words::new(42).lang(Lang::Bengali).ordinal().to_words(),
+            Ok(String::from("বিয়াল্লিশতম"))
+        );
+    }
+
+    #[test]
+    fn test_ordinal_num() {
+        assert_eq!(
+            Num2Words::new(2)
+                .lang(Lang::Bengali)
+                .ordinal_num()
+                .to_words(),
+            Ok(String::from("২য়"))
+        );
+        assert_eq!(
+            Num2Words::new(42)
+                .lang(Lang::Bengali)
+                .ordinal_num()
+                .to_words(),
+            Ok(String::from("৪২তম"))
+        );
+    }
+
+    #[test]
+    fn test_year() {
+        assert_eq!(
+            Num2Words::new(1991).lang(Lang::Bengali).year().to_words(),
+            Ok(String::from("উনিশশো একানব্বই"))
+        );
+        assert_eq!(
+            Num2Words::new(2024).lang(Lang::Bengali).year().to_words(),
+            Ok(String::from("দুই হাজার চব্বিশ"))
+        );
+        assert_eq!(
+            Num2Words::new(-500).lang(Lang::Bengali).year().to_words(),
+            Ok(String::from("খ্রিস্টপূর্ব পাঁচশো"))
+        );
+    }
+
+    #[test]
+    fn test_currency() {
+        assert_eq!(
+            Num2Words::new(42.01)
+                .lang(Lang::Bengali)
+                .currency(Currency::BDT)
+                .to_words(),
+            Ok(String::from("বিয়াল্লিশ টাকা এক পয়সা"))
+        );
+        assert_eq!(
+            Num2Words::new(0.5)
+                .lang(Lang::Bengali)
+                .currency(Currency::BDT)
+                .to_words(),
+            Ok(String::from("পঞ্চাশ পয়সা"))
+        );
+        assert_eq!(
+            Num2Words::new(0.2)
+                .lang(Lang::Bengali)
+                .currency(Currency::BDT)
+                .prefer("zero-major")
+                .to_words(),
+            Ok(String::from("শূন্য টাকা বিশ পয়সা"))
+        );
+    }
+
+    #[test]
+    fn test_percent_and_unit() {
+        assert_eq!(
+            Num2Words::new(25).lang(Lang::Bengali).percent().to_words(),
+            Ok(String::from("পঁচিশ শতাংশ"))
+        );
+        assert_eq!(
+            Num2Words::new(3)
+                .lang(Lang::Bengali)
+                .unit(Unit::Hour)
+                .to_words(),
+            Ok(String::from("তিন ঘণ্টা"))
+        );
+    }
+}
@@ -0,0 +1,1067 @@
+use crate::lang::slavic::{
+    plural_category_with_units, Declension, Gender, GrammaticalNumber, Slavic,
+};
+use crate::{num2words::Num2Err, Agreement, Currency, Language, PluralCategory, Unit};
+use num_bigfloat::BigFloat;
+
+// Source: Internetová jazyková příručka (ÚJČ AV ČR)
+// Skloňování číslovek základních, řadových a zlomků
+
+#[derive(Default, Clone, Copy, PartialEq, Debug)]
+pub struct Czech {
+    gender: Gender,
+    number: GrammaticalNumber,
+    declension: Declension,
+    zero_major: bool,
+}
+
+const MINUS: &str = "minus";
+
+const INFINITY: [&str; 7] = [
+    "nekonečno",
+    "nekonečna",
+    "nekonečnu",
+    "nekonečno",
+    "nekonečnem",
+    "nekonečnu",
+    "nekonečno",
+];
+
+const ZERO: [&str; 7] = ["nula", "nuly", "nule", "nulu", "nulou", "nule", "nulo"];
+
+const ORDINAL_ZERO_BASE: &str = "nult";
+
+#[rustfmt::skip]
+const GENDERED: [[[&str; 7]; 3]; 2] = [[
+    [ "jeden", "jednoho", "jednomu", "jeden", "jedním", "jednom", "jeden" ],
+    [ "jedna", "jedné",   "jedné",   "jednu", "jednou", "jedné",  "jedna" ],
+    [ "jedno", "jednoho", "jednomu", "jedno", "jedním", "jednom", "jedno" ],
+],
+[
+    [ "dva", "dvou", "dvěma", "dva", "dvěma", "dvou", "dva" ],
+    [ "dvě", "dvou", "dvěma", "dvě", "dvěma", "dvou", "dvě" ],
+    [ "dvě", "dvou", "dvěma", "dvě", "dvěma", "dvou", "dvě" ],
+]];
+
+#[rustfmt::skip]
+const UNITS: [[&str; 7]; 7] = [
+    [ "tři",   "tří",    "třem",    "tři",   "třemi",  "třech",   "tři"   ],
+    [ "čtyři", "čtyř",   "čtyřem",  "čtyři", "čtyřmi", "čtyřech", "čtyři" ],
+    [ "pět",   "pěti",   "pěti",    "pět",   "pěti",   "pěti",    "pět"   ],
+    [ "šest",  "šesti",  "šesti",   "šest",  "šesti",  "šesti",   "šest"  ],
+    [ "sedm",  "sedmi",  "sedmi",   "sedm",  "sedmi",  "sedmi",   "sedm"  ],
+    [ "osm",   "osmi",   "osmi",    "osm",   "osmi",   "osmi",    "osm"   ],
+    [ "devět", "devíti", "devíti",  "devět", "devíti", "devíti",  "devět" ],
+];
+
+#[rustfmt::skip]
+const TEENS: [[&str; 7]; 10] = [
+    [ "deset",      "deseti",      "deseti",      "deset",      "deseti",      "deseti",      "deset"      ],
+    [ "jedenáct",   "jedenácti",   "jedenácti",   "jedenáct",   "jedenácti",   "jedenácti",   "jedenáct"   ],
+    [ "dvanáct",    "dvanácti",    "dvanácti",    "dvanáct",    "dvanácti",    "dvanácti",    "dvanáct"    ],
+    [ "třináct",    "třinácti",    "třinácti",    "třináct",    "třinácti",    "třinácti",    "třináct"    ],
+    [ "čtrnáct",    "čtrnácti",    "čtrnácti",    "čtrnáct",    "čtrnácti",    "čtrnácti",    "čtrnáct"    ],
+    [ "patnáct",    "patnácti",    "patnácti",    "patnáct",    "patnácti",    "patnácti",    "patnáct"    ],
+    [ "šestnáct",   "šestnácti",   "šestnácti",   "šestnáct",   "šestnácti",   "šestnácti",   "šestnáct"   ],
+    [ "sedmnáct",   "sedmnácti",   "sedmnácti",   "sedmnáct",   "sedmnácti",   "sedmnácti",   "sedmnáct"   ],
+    [ "osmnáct",    "osmnácti",    "osmnácti",    "osmnáct",    "osmnácti",    "osmnácti",    "osmnáct"    ],
+    [ "devatenáct", "devatenácti", "devatenácti", "devatenáct", "devatenácti", "devatenácti", "devatenáct" ],
+];
+
+#[rustfmt::skip]
+const TENS: [[&str; 7]; 8] = [
+    [ "dvacet",    "dvaceti",    "dvaceti",    "dvacet",    "dvaceti",    "dvaceti",    "dvacet"    ],
+    [ "třicet",    "třiceti",    "třiceti",    "třicet",    "třiceti",    "třiceti",    "třicet"    ],
+    [ "čtyřicet",  "čtyřiceti",  "čtyřiceti",  "čtyřicet",  "čtyřiceti",  "čtyřiceti",  "čtyřicet"  ],
+    [ "padesát",   "padesáti",   "padesáti",   "padesát",   "padesáti",   "padesáti",   "padesát"   ],
+    [ "šedesát",   "šedesáti",   "šedesáti",   "šedesát",   "šedesáti",   "šedesáti",   "šedesát"   ],
+    [ "sedmdesát", "sedmdesáti", "sedmdesáti", "sedmdesát", "sedmdesáti", "sedmdesáti", "sedmdesát" ],
+    [ "osmdesát",  "osmdesáti",  "osmdesáti",  "osmdesát",  "osmdesáti",  "osmdesáti",  "osmdesát"  ],
+    [ "devadesát", "devadesáti", "devadesáti", "devadesát", "devadesáti", "devadesáti", "devadesát" ],
+];
+
+#[rustfmt::skip]
+const HUNDREDS: [[&str; 7]; 9] = [
+    [ "sto",       "sta",        "stu",          "sto",       "stem",         "stu",          "sto"       ],
+    [ "dvě stě",   "dvou set",   "dvěma stům",   "dvě stě",   "dvěma sty",    "dvou stech",   "dvě stě"   ],
+    [ "tři sta",   "tří set",    "třem stům",    "tři sta",   "třemi sty",    "třech stech",  "tři sta"   ],
+    [ "čtyři sta", "čtyř set",   "čtyřem stům",  "čtyři sta", "čtyřmi sty",   "čtyřech stech", "čtyři sta" ],
+    [ "pět set",   "pěti set",   "pěti stům",    "pět set",   "pěti sty",     "pěti stech",   "pět set"   ],
+    [ "šest set",  "šesti set",  "šesti stům",   "šest set",  "šesti sty",    "šesti stech",  "šest set"  ],
+    [ "sedm set",  "sedmi set",  "sedmi stům",   "sedm set",  "sedmi sty",    "sedmi stech",  "sedm set"  ],
+    [ "osm set",   "osmi set",   "osmi stům",    "osm set",   "osmi sty",     "osmi stech",   "osm set"   ],
+    [ "devět set", "devíti set", "devíti stům",  "devět set", "devíti sty",   "devíti stech", "devět set" ],
+];
+
+#[rustfmt::skip]
+const THOUSANDS: [[&str; 7]; 2] = [
+    [ "tisíc",  "tisíce", "tisíci",  "tisíc",  "tisícem", "tisíci",   "tisíci" ],
+    [ "tisíce", "tisíc",  "tisícům", "tisíce", "tisíci",  "tisících", "tisíce" ],
+];
+
+// milion is masculine, miliarda feminine
+const MEGA_BASES: [&str; 12] = [
+    "milion",
+    "miliard",
+    "bilion",
+    "biliard",
+    "trilion",
+    "triliard",
+    "kvadrilion",
+    "kvadriliard",
+    "kvintilion",
+    "kvintiliard",
+    "sextilion",
+    "sextiliard",
+];
+
+// the ordinals of the powers of a thousand are built as tisící, miliontý
+const ORDINAL_THOUSAND_BASE: &str = "tisíc";
+const ORDINAL_MEGA_SUFFIX: &str = "t";
+
+const ORDINAL_UNIT_BASES: [&str; 9] = [
+    "prvn", "druh", "třet", "čtvrt", "pát", "šest", "sedm", "osm", "devát",
+];
+
+const ORDINAL_TEENS_BASES: [&str; 10] = [
+    "desát",
+    "jedenáct",
+    "dvanáct",
+    "třináct",
+    "čtrnáct",
+    "patnáct",
+    "šestnáct",
+    "sedmnáct",
+    "osmnáct",
+    "devatenáct",
+];
+
+const ORDINAL_TENS_BASES: [&str; 8] = [
+    "dvacát",
+    "třicát",
+    "čtyřicát",
+    "padesát",
+    "šedesát",
+    "sedmdesát",
+    "osmdesát",
+    "devadesát",
+];
+
+const ORDINAL_HUNDREDS_BASES: [&str; 9] = [
+    "st",
+    "dvoust",
+    "třist",
+    "čtyřst",
+    "pětist",
+    "šestist",
+    "sedmist",
+    "osmist",
+    "devítist",
+];
+
+// prefixes compounding a count with a power of a thousand: dvoutisící
+const COMBINING_UNITS: [&str; 10] = [
+    "", "jedno", "dvou", "tří", "čtyř", "pěti", "šesti", "sedmi", "osmi", "devíti",
+];
+
+const COMBINING_TEENS: [&str; 10] = [
+    "deseti",
+    "jedenácti",
+    "dvanácti",
+    "třinácti",
+    "čtrnácti",
+    "patnácti",
+    "šestnácti",
+    "sedmnácti",
+    "osmnácti",
+    "devatenácti",
+];
+
+const COMBINING_TENS: [&str; 8] = [
+    "dvaceti",
+    "třiceti",
+    "čtyřiceti",
+    "padesáti",
+    "šedesáti",
+    "sedmdesáti",
+    "osmdesáti",
+    "devadesáti",
+];
+
+const COMBINING_HUNDREDS: [&str; 9] = [
+    "sto",
+    "dvousto",
+    "třísta",
+    "čtyřsta",
+    "pětiset",
+    "šestiset",
+    "sedmiset",
+    "osmiset",
+    "devítiset",
+];
+
+// the masculine forms are the inanimate ones
+#[rustfmt::skip]
+const ADJECTIVE_HARD_FLEXIONS_SINGULAR: [[&str; 7]; 3] = [
+    [ "ý", "ého", "ému", "ý",  "ým", "ém", "ý" ],
+    [ "á", "é",   "é",   "ou", "ou", "é",  "á" ],
+    [ "é", "ého", "ému", "é",  "ým", "ém", "é" ],
+];
+
+const ADJECTIVE_HARD_FLEXIONS_PLURAL: [&str; 7] = ["é", "ých", "ým", "é", "ými", "ých", "é"];
+
+#[rustfmt::skip]
+const ADJECTIVE_SOFT_FLEXIONS_SINGULAR: [[&str; 7]; 3] = [
+    [ "í", "ího", "ímu", "í", "ím", "ím", "í" ],
+    [ "í", "í",   "í",   "í", "í",  "í",  "í" ],
+    [ "í", "ího", "ímu", "í", "ím", "ím", "í" ],
+];
+
+const ADJECTIVE_SOFT_FLEXIONS_PLURAL: [&str; 7] = ["í", "ích", "ím", "í", "ími", "ích", "í"];
+
+#[rustfmt::skip]
+const NOUN_MASCULINE_HARD_DECLENSIONS: [[&str; 7]; 2] = [ //metr
+    [ "",  "u", "u",  "",  "em", "u",   "e" ],
+    [ "y", "ů", "ům", "y", "y",  "ech", "y" ],
+];
+
+#[rustfmt::skip]
+const NOUN_MASCULINE_SOFT_DECLENSIONS: [[&str; 7]; 2] = [ //haléř
+    [ "",  "e", "i",  "",  "em", "i",   "i" ],
+    [ "e", "ů", "ům", "e", "i",  "ích", "e" ],
+];
+
+#[rustfmt::skip]
+const NOUN_FEMININE_HARD_DECLENSIONS: [[&str; 7]; 2] = [ //koruna
+    [ "a", "y", "ě",  "u", "ou",  "ě",   "o" ],
+    [ "y", "",  "ám", "y", "ami", "ách", "y" ],
+];
+
+#[rustfmt::skip]
+const NOUN_NEUTER_HARD_DECLENSIONS: [[&str; 7]; 2] = [ //euro
+    [ "o", "a", "u",  "o", "em", "u",   "o" ],
+    [ "a", "",  "ům", "a", "y",  "ech", "a" ],
+];
+
+// denominators of the decimal fractions: desetina, setina, tisícina
+const FRACTION_BASES: [&str; 6] = [
+    "desetin",
+    "setin",
+    "tisícin",
+    "desetitisícin",
+    "stotisícin",
+    "miliontin",
+];
+
+impl Slavic for Czech {
+    // a trailing one keeps the noun in the singular: dvacet jedna koruna
+    const TRAILING_ONE_IS_SINGULAR: bool = true;
+    const GENITIVE_GOVERNING: &'static [Declension] = &[
+        Declension::Nominative,
+        Declension::Accusative,
+        Declension::Vocative,
+    ];
+
+    const MINUS: &'static str = MINUS;
+    const ZERO: &'static [&'static str] = &ZERO;
+    const INFINITY: &'static [&'static str] = &INFINITY;
+    const ADJECTIVE_FLEXIONS_SINGULAR: [&'static [&'static str]; 3] = [
+        &ADJECTIVE_HARD_FLEXIONS_SINGULAR[0],
+        &ADJECTIVE_HARD_FLEXIONS_SINGULAR[1],
+        &ADJECTIVE_HARD_FLEXIONS_SINGULAR[2],
+    ];
+    const ADJECTIVE_FLEXIONS_PLURAL: &'static [&'static str] = &ADJECTIVE_HARD_FLEXIONS_PLURAL;
+
+    fn gender(&self) -> Gender {
+        self.gender
+    }
+    fn number(&self) -> GrammaticalNumber {
+        self.number
+    }
+    fn declension(&self) -> Declension {
+        self.declension
+    }
+    fn with(&self, gender: Gender, number: GrammaticalNumber, declension: Declension) -> Self {
+        Self {
+            gender,
+            number,
+            declension,
+            ..*self
+        }
+    }
+}
+
+impl Czech {
+    pub fn new(gender: Gender, number: GrammaticalNumber, declension: Declension) -> Self {
+        Self {
+            gender,
+            number,
+            declension,
+            zero_major: false,
+        }
+    }
+
+    /// Keeps the major unit clause for amounts below one, e.g. `nula korun
+    /// dvacet haléřů`
+    pub fn zero_major(self, zero_major: bool) -> Self {
+        Self { zero_major, ..self }
+    }
+
+    fn currencies(&self, currency: Currency) -> String {
+        let number_idx = self.number.index();
+        let declension_idx = self.declension.index();
+        match currency {
+            Currency::AUD
+            | Currency::CAD
+            | Currency::DOLLAR
+            | Currency::HKD
+            | Currency::NZD
+            | Currency::SGD
+            | Currency::TWD
+            | Currency::USD => format!(
+                "dolar{}",
+                NOUN_MASCULINE_HARD_DECLENSIONS[number_idx][declension_idx]
+            ),
+            Currency::CZK => format!(
+                "korun{}",
+                NOUN_FEMININE_HARD_DECLENSIONS[number_idx][declension_idx]
+            ),
+            Currency::EUR => format!(
+                "eur{}",
+                NOUN_NEUTER_HARD_DECLENSIONS[number_idx][declension_idx]
+            ),
+            _ => currency.default_string(self.is_plural()),
+        }
+    }
+
+    fn currency_properties(&self, currency: Currency) -> Czech {
+        match currency {
+            Currency::CZK => self.feminine(),
+            Currency::EUR => self.neuter(),
+            _ => self.masculine(),
+        }
+    }
+
+    fn currency_fraction(&self, currency: Currency) -> String {
+        let number_idx = self.number.index();
+        let declension_idx = self.declension.index();
+        match currency {
+            Currency::AUD
+            | Currency::CAD
+            | Currency::DOLLAR
+            | Currency::EUR
+            | Currency::HKD
+            | Currency::NZD
+            | Currency::SGD
+            | Currency::TWD
+            | Currency::USD => format!(
+                "cent{}",
+                NOUN_MASCULINE_HARD_DECLENSIONS[number_idx][declension_idx]
+            ),
+            Currency::CZK => format!(
+                "haléř{}",
+                NOUN_MASCULINE_SOFT_DECLENSIONS[number_idx][declension_idx]
+            ),
+            _ => currency.default_subunit_string("cent{}", self.is_plural()),
+        }
+    }
+
+    fn currency_fraction_words(
+        &self,
+        fraction: BigFloat,
+        currency: Currency,
+    ) -> Result<String, Num2Err> {
+        let fraction = (fraction * BigFloat::from(100)).int();
+        let currency_lang = self.masculine();
+        let target_lang = currency_lang.agreement_with_num(fraction);
+        Ok(format!(
+            "{} {}",
+            currency_lang.int_to_cardinal(fraction)?,
+            target_lang.currency_fraction(currency)
+        ))
+    }
+
+    fn units(&self, unit: Unit) -> String {
+        let number_idx = self.number.index();
+        let declension_idx = self.declension.index();
+        let (base, flexions) = match unit {
+            Unit::Meter => ("metr", &NOUN_MASCULINE_HARD_DECLENSIONS),
+            Unit::Kilometer => ("kilometr", &NOUN_MASCULINE_HARD_DECLENSIONS),
+            Unit::Gram => ("gram", &NOUN_MASCULINE_HARD_DECLENSIONS),
+            Unit::Kilogram => ("kilogram", &NOUN_MASCULINE_HARD_DECLENSIONS),
+            Unit::Liter => ("litr", &NOUN_MASCULINE_HARD_DECLENSIONS),
+            Unit::Second => ("sekund", &NOUN_FEMININE_HARD_DECLENSIONS),
+            Unit::Minute => ("minut", &NOUN_FEMININE_HARD_DECLENSIONS),
+            Unit::Hour => ("hodin", &NOUN_FEMININE_HARD_DECLENSIONS),
+        };
+        format!("{}{}", base, flexions[number_idx][declension_idx])
+    }
+
+    fn unit_properties(&self, unit: Unit) -> Czech {
+        match unit {
+            Unit::Second | Unit::Minute | Unit::Hour => self.feminine(),
+            _ => self.masculine(),
+        }
+    }
+
+    fn split_thousands(&self, mut num: BigFloat) -> Vec<u64> {
+        let mut thousands = Vec::new();
+        let bf_1000 = BigFloat::from(1000);
+
+        while !num.is_zero() {
+            thousands.push((num % bf_1000).to_u64().unwrap());
+            num /= bf_1000;
+        }
+
+        thousands
+    }
+
+    // thousands and millions are masculine, milliards are feminine
+    fn mega_gender(&self, order: usize) -> Gender {
+        if order >= 2 && !order.is_multiple_of(2) {
+            Gender::Feminine
+        } else {
+            Gender::Masculine
+        }
+    }
+
+    fn mega_word(&self, order: usize) -> Result<String, Num2Err> {
+        let number_idx = self.number.index();
+        let declension_idx = self.declension.index();
+        if order == 1 {
+            return Ok(String::from(THOUSANDS[number_idx][declension_idx]));
+        }
+
+        let base = MEGA_BASES.get(order - 2).ok_or(Num2Err::CannotConvert)?;
+        let flexions = match self.mega_gender(order) {
+            Gender::Feminine => &NOUN_FEMININE_HARD_DECLENSIONS,
+            _ => &NOUN_MASCULINE_HARD_DECLENSIONS,
+        };
+        Ok(format!("{}{}", base, flexions[number_idx][declension_idx]))
+    }
+
+    fn int_to_cardinal(&self, mut num: BigFloat) -> Result<String, Num2Err> {
+        let declension_idx = self.declension.index();
+
+        // special case zero
+        if num.is_zero() {
+            return Ok(String::from(self.zero()));
+        }
+
+        // handling negative values
+        let mut words = vec![];
+        if num.is_negative() {
+            words.push(String::from(MINUS));
+            num = -num;
+        }
+
+        // iterate over thousands
+        for (order, triplet) in self.split_thousands(num).iter().enumerate().rev() {
+            if *triplet == 0 {
+                continue;
+            }
+
+            let gender = if order == 0 {
+                self.gender
+            } else {
+                self.mega_gender(order)
+            };
+            let mega_lang = self.with(gender, GrammaticalNumber::Singular, self.declension);
+
+            // a single thousand or million is not counted: tisíc, milion
+            if order != 0 && *triplet == 1 {
+                words.push(mega_lang.mega_word(order)?);
+                continue;
+            }
+
+            let hundreds = (triplet / 100 % 10) as usize;
+            let tens = (triplet / 10 % 10) as usize;
+            let units = (triplet % 10) as usize;
+
+            if hundreds > 0 {
+                words.push(String::from(HUNDREDS[hundreds - 1][declension_idx]));
+            }
+
+            if tens == 1 {
+                words.push(String::from(TEENS[units][declension_idx]));
+            } else {
+                if tens > 1 {
+                    words.push(String::from(TENS[tens - 2][declension_idx]));
+                }
+                // the last group agrees with the target word, the others
+                // with the power of a thousand
+                match units {
+                    1 | 2 => words.push(String::from(
+                        GENDERED[units - 1][gender.index()][declension_idx],
+                    )),
+                    3..=9 => words.push(String::from(UNITS[units - 3][declension_idx])),
+                    _ => (),
+                }
+            }
+
+            if order != 0 {
+                words.push(
+                    mega_lang
+                        .agreement_with_units(tens, units)
+                        .mega_word(order)?,
+                );
+            }
+        }
+
+        Ok(words.join(" "))
+    }
+
+    fn float_to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let whole = num.int();
+        let mut numerator = num.frac().abs();
+        if numerator.is_zero() {
+            return self.int_to_cardinal(whole);
+        }
+        let mut decimals = 0;
+        while !numerator.frac().is_zero() {
+            numerator *= BigFloat::from(10);
+            decimals += 1;
+        }
+        let denominator = FRACTION_BASES
+            .get(decimals - 1)
+            .ok_or(Num2Err::CannotConvert)?;
+
+        // the numerals keep the requested case, only the nouns agree with
+        // them: dvě celé pět desetin
+        let numeral_lang = self.feminine();
+        let whole_lang = numeral_lang.agreement_with_num(whole);
+        let denominator_lang = numeral_lang.agreement_with_num(numerator);
+        Ok(format!(
+            "{}{} cel{} {} {}{}",
+            if whole.is_zero() && num.is_negative() {
+                "minus "
+            } else {
+                ""
+            },
+            numeral_lang.int_to_cardinal(whole)?,
+            whole_lang.adjective_flexion(),
+            numeral_lang.int_to_cardinal(numerator)?,
+            denominator,
+            NOUN_FEMININE_HARD_DECLENSIONS[denominator_lang.number.index()]
+                [denominator_lang.declension.index()],
+        ))
+    }
+
+    // první, třetí and tisící decline like soft adjectives
+    fn ordinal_flexion(&self, soft: bool) -> &'static str {
+        let declension_idx = self.declension.index();
+        let (singular, plural) = if soft {
+            (
+                &ADJECTIVE_SOFT_FLEXIONS_SINGULAR,
+                &ADJECTIVE_SOFT_FLEXIONS_PLURAL,
+            )
+        } else {
+            (
+                &ADJECTIVE_HARD_FLEXIONS_SINGULAR,
+                &ADJECTIVE_HARD_FLEXIONS_PLURAL,
+            )
+        };
+        if self.is_plural() {
+            plural[declension_idx]
+        } else {
+            singular[self.gender.index()][declension_idx]
+        }
+    }
+
+    fn combining_prefix(&self, triplet: u64) -> String {
+        let hundreds = (triplet / 100 % 10) as usize;
+        let tens = (triplet / 10 % 10) as usize;
+        let units = (triplet % 10) as usize;
+
+        let mut prefix = String::new();
+        if hundreds > 0 {
+            prefix.push_str(COMBINING_HUNDREDS[hundreds - 1]);
+        }
+        if tens == 1 {
+            prefix.push_str(COMBINING_TEENS[units]);
+        } else {
+            if tens > 1 {
+                prefix.push_str(COMBINING_TENS[tens - 2]);
+            }
+            // a single thousand takes no prefix: tisící
+            if triplet != 1 {
+                prefix.push_str(COMBINING_UNITS[units]);
+            }
+        }
+        prefix
+    }
+}
+
+impl Language for Czech {
+    fn to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num.is_inf_pos() {
+            Ok(self.infinity(false))
+        } else if num.is_inf_neg() {
+            Ok(self.infinity(true))
+        } else if num.frac().is_zero() {
+            self.int_to_cardinal(num)
+        } else {
+            self.float_to_cardinal(num)
+        }
+    }
+
+    fn to_ordinal(&self, mut num: BigFloat) -> Result<String, Num2Err> {
+        // special case zero
+        if num.is_zero() {
+            return Ok(format!(
+                "{ORDINAL_ZERO_BASE}{}",
+                self.ordinal_flexion(false)
+            ));
+        }
+
+        // handling negative values
+        let mut words = vec![];
+        if num.is_negative() {
+            words.push(String::from(MINUS));
+            num = -num;
+        }
+
+        let triplets = self.split_thousands(num);
+        let last_non_empty = triplets.iter().position(|&t| t != 0).unwrap();
+
+        // only the last group is ordinal, the ones before are cardinal:
+        // dva tisíce čtyřicátý druhý
+        let mut power = BigFloat::from(1);
+        for _ in 0..=last_non_empty {
+            power *= BigFloat::from(1000);
+        }
+        let leading = (num / power).int() * power;
+        if !leading.is_zero() {
+            words.push(Czech::default().int_to_cardinal(leading)?);
+        }
+
+        let triplet = triplets[last_non_empty];
+        if last_non_empty > 0 {
+            // dvoutisící, miliontý
+            let (base, soft) = if last_non_empty == 1 {
+                (String::from(ORDINAL_THOUSAND_BASE), true)
+            } else {
+                let base = MEGA_BASES
+                    .get(last_non_empty - 2)
+                    .ok_or(Num2Err::CannotConvert)?;
+                (format!("{}{}", base, ORDINAL_MEGA_SUFFIX), false)
+            };
+            words.push(format!(
+                "{}{}{}",
+                self.combining_prefix(triplet),
+                base,
+                self.ordinal_flexion(soft)
+            ));
+            return Ok(words.join(" "));
+        }
+
+        let hundreds = (triplet / 100 % 10) as usize;
+        let tens = (triplet / 10 % 10) as usize;
+        let units = (triplet % 10) as usize;
+
+        if tens == 0 && units == 0 {
+            words.push(format!(
+                "{}{}",
+                ORDINAL_HUNDREDS_BASES[hundreds - 1],
+                self.ordinal_flexion(false)
+            ));
+            return Ok(words.join(" "));
+        }
+
+        if hundreds > 0 {
+            words.push(String::from(
+                HUNDREDS[hundreds - 1][Declension::Nominative.index()],
+            ));
+        }
+        if tens == 1 {
+            words.push(format!(
+                "{}{}",
+                ORDINAL_TEENS_BASES[units],
+                self.ordinal_flexion(false)
+            ));
+        } else {
+            if tens > 1 {
+                words.push(format!(
+                    "{}{}",
+                    ORDINAL_TENS_BASES[tens - 2],
+                    self.ordinal_flexion(false)
+                ));
+            }
+            if units > 0 {
+                words.push(format!(
+                    "{}{}",
+                    ORDINAL_UNIT_BASES[units - 1],
+                    self.ordinal_flexion(units == 1 || units == 3)
+                ));
+            }
+        }
+
+        Ok(words.join(" "))
+    }
+
+    fn to_ordinal_num(&self, num: BigFloat) -> Result<String, Num2Err> {
+        Ok(format!("{}.", num.to_u128().unwrap()))
+    }
+
+    fn to_year(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if !num.frac().is_zero() {
+            return Err(Num2Err::FloatingYear);
+        }
+        if num.is_inf() {
+            return Err(Num2Err::InfiniteYear);
+        }
+
+        // years are read as cardinals after roku: roku dva tisíce dvacet tři
+        let year_lang = Czech::default();
+        if num.is_negative() {
+            Ok(format!(
+                "roku {} před naším letopočtem",
+                year_lang.int_to_cardinal(-num)?
+            ))
+        } else {
+            Ok(format!("roku {}", year_lang.int_to_cardinal(num)?))
+        }
+    }
+
+    fn to_currency(&self, num: BigFloat, currency: Currency) -> Result<String, Num2Err> {
+        if num.is_inf() {
+            let currency_lang = self.currency_properties(currency);
+            let target_lang = currency_lang.agreement_with_num(num);
+            Ok(format!(
+                "{} {}",
+                currency_lang.to_cardinal(num)?,
+                target_lang.currencies(currency)
+            ))
+        } else {
+            let whole = num.int();
+            let fraction = num.frac();
+            if fraction.is_zero() {
+                let currency_lang = self.currency_properties(currency);
+                let target_lang = currency_lang.agreement_with_num(whole);
+                Ok(format!(
+                    "{} {}",
+                    currency_lang.int_to_cardinal(whole)?,
+                    target_lang.currencies(currency)
+                ))
+            } else if whole.is_zero() && !self.zero_major {
+                self.currency_fraction_words(fraction, currency)
+            } else {
+                Ok(format!(
+                    "{} {}",
+                    self.to_currency(whole, currency)?,
+                    self.currency_fraction_words(fraction, currency)?,
+                ))
+            }
+        }
+    }
+
+    fn to_percent(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let percent_lang = self.neuter();
+        let target_lang = percent_lang.agreement_with_num(num);
+        Ok(format!(
+            "{} procent{}",
+            percent_lang.to_cardinal(num)?,
+            NOUN_NEUTER_HARD_DECLENSIONS[target_lang.number.index()]
+                [target_lang.declension.index()]
+        ))
+    }
+
+    fn to_unit(&self, num: BigFloat, unit: Unit) -> Result<String, Num2Err> {
+        let unit_lang = self.unit_properties(unit);
+        let target_lang = unit_lang.agreement_with_num(num);
+        Ok(format!(
+            "{} {}",
+            unit_lang.to_cardinal(num)?,
+            target_lang.units(unit)
+        ))
+    }
+
+    fn plural_category(&self, num: BigFloat) -> PluralCategory {
+        if num.is_inf() {
+            // same agreement as zero: nekonečno korun
+            return PluralCategory::Many;
+        }
+        if !num.frac().is_zero() {
+            return PluralCategory::Other;
+        }
+        let tail = (num.abs() % BigFloat::from(100))
+            .to_u64()
+            .unwrap_or_default();
+        plural_category_with_units::<Self>((tail / 10) as usize, (tail % 10) as usize)
+    }
+
+    fn agreement(&self, num: BigFloat) -> Agreement {
+        self.counted_agreement(num)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Lang, Num2Words};
+
+    #[test]
+    fn test_cardinal() {
+        assert_eq!(
+            Num2Words::new(0).lang(Lang::Czech).to_words(),
+            Ok(String::from("nula"))
+        );
+        assert_eq!(
+            Num2Words::new(1).lang(Lang::Czech).prefer("f").to_words(),
+            Ok(String::from("jedna"))
+        );
+        assert_eq!(
+            Num2Words::new(2)
+                .lang(Lang::Czech)
+                .prefer("střední")
+                .to_words(),
+            Ok(String::from("dvě"))
+        );
+        assert_eq!(
+            Num2Words::new(42).lang(Lang::Czech).to_words(),
+            Ok(String::from("čtyřicet dva"))
+        );
+        assert_eq!(
+            Num2Words::new(200).lang(Lang::Czech).to_words(),
+            Ok(String::from("dvě stě"))
+        );
+        assert_eq!(
+            Num2Words::new(1000).lang(Lang::Czech).to_words(),
+            Ok(String::from("tisíc"))
+        );
+        assert_eq!(
+            Num2Words::new(2000).lang(Lang::Czech).to_words(),
+            Ok(String::from("dva tisíce"))
+        );
+        assert_eq!(
+            Num2Words::new(5000).lang(Lang::Czech).to_words(),
+            Ok(String::from("pět tisíc"))
+        );
+        assert_eq!(
+            Num2Words::new(21_000).lang(Lang::Czech).to_words(),
+            Ok(String::from("dvacet jeden tisíc"))
+        );
+        assert_eq!(
+            Num2Words::new(2_000_000_000).lang(Lang::Czech).to_words(),
+            Ok(String::from("dvě miliardy"))
+        );
+        assert_eq!(
+            Num2Words::new(918_654_321).lang(Lang::Czech).to_words(),
+            Ok(String::from(
+                "devět set osmnáct milionů šest set padesát čtyři tisíce tři sta dvacet jeden"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_declension() {
+        assert_eq!(
+            Num2Words::new(1).lang(Lang::Czech).prefer("gen").to_words(),
+            Ok(String::from("jednoho"))
+        );
+        assert_eq!(
+            Num2Words::new(1)
+                .lang(Lang::Czech)
+                .prefer("f")
+                .prefer("akuzativ")
+                .to_words(),
+            Ok(String::from("jednu"))
+        );
+        assert_eq!(
+            Num2Words::new(5432)
+                .lang(Lang::Czech)
+                .prefer("genitiv")
+                .to_words(),
+            Ok(String::from("pěti tisíc čtyř set třiceti dvou"))
+        );
+        assert_eq!(
+            Num2Words::new(2_000_000)
+                .lang(Lang::Czech)
+                .prefer("loc")
+                .to_words(),
+            Ok(String::from("dvou milionech"))
+        );
+    }
+
+    #[test]
+    fn test_agreement() {
+        assert_eq!(
+            plural_category_with_units::<Czech>(2, 1),
+            PluralCategory::One
+        );
+        assert_eq!(
+            Czech::default().plural_category(BigFloat::from(3)),
+            PluralCategory::Few
+        );
+        assert_eq!(
+            Czech::default().plural_category(BigFloat::from(12)),
+            PluralCategory::Many
+        );
+        assert_eq!(
+            Czech::default().agreement_with_category(PluralCategory::Many),
+            Czech {
+                number: GrammaticalNumber::Plural,
+                declension: Declension::Genitive,
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn test_ordinal() {
+        assert_eq!(
+            Num2Words::new(1).lang(Lang::Czech).ordinal().to_words(),
+            Ok(String::from("první"))
+        );
+        assert_eq!(
+            Num2Words::new(2)
+                .lang(Lang::Czech)
+                .prefer("f")
+                .ordinal()
+                .to_words(),
+            Ok(String::from("druhá"))
+        );
+        assert_eq!(
+            Num2Words::new(42).lang(Lang::Czech).ordinal().to_words(),
+            Ok(String::from("čtyřicátý druhý"))
+        );
+        assert_eq!(
+            Num2Words::new(43)
+                .lang(Lang::Czech)
+                .prefer("gen")
+                .ordinal()
+                .to_words(),
+            Ok(String::from("čtyřicátého třetího"))
+        );
+        assert_eq!(
+            Num2Words::new(100).lang(Lang::Czech).ordinal().to_words(),
+            Ok(String::from("stý"))
+        );
+        assert_eq!(
+            Num2Words::new(1000).lang(Lang::Czech).ordinal().to_words(),
+            Ok(String::from("tisící"))
+        );
+        assert_eq!(
+            Num2Words::new(2000).lang(Lang::Czech).ordinal().to_words(),
+            Ok(String::from("dvoutisící"))
+        );
+        assert_eq!(
+            Num2Words::new(1_000_000)
+                .lang(Lang::Czech)
+                .ordinal()
+                .to_words(),
+            Ok(String::from("miliontý"))
+        );
+        assert_eq!(
+            Num2Words::new(42)
+                .lang(Lang::Czech)
+                .ordinal_num()
+                .to_words(),
+            Ok(String::from("42."))
+        );
+    }
+
+    #[test]
+    fn test_year() {
+        assert_eq!(
+            Num2Words::new(2023).lang(Lang::Czech).year().to_words(),
+            Ok(String::from("roku dva tisíce dvacet tři"))
+        );
+        assert_eq!(
+            Num2Words::new(-44).lang(Lang::Czech).year().to_words(),
+            Ok(String::from("roku čtyřicet čtyři před naším letopočtem"))
+        );
+    }
+
+    #[test]
+    fn test_currency() {
+        assert_eq!(
+            Num2Words::new(1)
+                .lang(Lang::Czech)
+                .currency(Currency::CZK)
+                .to_words(),
+            Ok(String::from("jedna koruna"))
+        );
+        assert_eq!(
+            Num2Words::new(22)
+                .lang(Lang::Czech)
+                .currency(Currency::CZK)
+                .to_words(),
+            Ok(String::from("dvacet dvě koruny"))
+        );
+        assert_eq!(
+            Num2Words::new(25)
+                .lang(Lang::Czech)
+                .currency(Currency::CZK)
+                .to_words(),
+            Ok(String::from("dvacet pět korun"))
+        );
+        assert_eq!(
+            Num2Words::new(42.02)
+                .lang(Lang::Czech)
+                .currency(Currency::CZK)
+                .to_words(),
+            Ok(String::from("čtyřicet dvě koruny dva haléře"))
+        );
+        assert_eq!(
+            Num2Words::new(0.5)
+                .lang(Lang::Czech)
+                .currency(Currency::CZK)
+                .to_words(),
+            Ok(String::from("padesát haléřů"))
+        );
+        assert_eq!(
+            Num2Words::new(5)
+                .lang(Lang::Czech)
+                .prefer("ins")
+                .currency(Currency::CZK)
+                .to_words(),
+            Ok(String::from("pěti korunami"))
+        );
+        assert_eq!(
+            Num2Words::new(3)
+                .lang(Lang::Czech)
+                .currency(Currency::EUR)
+                .to_words(),
+            Ok(String::from("tři eura"))
+        );
+    }
+
+    #[test]
+    fn test_float() {
+        assert_eq!(
+            Num2Words::new(2.5).lang(Lang::Czech).to_words(),
+            Ok(String::from("dvě celé pět desetin"))
+        );
+        assert_eq!(
+            Num2Words::new(1.1).lang(Lang::Czech).to_words(),
+            Ok(String::from("jedna celá jedna desetina"))
+        );
+        assert_eq!(
+            Num2Words::new(-0.25).lang(Lang::Czech).to_words(),
+            Ok(String::from("minus nula celých dvacet pět setin"))
+        );
+    }
+
+    #[test]
+    fn test_percent_and_unit() {
+        assert_eq!(
+            Num2Words::new(2).lang(Lang::Czech).percent().to_words(),
+            Ok(String::from("dvě procenta"))
+        );
+        assert_eq!(
+            Num2Words::new(2)
+                .lang(Lang::Czech)
+                .unit(Unit::Hour)
+                .to_words(),
+            Ok(String::from("dvě hodiny"))
+        );
+        assert_eq!(
+            Num2Words::new(5)
+                .lang(Lang::Czech)
+                .unit(Unit::Meter)
+                .to_words(),
+            Ok(String::from("pět metrů"))
+        );
+    }
+}
@@ -1,9 +1,52 @@
-use crate::{num2words::Num2Err, Currency, Language};
+use crate::lang::group_digits;
+use crate::rounding::{quantize, Rounding};
+use crate::{num2words::Num2Err, Currency, Language, PluralCategory, Unit};
 use num_bigfloat::BigFloat;
 
 pub struct English {
-    prefer_oh: bool,
-    prefer_nil: bool,
+    zero: ZeroWord,
+    zero_major: bool,
+    american: bool,
+    british: bool,
+    no_and: bool,
+    long_scale: bool,
+    thousand_million: bool,
+    indian: bool,
+    informal: bool,
+    negative: bool,
+    bce: bool,
+    ad: bool,
+    formal_year: bool,
+    always_cents: bool,
+    no_cents: bool,
+    no_hyphen: bool,
+    hyphen_all: bool,
+    fraction: bool,
+    check: bool,
+    hundreds: bool,
+    oh_years: bool,
+    no_leading_zero: bool,
+    group_digits: bool,
+}
+
+/// Word read for zero
+#[derive(Default, Clone, Copy, PartialEq, Debug)]
+pub enum ZeroWord {
+    #[default]
+    Zero,
+    Oh,
+    Nil,
+    Nought,
+}
+
+/// Parses a zero word preference
+pub(crate) fn zero_word_from_str(s: &str) -> Option<ZeroWord> {
+    match s {
+        "oh" => Some(ZeroWord::Oh),
+        "nil" => Some(ZeroWord::Nil),
+        "nought" | "naught" => Some(ZeroWord::Nought),
+        _ => None,
+    }
 }
 
 const UNITS: [&str; 9] = [
@@ -54,56 +97,434 @@ const MEGAS: [&str; 21] = [
     "vigintillion",
 ];
 
+// The long scale, where a billion is a million millions
+const LONG_MEGAS: [&str; 21] = [
+    "thousand",
+    "million",
+    "milliard",
+    "billion",
+    "billiard",
+    "trillion",
+    "trilliard",
+    "quadrillion",
+    "quadrilliard",
+    "quintillion",
+    "quintilliard",
+    "sextillion",
+    "sextilliard",
+    "septillion",
+    "septilliard",
+    "octillion",
+    "octilliard",
+    "nonillion",
+    "nonilliard",
+    "decillion",
+    "decilliard",
+];
+
 impl English {
-    pub fn new(prefer_oh: bool, prefer_nil: bool) -> Self {
+    pub fn new(zero: ZeroWord) -> Self {
+        Self {
+            zero,
+            zero_major: false,
+            american: false,
+            british: false,
+            no_and: false,
+            long_scale: false,
+            thousand_million: false,
+            indian: false,
+            informal: false,
+            negative: false,
+            bce: false,
+            ad: false,
+            formal_year: false,
+            always_cents: false,
+            no_cents: false,
+            no_hyphen: false,
+            hyphen_all: false,
+            fraction: false,
+            check: false,
+            hundreds: false,
+            oh_years: false,
+            no_leading_zero: false,
+            group_digits: false,
+        }
+    }
+
+    /// Keeps the major unit clause for amounts below one, e.g. `zero dollars
+    /// and twenty cents`
+    pub fn zero_major(self, zero_major: bool) -> Self {
+        Self { zero_major, ..self }
+    }
+
+    /// Follows the American convention of not inserting "and" in spoken
+    /// expanded years, e.g. `twelve thousand twenty-four`
+    pub fn american(self, american: bool) -> Self {
+        Self { american, ..self }
+    }
+
+    /// Follows the British convention of inserting "and" after every hundred
+    /// and before a final triplet below one hundred, e.g. `one hundred and
+    /// twenty-three thousand four hundred and fifty-six`
+    pub fn british(self, british: bool) -> Self {
+        Self { british, ..self }
+    }
+
+    /// Never inserts "and" within a number, as in US check writing, e.g. `one
+    /// thousand nine hundred thirty-two`, the "and" joining currency units
+    /// being kept
+    pub fn no_and(self, no_and: bool) -> Self {
+        Self { no_and, ..self }
+    }
+
+    /// Follows the long scale, where a billion is a million millions, e.g.
+    /// `one milliard` for 10^9
+    pub fn long_scale(self, long_scale: bool) -> Self {
+        Self { long_scale, ..self }
+    }
+
+    /// Follows the long scale, reading the milliards as thousands of millions,
+    /// e.g. `one thousand million` for 10^9
+    pub fn thousand_million(self, thousand_million: bool) -> Self {
+        Self {
+            thousand_million,
+            ..self
+        }
+    }
+
+    /// Follows the Indian grouping in lakhs and crores, e.g. `twelve crore
+    /// thirty-four lakh fifty-six thousand seven hundred and eighty-nine`
+    ///
+    /// Round lakhs and crores have no ordinal and cannot be converted.
+    pub fn indian(self, indian: bool) -> Self {
+        Self { indian, ..self }
+    }
+
+    /// Reads a leading one hundred, thousand or higher power with "a", e.g. `a
+    /// thousand and two`, `a hundred dollars`
+    pub fn informal(self, informal: bool) -> Self {
+        Self { informal, ..self }
+    }
+
+    /// Reads negative numbers with "negative" rather than "minus", e.g.
+    /// `negative ten`
+    pub fn negative(self, negative: bool) -> Self {
+        Self { negative, ..self }
+    }
+
+    /// Marks the era of years as BCE and CE rather than BC and nothing, e.g.
+    /// `forty-four BCE`, `nineteen ninety CE`
+    pub fn bce(self, bce: bool) -> Self {
+        Self { bce, ..self }
+    }
+
+    /// Prefixes years of the first millennium with AD, e.g. `AD seventy-nine`
+    pub fn ad(self, ad: bool) -> Self {
+        Self { ad, ..self }
+    }
+
+    /// Reads years below 10000 as cardinals rather than in pairs of digits,
+    /// e.g. `two thousand and twenty-two`
+    pub fn formal_year(self, formal_year: bool) -> Self {
+        Self {
+            formal_year,
+            ..self
+        }
+    }
+
+    /// Keeps the cents clause of whole amounts, e.g. `forty-two dollars and
+    /// zero cents`
+    pub fn always_cents(self, always_cents: bool) -> Self {
+        Self {
+            always_cents,
+            ..self
+        }
+    }
+
+    /// Keeps the cents clause of whole amounts with "no", e.g. `forty-two
+    /// dollars and no cents`
+    pub fn no_cents(self, no_cents: bool) -> Self {
+        Self { no_cents, ..self }
+    }
+
+    /// Separates the tens and the units with a space rather than a hyphen,
+    /// e.g. `forty two`, `forty second`
+    pub fn no_hyphen(self, no_hyphen: bool) -> Self {
+        Self { no_hyphen, ..self }
+    }
+
+    /// Joins every word of a number with hyphens, e.g.
+    /// `one-hundred-twenty-three`, `nineteen-oh-five`
+    pub fn hyphen_all(self, hyphen_all: bool) -> Self {
+        Self { hyphen_all, ..self }
+    }
+
+    /// Reads decimals as common fractions rather than digit by digit, e.g.
+    /// `three and two tenths`, `seventy-five hundredths`
+    pub fn fraction(self, fraction: bool) -> Self {
+        Self { fraction, ..self }
+    }
+
+    /// Writes currency amounts as on US checks, with the cents as a fraction
+    /// of a hundred, e.g. `One thousand forty-two and 50/100 dollars`
+    pub fn check(self, check: bool) -> Self {
+        Self { check, ..self }
+    }
+
+    /// Reads the numbers from 1100 to 9999 in hundreds, unless their hundreds
+    /// digit is zero, e.g. `twenty-five hundred`, `nineteen hundred
+    /// eighty-four`
+    pub fn hundreds(self, hundreds: bool) -> Self {
+        Self { hundreds, ..self }
+    }
+
+    /// Reads the years X001 to X009 in pairs of digits, e.g. `twenty oh-one`,
+    /// `ten oh-nine`
+    pub fn oh_years(self, oh_years: bool) -> Self {
+        Self { oh_years, ..self }
+    }
+
+    /// Leaves out the zero before the decimal point, e.g. `point five`
+    pub fn no_leading_zero(self, no_leading_zero: bool) -> Self {
+        Self {
+            no_leading_zero,
+            ..self
+        }
+    }
+
+    /// Separates the thousands of the ordinal numbers with commas, e.g.
+    /// `1,000,000th`
+    pub fn group_digits(self, group_digits: bool) -> Self {
         Self {
-            prefer_oh,
-            prefer_nil,
+            group_digits,
+            ..self
+        }
+    }
+
+    fn whole_currency(&self, num: BigFloat, currency: Currency) -> Result<String, Num2Err> {
+        Ok(format!(
+            "{} {}",
+            self.int_to_cardinal(num)?,
+            self.currencies(currency, self.plural_category(num) != PluralCategory::One)
+        ))
+    }
+
+    // era around a year, as a prefix and a suffix
+    fn era(&self, num: BigFloat) -> (&'static str, &'static str) {
+        if num.is_negative() {
+            ("", if self.bce { " BCE" } else { " BC" })
+        } else if num.is_zero() {
+            ("", "")
+        } else if self.bce {
+            ("", " CE")
+        } else if self.ad && num < BigFloat::from(1000) {
+            ("AD ", "")
+        } else {
+            ("", "")
+        }
+    }
+
+    // digits of an ordinal number
+    fn digits(&self, num: BigFloat) -> String {
+        let num = num.to_u128().unwrap();
+        if self.group_digits {
+            group_digits(num, ",")
+        } else {
+            num.to_string()
+        }
+    }
+
+    // zero read as a digit of a decimal, e.g. nought point nought five
+    fn zero_digit(&self) -> &'static str {
+        match self.zero {
+            ZeroWord::Oh => "oh",
+            ZeroWord::Nought => "nought",
+            _ => "zero",
+        }
+    }
+
+    fn minus(&self) -> &'static str {
+        if self.negative {
+            "negative"
+        } else {
+            "minus"
+        }
+    }
+
+    // joiner of the tens and the units, e.g. forty-two
+    fn hyphen(&self) -> &'static str {
+        if self.no_hyphen {
+            " "
+        } else {
+            "-"
         }
     }
 
+    // joins the words of a single number
+    fn join<S: AsRef<str>>(&self, words: &[S]) -> String {
+        let words: Vec<&str> = words.iter().map(AsRef::as_ref).collect();
+        words.join(if self.hyphen_all { "-" } else { " " })
+    }
+
+    // the amount of a check: no "and" within the number, the first letter in
+    // uppercase and the cents always written as digits
+    fn check_currency(&self, num: BigFloat, currency: Currency) -> Result<String, Num2Err> {
+        let integral_part = num.abs().int();
+        let cents_nb = (num.abs() * BigFloat::from(100)).int() % BigFloat::from(100);
+        let mut words = self.cardinal_words(integral_part, false)?;
+        if num.is_negative() {
+            words = format!("{} {}", self.minus(), words);
+        }
+
+        let mut chars = words.chars();
+        let first = chars.next().map(|c| c.to_uppercase().to_string());
+        Ok(format!(
+            "{}{} and {:02}/100 {}",
+            first.unwrap_or_default(),
+            chars.as_str(),
+            cents_nb.to_u64().unwrap(),
+            self.currencies(currency, true)
+        ))
+    }
+
     fn currencies(&self, currency: Currency, plural_form: bool) -> String {
-        currency.default_string(plural_form)
+        match currency {
+            Currency::BHD => String::from("Bahraini dinar{}"),
+            Currency::CHF => String::from("Swiss franc{}"),
+            Currency::DKK => String::from(if plural_form {
+                "Danish kroner"
+            } else {
+                "Danish krone"
+            }),
+            Currency::ILS => String::from("Israeli new shekel{}"),
+            Currency::KWD => String::from("Kuwaiti dinar{}"),
+            Currency::NOK => String::from(if plural_form {
+                "Norwegian kroner"
+            } else {
+                "Norwegian krone"
+            }),
+            Currency::RUB => String::from("Russian ruble{}"),
+            Currency::SEK => String::from(if plural_form {
+                "Swedish kronor"
+            } else {
+                "Swedish krona"
+            }),
+            _ => currency.default_string(plural_form),
+        }
+        .replace("{}", if plural_form { "s" } else { "" })
     }
 
     fn cents(&self, currency: Currency, plural_form: bool) -> String {
-        currency.default_subunit_string("cent{}", plural_form)
+        match currency {
+            Currency::BHD | Currency::KWD => String::from("fils"),
+            Currency::CHF => String::from("rappen"),
+            Currency::DKK | Currency::NOK => String::from("øre"),
+            Currency::GBP => String::from(if plural_form { "pence" } else { "penny" }),
+            Currency::ILS => String::from(if plural_form { "agorot" } else { "agora" }),
+            Currency::RUB => String::from("kopeck{}"),
+            Currency::SEK => String::from("öre"),
+            _ => currency.default_subunit_string("cent{}", plural_form),
+        }
+        .replace("{}", if plural_form { "s" } else { "" })
     }
 
+    // Splits in groups of three digits, or in the Indian grouping of a
+    // triplet followed by pairs (thousands, lakhs) up to the crores, whose
+    // count is then split alike
     fn split_thousands(&self, mut num: BigFloat) -> Vec<u64> {
         let mut thousands = Vec::new();
-        let bf_1000 = BigFloat::from(1000);
 
         while !num.is_zero() {
-            thousands.push((num % bf_1000).to_u64().unwrap());
-            num /= bf_1000;
+            let size = match thousands.len() % 3 {
+                0 => BigFloat::from(1000),
+                _ if self.indian => BigFloat::from(100),
+                _ => BigFloat::from(1000),
+            };
+            thousands.push((num % size).to_u64().unwrap());
+            num = (num / size).int();
         }
 
         thousands
     }
 
-    fn int_to_cardinal(&self, mut num: BigFloat) -> Result<String, Num2Err> {
+    // Name of the i-th group in the Indian grouping, e.g. "lakh crore"
+    fn indian_mega(&self, i: usize) -> String {
+        let mut words = vec![];
+        match i % 3 {
+            1 => words.push("thousand"),
+            2 => words.push("lakh"),
+            _ => (),
+        }
+        words.extend(std::iter::repeat_n("crore", i / 3));
+        self.join(&words)
+    }
+
+    fn int_to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if self.hundreds && num.abs() < BigFloat::from(10000) {
+            let (high, low) = self.split_hundreds(num.abs());
+            if high > 10 && high % 10 != 0 {
+                return self.hundreds_words(num, high, low);
+            }
+        }
+        self.cardinal_words(num, !self.no_and)
+    }
+
+    // Splits in the hundreds and the last two digits, e.g. 1984 => (19, 84)
+    fn split_hundreds(&self, num: BigFloat) -> (i64, i64) {
+        let bf_100 = BigFloat::from(100);
+        (
+            (num / bf_100).to_i64().unwrap(),
+            (num % bf_100).to_i64().unwrap(),
+        )
+    }
+
+    // e.g. 1984 => nineteen hundred eighty-four
+    fn hundreds_words(&self, num: BigFloat, high: i64, low: i64) -> Result<String, Num2Err> {
+        let mut words = vec![];
+        if num.is_negative() {
+            words.push(String::from(self.minus()));
+        }
+        words.push(self.cardinal_words(BigFloat::from(high), false)?);
+        words.push(String::from("hundred"));
+        if low != 0 {
+            if self.british && !self.no_and {
+                words.push(String::from("and"));
+            }
+            words.push(self.cardinal_words(BigFloat::from(low), false)?);
+        }
+        Ok(self.join(&words))
+    }
+
+    // `with_and` inserts "and" before the last triplet: one thousand and one,
+    // or after every hundred in the British convention: one hundred and one
+    fn cardinal_words(&self, mut num: BigFloat, with_and: bool) -> Result<String, Num2Err> {
         // special case zero
         if num.is_zero() {
-            return Ok(String::from(if self.prefer_oh {
-                "oh"
-            } else if self.prefer_nil {
-                "nil"
-            } else {
-                "zero"
+            return Ok(String::from(match self.zero {
+                ZeroWord::Zero => "zero",
+                ZeroWord::Oh => "oh",
+                ZeroWord::Nil => "nil",
+                ZeroWord::Nought => "nought",
             }));
         }
 
         // handling negative values
         let mut words = vec![];
         if num.is_negative() {
-            words.push(String::from("minus"));
+            words.push(String::from(self.minus()));
             num = -num;
         }
 
         // iterate over thousands
         let mut first_elem = true;
-        for (i, triplet) in self.split_thousands(num).iter().enumerate().rev() {
+        let mut higher = false;
+        let megas = if self.long_scale || self.thousand_million {
+            LONG_MEGAS
+        } else {
+            MEGAS
+        };
+        let thousands = self.split_thousands(num);
+        for (i, triplet) in thousands.iter().enumerate().rev() {
             let hundreds = (triplet / 100 % 10) as usize;
             let tens = (triplet / 10 % 10) as usize;
             let units = (triplet % 10) as usize;
@@ -114,8 +535,14 @@ impl English {
             }
 
             if tens != 0 || units != 0 {
-                if i == 0 && !first_elem {
-                    words.push(String::from("and"));
+                if self.british {
+                    if with_and && (hundreds > 0 || (i == 0 && higher)) {
+                        words.push(String::from("and"));
+                    }
+                } else if i == 0 && !first_elem {
+                    if with_and {
+                        words.push(String::from("and"));
+                    }
                 } else {
                     first_elem = false;
                 }
@@ -134,33 +561,60 @@ impl English {
                         let ten: String = String::from(TENS[tens - 1]);
                         words.push(match units {
                             0 => ten,
-                            _ => format!("{}-{}", ten, UNITS[units - 1]),
+                            _ => format!("{}{}{}", ten, self.hyphen(), UNITS[units - 1]),
                         });
                     }
                 }
             }
 
             if i != 0 && triplet != &0 {
-                if i > MEGAS.len() {
+                if i > megas.len() {
                     return Err(Num2Err::CannotConvert);
                 }
-                words.push(String::from(MEGAS[i - 1]));
+                if self.indian {
+                    words.push(self.indian_mega(i));
+                } else if self.thousand_million && megas[i - 1].ends_with("illiard") {
+                    // one thousand [five hundred] million
+                    words.push(String::from("thousand"));
+                    if thousands[i - 1] == 0 {
+                        words.push(String::from(megas[i - 2]));
+                    }
+                } else {
+                    words.push(String::from(megas[i - 1]));
+                }
             }
+            higher |= triplet != &0;
         }
 
-        Ok(words.join(" "))
+        // only the very first "one" is replaced: twelve thousand one hundred
+        let start = if words[0] == self.minus() { 1 } else { 0 };
+        if self.informal && words[start] == "one" && words.len() > start + 1 {
+            words[start] = String::from("a");
+        }
+
+        Ok(self.join(&words))
     }
 
     fn float_to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if self.fraction {
+            return self.float_to_fraction(num);
+        }
+
         let integral_part = num.int();
         let mut words: Vec<String> = vec![];
 
         if !integral_part.is_zero() {
             let integral_word = self.int_to_cardinal(integral_part)?;
             words.push(integral_word);
+        } else if num.is_negative() {
+            // the sign would be lost with the integral part, e.g. -0.5
+            words.push(String::from(self.minus()));
+        }
+        if integral_part.is_zero() && !self.no_leading_zero {
+            words.push(String::from(self.zero_digit()));
         }
 
-        let mut ordinal_part = num.frac();
+        let mut ordinal_part = num.frac().abs();
         if !ordinal_part.is_zero() {
             words.push(String::from("point"));
         }
@@ -168,11 +622,46 @@ impl English {
             let digit = (ordinal_part * BigFloat::from(10)).int();
             ordinal_part = (ordinal_part * BigFloat::from(10)).frac();
             words.push(match digit.to_u64().unwrap() {
-                0 => String::from(if self.prefer_oh { "oh" } else { "zero" }),
+                0 => String::from(self.zero_digit()),
                 i => String::from(UNITS[i as usize - 1]),
             });
         }
-        Ok(words.join(" "))
+        Ok(self.join(&words))
+    }
+
+    // the fractional part read over a power of ten, e.g. three and two tenths
+    fn float_to_fraction(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let integral_part = num.int();
+        let mut numerator = num.frac().abs();
+        let mut denominator = BigFloat::from(1);
+        while !numerator.frac().is_zero() {
+            numerator *= BigFloat::from(10);
+            denominator *= BigFloat::from(10);
+        }
+
+        let mut words: Vec<String> = vec![];
+        if !integral_part.is_zero() {
+            words.push(self.int_to_cardinal(integral_part)?);
+            words.push(String::from("and"));
+        } else if num.is_negative() {
+            words.push(String::from(self.minus()));
+        }
+        words.push(self.int_to_cardinal(numerator)?);
+
+        // the leading one of the denominator is dropped: one hundredth =>
+        // hundredth, one hundred thousandth => hundred-thousandth
+        let ordinal = self.to_ordinal(denominator)?;
+        let mut ordinal_words: Vec<&str> = ordinal.split([' ', '-']).collect();
+        if ordinal_words.len() > 1 && ["one", "a"].contains(&ordinal_words[0]) {
+            ordinal_words.remove(0);
+        }
+        let mut denominator_word = ordinal_words.join("-");
+        if self.plural_category(numerator) != PluralCategory::One {
+            denominator_word.push('s');
+        }
+        words.push(denominator_word);
+
+        Ok(self.join(&words))
     }
 }
 
@@ -181,7 +670,7 @@ impl Language for English {
         if num.is_inf_pos() {
             Ok(String::from("infinity"))
         } else if num.is_inf_neg() {
-            Ok(String::from("minus infinity"))
+            Ok(format!("{} infinity", self.minus()))
         } else if num.frac().is_zero() {
             self.int_to_cardinal(num)
         } else {
@@ -204,17 +693,10 @@ impl Language for English {
                 let mut prefix = String::from("");
                 let mut suffix = String::from(w);
 
-                if w.contains('-') {
+                if let Some((pre, suf)) = w.rsplit_once('-') {
                     // e.g. forty-two => forty-second
-                    let mut w_split = w.split('-');
-
-                    if let Some(pre) = w_split.next() {
-                        prefix = format!("{}-", pre);
-                    }
-
-                    if let Some(suf) = w_split.next() {
-                        suffix = String::from(suf);
-                    }
+                    prefix = format!("{}-", pre);
+                    suffix = String::from(suf);
                 }
 
                 suffix = match suffix.as_str() {
@@ -230,6 +712,8 @@ impl Language for English {
                     "ten" => String::from("tenth"),
                     "eleven" => String::from("eleventh"),
                     "twelve" => String::from("twelfth"),
+                    // there is no ordinal of the Indian groups
+                    "lakh" | "crore" => return Err(Num2Err::CannotConvert),
                     _ => {
                         if suffix.ends_with('y') {
                             format!("{}ieth", &suffix[..suffix.len() - 1])
@@ -251,7 +735,7 @@ impl Language for English {
         let last = tail % 10;
         Ok(format!(
             "{}{}",
-            num.to_u128().unwrap(),
+            self.digits(num),
             match (tail / 10 != 1, last) {
                 (true, 1) => "st",
                 (true, 2) => "nd",
@@ -266,63 +750,100 @@ impl Language for English {
             return Err(Num2Err::FloatingYear);
         }
 
-        let mut num = num;
-
-        let mut suffix = "";
-        if num.is_negative() {
-            num = num.inv_sign();
-            suffix = " BC";
-        }
-
-        let bf_100 = BigFloat::from(100);
+        let (prefix, suffix) = self.era(num);
+        let num = num.abs();
 
-        let (high, low) = (
-            (num / bf_100).to_i64().unwrap(),
-            (num % bf_100).to_i64().unwrap(),
-        );
-        let year_word = if high == 0 || (high % 10 == 0 && low < 10) || high >= 100 {
-            // if year is 00XX, X00X, or beyond 9999, go cardinal
+        let (high, low) = self.split_hundreds(num);
+        let year_word = if high >= 100 || self.formal_year {
+            // expanded years beyond 9999, or any year in the formal style, are
+            // read as cardinals, without "and" in the American convention:
+            // twelve thousand twenty-four
+            self.cardinal_words(num, !self.american && !self.no_and)?
+        } else if high == 0 || (high % 10 == 0 && (low == 0 || (low < 10 && !self.oh_years))) {
+            // if year is 00XX or X00X, go cardinal
             self.int_to_cardinal(num)?
         } else {
-            let high_word = self.int_to_cardinal(BigFloat::from(high))?;
+            // a round hundred follows the informal "a" like the cardinals do
+            let high_word = if self.informal && high == 1 && low == 0 {
+                String::from("a")
+            } else {
+                self.int_to_cardinal(BigFloat::from(high))?
+            };
             let low_word = if low == 0 {
                 String::from("hundred")
             } else if low < 10 {
-                format!("oh-{}", self.int_to_cardinal(BigFloat::from(low))?)
+                format!(
+                    "oh{}{}",
+                    self.hyphen(),
+                    self.int_to_cardinal(BigFloat::from(low))?
+                )
             } else {
                 self.int_to_cardinal(BigFloat::from(low))?
             };
 
-            format!("{} {}", high_word, low_word)
+            self.join(&[high_word, low_word])
         };
 
-        Ok(format!("{}{}", year_word, suffix))
+        Ok(format!("{}{}{}", prefix, year_word, suffix))
+    }
+
+    fn to_year_range(&self, from: BigFloat, to: BigFloat) -> Result<String, Num2Err> {
+        Ok(format!(
+            "from {} to {}",
+            self.to_year(from)?,
+            self.to_year(to)?
+        ))
     }
 
     fn to_currency(&self, num: BigFloat, currency: Currency) -> Result<String, Num2Err> {
+        // rounded half-up to the nearest cent, once the noise of binary floats
+        // is dropped, e.g. 4.7_f32 has seventy cents and 4.999 none
+        let num = quantize(quantize(num, 9, Rounding::HalfUp), 2, Rounding::HalfUp);
+
         if num.is_inf() {
             Ok(format!(
                 "{}an infinity of {}",
-                if num.is_negative() { "minus " } else { "" },
+                if num.is_negative() {
+                    format!("{} ", self.minus())
+                } else {
+                    String::new()
+                },
                 self.currencies(currency, true)
             ))
-        } else if num.frac().is_zero() {
-            let words = self.int_to_cardinal(num)?;
+        } else if self.check {
+            self.check_currency(num, currency)
+        } else if num.is_negative() {
+            // the sign is read once, not on both the dollars and the cents
             Ok(format!(
                 "{} {}",
-                words,
-                self.currencies(currency, num != BigFloat::from(1))
+                self.minus(),
+                self.to_currency(-num, currency)?
             ))
+        } else if num.frac().is_zero() {
+            let words = self.whole_currency(num, currency)?;
+            if self.always_cents || self.no_cents {
+                Ok(format!(
+                    "{} and {} {}",
+                    words,
+                    if self.no_cents { "no" } else { "zero" },
+                    self.cents(currency, true)
+                ))
+            } else {
+                Ok(words)
+            }
         } else {
             let integral_part = num.int();
             let cents_nb = (num * BigFloat::from(100)).int() % BigFloat::from(100);
             let cents_words = self.int_to_cardinal(cents_nb)?;
-            let cents_suffix = self.cents(currency, cents_nb != BigFloat::from(1));
-            let integral_word = self.to_currency(integral_part, currency)?;
+            let cents_suffix = self.cents(
+                currency,
+                self.plural_category(cents_nb) != PluralCategory::One,
+            );
+            let integral_word = self.whole_currency(integral_part, currency)?;
 
             if cents_nb.is_zero() {
                 Ok(integral_word)
-            } else if integral_part.is_zero() {
+            } else if integral_part.is_zero() && !self.zero_major {
                 Ok(format!("{} {}", cents_words, cents_suffix))
             } else {
                 Ok(format!(
@@ -332,6 +853,38 @@ impl Language for English {
             }
         }
     }
+
+    fn to_percent(&self, num: BigFloat) -> Result<String, Num2Err> {
+        Ok(format!("{} percent", self.to_cardinal(num)?))
+    }
+
+    fn to_unit(&self, num: BigFloat, unit: Unit) -> Result<String, Num2Err> {
+        if num.is_inf() {
+            Ok(format!(
+                "{}an infinity of {}",
+                if num.is_negative() {
+                    format!("{} ", self.minus())
+                } else {
+                    String::new()
+                },
+                unit.default_string(true)
+            ))
+        } else {
+            Ok(format!(
+                "{} {}",
+                self.to_cardinal(num)?,
+                unit.default_string(self.plural_category(num) != PluralCategory::One)
+            ))
+        }
+    }
+
+    fn plural_category(&self, num: BigFloat) -> PluralCategory {
+        if num.abs() == BigFloat::from(1) {
+            PluralCategory::One
+        } else {
+            PluralCategory::Other
+        }
+    }
 }
 
 #[cfg(test)]
@@ -399,6 +952,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_group_digits() {
+        assert_eq!(
+            Num2Words::new(1_000_000)
+                .lang(Lang::English)
+                .ordinal_num()
+                .group_digits()
+                .to_words(),
+            Ok(String::from("1,000,000th"))
+        );
+        assert_eq!(
+            Num2Words::new(12_345)
+                .lang(Lang::English)
+                .ordinal_num()
+                .group_digits()
+                .to_words(),
+            Ok(String::from("12,345th"))
+        );
+        assert_eq!(
+            Num2Words::new(999)
+                .lang(Lang::English)
+                .ordinal_num()
+                .group_digits()
+                .to_words(),
+            Ok(String::from("999th"))
+        );
+    }
+
     #[test]
     fn test_ordinal_num() {
         assert_eq!(
@@ -524,58 +1105,376 @@ mod tests {
     }
 
     #[test]
-    fn test_year() {
+    fn test_currency_subunits() {
+        let amount = |num: f64, currency| {
+            Num2Words::new(num)
+                .lang(Lang::English)
+                .currency(currency)
+                .to_words()
+        };
+
         assert_eq!(
-            Num2Words::new(1990).lang(Lang::English).year().to_words(),
-            Ok(String::from("nineteen ninety"))
+            amount(2.05, Currency::GBP),
+            Ok(String::from("two pounds and five pence"))
         );
         assert_eq!(
-            Num2Words::new(5555).lang(Lang::English).year().to_words(),
-            Ok(String::from("fifty-five fifty-five"))
+            amount(1.01, Currency::GBP),
+            Ok(String::from("one pound and one penny"))
         );
         assert_eq!(
-            Num2Words::new(2022).lang(Lang::English).year().to_words(),
-            Ok(String::from("twenty twenty-two"))
+            amount(2.05, Currency::NOK),
+            Ok(String::from("two Norwegian kroner and five øre"))
         );
         assert_eq!(
-            Num2Words::new(2001).lang(Lang::English).year().to_words(),
-            Ok(String::from("two thousand and one"))
+            amount(1.01, Currency::SEK),
+            Ok(String::from("one Swedish krona and one öre"))
         );
         assert_eq!(
-            Num2Words::new(1901).lang(Lang::English).year().to_words(),
-            Ok(String::from("nineteen oh-one"))
+            amount(2.05, Currency::DKK),
+            Ok(String::from("two Danish kroner and five øre"))
         );
         assert_eq!(
-            Num2Words::new(5500).lang(Lang::English).year().to_words(),
-            Ok(String::from("fifty-five hundred"))
+            amount(2.05, Currency::CHF),
+            Ok(String::from("two Swiss francs and five rappen"))
         );
         assert_eq!(
-            Num2Words::new(500).lang(Lang::English).year().to_words(),
-            Ok(String::from("five hundred"))
+            amount(2.05, Currency::ILS),
+            Ok(String::from("two Israeli new shekels and five agorot"))
         );
         assert_eq!(
-            Num2Words::new(50).lang(Lang::English).year().to_words(),
-            Ok(String::from("fifty"))
+            amount(1.01, Currency::ILS),
+            Ok(String::from("one Israeli new shekel and one agora"))
         );
         assert_eq!(
-            Num2Words::new(0).lang(Lang::English).year().to_words(),
-            Ok(String::from("zero"))
+            amount(2.05, Currency::RUB),
+            Ok(String::from("two Russian rubles and five kopecks"))
         );
         assert_eq!(
-            Num2Words::new(-44).lang(Lang::English).year().to_words(),
-            Ok(String::from("forty-four BC"))
+            amount(1.01, Currency::KWD),
+            Ok(String::from("one Kuwaiti dinar and one fils"))
         );
         assert_eq!(
-            Num2Words::new(1.1).lang(Lang::English).year().to_words(),
-            Err(num2words::Num2Err::FloatingYear)
+            amount(2.05, Currency::BHD),
+            Ok(String::from("two Bahraini dinars and five fils"))
         );
     }
 
     #[test]
-    fn test_prefer() {
+    fn test_currency_rounding() {
+        let dollars = |num: Num2Words| num.lang(Lang::English).currency(Currency::DOLLAR);
+
+        for (num, words) in [
+            (4.999, "five dollars"),
+            (0.105, "eleven cents"),
+            (1.005, "one dollar and one cent"),
+            (4.70, "four dollars and seventy cents"),
+        ] {
+            assert_eq!(
+                dollars(Num2Words::new(num)).to_words(),
+                Ok(String::from(words))
+            );
+        }
         assert_eq!(
-            Num2Words::new(0)
-                .lang(Lang::English)
+            dollars(Num2Words::new(4.7_f32)).to_words(),
+            Ok(String::from("four dollars and seventy cents"))
+        );
+        // truncation is still available
+        assert_eq!(
+            dollars(Num2Words::parse("4.999").unwrap())
+                .rounding(Rounding::Truncate)
+                .to_words(),
+            Ok(String::from("four dollars and ninety-nine cents"))
+        );
+    }
+
+    #[test]
+    fn test_negative_currency() {
+        assert_eq!(
+            Num2Words::new(-0.25)
+                .lang(Lang::English)
+                .currency(Currency::USD)
+                .to_words(),
+            Ok(String::from("minus twenty-five cents"))
+        );
+        assert_eq!(
+            Num2Words::new(-1.00)
+                .lang(Lang::English)
+                .currency(Currency::USD)
+                .to_words(),
+            Ok(String::from("minus one US dollar"))
+        );
+        assert_eq!(
+            Num2Words::new(-5.10)
+                .lang(Lang::English)
+                .currency(Currency::USD)
+                .to_words(),
+            Ok(String::from("minus five US dollars and ten cents"))
+        );
+    }
+
+    #[test]
+    fn test_check() {
+        let check = |num: f64| {
+            Num2Words::new(num)
+                .lang(Lang::English)
+                .currency(Currency::DOLLAR)
+                .prefer("check")
+        };
+
+        assert_eq!(
+            check(0.07).to_words(),
+            Ok(String::from("Zero and 07/100 dollars"))
+        );
+        assert_eq!(
+            check(100.00).to_words(),
+            Ok(String::from("One hundred and 00/100 dollars"))
+        );
+        assert_eq!(
+            check(1042.50).to_words(),
+            Ok(String::from("One thousand forty-two and 50/100 dollars"))
+        );
+        assert_eq!(
+            check(1042.50).currency(Currency::USD).to_words(),
+            Ok(String::from("One thousand forty-two and 50/100 US dollars"))
+        );
+    }
+
+    #[test]
+    fn test_always_cents() {
+        let dollars = |num: f64, preference| {
+            Num2Words::new(num)
+                .lang(Lang::English)
+                .currency(Currency::DOLLAR)
+                .prefer(preference)
+                .to_words()
+        };
+
+        assert_eq!(
+            dollars(42.0, "always-cents"),
+            Ok(String::from("forty-two dollars and zero cents"))
+        );
+        assert_eq!(
+            dollars(42.0, "no-cents"),
+            Ok(String::from("forty-two dollars and no cents"))
+        );
+        assert_eq!(
+            dollars(4.999, "zero-cents"),
+            Ok(String::from("five dollars and zero cents"))
+        );
+        assert_eq!(
+            dollars(42.5, "always-cents"),
+            Ok(String::from("forty-two dollars and fifty cents"))
+        );
+    }
+
+    #[test]
+    fn test_currency_zero_major() {
+        assert_eq!(
+            Num2Words::new(0.20)
+                .lang(Lang::English)
+                .currency(Currency::DOLLAR)
+                .prefer("zero-major")
+                .to_words(),
+            Ok(String::from("zero dollars and twenty cents"))
+        );
+        assert_eq!(
+            Num2Words::new(0.01)
+                .lang(Lang::English)
+                .currency(Currency::DOLLAR)
+                .prefer("zero-major")
+                .to_words(),
+            Ok(String::from("zero dollars and one cent"))
+        );
+        assert_eq!(
+            Num2Words::new(0.00)
+                .lang(Lang::English)
+                .currency(Currency::DOLLAR)
+                .prefer("zero-major")
+                .to_words(),
+            Ok(String::from("zero dollars"))
+        );
+    }
+
+    #[test]
+    fn test_year() {
+        assert_eq!(
+            Num2Words::new(1990).lang(Lang::English).year().to_words(),
+            Ok(String::from("nineteen ninety"))
+        );
+        assert_eq!(
+            Num2Words::new(5555).lang(Lang::English).year().to_words(),
+            Ok(String::from("fifty-five fifty-five"))
+        );
+        assert_eq!(
+            Num2Words::new(2022).lang(Lang::English).year().to_words(),
+            Ok(String::from("twenty twenty-two"))
+        );
+        assert_eq!(
+            Num2Words::new(2001).lang(Lang::English).year().to_words(),
+            Ok(String::from("two thousand and one"))
+        );
+        assert_eq!(
+            Num2Words::new(1901).lang(Lang::English).year().to_words(),
+            Ok(String::from("nineteen oh-one"))
+        );
+        assert_eq!(
+            Num2Words::new(5500).lang(Lang::English).year().to_words(),
+            Ok(String::from("fifty-five hundred"))
+        );
+        assert_eq!(
+            Num2Words::new(500).lang(Lang::English).year().to_words(),
+            Ok(String::from("five hundred"))
+        );
+        assert_eq!(
+            Num2Words::new(50).lang(Lang::English).year().to_words(),
+            Ok(String::from("fifty"))
+        );
+        assert_eq!(
+            Num2Words::new(0).lang(Lang::English).year().to_words(),
+            Ok(String::from("zero"))
+        );
+        assert_eq!(
+            Num2Words::new(-44).lang(Lang::English).year().to_words(),
+            Ok(String::from("forty-four BC"))
+        );
+        assert_eq!(
+            Num2Words::new(1.1).lang(Lang::English).year().to_words(),
+            Err(num2words::Num2Err::FloatingYear)
+        );
+    }
+
+    #[test]
+    fn test_era() {
+        let year = |num: i64, preference| {
+            Num2Words::new(num)
+                .lang(Lang::English)
+                .year()
+                .prefer(preference)
+                .to_words()
+        };
+
+        assert_eq!(year(-44, "bce"), Ok(String::from("forty-four BCE")));
+        assert_eq!(year(1990, "bce"), Ok(String::from("nineteen ninety CE")));
+        assert_eq!(year(79, "ad"), Ok(String::from("AD seventy-nine")));
+        assert_eq!(year(1990, "ad"), Ok(String::from("nineteen ninety")));
+        assert_eq!(year(-44, "ad"), Ok(String::from("forty-four BC")));
+    }
+
+    #[test]
+    fn test_year_range() {
+        assert_eq!(
+            Num2Words::new(2020).year_range(2023).to_words(),
+            Ok(String::from("from twenty twenty to twenty twenty-three"))
+        );
+        assert_eq!(
+            Num2Words::new(-44).year_range(14).to_words(),
+            Ok(String::from("from forty-four BC to fourteen"))
+        );
+        assert_eq!(
+            Num2Words::new(2020).year_range(2020).to_words(),
+            Num2Words::new(2020).year().to_words()
+        );
+        assert_eq!(
+            Num2Words::new(2020).year_range(2023.5).to_words(),
+            Err(Num2Err::FloatingYear)
+        );
+    }
+
+    #[test]
+    fn test_oh_years() {
+        let year = |num: i64, oh_years: bool| {
+            let n2w = Num2Words::new(num).lang(Lang::English).year();
+            if oh_years {
+                n2w.prefer("oh-years").to_words()
+            } else {
+                n2w.to_words()
+            }
+        };
+
+        assert_eq!(year(2001, false), Ok(String::from("two thousand and one")));
+        assert_eq!(year(2001, true), Ok(String::from("twenty oh-one")));
+        assert_eq!(year(2009, true), Ok(String::from("twenty oh-nine")));
+        assert_eq!(year(1901, false), Ok(String::from("nineteen oh-one")));
+        assert_eq!(year(1901, true), Ok(String::from("nineteen oh-one")));
+        assert_eq!(year(1009, false), Ok(String::from("one thousand and nine")));
+        assert_eq!(year(1009, true), Ok(String::from("ten oh-nine")));
+        assert_eq!(year(2000, true), Ok(String::from("two thousand")));
+    }
+
+    #[test]
+    fn test_formal_year() {
+        let year = |num: i64| {
+            Num2Words::new(num)
+                .lang(Lang::English)
+                .year()
+                .prefer("formal-year")
+        };
+
+        assert_eq!(
+            year(2022).to_words(),
+            Ok(String::from("two thousand and twenty-two"))
+        );
+        assert_eq!(
+            year(2022).prefer("us").to_words(),
+            Ok(String::from("two thousand twenty-two"))
+        );
+        assert_eq!(
+            year(1901).to_words(),
+            Ok(String::from("one thousand nine hundred and one"))
+        );
+        assert_eq!(year(-44).to_words(), Ok(String::from("forty-four BC")));
+        // the split-hundreds reading stays the default
+        assert_eq!(
+            Num2Words::new(1901).lang(Lang::English).year().to_words(),
+            Ok(String::from("nineteen oh-one"))
+        );
+    }
+
+    #[test]
+    fn test_expanded_year() {
+        for (year, uk, us) in [
+            (
+                12024,
+                "twelve thousand and twenty-four",
+                "twelve thousand twenty-four",
+            ),
+            (10000, "ten thousand", "ten thousand"),
+            (-10000, "ten thousand BC", "ten thousand BC"),
+            (
+                99999,
+                "ninety-nine thousand nine hundred and ninety-nine",
+                "ninety-nine thousand nine hundred ninety-nine",
+            ),
+        ] {
+            assert_eq!(
+                Num2Words::new(year).lang(Lang::English).year().to_words(),
+                Ok(String::from(uk))
+            );
+            assert_eq!(
+                Num2Words::new(year)
+                    .lang(Lang::English)
+                    .year()
+                    .prefer("us")
+                    .to_words(),
+                Ok(String::from(us))
+            );
+        }
+        // the preference is limited to expanded years
+        assert_eq!(
+            Num2Words::new(2001)
+                .lang(Lang::English)
+                .year()
+                .prefer("american")
+                .to_words(),
+            Ok(String::from("two thousand and one"))
+        );
+    }
+
+    #[test]
+    fn test_prefer() {
+        assert_eq!(
+            Num2Words::new(0)
+                .lang(Lang::English)
                 .prefer("oh")
                 .to_words(),
             Ok(String::from("oh"))
@@ -592,7 +1491,7 @@ mod tests {
                 .lang(Lang::English)
                 .prefer("oh")
                 .to_words(),
-            Ok(String::from("point oh oh five"))
+            Ok(String::from("oh point oh oh five"))
         );
         assert_eq!(
             Num2Words::new(2.05)
@@ -601,6 +1500,383 @@ mod tests {
                 .to_words(),
             Ok(String::from("two point zero five"))
         );
+        assert_eq!(
+            Num2Words::new(0)
+                .lang(Lang::English)
+                .prefer("nought")
+                .to_words(),
+            Ok(String::from("nought"))
+        );
+        assert_eq!(
+            Num2Words::new(0.05)
+                .lang(Lang::English)
+                .prefer("nought")
+                .to_words(),
+            Ok(String::from("nought point nought five"))
+        );
+        assert_eq!(
+            Num2Words::new(-2.05)
+                .lang(Lang::English)
+                .prefer("naught")
+                .to_words(),
+            Ok(String::from("minus two point nought five"))
+        );
+        // the last zero word given wins
+        assert_eq!(
+            Num2Words::new(0)
+                .lang(Lang::English)
+                .prefer("nought")
+                .prefer("oh")
+                .to_words(),
+            Ok(String::from("oh"))
+        );
+    }
+
+    #[test]
+    fn test_british() {
+        let british = |num: i64| Num2Words::new(num).lang(Lang::English).prefer("uk");
+
+        for (num, words) in [
+            (101, "one hundred and one"),
+            (1001, "one thousand and one"),
+            (1100, "one thousand one hundred"),
+            (
+                123456,
+                "one hundred and twenty-three thousand four hundred and fifty-six",
+            ),
+            (1000042, "one million and forty-two"),
+        ] {
+            assert_eq!(british(num).to_words(), Ok(String::from(words)));
+        }
+        assert_eq!(
+            british(123).ordinal().to_words(),
+            Ok(String::from("one hundred and twenty-third"))
+        );
+        assert_eq!(
+            british(101).currency(Currency::GBP).to_words(),
+            Ok(String::from("one hundred and one pounds"))
+        );
+        // the default stays unchanged
+        assert_eq!(
+            Num2Words::new(123456).lang(Lang::English).to_words(),
+            Ok(String::from(
+                "one hundred twenty-three thousand four hundred and fifty-six"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_no_and() {
+        let no_and = |num: f64| Num2Words::new(num).lang(Lang::English).prefer("no-and");
+
+        assert_eq!(
+            no_and(1932.0).to_words(),
+            Ok(String::from("one thousand nine hundred thirty-two"))
+        );
+        assert_eq!(
+            no_and(38123147081932.0).to_words(),
+            Ok(String::from(
+                "thirty-eight trillion one hundred twenty-three \
+                 billion one hundred forty-seven million eighty-one thousand \
+                 nine hundred thirty-two"
+            ))
+        );
+        assert_eq!(
+            no_and(1001.0).ordinal().to_words(),
+            Ok(String::from("one thousand first"))
+        );
+        assert_eq!(
+            no_and(2001.0).year().to_words(),
+            Ok(String::from("two thousand one"))
+        );
+        // the "and" joining dollars and cents is kept
+        assert_eq!(
+            no_and(142.01).currency(Currency::DOLLAR).to_words(),
+            Ok(String::from("one hundred forty-two dollars and one cent"))
+        );
+        assert_eq!(
+            Num2Words::new(1000005.01)
+                .lang(Lang::English)
+                .prefer("plain-cardinal")
+                .currency(Currency::USD)
+                .to_words(),
+            Ok(String::from("one million five US dollars and one cent"))
+        );
+        // and it prevails over the British convention
+        assert_eq!(
+            no_and(101.0).prefer("uk").to_words(),
+            Ok(String::from("one hundred one"))
+        );
+    }
+
+    #[test]
+    fn test_long_scale() {
+        let long_scale =
+            |num: f64, preference| Num2Words::new(num).lang(Lang::English).prefer(preference);
+
+        for (num, long, thousand_million) in [
+            (1e6, "one million", "one million"),
+            (1e9, "one milliard", "one thousand million"),
+            (
+                1.5e9,
+                "one milliard five hundred million",
+                "one thousand five hundred million",
+            ),
+            (1e12, "one billion", "one billion"),
+            (2e15, "two billiard", "two thousand billion"),
+        ] {
+            assert_eq!(
+                long_scale(num, "long-scale").to_words(),
+                Ok(String::from(long))
+            );
+            assert_eq!(
+                long_scale(num, "thousand-million").to_words(),
+                Ok(String::from(thousand_million))
+            );
+        }
+        assert_eq!(
+            long_scale(1e9, "long-scale").ordinal().to_words(),
+            Ok(String::from("one milliardth"))
+        );
+        assert_eq!(
+            long_scale(1e12, "long-scale")
+                .currency(Currency::GBP)
+                .to_words(),
+            Ok(String::from("one billion pounds"))
+        );
+        assert_eq!(
+            long_scale(1e9, "thousand-million").year().to_words(),
+            Ok(String::from("one thousand million"))
+        );
+    }
+
+    #[test]
+    fn test_indian() {
+        let indian = |num: f64| Num2Words::new(num).lang(Lang::English).prefer("lakh");
+
+        for (num, words) in [
+            (1e5, "one lakh"),
+            (1e7, "one crore"),
+            (
+                123456789.0,
+                "twelve crore thirty-four lakh fifty-six thousand seven \
+                 hundred and eighty-nine",
+            ),
+            (1e12, "one lakh crore"),
+            (1e14, "one crore crore"),
+        ] {
+            assert_eq!(indian(num).to_words(), Ok(String::from(words)));
+        }
+        assert_eq!(
+            indian(2500000.0).currency(Currency::INR).to_words(),
+            Ok(String::from("twenty-five lakh rupees"))
+        );
+        assert_eq!(
+            indian(123456789.0).ordinal().to_words(),
+            Ok(String::from(
+                "twelve crore thirty-four lakh fifty-six thousand seven hundred and eighty-ninth"
+            ))
+        );
+        for num in [1e5, 1e6, 1e7, 1e12] {
+            assert_eq!(
+                indian(num).ordinal().to_words(),
+                Err(Num2Err::CannotConvert)
+            );
+        }
+    }
+
+    #[test]
+    fn test_informal() {
+        let informal = |num: f64| Num2Words::new(num).lang(Lang::English).prefer("a");
+
+        for (num, words) in [
+            (1.0, "one"),
+            (100.0, "a hundred"),
+            (102.0, "a hundred two"),
+            (1002.0, "a thousand and two"),
+            (1e6, "a million"),
+            (12100.0, "twelve thousand one hundred"),
+            (-1000.0, "minus a thousand"),
+        ] {
+            assert_eq!(informal(num).to_words(), Ok(String::from(words)));
+        }
+        assert_eq!(
+            informal(1000.0).currency(Currency::DOLLAR).to_words(),
+            Ok(String::from("a thousand dollars"))
+        );
+        assert_eq!(
+            informal(1.0).currency(Currency::DOLLAR).to_words(),
+            Ok(String::from("one dollar"))
+        );
+        assert_eq!(
+            informal(1000.0).year().to_words(),
+            Ok(String::from("a thousand"))
+        );
+        assert_eq!(
+            informal(100.0).year().to_words(),
+            Ok(String::from("a hundred"))
+        );
+        assert_eq!(
+            informal(105.0).year().to_words(),
+            Ok(String::from("one oh-five"))
+        );
+    }
+
+    #[test]
+    fn test_negative() {
+        let negative = |num: f64| Num2Words::new(num).lang(Lang::English).prefer("negative");
+
+        assert_eq!(negative(-10.0).to_words(), Ok(String::from("negative ten")));
+        assert_eq!(
+            negative(-0.5).to_words(),
+            Ok(String::from("negative zero point five"))
+        );
+        assert_eq!(
+            negative(f64::NEG_INFINITY).to_words(),
+            Ok(String::from("negative infinity"))
+        );
+        assert_eq!(
+            negative(-5.0).currency(Currency::DOLLAR).to_words(),
+            Ok(String::from("negative five dollars"))
+        );
+        assert_eq!(
+            negative(-1000.0).prefer("a").to_words(),
+            Ok(String::from("negative a thousand"))
+        );
+    }
+
+    #[test]
+    fn test_leading_zero() {
+        let leading = |num: f64| Num2Words::new(num).lang(Lang::English);
+
+        assert_eq!(leading(0.5).to_words(), Ok(String::from("zero point five")));
+        assert_eq!(
+            leading(-0.5).to_words(),
+            Ok(String::from("minus zero point five"))
+        );
+        assert_eq!(
+            leading(0.5).prefer("nought").to_words(),
+            Ok(String::from("nought point five"))
+        );
+        assert_eq!(
+            leading(0.5).prefer("no-leading-zero").to_words(),
+            Ok(String::from("point five"))
+        );
+        assert_eq!(
+            leading(-0.5).prefer("no-leading-zero").to_words(),
+            Ok(String::from("minus point five"))
+        );
+    }
+
+    #[test]
+    fn test_hyphen() {
+        let no_hyphen = |num: f64| Num2Words::new(num).lang(Lang::English).prefer("no-hyphen");
+        let hyphen_all = |num: f64| Num2Words::new(num).lang(Lang::English).prefer("hyphen-all");
+
+        assert_eq!(no_hyphen(42.0).to_words(), Ok(String::from("forty two")));
+        assert_eq!(
+            no_hyphen(42.0).ordinal().to_words(),
+            Ok(String::from("forty second"))
+        );
+        assert_eq!(
+            no_hyphen(1905.0).year().to_words(),
+            Ok(String::from("nineteen oh five"))
+        );
+        assert_eq!(
+            no_hyphen(21.25).currency(Currency::DOLLAR).to_words(),
+            Ok(String::from("twenty one dollars and twenty five cents"))
+        );
+
+        assert_eq!(
+            hyphen_all(123.0).to_words(),
+            Ok(String::from("one-hundred-twenty-three"))
+        );
+        assert_eq!(
+            hyphen_all(123.0).ordinal().to_words(),
+            Ok(String::from("one-hundred-twenty-third"))
+        );
+        assert_eq!(
+            hyphen_all(1999.0).year().to_words(),
+            Ok(String::from("nineteen-ninety-nine"))
+        );
+        assert_eq!(
+            hyphen_all(1.5).to_words(),
+            Ok(String::from("one-point-five"))
+        );
+        assert_eq!(
+            hyphen_all(1100.25).currency(Currency::DOLLAR).to_words(),
+            Ok(String::from(
+                "one-thousand-one-hundred dollars and twenty-five cents"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_fraction() {
+        let fraction = |num: f64| Num2Words::new(num).lang(Lang::English).prefer("fraction");
+
+        assert_eq!(
+            fraction(0.75).to_words(),
+            Ok(String::from("seventy-five hundredths"))
+        );
+        assert_eq!(
+            fraction(3.2).to_words(),
+            Ok(String::from("three and two tenths"))
+        );
+        assert_eq!(fraction(0.1).to_words(), Ok(String::from("one tenth")));
+        assert_eq!(
+            fraction(0.001).to_words(),
+            Ok(String::from("one thousandth"))
+        );
+        assert_eq!(
+            fraction(1.0001).to_words(),
+            Ok(String::from("one and one ten-thousandth"))
+        );
+        assert_eq!(
+            fraction(-0.5).to_words(),
+            Ok(String::from("minus five tenths"))
+        );
+        assert_eq!(fraction(42.0).to_words(), Ok(String::from("forty-two")));
+    }
+
+    #[test]
+    fn test_hundreds() {
+        let hundreds = |num: f64| Num2Words::new(num).lang(Lang::English).prefer("hundreds");
+
+        assert_eq!(
+            hundreds(2500.0).to_words(),
+            Ok(String::from("twenty-five hundred"))
+        );
+        assert_eq!(
+            hundreds(1984.0).to_words(),
+            Ok(String::from("nineteen hundred eighty-four"))
+        );
+        assert_eq!(
+            hundreds(1984.0).prefer("uk").to_words(),
+            Ok(String::from("nineteen hundred and eighty-four"))
+        );
+        assert_eq!(
+            hundreds(-1100.0).to_words(),
+            Ok(String::from("minus eleven hundred"))
+        );
+        assert_eq!(
+            hundreds(1999.0).ordinal().to_words(),
+            Ok(String::from("nineteen hundred ninety-ninth"))
+        );
+        assert_eq!(
+            hundreds(2500.0).currency(Currency::DOLLAR).to_words(),
+            Ok(String::from("twenty-five hundred dollars"))
+        );
+        // out of range, or with a zero hundreds digit
+        assert_eq!(
+            hundreds(2005.0).to_words(),
+            Ok(String::from("two thousand and five"))
+        );
+        assert_eq!(hundreds(900.0).to_words(), Ok(String::from("nine hundred")));
+        assert_eq!(
+            hundreds(12500.0).to_words(),
+            Ok(String::from("twelve thousand five hundred"))
+        );
     }
 
     #[test]
@@ -683,4 +1959,73 @@ mod tests {
             Ok(String::from("an infinity of dollars"))
         );
     }
+
+    #[test]
+    fn test_agreement_matrix() {
+        let matrix = [
+            (
+                -0.5,
+                "minus fifty cents",
+                "minus zero point five percent",
+                "minus zero point five kilograms",
+            ),
+            (
+                -1.0,
+                "minus one euro",
+                "minus one percent",
+                "minus one kilogram",
+            ),
+            (0.0, "zero euros", "zero percent", "zero kilograms"),
+            (
+                0.5,
+                "fifty cents",
+                "zero point five percent",
+                "zero point five kilograms",
+            ),
+            (1.0, "one euro", "one percent", "one kilogram"),
+            (2.0, "two euros", "two percent", "two kilograms"),
+            (5.0, "five euros", "five percent", "five kilograms"),
+            (
+                21.0,
+                "twenty-one euros",
+                "twenty-one percent",
+                "twenty-one kilograms",
+            ),
+            (
+                22.0,
+                "twenty-two euros",
+                "twenty-two percent",
+                "twenty-two kilograms",
+            ),
+            (
+                25.0,
+                "twenty-five euros",
+                "twenty-five percent",
+                "twenty-five kilograms",
+            ),
+        ];
+        for (num, currency, percent, unit) in matrix.iter() {
+            assert_eq!(
+                Num2Words::new(*num)
+                    .lang(Lang::English)
+                    .currency(Currency::EUR)
+                    .to_words(),
+                Ok(String::from(*currency))
+            );
+            assert_eq!(
+                Num2Words::new(*num)
+                    .lang(Lang::English)
+                    .percent()
+                    .to_words(),
+                Ok(String::from(*percent))
+            );
+            assert_eq!(
+                Num2Words::new(*num)
+                    .lang(Lang::English)
+                    .unit(Unit::Kilogram)
+                    .to_words(),
+                Ok(String::from(*unit))
+            );
+        }
+    }
 }
@@ -0,0 +1,824 @@
+use crate::{num2words::Num2Err, Currency, Language, PluralCategory, Unit};
+use num_bigfloat::BigFloat;
+
+pub struct Spanish {
+    feminine: bool,
+    zero_major: bool,
+}
+
+// Form taken by "one" at the end of a triplet
+#[derive(Clone, Copy, PartialEq)]
+enum One {
+    // uno, veintiuno
+    Full,
+    // un, veintiún, before a masculine noun or a scale word
+    Apocopated,
+    // una, veintiuna
+    Feminine,
+}
+
+const UNITS: [&str; 9] = [
+    "uno", "dos", "tres", "cuatro", "cinco", "seis", "siete", "ocho", "nueve",
+];
+
+const TEENS: [&str; 10] = [
+    "diez",
+    "once",
+    "doce",
+    "trece",
+    "catorce",
+    "quince",
+    "dieciséis",
+    "diecisiete",
+    "dieciocho",
+    "diecinueve",
+];
+
+const TWENTIES: [&str; 10] = [
+    "veinte",
+    "veintiuno",
+    "veintidós",
+    "veintitrés",
+    "veinticuatro",
+    "veinticinco",
+    "veintiséis",
+    "veintisiete",
+    "veintiocho",
+    "veintinueve",
+];
+
+const TENS: [&str; 9] = [
+    "diez",
+    "veinte",
+    "treinta",
+    "cuarenta",
+    "cincuenta",
+    "sesenta",
+    "setenta",
+    "ochenta",
+    "noventa",
+];
+
+// hundreds followed by something else, or in the masculine, e.g. ciento uno
+const HUNDREDS: [&str; 9] = [
+    "ciento",
+    "doscientos",
+    "trescientos",
+    "cuatrocientos",
+    "quinientos",
+    "seiscientos",
+    "setecientos",
+    "ochocientos",
+    "novecientos",
+];
+
+// long scale, every group of six digits
+const MEGAS: [&str; 10] = [
+    "millón",
+    "billón",
+    "trillón",
+    "cuatrillón",
+    "quintillón",
+    "sextillón",
+    "septillón",
+    "octillón",
+    "nonillón",
+    "decillón",
+];
+
+const ORDINAL_UNITS: [&str; 9] = [
+    "primero", "segundo", "tercero", "cuarto", "quinto", "sexto", "séptimo", "octavo", "noveno",
+];
+
+const ORDINAL_TEENS: [&str; 10] = [
+    "décimo",
+    "undécimo",
+    "duodécimo",
+    "decimotercero",
+    "decimocuarto",
+    "decimoquinto",
+    "decimosexto",
+    "decimoséptimo",
+    "decimoctavo",
+    "decimonoveno",
+];
+
+const ORDINAL_TENS: [&str; 9] = [
+    "décimo",
+    "vigésimo",
+    "trigésimo",
+    "cuadragésimo",
+    "quincuagésimo",
+    "sexagésimo",
+    "septuagésimo",
+    "octogésimo",
+    "nonagésimo",
+];
+
+const ORDINAL_HUNDREDS: [&str; 9] = [
+    "centésimo",
+    "ducentésimo",
+    "tricentésimo",
+    "cuadringentésimo",
+    "quingentésimo",
+    "sexcentésimo",
+    "septingentésimo",
+    "octingentésimo",
+    "noningentésimo",
+];
+
+impl Spanish {
+    pub fn new(feminine: bool) -> Self {
+        Self {
+            feminine,
+            zero_major: false,
+        }
+    }
+
+    /// Keeps the major unit clause for amounts below one, e.g. `cero pesos
+    /// con veinte centavos`
+    pub fn zero_major(self, zero_major: bool) -> Self {
+        Self { zero_major, ..self }
+    }
+
+    fn currencies(&self, currency: Currency, plural_form: bool) -> String {
+        match currency {
+            Currency::ARS => String::from("peso{} argentino{}"),
+            Currency::AUD => String::from(if plural_form {
+                "dólares australianos"
+            } else {
+                "dólar australiano"
+            }),
+            Currency::BRL => String::from(if plural_form { "reales" } else { "real" }),
+            Currency::CAD => String::from(if plural_form {
+                "dólares canadienses"
+            } else {
+                "dólar canadiense"
+            }),
+            Currency::CHF => String::from("franco{} suizo{}"),
+            Currency::CLP => String::from("peso{} chileno{}"),
+            Currency::COP => String::from("peso{} colombiano{}"),
+            Currency::DOLLAR => String::from(if plural_form { "dólares" } else { "dólar" }),
+            Currency::GBP => String::from("libra{} esterlina{}"),
+            Currency::INR => String::from("rupia{} india{}"),
+            Currency::JPY => String::from(if plural_form { "yenes" } else { "yen" }),
+            Currency::MXN => String::from("peso{} mexicano{}"),
+            Currency::PESO => String::from("peso{}"),
+            Currency::PHP => String::from("peso{} filipino{}"),
+            Currency::RUB => String::from("rublo{}"),
+            Currency::TRY => String::from("lira{} turca{}"),
+            Currency::USD => String::from(if plural_form {
+                "dólares estadounidenses"
+            } else {
+                "dólar estadounidense"
+            }),
+            Currency::UYU => String::from("peso{} uruguayo{}"),
+            _ => currency.default_string(plural_form),
+        }
+        .replace("{}", if plural_form { "s" } else { "" })
+    }
+
+    fn feminine_currency(&self, currency: Currency) -> bool {
+        matches!(currency, Currency::GBP | Currency::INR | Currency::TRY)
+    }
+
+    fn cents(&self, currency: Currency, plural_form: bool) -> String {
+        match currency {
+            Currency::EUR => String::from("céntimo{}"),
+            Currency::GBP => String::from("penique{}"),
+            Currency::UYU => String::from("centésimo{}"),
+            _ => currency.default_subunit_string("centavo{}", plural_form),
+        }
+        .replace("{}", if plural_form { "s" } else { "" })
+    }
+
+    fn units(&self, unit: Unit, plural_form: bool) -> String {
+        match unit {
+            Unit::Meter => "metro{}",
+            Unit::Kilometer => "kilómetro{}",
+            Unit::Gram => "gramo{}",
+            Unit::Kilogram => "kilogramo{}",
+            Unit::Liter => "litro{}",
+            Unit::Second => "segundo{}",
+            Unit::Minute => "minuto{}",
+            Unit::Hour => "hora{}",
+        }
+        .replace("{}", if plural_form { "s" } else { "" })
+    }
+
+    fn split_thousands(&self, mut num: BigFloat) -> Vec<u64> {
+        let mut thousands = Vec::new();
+        let bf_1000 = BigFloat::from(1000);
+
+        while !num.is_zero() {
+            thousands.push((num % bf_1000).to_u64().unwrap());
+            num /= bf_1000;
+        }
+
+        thousands
+    }
+
+    fn triplet_to_words(&self, triplet: u64, one: One) -> Vec<String> {
+        let mut words = vec![];
+        let hundreds = (triplet / 100) as usize;
+        let below_hundred = (triplet % 100) as usize;
+        let tens = below_hundred / 10;
+        let units = below_hundred % 10;
+
+        if hundreds == 1 && below_hundred == 0 {
+            words.push(String::from("cien"));
+        } else if hundreds > 0 {
+            let hundred = HUNDREDS[hundreds - 1];
+            words.push(if one == One::Feminine && hundreds > 1 {
+                hundred.replace("tos", "tas")
+            } else {
+                String::from(hundred)
+            });
+        }
+
+        let with_one = |word: &str| match one {
+            One::Full => String::from(word),
+            One::Apocopated if word == "veintiuno" => String::from("veintiún"),
+            One::Apocopated => word.replace("uno", "un"),
+            One::Feminine => word.replace("uno", "una"),
+        };
+
+        match (tens, units) {
+            (0, 0) => (),
+            (0, _) => words.push(with_one(UNITS[units - 1])),
+            (1, _) => words.push(String::from(TEENS[units])),
+            (2, _) => words.push(with_one(TWENTIES[units])),
+            (_, 0) => words.push(String::from(TENS[tens - 1])),
+            _ => words.push(format!(
+                "{} y {}",
+                TENS[tens - 1],
+                with_one(UNITS[units - 1])
+            )),
+        }
+
+        words
+    }
+
+    // `one` is the form taken by a final "one", which is only apocopated
+    // before a masculine noun
+    fn int_to_words(&self, mut num: BigFloat, one: One) -> Result<String, Num2Err> {
+        // special case zero
+        if num.is_zero() {
+            return Ok(String::from("cero"));
+        }
+
+        // handling negative values
+        let mut words = vec![];
+        if num.is_negative() {
+            words.push(String::from("menos"));
+            num = -num;
+        }
+
+        let triplets = self.split_thousands(num);
+        // iterate over groups of six digits, i.e. millones
+        for i in (0..triplets.len().div_ceil(2)).rev() {
+            let low = triplets[2 * i];
+            let high = triplets.get(2 * i + 1).copied().unwrap_or(0);
+            if high == 0 && low == 0 {
+                continue;
+            }
+
+            // scale words are masculine, except for the noun counted in mil
+            let group_one = if i == 0 && one == One::Feminine {
+                One::Feminine
+            } else {
+                One::Apocopated
+            };
+            if high == 1 {
+                words.push(String::from("mil"));
+            } else if high != 0 {
+                words.extend(self.triplet_to_words(high, group_one));
+                words.push(String::from("mil"));
+            }
+            if low != 0 {
+                words
+                    .extend(self.triplet_to_words(low, if i == 0 { one } else { One::Apocopated }));
+            }
+
+            if i != 0 {
+                if i > MEGAS.len() {
+                    return Err(Num2Err::CannotConvert);
+                }
+                let mega = MEGAS[i - 1];
+                words.push(if high == 0 && low == 1 {
+                    String::from(mega)
+                } else {
+                    format!("{}ones", mega.strip_suffix("ón").unwrap())
+                });
+            }
+        }
+
+        Ok(words.join(" "))
+    }
+
+    fn float_to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let integral_part = num.int();
+        let mut words: Vec<String> = vec![];
+
+        if !integral_part.is_zero() {
+            let integral_word = self.int_to_cardinal(integral_part)?;
+            words.push(integral_word);
+        } else if num.is_negative() {
+            words.push(String::from("menos"));
+        }
+        if integral_part.is_zero() {
+            words.push(String::from("cero"));
+        }
+
+        let mut ordinal_part = num.frac().abs();
+        if !ordinal_part.is_zero() {
+            words.push(String::from("coma"));
+        }
+        while !ordinal_part.is_zero() {
+            let digit = (ordinal_part * BigFloat::from(10)).int();
+            ordinal_part = (ordinal_part * BigFloat::from(10)).frac();
+            words.push(match digit.to_u64().unwrap() {
+                0 => String::from("cero"),
+                i => String::from(UNITS[i as usize - 1]),
+            });
+        }
+        Ok(words.join(" "))
+    }
+
+    fn int_to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        self.int_to_words(
+            num,
+            if self.feminine {
+                One::Feminine
+            } else {
+                One::Full
+            },
+        )
+    }
+
+    // A number before a noun, e.g. veintiún pesos, un millón de pesos
+    fn with_noun(&self, num: BigFloat, noun: &str, feminine: bool) -> Result<String, Num2Err> {
+        let words = if !num.frac().is_zero() {
+            Self::new(feminine).float_to_cardinal(num)?
+        } else {
+            self.int_to_words(
+                num,
+                if feminine {
+                    One::Feminine
+                } else {
+                    One::Apocopated
+                },
+            )?
+        };
+
+        if words.ends_with("llón") || words.ends_with("llones") {
+            Ok(format!("{} de {}", words, noun))
+        } else {
+            Ok(format!("{} {}", words, noun))
+        }
+    }
+
+    // A cardinal fused in front of a scale ordinal, e.g. dosmilésimo or
+    // milmillonésimo, up to a group of six digits
+    fn fused_cardinal(&self, num: u64) -> String {
+        if num == 1 {
+            return String::new();
+        }
+        let (high, low) = (num / 1000, num % 1000);
+        let mut words = vec![];
+        if high > 1 {
+            words.extend(self.triplet_to_words(high, One::Apocopated));
+        }
+        if high > 0 {
+            words.push(String::from("mil"));
+        }
+        if low > 0 {
+            words.extend(self.triplet_to_words(low, One::Apocopated));
+        }
+        words
+            .join("")
+            .replace(' ', "")
+            .replace('á', "a")
+            .replace('é', "e")
+            .replace('í', "i")
+            .replace('ó', "o")
+            .replace('ú', "u")
+    }
+
+    fn triplet_to_ordinal(&self, triplet: u64) -> Vec<String> {
+        let mut words = vec![];
+        let hundreds = (triplet / 100) as usize;
+        let tens = (triplet / 10 % 10) as usize;
+        let units = (triplet % 10) as usize;
+
+        if hundreds > 0 {
+            words.push(String::from(ORDINAL_HUNDREDS[hundreds - 1]));
+        }
+        match tens {
+            0 if units > 0 => words.push(String::from(ORDINAL_UNITS[units - 1])),
+            0 => (),
+            1 => words.push(String::from(ORDINAL_TEENS[units])),
+            _ => {
+                words.push(String::from(ORDINAL_TENS[tens - 1]));
+                if units > 0 {
+                    words.push(String::from(ORDINAL_UNITS[units - 1]));
+                }
+            }
+        }
+
+        words
+    }
+}
+
+impl Language for Spanish {
+    fn to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num.is_inf_pos() {
+            Ok(String::from("infinito"))
+        } else if num.is_inf_neg() {
+            Ok(String::from("menos infinito"))
+        } else if num.frac().is_zero() {
+            self.int_to_cardinal(num)
+        } else {
+            self.float_to_cardinal(num)
+        }
+    }
+
+    fn to_ordinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        // there is no ordinal of zero, see `Lang::Spanish`
+        if num.is_zero() {
+            return Err(Num2Err::CannotConvert);
+        }
+
+        let triplets = self.split_thousands(num);
+        let mut words = vec![];
+        // groups of six digits above the thousands, like the cardinal
+        for i in (1..triplets.len().div_ceil(2)).rev() {
+            let group = triplets.get(2 * i + 1).copied().unwrap_or(0) * 1000 + triplets[2 * i];
+            if group == 0 {
+                continue;
+            }
+            if i > MEGAS.len() {
+                return Err(Num2Err::CannotConvert);
+            }
+            words.push(format!(
+                "{}{}ésimo",
+                self.fused_cardinal(group),
+                MEGAS[i - 1].replace('ó', "o")
+            ));
+        }
+        if let Some(thousands) = triplets.get(1).filter(|t| **t != 0) {
+            words.push(format!("{}milésimo", self.fused_cardinal(*thousands)));
+        }
+        words.extend(self.triplet_to_ordinal(triplets[0]));
+
+        if self.feminine {
+            words = words
+                .into_iter()
+                .map(|w| format!("{}a", w.strip_suffix('o').unwrap()))
+                .collect();
+        }
+
+        Ok(words.join(" "))
+    }
+
+    fn to_ordinal_num(&self, num: BigFloat) -> Result<String, Num2Err> {
+        Ok(format!(
+            "{}.{}",
+            num.to_u128().unwrap(),
+            if self.feminine { "ª" } else { "º" }
+        ))
+    }
+
+    fn to_year(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num.is_negative() {
+            Ok(format!("{} a. C.", self.int_to_words(-num, One::Full)?))
+        } else {
+            self.int_to_words(num, One::Full)
+        }
+    }
+
+    fn to_currency(&self, num: BigFloat, currency: Currency) -> Result<String, Num2Err> {
+        let feminine = self.feminine_currency(currency);
+        if num.is_inf() {
+            Ok(format!(
+                "{}una infinidad de {}",
+                if num.is_negative() { "menos " } else { "" },
+                self.currencies(currency, true)
+            ))
+        } else if num.frac().is_zero() {
+            self.with_noun(
+                num,
+                &self.currencies(currency, self.plural_category(num) != PluralCategory::One),
+                feminine,
+            )
+        } else {
+            let integral_part = num.int();
+            let cents_nb = (num * BigFloat::from(100)).int() % BigFloat::from(100);
+            let cents_words = self.with_noun(
+                cents_nb,
+                &self.cents(
+                    currency,
+                    self.plural_category(cents_nb) != PluralCategory::One,
+                ),
+                false,
+            )?;
+            let integral_word = self.to_currency(integral_part, currency)?;
+
+            if cents_nb.is_zero() {
+                Ok(integral_word)
+            } else if integral_part.is_zero() && !self.zero_major {
+                Ok(cents_words)
+            } else {
+                Ok(format!("{} con {}", integral_word, cents_words))
+            }
+        }
+    }
+
+    fn to_percent(&self, num: BigFloat) -> Result<String, Num2Err> {
+        Ok(format!("{} por ciento", self.to_cardinal(num)?))
+    }
+
+    fn to_unit(&self, num: BigFloat, unit: Unit) -> Result<String, Num2Err> {
+        let noun = self.units(unit, self.plural_category(num) != PluralCategory::One);
+        if num.is_inf() {
+            Ok(format!(
+                "{}una infinidad de {}",
+                if num.is_negative() { "menos " } else { "" },
+                self.units(unit, true)
+            ))
+        } else {
+            self.with_noun(num, &noun, matches!(unit, Unit::Hour))
+        }
+    }
+
+    fn plural_category(&self, num: BigFloat) -> PluralCategory {
+        if num.abs() == BigFloat::from(1) {
+            PluralCategory::One
+        } else {
+            PluralCategory::Other
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_cardinal() {
+        assert_eq!(
+            Num2Words::new(0).lang(Lang::Spanish).to_words(),
+            Ok(String::from("cero"))
+        );
+        assert_eq!(
+            Num2Words::new(16).lang(Lang::Spanish).to_words(),
+            Ok(String::from("dieciséis"))
+        );
+        assert_eq!(
+            Num2Words::new(21).lang(Lang::Spanish).to_words(),
+            Ok(String::from("veintiuno"))
+        );
+        assert_eq!(
+            Num2Words::new(42).lang(Lang::Spanish).to_words(),
+            Ok(String::from("cuarenta y dos"))
+        );
+        assert_eq!(
+            Num2Words::new(100).lang(Lang::Spanish).to_words(),
+            Ok(String::from("cien"))
+        );
+        assert_eq!(
+            Num2Words::new(101).lang(Lang::Spanish).to_words(),
+            Ok(String::from("ciento uno"))
+        );
+        assert_eq!(
+            Num2Words::new(21_000).lang(Lang::Spanish).to_words(),
+            Ok(String::from("veintiún mil"))
+        );
+        assert_eq!(
+            Num2Words::new(100_000).lang(Lang::Spanish).to_words(),
+            Ok(String::from("cien mil"))
+        );
+        assert_eq!(
+            Num2Words::new(1_000_000).lang(Lang::Spanish).to_words(),
+            Ok(String::from("un millón"))
+        );
+        assert_eq!(
+            Num2Words::new(31_000_000_000i64)
+                .lang(Lang::Spanish)
+                .to_words(),
+            Ok(String::from("treinta y un mil millones"))
+        );
+        assert_eq!(
+            Num2Words::new(2_000_000_000_001i64)
+                .lang(Lang::Spanish)
+                .to_words(),
+            Ok(String::from("dos billones uno"))
+        );
+        assert_eq!(
+            Num2Words::new(-3.5).lang(Lang::Spanish).to_words(),
+            Ok(String::from("menos tres coma cinco"))
+        );
+        assert_eq!(
+            Num2Words::new(0.5).lang(Lang::Spanish).to_words(),
+            Ok(String::from("cero coma cinco"))
+        );
+        assert_eq!(
+            Num2Words::new(-0.25).lang(Lang::Spanish).to_words(),
+            Ok(String::from("menos cero coma dos cinco"))
+        );
+    }
+
+    #[test]
+    fn test_feminine() {
+        assert_eq!(
+            Num2Words::new(21)
+                .lang(Lang::Spanish)
+                .prefer("f")
+                .to_words(),
+            Ok(String::from("veintiuna"))
+        );
+        assert_eq!(
+            Num2Words::new(241)
+                .lang(Lang::Spanish)
+                .prefer("femenino")
+                .to_words(),
+            Ok(String::from("doscientas cuarenta y una"))
+        );
+        assert_eq!(
+            Num2Words::new(200_000)
+                .lang(Lang::Spanish)
+                .prefer("f")
+                .to_words(),
+            Ok(String::from("doscientas mil"))
+        );
+        assert_eq!(
+            Num2Words::new(200_000_000)
+                .lang(Lang::Spanish)
+                .prefer("f")
+                .to_words(),
+            Ok(String::from("doscientos millones"))
+        );
+    }
+
+    #[test]
+    fn test_ordinal() {
+        assert_eq!(
+            Num2Words::new(1).lang(Lang::Spanish).ordinal().to_words(),
+            Ok(String::from("primero"))
+        );
+        assert_eq!(
+            Num2Words::new(13).lang(Lang::Spanish).ordinal().to_words(),
+            Ok(String::from("decimotercero"))
+        );
+        assert_eq!(
+            Num2Words::new(42).lang(Lang::Spanish).ordinal().to_words(),
+            Ok(String::from("cuadragésimo segundo"))
+        );
+        assert_eq!(
+            Num2Words::new(42)
+                .lang(Lang::Spanish)
+                .ordinal()
+                .prefer("f")
+                .to_words(),
+            Ok(String::from("cuadragésima segunda"))
+        );
+        assert_eq!(
+            Num2Words::new(2024)
+                .lang(Lang::Spanish)
+                .ordinal()
+                .to_words(),
+            Ok(String::from("dosmilésimo vigésimo cuarto"))
+        );
+        assert_eq!(
+            Num2Words::new(1_000_000)
+                .lang(Lang::Spanish)
+                .ordinal()
+                .to_words(),
+            Ok(String::from("millonésimo"))
+        );
+        assert_eq!(
+            Num2Words::new(1_000_000_000)
+                .lang(Lang::Spanish)
+                .ordinal()
+                .to_words(),
+            Ok(String::from("milmillonésimo"))
+        );
+        assert_eq!(
+            Num2Words::new(2_003_000_000_000_u64)
+                .lang(Lang::Spanish)
+                .ordinal()
+                .to_words(),
+            Ok(String::from("dosbillonésimo tresmilmillonésimo"))
+        );
+        assert_eq!(
+            Num2Words::new(1_000_000_000_001_u64)
+                .lang(Lang::Spanish)
+                .ordinal()
+                .to_words(),
+            Ok(String::from("billonésimo primero"))
+        );
+        assert_eq!(
+            Num2Words::new(0).lang(Lang::Spanish).ordinal().to_words(),
+            Err(Num2Err::CannotConvert)
+        );
+        assert_eq!(
+            Num2Words::new(42)
+                .lang(Lang::Spanish)
+                .ordinal_num()
+                .to_words(),
+            Ok(String::from("42.º"))
+        );
+        assert_eq!(
+            Num2Words::new(42)
+                .lang(Lang::Spanish)
+                .ordinal_num()
+                .prefer("f")
+                .to_words(),
+            Ok(String::from("42.ª"))
+        );
+    }
+
+    #[test]
+    fn test_year() {
+        assert_eq!(
+            Num2Words::new(2021).lang(Lang::Spanish).year().to_words(),
+            Ok(String::from("dos mil veintiuno"))
+        );
+        assert_eq!(
+            Num2Words::new(-44).lang(Lang::Spanish).year().to_words(),
+            Ok(String::from("cuarenta y cuatro a. C."))
+        );
+    }
+
+    #[test]
+    fn test_currency() {
+        assert_eq!(
+            Num2Words::new(1)
+                .lang(Lang::Spanish)
+                .currency(Currency::PESO)
+                .to_words(),
+            Ok(String::from("un peso"))
+        );
+        assert_eq!(
+            Num2Words::new(21)
+                .lang(Lang::Spanish)
+                .currency(Currency::MXN)
+                .to_words(),
+            Ok(String::from("veintiún pesos mexicanos"))
+        );
+        assert_eq!(
+            Num2Words::new(42.01)
+                .lang(Lang::Spanish)
+                .currency(Currency::PESO)
+                .to_words(),
+            Ok(String::from("cuarenta y dos pesos con un centavo"))
+        );
+        assert_eq!(
+            Num2Words::new(21)
+                .lang(Lang::Spanish)
+                .currency(Currency::GBP)
+                .to_words(),
+            Ok(String::from("veintiuna libras esterlinas"))
+        );
+        assert_eq!(
+            Num2Words::new(1_000_000)
+                .lang(Lang::Spanish)
+                .currency(Currency::EUR)
+                .to_words(),
+            Ok(String::from("un millón de euros"))
+        );
+        assert_eq!(
+            Num2Words::new(0.2)
+                .lang(Lang::Spanish)
+                .currency(Currency::EUR)
+                .to_words(),
+            Ok(String::from("veinte céntimos"))
+        );
+        assert_eq!(
+            Num2Words::new(0.2)
+                .lang(Lang::Spanish)
+                .currency(Currency::EUR)
+                .prefer("zero-major")
+                .to_words(),
+            Ok(String::from("cero euros con veinte céntimos"))
+        );
+    }
+
+    #[test]
+    fn test_unit() {
+        assert_eq!(
+            Num2Words::new(1)
+                .lang(Lang::Spanish)
+                .unit(Unit::Hour)
+                .to_words(),
+            Ok(String::from("una hora"))
+        );
+        assert_eq!(
+            Num2Words::new(31)
+                .lang(Lang::Spanish)
+                .unit(Unit::Kilometer)
+                .to_words(),
+            Ok(String::from("treinta y un kilómetros"))
+        );
+    }
+}
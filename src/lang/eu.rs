@@ -0,0 +1,414 @@
+use crate::{
+    num2words::Num2Err, Agreement, Currency, GrammaticalNumber, Language, PluralCategory, Unit,
+};
+use num_bigfloat::BigFloat;
+
+pub struct Basque {
+    zero_major: bool,
+}
+
+const UNITS: [&str; 20] = [
+    "zero",
+    "bat",
+    "bi",
+    "hiru",
+    "lau",
+    "bost",
+    "sei",
+    "zazpi",
+    "zortzi",
+    "bederatzi",
+    "hamar",
+    "hamaika",
+    "hamabi",
+    "hamahiru",
+    "hamalau",
+    "hamabost",
+    "hamasei",
+    "hamazazpi",
+    "hemezortzi",
+    "hemeretzi",
+];
+
+// multiples of twenty, the base of the numbers below a hundred
+const TWENTIES: [&str; 4] = ["hogei", "berrogei", "hirurogei", "laurogei"];
+
+const HUNDREDS: [&str; 9] = [
+    "ehun",
+    "berrehun",
+    "hirurehun",
+    "laurehun",
+    "bostehun",
+    "seiehun",
+    "zazpiehun",
+    "zortziehun",
+    "bederatziehun",
+];
+
+// powers of a million
+const MEGAS: [&str; 4] = ["milioi", "bilioi", "trilioi", "koatrilioi"];
+
+impl Basque {
+    pub fn new() -> Self {
+        Self { zero_major: false }
+    }
+
+    /// Keeps the major unit clause for amounts below one, e.g. `zero euro eta
+    /// hogei zentimo`
+    pub fn zero_major(self, zero_major: bool) -> Self {
+        Self { zero_major }
+    }
+
+    fn currencies(&self, currency: Currency) -> String {
+        match currency {
+            Currency::EUR => String::from("euro"),
+            Currency::AUD
+            | Currency::CAD
+            | Currency::DOLLAR
+            | Currency::HKD
+            | Currency::NZD
+            | Currency::SGD
+            | Currency::TWD
+            | Currency::USD => String::from("dolar"),
+            Currency::GBP => String::from("libera"),
+            Currency::CHF => String::from("franko"),
+            Currency::JPY => String::from("yen"),
+            _ => currency.default_string(false),
+        }
+    }
+
+    fn cents(&self, currency: Currency) -> &'static str {
+        match currency {
+            Currency::GBP => "penny",
+            Currency::EUR => "zentimo",
+            _ => "zentabo",
+        }
+    }
+
+    fn units(&self, unit: Unit) -> &'static str {
+        match unit {
+            Unit::Meter => "metro",
+            Unit::Kilometer => "kilometro",
+            Unit::Gram => "gramo",
+            Unit::Kilogram => "kilogramo",
+            Unit::Liter => "litro",
+            Unit::Second => "segundo",
+            Unit::Minute => "minutu",
+            Unit::Hour => "ordu",
+        }
+    }
+
+    fn split_millions(&self, mut num: BigFloat) -> Vec<u64> {
+        let mut millions = Vec::new();
+        let bf_1000000 = BigFloat::from(1000000);
+
+        while !num.is_zero() {
+            millions.push((num % bf_1000000).to_u64().unwrap());
+            num /= bf_1000000;
+        }
+
+        millions
+    }
+
+    // numbers below a hundred are counted in twenties, e.g. "berrogeita bi"
+    // is two twenties and two
+    fn below_hundred(&self, num: u64) -> String {
+        let twenties = (num / 20) as usize;
+        let rest = (num % 20) as usize;
+
+        match (twenties, rest) {
+            (0, _) => String::from(UNITS[rest]),
+            (_, 0) => String::from(TWENTIES[twenties - 1]),
+            _ => format!("{}ta {}", TWENTIES[twenties - 1], UNITS[rest]),
+        }
+    }
+
+    // hundreds and what follows them, e.g. ["ehun", "hogeita bat"]
+    fn below_thousand(&self, num: u64) -> Vec<String> {
+        let mut chunks = vec![];
+        if num >= 100 {
+            chunks.push(String::from(HUNDREDS[(num / 100) as usize - 1]));
+        }
+        if !num.is_multiple_of(100) {
+            chunks.push(self.below_hundred(num % 100));
+        }
+        chunks
+    }
+
+    fn below_million(&self, num: u64) -> Vec<String> {
+        let mut chunks = vec![];
+        match num / 1000 {
+            0 => (),
+            // "mila" rather than "bat mila"
+            1 => chunks.push(String::from("mila")),
+            thousands => chunks.push(format!("{} mila", with_eta(self.below_thousand(thousands)))),
+        }
+        chunks.extend(self.below_thousand(num % 1000));
+        chunks
+    }
+
+    fn int_to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        // special case zero
+        if num.is_zero() {
+            return Ok(String::from(UNITS[0]));
+        }
+
+        let mut chunks = vec![];
+        for (i, group) in self.split_millions(num.abs()).iter().enumerate().rev() {
+            if *group == 0 {
+                continue;
+            }
+            if i == 0 {
+                chunks.extend(self.below_million(*group));
+                continue;
+            }
+
+            let mega = MEGAS.get(i - 1).ok_or(Num2Err::CannotConvert)?;
+            // one follows what it counts, e.g. "milioi bat"
+            if *group == 1 {
+                chunks.push(format!("{} bat", mega));
+            } else {
+                chunks.push(format!("{} {}", with_eta(self.below_million(*group)), mega));
+            }
+        }
+
+        let words = with_eta(chunks);
+        if num.is_negative() {
+            Ok(format!("minus {}", words))
+        } else {
+            Ok(words)
+        }
+    }
+
+    fn float_to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let mut words = vec![];
+        if num.is_negative() {
+            words.push(String::from("minus"));
+        }
+        words.push(self.int_to_cardinal(num.int().abs())?);
+        words.push(String::from("koma"));
+
+        let mut ordinal_part = num.frac().abs();
+        while !ordinal_part.is_zero() {
+            let digit = (ordinal_part * BigFloat::from(10)).int();
+            ordinal_part = (ordinal_part * BigFloat::from(10)).frac();
+            words.push(self.int_to_cardinal(digit)?);
+        }
+
+        Ok(words.join(" "))
+    }
+
+    // one alone follows the noun it counts, e.g. "euro bat" but "bi euro"
+    fn with_noun(&self, num: BigFloat, noun: &str) -> Result<String, Num2Err> {
+        if num == BigFloat::from(1) {
+            Ok(format!("{} bat", noun))
+        } else {
+            Ok(format!("{} {}", self.to_cardinal(num)?, noun))
+        }
+    }
+}
+
+// joins chunks of a number, the last one being introduced by "eta", e.g.
+// "mila berrehun eta hogeita hamalau"
+fn with_eta(mut chunks: Vec<String>) -> String {
+    match chunks.pop() {
+        Some(last) if !chunks.is_empty() => format!("{} eta {}", chunks.join(" "), last),
+        Some(last) => last,
+        None => String::new(),
+    }
+}
+
+impl Language for Basque {
+    fn to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num.is_inf_pos() {
+            Ok(String::from("infinitu"))
+        } else if num.is_inf_neg() {
+            Ok(String::from("minus infinitu"))
+        } else if num.frac().is_zero() {
+            self.int_to_cardinal(num)
+        } else {
+            self.float_to_cardinal(num)
+        }
+    }
+
+    fn to_ordinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        // only the first has its own word, e.g. "hogeita batgarren" for 21st
+        if num == BigFloat::from(1) {
+            return Ok(String::from("lehen"));
+        }
+
+        let cardinal_word = self.int_to_cardinal(num)?;
+        // a lone mega drops the "bat" that follows it, e.g. "milioigarren"
+        let cardinal_word = match cardinal_word.strip_suffix(" bat") {
+            Some(rest) if MEGAS.iter().any(|mega| rest.ends_with(mega)) => String::from(rest),
+            _ => cardinal_word,
+        };
+        let (rest, last) = match cardinal_word.rsplit_once(' ') {
+            Some((rest, last)) => (format!("{} ", rest), last),
+            None => (String::new(), cardinal_word.as_str()),
+        };
+        let stem = if last == "bost" { "bos" } else { last };
+        Ok(format!("{}{}garren", rest, stem))
+    }
+
+    fn to_ordinal_num(&self, num: BigFloat) -> Result<String, Num2Err> {
+        Ok(format!("{}.", num.to_u128().unwrap()))
+    }
+
+    fn to_year(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if !num.frac().is_zero() {
+            return Err(Num2Err::FloatingYear);
+        }
+
+        let year_word = self.int_to_cardinal(num.abs())?;
+        if num.is_negative() {
+            Ok(format!("K.a. {}", year_word))
+        } else {
+            Ok(year_word)
+        }
+    }
+
+    fn to_currency(&self, num: BigFloat, currency: Currency) -> Result<String, Num2Err> {
+        if num.is_inf() || num.frac().is_zero() {
+            return self.with_noun(num, &self.currencies(currency));
+        }
+
+        let integral_part = num.int();
+        let cents_nb = (num * BigFloat::from(100)).int() % BigFloat::from(100);
+        let cents_words = self.with_noun(cents_nb, self.cents(currency))?;
+        let integral_word = self.to_currency(integral_part, currency)?;
+
+        if cents_nb.is_zero() {
+            Ok(integral_word)
+        } else if integral_part.is_zero() && !self.zero_major {
+            Ok(cents_words)
+        } else {
+            Ok(format!("{} eta {}", integral_word, cents_words))
+        }
+    }
+
+    fn to_percent(&self, num: BigFloat) -> Result<String, Num2Err> {
+        // the percent is read first, e.g. "ehuneko berrogeita bi"
+        Ok(format!("ehuneko {}", self.to_cardinal(num)?))
+    }
+
+    fn to_unit(&self, num: BigFloat, unit: Unit) -> Result<String, Num2Err> {
+        self.with_noun(num, self.units(unit))
+    }
+
+    fn plural_category(&self, num: BigFloat) -> PluralCategory {
+        if num.abs() == BigFloat::from(1) {
+            PluralCategory::One
+        } else {
+            PluralCategory::Other
+        }
+    }
+
+    fn agreement(&self, num: BigFloat) -> Agreement {
+        Agreement {
+            number: GrammaticalNumber::Singular,
+            ..Agreement::from_category(self.plural_category(num))
+        }
+    }
+
+    fn negative_marker(&self) -> &'static str {
+        "minus"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Currency, Lang, Num2Words, Unit};
+
+    #[test]
+    fn test_cardinal() {
+        for (num, words) in [
+            (0, "zero"),
+            (11, "hamaika"),
+            (20, "hogei"),
+            (21, "hogeita bat"),
+            (30, "hogeita hamar"),
+            (40, "berrogei"),
+            (42, "berrogeita bi"),
+            (60, "hirurogei"),
+            (75, "hirurogeita hamabost"),
+            (80, "laurogei"),
+            (99, "laurogeita hemeretzi"),
+            (100, "ehun"),
+            (101, "ehun eta bat"),
+            (1000, "mila"),
+            (1100, "mila eta ehun"),
+            (1234, "mila berrehun eta hogeita hamalau"),
+            (2023, "bi mila eta hogeita hiru"),
+            (123000, "ehun eta hogeita hiru mila"),
+            (1000000, "milioi bat"),
+            (2001000, "bi milioi eta mila"),
+            (-5, "minus bost"),
+        ] {
+            assert_eq!(
+                Num2Words::new(num).lang(Lang::Basque).to_words(),
+                Ok(String::from(words))
+            );
+        }
+        assert_eq!(
+            Num2Words::new(42.5).lang(Lang::Basque).to_words(),
+            Ok(String::from("berrogeita bi koma bost"))
+        );
+    }
+
+    #[test]
+    fn test_ordinal() {
+        for (num, words) in [
+            (1, "lehen"),
+            (2, "bigarren"),
+            (5, "bosgarren"),
+            (21, "hogeita batgarren"),
+            (42, "berrogeita bigarren"),
+            (100, "ehungarren"),
+            (1_000_000, "milioigarren"),
+            (3_000_000, "hiru milioigarren"),
+            (1_000_001, "milioi bat eta batgarren"),
+        ] {
+            assert_eq!(
+                Num2Words::new(num).lang(Lang::Basque).ordinal().to_words(),
+                Ok(String::from(words))
+            );
+        }
+        assert_eq!(
+            Num2Words::new(42)
+                .lang(Lang::Basque)
+                .ordinal_num()
+                .to_words(),
+            Ok(String::from("42."))
+        );
+    }
+
+    #[test]
+    fn test_currency() {
+        for (num, words) in [
+            (1.0, "euro bat"),
+            (42.0, "berrogeita bi euro"),
+            (42.5, "berrogeita bi euro eta berrogeita hamar zentimo"),
+            (0.01, "zentimo bat"),
+        ] {
+            assert_eq!(
+                Num2Words::new(num)
+                    .lang(Lang::Basque)
+                    .currency(Currency::EUR)
+                    .to_words(),
+                Ok(String::from(words))
+            );
+        }
+        assert_eq!(
+            Num2Words::new(42).lang(Lang::Basque).percent().to_words(),
+            Ok(String::from("ehuneko berrogeita bi"))
+        );
+        assert_eq!(
+            Num2Words::new(1)
+                .lang(Lang::Basque)
+                .unit(Unit::Hour)
+                .to_words(),
+            Ok(String::from("ordu bat"))
+        );
+    }
+}
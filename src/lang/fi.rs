@@ -0,0 +1,712 @@
+use crate::{num2words::Num2Err, Agreement, Currency, Declension, GrammaticalNumber, Language};
+use crate::{PluralCategory, Unit};
+use num_bigfloat::BigFloat;
+
+// Finnish numerals are written as one word up to the millions, e.g.
+// "kaksituhattaneljäkymmentäkaksi", and every element takes the case of the
+// whole number, e.g. the partitive "kahtatuhattaneljääkymmentäkahta". Only the
+// nominative, the genitive and the partitive are supported.
+//
+// A multiplied power takes the partitive singular in the nominative, e.g.
+// "kaksikymmentä", and counted nouns do the same, e.g. "kaksi euroa".
+
+#[derive(Clone, Copy)]
+pub struct Finnish {
+    declension: Declension,
+    zero_major: bool,
+}
+
+/// Parses a Finnish (nominatiivi, genetiivi, partitiivi) or generic case
+/// preference
+pub(crate) fn declension_from_str(s: &str) -> Option<Declension> {
+    match s.to_lowercase().as_str() {
+        "nominative" | "nom" | "nominatiivi" => Some(Declension::Nominative),
+        "genitive" | "gen" | "genetiivi" => Some(Declension::Genitive),
+        "partitive" | "part" | "partitiivi" => Some(Declension::Partitive),
+        _ => None,
+    }
+}
+
+// every table below is ordered as nominative, genitive, partitive
+
+#[rustfmt::skip]
+const UNITS: [[&str; 3]; 9] = [
+    [ "yksi",      "yhden",     "yhtä"      ],
+    [ "kaksi",     "kahden",    "kahta"     ],
+    [ "kolme",     "kolmen",    "kolmea"    ],
+    [ "neljä",     "neljän",    "neljää"    ],
+    [ "viisi",     "viiden",    "viittä"    ],
+    [ "kuusi",     "kuuden",    "kuutta"    ],
+    [ "seitsemän", "seitsemän", "seitsemää" ],
+    [ "kahdeksan", "kahdeksan", "kahdeksaa" ],
+    [ "yhdeksän",  "yhdeksän",  "yhdeksää"  ],
+];
+
+const ZERO: [&str; 3] = ["nolla", "nollan", "nollaa"];
+const INFINITY: [&str; 3] = ["ääretön", "äärettömän", "ääretöntä"];
+
+// a power on its own, then multiplied, e.g. "sata" but "kaksisataa"
+const TEN: [[&str; 3]; 2] = [
+    ["kymmenen", "kymmenen", "kymmentä"],
+    ["kymmentä", "kymmenen", "kymmentä"],
+];
+const HUNDRED: [[&str; 3]; 2] = [["sata", "sadan", "sataa"], ["sataa", "sadan", "sataa"]];
+const THOUSAND: [[&str; 3]; 2] = [
+    ["tuhat", "tuhannen", "tuhatta"],
+    ["tuhatta", "tuhannen", "tuhatta"],
+];
+
+// long scale, as used in Finnish
+const MEGAS: [[[&str; 3]; 2]; 8] = [
+    [
+        ["miljoona", "miljoonan", "miljoonaa"],
+        ["miljoonaa", "miljoonan", "miljoonaa"],
+    ],
+    [
+        ["miljardi", "miljardin", "miljardia"],
+        ["miljardia", "miljardin", "miljardia"],
+    ],
+    [
+        ["biljoona", "biljoonan", "biljoonaa"],
+        ["biljoonaa", "biljoonan", "biljoonaa"],
+    ],
+    [
+        ["biljardi", "biljardin", "biljardia"],
+        ["biljardia", "biljardin", "biljardia"],
+    ],
+    [
+        ["triljoona", "triljoonan", "triljoonaa"],
+        ["triljoonaa", "triljoonan", "triljoonaa"],
+    ],
+    [
+        ["triljardi", "triljardin", "triljardia"],
+        ["triljardia", "triljardin", "triljardia"],
+    ],
+    [
+        ["kvadriljoona", "kvadriljoonan", "kvadriljoonaa"],
+        ["kvadriljoonaa", "kvadriljoonan", "kvadriljoonaa"],
+    ],
+    [
+        ["kvadriljardi", "kvadriljardin", "kvadriljardia"],
+        ["kvadriljardia", "kvadriljardin", "kvadriljardia"],
+    ],
+];
+
+// "ensimmäinen" and "toinen" end a number, but become "yhdes" and "kahdes"
+// inside of it, e.g. "kahdestoista" or "kahdeskymmenesensimmäinen"
+#[rustfmt::skip]
+const ORDINAL_UNITS: [[&str; 3]; 9] = [
+    [ "yhdes",      "yhdennen",      "yhdettä"      ],
+    [ "kahdes",     "kahdennen",     "kahdetta"     ],
+    [ "kolmas",     "kolmannen",     "kolmatta"     ],
+    [ "neljäs",     "neljännen",     "neljättä"     ],
+    [ "viides",     "viidennen",     "viidettä"     ],
+    [ "kuudes",     "kuudennen",     "kuudetta"     ],
+    [ "seitsemäs",  "seitsemännen",  "seitsemättä"  ],
+    [ "kahdeksas",  "kahdeksannen",  "kahdeksatta"  ],
+    [ "yhdeksäs",   "yhdeksännen",   "yhdeksättä"   ],
+];
+const FIRST: [&str; 3] = ["ensimmäinen", "ensimmäisen", "ensimmäistä"];
+const SECOND: [&str; 3] = ["toinen", "toisen", "toista"];
+
+const ORDINAL_ZERO: [&str; 3] = ["nollas", "nollannen", "nollatta"];
+const ORDINAL_TEN: [&str; 3] = ["kymmenes", "kymmenennen", "kymmenettä"];
+const ORDINAL_HUNDRED: [&str; 3] = ["sadas", "sadannen", "sadatta"];
+const ORDINAL_THOUSAND: [&str; 3] = ["tuhannes", "tuhannennen", "tuhannetta"];
+#[rustfmt::skip]
+const ORDINAL_MEGAS: [[&str; 3]; 8] = [
+    [ "miljoonas",     "miljoonannen",     "miljoonatta"     ],
+    [ "miljardis",     "miljardinnen",     "miljarditta"     ],
+    [ "biljoonas",     "biljoonannen",     "biljoonatta"     ],
+    [ "biljardis",     "biljardinnen",     "biljarditta"     ],
+    [ "triljoonas",    "triljoonannen",    "triljoonatta"    ],
+    [ "triljardis",    "triljardinnen",    "triljarditta"    ],
+    [ "kvadriljoonas", "kvadriljoonannen", "kvadriljoonatta" ],
+    [ "kvadriljardis", "kvadriljardinnen", "kvadriljarditta" ],
+];
+
+// nouns are counted in the singular, with the partitive plural only used
+// after "äärettömästi", e.g. "äärettömästi euroja"
+// [nominative, genitive, partitive, partitive plural]
+const EURO: [&str; 4] = ["euro", "euron", "euroa", "euroja"];
+const SENTTI: [&str; 4] = ["sentti", "sentin", "senttiä", "senttejä"];
+const DOLLARI: [&str; 4] = ["dollari", "dollarin", "dollaria", "dollareita"];
+const PUNTA: [&str; 4] = ["punta", "punnan", "puntaa", "puntia"];
+const KRUUNU: [&str; 4] = ["kruunu", "kruunun", "kruunua", "kruunuja"];
+const FRANGI: [&str; 4] = ["frangi", "frangin", "frangia", "frangeja"];
+const PROSENTTI: [&str; 4] = ["prosentti", "prosentin", "prosenttia", "prosentteja"];
+const METRI: [&str; 4] = ["metri", "metrin", "metriä", "metrejä"];
+const GRAMMA: [&str; 4] = ["gramma", "gramman", "grammaa", "grammoja"];
+const LITRA: [&str; 4] = ["litra", "litran", "litraa", "litroja"];
+const SEKUNTI: [&str; 4] = ["sekunti", "sekunnin", "sekuntia", "sekunteja"];
+const MINUUTTI: [&str; 4] = ["minuutti", "minuutin", "minuuttia", "minuutteja"];
+const TUNTI: [&str; 4] = ["tunti", "tunnin", "tuntia", "tunteja"];
+
+impl Finnish {
+    pub fn new(declension: Declension) -> Self {
+        Self {
+            declension,
+            zero_major: false,
+        }
+    }
+
+    /// Keeps the major unit clause for amounts below one, e.g. `nolla euroa
+    /// ja kaksikymmentä senttiä`
+    pub fn zero_major(self, zero_major: bool) -> Self {
+        Self { zero_major, ..self }
+    }
+
+    fn case_index(&self) -> usize {
+        match self.declension {
+            Declension::Genitive => 1,
+            Declension::Partitive => 2,
+            _ => 0,
+        }
+    }
+
+    // case of a noun counted by `num`: the partitive after any number but one
+    // in the nominative, e.g. "kaksi euroa" but "kahden euron"
+    fn noun_declension(&self, num: BigFloat) -> Declension {
+        match self.declension {
+            Declension::Genitive | Declension::Partitive => self.declension,
+            _ if self.plural_category(num) == PluralCategory::One => Declension::Nominative,
+            _ => Declension::Partitive,
+        }
+    }
+
+    fn noun_form(&self, table: &[&'static str; 4], num: BigFloat) -> &'static str {
+        if num.is_inf() {
+            return table[3];
+        }
+        match self.noun_declension(num) {
+            Declension::Genitive => table[1],
+            Declension::Partitive => table[2],
+            _ => table[0],
+        }
+    }
+
+    fn currencies(&self, currency: Currency, num: BigFloat) -> String {
+        let noun = |table| String::from(self.noun_form(table, num));
+        match currency {
+            Currency::EUR => noun(&EURO),
+            Currency::USD => format!("Yhdysvaltain {}", noun(&DOLLARI)),
+            Currency::AUD
+            | Currency::CAD
+            | Currency::DOLLAR
+            | Currency::HKD
+            | Currency::NZD
+            | Currency::SGD
+            | Currency::TWD => noun(&DOLLARI),
+            Currency::GBP => noun(&PUNTA),
+            Currency::CZK | Currency::ISK | Currency::NOK => noun(&KRUUNU),
+            Currency::CHF => noun(&FRANGI),
+            _ => currency.default_string(self.plural_category(num) != PluralCategory::One),
+        }
+    }
+
+    fn units(&self, unit: Unit, num: BigFloat) -> String {
+        let noun = |table| String::from(self.noun_form(table, num));
+        match unit {
+            Unit::Meter => noun(&METRI),
+            Unit::Kilometer => format!("kilo{}", noun(&METRI)),
+            Unit::Gram => noun(&GRAMMA),
+            Unit::Kilogram => format!("kilo{}", noun(&GRAMMA)),
+            Unit::Liter => noun(&LITRA),
+            Unit::Second => noun(&SEKUNTI),
+            Unit::Minute => noun(&MINUUTTI),
+            Unit::Hour => noun(&TUNTI),
+        }
+    }
+
+    fn split_thousands(&self, mut num: BigFloat) -> Vec<u64> {
+        let mut thousands = Vec::new();
+        let bf_1000 = BigFloat::from(1000);
+
+        while !num.is_zero() {
+            thousands.push((num % bf_1000).to_u64().unwrap());
+            num /= bf_1000;
+        }
+
+        thousands
+    }
+
+    fn below_thousand(&self, num: u64) -> String {
+        let case = self.case_index();
+        let hundreds = (num / 100) as usize;
+        let tens = (num / 10 % 10) as usize;
+        let units = (num % 10) as usize;
+        let mut word = String::new();
+
+        match hundreds {
+            0 => (),
+            1 => word.push_str(HUNDRED[0][case]),
+            _ => {
+                word.push_str(UNITS[hundreds - 1][case]);
+                word.push_str(HUNDRED[1][case]);
+            }
+        }
+
+        match (tens, units) {
+            (0, 0) => (),
+            (0, _) => word.push_str(UNITS[units - 1][case]),
+            (1, 0) => word.push_str(TEN[0][case]),
+            (1, _) => {
+                word.push_str(UNITS[units - 1][case]);
+                word.push_str("toista");
+            }
+            _ => {
+                word.push_str(UNITS[tens - 1][case]);
+                word.push_str(TEN[1][case]);
+                if units > 0 {
+                    word.push_str(UNITS[units - 1][case]);
+                }
+            }
+        }
+
+        word
+    }
+
+    fn int_to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let case = self.case_index();
+
+        // special case zero
+        if num.is_zero() {
+            return Ok(String::from(ZERO[case]));
+        }
+
+        let mut words = vec![];
+        // the thousands and what is below them make up a single word
+        let mut last_word = String::new();
+        for (i, triplet) in self.split_thousands(num.abs()).iter().enumerate().rev() {
+            if *triplet == 0 {
+                continue;
+            }
+
+            match i {
+                0 => last_word.push_str(&self.below_thousand(*triplet)),
+                1 if *triplet == 1 => last_word.push_str(THOUSAND[0][case]),
+                1 => {
+                    last_word.push_str(&self.below_thousand(*triplet));
+                    last_word.push_str(THOUSAND[1][case]);
+                }
+                _ => {
+                    let mega = MEGAS.get(i - 2).ok_or(Num2Err::CannotConvert)?;
+                    if *triplet == 1 {
+                        words.push(String::from(mega[0][case]));
+                    } else {
+                        words.push(self.below_thousand(*triplet));
+                        words.push(String::from(mega[1][case]));
+                    }
+                }
+            }
+        }
+        if !last_word.is_empty() {
+            words.push(last_word);
+        }
+
+        // handling negative values
+        if num.is_negative() {
+            words.insert(0, String::from("miinus"));
+        }
+
+        Ok(words.join(" "))
+    }
+
+    fn float_to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let mut words = vec![];
+        if num.is_negative() {
+            words.push(String::from("miinus"));
+        }
+        words.push(self.int_to_cardinal(num.int().abs())?);
+        words.push(String::from("pilkku"));
+
+        let mut ordinal_part = num.frac().abs();
+        while !ordinal_part.is_zero() {
+            let digit = (ordinal_part * BigFloat::from(10)).int();
+            ordinal_part = (ordinal_part * BigFloat::from(10)).frac();
+            words.push(self.int_to_cardinal(digit)?);
+        }
+
+        Ok(words.join(" "))
+    }
+
+    // `last` tells whether the units end the whole number
+    fn ordinal_below_thousand(&self, num: u64, last: bool) -> String {
+        let case = self.case_index();
+        let hundreds = (num / 100) as usize;
+        let tens = (num / 10 % 10) as usize;
+        let units = (num % 10) as usize;
+        let mut word = String::new();
+
+        match hundreds {
+            0 => (),
+            1 => word.push_str(ORDINAL_HUNDRED[case]),
+            _ => {
+                word.push_str(ORDINAL_UNITS[hundreds - 1][case]);
+                word.push_str(ORDINAL_HUNDRED[case]);
+            }
+        }
+
+        match (tens, units) {
+            (0, 0) => (),
+            (1, 0) => word.push_str(ORDINAL_TEN[case]),
+            (1, _) => {
+                word.push_str(ORDINAL_UNITS[units - 1][case]);
+                word.push_str("toista");
+            }
+            _ => {
+                if tens > 1 {
+                    word.push_str(ORDINAL_UNITS[tens - 1][case]);
+                    word.push_str(ORDINAL_TEN[case]);
+                }
+                match units {
+                    0 => (),
+                    1 if last => word.push_str(FIRST[case]),
+                    2 if last => word.push_str(SECOND[case]),
+                    _ => word.push_str(ORDINAL_UNITS[units - 1][case]),
+                }
+            }
+        }
+
+        word
+    }
+
+    fn noun_with_cardinal(&self, num: BigFloat, noun: String) -> Result<String, Num2Err> {
+        if num.is_inf() {
+            Ok(format!(
+                "{}äärettömästi {}",
+                if num.is_negative() { "miinus " } else { "" },
+                noun
+            ))
+        } else {
+            Ok(format!("{} {}", self.to_cardinal(num)?, noun))
+        }
+    }
+}
+
+impl Language for Finnish {
+    fn to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num.is_inf_pos() {
+            Ok(String::from(INFINITY[self.case_index()]))
+        } else if num.is_inf_neg() {
+            Ok(format!("miinus {}", INFINITY[self.case_index()]))
+        } else if num.frac().is_zero() {
+            self.int_to_cardinal(num)
+        } else {
+            self.float_to_cardinal(num)
+        }
+    }
+
+    fn to_ordinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let case = self.case_index();
+
+        if num.is_zero() {
+            return Ok(String::from(ORDINAL_ZERO[case]));
+        }
+
+        let mut words = vec![];
+        let mut last_word = String::new();
+        for (i, triplet) in self.split_thousands(num).iter().enumerate().rev() {
+            if *triplet == 0 {
+                continue;
+            }
+
+            match i {
+                0 => last_word.push_str(&self.ordinal_below_thousand(*triplet, true)),
+                1 => {
+                    if *triplet != 1 {
+                        last_word.push_str(&self.ordinal_below_thousand(*triplet, false));
+                    }
+                    last_word.push_str(ORDINAL_THOUSAND[case]);
+                }
+                _ => {
+                    let mega = ORDINAL_MEGAS.get(i - 2).ok_or(Num2Err::CannotConvert)?;
+                    if *triplet != 1 {
+                        words.push(self.ordinal_below_thousand(*triplet, false));
+                    }
+                    words.push(String::from(mega[case]));
+                }
+            }
+        }
+        if !last_word.is_empty() {
+            words.push(last_word);
+        }
+
+        Ok(words.join(" "))
+    }
+
+    fn to_ordinal_num(&self, num: BigFloat) -> Result<String, Num2Err> {
+        Ok(format!("{}.", num.to_u128().unwrap()))
+    }
+
+    fn to_year(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if !num.frac().is_zero() {
+            return Err(Num2Err::FloatingYear);
+        }
+
+        let year_word = self.int_to_cardinal(num.abs())?;
+        if num.is_negative() {
+            Ok(format!("{} ennen ajanlaskun alkua", year_word))
+        } else {
+            Ok(year_word)
+        }
+    }
+
+    fn to_currency(&self, num: BigFloat, currency: Currency) -> Result<String, Num2Err> {
+        if num.is_inf() || num.frac().is_zero() {
+            return self.noun_with_cardinal(num, self.currencies(currency, num));
+        }
+
+        let integral_part = num.int();
+        let cents_nb = (num * BigFloat::from(100)).int() % BigFloat::from(100);
+        let cents_words = format!(
+            "{} {}",
+            self.int_to_cardinal(cents_nb)?,
+            self.noun_form(&SENTTI, cents_nb)
+        );
+        let integral_word = self.to_currency(integral_part, currency)?;
+
+        if cents_nb.is_zero() {
+            Ok(integral_word)
+        } else if integral_part.is_zero() && !self.zero_major {
+            Ok(cents_words)
+        } else {
+            Ok(format!("{} ja {}", integral_word, cents_words))
+        }
+    }
+
+    fn to_percent(&self, num: BigFloat) -> Result<String, Num2Err> {
+        self.noun_with_cardinal(num, String::from(self.noun_form(&PROSENTTI, num)))
+    }
+
+    fn to_unit(&self, num: BigFloat, unit: Unit) -> Result<String, Num2Err> {
+        self.noun_with_cardinal(num, self.units(unit, num))
+    }
+
+    fn plural_category(&self, num: BigFloat) -> PluralCategory {
+        if num.abs() == BigFloat::from(1) {
+            PluralCategory::One
+        } else {
+            PluralCategory::Other
+        }
+    }
+
+    fn agreement(&self, num: BigFloat) -> Agreement {
+        // counted nouns stay in the singular, e.g. "kaksi euroa"
+        Agreement {
+            category: self.plural_category(num),
+            number: GrammaticalNumber::Singular,
+            gender: None,
+            case: Some(self.noun_declension(num)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn finnish(num: impl Into<num_bigfloat::BigFloat>, case: &str) -> Num2Words {
+        Num2Words::new(num).lang(Lang::Finnish).prefer(case)
+    }
+
+    #[test]
+    fn test_cardinal() {
+        for (num, words) in [
+            (0, "nolla"),
+            (1, "yksi"),
+            (10, "kymmenen"),
+            (11, "yksitoista"),
+            (20, "kaksikymmentä"),
+            (42, "neljäkymmentäkaksi"),
+            (100, "sata"),
+            (342, "kolmesataaneljäkymmentäkaksi"),
+            (1000, "tuhat"),
+            (2042, "kaksituhattaneljäkymmentäkaksi"),
+            (1_000_000, "miljoona"),
+            (2_300_005, "kaksi miljoonaa kolmesataatuhattaviisi"),
+            (-17, "miinus seitsemäntoista"),
+        ] {
+            assert_eq!(
+                Num2Words::new(num).lang(Lang::Finnish).to_words(),
+                Ok(String::from(words))
+            );
+        }
+        assert_eq!(
+            Num2Words::new(3_000_000_000i64)
+                .lang(Lang::Finnish)
+                .to_words(),
+            Ok(String::from("kolme miljardia"))
+        );
+        assert_eq!(
+            Num2Words::new(3.5).lang(Lang::Finnish).to_words(),
+            Ok(String::from("kolme pilkku viisi"))
+        );
+        assert_eq!(
+            Num2Words::new(1e100).lang(Lang::Finnish).to_words(),
+            Err(Num2Err::CannotConvert)
+        );
+    }
+
+    #[test]
+    fn test_cases() {
+        for (num, genitive, partitive) in [
+            (0, "nollan", "nollaa"),
+            (1, "yhden", "yhtä"),
+            (12, "kahdentoista", "kahtatoista"),
+            (42, "neljänkymmenenkahden", "neljääkymmentäkahta"),
+            (200, "kahdensadan", "kahtasataa"),
+            (
+                2042,
+                "kahdentuhannenneljänkymmenenkahden",
+                "kahtatuhattaneljääkymmentäkahta",
+            ),
+            (2_000_000, "kahden miljoonan", "kahta miljoonaa"),
+        ] {
+            assert_eq!(
+                finnish(num, "genetiivi").to_words(),
+                Ok(String::from(genitive))
+            );
+            assert_eq!(
+                finnish(num, "partitive").to_words(),
+                Ok(String::from(partitive))
+            );
+        }
+    }
+
+    #[test]
+    fn test_ordinal() {
+        for (num, words) in [
+            (0, "nollas"),
+            (1, "ensimmäinen"),
+            (2, "toinen"),
+            (10, "kymmenes"),
+            (11, "yhdestoista"),
+            (12, "kahdestoista"),
+            (21, "kahdeskymmenesensimmäinen"),
+            (42, "neljäskymmenestoinen"),
+            (100, "sadas"),
+            (1000, "tuhannes"),
+            (2000, "kahdestuhannes"),
+            (2_000_042, "kahdes miljoonas neljäskymmenestoinen"),
+        ] {
+            assert_eq!(
+                Num2Words::new(num).lang(Lang::Finnish).ordinal().to_words(),
+                Ok(String::from(words))
+            );
+        }
+        assert_eq!(
+            finnish(42, "gen").ordinal().to_words(),
+            Ok(String::from("neljännenkymmenennentoisen"))
+        );
+        assert_eq!(
+            finnish(42, "part").ordinal().to_words(),
+            Ok(String::from("neljättäkymmenettätoista"))
+        );
+    }
+
+    #[test]
+    fn test_ordinal_num() {
+        assert_eq!(
+            Num2Words::new(42)
+                .lang(Lang::Finnish)
+                .ordinal_num()
+                .to_words(),
+            Ok(String::from("42."))
+        );
+    }
+
+    #[test]
+    fn test_year() {
+        assert_eq!(
+            Num2Words::new(1990).lang(Lang::Finnish).year().to_words(),
+            Ok(String::from("tuhatyhdeksänsataayhdeksänkymmentä"))
+        );
+        assert_eq!(
+            Num2Words::new(2024).lang(Lang::Finnish).year().to_words(),
+            Ok(String::from("kaksituhattakaksikymmentäneljä"))
+        );
+        assert_eq!(
+            Num2Words::new(-44).lang(Lang::Finnish).year().to_words(),
+            Ok(String::from("neljäkymmentäneljä ennen ajanlaskun alkua"))
+        );
+    }
+
+    #[test]
+    fn test_currency() {
+        for (num, words) in [
+            (1.0, "yksi euro"),
+            (2.0, "kaksi euroa"),
+            (0.0, "nolla euroa"),
+            (2.5, "kaksi euroa ja viisikymmentä senttiä"),
+            (0.01, "yksi sentti"),
+            (42.01, "neljäkymmentäkaksi euroa ja yksi sentti"),
+        ] {
+            assert_eq!(
+                Num2Words::new(num)
+                    .lang(Lang::Finnish)
+                    .currency(Currency::EUR)
+                    .to_words(),
+                Ok(String::from(words))
+            );
+        }
+        assert_eq!(
+            finnish(2, "genitive").currency(Currency::EUR).to_words(),
+            Ok(String::from("kahden euron"))
+        );
+        assert_eq!(
+            finnish(1, "partitive").currency(Currency::USD).to_words(),
+            Ok(String::from("yhtä Yhdysvaltain dollaria"))
+        );
+        assert_eq!(
+            Num2Words::new(0.2)
+                .lang(Lang::Finnish)
+                .currency(Currency::EUR)
+                .prefer("zero-major")
+                .to_words(),
+            Ok(String::from("nolla euroa ja kaksikymmentä senttiä"))
+        );
+        assert_eq!(
+            Num2Words::parse("-inf")
+                .unwrap()
+                .lang(Lang::Finnish)
+                .currency(Currency::EUR)
+                .to_words(),
+            Ok(String::from("miinus äärettömästi euroja"))
+        );
+    }
+
+    #[test]
+    fn test_percent_and_unit() {
+        assert_eq!(
+            Num2Words::new(42).lang(Lang::Finnish).percent().to_words(),
+            Ok(String::from("neljäkymmentäkaksi prosenttia"))
+        );
+        assert_eq!(
+            Num2Words::new(1).lang(Lang::Finnish).percent().to_words(),
+            Ok(String::from("yksi prosentti"))
+        );
+        assert_eq!(
+            Num2Words::new(1.5)
+                .lang(Lang::Finnish)
+                .unit(Unit::Kilometer)
+                .to_words(),
+            Ok(String::from("yksi pilkku viisi kilometriä"))
+        );
+        assert_eq!(
+            finnish(3, "genitive").unit(Unit::Hour).to_words(),
+            Ok(String::from("kolmen tunnin"))
+        );
+    }
+
+    #[test]
+    fn test_agreement() {
+        let agreement = Num2Words::new(5).lang(Lang::Finnish).agreement().unwrap();
+        assert_eq!(agreement.number, GrammaticalNumber::Singular);
+        assert_eq!(agreement.case, Some(Declension::Partitive));
+        assert_eq!(
+            finnish(1, "genitive").agreement().unwrap().case,
+            Some(Declension::Genitive)
+        );
+    }
+}
@@ -1,10 +1,28 @@
-use crate::{num2words::Num2Err, Currency, Language};
+use crate::lang::group_digits;
+use crate::{num2words::Num2Err, Agreement, Currency, Gender, Language, PluralCategory, Unit};
 use num_bigfloat::BigFloat;
 
+#[derive(Clone, Copy)]
 pub struct French {
     feminine: bool,
     reformed: bool,
     region: RegionFrench,
+    zero_major: bool,
+    always_cents: bool,
+    no_leading_zero: bool,
+    group_digits: bool,
+    plural: bool,
+    second: bool,
+    short: bool,
+    point: bool,
+    grouped_decimals: bool,
+    hundreds: bool,
+    octante: bool,
+    sous: bool,
+    era: Option<EraFrench>,
+    common_era: bool,
+    euro_cent: bool,
+    fraction: bool,
 }
 
 #[derive(Default, Clone, Copy, PartialEq, Debug)]
@@ -13,13 +31,24 @@ pub enum RegionFrench {
     FR,
     BE,
     CH,
+    CA,
 }
 
-const UNITS: [&'static str; 9] = [
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum EraFrench {
+    // avant JC, après JC
+    JC,
+    // av. J.-C., apr. J.-C.
+    Abbreviated,
+    // avant notre ère, de notre ère
+    Secular,
+}
+
+const UNITS: [&str; 9] = [
     "un", "deux", "trois", "quatre", "cinq", "six", "sept", "huit", "neuf",
 ];
 
-const TENS: [&'static str; 9] = [
+const TENS: [&str; 9] = [
     "dix",
     "vingt",
     "trente",
@@ -31,12 +60,12 @@ const TENS: [&'static str; 9] = [
     "quatre-vingt-dix",
 ];
 
-const TEENS: [&'static str; 10] = [
+const TEENS: [&str; 10] = [
     "dix", "onze", "douze", "treize", "quatorze", "quinze", "seize", "dix-sept", "dix-huit",
     "dix-neuf",
 ];
 
-const MEGAS: [&'static str; 33] = [
+const MEGAS: [&str; 33] = [
     "mille",
     "million",
     "milliard",
@@ -72,18 +101,214 @@ const MEGAS: [&'static str; 33] = [
     "sexdécilliard",
 ];
 
+// Suffixes the last element of a (possibly hyphenated) word, e.g.
+// "quatre-vingt-unième"
+fn ordinal_word(word: &str) -> String {
+    let (prefix, last) = word.split_at(word.rfind('-').map_or(0, |i| i + 1));
+    let stem = match last {
+        "cinq" => "cinqu",
+        "neuf" => "neuv",
+        // the plural mark is dropped: quatre-vingtième, deux centième
+        "vingts" | "cents" => &last[..last.len() - 1],
+        // mille is invariant and loses its final e: millième
+        "mille" => "mill",
+        // the other magnitudes keep their stem: millionième, deux milliardième
+        _ if MEGAS.contains(&last.strip_suffix('s').unwrap_or(last)) => {
+            last.strip_suffix('s').unwrap_or(last)
+        }
+        _ => last.strip_suffix('e').unwrap_or(last),
+    };
+    format!("{}{}ième", prefix, stem)
+}
+
 impl French {
     pub fn new(feminine: bool, reformed: bool, region: RegionFrench) -> Self {
         Self {
             feminine,
             reformed,
             region,
+            zero_major: false,
+            always_cents: false,
+            no_leading_zero: false,
+            group_digits: false,
+            plural: false,
+            second: false,
+            short: false,
+            point: false,
+            grouped_decimals: false,
+            hundreds: false,
+            octante: false,
+            sous: false,
+            era: None,
+            common_era: false,
+            euro_cent: false,
+            fraction: false,
+        }
+    }
+
+    /// Keeps the major unit clause for amounts below one, e.g. `zéro euro et
+    /// vingt centimes`
+    pub fn zero_major(self, zero_major: bool) -> Self {
+        Self { zero_major, ..self }
+    }
+
+    /// Keeps the cents clause of whole amounts, e.g. `quarante-deux euros et
+    /// zéro centime`
+    pub fn always_cents(self, always_cents: bool) -> Self {
+        Self {
+            always_cents,
+            ..self
+        }
+    }
+
+    /// Leaves out the zero before the decimal point, e.g. `virgule cinq`
+    pub fn no_leading_zero(self, no_leading_zero: bool) -> Self {
+        Self {
+            no_leading_zero,
+            ..self
+        }
+    }
+
+    /// Separates the thousands of the ordinal numbers with spaces, e.g.
+    /// `1 000 000ème`
+    pub fn group_digits(self, group_digits: bool) -> Self {
+        Self {
+            group_digits,
+            ..self
+        }
+    }
+
+    /// Marks the plural of the ordinal numbers, e.g. `1ers`, `42èmes`
+    pub fn plural(self, plural: bool) -> Self {
+        Self { plural, ..self }
+    }
+
+    /// Writes the ordinal number of two as second rather than deuxième, e.g.
+    /// `2d`, `2de`
+    pub fn second(self, second: bool) -> Self {
+        Self { second, ..self }
+    }
+
+    /// Uses the short suffix of the ordinal numbers recommended by the
+    /// Imprimerie nationale, e.g. `42e`
+    pub fn short(self, short: bool) -> Self {
+        Self { short, ..self }
+    }
+
+    /// Reads the decimal separator as point rather than virgule, e.g. `douze
+    /// point cinq`
+    pub fn point(self, point: bool) -> Self {
+        Self { point, ..self }
+    }
+
+    /// Reads the decimals as a number after their leading zeros, e.g. `douze
+    /// virgule cinquante et un`, `douze virgule zéro cinq`
+    pub fn grouped_decimals(self, grouped_decimals: bool) -> Self {
+        Self {
+            grouped_decimals,
+            ..self
+        }
+    }
+
+    /// Reads the years 1100 to 1999 as hundreds, e.g. `dix-neuf cent
+    /// quatre-vingt-dix`
+    pub fn hundreds(self, hundreds: bool) -> Self {
+        Self { hundreds, ..self }
+    }
+
+    /// Reads eighty as octante rather than huitante in Swiss French
+    pub fn octante(self, octante: bool) -> Self {
+        Self { octante, ..self }
+    }
+
+    /// Reads the cents of dollars as sous in Canadian French, e.g. `deux
+    /// dollars et cinq sous`
+    pub fn sous(self, sous: bool) -> Self {
+        Self { sous, ..self }
+    }
+
+    /// Names the subunit of the euro cent, as in the legal texts, rather than
+    /// centime, e.g. `quarante-deux cents`
+    pub fn euro_cent(self, euro_cent: bool) -> Self {
+        Self { euro_cent, ..self }
+    }
+
+    /// Reads the decimals as a common fraction, e.g. `trois et deux dixièmes`,
+    /// `trois quarts`
+    pub fn fraction(self, fraction: bool) -> Self {
+        Self { fraction, ..self }
+    }
+
+    /// Wording of the era of the years, the default of the region if `None`,
+    /// e.g. `quarante-quatre av. J.-C.`, `quarante-quatre avant notre ère`
+    pub fn era(self, era: Option<EraFrench>) -> Self {
+        Self { era, ..self }
+    }
+
+    /// Marks the era of the positive years too, e.g. `deux mille vingt-quatre
+    /// après JC`
+    pub fn common_era(self, common_era: bool) -> Self {
+        Self { common_era, ..self }
+    }
+
+    // suffix of a year, e.g. " avant JC"
+    fn era_suffix(&self, num: BigFloat) -> &'static str {
+        let era = self.era.unwrap_or(match self.region {
+            RegionFrench::CA => EraFrench::Abbreviated,
+            _ => EraFrench::JC,
+        });
+        if num.is_negative() {
+            match era {
+                EraFrench::JC => " avant JC",
+                EraFrench::Abbreviated => " av. J.-C.",
+                EraFrench::Secular => " avant notre ère",
+            }
+        } else if self.common_era && !num.is_zero() {
+            match era {
+                EraFrench::JC => " après JC",
+                EraFrench::Abbreviated => " apr. J.-C.",
+                EraFrench::Secular => " de notre ère",
+            }
+        } else {
+            ""
+        }
+    }
+
+    // digits of an ordinal number
+    fn digits(&self, num: BigFloat) -> String {
+        let num = num.to_u128().unwrap();
+        if self.group_digits {
+            group_digits(num, " ")
+        } else {
+            num.to_string()
+        }
+    }
+
+    fn whole_currency(&self, num: BigFloat, currency: Currency) -> Result<String, Num2Err> {
+        let words = self.int_to_cardinal(num)?;
+        let noun = self.currencies(currency, self.plural_category(num) != PluralCategory::One);
+
+        // a round number of millions or more takes the partitive: un million
+        // d'euros, deux milliards de dollars
+        let last = words.rsplit([' ', '-']).next().unwrap_or_default();
+        let singular = last.strip_suffix('s').unwrap_or(last);
+        if singular != MEGAS[0] && MEGAS.contains(&singular) {
+            let elided = noun.starts_with(['a', 'e', 'é', 'i', 'o', 'u', 'y']);
+            Ok(format!(
+                "{} {}{}",
+                words,
+                if elided { "d'" } else { "de " },
+                noun
+            ))
+        } else {
+            Ok(format!("{} {}", words, noun))
         }
     }
 
     fn get_tens(&self, tens: usize) -> &str {
         match (tens, self.region) {
             (7, RegionFrench::BE) | (7, RegionFrench::CH) => "septante",
+            (8, RegionFrench::CH) if self.octante => "octante",
             (8, RegionFrench::CH) => "huitante",
             (9, RegionFrench::BE) | (9, RegionFrench::CH) => "nonante",
             _ => TENS[tens - 1],
@@ -95,7 +320,9 @@ impl French {
             Currency::ARS => String::from("peso{} argentin"),
             Currency::AUD => String::from("dollar{} australien"),
             Currency::BRL => String::from(if plural_form { "réaux" } else { "réal" }),
+            Currency::CAD if self.region == RegionFrench::CA => String::from("dollar{}"),
             Currency::CAD => String::from("dollar{} canadien"),
+            Currency::CHF if self.region == RegionFrench::CH => String::from("franc{} suisse{}"),
             Currency::CLP => String::from("peso{} chilien"),
             Currency::COP => String::from("peso{} colombien"),
             Currency::DZD => String::from("dinar{} algérien"),
@@ -104,9 +331,12 @@ impl French {
             Currency::IDR => String::from("roupie{} indonésienne"),
             Currency::ILS => String::from("shekel{}"),
             Currency::INR => String::from("roupie{}"),
+            Currency::ISK => String::from("couronne{} islandaise"),
             Currency::KWD => String::from("dinar{} koweïtien"),
+            Currency::LKR => String::from("roupie{} srilankaise"),
             Currency::MXN => String::from("peso{} mexicain"),
             Currency::NOK => String::from("couronne{} norvégienne"),
+            Currency::NPR => String::from("roupie{} népalaise"),
             Currency::NZD => String::from("dollar{} néo-zélandais"),
             Currency::PHP => String::from("peso{} phillippin"),
             Currency::PLN => String::from("złoty{}"),
@@ -125,9 +355,32 @@ impl French {
         .replace("{}", if plural_form { "s" } else { "" })
     }
 
+    fn units(&self, unit: Unit, plural_form: bool) -> String {
+        match unit {
+            Unit::Meter => "mètre{}",
+            Unit::Kilometer => "kilomètre{}",
+            Unit::Gram => "gramme{}",
+            Unit::Kilogram => "kilogramme{}",
+            Unit::Liter => "litre{}",
+            Unit::Second => "seconde{}",
+            Unit::Minute => "minute{}",
+            Unit::Hour => "heure{}",
+        }
+        .replace("{}", if plural_form { "s" } else { "" })
+    }
+
+    fn unit_properties(&self, unit: Unit) -> Self {
+        let feminine = matches!(unit, Unit::Second | Unit::Minute | Unit::Hour);
+        Self { feminine, ..*self }
+    }
+
     fn cents(&self, _currency: Currency, plural_form: bool) -> String {
         match _currency {
             Currency::UAH => String::from("kopeck{}"),
+            Currency::EUR if self.euro_cent => String::from("cent{}"),
+            Currency::CAD | Currency::DOLLAR | Currency::USD if self.region == RegionFrench::CA => {
+                String::from(if self.sous { "sou{}" } else { "cent{}" })
+            }
             _ => _currency.default_subunit_string("centime{}", plural_form),
         }
         .replace("{}", if plural_form { "s" } else { "" })
@@ -151,12 +404,13 @@ impl French {
             return Ok(String::from("zéro"));
         }
 
-        // handling negative values
-        let mut words = vec![];
-        if num.is_negative() {
-            words.push(String::from("moins"));
+        // handling negative values, the sign is kept apart from the hyphens of
+        // the reformed spelling: moins vingt-et-un
+        let negative = num.is_negative();
+        if negative {
             num = -num;
         }
+        let mut words = vec![];
 
         // iterate over thousands
         for (i, triplet) in self.split_thousands(num).iter().enumerate().rev() {
@@ -211,22 +465,24 @@ impl French {
                             }
                         }
                         (1, _) => words.push(String::from(TEENS[units])),
-                        (7, RegionFrench::FR) => words.push(format!(
+                        (7, RegionFrench::FR) | (7, RegionFrench::CA) => words.push(format!(
                             "{}{}{}",
                             self.get_tens(tens - 1),
                             et_string,
                             TEENS[units]
                         )),
-                        (8, RegionFrench::FR) | (8, RegionFrench::BE) => words.push(format!(
-                            "{}-{}",
-                            self.get_tens(tens),
-                            if i == 0 && units == 1 && self.feminine {
-                                "une"
-                            } else {
-                                UNITS[units - 1]
-                            }
-                        )),
-                        (9, RegionFrench::FR) => {
+                        (8, RegionFrench::FR) | (8, RegionFrench::BE) | (8, RegionFrench::CA) => {
+                            words.push(format!(
+                                "{}-{}",
+                                self.get_tens(tens),
+                                if i == 0 && units == 1 && self.feminine {
+                                    "une"
+                                } else {
+                                    UNITS[units - 1]
+                                }
+                            ))
+                        }
+                        (9, RegionFrench::FR) | (9, RegionFrench::CA) => {
                             words.push(format!("{}-{}", self.get_tens(tens - 1), TEENS[units]))
                         }
                         _ => words.push(format!(
@@ -256,32 +512,141 @@ impl French {
             }
         }
 
-        Ok(words.join(if self.reformed { "-" } else { " " }))
+        let words = words.join(if self.reformed { "-" } else { " " });
+        if negative {
+            Ok(format!("moins {}", words))
+        } else {
+            Ok(words)
+        }
     }
 
     fn float_to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if self.fraction {
+            if let Some(words) = self.float_to_fraction(num)? {
+                return Ok(words);
+            }
+        }
+
         let integral_part = num.int();
         let mut words: Vec<String> = vec![];
 
         if !integral_part.is_zero() {
             let integral_word = self.int_to_cardinal(integral_part)?;
             words.push(integral_word);
+        } else if num.is_negative() {
+            words.push(String::from("moins"));
+        }
+        if integral_part.is_zero() && !self.no_leading_zero {
+            words.push(String::from("zéro"));
         }
 
-        let mut ordinal_part = num.frac();
+        let mut ordinal_part = num.frac().abs();
         if !ordinal_part.is_zero() {
-            words.push(String::from("point"));
+            words.push(String::from(if self.point { "point" } else { "virgule" }));
         }
+        let mut digits = vec![];
         while !ordinal_part.is_zero() {
             let digit = (ordinal_part * BigFloat::from(10)).int();
             ordinal_part = (ordinal_part * BigFloat::from(10)).frac();
-            words.push(match digit.to_u64().unwrap() {
+            digits.push(digit.to_u64().unwrap());
+        }
+
+        let leading_zeros = if self.grouped_decimals {
+            digits.iter().take_while(|&&digit| digit == 0).count()
+        } else {
+            digits.len()
+        };
+        for &digit in &digits[..leading_zeros] {
+            words.push(match digit {
                 0 => String::from("zéro"),
                 i => String::from(UNITS[i as usize - 1]),
             });
         }
+        if leading_zeros < digits.len() {
+            // the decimals are read as a masculine number
+            let decimals = digits[leading_zeros..]
+                .iter()
+                .fold(BigFloat::from(0), |decimals, &digit| {
+                    decimals * BigFloat::from(10) + BigFloat::from(digit)
+                });
+            let masculine = Self {
+                feminine: false,
+                ..*self
+            };
+            words.push(masculine.int_to_cardinal(decimals)?);
+        }
         Ok(words.join(" "))
     }
+
+    // `None` when the denominator is too large to be read, e.g. 3.2 => trois et
+    // deux dixièmes, 0.75 => trois quarts, 2.5 => deux et demi
+    fn float_to_fraction(&self, num: BigFloat) -> Result<Option<String>, Num2Err> {
+        let integral_part = num.int();
+        let fraction = num.frac().abs();
+        let mut numerator = fraction;
+        let mut denominator = BigFloat::from(1);
+        while !numerator.frac().is_zero() {
+            if denominator >= BigFloat::from(1_000_000) {
+                // thirds are only ever approximated, e.g. 0.333333333333
+                let thirds = fraction * BigFloat::from(3);
+                let rounded = thirds.round(0, num_bigfloat::RoundingMode::ToEven);
+                if (thirds - rounded).abs() > BigFloat::from(1e-9) || rounded.is_zero() {
+                    return Ok(None);
+                }
+                numerator = rounded;
+                denominator = BigFloat::from(3);
+                break;
+            }
+            numerator *= BigFloat::from(10);
+            denominator *= BigFloat::from(10);
+        }
+
+        // only the halves and the quarters are reduced, the other fractions
+        // keep their decimal denominator: deux dixièmes rather than un cinquième
+        for reduced in [2, 4] {
+            let scale = denominator / BigFloat::from(reduced);
+            if scale.frac().is_zero() && (numerator / scale).frac().is_zero() {
+                numerator /= scale;
+                denominator = BigFloat::from(reduced);
+                break;
+            }
+        }
+
+        // fractions are masculine nouns: un demi, un tiers
+        let masculine = Self {
+            feminine: false,
+            ..*self
+        };
+        let mut words: Vec<String> = vec![];
+        if !integral_part.is_zero() {
+            words.push(self.int_to_cardinal(integral_part)?);
+            words.push(String::from("et"));
+            if denominator == BigFloat::from(2) {
+                // deux et demi
+                words.push(String::from("demi"));
+                return Ok(Some(words.join(" ")));
+            }
+        } else if num.is_negative() {
+            words.push(String::from("moins"));
+        }
+        words.push(masculine.int_to_cardinal(numerator)?);
+
+        let plural = numerator > BigFloat::from(1);
+        words.push(match denominator.to_u64().unwrap() {
+            2 => String::from("demi"),
+            3 => String::from("tiers"),
+            4 => String::from(if plural { "quarts" } else { "quart" }),
+            _ => {
+                let ordinal = masculine.to_ordinal(denominator)?;
+                if plural {
+                    format!("{}s", ordinal)
+                } else {
+                    ordinal
+                }
+            }
+        });
+        Ok(Some(words.join(" ")))
+    }
 }
 
 impl Language for French {
@@ -299,10 +664,26 @@ impl Language for French {
 
     fn to_ordinal(&self, num: BigFloat) -> Result<String, Num2Err> {
         if num == BigFloat::from(1) {
-            return Ok(String::from("premier"));
+            return Ok(String::from(if self.feminine {
+                "première"
+            } else {
+                "premier"
+            }));
         }
         let cardinal_word = self.to_cardinal(num)?;
 
+        // the ordinals of the powers of a magnitude are single words:
+        // millionième rather than un millionième, dix-millième, cent-millième
+        let cardinal_word = match cardinal_word.split_once(if self.reformed { '-' } else { ' ' }) {
+            Some(("un", mega)) if MEGAS.contains(&mega) => String::from(mega),
+            Some((multiplier @ ("dix" | "cent"), megas))
+                if MEGAS.contains(&megas.strip_suffix('s').unwrap_or(megas)) =>
+            {
+                format!("{}-{}", multiplier, megas)
+            }
+            _ => cardinal_word,
+        };
+
         let mut words: Vec<String> = vec![];
         let mut split = cardinal_word.split_whitespace().peekable();
 
@@ -312,14 +693,7 @@ impl Language for French {
                 words.push(String::from(w));
             } else {
                 // last word, needs to be processed
-                words.push(format!(
-                    "{}ième",
-                    if w.ends_with('e') {
-                        &w[..w.len() - 1]
-                    } else {
-                        &w
-                    }
-                ));
+                words.push(ordinal_word(w));
             }
         }
 
@@ -327,23 +701,60 @@ impl Language for French {
     }
 
     fn to_ordinal_num(&self, num: BigFloat) -> Result<String, Num2Err> {
-        Ok(format!(
-            "{}{}",
-            num.to_u128().unwrap(),
-            if num == BigFloat::from(1) {
+        let suffix = if num == BigFloat::from(1) {
+            if self.feminine {
+                "re"
+            } else {
                 "er"
+            }
+        } else if num == BigFloat::from(2) && self.second {
+            if self.feminine {
+                "de"
             } else {
-                "ème"
+                "d"
             }
+        } else if self.short {
+            "e"
+        } else {
+            "ème"
+        };
+        Ok(format!(
+            "{}{}{}",
+            self.digits(num),
+            suffix,
+            if self.plural { "s" } else { "" }
         ))
     }
 
     fn to_year(&self, num: BigFloat) -> Result<String, Num2Err> {
-        if num.is_negative() {
-            Ok(format!("{} avant JC", self.to_cardinal(-num)?))
+        let suffix = self.era_suffix(num);
+        let num = num.abs();
+
+        let words = if self.hundreds && num >= BigFloat::from(1100) && num < BigFloat::from(2000) {
+            // e.g. 1990 => dix-neuf cent quatre-vingt-dix, 1900 => dix-neuf cents
+            let bf_100 = BigFloat::from(100);
+            let high = (num / bf_100).int();
+            let low = num % bf_100;
+            let mut words = vec![self.int_to_cardinal(high)?];
+            if low.is_zero() {
+                words.push(String::from("cents"));
+            } else {
+                words.push(String::from("cent"));
+                words.push(self.int_to_cardinal(low)?);
+            }
+            words.join(if self.reformed { "-" } else { " " })
         } else {
-            self.to_cardinal(num)
-        }
+            self.to_cardinal(num)?
+        };
+        Ok(format!("{}{}", words, suffix))
+    }
+
+    fn to_year_range(&self, from: BigFloat, to: BigFloat) -> Result<String, Num2Err> {
+        Ok(format!(
+            "de {} à {}",
+            self.to_year(from)?,
+            self.to_year(to)?
+        ))
     }
 
     fn to_currency(&self, num: BigFloat, currency: Currency) -> Result<String, Num2Err> {
@@ -353,24 +764,42 @@ impl Language for French {
                 if num.is_negative() { "moins " } else { "" },
                 self.currencies(currency, true)
             ))
+        } else if num.is_negative() {
+            // the sign is read once, not on both the euros and the centimes
+            Ok(format!("moins {}", self.to_currency(-num, currency)?))
         } else if num.frac().is_zero() {
-            let words = self.int_to_cardinal(num)?;
-            Ok(format!(
-                "{} {}",
-                words,
-                self.currencies(currency, num != BigFloat::from(1))
-            ))
+            let words = self.whole_currency(num, currency)?;
+            if self.always_cents {
+                // the unit stays singular after "zéro"
+                Ok(format!("{} et zéro {}", words, self.cents(currency, false)))
+            } else {
+                Ok(words)
+            }
         } else {
             let integral_part = num.int();
             let cents_nb = (num * BigFloat::from(100)).int() % BigFloat::from(100);
             let cents_words = self.int_to_cardinal(cents_nb)?;
-            let cents_suffix = self.cents(currency, cents_nb != BigFloat::from(1));
-            let integral_word = self.to_currency(integral_part, currency)?;
+            let cents_suffix = self.cents(
+                currency,
+                self.plural_category(cents_nb) != PluralCategory::One,
+            );
+            let integral_word = self.whole_currency(integral_part, currency)?;
 
             if cents_nb.is_zero() {
                 Ok(integral_word)
             } else if integral_part.is_zero() {
-                Ok(format!("{} {}", cents_words, cents_suffix))
+                if self.zero_major {
+                    // the unit stays singular after "zéro"
+                    Ok(format!(
+                        "{} {} et {} {}",
+                        self.int_to_cardinal(integral_part)?,
+                        self.currencies(currency, false),
+                        cents_words,
+                        cents_suffix
+                    ))
+                } else {
+                    Ok(format!("{} {}", cents_words, cents_suffix))
+                }
             } else {
                 Ok(format!(
                     "{} et {} {}",
@@ -379,6 +808,55 @@ impl Language for French {
             }
         }
     }
+
+    fn to_percent(&self, num: BigFloat) -> Result<String, Num2Err> {
+        Ok(format!("{} pour cent", self.to_cardinal(num)?))
+    }
+
+    fn to_unit(&self, num: BigFloat, unit: Unit) -> Result<String, Num2Err> {
+        if num.is_inf() {
+            Ok(format!(
+                "{}une infinité de {}",
+                if num.is_negative() { "moins " } else { "" },
+                self.units(unit, true)
+            ))
+        } else {
+            Ok(format!(
+                "{} {}",
+                Self {
+                    fraction: false,
+                    ..self.unit_properties(unit)
+                }
+                .to_cardinal(num)?,
+                self.units(unit, self.plural_category(num) != PluralCategory::One)
+            ))
+        }
+    }
+
+    fn plural_category(&self, num: BigFloat) -> PluralCategory {
+        // the singular goes up to two, zero included: zéro euro, un virgule
+        // cinq heure
+        if num.abs() < BigFloat::from(2) {
+            PluralCategory::One
+        } else {
+            PluralCategory::Other
+        }
+    }
+
+    fn agreement(&self, num: BigFloat) -> Agreement {
+        Agreement {
+            gender: Some(if self.feminine {
+                Gender::Feminine
+            } else {
+                Gender::Masculine
+            }),
+            ..Agreement::from_category(self.plural_category(num))
+        }
+    }
+
+    fn negative_marker(&self) -> &'static str {
+        "négatif"
+    }
 }
 
 #[cfg(test)]
@@ -449,6 +927,25 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_plural_agreement() {
+        for (num, words) in [
+            (80, "quatre-vingts"),
+            (200, "deux cents"),
+            (280, "deux cent quatre-vingts"),
+            (281, "deux cent quatre-vingt-un"),
+            (200_000, "deux cent mille"),
+            (200_200, "deux cent mille deux cents"),
+            (80_000_000, "quatre-vingts millions"),
+            (200_000_000, "deux cents millions"),
+        ] {
+            assert_eq!(
+                Num2Words::new(num).lang(Lang::French).to_words(),
+                Ok(String::from(words))
+            );
+        }
+    }
+
     #[test]
     fn test_feminine() {
         assert_eq!(
@@ -489,6 +986,86 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_reformed_outputs() {
+        // traditional and reformed spellings of the same words
+        let both = |words: &dyn Fn() -> Num2Words| {
+            (
+                words().to_words().unwrap(),
+                words().prefer("reformed").to_words().unwrap(),
+            )
+        };
+        let french = |num: f64| Num2Words::new(num).lang(Lang::French);
+
+        assert_eq!(
+            both(&|| french(21.0).ordinal()),
+            (
+                String::from("vingt et unième"),
+                String::from("vingt-et-unième")
+            )
+        );
+        assert_eq!(
+            both(&|| french(1990.0).year()),
+            (
+                String::from("mille neuf cent quatre-vingt-dix"),
+                String::from("mille-neuf-cent-quatre-vingt-dix")
+            )
+        );
+        assert_eq!(
+            both(&|| french(-1990.0).year()),
+            (
+                String::from("mille neuf cent quatre-vingt-dix avant JC"),
+                String::from("mille-neuf-cent-quatre-vingt-dix avant JC")
+            )
+        );
+        assert_eq!(
+            both(&|| french(321.5).currency(Currency::EUR)),
+            (
+                String::from("trois cent vingt et un euros et cinquante centimes"),
+                String::from("trois-cent-vingt-et-un euros et cinquante centimes")
+            )
+        );
+        assert_eq!(
+            both(&|| french(2_000_000.0).currency(Currency::EUR)),
+            (
+                String::from("deux millions d'euros"),
+                String::from("deux-millions d'euros")
+            )
+        );
+        assert_eq!(
+            both(&|| french(-21.0)),
+            (
+                String::from("moins vingt et un"),
+                String::from("moins vingt-et-un")
+            )
+        );
+        assert_eq!(
+            both(&|| french(-21.5)),
+            (
+                String::from("moins vingt et un virgule cinq"),
+                String::from("moins vingt-et-un virgule cinq")
+            )
+        );
+        assert_eq!(
+            both(&|| french(-21.0).currency(Currency::EUR)),
+            (
+                String::from("moins vingt et un euros"),
+                String::from("moins vingt-et-un euros")
+            )
+        );
+        assert_eq!(
+            both(&|| french(81.0).percent()),
+            (
+                String::from("quatre-vingt-un pour cent"),
+                String::from("quatre-vingt-un pour cent")
+            )
+        );
+        assert_eq!(
+            both(&|| french(1_000_000.0).ordinal()),
+            (String::from("millionième"), String::from("millionième"))
+        );
+    }
+
     #[test]
     fn test_french_be() {
         assert_eq!(
@@ -505,6 +1082,121 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_regional_tens() {
+        for (lang, words) in [
+            (
+                Lang::French,
+                ["soixante-dix", "quatre-vingts", "quatre-vingt-dix"],
+            ),
+            (Lang::French_BE, ["septante", "quatre-vingts", "nonante"]),
+            (Lang::French_CH, ["septante", "huitante", "nonante"]),
+        ] {
+            for (num, word) in [70, 80, 90].iter().zip(words.iter()) {
+                assert_eq!(
+                    Num2Words::new(*num).lang(lang).to_words(),
+                    Ok(String::from(*word))
+                );
+            }
+        }
+        assert_eq!(
+            Num2Words::new(81)
+                .lang(Lang::French_CH)
+                .prefer("octante")
+                .to_words(),
+            Ok(String::from("octante et un"))
+        );
+        assert_eq!(
+            Num2Words::new(80)
+                .lang(Lang::French_CH)
+                .prefer("octante")
+                .ordinal()
+                .to_words(),
+            Ok(String::from("octantième"))
+        );
+    }
+
+    #[test]
+    fn test_regional_currency() {
+        for (lang, words) in [
+            (Lang::French, "deux francs et cinq centimes"),
+            (Lang::French_BE, "deux francs et cinq centimes"),
+            (Lang::French_CH, "deux francs suisses et cinq centimes"),
+        ] {
+            assert_eq!(
+                Num2Words::new(2.05)
+                    .lang(lang)
+                    .currency(Currency::CHF)
+                    .to_words(),
+                Ok(String::from(words))
+            );
+        }
+        assert_eq!(
+            Num2Words::new(1)
+                .lang(Lang::French_CH)
+                .currency(Currency::CHF)
+                .to_words(),
+            Ok(String::from("un franc suisse"))
+        );
+    }
+
+    #[test]
+    fn test_french_ca() {
+        assert_eq!(
+            Num2Words::new(71).lang(Lang::French_CA).to_words(),
+            Ok(String::from("soixante et onze"))
+        );
+        assert_eq!(
+            Num2Words::new(80).lang(Lang::French_CA).to_words(),
+            Ok(String::from("quatre-vingts"))
+        );
+        assert_eq!(
+            Num2Words::new(99).lang(Lang::French_CA).to_words(),
+            Ok(String::from("quatre-vingt-dix-neuf"))
+        );
+        assert_eq!(
+            Num2Words::new(2.05)
+                .lang(Lang::French_CA)
+                .currency(Currency::CAD)
+                .to_words(),
+            Ok(String::from("deux dollars et cinq cents"))
+        );
+        assert_eq!(
+            Num2Words::new(2.05)
+                .lang(Lang::French_CA)
+                .currency(Currency::CAD)
+                .prefer("sous")
+                .to_words(),
+            Ok(String::from("deux dollars et cinq sous"))
+        );
+        assert_eq!(
+            Num2Words::new(1.01)
+                .lang(Lang::French_CA)
+                .currency(Currency::USD)
+                .to_words(),
+            Ok(String::from("un dollar américain et un cent"))
+        );
+        assert_eq!(
+            Num2Words::new(2.05)
+                .lang(Lang::French_CA)
+                .currency(Currency::EUR)
+                .to_words(),
+            Ok(String::from("deux euros et cinq centimes"))
+        );
+        assert_eq!(
+            Num2Words::new(1990).lang(Lang::French_CA).year().to_words(),
+            Ok(String::from("dix-neuf cent quatre-vingt-dix"))
+        );
+        assert_eq!(
+            Num2Words::new(1990)
+                .lang(Lang::French_CA)
+                .prefer("formal-year")
+                .year()
+                .to_words(),
+            Ok(String::from("mille neuf cent quatre-vingt-dix"))
+        );
+    }
+
     #[test]
     fn test_french_ch() {
         assert_eq!(
@@ -542,12 +1234,171 @@ mod tests {
     }
 
     #[test]
-    fn test_ordinal_num() {
+    fn test_feminine_ordinal() {
+        let feminine = |num: i64| Num2Words::new(num).lang(Lang::French).prefer("f");
+
         assert_eq!(
-            Num2Words::new(1)
-                .lang(Lang::French)
-                .ordinal_num()
-                .to_words(),
+            feminine(1).ordinal().to_words(),
+            Ok(String::from("première"))
+        );
+        assert_eq!(
+            feminine(21).ordinal().to_words(),
+            Ok(String::from("vingt et unième"))
+        );
+        assert_eq!(
+            feminine(101).ordinal().to_words(),
+            Ok(String::from("cent unième"))
+        );
+        assert_eq!(
+            feminine(1).ordinal_num().to_words(),
+            Ok(String::from("1re"))
+        );
+        assert_eq!(
+            feminine(21).ordinal_num().to_words(),
+            Ok(String::from("21ème"))
+        );
+        assert_eq!(
+            feminine(101).ordinal_num().to_words(),
+            Ok(String::from("101ème"))
+        );
+    }
+
+    #[test]
+    fn test_ordinal_num_forms() {
+        let ordinal_num = |num: i64, preferences: &[&str]| {
+            preferences
+                .iter()
+                .fold(
+                    Num2Words::new(num).lang(Lang::French).ordinal_num(),
+                    |n2w, preference| n2w.prefer(*preference),
+                )
+                .to_words()
+        };
+
+        assert_eq!(ordinal_num(1, &[]), Ok(String::from("1er")));
+        assert_eq!(ordinal_num(1, &["pl"]), Ok(String::from("1ers")));
+        assert_eq!(ordinal_num(1, &["f", "pl"]), Ok(String::from("1res")));
+        assert_eq!(ordinal_num(2, &[]), Ok(String::from("2ème")));
+        assert_eq!(ordinal_num(2, &["second"]), Ok(String::from("2d")));
+        assert_eq!(ordinal_num(2, &["second", "f"]), Ok(String::from("2de")));
+        assert_eq!(ordinal_num(42, &["short"]), Ok(String::from("42e")));
+        assert_eq!(ordinal_num(42, &["short", "pl"]), Ok(String::from("42es")));
+        assert_eq!(ordinal_num(42, &["pluriel"]), Ok(String::from("42èmes")));
+        assert_eq!(ordinal_num(2, &["e"]), Ok(String::from("2e")));
+        assert_eq!(ordinal_num(42, &["e"]), Ok(String::from("42e")));
+        assert_eq!(ordinal_num(1, &["e"]), Ok(String::from("1er")));
+        assert_eq!(ordinal_num(1, &["e", "f"]), Ok(String::from("1re")));
+        assert_eq!(ordinal_num(1, &["e", "f", "pl"]), Ok(String::from("1res")));
+        assert_eq!(ordinal_num(2, &["e", "second"]), Ok(String::from("2d")));
+    }
+
+    #[test]
+    fn test_ordinal_magnitudes() {
+        let ordinal = |num: i64| Num2Words::new(num).lang(Lang::French).ordinal();
+        assert_eq!(ordinal(1000).to_words(), Ok(String::from("millième")));
+        assert_eq!(ordinal(2000).to_words(), Ok(String::from("deux millième")));
+        assert_eq!(
+            ordinal(2000).prefer("reformed").to_words(),
+            Ok(String::from("deux-millième"))
+        );
+        assert_eq!(ordinal(10_000).to_words(), Ok(String::from("dix-millième")));
+        assert_eq!(
+            ordinal(100_000).to_words(),
+            Ok(String::from("cent-millième"))
+        );
+        assert_eq!(
+            ordinal(1_000_000).to_words(),
+            Ok(String::from("millionième"))
+        );
+        assert_eq!(
+            ordinal(1_000_000).prefer("reformed").to_words(),
+            Ok(String::from("millionième"))
+        );
+        assert_eq!(
+            ordinal(2_000_000).to_words(),
+            Ok(String::from("deux millionième"))
+        );
+        assert_eq!(
+            ordinal(10_000_000_000).to_words(),
+            Ok(String::from("dix-milliardième"))
+        );
+        assert_eq!(
+            ordinal(10_001).to_words(),
+            Ok(String::from("dix mille unième"))
+        );
+        assert_eq!(
+            ordinal(1_001_000).to_words(),
+            Ok(String::from("un million millième"))
+        );
+    }
+
+    #[test]
+    fn test_ordinal_agreement() {
+        for (num, traditional, reformed) in [
+            (5, "cinquième", "cinquième"),
+            (9, "neuvième", "neuvième"),
+            (19, "dix-neuvième", "dix-neuvième"),
+            (45, "quarante-cinquième", "quarante-cinquième"),
+            (70, "soixante-dixième", "soixante-dixième"),
+            (71, "soixante et onzième", "soixante-et-onzième"),
+            (80, "quatre-vingtième", "quatre-vingtième"),
+            (81, "quatre-vingt-unième", "quatre-vingt-unième"),
+            (91, "quatre-vingt-onzième", "quatre-vingt-onzième"),
+            (95, "quatre-vingt-quinzième", "quatre-vingt-quinzième"),
+            (99, "quatre-vingt-dix-neuvième", "quatre-vingt-dix-neuvième"),
+            (1005, "mille cinquième", "mille-cinquième"),
+            (100, "centième", "centième"),
+            (200, "deux centième", "deux-centième"),
+            (
+                280,
+                "deux cent quatre-vingtième",
+                "deux-cent-quatre-vingtième",
+            ),
+            (1000, "millième", "millième"),
+            (2_000_000, "deux millionième", "deux-millionième"),
+        ] {
+            assert_eq!(
+                Num2Words::new(num).lang(Lang::French).ordinal().to_words(),
+                Ok(String::from(traditional))
+            );
+            assert_eq!(
+                Num2Words::new(num)
+                    .lang(Lang::French)
+                    .prefer("reformed")
+                    .ordinal()
+                    .to_words(),
+                Ok(String::from(reformed))
+            );
+        }
+    }
+
+    #[test]
+    fn test_group_digits() {
+        assert_eq!(
+            Num2Words::new(1_000_000)
+                .lang(Lang::French)
+                .ordinal_num()
+                .group_digits()
+                .to_words(),
+            Ok(String::from("1 000 000ème"))
+        );
+        assert_eq!(
+            Num2Words::new(1000)
+                .lang(Lang::French)
+                .ordinal_num()
+                .group_digits()
+                .to_words(),
+            Ok(String::from("1 000ème"))
+        );
+    }
+
+    #[test]
+    fn test_ordinal_num() {
+        assert_eq!(
+            Num2Words::new(1)
+                .lang(Lang::French)
+                .ordinal_num()
+                .to_words(),
             Ok(String::from("1er"))
         );
         assert_eq!(
@@ -594,15 +1445,86 @@ mod tests {
                 .lang(Lang::French)
                 .cardinal()
                 .to_words(),
-            Ok(String::from("douze point cinq"))
+            Ok(String::from("douze virgule cinq"))
         );
         assert_eq!(
             Num2Words::new(12.51)
                 .lang(Lang::French)
                 .cardinal()
                 .to_words(),
+            Ok(String::from("douze virgule cinq un"))
+        );
+        assert_eq!(
+            Num2Words::new(0.5).lang(Lang::French).cardinal().to_words(),
+            Ok(String::from("zéro virgule cinq"))
+        );
+        assert_eq!(
+            Num2Words::new(-0.5)
+                .lang(Lang::French)
+                .prefer("no-leading-zero")
+                .to_words(),
+            Ok(String::from("moins virgule cinq"))
+        );
+        assert_eq!(
+            Num2Words::new(12.51)
+                .lang(Lang::French)
+                .prefer("point")
+                .to_words(),
             Ok(String::from("douze point cinq un"))
         );
+        assert_eq!(
+            Num2Words::new(12.51)
+                .lang(Lang::French_BE)
+                .prefer("grouped-decimals")
+                .to_words(),
+            Ok(String::from("douze virgule cinquante et un"))
+        );
+        assert_eq!(
+            Num2Words::new(12.05)
+                .lang(Lang::French_CH)
+                .prefer("grouped-decimals")
+                .to_words(),
+            Ok(String::from("douze virgule zéro cinq"))
+        );
+    }
+
+    #[test]
+    fn test_fraction() {
+        let fraction = |num: f64| Num2Words::new(num).lang(Lang::French).prefer("fraction");
+
+        assert_eq!(fraction(0.5).to_words(), Ok(String::from("un demi")));
+        assert_eq!(fraction(0.75).to_words(), Ok(String::from("trois quarts")));
+        assert_eq!(fraction(0.25).to_words(), Ok(String::from("un quart")));
+        assert_eq!(
+            fraction(3.2).to_words(),
+            Ok(String::from("trois et deux dixièmes"))
+        );
+        assert_eq!(fraction(0.1).to_words(), Ok(String::from("un dixième")));
+        assert_eq!(fraction(2.5).to_words(), Ok(String::from("deux et demi")));
+        assert_eq!(fraction(1.0 / 3.0).to_words(), Ok(String::from("un tiers")));
+        assert_eq!(
+            fraction(2.0 / 3.0).to_words(),
+            Ok(String::from("deux tiers"))
+        );
+        assert_eq!(
+            fraction(-0.05).to_words(),
+            Ok(String::from("moins cinq centièmes"))
+        );
+        assert_eq!(
+            fraction(1.0001).to_words(),
+            Ok(String::from("un et un dix-millième"))
+        );
+        assert_eq!(
+            fraction(0.1234567).to_words(),
+            Ok(String::from(
+                "zéro virgule un deux trois quatre cinq six sept"
+            ))
+        );
+        assert_eq!(
+            fraction(0.5).prefer("f").to_words(),
+            Ok(String::from("un demi"))
+        );
+        assert_eq!(fraction(42.0).to_words(), Ok(String::from("quarante-deux")));
     }
 
     #[test]
@@ -640,7 +1562,197 @@ mod tests {
                 .lang(Lang::French)
                 .currency(Currency::DOLLAR)
                 .to_words(),
-            Ok(String::from("zéro dollars"))
+            Ok(String::from("zéro dollar"))
+        );
+    }
+
+    #[test]
+    fn test_zero_singular() {
+        assert_eq!(
+            Num2Words::new(0)
+                .lang(Lang::French)
+                .currency(Currency::DOLLAR)
+                .prefer("always-cents")
+                .to_words(),
+            Ok(String::from("zéro dollar et zéro centime"))
+        );
+        assert_eq!(
+            Num2Words::new(0)
+                .lang(Lang::French)
+                .unit(Unit::Hour)
+                .to_words(),
+            Ok(String::from("zéro heure"))
+        );
+        assert_eq!(
+            Num2Words::new(1.5)
+                .lang(Lang::French)
+                .unit(Unit::Meter)
+                .to_words(),
+            Ok(String::from("un virgule cinq mètre"))
+        );
+        assert_eq!(
+            Num2Words::new(2.5)
+                .lang(Lang::French)
+                .unit(Unit::Meter)
+                .to_words(),
+            Ok(String::from("deux virgule cinq mètres"))
+        );
+        assert_eq!(
+            Num2Words::new(0)
+                .lang(Lang::English)
+                .currency(Currency::DOLLAR)
+                .to_words(),
+            Ok(String::from("zero dollars"))
+        );
+    }
+
+    #[test]
+    fn test_negative_currency() {
+        assert_eq!(
+            Num2Words::new(-0.25)
+                .lang(Lang::French)
+                .currency(Currency::EUR)
+                .to_words(),
+            Ok(String::from("moins vingt-cinq centimes"))
+        );
+        assert_eq!(
+            Num2Words::new(-1.00)
+                .lang(Lang::French)
+                .currency(Currency::EUR)
+                .to_words(),
+            Ok(String::from("moins un euro"))
+        );
+        assert_eq!(
+            Num2Words::new(-5.10)
+                .lang(Lang::French)
+                .currency(Currency::EUR)
+                .to_words(),
+            Ok(String::from("moins cinq euros et dix centimes"))
+        );
+    }
+
+    #[test]
+    fn test_currency_partitive() {
+        assert_eq!(
+            Num2Words::new(1_000_000)
+                .lang(Lang::French)
+                .currency(Currency::EUR)
+                .to_words(),
+            Ok(String::from("un million d'euros"))
+        );
+        assert_eq!(
+            Num2Words::new(2_000_000_000)
+                .lang(Lang::French)
+                .currency(Currency::DOLLAR)
+                .to_words(),
+            Ok(String::from("deux milliards de dollars"))
+        );
+        assert_eq!(
+            Num2Words::new(1_000_001)
+                .lang(Lang::French)
+                .currency(Currency::EUR)
+                .to_words(),
+            Ok(String::from("un million un euros"))
+        );
+        assert_eq!(
+            Num2Words::new(1_000_000.5)
+                .lang(Lang::French)
+                .currency(Currency::EUR)
+                .to_words(),
+            Ok(String::from("un million d'euros et cinquante centimes"))
+        );
+        assert_eq!(
+            Num2Words::new(1000)
+                .lang(Lang::French)
+                .currency(Currency::EUR)
+                .to_words(),
+            Ok(String::from("mille euros"))
+        );
+    }
+
+    #[test]
+    fn test_euro_cent() {
+        let cent = |num: f64, currency: Currency| {
+            Num2Words::new(num)
+                .lang(Lang::French)
+                .currency(currency)
+                .prefer("cent")
+                .to_words()
+        };
+        assert_eq!(
+            cent(42.42, Currency::EUR),
+            Ok(String::from("quarante-deux euros et quarante-deux cents"))
+        );
+        assert_eq!(cent(0.01, Currency::EUR), Ok(String::from("un cent")));
+        assert_eq!(cent(0.02, Currency::EUR), Ok(String::from("deux cents")));
+        assert_eq!(
+            cent(0.80, Currency::EUR),
+            Ok(String::from("quatre-vingts cents"))
+        );
+        assert_eq!(
+            cent(200.02, Currency::EUR),
+            Ok(String::from("deux cents euros et deux cents"))
+        );
+        assert_eq!(
+            cent(1.01, Currency::DOLLAR),
+            Ok(String::from("un dollar et un centime"))
+        );
+        assert_eq!(
+            Num2Words::new(1)
+                .lang(Lang::French)
+                .currency(Currency::EUR)
+                .prefer("cent")
+                .prefer("always-cents")
+                .to_words(),
+            Ok(String::from("un euro et zéro cent"))
+        );
+    }
+
+    #[test]
+    fn test_always_cents() {
+        assert_eq!(
+            Num2Words::new(42)
+                .lang(Lang::French)
+                .currency(Currency::EUR)
+                .prefer("always-cents")
+                .to_words(),
+            Ok(String::from("quarante-deux euros et zéro centime"))
+        );
+        assert_eq!(
+            Num2Words::new(42.5)
+                .lang(Lang::French)
+                .currency(Currency::EUR)
+                .prefer("always-cents")
+                .to_words(),
+            Ok(String::from("quarante-deux euros et cinquante centimes"))
+        );
+    }
+
+    #[test]
+    fn test_currency_zero_major() {
+        assert_eq!(
+            Num2Words::new(0.20)
+                .lang(Lang::French)
+                .currency(Currency::EUR)
+                .prefer("zero-major")
+                .to_words(),
+            Ok(String::from("zéro euro et vingt centimes"))
+        );
+        assert_eq!(
+            Num2Words::new(0.01)
+                .lang(Lang::French)
+                .currency(Currency::EUR)
+                .prefer("zero-major")
+                .to_words(),
+            Ok(String::from("zéro euro et un centime"))
+        );
+        assert_eq!(
+            Num2Words::new(0.00)
+                .lang(Lang::French)
+                .currency(Currency::EUR)
+                .prefer("zero-major")
+                .to_words(),
+            Ok(String::from("zéro euro"))
         );
     }
 
@@ -660,6 +1772,119 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_year_hundreds() {
+        let hundreds = |num: i64| {
+            Num2Words::new(num)
+                .lang(Lang::French)
+                .prefer("hundreds")
+                .year()
+                .to_words()
+        };
+        assert_eq!(
+            hundreds(1990),
+            Ok(String::from("dix-neuf cent quatre-vingt-dix"))
+        );
+        assert_eq!(hundreds(1900), Ok(String::from("dix-neuf cents")));
+        assert_eq!(hundreds(1101), Ok(String::from("onze cent un")));
+        assert_eq!(hundreds(1080), Ok(String::from("mille quatre-vingts")));
+        assert_eq!(hundreds(2024), Ok(String::from("deux mille vingt-quatre")));
+        assert_eq!(
+            hundreds(-1515),
+            Ok(String::from("quinze cent quinze avant JC"))
+        );
+        assert_eq!(
+            Num2Words::new(1984)
+                .lang(Lang::French)
+                .prefer("hundreds")
+                .prefer("reformed")
+                .year()
+                .to_words(),
+            Ok(String::from("dix-neuf-cent-quatre-vingt-quatre"))
+        );
+    }
+
+    #[test]
+    fn test_era() {
+        let year = |lang: Lang, num: i64, preferences: &[&str]| {
+            let mut words = Num2Words::new(num).lang(lang).year();
+            for preference in preferences {
+                words = words.prefer(*preference);
+            }
+            words.to_words()
+        };
+        assert_eq!(
+            year(Lang::French, -44, &["abrégé"]),
+            Ok(String::from("quarante-quatre av. J.-C."))
+        );
+        assert_eq!(
+            year(Lang::French, -44, &["notre-ère"]),
+            Ok(String::from("quarante-quatre avant notre ère"))
+        );
+        assert_eq!(
+            year(Lang::French, 44, &[]),
+            Ok(String::from("quarante-quatre"))
+        );
+        assert_eq!(
+            year(Lang::French, 44, &["ère"]),
+            Ok(String::from("quarante-quatre après JC"))
+        );
+        assert_eq!(
+            year(Lang::French, 44, &["ère", "abrégé"]),
+            Ok(String::from("quarante-quatre apr. J.-C."))
+        );
+        assert_eq!(
+            year(Lang::French, 44, &["ère", "notre-ère"]),
+            Ok(String::from("quarante-quatre de notre ère"))
+        );
+        assert_eq!(year(Lang::French, 0, &["ère"]), Ok(String::from("zéro")));
+        assert_eq!(
+            year(Lang::French_CA, -44, &[]),
+            Ok(String::from("quarante-quatre av. J.-C."))
+        );
+        assert_eq!(
+            year(Lang::French_CA, -44, &["notre-ère"]),
+            Ok(String::from("quarante-quatre avant notre ère"))
+        );
+        assert_eq!(
+            Num2Words::new(-50)
+                .lang(Lang::French)
+                .year_range(50)
+                .prefer("ère")
+                .to_words(),
+            Ok(String::from("de cinquante avant JC à cinquante après JC"))
+        );
+    }
+
+    #[test]
+    fn test_year_range() {
+        assert_eq!(
+            Num2Words::new(2020)
+                .lang(Lang::French)
+                .year_range(2023)
+                .to_words(),
+            Ok(String::from("de deux mille vingt à deux mille vingt-trois"))
+        );
+    }
+
+    #[test]
+    fn test_expanded_year() {
+        for (year, words) in [
+            (12024, "douze mille vingt-quatre"),
+            (10000, "dix mille"),
+            (-10000, "dix mille avant JC"),
+            (
+                99999,
+                "quatre-vingt-dix-neuf mille neuf cent quatre-vingt-dix-neuf",
+            ),
+        ] {
+            assert_eq!(
+                Num2Words::new(year).lang(Lang::French).year().to_words(),
+                Ok(String::from(words))
+            );
+        }
+    }
+
     #[test]
     fn test_big_num() {
         use crate::lang::fr::MEGAS;
@@ -671,7 +1896,7 @@ mod tests {
             num *= BigFloat::from(1000);
             assert_eq!(
                 Num2Words::new(num).lang(Lang::French).cardinal().to_words(),
-                Ok(String::from(format!("{}{}", un, m)))
+                Ok(format!("{}{}", un, m))
             );
             un = "un ";
         }
@@ -701,4 +1926,70 @@ mod tests {
             Ok(String::from("une infinité de dollars"))
         );
     }
+
+    #[test]
+    fn test_agreement_matrix() {
+        let matrix = [
+            (
+                -0.5,
+                "moins cinquante centimes",
+                "moins zéro virgule cinq pour cent",
+                "moins zéro virgule cinq heure",
+            ),
+            (
+                -1.0,
+                "moins un euro",
+                "moins un pour cent",
+                "moins une heure",
+            ),
+            (0.0, "zéro euro", "zéro pour cent", "zéro heure"),
+            (
+                0.5,
+                "cinquante centimes",
+                "zéro virgule cinq pour cent",
+                "zéro virgule cinq heure",
+            ),
+            (1.0, "un euro", "un pour cent", "une heure"),
+            (2.0, "deux euros", "deux pour cent", "deux heures"),
+            (5.0, "cinq euros", "cinq pour cent", "cinq heures"),
+            (
+                21.0,
+                "vingt et un euros",
+                "vingt et un pour cent",
+                "vingt et une heures",
+            ),
+            (
+                22.0,
+                "vingt-deux euros",
+                "vingt-deux pour cent",
+                "vingt-deux heures",
+            ),
+            (
+                25.0,
+                "vingt-cinq euros",
+                "vingt-cinq pour cent",
+                "vingt-cinq heures",
+            ),
+        ];
+        for (num, currency, percent, unit) in matrix.iter() {
+            assert_eq!(
+                Num2Words::new(*num)
+                    .lang(Lang::French)
+                    .currency(Currency::EUR)
+                    .to_words(),
+                Ok(String::from(*currency))
+            );
+            assert_eq!(
+                Num2Words::new(*num).lang(Lang::French).percent().to_words(),
+                Ok(String::from(*percent))
+            );
+            assert_eq!(
+                Num2Words::new(*num)
+                    .lang(Lang::French)
+                    .unit(Unit::Hour)
+                    .to_words(),
+                Ok(String::from(*unit))
+            );
+        }
+    }
 }
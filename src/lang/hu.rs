@@ -0,0 +1,452 @@
+use crate::{
+    num2words::Num2Err, Agreement, Currency, GrammaticalNumber, Language, PluralCategory, Unit,
+};
+use num_bigfloat::BigFloat;
+
+pub struct Hungarian {
+    zero_major: bool,
+}
+
+// "kettő" ends a number, "két" is followed by a multiplier or a noun
+const UNITS: [&str; 9] = [
+    "egy", "kettő", "három", "négy", "öt", "hat", "hét", "nyolc", "kilenc",
+];
+
+// tens standing alone, then tens followed by units
+const TENS: [(&str, &str); 9] = [
+    ("tíz", "tizen"),
+    ("húsz", "huszon"),
+    ("harminc", "harminc"),
+    ("negyven", "negyven"),
+    ("ötven", "ötven"),
+    ("hatvan", "hatvan"),
+    ("hetven", "hetven"),
+    ("nyolcvan", "nyolcvan"),
+    ("kilencven", "kilencven"),
+];
+
+const MEGAS: [&str; 8] = [
+    "millió",
+    "milliárd",
+    "billió",
+    "billiárd",
+    "trillió",
+    "trilliárd",
+    "kvadrillió",
+    "kvadrilliárd",
+];
+
+// ordinal forms of the last element, matched on the end of the cardinal
+const ORDINALS: [(&str, &str); 22] = [
+    ("nulla", "nulladik"),
+    ("egy", "egyedik"),
+    ("kettő", "kettedik"),
+    ("három", "harmadik"),
+    ("négy", "negyedik"),
+    ("öt", "ötödik"),
+    ("hat", "hatodik"),
+    ("hét", "hetedik"),
+    ("nyolc", "nyolcadik"),
+    ("kilenc", "kilencedik"),
+    ("tíz", "tizedik"),
+    ("húsz", "huszadik"),
+    ("harminc", "harmincadik"),
+    ("negyven", "negyvenedik"),
+    ("ötven", "ötvenedik"),
+    ("hatvan", "hatvanadik"),
+    ("hetven", "hetvenedik"),
+    ("nyolcvan", "nyolcvanadik"),
+    ("kilencven", "kilencvenedik"),
+    ("száz", "századik"),
+    ("ezer", "ezredik"),
+    ("ió", "iomodik"),
+];
+
+impl Hungarian {
+    pub fn new() -> Self {
+        Self { zero_major: false }
+    }
+
+    /// Keeps the major unit clause for amounts below one, e.g. `nulla forint
+    /// húsz fillér`
+    pub fn zero_major(self, zero_major: bool) -> Self {
+        Self { zero_major }
+    }
+
+    fn currencies(&self, currency: Currency) -> String {
+        match currency {
+            Currency::HUF => String::from("forint"),
+            Currency::EUR => String::from("euró"),
+            Currency::AUD
+            | Currency::CAD
+            | Currency::DOLLAR
+            | Currency::HKD
+            | Currency::NZD
+            | Currency::SGD
+            | Currency::TWD
+            | Currency::USD => String::from("dollár"),
+            Currency::GBP => String::from("font"),
+            Currency::CHF => String::from("svájci frank"),
+            Currency::JPY => String::from("jen"),
+            _ => currency.default_string(false),
+        }
+    }
+
+    fn cents(&self, currency: Currency) -> String {
+        match currency {
+            Currency::HUF => String::from("fillér"),
+            Currency::GBP => String::from("penny"),
+            _ => currency.default_subunit_string("cent", false),
+        }
+    }
+
+    fn units(&self, unit: Unit) -> &'static str {
+        match unit {
+            Unit::Meter => "méter",
+            Unit::Kilometer => "kilométer",
+            Unit::Gram => "gramm",
+            Unit::Kilogram => "kilogramm",
+            Unit::Liter => "liter",
+            Unit::Second => "másodperc",
+            Unit::Minute => "perc",
+            Unit::Hour => "óra",
+        }
+    }
+
+    fn split_thousands(&self, mut num: BigFloat) -> Vec<u64> {
+        let mut thousands = Vec::new();
+        let bf_1000 = BigFloat::from(1000);
+
+        while !num.is_zero() {
+            thousands.push((num % bf_1000).to_u64().unwrap());
+            num /= bf_1000;
+        }
+
+        thousands
+    }
+
+    // `attributive` gives "két" rather than "kettő" when the triplet is
+    // followed by a multiplier or a noun
+    fn triplet_word(&self, triplet: u64, attributive: bool) -> String {
+        let hundreds = (triplet / 100) as usize;
+        let tens = (triplet / 10 % 10) as usize;
+        let units = (triplet % 10) as usize;
+        let mut word = String::new();
+
+        match hundreds {
+            0 => (),
+            1 => word.push_str("száz"),
+            2 => word.push_str("kétszáz"),
+            _ => {
+                word.push_str(UNITS[hundreds - 1]);
+                word.push_str("száz");
+            }
+        }
+
+        if tens > 0 {
+            let (alone, compound) = TENS[tens - 1];
+            word.push_str(if units > 0 { compound } else { alone });
+        }
+
+        match units {
+            0 => (),
+            2 if attributive => word.push_str("két"),
+            _ => word.push_str(UNITS[units - 1]),
+        }
+
+        word
+    }
+
+    fn int_to_cardinal(&self, num: BigFloat, attributive: bool) -> Result<String, Num2Err> {
+        // special case zero
+        if num.is_zero() {
+            return Ok(String::from("nulla"));
+        }
+
+        let mut groups = vec![];
+        for (i, triplet) in self.split_thousands(num.abs()).iter().enumerate().rev() {
+            if *triplet == 0 {
+                continue;
+            }
+
+            groups.push(match i {
+                0 => self.triplet_word(*triplet, attributive),
+                // "ezer" rather than "egyezer" at the start of a number
+                1 if *triplet == 1 && groups.is_empty() => String::from("ezer"),
+                1 => format!("{}ezer", self.triplet_word(*triplet, true)),
+                _ => {
+                    let mega = MEGAS.get(i - 2).ok_or(Num2Err::CannotConvert)?;
+                    format!("{}{}", self.triplet_word(*triplet, true), mega)
+                }
+            });
+        }
+
+        // AkH. 289.: above 2000, the groups of three digits are separated by a
+        // hyphen, e.g. "kétezer-egy" but "ezerkilencszázkilencvenkilenc"
+        let separator = if num.abs() > BigFloat::from(2000) {
+            "-"
+        } else {
+            ""
+        };
+        let words = groups.join(separator);
+
+        // handling negative values
+        if num.is_negative() {
+            Ok(format!("mínusz {}", words))
+        } else {
+            Ok(words)
+        }
+    }
+
+    fn float_to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let mut words = vec![];
+        if num.is_negative() {
+            words.push(String::from("mínusz"));
+        }
+        words.push(self.int_to_cardinal(num.int().abs(), false)?);
+        words.push(String::from("egész"));
+
+        let mut ordinal_part = num.frac().abs();
+        while !ordinal_part.is_zero() {
+            let digit = (ordinal_part * BigFloat::from(10)).int();
+            ordinal_part = (ordinal_part * BigFloat::from(10)).frac();
+            words.push(self.int_to_cardinal(digit, false)?);
+        }
+
+        Ok(words.join(" "))
+    }
+
+    // the counted noun stays in the singular, e.g. "negyvenkét forint"
+    fn with_noun(&self, num: BigFloat, noun: &str) -> Result<String, Num2Err> {
+        if num.is_inf() {
+            return Ok(format!(
+                "{}végtelen {}",
+                if num.is_negative() { "mínusz " } else { "" },
+                noun
+            ));
+        }
+
+        let number = if num.frac().is_zero() {
+            self.int_to_cardinal(num, true)?
+        } else {
+            self.float_to_cardinal(num)?
+        };
+        Ok(format!("{} {}", number, noun))
+    }
+}
+
+impl Default for Hungarian {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Language for Hungarian {
+    fn to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num.is_inf_pos() {
+            Ok(String::from("végtelen"))
+        } else if num.is_inf_neg() {
+            Ok(String::from("mínusz végtelen"))
+        } else if num.frac().is_zero() {
+            self.int_to_cardinal(num, false)
+        } else {
+            self.float_to_cardinal(num)
+        }
+    }
+
+    fn to_ordinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num == BigFloat::from(1) {
+            return Ok(String::from("első"));
+        } else if num == BigFloat::from(2) {
+            return Ok(String::from("második"));
+        }
+
+        let cardinal_word = self.int_to_cardinal(num, false)?;
+        if cardinal_word.ends_with("árd") {
+            return Ok(format!("{}odik", cardinal_word));
+        }
+        for (cardinal, ordinal) in ORDINALS {
+            if let Some(stem) = cardinal_word.strip_suffix(cardinal) {
+                return Ok(format!("{}{}", stem, ordinal));
+            }
+        }
+
+        Err(Num2Err::CannotConvert)
+    }
+
+    fn to_ordinal_num(&self, num: BigFloat) -> Result<String, Num2Err> {
+        Ok(format!("{}.", num.to_u128().unwrap()))
+    }
+
+    fn to_year(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if !num.frac().is_zero() {
+            return Err(Num2Err::FloatingYear);
+        }
+
+        let year_word = self.int_to_cardinal(num.abs(), false)?;
+        if num.is_negative() {
+            Ok(format!("időszámításunk előtt {}", year_word))
+        } else {
+            Ok(year_word)
+        }
+    }
+
+    fn to_currency(&self, num: BigFloat, currency: Currency) -> Result<String, Num2Err> {
+        if num.is_inf() || num.frac().is_zero() {
+            return self.with_noun(num, &self.currencies(currency));
+        }
+
+        let integral_part = num.int();
+        let cents_nb = (num * BigFloat::from(100)).int() % BigFloat::from(100);
+        let cents_words = self.with_noun(cents_nb, &self.cents(currency))?;
+        let integral_word = self.to_currency(integral_part, currency)?;
+
+        if cents_nb.is_zero() {
+            Ok(integral_word)
+        } else if integral_part.is_zero() && !self.zero_major {
+            Ok(cents_words)
+        } else {
+            Ok(format!("{} {}", integral_word, cents_words))
+        }
+    }
+
+    fn to_percent(&self, num: BigFloat) -> Result<String, Num2Err> {
+        self.with_noun(num, "százalék")
+    }
+
+    fn to_unit(&self, num: BigFloat, unit: Unit) -> Result<String, Num2Err> {
+        self.with_noun(num, self.units(unit))
+    }
+
+    fn plural_category(&self, num: BigFloat) -> PluralCategory {
+        if num.abs() == BigFloat::from(1) {
+            PluralCategory::One
+        } else {
+            PluralCategory::Other
+        }
+    }
+
+    fn agreement(&self, num: BigFloat) -> Agreement {
+        Agreement {
+            number: GrammaticalNumber::Singular,
+            ..Agreement::from_category(self.plural_category(num))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Currency, GrammaticalNumber, Lang, Num2Words};
+
+    #[test]
+    fn test_cardinal() {
+        for (num, words) in [
+            (0, "nulla"),
+            (2, "kettő"),
+            (10, "tíz"),
+            (12, "tizenkettő"),
+            (20, "húsz"),
+            (21, "huszonegy"),
+            (42, "negyvenkettő"),
+            (100, "száz"),
+            (200, "kétszáz"),
+            (1000, "ezer"),
+            (1999, "ezerkilencszázkilencvenkilenc"),
+            (2000, "kétezer"),
+            (2001, "kétezer-egy"),
+            (12000, "tizenkétezer"),
+            (1000000, "egymillió"),
+            (2300000, "kétmillió-háromszázezer"),
+            (1001000, "egymillió-egyezer"),
+            (-42, "mínusz negyvenkettő"),
+        ] {
+            assert_eq!(
+                Num2Words::new(num).lang(Lang::Hungarian).to_words(),
+                Ok(String::from(words))
+            );
+        }
+        assert_eq!(
+            Num2Words::new(2.5).lang(Lang::Hungarian).to_words(),
+            Ok(String::from("kettő egész öt"))
+        );
+    }
+
+    #[test]
+    fn test_ordinal() {
+        for (num, words) in [
+            (0, "nulladik"),
+            (1, "első"),
+            (2, "második"),
+            (3, "harmadik"),
+            (10, "tizedik"),
+            (11, "tizenegyedik"),
+            (20, "huszadik"),
+            (42, "negyvenkettedik"),
+            (100, "századik"),
+            (1000, "ezredik"),
+            (2001, "kétezer-egyedik"),
+            (1000000, "egymilliomodik"),
+            (1000000000, "egymilliárdodik"),
+        ] {
+            assert_eq!(
+                Num2Words::new(num)
+                    .lang(Lang::Hungarian)
+                    .ordinal()
+                    .to_words(),
+                Ok(String::from(words))
+            );
+        }
+        assert_eq!(
+            Num2Words::new(42)
+                .lang(Lang::Hungarian)
+                .ordinal_num()
+                .to_words(),
+            Ok(String::from("42."))
+        );
+    }
+
+    #[test]
+    fn test_year() {
+        assert_eq!(
+            Num2Words::new(1999).lang(Lang::Hungarian).year().to_words(),
+            Ok(String::from("ezerkilencszázkilencvenkilenc"))
+        );
+        assert_eq!(
+            Num2Words::new(2024).lang(Lang::Hungarian).year().to_words(),
+            Ok(String::from("kétezer-huszonnégy"))
+        );
+    }
+
+    #[test]
+    fn test_currency() {
+        for (num, words) in [
+            (1.0, "egy forint"),
+            (42.0, "negyvenkét forint"),
+            (2000.0, "kétezer forint"),
+            (2.5, "két forint ötven fillér"),
+            (0.02, "két fillér"),
+        ] {
+            assert_eq!(
+                Num2Words::new(num)
+                    .lang(Lang::Hungarian)
+                    .currency(Currency::HUF)
+                    .to_words(),
+                Ok(String::from(words))
+            );
+        }
+        assert_eq!(
+            Num2Words::new(42)
+                .lang(Lang::Hungarian)
+                .currency(Currency::EUR)
+                .to_words(),
+            Ok(String::from("negyvenkét euró"))
+        );
+        assert_eq!(
+            Num2Words::new(12)
+                .lang(Lang::Hungarian)
+                .agreement()
+                .map(|agreement| agreement.number),
+            Ok(GrammaticalNumber::Singular)
+        );
+    }
+}
@@ -0,0 +1,516 @@
+use crate::{num2words::Num2Err, Currency, Language, PluralCategory, Unit};
+use num_bigfloat::BigFloat;
+
+pub struct Armenian {
+    prefer_year_suffix: bool,
+    zero_major: bool,
+}
+
+const UNITS: [&str; 9] = [
+    "մեկ",
+    "երկու",
+    "երեք",
+    "չորս",
+    "հինգ",
+    "վեց",
+    "յոթ",
+    "ութ",
+    "ինը",
+];
+
+const TENS: [&str; 9] = [
+    "տասը",
+    "քսան",
+    "երեսուն",
+    "քառասուն",
+    "հիսուն",
+    "վաթսուն",
+    "յոթանասուն",
+    "ութսուն",
+    "իննսուն",
+];
+
+// 10 is "տասը" on its own but "տասն" when compounded, e.g. տասներկու (12)
+const TEN_PREFIX: &str = "տասն";
+
+// Short scale, with միլիարդ for 10^9
+const MEGAS: [&str; 10] = [
+    "հազար",
+    "միլիոն",
+    "միլիարդ",
+    "տրիլիոն",
+    "կվադրիլիոն",
+    "կվինտիլիոն",
+    "սեքստիլիոն",
+    "սեպտիլիոն",
+    "օկտիլիոն",
+    "նոնիլիոն",
+];
+
+impl Armenian {
+    pub fn new(prefer_year_suffix: bool) -> Self {
+        Self {
+            prefer_year_suffix,
+            zero_major: false,
+        }
+    }
+
+    /// Keeps the major unit clause for amounts below one, e.g. `զրո դրամ և
+    /// քսան լումա`
+    pub fn zero_major(self, zero_major: bool) -> Self {
+        Self { zero_major, ..self }
+    }
+
+    fn currencies(&self, currency: Currency) -> String {
+        match currency {
+            Currency::AMD => String::from("դրամ"),
+            Currency::AUD => String::from("ավստրալիական դոլար"),
+            Currency::CAD => String::from("կանադական դոլար"),
+            Currency::CHF => String::from("շվեյցարական ֆրանկ"),
+            Currency::CNY => String::from("յուան"),
+            Currency::DOLLAR => String::from("դոլար"),
+            Currency::EUR => String::from("եվրո"),
+            Currency::GBP => String::from("ֆունտ ստեռլինգ"),
+            Currency::JPY => String::from("իեն"),
+            Currency::RUB => String::from("ռուբլի"),
+            Currency::UAH => String::from("գրիվնա"),
+            Currency::USD => String::from("ԱՄՆ դոլար"),
+            _ => currency.default_string(false),
+        }
+    }
+
+    fn cents(&self, currency: Currency) -> String {
+        match currency {
+            Currency::AMD => String::from("լումա"),
+            Currency::EUR => String::from("եվրոցենտ"),
+            Currency::GBP => String::from("պենս"),
+            Currency::RUB => String::from("կոպեկ"),
+            Currency::UAH => String::from("կոպեկ"),
+            _ => String::from("ցենտ"),
+        }
+    }
+
+    fn units(&self, unit: Unit) -> &'static str {
+        match unit {
+            Unit::Meter => "մետր",
+            Unit::Kilometer => "կիլոմետր",
+            Unit::Gram => "գրամ",
+            Unit::Kilogram => "կիլոգրամ",
+            Unit::Liter => "լիտր",
+            Unit::Second => "վայրկյան",
+            Unit::Minute => "րոպե",
+            Unit::Hour => "ժամ",
+        }
+    }
+
+    fn split_thousands(&self, mut num: BigFloat) -> Vec<u64> {
+        let mut thousands = Vec::new();
+        let bf_1000 = BigFloat::from(1000);
+
+        while !num.is_zero() {
+            thousands.push((num % bf_1000).to_u64().unwrap());
+            num /= bf_1000;
+        }
+
+        thousands
+    }
+
+    fn int_to_cardinal(&self, mut num: BigFloat) -> Result<String, Num2Err> {
+        // special case zero
+        if num.is_zero() {
+            return Ok(String::from("զրո"));
+        }
+
+        // handling negative values
+        let mut words = vec![];
+        if num.is_negative() {
+            words.push(String::from("մինուս"));
+            num = -num;
+        }
+
+        // iterate over thousands
+        for (i, triplet) in self.split_thousands(num).iter().enumerate().rev() {
+            let hundreds = (triplet / 100 % 10) as usize;
+            let tens = (triplet / 10 % 10) as usize;
+            let units = (triplet % 10) as usize;
+
+            if hundreds > 1 {
+                words.push(String::from(UNITS[hundreds - 1]));
+            }
+            if hundreds > 0 {
+                // one hundred is simply հարյուր
+                words.push(String::from("հարյուր"));
+            }
+
+            // tens and units are written as a single word, e.g. քառասուներկու
+            match (tens, units) {
+                (0, 0) => {}
+                (0, _) => {
+                    // a lone thousand is simply հազար
+                    if !(i == 1 && units == 1 && hundreds == 0) {
+                        words.push(String::from(UNITS[units - 1]));
+                    }
+                }
+                (1, 0) => words.push(String::from(TENS[0])),
+                (1, _) => words.push(format!("{}{}", TEN_PREFIX, UNITS[units - 1])),
+                (_, 0) => words.push(String::from(TENS[tens - 1])),
+                _ => words.push(format!("{}{}", TENS[tens - 1], UNITS[units - 1])),
+            }
+
+            if i != 0 && triplet != &0 {
+                if i > MEGAS.len() {
+                    return Err(Num2Err::CannotConvert);
+                }
+                words.push(String::from(MEGAS[i - 1]));
+            }
+        }
+
+        Ok(words.join(" "))
+    }
+
+    fn float_to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let integral_part = num.int();
+        let mut words: Vec<String> = vec![];
+
+        if !integral_part.is_zero() {
+            let integral_word = self.int_to_cardinal(integral_part)?;
+            words.push(integral_word);
+        } else if num.is_negative() {
+            words.push(String::from("մինուս"));
+        }
+        if integral_part.is_zero() {
+            words.push(String::from("զրո"));
+        }
+
+        let mut ordinal_part = num.frac().abs();
+        if !ordinal_part.is_zero() {
+            words.push(String::from("ստորակետ"));
+        }
+        while !ordinal_part.is_zero() {
+            let digit = (ordinal_part * BigFloat::from(10)).int();
+            ordinal_part = (ordinal_part * BigFloat::from(10)).frac();
+            words.push(match digit.to_u64().unwrap() {
+                0 => String::from("զրո"),
+                i => String::from(UNITS[i as usize - 1]),
+            });
+        }
+        Ok(words.join(" "))
+    }
+}
+
+impl Language for Armenian {
+    fn to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num.is_inf_pos() {
+            Ok(String::from("անվերջություն"))
+        } else if num.is_inf_neg() {
+            Ok(String::from("մինուս անվերջություն"))
+        } else if num.frac().is_zero() {
+            self.int_to_cardinal(num)
+        } else {
+            self.float_to_cardinal(num)
+        }
+    }
+
+    fn to_ordinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        // only the first four ordinals are irregular
+        match num.to_u64() {
+            Some(1) => return Ok(String::from("առաջին")),
+            Some(2) => return Ok(String::from("երկրորդ")),
+            Some(3) => return Ok(String::from("երրորդ")),
+            Some(4) => return Ok(String::from("չորրորդ")),
+            _ => {}
+        }
+
+        let cardinal_word = self.to_cardinal(num)?;
+        // compounds keep the irregular stems of 2, 3 and 4, e.g. տասներկրորդ,
+        // but not the suppletive one of 1, e.g. տասնմեկերորդ
+        let ordinal_word = if let Some(stem) = cardinal_word.strip_suffix("երկու") {
+            format!("{}երկրորդ", stem)
+        } else if let Some(stem) = cardinal_word.strip_suffix("երեք") {
+            format!("{}երրորդ", stem)
+        } else if let Some(stem) = cardinal_word.strip_suffix("չորս") {
+            format!("{}չորրորդ", stem)
+        } else if let Some(stem) = cardinal_word.strip_suffix('ը') {
+            // e.g. ինը => իններորդ, տասը => տասներորդ
+            format!("{}ներորդ", stem)
+        } else {
+            format!("{}երորդ", cardinal_word)
+        };
+
+        Ok(ordinal_word)
+    }
+
+    fn to_ordinal_num(&self, num: BigFloat) -> Result<String, Num2Err> {
+        Ok(format!(
+            "{}-{}",
+            num.to_u128().unwrap(),
+            if num == BigFloat::from(1) {
+                "ին"
+            } else {
+                "րդ"
+            }
+        ))
+    }
+
+    fn to_year(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if !num.frac().is_zero() {
+            return Err(Num2Err::FloatingYear);
+        }
+
+        let mut words = vec![self.int_to_cardinal(num.abs())?];
+        if self.prefer_year_suffix {
+            words.push(String::from("թվական"));
+        }
+        if num.is_negative() {
+            words.push(String::from("մ.թ.ա."));
+        }
+
+        Ok(words.join(" "))
+    }
+
+    fn to_currency(&self, num: BigFloat, currency: Currency) -> Result<String, Num2Err> {
+        // nouns stay in the singular after a numeral, e.g. քառասուներկու դրամ
+        if num.is_inf() {
+            Ok(format!(
+                "{}անվերջ {}",
+                if num.is_negative() {
+                    "մինուս "
+                } else {
+                    ""
+                },
+                self.currencies(currency)
+            ))
+        } else if num.frac().is_zero() {
+            let words = self.int_to_cardinal(num)?;
+            Ok(format!("{} {}", words, self.currencies(currency)))
+        } else {
+            let integral_part = num.int();
+            let cents_nb = (num * BigFloat::from(100)).int() % BigFloat::from(100);
+            let cents_words = self.int_to_cardinal(cents_nb)?;
+            let cents_suffix = self.cents(currency);
+            let integral_word = self.to_currency(integral_part, currency)?;
+
+            if cents_nb.is_zero() {
+                Ok(integral_word)
+            } else if integral_part.is_zero() && !self.zero_major {
+                Ok(format!("{} {}", cents_words, cents_suffix))
+            } else {
+                Ok(format!(
+                    "{} և {} {}",
+                    integral_word, cents_words, cents_suffix
+                ))
+            }
+        }
+    }
+
+    fn to_percent(&self, num: BigFloat) -> Result<String, Num2Err> {
+        Ok(format!("{} տոկոս", self.to_cardinal(num)?))
+    }
+
+    fn to_unit(&self, num: BigFloat, unit: Unit) -> Result<String, Num2Err> {
+        if num.is_inf() {
+            Ok(format!(
+                "{}անվերջ {}",
+                if num.is_negative() {
+                    "մինուս "
+                } else {
+                    ""
+                },
+                self.units(unit)
+            ))
+        } else {
+            Ok(format!("{} {}", self.to_cardinal(num)?, self.units(unit)))
+        }
+    }
+
+    fn plural_category(&self, num: BigFloat) -> PluralCategory {
+        // CLDR: one is i = 0,1
+        if num.abs() < BigFloat::from(2) {
+            PluralCategory::One
+        } else {
+            PluralCategory::Other
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_cardinal() {
+        assert_eq!(
+            Num2Words::new(0).lang(Lang::Armenian).to_words(),
+            Ok(String::from("զրո"))
+        );
+        assert_eq!(
+            Num2Words::new(42).lang(Lang::Armenian).to_words(),
+            Ok(String::from("քառասուներկու"))
+        );
+        assert_eq!(
+            Num2Words::new(-19).lang(Lang::Armenian).to_words(),
+            Ok(String::from("մինուս տասնինը"))
+        );
+        assert_eq!(
+            Num2Words::new(1000).lang(Lang::Armenian).to_words(),
+            Ok(String::from("հազար"))
+        );
+        assert_eq!(
+            Num2Words::new(2_024).lang(Lang::Armenian).to_words(),
+            Ok(String::from("երկու հազար քսանչորս"))
+        );
+        assert_eq!(
+            Num2Words::new(1_101_110).lang(Lang::Armenian).to_words(),
+            Ok(String::from("մեկ միլիոն հարյուր մեկ հազար հարյուր տասը"))
+        );
+        assert_eq!(
+            Num2Words::new(2.25).lang(Lang::Armenian).to_words(),
+            Ok(String::from("երկու ստորակետ երկու հինգ"))
+        );
+        assert_eq!(
+            Num2Words::new(0.5).lang(Lang::Armenian).to_words(),
+            Ok(String::from("զրո ստորակետ հինգ"))
+        );
+        assert_eq!(
+            Num2Words::new(-0.25).lang(Lang::Armenian).to_words(),
+            Ok(String::from("մինուս զրո ստորակետ երկու հինգ"))
+        );
+    }
+
+    #[test]
+    fn test_ordinal() {
+        assert_eq!(
+            Num2Words::new(1).lang(Lang::Armenian).ordinal().to_words(),
+            Ok(String::from("առաջին"))
+        );
+        assert_eq!(
+            Num2Words::new(2).lang(Lang::Armenian).ordinal().to_words(),
+            Ok(String::from("երկրորդ"))
+        );
+        assert_eq!(
+            Num2Words::new(3).lang(Lang::Armenian).ordinal().to_words(),
+            Ok(String::from("երրորդ"))
+        );
+        assert_eq!(
+            Num2Words::new(4).lang(Lang::Armenian).ordinal().to_words(),
+            Ok(String::from("չորրորդ"))
+        );
+        assert_eq!(
+            Num2Words::new(5).lang(Lang::Armenian).ordinal().to_words(),
+            Ok(String::from("հինգերորդ"))
+        );
+        assert_eq!(
+            Num2Words::new(10).lang(Lang::Armenian).ordinal().to_words(),
+            Ok(String::from("տասներորդ"))
+        );
+        assert_eq!(
+            Num2Words::new(11).lang(Lang::Armenian).ordinal().to_words(),
+            Ok(String::from("տասնմեկերորդ"))
+        );
+        assert_eq!(
+            Num2Words::new(12).lang(Lang::Armenian).ordinal().to_words(),
+            Ok(String::from("տասներկրորդ"))
+        );
+        assert_eq!(
+            Num2Words::new(19).lang(Lang::Armenian).ordinal().to_words(),
+            Ok(String::from("տասնիններորդ"))
+        );
+        assert_eq!(
+            Num2Words::new(42).lang(Lang::Armenian).ordinal().to_words(),
+            Ok(String::from("քառասուներկրորդ"))
+        );
+        assert_eq!(
+            Num2Words::new(100)
+                .lang(Lang::Armenian)
+                .ordinal()
+                .to_words(),
+            Ok(String::from("հարյուրերորդ"))
+        );
+    }
+
+    #[test]
+    fn test_ordinal_num() {
+        assert_eq!(
+            Num2Words::new(1)
+                .lang(Lang::Armenian)
+                .ordinal_num()
+                .to_words(),
+            Ok(String::from("1-ին"))
+        );
+        assert_eq!(
+            Num2Words::new(42)
+                .lang(Lang::Armenian)
+                .ordinal_num()
+                .to_words(),
+            Ok(String::from("42-րդ"))
+        );
+    }
+
+    #[test]
+    fn test_year() {
+        assert_eq!(
+            Num2Words::new(1991).lang(Lang::Armenian).year().to_words(),
+            Ok(String::from("հազար ինը հարյուր իննսունմեկ"))
+        );
+        assert_eq!(
+            Num2Words::new(1991)
+                .lang(Lang::Armenian)
+                .year()
+                .prefer("թվական")
+                .to_words(),
+            Ok(String::from("հազար ինը հարյուր իննսունմեկ թվական"))
+        );
+        assert_eq!(
+            Num2Words::new(-44).lang(Lang::Armenian).year().to_words(),
+            Ok(String::from("քառասունչորս մ.թ.ա."))
+        );
+    }
+
+    #[test]
+    fn test_currency() {
+        assert_eq!(
+            Num2Words::new(42)
+                .lang(Lang::Armenian)
+                .currency(Currency::AMD)
+                .to_words(),
+            Ok(String::from("քառասուներկու դրամ"))
+        );
+        assert_eq!(
+            Num2Words::new(1.5)
+                .lang(Lang::Armenian)
+                .currency(Currency::AMD)
+                .to_words(),
+            Ok(String::from("մեկ դրամ և հիսուն լումա"))
+        );
+        assert_eq!(
+            Num2Words::new(0.2)
+                .lang(Lang::Armenian)
+                .currency(Currency::AMD)
+                .prefer("zero-major")
+                .to_words(),
+            Ok(String::from("զրո դրամ և քսան լումա"))
+        );
+        assert_eq!(
+            Num2Words::new(21)
+                .lang(Lang::Armenian)
+                .currency(Currency::EUR)
+                .to_words(),
+            Ok(String::from("քսանմեկ եվրո"))
+        );
+    }
+
+    #[test]
+    fn test_percent_and_unit() {
+        assert_eq!(
+            Num2Words::new(25).lang(Lang::Armenian).percent().to_words(),
+            Ok(String::from("քսանհինգ տոկոս"))
+        );
+        assert_eq!(
+            Num2Words::new(2)
+                .lang(Lang::Armenian)
+                .unit(Unit::Hour)
+                .to_words(),
+            Ok(String::from("երկու ժամ"))
+        );
+    }
+}
@@ -0,0 +1,952 @@
+use crate::{num2words::Num2Err, Currency, Declension, Gender, Language, PluralCategory, Unit};
+use num_bigfloat::BigFloat;
+
+// Icelandic only has four cases: the instrumental and the locative of other
+// languages are expressed with the dative.
+//
+// Only one to four (and compounds ending with them) decline, the cardinal
+// numbers from five onwards are invariant.
+
+#[derive(Clone, Copy)]
+pub struct Icelandic {
+    gender: Gender,
+    declension: Declension,
+    zero_major: bool,
+}
+
+/// Parses an Icelandic (kk, kvk, hk) or generic gender preference
+pub(crate) fn gender_from_str(s: &str) -> Option<Gender> {
+    match s.to_lowercase().as_str() {
+        "kk" | "karlkyn" => Some(Gender::Masculine),
+        "kvk" | "kvenkyn" => Some(Gender::Feminine),
+        "hk" | "hvorugkyn" => Some(Gender::Neuter),
+        other => other.parse().ok(),
+    }
+}
+
+/// Parses an Icelandic (nf, þf, þgf, ef) or generic case preference
+pub(crate) fn declension_from_str(s: &str) -> Option<Declension> {
+    match s.to_lowercase().as_str() {
+        "nf" | "nefnifall" => Some(Declension::Nominative),
+        "þf" | "þolfall" => Some(Declension::Accusative),
+        "þgf" | "þágufall" => Some(Declension::Dative),
+        "ef" | "eignarfall" => Some(Declension::Genitive),
+        other => other.parse().ok(),
+    }
+}
+
+// [number][gender][case], with cases ordered as nom, acc, dat, gen
+#[rustfmt::skip]
+const DECLINED_UNITS: [[[&str; 4]; 3]; 4] = [
+    [
+        [ "einn",   "einn",   "einum",   "eins"     ],
+        [ "ein",    "eina",   "einni",   "einnar"   ],
+        [ "eitt",   "eitt",   "einu",    "eins"     ],
+    ],
+    [
+        [ "tveir",  "tvo",    "tveimur", "tveggja"  ],
+        [ "tvær",   "tvær",   "tveimur", "tveggja"  ],
+        [ "tvö",    "tvö",    "tveimur", "tveggja"  ],
+    ],
+    [
+        [ "þrír",   "þrjá",   "þremur",  "þriggja"  ],
+        [ "þrjár",  "þrjár",  "þremur",  "þriggja"  ],
+        [ "þrjú",   "þrjú",   "þremur",  "þriggja"  ],
+    ],
+    [
+        [ "fjórir", "fjóra",  "fjórum",  "fjögurra" ],
+        [ "fjórar", "fjórar", "fjórum",  "fjögurra" ],
+        [ "fjögur", "fjögur", "fjórum",  "fjögurra" ],
+    ],
+];
+
+const UNITS: [&str; 5] = ["fimm", "sex", "sjö", "átta", "níu"];
+
+const TEENS: [&str; 10] = [
+    "tíu",
+    "ellefu",
+    "tólf",
+    "þrettán",
+    "fjórtán",
+    "fimmtán",
+    "sextán",
+    "sautján",
+    "átján",
+    "nítján",
+];
+
+const TENS: [&str; 8] = [
+    "tuttugu",
+    "þrjátíu",
+    "fjörutíu",
+    "fimmtíu",
+    "sextíu",
+    "sjötíu",
+    "áttatíu",
+    "níutíu",
+];
+
+// (singular, plural, gender), alternating -ón and -arður names
+const MEGAS: [(&str, &str, Gender); 9] = [
+    ("þúsund", "þúsund", Gender::Neuter),
+    ("milljón", "milljónir", Gender::Feminine),
+    ("milljarður", "milljarðar", Gender::Masculine),
+    ("billjón", "billjónir", Gender::Feminine),
+    ("billjarður", "billjarðar", Gender::Masculine),
+    ("trilljón", "trilljónir", Gender::Feminine),
+    ("trilljarður", "trilljarðar", Gender::Masculine),
+    ("kvaðrilljón", "kvaðrilljónir", Gender::Feminine),
+    ("kvaðrilljarður", "kvaðrilljarðar", Gender::Masculine),
+];
+
+// "annar" is the only ordinal with a strong declension, [gender][case]
+#[rustfmt::skip]
+const SECOND: [[&str; 4]; 3] = [
+    [ "annar",  "annan", "öðrum",   "annars"   ],
+    [ "önnur",  "aðra",  "annarri", "annarrar" ],
+    [ "annað",  "annað", "öðru",    "annars"   ],
+];
+
+// weak adjective endings of the other ordinals, [gender][case]
+#[rustfmt::skip]
+const WEAK_ENDINGS: [[&str; 4]; 3] = [
+    [ "i", "a", "a", "a" ],
+    [ "a", "u", "u", "u" ],
+    [ "a", "a", "a", "a" ],
+];
+
+const UNIT_ORDINAL_STEMS: [&str; 9] = [
+    "fyrst", "", "þriðj", "fjórð", "fimmt", "sjött", "sjöund", "áttund", "níund",
+];
+
+const TEEN_ORDINAL_STEMS: [&str; 10] = [
+    "tíund",
+    "elleft",
+    "tólft",
+    "þrettánd",
+    "fjórtánd",
+    "fimmtánd",
+    "sextánd",
+    "sautjánd",
+    "átjánd",
+    "nítjánd",
+];
+
+const TEN_ORDINAL_STEMS: [&str; 8] = [
+    "tuttugast",
+    "þrítugast",
+    "fertugast",
+    "fimmtugast",
+    "sextugast",
+    "sjötugast",
+    "áttugast",
+    "nítugast",
+];
+
+// [number][case], with cases ordered as nom, acc, dat, gen
+#[rustfmt::skip]
+const KRONUR: [[&str; 4]; 2] = [
+    [ "króna",  "krónu",  "krónu",  "krónu" ],
+    [ "krónur", "krónur", "krónum", "króna" ],
+];
+
+#[rustfmt::skip]
+const EVRUR: [[&str; 4]; 2] = [
+    [ "evra",  "evru",  "evru",  "evru" ],
+    [ "evrur", "evrur", "evrum", "evra" ],
+];
+
+#[rustfmt::skip]
+const DALIR: [[&str; 4]; 2] = [
+    [ "dalur", "dal",  "dal",   "dals" ],
+    [ "dalir", "dali", "dölum", "dala" ],
+];
+
+#[rustfmt::skip]
+const PUND: [[&str; 4]; 2] = [
+    [ "pund", "pund", "pundi",  "punds" ],
+    [ "pund", "pund", "pundum", "punda" ],
+];
+
+#[rustfmt::skip]
+const SENT: [[&str; 4]; 2] = [
+    [ "sent", "sent", "senti",  "sents" ],
+    [ "sent", "sent", "sentum", "senta" ],
+];
+
+#[rustfmt::skip]
+const PROSENT: [[&str; 4]; 2] = [
+    [ "prósent", "prósent", "prósenti",  "prósents" ],
+    [ "prósent", "prósent", "prósentum", "prósenta" ],
+];
+
+#[rustfmt::skip]
+const METRAR: [[&str; 4]; 2] = [
+    [ "metri",  "metra", "metra",  "metra" ],
+    [ "metrar", "metra", "metrum", "metra" ],
+];
+
+#[rustfmt::skip]
+const GROMM: [[&str; 4]; 2] = [
+    [ "gramm", "gramm", "grammi",  "gramms" ],
+    [ "grömm", "grömm", "grömmum", "gramma" ],
+];
+
+#[rustfmt::skip]
+const LITRAR: [[&str; 4]; 2] = [
+    [ "lítri",  "lítra", "lítra",  "lítra" ],
+    [ "lítrar", "lítra", "lítrum", "lítra" ],
+];
+
+#[rustfmt::skip]
+const SEKUNDUR: [[&str; 4]; 2] = [
+    [ "sekúnda",  "sekúndu",  "sekúndu",  "sekúndu"  ],
+    [ "sekúndur", "sekúndur", "sekúndum", "sekúndna" ],
+];
+
+#[rustfmt::skip]
+const MINUTUR: [[&str; 4]; 2] = [
+    [ "mínúta",  "mínútu",  "mínútu",  "mínútu"  ],
+    [ "mínútur", "mínútur", "mínútum", "mínútna" ],
+];
+
+#[rustfmt::skip]
+const KLUKKUSTUNDIR: [[&str; 4]; 2] = [
+    [ "klukkustund",   "klukkustund",   "klukkustund",  "klukkustundar" ],
+    [ "klukkustundir", "klukkustundir", "klukkustundum", "klukkustunda" ],
+];
+
+fn gender_index(gender: Gender) -> usize {
+    match gender {
+        Gender::Masculine => 0,
+        Gender::Feminine => 1,
+        Gender::Neuter => 2,
+    }
+}
+
+fn join_with_og(components: &[String]) -> String {
+    match components.split_last() {
+        Some((last, [])) => last.clone(),
+        Some((last, rest)) => format!("{} og {}", rest.join(" "), last),
+        None => String::new(),
+    }
+}
+
+// "og" goes before the last component of the whole number, e.g. "eitt
+// þúsund og eitt hundrað", unless the last group already has its own, e.g.
+// "eitt þúsund fjörutíu og tveir"
+fn join_groups(groups: &[(String, usize)]) -> String {
+    match groups.split_last() {
+        Some(((last, 1), rest)) if !rest.is_empty() => {
+            let rest: Vec<&str> = rest.iter().map(|(g, _)| g.as_str()).collect();
+            format!("{} og {}", rest.join(" "), last)
+        }
+        _ => groups
+            .iter()
+            .map(|(g, _)| g.as_str())
+            .collect::<Vec<_>>()
+            .join(" "),
+    }
+}
+
+fn is_singular(num: u64) -> bool {
+    num % 10 == 1 && num % 100 != 11
+}
+
+impl Icelandic {
+    pub fn new(gender: Gender, declension: Declension) -> Self {
+        Self {
+            gender,
+            declension,
+            zero_major: false,
+        }
+    }
+
+    /// Keeps the major unit clause for amounts below one, e.g. `núll evrur
+    /// og tuttugu sent`
+    pub fn zero_major(self, zero_major: bool) -> Self {
+        Self { zero_major, ..self }
+    }
+
+    fn case_index(&self) -> usize {
+        match self.declension {
+            Declension::Nominative => 0,
+            Declension::Accusative => 1,
+            Declension::Genitive => 3,
+            _ => 2,
+        }
+    }
+
+    fn noun_form(&self, table: &[[&str; 4]; 2], num: BigFloat) -> String {
+        // a round million or more is a noun, which takes the genitive plural,
+        // e.g. "ein milljón dala"
+        if !num.is_zero() && num.frac().is_zero() && (num % BigFloat::from(1_000_000)).is_zero() {
+            return String::from(table[1][3]);
+        }
+        let plural = self.plural_category(num) != PluralCategory::One;
+        String::from(table[plural as usize][self.case_index()])
+    }
+
+    fn currencies(&self, currency: Currency, num: BigFloat) -> (String, Gender) {
+        match currency {
+            Currency::ISK => (self.noun_form(&KRONUR, num), Gender::Feminine),
+            Currency::EUR => (self.noun_form(&EVRUR, num), Gender::Feminine),
+            Currency::USD => (
+                format!("bandaríkja{}", self.noun_form(&DALIR, num)),
+                Gender::Masculine,
+            ),
+            Currency::AUD
+            | Currency::CAD
+            | Currency::DOLLAR
+            | Currency::HKD
+            | Currency::NZD
+            | Currency::SGD
+            | Currency::TWD => (self.noun_form(&DALIR, num), Gender::Masculine),
+            Currency::GBP => (
+                format!("sterlings{}", self.noun_form(&PUND, num)),
+                Gender::Neuter,
+            ),
+            _ => (
+                currency.default_string(self.plural_category(num) != PluralCategory::One),
+                Gender::Masculine,
+            ),
+        }
+    }
+
+    fn units(&self, unit: Unit, num: BigFloat) -> (String, Gender) {
+        match unit {
+            Unit::Meter => (self.noun_form(&METRAR, num), Gender::Masculine),
+            Unit::Kilometer => (
+                format!("kíló{}", self.noun_form(&METRAR, num)),
+                Gender::Masculine,
+            ),
+            Unit::Gram => (self.noun_form(&GROMM, num), Gender::Neuter),
+            Unit::Kilogram => (
+                format!("kíló{}", self.noun_form(&GROMM, num)),
+                Gender::Neuter,
+            ),
+            Unit::Liter => (self.noun_form(&LITRAR, num), Gender::Masculine),
+            Unit::Second => (self.noun_form(&SEKUNDUR, num), Gender::Feminine),
+            Unit::Minute => (self.noun_form(&MINUTUR, num), Gender::Feminine),
+            Unit::Hour => (self.noun_form(&KLUKKUSTUNDIR, num), Gender::Feminine),
+        }
+    }
+
+    fn with_gender(&self, gender: Gender) -> Self {
+        Self { gender, ..*self }
+    }
+
+    fn split_thousands(&self, mut num: BigFloat) -> Vec<u64> {
+        let mut thousands = Vec::new();
+        let bf_1000 = BigFloat::from(1000);
+
+        while !num.is_zero() {
+            thousands.push((num % bf_1000).to_u64().unwrap());
+            num /= bf_1000;
+        }
+
+        thousands
+    }
+
+    fn unit_word(&self, units: usize, gender: Gender, case: usize) -> &'static str {
+        if units <= 4 {
+            DECLINED_UNITS[units - 1][gender_index(gender)][case]
+        } else {
+            UNITS[units - 5]
+        }
+    }
+
+    fn triplet_components(&self, triplet: u64, gender: Gender, case: usize) -> Vec<String> {
+        let hundreds = (triplet / 100 % 10) as usize;
+        let tens = (triplet / 10 % 10) as usize;
+        let units = (triplet % 10) as usize;
+        let mut components = vec![];
+
+        if hundreds > 0 {
+            components.push(format!(
+                "{} {}",
+                self.unit_word(hundreds, Gender::Neuter, 0),
+                if hundreds == 1 {
+                    "hundrað"
+                } else {
+                    "hundruð"
+                }
+            ));
+        }
+
+        match tens {
+            0 => {
+                if units > 0 {
+                    components.push(String::from(self.unit_word(units, gender, case)));
+                }
+            }
+            1 => components.push(String::from(TEENS[units])),
+            _ => {
+                components.push(String::from(TENS[tens - 2]));
+                if units > 0 {
+                    components.push(String::from(self.unit_word(units, gender, case)));
+                }
+            }
+        }
+
+        components
+    }
+
+    // every non-zero group of thousands, with its number of components
+    fn groups(
+        &self,
+        num: BigFloat,
+        gender: Gender,
+        case: usize,
+    ) -> Result<Vec<(String, usize)>, Num2Err> {
+        let mut groups = vec![];
+
+        for (i, triplet) in self.split_thousands(num).iter().enumerate().rev() {
+            if *triplet == 0 {
+                continue;
+            }
+
+            let components = if i == 0 {
+                self.triplet_components(*triplet, gender, case)
+            } else {
+                if i > MEGAS.len() {
+                    return Err(Num2Err::CannotConvert);
+                }
+                let (singular, plural, mega_gender) = MEGAS[i - 1];
+                let mut components = self.triplet_components(*triplet, mega_gender, 0);
+                if let Some(last) = components.last_mut() {
+                    last.push(' ');
+                    last.push_str(if is_singular(*triplet) {
+                        singular
+                    } else {
+                        plural
+                    });
+                }
+                components
+            };
+
+            groups.push((join_with_og(&components), components.len()));
+        }
+
+        Ok(groups)
+    }
+
+    fn int_to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        // special case zero
+        if num.is_zero() {
+            return Ok(String::from("núll"));
+        }
+
+        let words = join_groups(&self.groups(num.abs(), self.gender, self.case_index())?);
+
+        // handling negative values
+        if num.is_negative() {
+            Ok(format!("mínus {}", words))
+        } else {
+            Ok(words)
+        }
+    }
+
+    fn float_to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let mut words = vec![];
+        if num.is_negative() {
+            words.push(String::from("mínus"));
+        }
+        words.push(self.int_to_cardinal(num.int().abs())?);
+        words.push(String::from("komma"));
+
+        let mut ordinal_part = num.frac().abs();
+        while !ordinal_part.is_zero() {
+            let digit = (ordinal_part * BigFloat::from(10)).int();
+            ordinal_part = (ordinal_part * BigFloat::from(10)).frac();
+            words.push(self.int_to_cardinal(digit)?);
+        }
+
+        Ok(words.join(" "))
+    }
+
+    fn ordinal_ending(&self) -> &'static str {
+        WEAK_ENDINGS[gender_index(self.gender)][self.case_index()]
+    }
+
+    fn unit_ordinal(&self, units: usize) -> String {
+        if units == 2 {
+            String::from(SECOND[gender_index(self.gender)][self.case_index()])
+        } else {
+            format!("{}{}", UNIT_ORDINAL_STEMS[units - 1], self.ordinal_ending())
+        }
+    }
+}
+
+impl Language for Icelandic {
+    fn to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num.is_inf_pos() {
+            Ok(String::from("óendanlegt"))
+        } else if num.is_inf_neg() {
+            Ok(String::from("mínus óendanlegt"))
+        } else if num.frac().is_zero() {
+            self.int_to_cardinal(num)
+        } else {
+            self.float_to_cardinal(num)
+        }
+    }
+
+    fn to_ordinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num.is_zero() {
+            return Ok(format!("núllt{}", self.ordinal_ending()));
+        }
+
+        let last_two = (num % BigFloat::from(100)).to_u64().unwrap();
+        let rest = num - BigFloat::from(last_two);
+
+        if last_two != 0 {
+            // only the tens and units take the ordinal form, e.g. "eitt
+            // hundrað fertugasti og annar"
+            let tens = (last_two / 10) as usize;
+            let units = (last_two % 10) as usize;
+            let components = match tens {
+                0 => vec![self.unit_ordinal(units)],
+                1 => vec![format!(
+                    "{}{}",
+                    TEEN_ORDINAL_STEMS[units],
+                    self.ordinal_ending()
+                )],
+                _ => {
+                    let mut components = vec![format!(
+                        "{}{}",
+                        TEN_ORDINAL_STEMS[tens - 2],
+                        self.ordinal_ending()
+                    )];
+                    if units > 0 {
+                        components.push(self.unit_ordinal(units));
+                    }
+                    components
+                }
+            };
+
+            let mut groups = if rest.is_zero() {
+                vec![]
+            } else {
+                self.groups(rest, Gender::Neuter, 0)?
+            };
+            groups.push((join_with_og(&components), components.len()));
+
+            return Ok(join_groups(&groups));
+        }
+
+        // the last word is a hundred or a power of a thousand, e.g. "tvö
+        // hundraðasti"
+        let cardinal_word = join_groups(&self.groups(num, Gender::Neuter, 0)?);
+        let mut words: Vec<&str> = cardinal_word.split_whitespace().collect();
+        let last = words.pop().unwrap_or_default();
+        let stem = if last == "hundrað" || last == "hundruð" {
+            String::from("hundrað")
+        } else {
+            let (singular, _, _) = MEGAS
+                .iter()
+                .find(|(singular, plural, _)| last == *singular || last == *plural)
+                .ok_or(Num2Err::CannotConvert)?;
+            String::from(singular.strip_suffix("ur").unwrap_or(singular))
+        };
+        // a lone hundred or thousand drops its "one", e.g. "þúsundasti"
+        if words.len() == 1 && ["eitt", "ein", "einn"].contains(&words[0]) {
+            words.clear();
+        }
+        let ordinal_word = format!("{}ast{}", stem, self.ordinal_ending());
+        // the count is fused in the neuter, e.g. "tvöhundraðasti"
+        let ordinal_word = match words.pop() {
+            Some(count) => {
+                let count = DECLINED_UNITS
+                    .iter()
+                    .find(|genders| genders.iter().any(|cases| cases[0] == count))
+                    .map_or(count, |genders| genders[gender_index(Gender::Neuter)][0]);
+                format!("{}{}", count, ordinal_word)
+            }
+            None => ordinal_word,
+        };
+        words.push(&ordinal_word);
+
+        Ok(words.join(" "))
+    }
+
+    fn to_ordinal_num(&self, num: BigFloat) -> Result<String, Num2Err> {
+        Ok(format!("{}.", num.to_u128().unwrap()))
+    }
+
+    fn to_year(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if !num.frac().is_zero() {
+            return Err(Num2Err::FloatingYear);
+        }
+
+        // years are counted in the neuter, as "ár" is
+        let neuter = Self {
+            gender: Gender::Neuter,
+            declension: Declension::Nominative,
+            ..*self
+        };
+        let year = num.abs();
+        let bf_100 = BigFloat::from(100);
+        let (high, low) = (
+            (year / bf_100).int().to_u64().unwrap(),
+            (year % bf_100).to_u64().unwrap(),
+        );
+
+        let year_word = if (11..20).contains(&high) {
+            // e.g. "nítján hundruð níutíu og eitt" (1991)
+            let mut groups = vec![(format!("{} hundruð", TEENS[high as usize - 10]), 1)];
+            if low != 0 {
+                let components = neuter.triplet_components(low, Gender::Neuter, 0);
+                groups.push((join_with_og(&components), components.len()));
+            }
+            join_groups(&groups)
+        } else {
+            neuter.int_to_cardinal(year)?
+        };
+
+        if num.is_negative() {
+            Ok(format!("{} fyrir Krist", year_word))
+        } else {
+            Ok(year_word)
+        }
+    }
+
+    fn to_currency(&self, num: BigFloat, currency: Currency) -> Result<String, Num2Err> {
+        if num.is_inf() {
+            let (noun, gender) = self.currencies(currency, num);
+            return Ok(format!(
+                "{}óendanlega {} {}",
+                if num.is_negative() { "mínus " } else { "" },
+                ["margir", "margar", "mörg"][gender_index(gender)],
+                noun
+            ));
+        }
+
+        // the eyrir is out of circulation, so krónur have no minor unit
+        let num = match currency {
+            Currency::ISK => num.int(),
+            _ => num,
+        };
+
+        if num.frac().is_zero() {
+            let (noun, gender) = self.currencies(currency, num);
+            Ok(format!(
+                "{} {}",
+                self.with_gender(gender).int_to_cardinal(num)?,
+                noun
+            ))
+        } else {
+            let integral_part = num.int();
+            let cents_nb = (num * BigFloat::from(100)).int() % BigFloat::from(100);
+            let cents_words = self.with_gender(Gender::Neuter).int_to_cardinal(cents_nb)?;
+            let cents_suffix = self.noun_form(&SENT, cents_nb);
+            let integral_word = self.to_currency(integral_part, currency)?;
+
+            if cents_nb.is_zero() {
+                Ok(integral_word)
+            } else if integral_part.is_zero() && !self.zero_major {
+                Ok(format!("{} {}", cents_words, cents_suffix))
+            } else {
+                Ok(format!(
+                    "{} og {} {}",
+                    integral_word, cents_words, cents_suffix
+                ))
+            }
+        }
+    }
+
+    fn to_percent(&self, num: BigFloat) -> Result<String, Num2Err> {
+        Ok(format!(
+            "{} {}",
+            self.with_gender(Gender::Neuter).to_cardinal(num)?,
+            self.noun_form(&PROSENT, num)
+        ))
+    }
+
+    fn to_unit(&self, num: BigFloat, unit: Unit) -> Result<String, Num2Err> {
+        let (noun, gender) = self.units(unit, num);
+        if num.is_inf() {
+            Ok(format!(
+                "{}óendanlega {} {}",
+                if num.is_negative() { "mínus " } else { "" },
+                ["margir", "margar", "mörg"][gender_index(gender)],
+                noun
+            ))
+        } else {
+            Ok(format!(
+                "{} {}",
+                self.with_gender(gender).to_cardinal(num)?,
+                noun
+            ))
+        }
+    }
+
+    fn plural_category(&self, num: BigFloat) -> PluralCategory {
+        if !num.frac().is_zero() || num.is_inf() {
+            return PluralCategory::Other;
+        }
+        let tail = (num.abs() % BigFloat::from(100))
+            .to_u64()
+            .unwrap_or_default();
+        if is_singular(tail) {
+            PluralCategory::One
+        } else {
+            PluralCategory::Other
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_cardinal() {
+        assert_eq!(
+            Num2Words::new(0).lang(Lang::Icelandic).to_words(),
+            Ok(String::from("núll"))
+        );
+        assert_eq!(
+            Num2Words::new(42).lang(Lang::Icelandic).to_words(),
+            Ok(String::from("fjörutíu og tveir"))
+        );
+        assert_eq!(
+            Num2Words::new(-17).lang(Lang::Icelandic).to_words(),
+            Ok(String::from("mínus sautján"))
+        );
+        assert_eq!(
+            Num2Words::new(120).lang(Lang::Icelandic).to_words(),
+            Ok(String::from("eitt hundrað og tuttugu"))
+        );
+        assert_eq!(
+            Num2Words::new(1100).lang(Lang::Icelandic).to_words(),
+            Ok(String::from("eitt þúsund og eitt hundrað"))
+        );
+        assert_eq!(
+            Num2Words::new(42_042).lang(Lang::Icelandic).to_words(),
+            Ok(String::from("fjörutíu og tvö þúsund fjörutíu og tveir"))
+        );
+        assert_eq!(
+            Num2Words::new(21_000_000).lang(Lang::Icelandic).to_words(),
+            Ok(String::from("tuttugu og ein milljón"))
+        );
+        assert_eq!(
+            Num2Words::new(3_000_000_000i64)
+                .lang(Lang::Icelandic)
+                .to_words(),
+            Ok(String::from("þrír milljarðar"))
+        );
+        assert_eq!(
+            Num2Words::new(3.5).lang(Lang::Icelandic).to_words(),
+            Ok(String::from("þrír komma fimm"))
+        );
+    }
+
+    #[test]
+    fn test_gender_and_case() {
+        let expected = [
+            ("kk", "nf", ["einn", "tveir", "þrír", "fjórir"]),
+            ("kvk", "nf", ["ein", "tvær", "þrjár", "fjórar"]),
+            ("hk", "nf", ["eitt", "tvö", "þrjú", "fjögur"]),
+            ("kk", "þf", ["einn", "tvo", "þrjá", "fjóra"]),
+            ("kvk", "þgf", ["einni", "tveimur", "þremur", "fjórum"]),
+            ("hk", "ef", ["eins", "tveggja", "þriggja", "fjögurra"]),
+        ];
+        for (gender, case, words) in expected.iter() {
+            for (i, word) in words.iter().enumerate() {
+                assert_eq!(
+                    Num2Words::new(i as i64 + 1)
+                        .lang(Lang::Icelandic)
+                        .prefer(*gender)
+                        .prefer(*case)
+                        .to_words(),
+                    Ok(String::from(*word))
+                );
+            }
+        }
+        assert_eq!(
+            Num2Words::new(5)
+                .lang(Lang::Icelandic)
+                .prefer("f")
+                .prefer("dative")
+                .to_words(),
+            Ok(String::from("fimm"))
+        );
+    }
+
+    #[test]
+    fn test_ordinal() {
+        assert_eq!(
+            Num2Words::new(1).lang(Lang::Icelandic).ordinal().to_words(),
+            Ok(String::from("fyrsti"))
+        );
+        assert_eq!(
+            Num2Words::new(2)
+                .lang(Lang::Icelandic)
+                .ordinal()
+                .prefer("kvk")
+                .to_words(),
+            Ok(String::from("önnur"))
+        );
+        assert_eq!(
+            Num2Words::new(13)
+                .lang(Lang::Icelandic)
+                .ordinal()
+                .to_words(),
+            Ok(String::from("þrettándi"))
+        );
+        for (num, words) in [
+            (200.0, "tvöhundraðasti"),
+            (2000.0, "tvöþúsundasti"),
+            (2e6, "tvömilljónasti"),
+            (1e6, "milljónasti"),
+        ] {
+            assert_eq!(
+                Num2Words::new(num)
+                    .lang(Lang::Icelandic)
+                    .ordinal()
+                    .to_words(),
+                Ok(String::from(words))
+            );
+        }
+        assert_eq!(
+            Num2Words::new(42)
+                .lang(Lang::Icelandic)
+                .ordinal()
+                .to_words(),
+            Ok(String::from("fertugasti og annar"))
+        );
+        assert_eq!(
+            Num2Words::new(105)
+                .lang(Lang::Icelandic)
+                .ordinal()
+                .to_words(),
+            Ok(String::from("eitt hundrað og fimmti"))
+        );
+        assert_eq!(
+            Num2Words::new(1000)
+                .lang(Lang::Icelandic)
+                .ordinal()
+                .to_words(),
+            Ok(String::from("þúsundasti"))
+        );
+        assert_eq!(
+            Num2Words::new(200)
+                .lang(Lang::Icelandic)
+                .ordinal()
+                .prefer("hk")
+                .to_words(),
+            Ok(String::from("tvöhundraðasta"))
+        );
+        assert_eq!(
+            Num2Words::new(42)
+                .lang(Lang::Icelandic)
+                .ordinal_num()
+                .to_words(),
+            Ok(String::from("42."))
+        );
+    }
+
+    #[test]
+    fn test_year() {
+        assert_eq!(
+            Num2Words::new(1991).lang(Lang::Icelandic).year().to_words(),
+            Ok(String::from("nítján hundruð níutíu og eitt"))
+        );
+        assert_eq!(
+            Num2Words::new(1905).lang(Lang::Icelandic).year().to_words(),
+            Ok(String::from("nítján hundruð og fimm"))
+        );
+        assert_eq!(
+            Num2Words::new(2024).lang(Lang::Icelandic).year().to_words(),
+            Ok(String::from("tvö þúsund tuttugu og fjögur"))
+        );
+        assert_eq!(
+            Num2Words::new(-44).lang(Lang::Icelandic).year().to_words(),
+            Ok(String::from("fjörutíu og fjögur fyrir Krist"))
+        );
+    }
+
+    #[test]
+    fn test_currency() {
+        let expected = [
+            (1.0, "ein króna"),
+            (2.0, "tvær krónur"),
+            (3.0, "þrjár krónur"),
+            (4.0, "fjórar krónur"),
+            (5.0, "fimm krónur"),
+            (11.0, "ellefu krónur"),
+            (21.0, "tuttugu og ein króna"),
+            (42.0, "fjörutíu og tvær krónur"),
+            (42.5, "fjörutíu og tvær krónur"),
+        ];
+        for (num, words) in expected.iter() {
+            assert_eq!(
+                Num2Words::new(*num)
+                    .lang(Lang::Icelandic)
+                    .currency(Currency::ISK)
+                    .to_words(),
+                Ok(String::from(*words))
+            );
+        }
+        assert_eq!(
+            Num2Words::new(1e6)
+                .lang(Lang::Icelandic)
+                .currency(Currency::USD)
+                .to_words(),
+            Ok(String::from("ein milljón bandaríkjadala"))
+        );
+        assert_eq!(
+            Num2Words::new(2.5e6)
+                .lang(Lang::Icelandic)
+                .currency(Currency::USD)
+                .to_words(),
+            Ok(String::from(
+                "tvær milljónir og fimm hundruð þúsund bandaríkjadalir"
+            ))
+        );
+        assert_eq!(
+            Num2Words::new(2)
+                .lang(Lang::Icelandic)
+                .currency(Currency::ISK)
+                .prefer("þgf")
+                .to_words(),
+            Ok(String::from("tveimur krónum"))
+        );
+        assert_eq!(
+            Num2Words::new(2.2)
+                .lang(Lang::Icelandic)
+                .currency(Currency::EUR)
+                .to_words(),
+            Ok(String::from("tvær evrur og tuttugu sent"))
+        );
+        assert_eq!(
+            Num2Words::new(f64::INFINITY)
+                .lang(Lang::Icelandic)
+                .currency(Currency::ISK)
+                .to_words(),
+            Ok(String::from("óendanlega margar krónur"))
+        );
+    }
+
+    #[test]
+    fn test_percent_and_unit() {
+        assert_eq!(
+            Num2Words::new(1).lang(Lang::Icelandic).percent().to_words(),
+            Ok(String::from("eitt prósent"))
+        );
+        assert_eq!(
+            Num2Words::new(22)
+                .lang(Lang::Icelandic)
+                .unit(Unit::Minute)
+                .to_words(),
+            Ok(String::from("tuttugu og tvær mínútur"))
+        );
+        assert_eq!(
+            Num2Words::new(31)
+                .lang(Lang::Icelandic)
+                .unit(Unit::Meter)
+                .to_words(),
+            Ok(String::from("þrjátíu og einn metri"))
+        );
+    }
+}
@@ -0,0 +1,659 @@
+use crate::{num2words::Num2Err, Currency, Language, PluralCategory, Unit};
+use num_bigfloat::BigFloat;
+
+pub struct Italian {
+    feminine: bool,
+    zero_major: bool,
+}
+
+const UNITS: [&str; 10] = [
+    "zero", "uno", "due", "tre", "quattro", "cinque", "sei", "sette", "otto", "nove",
+];
+
+const TEENS: [&str; 10] = [
+    "dieci",
+    "undici",
+    "dodici",
+    "tredici",
+    "quattordici",
+    "quindici",
+    "sedici",
+    "diciassette",
+    "diciotto",
+    "diciannove",
+];
+
+const TENS: [&str; 8] = [
+    "venti",
+    "trenta",
+    "quaranta",
+    "cinquanta",
+    "sessanta",
+    "settanta",
+    "ottanta",
+    "novanta",
+];
+
+const MEGAS: [&str; 10] = [
+    "milione",
+    "miliardo",
+    "bilione",
+    "biliardo",
+    "trilione",
+    "triliardo",
+    "quadrilione",
+    "quadriliardo",
+    "quintilione",
+    "quintiliardo",
+];
+
+const ORDINALS: [&str; 10] = [
+    "primo", "secondo", "terzo", "quarto", "quinto", "sesto", "settimo", "ottavo", "nono", "decimo",
+];
+
+impl Italian {
+    pub fn new(feminine: bool) -> Self {
+        Self {
+            feminine,
+            zero_major: false,
+        }
+    }
+
+    /// Keeps the major unit clause for amounts below one, e.g. `zero euro e
+    /// venti centesimi`
+    pub fn zero_major(self, zero_major: bool) -> Self {
+        Self { zero_major, ..self }
+    }
+
+    fn currencies(&self, currency: Currency, plural_form: bool) -> String {
+        match currency {
+            Currency::CHF => String::from(if plural_form {
+                "franchi svizzeri"
+            } else {
+                "franco svizzero"
+            }),
+            Currency::DOLLAR => String::from(if plural_form { "dollari" } else { "dollaro" }),
+            Currency::EUR => String::from("euro"),
+            Currency::GBP => String::from(if plural_form { "sterline" } else { "sterlina" }),
+            Currency::INR => String::from(if plural_form { "rupie" } else { "rupia" }),
+            Currency::JPY => String::from("yen"),
+            Currency::USD => String::from(if plural_form {
+                "dollari statunitensi"
+            } else {
+                "dollaro statunitense"
+            }),
+            _ => currency.default_string(plural_form),
+        }
+    }
+
+    fn feminine_currency(&self, currency: Currency) -> bool {
+        matches!(currency, Currency::GBP | Currency::INR)
+    }
+
+    fn cents(&self, currency: Currency, plural_form: bool) -> String {
+        match currency {
+            Currency::GBP => String::from(if plural_form { "pence" } else { "penny" }),
+            _ => currency.default_subunit_string(
+                if plural_form {
+                    "centesimi"
+                } else {
+                    "centesimo"
+                },
+                plural_form,
+            ),
+        }
+    }
+
+    fn units(&self, unit: Unit, plural_form: bool) -> &'static str {
+        match (unit, plural_form) {
+            (Unit::Meter, false) => "metro",
+            (Unit::Meter, true) => "metri",
+            (Unit::Kilometer, false) => "chilometro",
+            (Unit::Kilometer, true) => "chilometri",
+            (Unit::Gram, false) => "grammo",
+            (Unit::Gram, true) => "grammi",
+            (Unit::Kilogram, false) => "chilogrammo",
+            (Unit::Kilogram, true) => "chilogrammi",
+            (Unit::Liter, false) => "litro",
+            (Unit::Liter, true) => "litri",
+            (Unit::Second, false) => "secondo",
+            (Unit::Second, true) => "secondi",
+            (Unit::Minute, false) => "minuto",
+            (Unit::Minute, true) => "minuti",
+            (Unit::Hour, false) => "ora",
+            (Unit::Hour, true) => "ore",
+        }
+    }
+
+    fn split_thousands(&self, mut num: BigFloat) -> Vec<u64> {
+        let mut thousands = Vec::new();
+        let bf_1000 = BigFloat::from(1000);
+
+        while !num.is_zero() {
+            thousands.push((num % bf_1000).to_u64().unwrap());
+            num /= bf_1000;
+        }
+
+        thousands
+    }
+
+    // Hundreds, tens and units fused into a single word, e.g. centoquarantadue
+    fn triplet_to_word(&self, triplet: u64) -> String {
+        let hundreds = (triplet / 100) as usize;
+        let tens = (triplet / 10 % 10) as usize;
+        let units = (triplet % 10) as usize;
+
+        let below_hundred = match tens {
+            0 if units == 0 => String::new(),
+            0 => String::from(UNITS[units]),
+            1 => String::from(TEENS[units]),
+            _ => {
+                let ten = TENS[tens - 2];
+                match units {
+                    0 => String::from(ten),
+                    // the final vowel of the ten is elided, e.g. ventuno
+                    1 | 8 => format!("{}{}", &ten[..ten.len() - 1], UNITS[units]),
+                    _ => format!("{}{}", ten, UNITS[units]),
+                }
+            }
+        };
+
+        let hundred = match hundreds {
+            0 => String::new(),
+            1 => String::from("cento"),
+            _ => format!("{}cento", UNITS[hundreds]),
+        };
+        if hundreds > 0 && below_hundred.starts_with("ott") {
+            // e.g. centotto, centottanta
+            format!("{}{}", &hundred[..hundred.len() - 1], below_hundred)
+        } else {
+            format!("{}{}", hundred, below_hundred)
+        }
+    }
+
+    // A final "uno" drops its vowel before a noun or mila, e.g. ventunmila
+    fn apocopated(&self, word: String) -> String {
+        match word.strip_suffix("uno") {
+            Some(stem) => format!("{}un", stem),
+            None => word,
+        }
+    }
+
+    // The final tre of a compound is accented, e.g. ventitré
+    fn accented(&self, word: String) -> String {
+        match word.strip_suffix("tre") {
+            Some(stem) if !stem.is_empty() => format!("{}tré", stem),
+            _ => word,
+        }
+    }
+
+    // `noun` tells whether a noun follows, in which case a final "uno" is
+    // apocopated, unless the noun is feminine
+    fn int_to_words(
+        &self,
+        mut num: BigFloat,
+        noun: bool,
+        feminine: bool,
+    ) -> Result<String, Num2Err> {
+        // special case zero
+        if num.is_zero() {
+            return Ok(String::from(UNITS[0]));
+        }
+
+        // handling negative values
+        let mut words = vec![];
+        if num.is_negative() {
+            words.push(String::from("meno"));
+            num = -num;
+        }
+
+        let triplets = self.split_thousands(num);
+        // every group of a thousand from the millions on is named, e.g. due
+        // miliardi
+        for i in (2..triplets.len()).rev() {
+            let triplet = triplets[i];
+            if triplet == 0 {
+                continue;
+            }
+            if i - 2 >= MEGAS.len() {
+                return Err(Num2Err::CannotConvert);
+            }
+
+            let mega = MEGAS[i - 2];
+            if triplet == 1 {
+                words.push(format!("un {}", mega));
+            } else {
+                let count = self.accented(self.apocopated(self.triplet_to_word(triplet)));
+                words.push(format!("{} {}i", count, &mega[..mega.len() - 1]));
+            }
+        }
+
+        // thousands and units are fused, e.g. duemilaventitré
+        let mut word = match triplets.get(1).copied().unwrap_or(0) {
+            0 => String::new(),
+            1 => String::from("mille"),
+            thousands => format!("{}mila", self.apocopated(self.triplet_to_word(thousands))),
+        };
+        if triplets[0] != 0 {
+            let units = self.triplet_to_word(triplets[0]);
+            word.push_str(&match (feminine, noun) {
+                (true, _) if units.ends_with("uno") => {
+                    format!("{}a", units.strip_suffix('o').unwrap())
+                }
+                (false, true) => self.apocopated(units),
+                _ => units,
+            });
+        }
+        if !word.is_empty() {
+            words.push(self.accented(word));
+        }
+
+        Ok(words.join(" "))
+    }
+
+    fn int_to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        self.int_to_words(num, false, self.feminine)
+    }
+
+    fn float_to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let integral_part = num.int();
+        let mut words: Vec<String> = vec![];
+
+        if !integral_part.is_zero() {
+            let integral_word = self.int_to_cardinal(integral_part)?;
+            words.push(integral_word);
+        } else if num.is_negative() {
+            words.push(String::from("meno"));
+        }
+        if integral_part.is_zero() {
+            words.push(String::from(UNITS[0]));
+        }
+
+        let mut ordinal_part = num.frac().abs();
+        if !ordinal_part.is_zero() {
+            words.push(String::from("virgola"));
+        }
+        while !ordinal_part.is_zero() {
+            let digit = (ordinal_part * BigFloat::from(10)).int();
+            ordinal_part = (ordinal_part * BigFloat::from(10)).frac();
+            words.push(String::from(UNITS[digit.to_u64().unwrap() as usize]));
+        }
+        Ok(words.join(" "))
+    }
+
+    // A number before a noun, e.g. ventun euro, un milione di euro
+    fn with_noun(&self, num: BigFloat, noun: &str, feminine: bool) -> Result<String, Num2Err> {
+        let words = if num.frac().is_zero() {
+            self.int_to_words(num, true, feminine)?
+        } else {
+            self.float_to_cardinal(num)?
+        };
+
+        // e.g. milione, milioni
+        let last_word = words.rsplit(' ').next().unwrap();
+        if MEGAS
+            .iter()
+            .any(|mega| last_word.starts_with(&mega[..mega.len() - 1]))
+        {
+            Ok(format!("{} di {}", words, noun))
+        } else {
+            Ok(format!("{} {}", words, noun))
+        }
+    }
+}
+
+impl Language for Italian {
+    fn to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num.is_inf_pos() {
+            Ok(String::from("infinito"))
+        } else if num.is_inf_neg() {
+            Ok(String::from("meno infinito"))
+        } else if num.frac().is_zero() {
+            self.int_to_cardinal(num)
+        } else {
+            self.float_to_cardinal(num)
+        }
+    }
+
+    fn to_ordinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let word = if num >= BigFloat::from(1) && num <= BigFloat::from(10) {
+            String::from(ORDINALS[num.to_u64().unwrap() as usize - 1])
+        } else {
+            let cardinal_word = self.int_to_words(num, false, false)?;
+            // a lone million is not counted, e.g. milionesimo, and the rest is
+            // fused into one word, e.g. duemilionesimo
+            let cardinal_word = cardinal_word
+                .strip_prefix("un ")
+                .unwrap_or(&cardinal_word)
+                .replace(' ', "");
+
+            // a fused tre is left unaccented by a space, e.g. un milione tre
+            let tre = cardinal_word
+                .strip_suffix("tré")
+                .or_else(|| cardinal_word.strip_suffix("tre"));
+            if let Some(stem) = tre {
+                // e.g. ventitreesimo
+                format!("{}treesimo", stem)
+            } else if cardinal_word.ends_with("sei") {
+                format!("{}esimo", cardinal_word)
+            } else if let Some(stem) = cardinal_word.strip_suffix("mila") {
+                // e.g. duemillesimo
+                format!("{}millesimo", stem)
+            } else {
+                let mut stem = cardinal_word;
+                stem.pop();
+                format!("{}esimo", stem)
+            }
+        };
+
+        if self.feminine {
+            Ok(format!("{}a", word.strip_suffix('o').unwrap()))
+        } else {
+            Ok(word)
+        }
+    }
+
+    fn to_ordinal_num(&self, num: BigFloat) -> Result<String, Num2Err> {
+        Ok(format!(
+            "{}{}",
+            num.to_u128().unwrap(),
+            if self.feminine { "ª" } else { "º" }
+        ))
+    }
+
+    fn to_year(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num.is_negative() {
+            Ok(format!("{} a.C.", self.int_to_words(-num, false, false)?))
+        } else {
+            self.int_to_words(num, false, false)
+        }
+    }
+
+    fn to_currency(&self, num: BigFloat, currency: Currency) -> Result<String, Num2Err> {
+        if num.is_inf() {
+            Ok(format!(
+                "{} {}",
+                self.to_cardinal(num)?,
+                self.currencies(currency, true)
+            ))
+        } else if num.frac().is_zero() {
+            self.with_noun(
+                num,
+                &self.currencies(currency, self.plural_category(num) != PluralCategory::One),
+                self.feminine_currency(currency),
+            )
+        } else {
+            let integral_part = num.int();
+            let cents_nb = (num * BigFloat::from(100)).int() % BigFloat::from(100);
+            let cents_words = self.with_noun(
+                cents_nb,
+                &self.cents(
+                    currency,
+                    self.plural_category(cents_nb) != PluralCategory::One,
+                ),
+                false,
+            )?;
+            let integral_word = self.to_currency(integral_part, currency)?;
+
+            if cents_nb.is_zero() {
+                Ok(integral_word)
+            } else if integral_part.is_zero() && !self.zero_major {
+                Ok(cents_words)
+            } else {
+                Ok(format!("{} e {}", integral_word, cents_words))
+            }
+        }
+    }
+
+    fn to_percent(&self, num: BigFloat) -> Result<String, Num2Err> {
+        Ok(format!("{} per cento", self.to_cardinal(num)?))
+    }
+
+    fn to_unit(&self, num: BigFloat, unit: Unit) -> Result<String, Num2Err> {
+        let noun = self.units(unit, self.plural_category(num) != PluralCategory::One);
+        if num.is_inf() {
+            Ok(format!(
+                "{} {}",
+                self.to_cardinal(num)?,
+                self.units(unit, true)
+            ))
+        } else {
+            self.with_noun(num, noun, matches!(unit, Unit::Hour))
+        }
+    }
+
+    fn plural_category(&self, num: BigFloat) -> PluralCategory {
+        if num.abs() == BigFloat::from(1) {
+            PluralCategory::One
+        } else {
+            PluralCategory::Other
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_cardinal() {
+        assert_eq!(
+            Num2Words::new(0).lang(Lang::Italian).to_words(),
+            Ok(String::from("zero"))
+        );
+        assert_eq!(
+            Num2Words::new(3).lang(Lang::Italian).to_words(),
+            Ok(String::from("tre"))
+        );
+        assert_eq!(
+            Num2Words::new(8).lang(Lang::Italian).to_words(),
+            Ok(String::from("otto"))
+        );
+        assert_eq!(
+            Num2Words::new(80).lang(Lang::Italian).to_words(),
+            Ok(String::from("ottanta"))
+        );
+        assert_eq!(
+            Num2Words::new(21).lang(Lang::Italian).to_words(),
+            Ok(String::from("ventuno"))
+        );
+        assert_eq!(
+            Num2Words::new(23).lang(Lang::Italian).to_words(),
+            Ok(String::from("ventitré"))
+        );
+        assert_eq!(
+            Num2Words::new(48).lang(Lang::Italian).to_words(),
+            Ok(String::from("quarantotto"))
+        );
+        assert_eq!(
+            Num2Words::new(108).lang(Lang::Italian).to_words(),
+            Ok(String::from("centotto"))
+        );
+        assert_eq!(
+            Num2Words::new(142).lang(Lang::Italian).to_words(),
+            Ok(String::from("centoquarantadue"))
+        );
+        assert_eq!(
+            Num2Words::new(-3.5).lang(Lang::Italian).to_words(),
+            Ok(String::from("meno tre virgola cinque"))
+        );
+        assert_eq!(
+            Num2Words::new(0.5).lang(Lang::Italian).to_words(),
+            Ok(String::from("zero virgola cinque"))
+        );
+        assert_eq!(
+            Num2Words::new(-0.25).lang(Lang::Italian).to_words(),
+            Ok(String::from("meno zero virgola due cinque"))
+        );
+    }
+
+    #[test]
+    fn test_thousands() {
+        assert_eq!(
+            Num2Words::new(1000).lang(Lang::Italian).to_words(),
+            Ok(String::from("mille"))
+        );
+        assert_eq!(
+            Num2Words::new(2023).lang(Lang::Italian).to_words(),
+            Ok(String::from("duemilaventitré"))
+        );
+        assert_eq!(
+            Num2Words::new(21_000).lang(Lang::Italian).to_words(),
+            Ok(String::from("ventunmila"))
+        );
+        assert_eq!(
+            Num2Words::new(1_000_000).lang(Lang::Italian).to_words(),
+            Ok(String::from("un milione"))
+        );
+        assert_eq!(
+            Num2Words::new(3_200_000).lang(Lang::Italian).to_words(),
+            Ok(String::from("tre milioni duecentomila"))
+        );
+        assert_eq!(
+            Num2Words::new(2_000_000_001i64)
+                .lang(Lang::Italian)
+                .to_words(),
+            Ok(String::from("due miliardi uno"))
+        );
+    }
+
+    #[test]
+    fn test_feminine() {
+        assert_eq!(
+            Num2Words::new(21)
+                .lang(Lang::Italian)
+                .prefer("f")
+                .to_words(),
+            Ok(String::from("ventuna"))
+        );
+        assert_eq!(
+            Num2Words::new(1)
+                .lang(Lang::Italian)
+                .unit(Unit::Hour)
+                .to_words(),
+            Ok(String::from("una ora"))
+        );
+    }
+
+    #[test]
+    fn test_ordinal() {
+        assert_eq!(
+            Num2Words::new(1).lang(Lang::Italian).ordinal().to_words(),
+            Ok(String::from("primo"))
+        );
+        assert_eq!(
+            Num2Words::new(10).lang(Lang::Italian).ordinal().to_words(),
+            Ok(String::from("decimo"))
+        );
+        assert_eq!(
+            Num2Words::new(11).lang(Lang::Italian).ordinal().to_words(),
+            Ok(String::from("undicesimo"))
+        );
+        assert_eq!(
+            Num2Words::new(23).lang(Lang::Italian).ordinal().to_words(),
+            Ok(String::from("ventitreesimo"))
+        );
+        assert_eq!(
+            Num2Words::new(26).lang(Lang::Italian).ordinal().to_words(),
+            Ok(String::from("ventiseiesimo"))
+        );
+        assert_eq!(
+            Num2Words::new(42)
+                .lang(Lang::Italian)
+                .ordinal()
+                .prefer("f")
+                .to_words(),
+            Ok(String::from("quarantaduesima"))
+        );
+        assert_eq!(
+            Num2Words::new(2000)
+                .lang(Lang::Italian)
+                .ordinal()
+                .to_words(),
+            Ok(String::from("duemillesimo"))
+        );
+        assert_eq!(
+            Num2Words::new(2_000_000)
+                .lang(Lang::Italian)
+                .ordinal()
+                .to_words(),
+            Ok(String::from("duemilionesimo"))
+        );
+        assert_eq!(
+            Num2Words::new(1_000_000)
+                .lang(Lang::Italian)
+                .ordinal()
+                .to_words(),
+            Ok(String::from("milionesimo"))
+        );
+        assert_eq!(
+            Num2Words::new(3_000_000_000_u64)
+                .lang(Lang::Italian)
+                .ordinal()
+                .to_words(),
+            Ok(String::from("tremiliardesimo"))
+        );
+        assert_eq!(
+            Num2Words::new(1_000_003)
+                .lang(Lang::Italian)
+                .ordinal()
+                .to_words(),
+            Ok(String::from("milionetreesimo"))
+        );
+        assert_eq!(
+            Num2Words::new(42)
+                .lang(Lang::Italian)
+                .ordinal_num()
+                .to_words(),
+            Ok(String::from("42º"))
+        );
+        assert_eq!(
+            Num2Words::new(42)
+                .lang(Lang::Italian)
+                .ordinal_num()
+                .prefer("f")
+                .to_words(),
+            Ok(String::from("42ª"))
+        );
+    }
+
+    #[test]
+    fn test_currency() {
+        assert_eq!(
+            Num2Words::new(1)
+                .lang(Lang::Italian)
+                .currency(Currency::EUR)
+                .to_words(),
+            Ok(String::from("un euro"))
+        );
+        assert_eq!(
+            Num2Words::new(42.01)
+                .lang(Lang::Italian)
+                .currency(Currency::EUR)
+                .to_words(),
+            Ok(String::from("quarantadue euro e un centesimo"))
+        );
+        assert_eq!(
+            Num2Words::new(21.5)
+                .lang(Lang::Italian)
+                .currency(Currency::EUR)
+                .to_words(),
+            Ok(String::from("ventun euro e cinquanta centesimi"))
+        );
+        assert_eq!(
+            Num2Words::new(1_000_000)
+                .lang(Lang::Italian)
+                .currency(Currency::EUR)
+                .to_words(),
+            Ok(String::from("un milione di euro"))
+        );
+        assert_eq!(
+            Num2Words::new(0.2)
+                .lang(Lang::Italian)
+                .currency(Currency::EUR)
+                .prefer("zero-major")
+                .to_words(),
+            Ok(String::from("zero euro e venti centesimi"))
+        );
+    }
+}
@@ -0,0 +1,702 @@
+use crate::{num2words::Num2Err, Currency, Declension, Gender, Language, PluralCategory, Unit};
+use num_bigfloat::BigFloat;
+
+/// Numeral series, i.e. what a number answers to
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Series {
+    /// How many? e.g. duo
+    Cardinal,
+    /// Which in order? e.g. secundus
+    Ordinal,
+    /// How many each? e.g. bini
+    Distributive,
+    /// How many times? e.g. bis
+    Adverbial,
+}
+
+/// Parses a Latin or generic gender preference
+pub(crate) fn gender_from_str(s: &str) -> Option<Gender> {
+    match s.to_lowercase().as_str() {
+        "masculinum" => Some(Gender::Masculine),
+        "femininum" => Some(Gender::Feminine),
+        "neutrum" => Some(Gender::Neuter),
+        other => other.parse().ok(),
+    }
+}
+
+/// Parses a Latin or generic case preference
+///
+/// The ablative is stored as the instrumental, which it covers.
+pub(crate) fn declension_from_str(s: &str) -> Option<Declension> {
+    match s.to_lowercase().as_str() {
+        "nominativus" => Some(Declension::Nominative),
+        "genetivus" => Some(Declension::Genitive),
+        "dativus" => Some(Declension::Dative),
+        "accusativus" => Some(Declension::Accusative),
+        "abl" | "ablative" | "ablativus" => Some(Declension::Instrumental),
+        "vocativus" => Some(Declension::Vocative),
+        other => other.parse().ok(),
+    }
+}
+
+/// Parses a numeral series preference
+pub(crate) fn series_from_str(s: &str) -> Option<Series> {
+    match s.to_lowercase().as_str() {
+        "distributive" | "bini" => Some(Series::Distributive),
+        "adverbial" | "bis" => Some(Series::Adverbial),
+        _ => None,
+    }
+}
+
+// [gender][case], with cases ordered as nom, gen, dat, acc, abl
+#[rustfmt::skip]
+const UNUS: [[&str; 5]; 3] = [
+    ["unus", "unius", "uni", "unum", "uno"],
+    ["una",  "unius", "uni", "unam", "una"],
+    ["unum", "unius", "uni", "unum", "uno"],
+];
+
+#[rustfmt::skip]
+const DUO: [[&str; 5]; 3] = [
+    ["duo",  "duorum", "duobus", "duos", "duobus"],
+    ["duae", "duarum", "duabus", "duas", "duabus"],
+    ["duo",  "duorum", "duobus", "duo",  "duobus"],
+];
+
+#[rustfmt::skip]
+const TRES: [[&str; 5]; 3] = [
+    ["tres", "trium", "tribus", "tres", "tribus"],
+    ["tres", "trium", "tribus", "tres", "tribus"],
+    ["tria", "trium", "tribus", "tria", "tribus"],
+];
+
+// endings of the first and second declensions, e.g. secundus, ducenti
+#[rustfmt::skip]
+const SINGULAR_ENDINGS: [[&str; 5]; 3] = [
+    ["us", "i",  "o",  "um", "o"],
+    ["a",  "ae", "ae", "am", "a"],
+    ["um", "i",  "o",  "um", "o"],
+];
+
+#[rustfmt::skip]
+const PLURAL_ENDINGS: [[&str; 5]; 3] = [
+    ["i",  "orum", "is", "os", "is"],
+    ["ae", "arum", "is", "as", "is"],
+    ["a",  "orum", "is", "a",  "is"],
+];
+
+const MILIA: [&str; 5] = ["milia", "milium", "milibus", "milia", "milibus"];
+
+// Stems of each series, for 1 to 9, 10 to 17, 20 to 90 and 100 to 900. 18
+// and 19 (and every x8 and x9 after them) are subtracted from the next ten,
+// e.g. duodeviginti, undeviginti
+struct Stems {
+    units: [&'static str; 9],
+    teens: [&'static str; 8],
+    tens: [&'static str; 8],
+    hundreds: [&'static str; 9],
+}
+
+const CARDINALS: Stems = Stems {
+    units: [
+        "unus", "duo", "tres", "quattuor", "quinque", "sex", "septem", "octo", "novem",
+    ],
+    teens: [
+        "decem",
+        "undecim",
+        "duodecim",
+        "tredecim",
+        "quattuordecim",
+        "quindecim",
+        "sedecim",
+        "septendecim",
+    ],
+    tens: [
+        "viginti",
+        "triginta",
+        "quadraginta",
+        "quinquaginta",
+        "sexaginta",
+        "septuaginta",
+        "octoginta",
+        "nonaginta",
+    ],
+    hundreds: [
+        "centum",
+        "ducent",
+        "trecent",
+        "quadringent",
+        "quingent",
+        "sescent",
+        "septingent",
+        "octingent",
+        "nongent",
+    ],
+};
+
+const ORDINALS: Stems = Stems {
+    units: [
+        "prim", "secund", "terti", "quart", "quint", "sext", "septim", "octav", "non",
+    ],
+    teens: [
+        "decim",
+        "undecim",
+        "duodecim",
+        "terti decim",
+        "quart decim",
+        "quint decim",
+        "sext decim",
+        "septim decim",
+    ],
+    tens: [
+        "vicesim",
+        "tricesim",
+        "quadragesim",
+        "quinquagesim",
+        "sexagesim",
+        "septuagesim",
+        "octogesim",
+        "nonagesim",
+    ],
+    hundreds: [
+        "centesim",
+        "ducentesim",
+        "trecentesim",
+        "quadringentesim",
+        "quingentesim",
+        "sescentesim",
+        "septingentesim",
+        "octingentesim",
+        "nongentesim",
+    ],
+};
+
+const DISTRIBUTIVES: Stems = Stems {
+    units: [
+        "singul", "bin", "tern", "quatern", "quin", "sen", "septen", "octon", "noven",
+    ],
+    teens: [
+        "den",
+        "unden",
+        "duoden",
+        "tern den",
+        "quatern den",
+        "quin den",
+        "sen den",
+        "septen den",
+    ],
+    tens: [
+        "vicen",
+        "tricen",
+        "quadragen",
+        "quinquagen",
+        "sexagen",
+        "septuagen",
+        "octogen",
+        "nonagen",
+    ],
+    hundreds: [
+        "centen",
+        "ducen",
+        "trecen",
+        "quadringen",
+        "quingen",
+        "sescen",
+        "septingen",
+        "octingen",
+        "nongen",
+    ],
+};
+
+const ADVERBIALS: Stems = Stems {
+    units: [
+        "semel",
+        "bis",
+        "ter",
+        "quater",
+        "quinquies",
+        "sexies",
+        "septies",
+        "octies",
+        "novies",
+    ],
+    teens: [
+        "decies",
+        "undecies",
+        "duodecies",
+        "terdecies",
+        "quater decies",
+        "quindecies",
+        "sedecies",
+        "septies decies",
+    ],
+    tens: [
+        "vicies",
+        "tricies",
+        "quadragies",
+        "quinquagies",
+        "sexagies",
+        "septuagies",
+        "octogies",
+        "nonagies",
+    ],
+    hundreds: [
+        "centies",
+        "ducenties",
+        "trecenties",
+        "quadringenties",
+        "quingenties",
+        "sescenties",
+        "septingenties",
+        "octingenties",
+        "nongenties",
+    ],
+};
+
+const ROMAN: [(u64, &str); 13] = [
+    (1000, "M"),
+    (900, "CM"),
+    (500, "D"),
+    (400, "CD"),
+    (100, "C"),
+    (90, "XC"),
+    (50, "L"),
+    (40, "XL"),
+    (10, "X"),
+    (9, "IX"),
+    (5, "V"),
+    (4, "IV"),
+    (1, "I"),
+];
+
+pub struct Latin {
+    gender: Gender,
+    declension: Declension,
+    series: Series,
+}
+
+impl Latin {
+    pub fn new(gender: Gender, declension: Declension, series: Series) -> Self {
+        Self {
+            gender,
+            declension,
+            series,
+        }
+    }
+
+    fn gender_index(gender: Gender) -> usize {
+        match gender {
+            Gender::Masculine => 0,
+            Gender::Feminine => 1,
+            Gender::Neuter => 2,
+        }
+    }
+
+    fn case_index(&self) -> usize {
+        match self.declension {
+            // numerals have no vocative of their own, and there is no partitive
+            Declension::Nominative | Declension::Vocative | Declension::Partitive => 0,
+            Declension::Genitive => 1,
+            Declension::Dative => 2,
+            Declension::Accusative => 3,
+            Declension::Instrumental | Declension::Locative => 4,
+        }
+    }
+
+    fn stems(series: Series) -> &'static Stems {
+        match series {
+            Series::Cardinal => &CARDINALS,
+            Series::Ordinal => &ORDINALS,
+            Series::Distributive => &DISTRIBUTIVES,
+            Series::Adverbial => &ADVERBIALS,
+        }
+    }
+
+    // Declines every word of a stem as the series requires, e.g. terti decim
+    // => tertius decimus
+    fn decline(&self, stem: &str, series: Series, gender: Gender) -> String {
+        let g = Self::gender_index(gender);
+        let c = self.case_index();
+        stem.split(' ')
+            .map(|word| match (series, word) {
+                (Series::Cardinal, "unus") => String::from(UNUS[g][c]),
+                (Series::Cardinal, "duo") => String::from(DUO[g][c]),
+                (Series::Cardinal, "tres") => String::from(TRES[g][c]),
+                // only the hundreds are declined past three, e.g. ducenti
+                (Series::Cardinal, w) if w.ends_with("ent") => {
+                    format!("{}{}", w, PLURAL_ENDINGS[g][c])
+                }
+                (Series::Ordinal, w) => format!("{}{}", w, SINGULAR_ENDINGS[g][c]),
+                (Series::Distributive, w) => format!("{}{}", w, PLURAL_ENDINGS[g][c]),
+                (_, w) => String::from(w),
+            })
+            .collect::<Vec<String>>()
+            .join(" ")
+    }
+
+    fn below_thousand(&self, num: u64, series: Series, gender: Gender) -> Vec<String> {
+        let stems = Self::stems(series);
+        let hundreds = (num / 100) as usize;
+        let tens = (num / 10 % 10) as usize;
+        let units = (num % 10) as usize;
+        let mut words = vec![];
+
+        if hundreds > 0 {
+            words.push(self.decline(stems.hundreds[hundreds - 1], series, gender));
+        }
+
+        match (tens, units) {
+            (0, 0) => {}
+            (0, _) => words.push(self.decline(stems.units[units - 1], series, gender)),
+            (1, 0..=7) => words.push(self.decline(stems.teens[units], series, gender)),
+            (_, 8) | (_, 9) => {
+                // subtracted from the next ten, e.g. duodequadraginta (38)
+                let next = if tens == 9 {
+                    stems.hundreds[0]
+                } else {
+                    stems.tens[tens - 1]
+                };
+                let prefix = if units == 8 { "duode" } else { "unde" };
+                words.push(self.decline(&format!("{}{}", prefix, next), series, gender));
+            }
+            _ => {
+                words.push(self.decline(stems.tens[tens - 2], series, gender));
+                if units > 0 {
+                    words.push(self.decline(stems.units[units - 1], series, gender));
+                }
+            }
+        }
+
+        words
+    }
+
+    fn int_to_words(&self, num: BigFloat, series: Series) -> Result<String, Num2Err> {
+        // Latin has no word for a million, which would be decies centena milia
+        let num = match num.to_u64() {
+            Some(num) if num < 1_000_000 => num,
+            _ => return Err(Num2Err::CannotConvert),
+        };
+
+        let thousands = num / 1000;
+        let mut words = vec![];
+        match (thousands, series) {
+            (0, _) => {}
+            (1, Series::Cardinal) => words.push(String::from("mille")),
+            (1, Series::Ordinal) => words.push(self.decline("millesim", series, self.gender)),
+            (1, Series::Distributive) => words.push(self.decline("millen", series, self.gender)),
+            (1, Series::Adverbial) => words.push(String::from("milies")),
+            (_, Series::Cardinal) | (_, Series::Distributive) => {
+                // milia is a neuter noun, e.g. duo milia, bina milia
+                words.extend(self.below_thousand(thousands, series, Gender::Neuter));
+                words.push(String::from(MILIA[self.case_index()]));
+            }
+            (_, Series::Ordinal) => {
+                // e.g. bis millesimus
+                words.extend(self.below_thousand(thousands, Series::Adverbial, self.gender));
+                words.push(self.decline("millesim", series, self.gender));
+            }
+            (_, Series::Adverbial) => {
+                words.extend(self.below_thousand(thousands, series, self.gender));
+                words.push(String::from("milies"));
+            }
+        }
+        words.extend(self.below_thousand(num % 1000, series, self.gender));
+
+        Ok(words.join(" "))
+    }
+}
+
+impl Language for Latin {
+    fn to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num.is_inf_pos() {
+            Ok(String::from("infinitum"))
+        } else if num.is_inf_neg() {
+            Ok(String::from("minus infinitum"))
+        } else if !num.frac().is_zero() {
+            // Latin counted fractions with nouns (semis, triens, ...)
+            Err(Num2Err::CannotConvert)
+        } else if num.is_zero() {
+            Ok(String::from("nihil"))
+        } else if num.is_negative() {
+            Ok(format!("minus {}", self.int_to_words(-num, self.series)?))
+        } else {
+            self.int_to_words(num, self.series)
+        }
+    }
+
+    fn to_ordinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        // there is no zeroth, nor a year zero, see `Lang::Latin`
+        if num.is_zero() {
+            return Err(Num2Err::CannotConvert);
+        }
+        self.int_to_words(num, Series::Ordinal)
+    }
+
+    fn to_ordinal_num(&self, num: BigFloat) -> Result<String, Num2Err> {
+        // Roman numerals, with M repeated past 3999
+        let mut num = match num.to_u64() {
+            Some(num) if num > 0 && num < 1_000_000 => num,
+            _ => return Err(Num2Err::CannotConvert),
+        };
+
+        let mut numeral = String::new();
+        for (value, letters) in ROMAN {
+            while num >= value {
+                numeral.push_str(letters);
+                num -= value;
+            }
+        }
+
+        Ok(numeral)
+    }
+
+    fn to_year(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if !num.frac().is_zero() {
+            return Err(Num2Err::FloatingYear);
+        }
+
+        // years are ordinals in the ablative, e.g. anno millesimo
+        let latin = Latin::new(Gender::Masculine, Declension::Instrumental, Series::Ordinal);
+        let words = format!("anno {}", latin.to_ordinal(num.abs())?);
+        if num.is_negative() {
+            Ok(format!("{} ante Christum natum", words))
+        } else {
+            Ok(words)
+        }
+    }
+
+    fn to_currency(&self, _num: BigFloat, _currency: Currency) -> Result<String, Num2Err> {
+        Err(Num2Err::CannotConvert)
+    }
+
+    fn to_percent(&self, num: BigFloat) -> Result<String, Num2Err> {
+        Ok(format!("{} per centum", self.to_cardinal(num)?))
+    }
+
+    fn to_unit(&self, _num: BigFloat, _unit: Unit) -> Result<String, Num2Err> {
+        Err(Num2Err::CannotConvert)
+    }
+
+    fn plural_category(&self, num: BigFloat) -> PluralCategory {
+        if num.abs() == BigFloat::from(1) {
+            PluralCategory::One
+        } else {
+            PluralCategory::Other
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_cardinal() {
+        assert_eq!(
+            Num2Words::new(0).lang(Lang::Latin).to_words(),
+            Ok(String::from("nihil"))
+        );
+        assert_eq!(
+            Num2Words::new(1).lang(Lang::Latin).to_words(),
+            Ok(String::from("unus"))
+        );
+        assert_eq!(
+            Num2Words::new(17).lang(Lang::Latin).to_words(),
+            Ok(String::from("septendecim"))
+        );
+        assert_eq!(
+            Num2Words::new(18).lang(Lang::Latin).to_words(),
+            Ok(String::from("duodeviginti"))
+        );
+        assert_eq!(
+            Num2Words::new(19).lang(Lang::Latin).to_words(),
+            Ok(String::from("undeviginti"))
+        );
+        assert_eq!(
+            Num2Words::new(42).lang(Lang::Latin).to_words(),
+            Ok(String::from("quadraginta duo"))
+        );
+        assert_eq!(
+            Num2Words::new(99).lang(Lang::Latin).to_words(),
+            Ok(String::from("undecentum"))
+        );
+        assert_eq!(
+            Num2Words::new(1999).lang(Lang::Latin).to_words(),
+            Ok(String::from("mille nongenti undecentum"))
+        );
+        assert_eq!(
+            Num2Words::new(3_200).lang(Lang::Latin).to_words(),
+            Ok(String::from("tria milia ducenti"))
+        );
+        assert_eq!(
+            Num2Words::new(-5).lang(Lang::Latin).to_words(),
+            Ok(String::from("minus quinque"))
+        );
+        assert_eq!(
+            Num2Words::new(1.5).lang(Lang::Latin).to_words(),
+            Err(Num2Err::CannotConvert)
+        );
+        assert_eq!(
+            Num2Words::new(1_000_000).lang(Lang::Latin).to_words(),
+            Err(Num2Err::CannotConvert)
+        );
+    }
+
+    #[test]
+    fn test_declension() {
+        assert_eq!(
+            Num2Words::new(1).lang(Lang::Latin).prefer("f").to_words(),
+            Ok(String::from("una"))
+        );
+        assert_eq!(
+            Num2Words::new(1)
+                .lang(Lang::Latin)
+                .prefer("neutrum")
+                .to_words(),
+            Ok(String::from("unum"))
+        );
+        assert_eq!(
+            Num2Words::new(2)
+                .lang(Lang::Latin)
+                .prefer("f")
+                .prefer("gen")
+                .to_words(),
+            Ok(String::from("duarum"))
+        );
+        assert_eq!(
+            Num2Words::new(3).lang(Lang::Latin).prefer("n").to_words(),
+            Ok(String::from("tria"))
+        );
+        assert_eq!(
+            Num2Words::new(200).lang(Lang::Latin).prefer("f").to_words(),
+            Ok(String::from("ducentae"))
+        );
+        assert_eq!(
+            Num2Words::new(223)
+                .lang(Lang::Latin)
+                .prefer("n")
+                .prefer("abl")
+                .to_words(),
+            Ok(String::from("ducentis viginti tribus"))
+        );
+        assert_eq!(
+            Num2Words::new(2000)
+                .lang(Lang::Latin)
+                .prefer("dat")
+                .to_words(),
+            Ok(String::from("duobus milibus"))
+        );
+    }
+
+    #[test]
+    fn test_series() {
+        assert_eq!(
+            Num2Words::new(2)
+                .lang(Lang::Latin)
+                .prefer("bini")
+                .to_words(),
+            Ok(String::from("bini"))
+        );
+        assert_eq!(
+            Num2Words::new(13)
+                .lang(Lang::Latin)
+                .prefer("distributive")
+                .prefer("f")
+                .to_words(),
+            Ok(String::from("ternae denae"))
+        );
+        assert_eq!(
+            Num2Words::new(2).lang(Lang::Latin).prefer("bis").to_words(),
+            Ok(String::from("bis"))
+        );
+        assert_eq!(
+            Num2Words::new(21)
+                .lang(Lang::Latin)
+                .prefer("adverbial")
+                .to_words(),
+            Ok(String::from("vicies semel"))
+        );
+    }
+
+    #[test]
+    fn test_ordinal() {
+        assert_eq!(
+            Num2Words::new(0).lang(Lang::Latin).ordinal().to_words(),
+            Err(Num2Err::CannotConvert)
+        );
+        assert_eq!(
+            Num2Words::new(1).lang(Lang::Latin).ordinal().to_words(),
+            Ok(String::from("primus"))
+        );
+        assert_eq!(
+            Num2Words::new(13).lang(Lang::Latin).ordinal().to_words(),
+            Ok(String::from("tertius decimus"))
+        );
+        assert_eq!(
+            Num2Words::new(18).lang(Lang::Latin).ordinal().to_words(),
+            Ok(String::from("duodevicesimus"))
+        );
+        assert_eq!(
+            Num2Words::new(19).lang(Lang::Latin).ordinal().to_words(),
+            Ok(String::from("undevicesimus"))
+        );
+        assert_eq!(
+            Num2Words::new(42).lang(Lang::Latin).ordinal().to_words(),
+            Ok(String::from("quadragesimus secundus"))
+        );
+        assert_eq!(
+            Num2Words::new(42)
+                .lang(Lang::Latin)
+                .ordinal()
+                .prefer("f")
+                .prefer("acc")
+                .to_words(),
+            Ok(String::from("quadragesimam secundam"))
+        );
+        assert_eq!(
+            Num2Words::new(2000).lang(Lang::Latin).ordinal().to_words(),
+            Ok(String::from("bis millesimus"))
+        );
+    }
+
+    #[test]
+    fn test_ordinal_num() {
+        assert_eq!(
+            Num2Words::new(42)
+                .lang(Lang::Latin)
+                .ordinal_num()
+                .to_words(),
+            Ok(String::from("XLII"))
+        );
+        assert_eq!(
+            Num2Words::new(1994)
+                .lang(Lang::Latin)
+                .ordinal_num()
+                .to_words(),
+            Ok(String::from("MCMXCIV"))
+        );
+    }
+
+    #[test]
+    fn test_year() {
+        assert_eq!(
+            Num2Words::new(2024).lang(Lang::Latin).year().to_words(),
+            Ok(String::from("anno bis millesimo vicesimo quarto"))
+        );
+        assert_eq!(
+            Num2Words::new(-44).lang(Lang::Latin).year().to_words(),
+            Ok(String::from("anno quadragesimo quarto ante Christum natum"))
+        );
+    }
+
+    #[test]
+    fn test_currency() {
+        assert_eq!(
+            Num2Words::new(42)
+                .lang(Lang::Latin)
+                .currency(Currency::EUR)
+                .to_words(),
+            Err(Num2Err::CannotConvert)
+        );
+    }
+}
@@ -1,6 +1,6 @@
 use crate::lang;
 use crate::num2words::Num2Err;
-use crate::Currency;
+use crate::{Agreement, Currency, Output, Unit};
 use num_bigfloat::BigFloat;
 use std::str::FromStr;
 
@@ -10,12 +10,159 @@ pub trait Language {
     fn to_ordinal(&self, num: BigFloat) -> Result<String, Num2Err>;
     fn to_ordinal_num(&self, num: BigFloat) -> Result<String, Num2Err>;
     fn to_year(&self, num: BigFloat) -> Result<String, Num2Err>;
+    /// Range of two different years, e.g. `from twenty twenty to twenty
+    /// twenty-three`
+    fn to_year_range(&self, from: BigFloat, to: BigFloat) -> Result<String, Num2Err> {
+        Ok(format!("{} – {}", self.to_year(from)?, self.to_year(to)?))
+    }
     fn to_currency(&self, num: BigFloat, currency: Currency) -> Result<String, Num2Err>;
+    fn to_percent(&self, num: BigFloat) -> Result<String, Num2Err>;
+    fn to_unit(&self, num: BigFloat, unit: Unit) -> Result<String, Num2Err>;
+    /// Plural category of a noun counted by `num`
+    ///
+    /// Every output that appends a noun (currency, percent, unit) must pick its
+    /// form from this, so that they never disagree.
+    fn plural_category(&self, num: BigFloat) -> PluralCategory;
+    /// Form of a noun counted by `num`
+    ///
+    /// Languages with genders or cases must override this to report them.
+    fn agreement(&self, num: BigFloat) -> Agreement {
+        Agreement::from_category(self.plural_category(num))
+    }
+    /// Position of a counted noun relative to its number
+    ///
+    /// Every output that counts a noun (currency, unit) must place it
+    /// according to this, e.g. `forty-two dollars` but `රුපියල් හතළිස් දෙක`.
+    fn noun_order(&self) -> NounOrder {
+        NounOrder::NumberFirst
+    }
+    /// Word marking a negative amount written in accounting style, e.g.
+    /// `forty-two dollars (negative)`
+    fn negative_marker(&self) -> &'static str {
+        "negative"
+    }
+    /// Name of an uppercase letter of the Latin alphabet, as read in a
+    /// reference, e.g. `D` in `D E eighty-nine`
+    fn letter_name(&self, letter: char) -> String {
+        letter.to_string()
+    }
+}
+
+/// Word order of a number and the noun it counts
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum NounOrder {
+    /// The noun follows the number, e.g. `forty-two dollars`
+    NumberFirst,
+    /// The noun precedes the number, e.g. `රුපියල් හතළිස් දෙක`
+    NounFirst,
+    /// The noun precedes the number and is repeated right after it as its
+    /// classifier, e.g. `ကျပ် လေးဆယ့်နှစ်ကျပ်`
+    Classifier,
+}
+
+impl NounOrder {
+    /// Joins a number and its noun in this order
+    pub fn place(self, number: &str, noun: &str) -> String {
+        match self {
+            NounOrder::NumberFirst => format!("{} {}", number, noun),
+            NounOrder::NounFirst => format!("{} {}", noun, number),
+            NounOrder::Classifier => format!("{} {}{}", noun, number, noun),
+        }
+    }
+}
+
+/// Plural categories, as defined by the Unicode CLDR
+///
+/// Not every language uses every category, e.g., English only has `One` and
+/// `Other`, while Ukrainian uses `One`, `Few`, `Many` and `Other` (fractions).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PluralCategory {
+    Zero,
+    One,
+    Two,
+    Few,
+    Many,
+    Other,
 }
 
 /// Languages available in `num2words`
+#[derive(Clone, Copy, Debug, PartialEq)]
 #[allow(non_camel_case_types)]
 pub enum Lang {
+    /// ```
+    /// use num2words::{Num2Words, Lang};
+    /// assert_eq!(
+    ///     Num2Words::new(42).lang(Lang::Amharic).to_words(),
+    ///     Ok(String::from("አርባ ሁለት"))
+    /// );
+    /// ```
+    Amharic,
+    /// ```
+    /// use num2words::{Num2Words, Lang};
+    /// assert_eq!(
+    ///     Num2Words::new(42).lang(Lang::Arabic).to_words(),
+    ///     Ok(String::from("اثنان وأربعون"))
+    /// );
+    /// ```
+    ///
+    /// Zero has no ordinal and returns `Num2Err::CannotConvert`.
+    Arabic,
+    /// ```
+    /// use num2words::{Num2Words, Lang};
+    /// assert_eq!(
+    ///     Num2Words::new(42).lang(Lang::Armenian).to_words(),
+    ///     Ok(String::from("քառասուներկու"))
+    /// );
+    /// ```
+    Armenian,
+    /// ```
+    /// use num2words::{Num2Words, Lang};
+    /// assert_eq!(
+    ///     Num2Words::new(42).lang(Lang::Basque).to_words(),
+    ///     Ok(String::from("berrogeita bi"))
+    /// );
+    /// ```
+    Basque,
+    /// ```
+    /// use num2words::{Num2Words, Lang};
+    /// assert_eq!(
+    ///     Num2Words::new(42).lang(Lang::Bengali).to_words(),
+    ///     Ok(String::from("বিয়াল্লিশ"))
+    /// );
+    /// ```
+    Bengali,
+    /// ```
+    /// use num2words::{Num2Words, Lang};
+    /// assert_eq!(
+    ///     Num2Words::new(42).lang(Lang::Burmese).to_words(),
+    ///     Ok(String::from("လေးဆယ့်နှစ်"))
+    /// );
+    /// ```
+    Burmese,
+    /// ```
+    /// use num2words::{Num2Words, Lang};
+    /// assert_eq!(
+    ///     Num2Words::new(42).lang(Lang::Chinese).to_words(),
+    ///     Ok(String::from("四十二"))
+    /// );
+    /// ```
+    Chinese,
+    /// ```
+    /// use num2words::{Num2Words, Lang};
+    /// assert_eq!(
+    ///     Num2Words::new(42).lang(Lang::Czech).to_words(),
+    ///     Ok(String::from("čtyřicet dva"))
+    /// );
+    /// ```
+    Czech,
+    /// ```
+    /// use num2words::{Num2Words, Lang};
+    /// assert_eq!(
+    ///     Num2Words::new(42).lang(Lang::Dutch).to_words(),
+    ///     Ok(String::from("tweeënveertig"))
+    /// );
+    /// ```
+    Dutch,
     /// ```
     /// use num2words::{Num2Words, Lang};
     /// assert_eq!(
@@ -24,7 +171,15 @@ pub enum Lang {
     /// );
     /// ```
     English,
-    /// French from France and Canada
+    /// ```
+    /// use num2words::{Num2Words, Lang};
+    /// assert_eq!(
+    ///     Num2Words::new(42).lang(Lang::Finnish).to_words(),
+    ///     Ok(String::from("neljäkymmentäkaksi"))
+    /// );
+    /// ```
+    Finnish,
+    /// French from France
     /// ```
     /// use num2words::{Num2Words, Lang};
     /// assert_eq!(
@@ -51,6 +206,209 @@ pub enum Lang {
     /// );
     /// ```
     French_CH,
+    /// French from Canada
+    /// ```
+    /// use num2words::{Num2Words, Lang};
+    /// assert_eq!(
+    ///     Num2Words::new(1984).lang(Lang::French_CA).year().to_words(),
+    ///     Ok(String::from("dix-neuf cent quatre-vingt-quatre"))
+    /// );
+    /// ```
+    French_CA,
+    /// ```
+    /// use num2words::{Num2Words, Lang};
+    /// assert_eq!(
+    ///     Num2Words::new(42).lang(Lang::Hungarian).to_words(),
+    ///     Ok(String::from("negyvenkettő"))
+    /// );
+    /// ```
+    Hungarian,
+    /// ```
+    /// use num2words::{Num2Words, Lang};
+    /// assert_eq!(
+    ///     Num2Words::new(42).lang(Lang::Icelandic).to_words(),
+    ///     Ok(String::from("fjörutíu og tveir"))
+    /// );
+    /// ```
+    Icelandic,
+    /// ```
+    /// use num2words::{Num2Words, Lang};
+    /// assert_eq!(
+    ///     Num2Words::new(42).lang(Lang::Italian).to_words(),
+    ///     Ok(String::from("quarantadue"))
+    /// );
+    /// ```
+    Italian,
+    /// ```
+    /// use num2words::{Num2Words, Lang};
+    /// assert_eq!(
+    ///     Num2Words::new(42).lang(Lang::Lao).to_words(),
+    ///     Ok(String::from("ສີ່ສິບສອງ"))
+    /// );
+    /// ```
+    Lao,
+    /// ```
+    /// use num2words::{Num2Words, Lang};
+    /// assert_eq!(
+    ///     Num2Words::new(42).lang(Lang::Latin).to_words(),
+    ///     Ok(String::from("quadraginta duo"))
+    /// );
+    /// ```
+    ///
+    /// Zero has no ordinal and returns `Num2Err::CannotConvert`.
+    Latin,
+    /// ```
+    /// use num2words::{Num2Words, Lang};
+    /// assert_eq!(
+    ///     Num2Words::new(42).lang(Lang::Latvian).to_words(),
+    ///     Ok(String::from("četrdesmit divi"))
+    /// );
+    /// ```
+    Latvian,
+    /// ```
+    /// use num2words::{Num2Words, Lang};
+    /// assert_eq!(
+    ///     Num2Words::new(42).lang(Lang::Maltese).to_words(),
+    ///     Ok(String::from("tnejn u erbgħin"))
+    /// );
+    /// ```
+    Maltese,
+    /// ```
+    /// use num2words::{Num2Words, Lang};
+    /// assert_eq!(
+    ///     Num2Words::new(42).lang(Lang::Mongolian).to_words(),
+    ///     Ok(String::from("дөчин хоёр"))
+    /// );
+    /// ```
+    Mongolian,
+    /// ```
+    /// use num2words::{Num2Words, Lang};
+    /// assert_eq!(
+    ///     Num2Words::new(42).lang(Lang::Nepali).to_words(),
+    ///     Ok(String::from("बयालीस"))
+    /// );
+    /// ```
+    Nepali,
+    /// ```
+    /// use num2words::{Num2Words, Lang};
+    /// assert_eq!(
+    ///     Num2Words::new(42).lang(Lang::Polish).to_words(),
+    ///     Ok(String::from("czterdzieści dwa"))
+    /// );
+    /// ```
+    Polish,
+    /// Portuguese from Portugal and other Lusophone countries
+    /// ```
+    /// use num2words::{Num2Words, Lang};
+    /// assert_eq!(
+    ///     Num2Words::new(42).lang(Lang::Portuguese).to_words(),
+    ///     Ok(String::from("quarenta e dois"))
+    /// );
+    /// ```
+    ///
+    /// Zero has no ordinal and returns `Num2Err::CannotConvert`.
+    Portuguese,
+    /// Portuguese from Brazil
+    /// ```
+    /// use num2words::{Num2Words, Lang};
+    /// assert_eq!(
+    ///     Num2Words::new(16).lang(Lang::Portuguese_BR).to_words(),
+    ///     Ok(String::from("dezesseis"))
+    /// );
+    /// ```
+    ///
+    /// Zero has no ordinal and returns `Num2Err::CannotConvert`.
+    Portuguese_BR,
+    /// ```
+    /// use num2words::{Num2Words, Lang};
+    /// assert_eq!(
+    ///     Num2Words::new(42).lang(Lang::Romanian).to_words(),
+    ///     Ok(String::from("patruzeci și doi"))
+    /// );
+    /// ```
+    Romanian,
+    /// ```
+    /// use num2words::{Num2Words, Lang};
+    /// assert_eq!(
+    ///     Num2Words::new(42).lang(Lang::Serbian).to_words(),
+    ///     Ok(String::from("четрдесет два"))
+    /// );
+    /// ```
+    Serbian,
+    /// ```
+    /// use num2words::{Num2Words, Lang};
+    /// assert_eq!(
+    ///     Num2Words::new(42).lang(Lang::Sinhala).to_words(),
+    ///     Ok(String::from("හතළිස් දෙක"))
+    /// );
+    /// ```
+    Sinhala,
+    /// ```
+    /// use num2words::{Num2Words, Lang};
+    /// assert_eq!(
+    ///     Num2Words::new(42).lang(Lang::Slovak).to_words(),
+    ///     Ok(String::from("štyridsaťdva"))
+    /// );
+    /// ```
+    Slovak,
+    /// ```
+    /// use num2words::{Num2Words, Lang};
+    /// assert_eq!(
+    ///     Num2Words::new(42).lang(Lang::Slovenian).to_words(),
+    ///     Ok(String::from("dvainštirideset"))
+    /// );
+    /// ```
+    Slovenian,
+    /// ```
+    /// use num2words::{Num2Words, Lang};
+    /// assert_eq!(
+    ///     Num2Words::new(42).lang(Lang::Spanish).to_words(),
+    ///     Ok(String::from("cuarenta y dos"))
+    /// );
+    /// ```
+    ///
+    /// Zero has no ordinal and returns `Num2Err::CannotConvert`.
+    Spanish,
+    /// ```
+    /// use num2words::{Num2Words, Lang};
+    /// assert_eq!(
+    ///     Num2Words::new(42).lang(Lang::Swahili).to_words(),
+    ///     Ok(String::from("arobaini na mbili"))
+    /// );
+    /// ```
+    Swahili,
+    /// ```
+    /// use num2words::{Num2Words, Lang};
+    /// assert_eq!(
+    ///     Num2Words::new(42).lang(Lang::Tagalog).to_words(),
+    ///     Ok(String::from("apatnapu't dalawa"))
+    /// );
+    /// ```
+    Tagalog,
+    /// ```
+    /// use num2words::{Num2Words, Lang};
+    /// assert_eq!(
+    ///     Num2Words::new(42).lang(Lang::Tamil).to_words(),
+    ///     Ok(String::from("நாற்பத்தி இரண்டு"))
+    /// );
+    /// ```
+    Tamil,
+    /// ```
+    /// use num2words::{Num2Words, Lang};
+    /// assert_eq!(
+    ///     Num2Words::new(42).lang(Lang::Telugu).to_words(),
+    ///     Ok(String::from("నలభై రెండు"))
+    /// );
+    /// ```
+    Telugu,
+    /// ```
+    /// use num2words::{Num2Words, Lang};
+    /// assert_eq!(
+    ///     Num2Words::new(42).lang(Lang::Turkish).to_words(),
+    ///     Ok(String::from("kırk iki"))
+    /// );
+    /// ```
+    Turkish,
     /// ```
     /// use num2words::{Num2Words, Lang};
     /// assert_eq!(
@@ -59,6 +417,30 @@ pub enum Lang {
     /// );
     /// ```
     Ukrainian,
+    /// ```
+    /// use num2words::{Num2Words, Lang};
+    /// assert_eq!(
+    ///     Num2Words::new(42).lang(Lang::Uzbek).to_words(),
+    ///     Ok(String::from("qirq ikki"))
+    /// );
+    /// ```
+    Uzbek,
+    /// ```
+    /// use num2words::{Num2Words, Lang};
+    /// assert_eq!(
+    ///     Num2Words::new(42).lang(Lang::Vietnamese).to_words(),
+    ///     Ok(String::from("bốn mươi hai"))
+    /// );
+    /// ```
+    Vietnamese,
+    /// ```
+    /// use num2words::{Num2Words, Lang};
+    /// assert_eq!(
+    ///     Num2Words::new(42).lang(Lang::Zulu).to_words(),
+    ///     Ok(String::from("amashumi amane nambili"))
+    /// );
+    /// ```
+    Zulu,
 }
 
 impl FromStr for Lang {
@@ -68,90 +450,645 @@ impl FromStr for Lang {
     ///
     /// | Locale    | Lang              | 42            |
     /// | --------- | ----------------- | ------------- |
+    /// | `am`      | `Lang::Amharic`   | አርባ ሁለት       |
+    /// | `ar`      | `Lang::Arabic`    | اثنان وأربعون |
+    /// | `hy`      | `Lang::Armenian`  | քառասուներկու |
+    /// | `eu`      | `Lang::Basque`    | berrogeita bi |
+    /// | `bn`      | `Lang::Bengali`   | বিয়াল্লিশ    |
+    /// | `my`      | `Lang::Burmese`   | လေးဆယ့်နှစ်   |
+    /// | `zh`      | `Lang::Chinese`   | 四十二           |
+    /// | `cs`      | `Lang::Czech`     | čtyřicet dva  |
+    /// | `nl`      | `Lang::Dutch`     | tweeënveertig |
     /// | `en`      | `Lang::English`   | forty-two     |
+    /// | `fi`      | `Lang::Finnish`   | neljäkymmentäkaksi |
     /// | `fr`      | `Lang::French`    | quarante-deux |
     /// | `fr_BE`   | `Lang::French_BE` | quarante-deux |
     /// | `fr_CH`   | `Lang::French_CH` | quarante-deux |
+    /// | `fr_CA`   | `Lang::French_CA` | quarante-deux |
+    /// | `hu`      | `Lang::Hungarian` | negyvenkettő  |
+    /// | `is`      | `Lang::Icelandic` | fjörutíu og tveir |
+    /// | `it`      | `Lang::Italian`   | quarantadue   |
+    /// | `lo`      | `Lang::Lao`       | ສີ່ສິບສອງ     |
+    /// | `la`      | `Lang::Latin`     | quadraginta duo |
+    /// | `lv`      | `Lang::Latvian`   | četrdesmit divi |
+    /// | `mt`      | `Lang::Maltese`   | tnejn u erbgħin |
+    /// | `mn`      | `Lang::Mongolian` | дөчин хоёр    |
+    /// | `ne`      | `Lang::Nepali`    | बयालीस        |
+    /// | `pl`      | `Lang::Polish`    | czterdzieści dwa |
+    /// | `pt`      | `Lang::Portuguese` | quarenta e dois |
+    /// | `pt_BR`   | `Lang::Portuguese_BR` | quarenta e dois |
+    /// | `ro`      | `Lang::Romanian`  | patruzeci și doi |
+    /// | `sr`      | `Lang::Serbian`   | четрдесет два |
+    /// | `si`      | `Lang::Sinhala`   | හතළිස් දෙක    |
+    /// | `sk`      | `Lang::Slovak`    | štyridsaťdva  |
+    /// | `sl`      | `Lang::Slovenian` | dvainštirideset |
+    /// | `es`      | `Lang::Spanish`   | cuarenta y dos |
+    /// | `sw`      | `Lang::Swahili`   | arobaini na mbili |
+    /// | `tl`      | `Lang::Tagalog`   | apatnapu't dalawa |
+    /// | `ta`      | `Lang::Tamil`     | நாற்பத்தி இரண்டு |
+    /// | `te`      | `Lang::Telugu`    | నలభై రెండు    |
+    /// | `tr`      | `Lang::Turkish`   | kırk iki      |
     /// | `uk`      | `Lang::Ukrainian` | сорок два     |
+    /// | `uz`      | `Lang::Uzbek`     | qirq ikki     |
+    /// | `vi`      | `Lang::Vietnamese` | bốn mươi hai  |
+    /// | `zu`      | `Lang::Zulu`      | amashumi amane nambili |
     fn from_str(input: &str) -> Result<Self, Self::Err> {
         match input {
+            "am" => Ok(Self::Amharic),
+            "ar" => Ok(Self::Arabic),
+            "hy" => Ok(Self::Armenian),
+            "eu" => Ok(Self::Basque),
+            "bn" => Ok(Self::Bengali),
+            "my" => Ok(Self::Burmese),
+            "zh" => Ok(Self::Chinese),
+            "cs" => Ok(Self::Czech),
+            "nl" => Ok(Self::Dutch),
             "en" => Ok(Self::English),
+            "fi" => Ok(Self::Finnish),
             "fr" => Ok(Self::French),
             "fr_BE" => Ok(Self::French_BE),
             "fr_CH" => Ok(Self::French_CH),
+            "fr_CA" => Ok(Self::French_CA),
+            "hu" => Ok(Self::Hungarian),
+            "is" => Ok(Self::Icelandic),
+            "it" => Ok(Self::Italian),
+            "lo" => Ok(Self::Lao),
+            "la" => Ok(Self::Latin),
+            "lv" => Ok(Self::Latvian),
+            "mt" => Ok(Self::Maltese),
+            "mn" => Ok(Self::Mongolian),
+            "ne" => Ok(Self::Nepali),
+            "pl" => Ok(Self::Polish),
+            "pt" => Ok(Self::Portuguese),
+            "pt_BR" => Ok(Self::Portuguese_BR),
+            "ro" => Ok(Self::Romanian),
+            "sr" => Ok(Self::Serbian),
+            "si" => Ok(Self::Sinhala),
+            "sk" => Ok(Self::Slovak),
+            "sl" => Ok(Self::Slovenian),
+            "es" => Ok(Self::Spanish),
+            "sw" => Ok(Self::Swahili),
+            "tl" => Ok(Self::Tagalog),
+            "ta" => Ok(Self::Tamil),
+            "te" => Ok(Self::Telugu),
+            "tr" => Ok(Self::Turkish),
             "uk" => Ok(Self::Ukrainian),
+            "uz" => Ok(Self::Uzbek),
+            "vi" => Ok(Self::Vietnamese),
+            "zu" => Ok(Self::Zulu),
             _ => Err(()),
         }
     }
 }
 
+/// Writes the digits of a number in groups of three, e.g. `1,000,000`
+pub(crate) fn group_digits(num: u128, separator: &str) -> String {
+    let digits = num.to_string();
+    let mut grouped = String::new();
+    for (i, digit) in digits.chars().enumerate() {
+        if i != 0 && (digits.len() - i).is_multiple_of(3) {
+            grouped.push_str(separator);
+        }
+        grouped.push(digit);
+    }
+    grouped
+}
+
+fn has_preference(preferences: &[String], names: &[&str]) -> bool {
+    preferences.iter().any(|v| names.contains(&v.as_str()))
+}
+
+/// Reads a gender preference for the languages that only tell the feminine
+/// from the masculine
+///
+/// `f`/`feminine` and `m`/`masculine` are understood everywhere, on top of
+/// the native words of the language.
+fn gender_preference(preference: &str, feminine: &[&str], masculine: &[&str]) -> Option<bool> {
+    if preference == "f" || preference == "feminine" || feminine.contains(&preference) {
+        Some(true)
+    } else if preference == "m" || preference == "masculine" || masculine.contains(&preference) {
+        Some(false)
+    } else {
+        None
+    }
+}
+
+/// Whether the last gender preference given selects the feminine
+fn prefers_feminine(preferences: &[String], feminine: &[&str], masculine: &[&str]) -> bool {
+    preferences
+        .iter()
+        .rev()
+        .find_map(|p| gender_preference(p, feminine, masculine))
+        .unwrap_or(false)
+}
+
+/// The last French era wording asked for, if any
+fn french_era(preferences: &[String]) -> Option<lang::fr::EraFrench> {
+    preferences.iter().rev().find_map(|p| match p.as_str() {
+        "abrege" | "abrégé" => Some(lang::fr::EraFrench::Abbreviated),
+        "notre-ere" | "notre-ère" => Some(lang::fr::EraFrench::Secular),
+        _ => None,
+    })
+}
+
+// the preferences shared by every French region
+fn french(
+    preferences: &[String],
+    region: lang::fr::RegionFrench,
+    zero_major: bool,
+    always_cents: bool,
+    no_leading_zero: bool,
+    group_digits: bool,
+) -> lang::French {
+    let feminine = prefers_feminine(preferences, &["feminin", "féminin"], &["masculin"]);
+    let reformed = preferences
+        .iter()
+        .find(|v: &&String| ["reformed", "1990", "rectifié", "rectification"].contains(&v.as_str()))
+        .is_some();
+
+    lang::French::new(feminine, reformed, region)
+        .point(has_preference(preferences, &["point"]))
+        .grouped_decimals(has_preference(preferences, &["grouped-decimals"]))
+        .hundreds(has_preference(preferences, &["hundreds"]))
+        .plural(has_preference(preferences, &["pl", "plural", "pluriel"]))
+        .second(has_preference(preferences, &["second", "seconde"]))
+        .short(has_preference(preferences, &["short", "e"]))
+        .era(french_era(preferences))
+        .common_era(has_preference(preferences, &["ere", "ère"]))
+        .euro_cent(has_preference(preferences, &["cent"]))
+        .fraction(has_preference(preferences, &["fraction"]))
+        .zero_major(zero_major)
+        .always_cents(always_cents)
+        .no_leading_zero(no_leading_zero)
+        .group_digits(group_digits)
+}
+
+const EVERY_OUTPUT: &[Output] = &[
+    Output::Cardinal,
+    Output::Currency,
+    Output::Ordinal,
+    Output::OrdinalNum,
+    Output::Year,
+    Output::YearRange,
+    Output::Percent,
+    Output::Unit,
+    Output::Reference,
+];
+// every output spelled out in words, i.e. all but the numbered ordinal
+const WORDS: &[Output] = &[
+    Output::Cardinal,
+    Output::Currency,
+    Output::Ordinal,
+    Output::Year,
+    Output::YearRange,
+    Output::Percent,
+    Output::Unit,
+    Output::Reference,
+];
+const ORDINALS: &[Output] = &[Output::Ordinal, Output::OrdinalNum];
+
+/// Outputs on which a preference has an effect in a language
+///
+/// Returns `None` when the language ignores the preference. This has to be
+/// kept in sync with [`to_language`].
+pub fn preference_outputs(lang: Lang, preference: &str) -> Option<&'static [Output]> {
+    let is = |names: &[&str]| names.contains(&preference);
+    let gender = |feminine: &[&str], masculine: &[&str]| {
+        gender_preference(preference, feminine, masculine).is_some()
+    };
+    let slavic = || {
+        preference.parse::<lang::Declension>().is_ok()
+            || preference.parse::<lang::Gender>().is_ok()
+            || preference.parse::<lang::GrammaticalNumber>().is_ok()
+    };
+
+    match preference {
+        "strict-config" => return Some(EVERY_OUTPUT),
+        "accounting-negative" | "parentheses" => return Some(&[Output::Currency]),
+        "nato" | "pairs" => return Some(&[Output::Reference]),
+        "zero-major" if lang != Lang::Latin => return Some(&[Output::Currency]),
+        _ => (),
+    }
+
+    let outputs: &[Output] = match lang {
+        Lang::English
+        | Lang::French
+        | Lang::French_BE
+        | Lang::French_CH
+        | Lang::French_CA
+        | Lang::Ukrainian
+            if is(&["always-cents", "zero-cents", "no-cents"]) =>
+        {
+            &[Output::Currency]
+        }
+        Lang::English
+        | Lang::French
+        | Lang::French_BE
+        | Lang::French_CH
+        | Lang::French_CA
+        | Lang::Ukrainian
+            if is(&["group-digits"]) =>
+        {
+            &[Output::OrdinalNum]
+        }
+        Lang::English | Lang::French | Lang::French_BE | Lang::French_CH | Lang::French_CA
+            if is(&["no-leading-zero"]) =>
+        {
+            &[Output::Cardinal, Output::Percent, Output::Unit]
+        }
+        Lang::Arabic if gender(&["مؤنث"], &["مذكر"]) => &[
+            Output::Cardinal,
+            Output::Ordinal,
+            Output::Percent,
+            Output::Reference,
+        ],
+        Lang::Arabic if is(&["eastern-digits"]) => &[Output::OrdinalNum],
+        Lang::Armenian if is(&["թվական", "tvakan"]) => &[Output::Year, Output::YearRange],
+        Lang::Chinese if is(&["traditional", "繁體", "繁体"]) => WORDS,
+        Lang::Czech | Lang::Polish | Lang::Slovak if slavic() => WORDS,
+        Lang::Dutch if is(&["ste"]) => &[Output::OrdinalNum],
+        Lang::English if lang::en::zero_word_from_str(preference).is_some() => WORDS,
+        Lang::English
+            if is(&[
+                "uk",
+                "gb",
+                "british",
+                "no-and",
+                "plain-cardinal",
+                "long-scale",
+                "thousand-million",
+                "lakh",
+                "indian",
+                "a",
+                "informal",
+                "negative",
+                "no-hyphen",
+                "hyphen-all",
+                "fraction",
+                "hundreds",
+            ]) =>
+        {
+            WORDS
+        }
+        Lang::English if is(&["check"]) => &[Output::Currency],
+        Lang::English if is(&["us", "american", "bce", "ad", "formal-year", "oh-years"]) => {
+            &[Output::Year, Output::YearRange]
+        }
+        Lang::Finnish if lang::fi::declension_from_str(preference).is_some() => WORDS,
+        Lang::French | Lang::French_BE | Lang::French_CH | Lang::French_CA
+            if gender(&["feminin", "féminin"], &["masculin"]) =>
+        {
+            EVERY_OUTPUT
+        }
+        Lang::French | Lang::French_BE | Lang::French_CH | Lang::French_CA
+            if is(&["reformed", "1990", "rectifié", "rectification"]) =>
+        {
+            WORDS
+        }
+        Lang::French | Lang::French_BE | Lang::French_CH | Lang::French_CA
+            if is(&["point", "grouped-decimals"]) =>
+        {
+            &[Output::Cardinal, Output::Percent, Output::Unit]
+        }
+        Lang::French_CH if is(&["octante"]) => WORDS,
+        Lang::French | Lang::French_BE | Lang::French_CH | Lang::French_CA
+            if is(&["abrege", "abrégé", "notre-ere", "notre-ère", "ere", "ère"]) =>
+        {
+            &[Output::Year, Output::YearRange]
+        }
+        Lang::French | Lang::French_BE | Lang::French_CH if is(&["hundreds"]) => {
+            &[Output::Year, Output::YearRange]
+        }
+        Lang::French_CA if is(&["formal-year"]) => &[Output::Year, Output::YearRange],
+        Lang::French_CA if is(&["sous"]) => &[Output::Currency],
+        Lang::French | Lang::French_BE | Lang::French_CH | Lang::French_CA if is(&["fraction"]) => {
+            &[Output::Cardinal, Output::Percent]
+        }
+        Lang::French | Lang::French_BE | Lang::French_CH | Lang::French_CA if is(&["cent"]) => {
+            &[Output::Currency]
+        }
+        Lang::French | Lang::French_BE | Lang::French_CH | Lang::French_CA
+            if is(&["pl", "plural", "pluriel", "second", "seconde", "short", "e"]) =>
+        {
+            &[Output::OrdinalNum]
+        }
+        Lang::Icelandic
+            if lang::is::gender_from_str(preference).is_some()
+                || lang::is::declension_from_str(preference).is_some() =>
+        {
+            WORDS
+        }
+        Lang::Italian if gender(&["femminile"], &["maschile"]) => EVERY_OUTPUT,
+        Lang::Latin
+            if lang::la::gender_from_str(preference).is_some()
+                || lang::la::declension_from_str(preference).is_some()
+                || lang::la::series_from_str(preference).is_some() =>
+        {
+            WORDS
+        }
+        Lang::Latvian if gender(&["sieviešu"], &["vīriešu"]) => {
+            &[Output::Cardinal, Output::Ordinal]
+        }
+        Lang::Mongolian if is(&["short", "р"]) => &[Output::OrdinalNum],
+        Lang::Portuguese | Lang::Portuguese_BR if gender(&["feminino"], &["masculino"]) => {
+            EVERY_OUTPUT
+        }
+        Lang::Portuguese if is(&["br", "pt_BR"]) => WORDS,
+        Lang::Romanian if gender(&["feminin"], &["masculin"]) => EVERY_OUTPUT,
+        Lang::Serbian if preference.parse::<lang::Gender>().is_ok() => {
+            &[Output::Cardinal, Output::Ordinal]
+        }
+        Lang::Serbian if is(&["latin", "latinica", "латиница"]) => WORDS,
+        Lang::Sinhala if is(&["වැනි", "vaeni"]) => ORDINALS,
+        Lang::Slovenian if preference.parse::<lang::Gender>().is_ok() => {
+            &[Output::Cardinal, Output::Ordinal]
+        }
+        Lang::Spanish if gender(&["femenino"], &["masculino"]) => EVERY_OUTPUT,
+        Lang::Swahili if lang::sw::noun_class_from_str(preference).is_some() => {
+            &[Output::Cardinal, Output::Currency, Output::Unit]
+        }
+        Lang::Tagalog if is(&["spanish", "kastila"]) => &[
+            Output::Cardinal,
+            Output::Currency,
+            Output::Percent,
+            Output::Unit,
+            Output::Year,
+            Output::YearRange,
+        ],
+        Lang::Turkish if is(&["suffix", "ek"]) => &[Output::OrdinalNum],
+        Lang::Ukrainian if slavic() => EVERY_OUTPUT,
+        Lang::Ukrainian if is(&["collective", "збірний", "істота", "animate"]) => {
+            &[Output::Cardinal]
+        }
+        Lang::Ukrainian if is(&["кома", "comma"]) => {
+            &[Output::Cardinal, Output::Percent, Output::Unit]
+        }
+        Lang::Uzbek if is(&["cyrillic", "kirill"]) => EVERY_OUTPUT,
+        Lang::Vietnamese if is(&["linh", "tư", "tu", "ngàn", "ngan"]) => WORDS,
+        Lang::Zulu if lang::zu::noun_class_from_str(preference).is_some() => WORDS,
+        _ => return None,
+    };
+    Some(outputs)
+}
+
 pub fn to_language(lang: Lang, preferences: Vec<String>) -> Box<dyn Language> {
+    let zero_major = has_preference(&preferences, &["zero-major"]);
+    let always_cents = has_preference(&preferences, &["always-cents", "zero-cents", "no-cents"]);
+    let no_leading_zero = has_preference(&preferences, &["no-leading-zero"]);
+    let group_digits = has_preference(&preferences, &["group-digits"]);
+
     match lang {
+        Lang::Amharic => Box::new(lang::Amharic::new().zero_major(zero_major)),
+        Lang::Arabic => Box::new(
+            lang::Arabic::new(
+                prefers_feminine(&preferences, &["مؤنث"], &["مذكر"]),
+                has_preference(&preferences, &["eastern-digits"]),
+            )
+            .zero_major(zero_major),
+        ),
+        Lang::Armenian => {
+            let prefer_year_suffix = has_preference(&preferences, &["թվական", "tvakan"]);
+            Box::new(lang::Armenian::new(prefer_year_suffix).zero_major(zero_major))
+        }
+        Lang::Basque => Box::new(lang::Basque::new().zero_major(zero_major)),
+        Lang::Bengali => Box::new(lang::Bengali::new().zero_major(zero_major)),
+        Lang::Burmese => Box::new(lang::Burmese::new().zero_major(zero_major)),
+        Lang::Chinese => Box::new(
+            lang::Chinese::new(has_preference(
+                &preferences,
+                &["traditional", "繁體", "繁体"],
+            ))
+            .zero_major(zero_major),
+        ),
+        Lang::Czech => {
+            let (gender, number, declension) = lang::slavic::parse_preferences(&preferences);
+            Box::new(lang::Czech::new(gender, number, declension).zero_major(zero_major))
+        }
+        Lang::Dutch => Box::new(
+            lang::Dutch::new(has_preference(&preferences, &["ste"])).zero_major(zero_major),
+        ),
         Lang::English => {
-            let last = preferences
+            let zero = preferences
                 .iter()
                 .rev()
-                .find(|v| ["oh", "nil"].contains(&v.as_str()));
+                .find_map(|p| lang::en::zero_word_from_str(p))
+                .unwrap_or_default();
 
-            if let Some(v) = last {
-                return Box::new(lang::English::new(v == "oh", v == "nil"));
-            }
+            let english = lang::English::new(zero);
 
-            Box::new(lang::English::new(false, false))
+            let american = has_preference(&preferences, &["us", "american"]);
+            let british = has_preference(&preferences, &["uk", "gb", "british"]);
+            let no_and = has_preference(&preferences, &["no-and", "plain-cardinal"]);
+            let long_scale = has_preference(&preferences, &["long-scale"]);
+            let thousand_million = has_preference(&preferences, &["thousand-million"]);
+            let indian = has_preference(&preferences, &["lakh", "indian"]);
+            let informal = has_preference(&preferences, &["a", "informal"]);
+            let negative = has_preference(&preferences, &["negative"]);
+            let bce = has_preference(&preferences, &["bce"]);
+            let ad = has_preference(&preferences, &["ad"]);
+            let formal_year = has_preference(&preferences, &["formal-year"]);
+            Box::new(
+                english
+                    .zero_major(zero_major)
+                    .american(american)
+                    .british(british)
+                    .no_and(no_and)
+                    .long_scale(long_scale)
+                    .thousand_million(thousand_million)
+                    .indian(indian)
+                    .informal(informal)
+                    .negative(negative)
+                    .bce(bce)
+                    .ad(ad)
+                    .formal_year(formal_year)
+                    .always_cents(always_cents)
+                    .no_cents(has_preference(&preferences, &["no-cents"]))
+                    .no_hyphen(has_preference(&preferences, &["no-hyphen"]))
+                    .hyphen_all(has_preference(&preferences, &["hyphen-all"]))
+                    .fraction(has_preference(&preferences, &["fraction"]))
+                    .check(has_preference(&preferences, &["check"]))
+                    .hundreds(has_preference(&preferences, &["hundreds"]))
+                    .oh_years(has_preference(&preferences, &["oh-years"]))
+                    .no_leading_zero(no_leading_zero)
+                    .group_digits(group_digits),
+            )
         }
-        Lang::French => {
-            let feminine = preferences
-                .iter()
-                .find(|v| ["feminine", "feminin", "féminin", "f"].contains(&v.as_str()))
-                .is_some();
-            let reformed = preferences
+        Lang::Finnish => {
+            let declension = preferences
                 .iter()
-                .find(|v: &&String| ["reformed", "1990", "rectifié", "rectification"].contains(&v.as_str()))
-                .is_some();
-
-            Box::new(lang::French::new(feminine, reformed, lang::fr::RegionFrench::FR))
+                .rev()
+                .find_map(|p| lang::fi::declension_from_str(p))
+                .unwrap_or_default();
+            Box::new(lang::Finnish::new(declension).zero_major(zero_major))
         }
-        Lang::French_BE => {
-            let feminine = preferences
-                .iter()
-                .find(|v| ["feminine", "feminin", "féminin", "f"].contains(&v.as_str()))
-                .is_some();
-            let reformed = preferences
-                .iter()
-                .find(|v: &&String| ["reformed", "1990", "rectifié", "rectification"].contains(&v.as_str()))
-                .is_some();
+        Lang::French | Lang::French_BE | Lang::French_CH | Lang::French_CA => {
+            use lang::fr::RegionFrench;
+            let region = match lang {
+                Lang::French_BE => RegionFrench::BE,
+                Lang::French_CH => RegionFrench::CH,
+                Lang::French_CA => RegionFrench::CA,
+                _ => RegionFrench::FR,
+            };
+            let french = french(
+                &preferences,
+                region,
+                zero_major,
+                always_cents,
+                no_leading_zero,
+                group_digits,
+            );
 
-            Box::new(lang::French::new(feminine, reformed, lang::fr::RegionFrench::BE))
+            Box::new(match region {
+                RegionFrench::CH => french.octante(has_preference(&preferences, &["octante"])),
+                RegionFrench::CA => french
+                    .hundreds(!has_preference(&preferences, &["formal-year"]))
+                    .sous(has_preference(&preferences, &["sous"])),
+                _ => french,
+            })
         }
-        Lang::French_CH => {
-            let feminine = preferences
+        Lang::Hungarian => Box::new(lang::Hungarian::new().zero_major(zero_major)),
+        Lang::Icelandic => {
+            let gender = preferences
                 .iter()
-                .find(|v| ["feminine", "feminin", "féminin", "f"].contains(&v.as_str()))
-                .is_some();
-            let reformed = preferences
+                .rev()
+                .find_map(|p| lang::is::gender_from_str(p))
+                .unwrap_or_default();
+            let declension = preferences
                 .iter()
-                .find(|v: &&String| ["reformed", "1990", "rectifié", "rectification"].contains(&v.as_str()))
-                .is_some();
-
-            Box::new(lang::French::new(feminine, reformed, lang::fr::RegionFrench::CH))
+                .rev()
+                .find_map(|p| lang::is::declension_from_str(p))
+                .unwrap_or_default();
+            Box::new(lang::Icelandic::new(gender, declension).zero_major(zero_major))
         }
-        Lang::Ukrainian => {
-            let declension: lang::uk::Declension = preferences
+        Lang::Italian => {
+            let feminine = prefers_feminine(&preferences, &["femminile"], &["maschile"]);
+            Box::new(lang::Italian::new(feminine).zero_major(zero_major))
+        }
+        Lang::Lao => Box::new(lang::Lao::new().zero_major(zero_major)),
+        Lang::Latin => {
+            let gender = preferences
                 .iter()
                 .rev()
-                .find_map(|d| d.parse().ok())
+                .find_map(|p| lang::la::gender_from_str(p))
                 .unwrap_or_default();
-            let gender: lang::uk::Gender = preferences
+            let declension = preferences
                 .iter()
                 .rev()
-                .find_map(|d| d.parse().ok())
+                .find_map(|p| lang::la::declension_from_str(p))
                 .unwrap_or_default();
-            let number: lang::uk::GrammaticalNumber = preferences
+            let series = preferences
+                .iter()
+                .rev()
+                .find_map(|p| lang::la::series_from_str(p))
+                .unwrap_or(lang::la::Series::Cardinal);
+            Box::new(lang::Latin::new(gender, declension, series))
+        }
+        Lang::Latvian => Box::new(
+            lang::Latvian::new(prefers_feminine(&preferences, &["sieviešu"], &["vīriešu"]))
+                .zero_major(zero_major),
+        ),
+        Lang::Maltese => Box::new(lang::Maltese::new().zero_major(zero_major)),
+        Lang::Mongolian => {
+            let prefer_short_ordinal = has_preference(&preferences, &["short", "р"]);
+            Box::new(lang::Mongolian::new(prefer_short_ordinal).zero_major(zero_major))
+        }
+        Lang::Nepali => Box::new(lang::Nepali::new().zero_major(zero_major)),
+        Lang::Polish => {
+            let (gender, number, declension) = lang::slavic::parse_preferences(&preferences);
+            Box::new(lang::Polish::new(gender, number, declension).zero_major(zero_major))
+        }
+        Lang::Portuguese => {
+            let feminine = prefers_feminine(&preferences, &["feminino"], &["masculino"]);
+            let region = if has_preference(&preferences, &["br", "pt_BR"]) {
+                lang::pt::RegionPortuguese::BR
+            } else {
+                lang::pt::RegionPortuguese::PT
+            };
+            Box::new(lang::Portuguese::new(feminine, region).zero_major(zero_major))
+        }
+        Lang::Portuguese_BR => {
+            let feminine = prefers_feminine(&preferences, &["feminino"], &["masculino"]);
+            Box::new(
+                lang::Portuguese::new(feminine, lang::pt::RegionPortuguese::BR)
+                    .zero_major(zero_major),
+            )
+        }
+        Lang::Romanian => Box::new(
+            lang::Romanian::new(prefers_feminine(&preferences, &["feminin"], &["masculin"]))
+                .zero_major(zero_major),
+        ),
+        Lang::Serbian => {
+            let gender: lang::Gender = preferences
                 .iter()
                 .rev()
                 .find_map(|d| d.parse().ok())
                 .unwrap_or_default();
-            Box::new(lang::Ukrainian::new(gender, number, declension))
+            let latin = has_preference(&preferences, &["latin", "latinica", "латиница"]);
+            Box::new(lang::Serbian::new(gender, latin).zero_major(zero_major))
+        }
+        Lang::Sinhala => {
+            let prefer_vaeni = has_preference(&preferences, &["වැනි", "vaeni"]);
+            Box::new(lang::Sinhala::new(prefer_vaeni).zero_major(zero_major))
+        }
+        Lang::Slovak => {
+            let (gender, number, declension) = lang::slavic::parse_preferences(&preferences);
+            Box::new(lang::Slovak::new(gender, number, declension).zero_major(zero_major))
+        }
+        Lang::Slovenian => {
+            let gender = preferences.iter().rev().find_map(|d| d.parse().ok());
+            Box::new(lang::Slovenian::new(gender).zero_major(zero_major))
+        }
+        Lang::Spanish => {
+            let feminine = prefers_feminine(&preferences, &["femenino"], &["masculino"]);
+            Box::new(lang::Spanish::new(feminine).zero_major(zero_major))
+        }
+        Lang::Swahili => {
+            let class = preferences
+                .iter()
+                .rev()
+                .find_map(|p| lang::sw::noun_class_from_str(p));
+            Box::new(lang::Swahili::new(class).zero_major(zero_major))
+        }
+        Lang::Tagalog => Box::new(
+            lang::Tagalog::new(has_preference(&preferences, &["spanish", "kastila"]))
+                .zero_major(zero_major),
+        ),
+        Lang::Tamil => Box::new(lang::Tamil::new().zero_major(zero_major)),
+        Lang::Telugu => Box::new(lang::Telugu::new().zero_major(zero_major)),
+        Lang::Turkish => Box::new(
+            lang::Turkish::new(has_preference(&preferences, &["suffix", "ek"]))
+                .zero_major(zero_major),
+        ),
+        Lang::Ukrainian => {
+            let (gender, number, declension) = lang::slavic::parse_preferences(&preferences);
+            Box::new(
+                lang::Ukrainian::new(gender, number, declension)
+                    .collective(has_preference(&preferences, &["collective", "збірний"]))
+                    .comma(has_preference(&preferences, &["кома", "comma"]))
+                    .animate(has_preference(&preferences, &["істота", "animate"]))
+                    .zero_major(zero_major)
+                    .always_cents(always_cents)
+                    .group_digits(group_digits),
+            )
+        }
+        Lang::Uzbek => Box::new(
+            lang::Uzbek::new(has_preference(&preferences, &["cyrillic", "kirill"]))
+                .zero_major(zero_major),
+        ),
+        Lang::Vietnamese => Box::new(
+            lang::Vietnamese::new(
+                has_preference(&preferences, &["linh"]),
+                has_preference(&preferences, &["tư", "tu"]),
+                has_preference(&preferences, &["ngàn", "ngan"]),
+            )
+            .zero_major(zero_major),
+        ),
+        Lang::Zulu => {
+            let class = preferences
+                .iter()
+                .rev()
+                .find_map(|p| lang::zu::noun_class_from_str(p));
+            Box::new(lang::Zulu::new(class).zero_major(zero_major))
         }
     }
 }
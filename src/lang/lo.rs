@@ -0,0 +1,319 @@
+use crate::{num2words::Num2Err, Currency, Language, PluralCategory, Unit};
+use num_bigfloat::BigFloat;
+
+pub struct Lao {
+    zero_major: bool,
+}
+
+const UNITS: [&str; 10] = [
+    "ສູນ",
+    "ໜຶ່ງ",
+    "ສອງ",
+    "ສາມ",
+    "ສີ່",
+    "ຫ້າ",
+    "ຫົກ",
+    "ເຈັດ",
+    "ແປດ",
+    "ເກົ້າ",
+];
+
+// every power of ten below a million has its own word, from ten (ສິບ) to a
+// hundred thousand (ແສນ)
+const PLACES: [&str; 6] = ["", "ສິບ", "ຮ້ອຍ", "ພັນ", "ໝື່ນ", "ແສນ"];
+
+impl Lao {
+    pub fn new() -> Self {
+        Self { zero_major: false }
+    }
+
+    /// Keeps the major unit clause for amounts below one, e.g. `ສູນກີບ
+    /// ຊາວອັດ`
+    pub fn zero_major(self, zero_major: bool) -> Self {
+        Self { zero_major }
+    }
+
+    fn currencies(&self, currency: Currency) -> String {
+        match currency {
+            Currency::CNY => String::from("ຢວນ"),
+            Currency::DOLLAR | Currency::USD => String::from("ໂດລາ"),
+            Currency::EUR => String::from("ເອີໂຣ"),
+            Currency::LAK => String::from("ກີບ"),
+            Currency::THB => String::from("ບາດ"),
+            // names in the Latin script are kept apart from the number
+            _ => format!(" {}", currency.default_string(false)),
+        }
+    }
+
+    fn cents(&self, currency: Currency) -> String {
+        match currency {
+            Currency::LAK => String::from("ອັດ"),
+            Currency::THB => String::from("ສະຕາງ"),
+            _ => String::from("ເຊັນ"),
+        }
+    }
+
+    fn units(&self, unit: Unit) -> &'static str {
+        match unit {
+            Unit::Meter => "ແມັດ",
+            Unit::Kilometer => "ກິໂລແມັດ",
+            Unit::Gram => "ກຣາມ",
+            Unit::Kilogram => "ກິໂລກຣາມ",
+            Unit::Liter => "ລິດ",
+            Unit::Second => "ວິນາທີ",
+            Unit::Minute => "ນາທີ",
+            Unit::Hour => "ຊົ່ວໂມງ",
+        }
+    }
+
+    // Below a million, every digit is read with its place, e.g. ສາມຮ້ອຍຊາວເອັດ
+    fn below_million(&self, num: u64) -> String {
+        let mut words = vec![];
+        for place in (0..PLACES.len()).rev() {
+            let digit = (num / 10u64.pow(place as u32) % 10) as usize;
+            if digit == 0 {
+                continue;
+            }
+
+            words.push(match (place, digit) {
+                (1, 1) => String::from("ສິບ"),
+                (1, 2) => String::from("ຊາວ"),
+                // a trailing one after tens, e.g. ຊາວເອັດ
+                (0, 1) if num % 100 > 10 => String::from("ເອັດ"),
+                _ => format!("{}{}", UNITS[digit], PLACES[place]),
+            });
+        }
+
+        words.concat()
+    }
+
+    fn int_to_cardinal(&self, mut num: BigFloat) -> Result<String, Num2Err> {
+        // special case zero
+        if num.is_zero() {
+            return Ok(String::from(UNITS[0]));
+        }
+
+        // handling negative values
+        let mut words = vec![];
+        if num.is_negative() {
+            words.push(String::from("ລົບ"));
+            num = -num;
+        }
+
+        // millions are counted with the whole number system, e.g. ລ້ານລ້ານ
+        let bf_million = BigFloat::from(1_000_000);
+        let millions = (num / bf_million).int();
+        if !millions.is_zero() {
+            words.push(format!("{}ລ້ານ", self.int_to_cardinal(millions)?));
+        }
+        let rest = (num % bf_million).to_u64().unwrap();
+        if rest != 0 {
+            words.push(self.below_million(rest));
+        }
+
+        Ok(words.concat())
+    }
+
+    fn float_to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let integral_part = num.int();
+        let mut words: Vec<String> = vec![];
+
+        if !integral_part.is_zero() {
+            let integral_word = self.int_to_cardinal(integral_part)?;
+            words.push(integral_word);
+        } else if num.is_negative() {
+            words.push(String::from("ລົບ"));
+        }
+        if integral_part.is_zero() {
+            words.push(String::from(UNITS[0]));
+        }
+
+        let mut ordinal_part = num.frac().abs();
+        if !ordinal_part.is_zero() {
+            words.push(String::from("ຈຸດ"));
+        }
+        while !ordinal_part.is_zero() {
+            let digit = (ordinal_part * BigFloat::from(10)).int();
+            ordinal_part = (ordinal_part * BigFloat::from(10)).frac();
+            words.push(String::from(UNITS[digit.to_u64().unwrap() as usize]));
+        }
+        Ok(words.concat())
+    }
+}
+
+impl Default for Lao {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Language for Lao {
+    fn to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num.is_inf_pos() {
+            Ok(String::from("ອະນັນ"))
+        } else if num.is_inf_neg() {
+            Ok(String::from("ລົບອະນັນ"))
+        } else if num.frac().is_zero() {
+            self.int_to_cardinal(num)
+        } else {
+            self.float_to_cardinal(num)
+        }
+    }
+
+    fn to_ordinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        Ok(format!("ທີ{}", self.to_cardinal(num)?))
+    }
+
+    fn to_ordinal_num(&self, num: BigFloat) -> Result<String, Num2Err> {
+        Ok(format!("ທີ {}", num.to_u128().unwrap()))
+    }
+
+    fn to_year(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if !num.frac().is_zero() {
+            return Err(Num2Err::FloatingYear);
+        }
+
+        let words = self.int_to_cardinal(num.abs())?;
+        if num.is_negative() {
+            Ok(format!("ກ່ອນ ຄ.ສ. {}", words))
+        } else {
+            Ok(words)
+        }
+    }
+
+    fn to_currency(&self, num: BigFloat, currency: Currency) -> Result<String, Num2Err> {
+        // the currency is written right after the number, e.g. ສີ່ສິບສອງກີບ
+        if num.is_inf() || num.frac().is_zero() {
+            Ok(format!(
+                "{}{}",
+                self.to_cardinal(num)?,
+                self.currencies(currency)
+            ))
+        } else {
+            let integral_part = num.int();
+            let cents_nb = (num * BigFloat::from(100)).int() % BigFloat::from(100);
+            let cents_words = format!(
+                "{}{}",
+                self.int_to_cardinal(cents_nb)?,
+                self.cents(currency)
+            );
+            let integral_word = self.to_currency(integral_part, currency)?;
+
+            if cents_nb.is_zero() {
+                Ok(integral_word)
+            } else if integral_part.is_zero() && !self.zero_major {
+                Ok(cents_words)
+            } else {
+                Ok(format!("{} {}", integral_word, cents_words))
+            }
+        }
+    }
+
+    fn to_percent(&self, num: BigFloat) -> Result<String, Num2Err> {
+        Ok(format!("{}ເປີເຊັນ", self.to_cardinal(num)?))
+    }
+
+    fn to_unit(&self, num: BigFloat, unit: Unit) -> Result<String, Num2Err> {
+        Ok(format!("{}{}", self.to_cardinal(num)?, self.units(unit)))
+    }
+
+    fn plural_category(&self, _num: BigFloat) -> PluralCategory {
+        // CLDR: Lao nouns do not inflect for number
+        PluralCategory::Other
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_cardinal() {
+        assert_eq!(
+            Num2Words::new(1).lang(Lang::Lao).to_words(),
+            Ok(String::from("ໜຶ່ງ"))
+        );
+        assert_eq!(
+            Num2Words::new(11).lang(Lang::Lao).to_words(),
+            Ok(String::from("ສິບເອັດ"))
+        );
+        assert_eq!(
+            Num2Words::new(21).lang(Lang::Lao).to_words(),
+            Ok(String::from("ຊາວເອັດ"))
+        );
+        assert_eq!(
+            Num2Words::new(22).lang(Lang::Lao).to_words(),
+            Ok(String::from("ຊາວສອງ"))
+        );
+        assert_eq!(
+            Num2Words::new(42).lang(Lang::Lao).to_words(),
+            Ok(String::from("ສີ່ສິບສອງ"))
+        );
+        assert_eq!(
+            Num2Words::new(100).lang(Lang::Lao).to_words(),
+            Ok(String::from("ໜຶ່ງຮ້ອຍ"))
+        );
+        assert_eq!(
+            Num2Words::new(101).lang(Lang::Lao).to_words(),
+            Ok(String::from("ໜຶ່ງຮ້ອຍໜຶ່ງ"))
+        );
+        assert_eq!(
+            Num2Words::new(-2.5).lang(Lang::Lao).to_words(),
+            Ok(String::from("ລົບສອງຈຸດຫ້າ"))
+        );
+        assert_eq!(
+            Num2Words::new(0.5).lang(Lang::Lao).to_words(),
+            Ok(String::from("ສູນຈຸດຫ້າ"))
+        );
+        assert_eq!(
+            Num2Words::new(-0.25).lang(Lang::Lao).to_words(),
+            Ok(String::from("ລົບສູນຈຸດສອງຫ້າ"))
+        );
+    }
+
+    #[test]
+    fn test_grouping() {
+        assert_eq!(
+            Num2Words::new(250_000).lang(Lang::Lao).to_words(),
+            Ok(String::from("ສອງແສນຫ້າໝື່ນ"))
+        );
+        assert_eq!(
+            Num2Words::new(1_000_000).lang(Lang::Lao).to_words(),
+            Ok(String::from("ໜຶ່ງລ້ານ"))
+        );
+        assert_eq!(
+            Num2Words::new(21_000_021).lang(Lang::Lao).to_words(),
+            Ok(String::from("ຊາວເອັດລ້ານຊາວເອັດ"))
+        );
+    }
+
+    #[test]
+    fn test_ordinal() {
+        assert_eq!(
+            Num2Words::new(42).lang(Lang::Lao).ordinal().to_words(),
+            Ok(String::from("ທີສີ່ສິບສອງ"))
+        );
+        assert_eq!(
+            Num2Words::new(42).lang(Lang::Lao).ordinal_num().to_words(),
+            Ok(String::from("ທີ 42"))
+        );
+    }
+
+    #[test]
+    fn test_currency() {
+        assert_eq!(
+            Num2Words::new(42)
+                .lang(Lang::Lao)
+                .currency(Currency::LAK)
+                .to_words(),
+            Ok(String::from("ສີ່ສິບສອງກີບ"))
+        );
+        assert_eq!(
+            Num2Words::new(42.5)
+                .lang(Lang::Lao)
+                .currency(Currency::LAK)
+                .to_words(),
+            Ok(String::from("ສີ່ສິບສອງກີບ ຫ້າສິບອັດ"))
+        );
+    }
+}
@@ -0,0 +1,545 @@
+use crate::{num2words::Num2Err, Currency, Gender, Language, PluralCategory, Unit};
+use num_bigfloat::BigFloat;
+
+pub struct Latvian {
+    feminine: bool,
+    zero_major: bool,
+}
+
+// (singular, plural, genitive plural, gender)
+type Noun = (&'static str, &'static str, &'static str, Gender);
+
+const UNITS: [[&str; 9]; 2] = [
+    [
+        "viens", "divi", "trīs", "četri", "pieci", "seši", "septiņi", "astoņi", "deviņi",
+    ],
+    [
+        "viena",
+        "divas",
+        "trīs",
+        "četras",
+        "piecas",
+        "sešas",
+        "septiņas",
+        "astoņas",
+        "deviņas",
+    ],
+];
+
+// stems shared by the teens and the tens, e.g. "div" in "divpadsmit"
+const STEMS: [&str; 9] = [
+    "vien", "div", "trīs", "četr", "piec", "seš", "septiņ", "astoņ", "deviņ",
+];
+
+// stems of the ordinals of the units, e.g. "otr" for "otrais"
+const ORDINAL_UNITS: [&str; 9] = [
+    "pirm", "otr", "treš", "ceturt", "piekt", "sest", "septīt", "astot", "devīt",
+];
+
+const MEGAS: [Noun; 5] = [
+    ("tūkstotis", "tūkstoši", "tūkstošu", Gender::Masculine),
+    ("miljons", "miljoni", "miljonu", Gender::Masculine),
+    ("miljards", "miljardi", "miljardu", Gender::Masculine),
+    ("triljons", "triljoni", "triljonu", Gender::Masculine),
+    ("triljards", "triljardi", "triljardu", Gender::Masculine),
+];
+
+// stems of the ordinals of the powers of a thousand, e.g. "tūkstošais"
+const ORDINAL_MEGAS: [&str; 5] = ["tūkstoš", "miljon", "miljard", "triljon", "triljard"];
+
+impl Latvian {
+    pub fn new(feminine: bool) -> Self {
+        Self {
+            feminine,
+            zero_major: false,
+        }
+    }
+
+    /// Keeps the major unit clause for amounts below one, e.g. `nulle eiro un
+    /// divdesmit centi`
+    pub fn zero_major(self, zero_major: bool) -> Self {
+        Self { zero_major, ..self }
+    }
+
+    fn with_gender(&self, gender: Gender) -> Self {
+        Self {
+            feminine: gender == Gender::Feminine,
+            ..*self
+        }
+    }
+
+    fn currencies(&self, currency: Currency) -> Noun {
+        match currency {
+            // the euro does not decline
+            Currency::EUR => ("eiro", "eiro", "eiro", Gender::Masculine),
+            Currency::AUD
+            | Currency::CAD
+            | Currency::DOLLAR
+            | Currency::HKD
+            | Currency::NZD
+            | Currency::SGD
+            | Currency::TWD
+            | Currency::USD => ("dolārs", "dolāri", "dolāru", Gender::Masculine),
+            Currency::GBP => ("mārciņa", "mārciņas", "mārciņu", Gender::Feminine),
+            Currency::CHF => ("franks", "franki", "franku", Gender::Masculine),
+            Currency::JPY => ("jena", "jenas", "jenu", Gender::Feminine),
+            _ => ("", "", "", Gender::Masculine),
+        }
+    }
+
+    fn cents(&self, currency: Currency) -> Noun {
+        match currency {
+            Currency::GBP => ("penss", "pensi", "pensu", Gender::Masculine),
+            _ => ("cents", "centi", "centu", Gender::Masculine),
+        }
+    }
+
+    fn units(&self, unit: Unit) -> Noun {
+        match unit {
+            Unit::Meter => ("metrs", "metri", "metru", Gender::Masculine),
+            Unit::Kilometer => ("kilometrs", "kilometri", "kilometru", Gender::Masculine),
+            Unit::Gram => ("grams", "grami", "gramu", Gender::Masculine),
+            Unit::Kilogram => ("kilograms", "kilogrami", "kilogramu", Gender::Masculine),
+            Unit::Liter => ("litrs", "litri", "litru", Gender::Masculine),
+            Unit::Second => ("sekunde", "sekundes", "sekunžu", Gender::Feminine),
+            Unit::Minute => ("minūte", "minūtes", "minūšu", Gender::Feminine),
+            Unit::Hour => ("stunda", "stundas", "stundu", Gender::Feminine),
+        }
+    }
+
+    fn split_thousands(&self, mut num: BigFloat) -> Vec<u64> {
+        let mut thousands = Vec::new();
+        let bf_1000 = BigFloat::from(1000);
+
+        while !num.is_zero() {
+            thousands.push((num % bf_1000).to_u64().unwrap());
+            num /= bf_1000;
+        }
+
+        thousands
+    }
+
+    // tens and units of a triplet, e.g. "četrdesmit divi"
+    fn tens_to_words(&self, tens: usize, units: usize) -> Vec<String> {
+        let mut words = vec![];
+        match tens {
+            0 => (),
+            1 if units == 0 => words.push(String::from("desmit")),
+            1 => return vec![format!("{}padsmit", STEMS[units - 1])],
+            _ => words.push(format!("{}desmit", STEMS[tens - 1])),
+        }
+        if units > 0 {
+            words.push(String::from(UNITS[self.feminine as usize][units - 1]));
+        }
+        words
+    }
+
+    fn triplet_to_words(&self, triplet: u64) -> Vec<String> {
+        let hundreds = (triplet / 100 % 10) as usize;
+        let tens = (triplet / 10 % 10) as usize;
+        let units = (triplet % 10) as usize;
+        let mut words = vec![];
+
+        // the hundred is a masculine noun, e.g. "divi simti"
+        match hundreds {
+            0 => (),
+            1 => words.push(String::from("simts")),
+            _ => {
+                words.push(String::from(UNITS[0][hundreds - 1]));
+                words.push(String::from("simti"));
+            }
+        }
+        words.extend(self.tens_to_words(tens, units));
+
+        words
+    }
+
+    fn int_to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        // special case zero
+        if num.is_zero() {
+            return Ok(String::from("nulle"));
+        }
+
+        let mut words = vec![];
+        for (i, triplet) in self.split_thousands(num.abs()).iter().enumerate().rev() {
+            if *triplet == 0 {
+                continue;
+            }
+            if i == 0 {
+                words.extend(self.triplet_to_words(*triplet));
+                continue;
+            }
+
+            let (singular, plural, _, gender) = MEGAS.get(i - 1).ok_or(Num2Err::CannotConvert)?;
+            // "tūkstotis" rather than "viens tūkstotis", but "viens miljons"
+            if i != 1 || *triplet != 1 {
+                words.extend(self.with_gender(*gender).triplet_to_words(*triplet));
+            }
+            words.push(String::from(self.pick(
+                BigFloat::from(*triplet),
+                singular,
+                plural,
+            )));
+        }
+
+        // handling negative values
+        if num.is_negative() {
+            words.insert(0, String::from("mīnus"));
+        }
+
+        Ok(words.join(" "))
+    }
+
+    fn float_to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let mut words = vec![];
+        if num.is_negative() {
+            words.push(String::from("mīnus"));
+        }
+        words.push(self.int_to_cardinal(num.int().abs())?);
+        words.push(String::from("komats"));
+
+        let mut ordinal_part = num.frac().abs();
+        while !ordinal_part.is_zero() {
+            let digit = (ordinal_part * BigFloat::from(10)).int();
+            ordinal_part = (ordinal_part * BigFloat::from(10)).frac();
+            words.push(Self::new(false).int_to_cardinal(digit)?);
+        }
+
+        Ok(words.join(" "))
+    }
+
+    fn cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num.is_inf_pos() {
+            Ok(String::from("bezgalība"))
+        } else if num.is_inf_neg() {
+            Ok(String::from("mīnus bezgalība"))
+        } else if num.frac().is_zero() {
+            self.int_to_cardinal(num)
+        } else {
+            self.float_to_cardinal(num)
+        }
+    }
+
+    // only the last word is an ordinal, e.g. "četrdesmit otrais"
+    fn ordinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num.is_zero() {
+            return Ok(String::from(if self.feminine {
+                "nultā"
+            } else {
+                "nultais"
+            }));
+        }
+
+        let thousands = self.split_thousands(num.abs());
+        let last = thousands
+            .iter()
+            .position(|triplet| *triplet != 0)
+            .ok_or(Num2Err::CannotConvert)?;
+        let triplet = thousands[last];
+
+        // the whole number before the last group stays a cardinal
+        let bf_1000 = BigFloat::from(1000);
+        let mut scale = BigFloat::from(1);
+        for _ in 0..last {
+            scale *= bf_1000;
+        }
+        let head = num.abs() - num.abs() % (scale * bf_1000);
+        let mut words = vec![];
+        if !head.is_zero() {
+            words.push(Self::new(false).int_to_cardinal(head)?);
+        }
+
+        let hundreds = (triplet / 100 % 10) as usize;
+        let tens = (triplet / 10 % 10) as usize;
+        let units = (triplet % 10) as usize;
+        let stem = if last > 0 {
+            // powers of a thousand fuse with their count, e.g. "divtūkstošais"
+            let stem = ORDINAL_MEGAS.get(last - 1).ok_or(Num2Err::CannotConvert)?;
+            let count = match triplet {
+                1 => String::new(),
+                2..=9 => String::from(STEMS[units - 1]),
+                _ => Self::new(false).triplet_to_words(triplet).concat(),
+            };
+            format!("{}{}", count, stem)
+        } else if tens == 0 && units == 0 {
+            // hundreds fuse with their count, e.g. "divsimtais"
+            match hundreds {
+                1 => String::from("simt"),
+                _ => format!("{}simt", STEMS[hundreds - 1]),
+            }
+        } else {
+            words.extend(Self::new(false).triplet_to_words(triplet - triplet % 100));
+            match (tens, units) {
+                (1, _) => String::from(&Self::new(false).tens_to_words(tens, units)[0]),
+                (_, 0) => format!("{}desmit", STEMS[tens - 1]),
+                _ => {
+                    words.extend(Self::new(false).tens_to_words(tens, 0));
+                    String::from(ORDINAL_UNITS[units - 1])
+                }
+            }
+        };
+
+        let ending = if self.feminine { "ā" } else { "ais" };
+        words.push(format!("{}{}", stem, ending));
+        Ok(words.join(" "))
+    }
+
+    // singular after a number ending in one but not eleven
+    fn pick<'a>(&self, num: BigFloat, singular: &'a str, plural: &'a str) -> &'a str {
+        if self.plural_category(num) == PluralCategory::One {
+            singular
+        } else {
+            plural
+        }
+    }
+
+    // like the powers of a thousand, a noun counted by a round thousand or
+    // more takes the genitive plural, e.g. "viens miljons dolāru"
+    fn noun_form<'a>(
+        &self,
+        num: BigFloat,
+        singular: &'a str,
+        plural: &'a str,
+        genitive: &'a str,
+    ) -> &'a str {
+        if !num.is_zero() && num.frac().is_zero() && (num % BigFloat::from(1000)).is_zero() {
+            genitive
+        } else {
+            self.pick(num, singular, plural)
+        }
+    }
+
+    fn with_noun(
+        &self,
+        num: BigFloat,
+        (singular, plural, genitive, gender): Noun,
+    ) -> Result<String, Num2Err> {
+        if num.is_inf() {
+            return Ok(format!("{} {}", self.cardinal(num)?, plural));
+        }
+
+        Ok(format!(
+            "{} {}",
+            self.with_gender(gender).cardinal(num)?,
+            self.noun_form(num, singular, plural, genitive)
+        ))
+    }
+}
+
+impl Language for Latvian {
+    fn to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        self.cardinal(num)
+    }
+
+    fn to_ordinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        self.ordinal(num)
+    }
+
+    fn to_ordinal_num(&self, num: BigFloat) -> Result<String, Num2Err> {
+        Ok(format!("{}.", num.to_u128().unwrap()))
+    }
+
+    fn to_year(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if !num.frac().is_zero() {
+            return Err(Num2Err::FloatingYear);
+        }
+
+        // years are ordinals agreeing with "gads", e.g. "divi tūkstoši
+        // divdesmit trešais"
+        let year_word = Self::new(false).ordinal(num.abs())?;
+        if num.is_negative() {
+            Ok(format!("{} pirms mūsu ēras", year_word))
+        } else {
+            Ok(year_word)
+        }
+    }
+
+    fn to_currency(&self, num: BigFloat, currency: Currency) -> Result<String, Num2Err> {
+        let (singular, plural, genitive, gender) = self.currencies(currency);
+        let (singular, plural, genitive) = if singular.is_empty() {
+            (
+                currency.default_string(false),
+                currency.default_string(true),
+                currency.default_string(true),
+            )
+        } else {
+            (
+                String::from(singular),
+                String::from(plural),
+                String::from(genitive),
+            )
+        };
+        let major = |num: BigFloat| -> Result<String, Num2Err> {
+            if num.is_inf() {
+                return Ok(format!("{} {}", self.cardinal(num)?, plural));
+            }
+            Ok(format!(
+                "{} {}",
+                self.with_gender(gender).cardinal(num)?,
+                self.noun_form(num, &singular, &plural, &genitive)
+            ))
+        };
+
+        if num.is_inf() || num.frac().is_zero() {
+            return major(num);
+        }
+
+        let integral_part = num.int();
+        let cents_nb = (num * BigFloat::from(100)).int() % BigFloat::from(100);
+        let cents_words = self.with_noun(cents_nb, self.cents(currency))?;
+        let integral_word = major(integral_part)?;
+
+        if cents_nb.is_zero() {
+            Ok(integral_word)
+        } else if integral_part.is_zero() && !self.zero_major {
+            Ok(cents_words)
+        } else {
+            Ok(format!("{} un {}", integral_word, cents_words))
+        }
+    }
+
+    fn to_percent(&self, num: BigFloat) -> Result<String, Num2Err> {
+        self.with_noun(num, ("procents", "procenti", "procentu", Gender::Masculine))
+    }
+
+    fn to_unit(&self, num: BigFloat, unit: Unit) -> Result<String, Num2Err> {
+        self.with_noun(num, self.units(unit))
+    }
+
+    fn plural_category(&self, num: BigFloat) -> PluralCategory {
+        if !num.frac().is_zero() || num.is_inf() {
+            return PluralCategory::Other;
+        }
+
+        let last_two = (num.abs() % BigFloat::from(100)).to_u64().unwrap_or(0);
+        if last_two % 10 == 1 && last_two != 11 {
+            PluralCategory::One
+        } else if last_two.is_multiple_of(10) || (11..=19).contains(&last_two) {
+            PluralCategory::Zero
+        } else {
+            PluralCategory::Other
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Currency, Lang, Num2Words};
+
+    #[test]
+    fn test_cardinal() {
+        for (num, words) in [
+            (0, "nulle"),
+            (11, "vienpadsmit"),
+            (42, "četrdesmit divi"),
+            (100, "simts"),
+            (342, "trīs simti četrdesmit divi"),
+            (1000, "tūkstotis"),
+            (2000, "divi tūkstoši"),
+            (21000, "divdesmit viens tūkstotis"),
+            (1000000, "viens miljons"),
+            (-5, "mīnus pieci"),
+        ] {
+            assert_eq!(
+                Num2Words::new(num).lang(Lang::Latvian).to_words(),
+                Ok(String::from(words))
+            );
+        }
+        assert_eq!(
+            Num2Words::new(42)
+                .lang(Lang::Latvian)
+                .prefer("f")
+                .to_words(),
+            Ok(String::from("četrdesmit divas"))
+        );
+    }
+
+    #[test]
+    fn test_ordinal() {
+        let ordinal = |num: i64, gender| {
+            Num2Words::new(num)
+                .lang(Lang::Latvian)
+                .prefer(gender)
+                .ordinal()
+                .to_words()
+        };
+
+        for (num, masculine, feminine) in [
+            (0, "nultais", "nultā"),
+            (1, "pirmais", "pirmā"),
+            (11, "vienpadsmitais", "vienpadsmitā"),
+            (20, "divdesmitais", "divdesmitā"),
+            (42, "četrdesmit otrais", "četrdesmit otrā"),
+            (100, "simtais", "simtā"),
+            (200, "divsimtais", "divsimtā"),
+            (142, "simts četrdesmit otrais", "simts četrdesmit otrā"),
+            (1000, "tūkstošais", "tūkstošā"),
+            (2000, "divtūkstošais", "divtūkstošā"),
+        ] {
+            assert_eq!(ordinal(num, "m"), Ok(String::from(masculine)));
+            assert_eq!(ordinal(num, "f"), Ok(String::from(feminine)));
+        }
+        assert_eq!(
+            Num2Words::new(42)
+                .lang(Lang::Latvian)
+                .ordinal_num()
+                .to_words(),
+            Ok(String::from("42."))
+        );
+    }
+
+    #[test]
+    fn test_year() {
+        assert_eq!(
+            Num2Words::new(2023).lang(Lang::Latvian).year().to_words(),
+            Ok(String::from("divi tūkstoši divdesmit trešais"))
+        );
+    }
+
+    #[test]
+    fn test_currency() {
+        for (num, words) in [
+            (1.0, "viens eiro"),
+            (11.0, "vienpadsmit eiro"),
+            (21.0, "divdesmit viens eiro"),
+            (101.0, "simts viens eiro"),
+            (42.21, "četrdesmit divi eiro un divdesmit viens cents"),
+            (0.11, "vienpadsmit centi"),
+            (0.01, "viens cents"),
+        ] {
+            assert_eq!(
+                Num2Words::new(num)
+                    .lang(Lang::Latvian)
+                    .currency(Currency::EUR)
+                    .to_words(),
+                Ok(String::from(words))
+            );
+        }
+        for (num, words) in [
+            (11.0, "vienpadsmit mārciņas"),
+            (21.0, "divdesmit viena mārciņa"),
+            (101.0, "simts viena mārciņa"),
+        ] {
+            assert_eq!(
+                Num2Words::new(num)
+                    .lang(Lang::Latvian)
+                    .currency(Currency::GBP)
+                    .to_words(),
+                Ok(String::from(words))
+            );
+        }
+        for (num, words) in [
+            (1e6, "viens miljons dolāru"),
+            (2000.0, "divi tūkstoši dolāru"),
+            (2001.0, "divi tūkstoši viens dolārs"),
+        ] {
+            assert_eq!(
+                Num2Words::new(num)
+                    .lang(Lang::Latvian)
+                    .currency(Currency::USD)
+                    .to_words(),
+                Ok(String::from(words))
+            );
+        }
+    }
+}
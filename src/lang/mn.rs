@@ -0,0 +1,473 @@
+use crate::{num2words::Num2Err, Currency, Language, PluralCategory, Unit};
+use num_bigfloat::BigFloat;
+
+pub struct Mongolian {
+    prefer_short_ordinal: bool,
+    zero_major: bool,
+}
+
+const UNITS: [&str; 9] = [
+    "нэг",
+    "хоёр",
+    "гурав",
+    "дөрөв",
+    "тав",
+    "зургаа",
+    "долоо",
+    "найм",
+    "ес",
+];
+
+const TENS: [&str; 9] = [
+    "арав", "хорь", "гуч", "дөч", "тавь", "жар", "дал", "ная", "ер",
+];
+
+const HUNDRED: &str = "зуу";
+
+const MEGAS: [&str; 4] = ["мянга", "сая", "тэрбум", "их наяд"];
+
+// Every numeral but the last one takes its attributive form, as does the last
+// one when a noun follows, e.g. "дөчин хоёр", "гурван зуу", "арван төгрөг"
+const ATTRIBUTIVES: [(&str, &str); 19] = [
+    ("гурав", "гурван"),
+    ("дөрөв", "дөрвөн"),
+    ("тав", "таван"),
+    ("зургаа", "зургаан"),
+    ("долоо", "долоон"),
+    ("найм", "найман"),
+    ("ес", "есөн"),
+    ("арав", "арван"),
+    ("хорь", "хорин"),
+    ("гуч", "гучин"),
+    ("дөч", "дөчин"),
+    ("тавь", "тавин"),
+    ("жар", "жаран"),
+    ("дал", "далан"),
+    ("ная", "наян"),
+    ("ер", "ерэн"),
+    ("зуу", "зуун"),
+    ("мянга", "мянган"),
+    ("тэг", "тэг"),
+];
+
+fn attributive(word: &str) -> &str {
+    ATTRIBUTIVES
+        .iter()
+        .find(|(independent, _)| *independent == word)
+        .map_or(word, |(_, attributive)| attributive)
+}
+
+// The ordinal suffix follows the vowel harmony of the word it is attached to,
+// e.g. "хоёрдугаар" but "нэгдүгээр"
+fn ordinal_suffix(word: &str) -> &'static str {
+    let last_vowel = word
+        .chars()
+        .rev()
+        .find(|c| "аоуяёюэөүе".contains(*c))
+        .unwrap_or('а');
+    if "эөүе".contains(last_vowel) {
+        "дүгээр"
+    } else {
+        "дугаар"
+    }
+}
+
+impl Mongolian {
+    pub fn new(prefer_short_ordinal: bool) -> Self {
+        Self {
+            prefer_short_ordinal,
+            zero_major: false,
+        }
+    }
+
+    /// Keeps the major unit clause for amounts below one, e.g. `тэг төгрөг
+    /// хорин мөнгө`
+    pub fn zero_major(self, zero_major: bool) -> Self {
+        Self { zero_major, ..self }
+    }
+
+    fn currencies(&self, currency: Currency) -> String {
+        match currency {
+            Currency::CNY => String::from("юань"),
+            Currency::DOLLAR => String::from("доллар"),
+            Currency::EUR => String::from("евро"),
+            Currency::GBP => String::from("фунт стерлинг"),
+            Currency::JPY => String::from("иен"),
+            Currency::MNT => String::from("төгрөг"),
+            Currency::RUB => String::from("рубль"),
+            Currency::USD => String::from("америк доллар"),
+            _ => currency.default_string(false),
+        }
+    }
+
+    fn cents(&self, currency: Currency) -> String {
+        match currency {
+            Currency::EUR => String::from("евроцент"),
+            Currency::MNT => String::from("мөнгө"),
+            Currency::RUB => String::from("копейк"),
+            _ => String::from("цент"),
+        }
+    }
+
+    fn units(&self, unit: Unit) -> &'static str {
+        match unit {
+            Unit::Meter => "метр",
+            Unit::Kilometer => "километр",
+            Unit::Gram => "грамм",
+            Unit::Kilogram => "килограмм",
+            Unit::Liter => "литр",
+            Unit::Second => "секунд",
+            Unit::Minute => "минут",
+            Unit::Hour => "цаг",
+        }
+    }
+
+    fn split_thousands(&self, mut num: BigFloat) -> Vec<u64> {
+        let mut thousands = Vec::new();
+        let bf_1000 = BigFloat::from(1000);
+
+        while !num.is_zero() {
+            thousands.push((num % bf_1000).to_u64().unwrap());
+            num /= bf_1000;
+        }
+
+        thousands
+    }
+
+    // the independent forms of every numeral
+    fn words(&self, num: BigFloat) -> Result<Vec<&'static str>, Num2Err> {
+        if num.is_zero() {
+            return Ok(vec!["тэг"]);
+        }
+
+        let mut words = vec![];
+        for (i, triplet) in self.split_thousands(num).iter().enumerate().rev() {
+            let hundreds = (triplet / 100 % 10) as usize;
+            let tens = (triplet / 10 % 10) as usize;
+            let units = (triplet % 10) as usize;
+
+            if hundreds > 0 {
+                words.push(UNITS[hundreds - 1]);
+                words.push(HUNDRED);
+            }
+            if tens > 0 {
+                words.push(TENS[tens - 1]);
+            }
+            if units > 0 {
+                words.push(UNITS[units - 1]);
+            }
+
+            if i != 0 && triplet != &0 {
+                if i > MEGAS.len() {
+                    return Err(Num2Err::CannotConvert);
+                }
+                words.push(MEGAS[i - 1]);
+            }
+        }
+
+        Ok(words)
+    }
+
+    fn int_to_cardinal(&self, num: BigFloat, before_noun: bool) -> Result<String, Num2Err> {
+        let words = self.words(num.abs())?;
+        let last = words.len() - 1;
+        let words: Vec<&str> = words
+            .iter()
+            .enumerate()
+            .map(|(i, w)| {
+                // "мянга" only changes before a noun, e.g. "хоёр мянга хорин"
+                if (i != last && !MEGAS.contains(w)) || (i == last && before_noun) {
+                    attributive(w)
+                } else {
+                    w
+                }
+            })
+            .collect();
+
+        // handling negative values
+        if num.is_negative() {
+            Ok(format!("хасах {}", words.join(" ")))
+        } else {
+            Ok(words.join(" "))
+        }
+    }
+
+    fn float_to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let mut words = vec![];
+        if num.is_negative() {
+            words.push(String::from("хасах"));
+        }
+        words.push(self.int_to_cardinal(num.int().abs(), false)?);
+        words.push(String::from("таслал"));
+
+        let mut ordinal_part = num.frac().abs();
+        while !ordinal_part.is_zero() {
+            let digit = (ordinal_part * BigFloat::from(10)).int();
+            ordinal_part = (ordinal_part * BigFloat::from(10)).frac();
+            words.push(self.int_to_cardinal(digit, false)?);
+        }
+
+        Ok(words.join(" "))
+    }
+
+    fn to_counted(&self, num: BigFloat, noun: &str) -> Result<String, Num2Err> {
+        if num.is_inf() {
+            Ok(format!(
+                "{}хязгааргүй {}",
+                if num.is_negative() { "хасах " } else { "" },
+                noun
+            ))
+        } else if num.frac().is_zero() {
+            Ok(format!("{} {}", self.int_to_cardinal(num, true)?, noun))
+        } else {
+            Ok(format!("{} {}", self.float_to_cardinal(num)?, noun))
+        }
+    }
+}
+
+impl Language for Mongolian {
+    fn to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num.is_inf_pos() {
+            Ok(String::from("хязгааргүй"))
+        } else if num.is_inf_neg() {
+            Ok(String::from("хасах хязгааргүй"))
+        } else if num.frac().is_zero() {
+            self.int_to_cardinal(num, false)
+        } else {
+            self.float_to_cardinal(num)
+        }
+    }
+
+    fn to_ordinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let cardinal_word = self.int_to_cardinal(num, false)?;
+        Ok(format!(
+            "{}{}",
+            cardinal_word,
+            ordinal_suffix(&cardinal_word)
+        ))
+    }
+
+    fn to_ordinal_num(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if self.prefer_short_ordinal {
+            Ok(format!("{}-р", num.to_u128().unwrap()))
+        } else {
+            Ok(format!(
+                "{} {}",
+                num.to_u128().unwrap(),
+                ordinal_suffix(&self.int_to_cardinal(num, false)?)
+            ))
+        }
+    }
+
+    fn to_year(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if !num.frac().is_zero() {
+            return Err(Num2Err::FloatingYear);
+        }
+
+        // e.g. "хоёр мянга хорин дөрвөн он" (2024)
+        let year_word = self.int_to_cardinal(num.abs(), true)?;
+        if num.is_negative() {
+            Ok(format!("МЭӨ {} он", year_word))
+        } else {
+            Ok(format!("{} он", year_word))
+        }
+    }
+
+    fn to_currency(&self, num: BigFloat, currency: Currency) -> Result<String, Num2Err> {
+        // nouns stay in the singular after a numeral, e.g. дөчин хоёр төгрөг
+        if num.is_inf() || num.frac().is_zero() {
+            self.to_counted(num, &self.currencies(currency))
+        } else {
+            let integral_part = num.int();
+            let cents_nb = (num * BigFloat::from(100)).int() % BigFloat::from(100);
+            let cents_word = self.to_counted(cents_nb, &self.cents(currency))?;
+            let integral_word = self.to_currency(integral_part, currency)?;
+
+            if cents_nb.is_zero() {
+                Ok(integral_word)
+            } else if integral_part.is_zero() && !self.zero_major {
+                Ok(cents_word)
+            } else {
+                Ok(format!("{} {}", integral_word, cents_word))
+            }
+        }
+    }
+
+    fn to_percent(&self, num: BigFloat) -> Result<String, Num2Err> {
+        self.to_counted(num, "хувь")
+    }
+
+    fn to_unit(&self, num: BigFloat, unit: Unit) -> Result<String, Num2Err> {
+        self.to_counted(num, self.units(unit))
+    }
+
+    fn plural_category(&self, num: BigFloat) -> PluralCategory {
+        if num.abs() == BigFloat::from(1) {
+            PluralCategory::One
+        } else {
+            PluralCategory::Other
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_cardinal() {
+        assert_eq!(
+            Num2Words::new(0).lang(Lang::Mongolian).to_words(),
+            Ok(String::from("тэг"))
+        );
+        assert_eq!(
+            Num2Words::new(40).lang(Lang::Mongolian).to_words(),
+            Ok(String::from("дөч"))
+        );
+        assert_eq!(
+            Num2Words::new(42).lang(Lang::Mongolian).to_words(),
+            Ok(String::from("дөчин хоёр"))
+        );
+        assert_eq!(
+            Num2Words::new(-15).lang(Lang::Mongolian).to_words(),
+            Ok(String::from("хасах арван тав"))
+        );
+        assert_eq!(
+            Num2Words::new(300).lang(Lang::Mongolian).to_words(),
+            Ok(String::from("гурван зуу"))
+        );
+        assert_eq!(
+            Num2Words::new(342_000).lang(Lang::Mongolian).to_words(),
+            Ok(String::from("гурван зуун дөчин хоёр мянга"))
+        );
+        assert_eq!(
+            Num2Words::new(1500).lang(Lang::Mongolian).to_words(),
+            Ok(String::from("нэг мянга таван зуу"))
+        );
+        assert_eq!(
+            Num2Words::new(2_000_000).lang(Lang::Mongolian).to_words(),
+            Ok(String::from("хоёр сая"))
+        );
+        assert_eq!(
+            Num2Words::new(1.5).lang(Lang::Mongolian).to_words(),
+            Ok(String::from("нэг таслал тав"))
+        );
+    }
+
+    #[test]
+    fn test_ordinal() {
+        // back vowels
+        assert_eq!(
+            Num2Words::new(2).lang(Lang::Mongolian).ordinal().to_words(),
+            Ok(String::from("хоёрдугаар"))
+        );
+        assert_eq!(
+            Num2Words::new(42)
+                .lang(Lang::Mongolian)
+                .ordinal()
+                .to_words(),
+            Ok(String::from("дөчин хоёрдугаар"))
+        );
+        assert_eq!(
+            Num2Words::new(100)
+                .lang(Lang::Mongolian)
+                .ordinal()
+                .to_words(),
+            Ok(String::from("нэг зуудугаар"))
+        );
+        // front vowels
+        assert_eq!(
+            Num2Words::new(1).lang(Lang::Mongolian).ordinal().to_words(),
+            Ok(String::from("нэгдүгээр"))
+        );
+        assert_eq!(
+            Num2Words::new(4).lang(Lang::Mongolian).ordinal().to_words(),
+            Ok(String::from("дөрөвдүгээр"))
+        );
+        assert_eq!(
+            Num2Words::new(19)
+                .lang(Lang::Mongolian)
+                .ordinal()
+                .to_words(),
+            Ok(String::from("арван есдүгээр"))
+        );
+    }
+
+    #[test]
+    fn test_ordinal_num() {
+        assert_eq!(
+            Num2Words::new(42)
+                .lang(Lang::Mongolian)
+                .ordinal_num()
+                .to_words(),
+            Ok(String::from("42 дугаар"))
+        );
+        assert_eq!(
+            Num2Words::new(41)
+                .lang(Lang::Mongolian)
+                .ordinal_num()
+                .to_words(),
+            Ok(String::from("41 дүгээр"))
+        );
+        assert_eq!(
+            Num2Words::new(42)
+                .lang(Lang::Mongolian)
+                .ordinal_num()
+                .prefer("short")
+                .to_words(),
+            Ok(String::from("42-р"))
+        );
+    }
+
+    #[test]
+    fn test_year() {
+        assert_eq!(
+            Num2Words::new(2024).lang(Lang::Mongolian).year().to_words(),
+            Ok(String::from("хоёр мянга хорин дөрвөн он"))
+        );
+        assert_eq!(
+            Num2Words::new(-209).lang(Lang::Mongolian).year().to_words(),
+            Ok(String::from("МЭӨ хоёр зуун есөн он"))
+        );
+    }
+
+    #[test]
+    fn test_currency() {
+        assert_eq!(
+            Num2Words::new(42)
+                .lang(Lang::Mongolian)
+                .currency(Currency::MNT)
+                .to_words(),
+            Ok(String::from("дөчин хоёр төгрөг"))
+        );
+        assert_eq!(
+            Num2Words::new(10.5)
+                .lang(Lang::Mongolian)
+                .currency(Currency::MNT)
+                .to_words(),
+            Ok(String::from("арван төгрөг тавин мөнгө"))
+        );
+        assert_eq!(
+            Num2Words::new(0.03)
+                .lang(Lang::Mongolian)
+                .currency(Currency::MNT)
+                .to_words(),
+            Ok(String::from("гурван мөнгө"))
+        );
+    }
+
+    #[test]
+    fn test_percent_and_unit() {
+        assert_eq!(
+            Num2Words::new(5).lang(Lang::Mongolian).percent().to_words(),
+            Ok(String::from("таван хувь"))
+        );
+        assert_eq!(
+            Num2Words::new(8)
+                .lang(Lang::Mongolian)
+                .unit(Unit::Hour)
+                .to_words(),
+            Ok(String::from("найман цаг"))
+        );
+    }
+}
@@ -1,12 +1,89 @@
-mod lang;
+mod am;
+mod ar;
+mod bn;
+mod cs;
 mod en;
+mod es;
+mod eu;
+mod fi;
 mod fr;
+mod hu;
+mod hy;
+mod is;
+mod it;
+mod la;
+#[allow(clippy::module_inception)]
+mod lang;
+mod lo;
+mod lv;
+mod mn;
+mod mt;
+mod my;
+mod ne;
+mod nl;
+mod pl;
+mod pt;
+mod ro;
+mod si;
+mod sk;
+mod sl;
+mod slavic;
+mod sr;
+mod sw;
+mod ta;
+mod te;
+mod tl;
+mod tr;
 mod uk;
+mod uz;
+mod vi;
+mod zh;
+mod zu;
 
+pub use am::Amharic;
+pub use ar::Arabic;
+pub use bn::Bengali;
+pub use cs::Czech;
 pub use en::English;
+pub use es::Spanish;
+pub use eu::Basque;
+pub use fi::Finnish;
 pub use fr::French;
+pub use hu::Hungarian;
+pub use hy::Armenian;
+pub use is::Icelandic;
+pub use it::Italian;
+pub use la::Latin;
+pub use lo::Lao;
+pub use lv::Latvian;
+pub use mn::Mongolian;
+pub use mt::Maltese;
+pub use my::Burmese;
+pub use ne::Nepali;
+pub use nl::Dutch;
+pub use pl::Polish;
+pub use pt::Portuguese;
+pub use ro::Romanian;
+pub use si::Sinhala;
+pub use sk::Slovak;
+pub use sl::Slovenian;
+pub use slavic::{Declension, Gender, GrammaticalNumber};
+pub use sr::Serbian;
+pub use sw::Swahili;
+pub use ta::Tamil;
+pub use te::Telugu;
+pub use tl::Tagalog;
+pub use tr::Turkish;
 pub use uk::Ukrainian;
+pub use uz::Uzbek;
+pub use vi::Vietnamese;
+pub use zh::Chinese;
+pub use zu::Zulu;
 
+pub(crate) use lang::group_digits;
+pub use lang::preference_outputs;
 pub use lang::to_language;
 pub use lang::Lang;
 pub use lang::Language;
+pub use lang::NounOrder;
+pub use lang::PluralCategory;
@@ -0,0 +1,651 @@
+use crate::{num2words::Num2Err, Currency, Language, PluralCategory, Unit};
+use num_bigfloat::BigFloat;
+
+pub struct Maltese {
+    zero_major: bool,
+}
+
+// A counted noun, which decides the form of the numeral before it
+struct Noun {
+    singular: &'static str,
+    plural: &'static str,
+    dual: Option<&'static str>,
+    feminine: bool,
+    // "tlitt elef" but "tliet miljuni"
+    vowel_initial: bool,
+}
+
+impl Noun {
+    const fn new(singular: &'static str, plural: &'static str) -> Self {
+        Self {
+            singular,
+            plural,
+            dual: None,
+            feminine: false,
+            vowel_initial: false,
+        }
+    }
+
+    const fn dual(self, dual: &'static str) -> Self {
+        Self {
+            dual: Some(dual),
+            ..self
+        }
+    }
+
+    const fn feminine(self) -> Self {
+        Self {
+            feminine: true,
+            ..self
+        }
+    }
+
+    const fn vowel_initial(self) -> Self {
+        Self {
+            vowel_initial: true,
+            ..self
+        }
+    }
+}
+
+const UNITS: [&str; 10] = [
+    "wieħed", "tnejn", "tlieta", "erbgħa", "ħamsa", "sitta", "sebgħa", "tmienja", "disgħa",
+    "għaxra",
+];
+
+const TEENS: [&str; 9] = [
+    "ħdax", "tnax", "tlettax", "erbatax", "ħmistax", "sittax", "sbatax", "tmintax", "dsatax",
+];
+
+const TENS: [&str; 8] = [
+    "għoxrin", "tletin", "erbgħin", "ħamsin", "sittin", "sebgħin", "tmenin", "disgħin",
+];
+
+// construct forms of 2 to 10, used before a counted noun
+const CONSTRUCT: [&str; 9] = [
+    "żewġ", "tliet", "erba'", "ħames", "sitt", "seba'", "tmien", "disa'", "għaxar",
+];
+
+const CONSTRUCT_VOWEL: [&str; 9] = [
+    "żewġ", "tlitt", "erbat", "ħamest", "sitt", "sebat", "tmint", "disat", "għaxart",
+];
+
+const ORDINALS: [&str; 10] = [
+    "ewwel", "tieni", "tielet", "raba'", "ħames", "sitt", "seba'", "tmien", "disa'", "għaxar",
+];
+
+const HUNDRED: Noun = Noun::new("mija", "mija").dual("mitejn");
+
+const MEGAS: [Noun; 4] = [
+    Noun::new("elf", "elef").dual("elfejn").vowel_initial(),
+    Noun::new("miljun", "miljuni"),
+    Noun::new("biljun", "biljuni"),
+    Noun::new("triljun", "triljuni"),
+];
+
+const EURO: Noun = Noun::new("euro", "euro");
+const CENT: Noun = Noun::new("ċenteżmu", "ċenteżmi");
+const PERCENT: &str = "fil-mija";
+
+// l-ewwel, il-ħames, but the article assimilates to the "sun letters", e.g.
+// it-tieni, is-sitt
+fn with_article(word: &str) -> String {
+    match word.chars().next() {
+        Some('a' | 'e' | 'i' | 'o' | 'u') => format!("l-{}", word),
+        Some(_) if word.starts_with("għ") => format!("l-{}", word),
+        Some(c @ ('ċ' | 'd' | 'n' | 'r' | 's' | 't' | 'x' | 'ż' | 'z')) => {
+            format!("i{}-{}", c, word)
+        }
+        _ => format!("il-{}", word),
+    }
+}
+
+impl Maltese {
+    pub fn new() -> Self {
+        Self { zero_major: false }
+    }
+
+    /// Keeps the major unit clause for amounts below one, e.g. `żero euro u
+    /// għoxrin ċenteżmu`
+    pub fn zero_major(self, zero_major: bool) -> Self {
+        Self { zero_major }
+    }
+
+    fn currencies(&self, currency: Currency) -> Option<Noun> {
+        match currency {
+            Currency::EUR => Some(EURO),
+            Currency::GBP => Some(Noun::new("lira sterlina", "liri sterlini").feminine()),
+            Currency::DOLLAR | Currency::USD => Some(Noun::new("dollaru", "dollari")),
+            _ => None,
+        }
+    }
+
+    fn units(&self, unit: Unit) -> Noun {
+        match unit {
+            Unit::Meter => Noun::new("metru", "metri"),
+            Unit::Kilometer => Noun::new("kilometru", "kilometri"),
+            Unit::Gram => Noun::new("gramma", "grammi"),
+            Unit::Kilogram => Noun::new("kilogramma", "kilogrammi"),
+            Unit::Liter => Noun::new("litru", "litri"),
+            Unit::Second => Noun::new("sekonda", "sekondi").feminine(),
+            Unit::Minute => Noun::new("minuta", "minuti").feminine(),
+            Unit::Hour => Noun::new("siegħa", "sigħat").dual("sagħtejn").feminine(),
+        }
+    }
+
+    fn split_thousands(&self, mut num: BigFloat) -> Vec<u64> {
+        let mut thousands = Vec::new();
+        let bf_1000 = BigFloat::from(1000);
+
+        while !num.is_zero() {
+            thousands.push((num % bf_1000).to_u64().unwrap());
+            num /= bf_1000;
+        }
+
+        thousands
+    }
+
+    // 1 to 99, as said on its own
+    fn tens_to_cardinal(&self, num: u64, feminine: bool) -> String {
+        let (tens, units) = ((num / 10) as usize, (num % 10) as usize);
+        let unit = |units: usize| {
+            if units == 1 && feminine {
+                "waħda"
+            } else {
+                UNITS[units - 1]
+            }
+        };
+
+        match tens {
+            0 => String::from(unit(units)),
+            1 if units == 0 => String::from(UNITS[9]),
+            1 => String::from(TEENS[units - 1]),
+            _ if units == 0 => String::from(TENS[tens - 2]),
+            // units come first, e.g. tnejn u erbgħin (42)
+            _ => format!("{} u {}", unit(units), TENS[tens - 2]),
+        }
+    }
+
+    // 1 to 999, as said on its own
+    fn triplet_to_cardinal(&self, triplet: u64) -> String {
+        let hundreds = triplet / 100;
+        let tail = triplet % 100;
+        let mut words = vec![];
+
+        match hundreds {
+            0 => {}
+            1 => words.push(String::from(HUNDRED.singular)),
+            2 => words.push(String::from(HUNDRED.dual.unwrap())),
+            _ => words.push(format!(
+                "{} {}",
+                CONSTRUCT[hundreds as usize - 2],
+                HUNDRED.plural
+            )),
+        }
+        if tail > 0 {
+            words.push(self.tens_to_cardinal(tail, false));
+        }
+
+        words.join(" u ")
+    }
+
+    // 1 to 999 followed by the noun they count, e.g. żewġ euro, ħdax-il euro
+    fn count(&self, num: u64, noun: &Noun) -> String {
+        let hundreds = num / 100;
+        let tail = num % 100;
+        let construct = if noun.vowel_initial {
+            CONSTRUCT_VOWEL
+        } else {
+            CONSTRUCT
+        };
+
+        if num == 1 {
+            // the noun comes first, e.g. euro wieħed
+            return format!(
+                "{} {}",
+                noun.singular,
+                if noun.feminine { "waħda" } else { "wieħed" }
+            );
+        }
+        if num == 2 {
+            if let Some(dual) = noun.dual {
+                return String::from(dual);
+            }
+        }
+
+        let mut words = vec![];
+        if hundreds > 0 {
+            words.push(if tail == 0 {
+                // the hundred itself is in the construct state, e.g. mitt elf
+                match hundreds {
+                    1 => String::from("mitt"),
+                    2 => String::from(HUNDRED.dual.unwrap()),
+                    _ => format!("{} mitt", CONSTRUCT[hundreds as usize - 2]),
+                }
+            } else {
+                self.triplet_to_cardinal(hundreds * 100)
+            });
+        }
+
+        let noun_word = match self.plural_category(BigFloat::from(num)) {
+            PluralCategory::Two | PluralCategory::Few => noun.plural,
+            _ => noun.singular,
+        };
+        match tail {
+            0 => {}
+            2..=10 => words.push(String::from(construct[tail as usize - 2])),
+            // the "-il" linker before the counted noun, e.g. ħdax-il euro
+            11..=19 => words.push(format!("{}-il", TEENS[tail as usize - 11])),
+            _ => words.push(self.tens_to_cardinal(tail, noun.feminine)),
+        }
+
+        format!("{} {}", words.join(" u "), noun_word)
+    }
+
+    fn int_to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        // special case zero
+        if num.is_zero() {
+            return Ok(String::from("żero"));
+        }
+
+        let mut words = vec![];
+        for (i, triplet) in self.split_thousands(num.abs()).iter().enumerate().rev() {
+            if *triplet == 0 {
+                continue;
+            }
+            if i == 0 {
+                words.push(self.triplet_to_cardinal(*triplet));
+            } else if i > MEGAS.len() {
+                return Err(Num2Err::CannotConvert);
+            } else if *triplet == 1 {
+                // a lone thousand or million, e.g. elf, miljun
+                words.push(String::from(MEGAS[i - 1].singular));
+            } else {
+                words.push(self.count(*triplet, &MEGAS[i - 1]));
+            }
+        }
+
+        // "u" joins the last group, e.g. elf u mija (1100)
+        let last = words.pop().unwrap_or_default();
+        let words = if words.is_empty() {
+            last
+        } else {
+            format!("{} u {}", words.join(" "), last)
+        };
+
+        // handling negative values
+        if num.is_negative() {
+            Ok(format!("minus {}", words))
+        } else {
+            Ok(words)
+        }
+    }
+
+    fn float_to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let mut words = vec![];
+        if num.is_negative() {
+            words.push(String::from("minus"));
+        }
+        words.push(self.int_to_cardinal(num.int().abs())?);
+        words.push(String::from("punt"));
+
+        let mut ordinal_part = num.frac().abs();
+        while !ordinal_part.is_zero() {
+            let digit = (ordinal_part * BigFloat::from(10)).int();
+            ordinal_part = (ordinal_part * BigFloat::from(10)).frac();
+            words.push(self.int_to_cardinal(digit)?);
+        }
+
+        Ok(words.join(" "))
+    }
+
+    // the counted amount, where the last group of thousands agrees with the
+    // noun
+    fn int_to_counted(&self, num: BigFloat, noun: &Noun) -> Result<String, Num2Err> {
+        let bf_1000 = BigFloat::from(1000);
+        let triplet = (num.abs() % bf_1000).to_u64().unwrap();
+        let rest = num.abs() - BigFloat::from(triplet);
+
+        let words = if triplet == 0 {
+            // e.g. żero euro, elf euro
+            format!("{} {}", self.int_to_cardinal(num.abs())?, noun.singular)
+        } else if rest.is_zero() {
+            self.count(triplet, noun)
+        } else if triplet == 1 {
+            // e.g. elf u wieħed euro
+            format!(
+                "{} u {} {}",
+                self.int_to_cardinal(rest)?,
+                if noun.feminine { "waħda" } else { "wieħed" },
+                noun.singular
+            )
+        } else {
+            format!(
+                "{} u {}",
+                self.int_to_cardinal(rest)?,
+                self.count(triplet, noun)
+            )
+        };
+
+        if num.is_negative() {
+            Ok(format!("minus {}", words))
+        } else {
+            Ok(words)
+        }
+    }
+}
+
+impl Default for Maltese {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Language for Maltese {
+    fn to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num.is_inf_pos() {
+            Ok(String::from("infinit"))
+        } else if num.is_inf_neg() {
+            Ok(String::from("minus infinit"))
+        } else if num.frac().is_zero() {
+            self.int_to_cardinal(num)
+        } else {
+            self.float_to_cardinal(num)
+        }
+    }
+
+    fn to_ordinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num.is_zero() {
+            return Ok(with_article("żero"));
+        }
+
+        let tail = (num % BigFloat::from(100)).to_u64().unwrap();
+        let (tens, units) = ((tail / 10) as usize, (tail % 10) as usize);
+        if num < BigFloat::from(100) && units > 0 && tens != 1 {
+            // only the units take an ordinal form, e.g. it-tieni u erbgħin
+            let ordinal = with_article(ORDINALS[units - 1]);
+            if tens == 0 {
+                Ok(ordinal)
+            } else {
+                Ok(format!("{} u {}", ordinal, TENS[tens - 2]))
+            }
+        } else if num == BigFloat::from(10) {
+            Ok(with_article(ORDINALS[9]))
+        } else {
+            // from eleven onwards, the article comes before the cardinal
+            Ok(with_article(&self.int_to_cardinal(num)?))
+        }
+    }
+
+    fn to_ordinal_num(&self, num: BigFloat) -> Result<String, Num2Err> {
+        Ok(format!("{}.", num.to_u128().unwrap()))
+    }
+
+    fn to_year(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if !num.frac().is_zero() {
+            return Err(Num2Err::FloatingYear);
+        }
+
+        let year_word = self.int_to_cardinal(num.abs())?;
+        if num.is_negative() {
+            Ok(format!("{} QK", year_word))
+        } else {
+            Ok(year_word)
+        }
+    }
+
+    fn to_currency(&self, num: BigFloat, currency: Currency) -> Result<String, Num2Err> {
+        let noun = self.currencies(currency);
+        if num.is_inf() {
+            Ok(format!(
+                "{}infinità ta' {}",
+                if num.is_negative() { "minus " } else { "" },
+                match &noun {
+                    Some(noun) => String::from(noun.plural),
+                    None => currency.default_string(true),
+                }
+            ))
+        } else if num.frac().is_zero() {
+            match &noun {
+                Some(noun) => self.int_to_counted(num, noun),
+                None => Ok(format!(
+                    "{} {}",
+                    self.int_to_cardinal(num)?,
+                    currency.default_string(self.plural_category(num) != PluralCategory::One)
+                )),
+            }
+        } else {
+            let integral_part = num.int();
+            let cents_nb = (num * BigFloat::from(100)).int() % BigFloat::from(100);
+            let cents_words = self.int_to_counted(cents_nb, &CENT)?;
+            let integral_word = self.to_currency(integral_part, currency)?;
+
+            if cents_nb.is_zero() {
+                Ok(integral_word)
+            } else if integral_part.is_zero() && !self.zero_major {
+                Ok(cents_words)
+            } else {
+                Ok(format!("{} u {}", integral_word, cents_words))
+            }
+        }
+    }
+
+    fn to_percent(&self, num: BigFloat) -> Result<String, Num2Err> {
+        // "fil-mija" is a prepositional phrase, the numeral does not agree
+        Ok(format!("{} {}", self.to_cardinal(num)?, PERCENT))
+    }
+
+    fn to_unit(&self, num: BigFloat, unit: Unit) -> Result<String, Num2Err> {
+        let noun = self.units(unit);
+        if num.is_inf() {
+            Ok(format!(
+                "{}infinità ta' {}",
+                if num.is_negative() { "minus " } else { "" },
+                noun.plural
+            ))
+        } else if num.frac().is_zero() {
+            self.int_to_counted(num, &noun)
+        } else {
+            Ok(format!("{} {}", self.to_cardinal(num)?, noun.singular))
+        }
+    }
+
+    fn plural_category(&self, num: BigFloat) -> PluralCategory {
+        if !num.frac().is_zero() || num.is_inf() {
+            return PluralCategory::Other;
+        }
+        let num = num.abs();
+        let tail = (num % BigFloat::from(100)).to_u64().unwrap_or_default();
+        if num == BigFloat::from(1) {
+            PluralCategory::One
+        } else if num == BigFloat::from(2) {
+            PluralCategory::Two
+        } else if num.is_zero() || (3..=10).contains(&tail) {
+            PluralCategory::Few
+        } else if (11..=19).contains(&tail) {
+            PluralCategory::Many
+        } else {
+            PluralCategory::Other
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_cardinal() {
+        assert_eq!(
+            Num2Words::new(0).lang(Lang::Maltese).to_words(),
+            Ok(String::from("żero"))
+        );
+        assert_eq!(
+            Num2Words::new(42).lang(Lang::Maltese).to_words(),
+            Ok(String::from("tnejn u erbgħin"))
+        );
+        assert_eq!(
+            Num2Words::new(-11).lang(Lang::Maltese).to_words(),
+            Ok(String::from("minus ħdax"))
+        );
+        assert_eq!(
+            Num2Words::new(142).lang(Lang::Maltese).to_words(),
+            Ok(String::from("mija u tnejn u erbgħin"))
+        );
+        assert_eq!(
+            Num2Words::new(200).lang(Lang::Maltese).to_words(),
+            Ok(String::from("mitejn"))
+        );
+        assert_eq!(
+            Num2Words::new(1100).lang(Lang::Maltese).to_words(),
+            Ok(String::from("elf u mija"))
+        );
+        assert_eq!(
+            Num2Words::new(2000).lang(Lang::Maltese).to_words(),
+            Ok(String::from("elfejn"))
+        );
+        assert_eq!(
+            Num2Words::new(3000).lang(Lang::Maltese).to_words(),
+            Ok(String::from("tlitt elef"))
+        );
+        assert_eq!(
+            Num2Words::new(11_000).lang(Lang::Maltese).to_words(),
+            Ok(String::from("ħdax-il elf"))
+        );
+        assert_eq!(
+            Num2Words::new(100_000).lang(Lang::Maltese).to_words(),
+            Ok(String::from("mitt elf"))
+        );
+        assert_eq!(
+            Num2Words::new(1_000_000).lang(Lang::Maltese).to_words(),
+            Ok(String::from("miljun"))
+        );
+        assert_eq!(
+            Num2Words::new(5_000_000).lang(Lang::Maltese).to_words(),
+            Ok(String::from("ħames miljuni"))
+        );
+        assert_eq!(
+            Num2Words::new(2.5).lang(Lang::Maltese).to_words(),
+            Ok(String::from("tnejn punt ħamsa"))
+        );
+    }
+
+    #[test]
+    fn test_ordinal() {
+        assert_eq!(
+            Num2Words::new(1).lang(Lang::Maltese).ordinal().to_words(),
+            Ok(String::from("l-ewwel"))
+        );
+        assert_eq!(
+            Num2Words::new(6).lang(Lang::Maltese).ordinal().to_words(),
+            Ok(String::from("is-sitt"))
+        );
+        assert_eq!(
+            Num2Words::new(10).lang(Lang::Maltese).ordinal().to_words(),
+            Ok(String::from("l-għaxar"))
+        );
+        assert_eq!(
+            Num2Words::new(11).lang(Lang::Maltese).ordinal().to_words(),
+            Ok(String::from("il-ħdax"))
+        );
+        assert_eq!(
+            Num2Words::new(30).lang(Lang::Maltese).ordinal().to_words(),
+            Ok(String::from("it-tletin"))
+        );
+        assert_eq!(
+            Num2Words::new(42).lang(Lang::Maltese).ordinal().to_words(),
+            Ok(String::from("it-tieni u erbgħin"))
+        );
+        assert_eq!(
+            Num2Words::new(42)
+                .lang(Lang::Maltese)
+                .ordinal_num()
+                .to_words(),
+            Ok(String::from("42."))
+        );
+    }
+
+    #[test]
+    fn test_year() {
+        assert_eq!(
+            Num2Words::new(2024).lang(Lang::Maltese).year().to_words(),
+            Ok(String::from("elfejn u erbgħa u għoxrin"))
+        );
+        assert_eq!(
+            Num2Words::new(-218).lang(Lang::Maltese).year().to_words(),
+            Ok(String::from("mitejn u tmintax QK"))
+        );
+    }
+
+    #[test]
+    fn test_currency() {
+        let expected = [
+            (1.0, "euro wieħed"),
+            (2.0, "żewġ euro"),
+            (10.0, "għaxar euro"),
+            (11.0, "ħdax-il euro"),
+            (19.0, "dsatax-il euro"),
+            (20.0, "għoxrin euro"),
+            (21.0, "wieħed u għoxrin euro"),
+            (100.0, "mitt euro"),
+            (101.0, "mija u wieħed euro"),
+            (103.0, "mija u tliet euro"),
+            (1000.0, "elf euro"),
+            (1001.0, "elf u wieħed euro"),
+            (0.02, "żewġ ċenteżmi"),
+            (2.15, "żewġ euro u ħmistax-il ċenteżmu"),
+        ];
+        for (num, words) in expected.iter() {
+            assert_eq!(
+                Num2Words::new(*num)
+                    .lang(Lang::Maltese)
+                    .currency(Currency::EUR)
+                    .to_words(),
+                Ok(String::from(*words))
+            );
+        }
+        assert_eq!(
+            Num2Words::new(3)
+                .lang(Lang::Maltese)
+                .currency(Currency::USD)
+                .to_words(),
+            Ok(String::from("tliet dollari"))
+        );
+        assert_eq!(
+            Num2Words::new(12)
+                .lang(Lang::Maltese)
+                .currency(Currency::USD)
+                .to_words(),
+            Ok(String::from("tnax-il dollaru"))
+        );
+    }
+
+    #[test]
+    fn test_percent_and_unit() {
+        assert_eq!(
+            Num2Words::new(5).lang(Lang::Maltese).percent().to_words(),
+            Ok(String::from("ħamsa fil-mija"))
+        );
+        assert_eq!(
+            Num2Words::new(2)
+                .lang(Lang::Maltese)
+                .unit(Unit::Hour)
+                .to_words(),
+            Ok(String::from("sagħtejn"))
+        );
+        assert_eq!(
+            Num2Words::new(21)
+                .lang(Lang::Maltese)
+                .unit(Unit::Hour)
+                .to_words(),
+            Ok(String::from("waħda u għoxrin siegħa"))
+        );
+        assert_eq!(
+            Num2Words::new(4)
+                .lang(Lang::Maltese)
+                .unit(Unit::Kilometer)
+                .to_words(),
+            Ok(String::from("erba' kilometri"))
+        );
+    }
+}
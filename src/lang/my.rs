@@ -0,0 +1,349 @@
+use crate::lang::NounOrder;
+use crate::{num2words::Num2Err, Currency, Language, PluralCategory, Unit};
+use num_bigfloat::BigFloat;
+
+pub struct Burmese {
+    zero_major: bool,
+}
+
+const UNITS: [&str; 10] = [
+    "သုည",
+    "တစ်",
+    "နှစ်",
+    "သုံး",
+    "လေး",
+    "ငါး",
+    "ခြောက်",
+    "ခုနစ်",
+    "ရှစ်",
+    "ကိုး",
+];
+
+// every power of ten up to a million has its own word, from ten (ဆယ်) to
+// a million (သန်း)
+const PLACES: [&str; 7] = ["", "ဆယ်", "ရာ", "ထောင်", "သောင်း", "သိန်း", "သန်း"];
+
+// tens, hundreds and thousands take the creaky tone when followed by
+// something else, e.g. လေးဆယ့်နှစ်
+const PLACES_LINKING: [&str; 7] = ["", "ဆယ့်", "ရာ့", "ထောင့်", "သောင်း", "သိန်း", "သန်း"];
+
+const ORDINALS: [&str; 10] = [
+    "ပထမ",
+    "ဒုတိယ",
+    "တတိယ",
+    "စတုတ္ထ",
+    "ပဉ္စမ",
+    "ဆဋ္ဌမ",
+    "သတ္တမ",
+    "အဋ္ဌမ",
+    "နဝမ",
+    "ဒသမ",
+];
+
+const DIGITS: [char; 10] = ['၀', '၁', '၂', '၃', '၄', '၅', '၆', '၇', '၈', '၉'];
+
+impl Burmese {
+    pub fn new() -> Self {
+        Self { zero_major: false }
+    }
+
+    /// Keeps the major unit clause for amounts below one, e.g. `ကျပ်
+    /// သုညကျပ် ပြား နှစ်ဆယ်ပြား`
+    pub fn zero_major(self, zero_major: bool) -> Self {
+        Self { zero_major }
+    }
+
+    fn currencies(&self, currency: Currency) -> String {
+        match currency {
+            Currency::DOLLAR | Currency::USD => String::from("ဒေါ်လာ"),
+            Currency::EUR => String::from("ယူရို"),
+            Currency::GBP => String::from("ပေါင်"),
+            Currency::INR => String::from("ရူပီး"),
+            Currency::MMK => String::from("ကျပ်"),
+            _ => currency.default_string(false),
+        }
+    }
+
+    fn cents(&self, currency: Currency) -> String {
+        match currency {
+            Currency::GBP => String::from("ပဲနီ"),
+            Currency::MMK => String::from("ပြား"),
+            _ => String::from("ဆင့်"),
+        }
+    }
+
+    fn units(&self, unit: Unit) -> &'static str {
+        match unit {
+            Unit::Meter => "မီတာ",
+            Unit::Kilometer => "ကီလိုမီတာ",
+            Unit::Gram => "ဂရမ်",
+            Unit::Kilogram => "ကီလိုဂရမ်",
+            Unit::Liter => "လီတာ",
+            Unit::Second => "စက္ကန့်",
+            Unit::Minute => "မိနစ်",
+            Unit::Hour => "နာရီ",
+        }
+    }
+
+    fn int_to_cardinal(&self, mut num: BigFloat) -> Result<String, Num2Err> {
+        // special case zero
+        if num.is_zero() {
+            return Ok(String::from(UNITS[0]));
+        }
+
+        // handling negative values
+        let mut words = vec![];
+        if num.is_negative() {
+            words.push(String::from("အနှုတ်"));
+            num = -num;
+        }
+
+        // ten millions (ကုဋေ) are counted with the whole number system
+        let bf_crore = BigFloat::from(10_000_000);
+        let crores = (num / bf_crore).int();
+        if !crores.is_zero() {
+            words.push(format!("{}ကုဋေ", self.int_to_cardinal(crores)?));
+        }
+
+        let mut rest = (num % bf_crore).to_u64().unwrap();
+        for place in (0..PLACES.len()).rev() {
+            let power = 10u64.pow(place as u32);
+            let digit = (rest / power) as usize;
+            rest %= power;
+            if digit == 0 {
+                continue;
+            }
+
+            let place_word = if rest != 0 {
+                PLACES_LINKING[place]
+            } else {
+                PLACES[place]
+            };
+            words.push(format!("{}{}", UNITS[digit], place_word));
+        }
+
+        Ok(words.join(""))
+    }
+
+    fn float_to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let integral_part = num.int();
+        let mut words: Vec<String> = vec![];
+
+        if !integral_part.is_zero() {
+            let integral_word = self.int_to_cardinal(integral_part)?;
+            words.push(integral_word);
+        } else if num.is_negative() {
+            words.push(String::from("အနှုတ်"));
+        }
+        if integral_part.is_zero() {
+            words.push(String::from(UNITS[0]));
+        }
+
+        let mut ordinal_part = num.frac().abs();
+        if !ordinal_part.is_zero() {
+            words.push(String::from("ဒသမ"));
+        }
+        while !ordinal_part.is_zero() {
+            let digit = (ordinal_part * BigFloat::from(10)).int();
+            ordinal_part = (ordinal_part * BigFloat::from(10)).frac();
+            words.push(String::from(UNITS[digit.to_u64().unwrap() as usize]));
+        }
+        Ok(words.join(""))
+    }
+}
+
+impl Default for Burmese {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Language for Burmese {
+    fn to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num.is_inf_pos() {
+            Ok(String::from("အနန္တ"))
+        } else if num.is_inf_neg() {
+            Ok(String::from("အနှုတ်အနန္တ"))
+        } else if num.frac().is_zero() {
+            self.int_to_cardinal(num)
+        } else {
+            self.float_to_cardinal(num)
+        }
+    }
+
+    fn to_ordinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        // the first ten ordinals are borrowed from Pali, e.g. ဒုတိယ
+        if num >= BigFloat::from(1) && num <= BigFloat::from(10) {
+            return Ok(String::from(ORDINALS[num.to_u64().unwrap() as usize - 1]));
+        }
+
+        Ok(format!("{}မြောက်", self.to_cardinal(num)?))
+    }
+
+    fn to_ordinal_num(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let digits: String = num
+            .to_u128()
+            .unwrap()
+            .to_string()
+            .chars()
+            .map(|c| DIGITS[c.to_digit(10).unwrap() as usize])
+            .collect();
+        Ok(format!("{}မြောက်", digits))
+    }
+
+    fn to_year(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if !num.frac().is_zero() {
+            return Err(Num2Err::FloatingYear);
+        }
+
+        let words = self.int_to_cardinal(num.abs())?;
+        if num.is_negative() {
+            Ok(format!("ဘီစီ {}", words))
+        } else {
+            Ok(words)
+        }
+    }
+
+    fn to_currency(&self, num: BigFloat, currency: Currency) -> Result<String, Num2Err> {
+        // the currency comes first and is repeated as its own classifier,
+        // e.g. ကျပ် လေးဆယ့်နှစ်ကျပ်
+        let order = self.noun_order();
+        if num.is_inf() || num.frac().is_zero() {
+            Ok(order.place(&self.to_cardinal(num)?, &self.currencies(currency)))
+        } else {
+            let integral_part = num.int();
+            let cents_nb = (num * BigFloat::from(100)).int() % BigFloat::from(100);
+            let cents_words = order.place(&self.int_to_cardinal(cents_nb)?, &self.cents(currency));
+            let integral_word = self.to_currency(integral_part, currency)?;
+
+            if cents_nb.is_zero() {
+                Ok(integral_word)
+            } else if integral_part.is_zero() && !self.zero_major {
+                Ok(cents_words)
+            } else {
+                Ok(format!("{} {}", integral_word, cents_words))
+            }
+        }
+    }
+
+    fn to_percent(&self, num: BigFloat) -> Result<String, Num2Err> {
+        Ok(format!("{} ရာခိုင်နှုန်း", self.to_cardinal(num)?))
+    }
+
+    fn to_unit(&self, num: BigFloat, unit: Unit) -> Result<String, Num2Err> {
+        Ok(self
+            .noun_order()
+            .place(&self.to_cardinal(num)?, self.units(unit)))
+    }
+
+    fn plural_category(&self, _num: BigFloat) -> PluralCategory {
+        // CLDR: Burmese nouns do not inflect for number
+        PluralCategory::Other
+    }
+
+    fn noun_order(&self) -> NounOrder {
+        NounOrder::Classifier
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_cardinal() {
+        assert_eq!(
+            Num2Words::new(0).lang(Lang::Burmese).to_words(),
+            Ok(String::from("သုည"))
+        );
+        assert_eq!(
+            Num2Words::new(40).lang(Lang::Burmese).to_words(),
+            Ok(String::from("လေးဆယ်"))
+        );
+        assert_eq!(
+            Num2Words::new(42).lang(Lang::Burmese).to_words(),
+            Ok(String::from("လေးဆယ့်နှစ်"))
+        );
+        assert_eq!(
+            Num2Words::new(1042).lang(Lang::Burmese).to_words(),
+            Ok(String::from("တစ်ထောင့်လေးဆယ့်နှစ်"))
+        );
+        assert_eq!(
+            Num2Words::new(-3.5).lang(Lang::Burmese).to_words(),
+            Ok(String::from("အနှုတ်သုံးဒသမငါး"))
+        );
+        assert_eq!(
+            Num2Words::new(0.5).lang(Lang::Burmese).to_words(),
+            Ok(String::from("သုညဒသမငါး"))
+        );
+        assert_eq!(
+            Num2Words::new(-0.25).lang(Lang::Burmese).to_words(),
+            Ok(String::from("အနှုတ်သုညဒသမနှစ်ငါး"))
+        );
+    }
+
+    #[test]
+    fn test_grouping() {
+        assert_eq!(
+            Num2Words::new(10_000).lang(Lang::Burmese).to_words(),
+            Ok(String::from("တစ်သောင်း"))
+        );
+        assert_eq!(
+            Num2Words::new(123_456).lang(Lang::Burmese).to_words(),
+            Ok(String::from("တစ်သိန်းနှစ်သောင်းသုံးထောင့်လေးရာ့ငါးဆယ့်ခြောက်"))
+        );
+        assert_eq!(
+            Num2Words::new(2_000_000).lang(Lang::Burmese).to_words(),
+            Ok(String::from("နှစ်သန်း"))
+        );
+        assert_eq!(
+            Num2Words::new(420_000_000).lang(Lang::Burmese).to_words(),
+            Ok(String::from("လေးဆယ့်နှစ်ကုဋေ"))
+        );
+    }
+
+    #[test]
+    fn test_ordinal() {
+        assert_eq!(
+            Num2Words::new(2).lang(Lang::Burmese).ordinal().to_words(),
+            Ok(String::from("ဒုတိယ"))
+        );
+        assert_eq!(
+            Num2Words::new(42).lang(Lang::Burmese).ordinal().to_words(),
+            Ok(String::from("လေးဆယ့်နှစ်မြောက်"))
+        );
+        assert_eq!(
+            Num2Words::new(42)
+                .lang(Lang::Burmese)
+                .ordinal_num()
+                .to_words(),
+            Ok(String::from("၄၂မြောက်"))
+        );
+    }
+
+    #[test]
+    fn test_currency() {
+        assert_eq!(
+            Num2Words::new(42)
+                .lang(Lang::Burmese)
+                .currency(Currency::MMK)
+                .to_words(),
+            Ok(String::from("ကျပ် လေးဆယ့်နှစ်ကျပ်"))
+        );
+        assert_eq!(
+            Num2Words::new(42.5)
+                .lang(Lang::Burmese)
+                .currency(Currency::MMK)
+                .to_words(),
+            Ok(String::from("ကျပ် လေးဆယ့်နှစ်ကျပ် ပြား ငါးဆယ်ပြား"))
+        );
+        assert_eq!(
+            Num2Words::new(0.2)
+                .lang(Lang::Burmese)
+                .currency(Currency::MMK)
+                .prefer("zero-major")
+                .to_words(),
+            Ok(String::from("ကျပ် သုညကျပ် ပြား နှစ်ဆယ်ပြား"))
+        );
+    }
+}
@@ -0,0 +1,555 @@
+use crate::{num2words::Num2Err, Currency, Language, PluralCategory, Unit};
+use num_bigfloat::BigFloat;
+
+pub struct Nepali {
+    zero_major: bool,
+}
+
+// Every number below a hundred is its own word, with compounds that cannot be
+// derived from their tens and units, e.g. उनन्पचास (49) or बयालीस (42)
+const NUMBERS: [&str; 100] = [
+    "शून्य",
+    "एक",
+    "दुई",
+    "तीन",
+    "चार",
+    "पाँच",
+    "छ",
+    "सात",
+    "आठ",
+    "नौ",
+    "दस",
+    "एघार",
+    "बाह्र",
+    "तेह्र",
+    "चौध",
+    "पन्ध्र",
+    "सोह्र",
+    "सत्र",
+    "अठार",
+    "उन्नाइस",
+    "बीस",
+    "एक्काइस",
+    "बाइस",
+    "तेइस",
+    "चौबीस",
+    "पच्चीस",
+    "छब्बीस",
+    "सत्ताइस",
+    "अट्ठाइस",
+    "उनन्तीस",
+    "तीस",
+    "एकतीस",
+    "बत्तीस",
+    "तेत्तीस",
+    "चौंतीस",
+    "पैंतीस",
+    "छत्तीस",
+    "सैंतीस",
+    "अठतीस",
+    "उनन्चालीस",
+    "चालीस",
+    "एकचालीस",
+    "बयालीस",
+    "त्रिचालीस",
+    "चवालीस",
+    "पैंतालीस",
+    "छयालीस",
+    "सतचालीस",
+    "अठचालीस",
+    "उनन्पचास",
+    "पचास",
+    "एकाउन्न",
+    "बाउन्न",
+    "त्रिपन्न",
+    "चउन्न",
+    "पचपन्न",
+    "छपन्न",
+    "सन्ताउन्न",
+    "अन्ठाउन्न",
+    "उनन्साठी",
+    "साठी",
+    "एकसट्ठी",
+    "बयसट्ठी",
+    "त्रिसट्ठी",
+    "चौंसट्ठी",
+    "पैंसट्ठी",
+    "छयसट्ठी",
+    "सतसट्ठी",
+    "अठसट्ठी",
+    "उनन्सत्तरी",
+    "सत्तरी",
+    "एकहत्तर",
+    "बहत्तर",
+    "त्रिहत्तर",
+    "चौहत्तर",
+    "पचहत्तर",
+    "छयहत्तर",
+    "सतहत्तर",
+    "अठहत्तर",
+    "उनासी",
+    "असी",
+    "एकासी",
+    "बयासी",
+    "त्रियासी",
+    "चौरासी",
+    "पचासी",
+    "छयासी",
+    "सतासी",
+    "अठासी",
+    "उनान्नब्बे",
+    "नब्बे",
+    "एकानब्बे",
+    "बयानब्बे",
+    "त्रियानब्बे",
+    "चौरानब्बे",
+    "पन्चानब्बे",
+    "छयानब्बे",
+    "सन्तानब्बे",
+    "अन्ठानब्बे",
+    "उनान्सय",
+];
+
+// Indian numbering system: a thousand, then every hundred-fold, e.g. लाख for
+// 10^5 and करोड for 10^7
+const MEGAS: [&str; 8] = ["हजार", "लाख", "करोड", "अरब", "खरब", "नील", "पद्म", "शङ्ख"];
+
+const DIGITS: [char; 10] = ['०', '१', '२', '३', '४', '५', '६', '७', '८', '९'];
+
+// Devanagari vowel signs, after which the ordinal suffix takes its full form
+const VOWEL_SIGNS: [char; 10] = ['ा', 'ि', 'ी', 'ु', 'ू', 'े', 'ै', 'ो', 'ौ', 'ं'];
+
+/// Splits a number as in the Indian numbering system: the last three digits,
+/// then groups of two, e.g. 12,34,56,789
+pub(crate) fn split_indian_groups(mut num: BigFloat) -> Vec<u64> {
+    let mut groups = vec![(num % BigFloat::from(1000)).to_u64().unwrap()];
+    num = (num / BigFloat::from(1000)).int();
+    let bf_100 = BigFloat::from(100);
+
+    while !num.is_zero() {
+        groups.push((num % bf_100).to_u64().unwrap());
+        num = (num / bf_100).int();
+    }
+
+    groups
+}
+
+impl Nepali {
+    pub fn new() -> Self {
+        Self { zero_major: false }
+    }
+
+    /// Keeps the major unit clause for amounts below one, e.g. `शून्य
+    /// रुपैयाँ बीस पैसा`
+    pub fn zero_major(self, zero_major: bool) -> Self {
+        Self { zero_major }
+    }
+
+    fn currencies(&self, currency: Currency) -> String {
+        match currency {
+            Currency::AUD => String::from("अस्ट्रेलियाली डलर"),
+            Currency::CAD => String::from("क्यानेडियन डलर"),
+            Currency::CNY => String::from("युआन"),
+            Currency::DOLLAR => String::from("डलर"),
+            Currency::EUR => String::from("युरो"),
+            Currency::GBP => String::from("पाउन्ड"),
+            Currency::INR => String::from("भारतीय रुपैयाँ"),
+            Currency::JPY => String::from("येन"),
+            Currency::NPR => String::from("रुपैयाँ"),
+            Currency::RUB => String::from("रुबल"),
+            Currency::USD => String::from("अमेरिकी डलर"),
+            _ => currency.default_string(false),
+        }
+    }
+
+    fn cents(&self, currency: Currency) -> String {
+        match currency {
+            Currency::GBP => String::from("पेनी"),
+            Currency::INR | Currency::NPR => String::from("पैसा"),
+            _ => String::from("सेन्ट"),
+        }
+    }
+
+    fn units(&self, unit: Unit) -> &'static str {
+        match unit {
+            Unit::Meter => "मिटर",
+            Unit::Kilometer => "किलोमिटर",
+            Unit::Gram => "ग्राम",
+            Unit::Kilogram => "किलोग्राम",
+            Unit::Liter => "लिटर",
+            Unit::Second => "सेकेन्ड",
+            Unit::Minute => "मिनेट",
+            Unit::Hour => "घण्टा",
+        }
+    }
+
+    fn int_to_cardinal(&self, mut num: BigFloat) -> Result<String, Num2Err> {
+        // special case zero
+        if num.is_zero() {
+            return Ok(String::from(NUMBERS[0]));
+        }
+
+        // handling negative values
+        let mut words = vec![];
+        if num.is_negative() {
+            words.push(String::from("ऋण"));
+            num = -num;
+        }
+
+        for (i, group) in split_indian_groups(num).iter().enumerate().rev() {
+            if group == &0 {
+                continue;
+            }
+
+            let hundreds = (group / 100) as usize;
+            let below_hundred = (group % 100) as usize;
+
+            if hundreds > 0 {
+                words.push(String::from(NUMBERS[hundreds]));
+                words.push(String::from("सय"));
+            }
+            if below_hundred > 0 {
+                words.push(String::from(NUMBERS[below_hundred]));
+            }
+
+            if i != 0 {
+                if i > MEGAS.len() {
+                    return Err(Num2Err::CannotConvert);
+                }
+                words.push(String::from(MEGAS[i - 1]));
+            }
+        }
+
+        Ok(words.join(" "))
+    }
+
+    fn float_to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let integral_part = num.int();
+        let mut words: Vec<String> = vec![];
+
+        if !integral_part.is_zero() {
+            let integral_word = self.int_to_cardinal(integral_part)?;
+            words.push(integral_word);
+        } else if num.is_negative() {
+            words.push(String::from("ऋण"));
+        }
+        if integral_part.is_zero() {
+            words.push(String::from(NUMBERS[0]));
+        }
+
+        let mut ordinal_part = num.frac().abs();
+        if !ordinal_part.is_zero() {
+            words.push(String::from("दशमलव"));
+        }
+        while !ordinal_part.is_zero() {
+            let digit = (ordinal_part * BigFloat::from(10)).int();
+            ordinal_part = (ordinal_part * BigFloat::from(10)).frac();
+            words.push(String::from(NUMBERS[digit.to_u64().unwrap() as usize]));
+        }
+        Ok(words.join(" "))
+    }
+
+    fn to_devanagari_digits(&self, num: BigFloat) -> String {
+        num.to_u128()
+            .unwrap()
+            .to_string()
+            .chars()
+            .map(|c| DIGITS[c.to_digit(10).unwrap() as usize])
+            .collect()
+    }
+}
+
+impl Default for Nepali {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Language for Nepali {
+    fn to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num.is_inf_pos() {
+            Ok(String::from("अनन्त"))
+        } else if num.is_inf_neg() {
+            Ok(String::from("ऋण अनन्त"))
+        } else if num.frac().is_zero() {
+            self.int_to_cardinal(num)
+        } else {
+            self.float_to_cardinal(num)
+        }
+    }
+
+    fn to_ordinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        match num.to_u64() {
+            Some(1) => return Ok(String::from("पहिलो")),
+            Some(2) => return Ok(String::from("दोस्रो")),
+            Some(3) => return Ok(String::from("तेस्रो")),
+            Some(4) => return Ok(String::from("चौथो")),
+            Some(6) => return Ok(String::from("छैटौं")),
+            Some(9) => return Ok(String::from("नवौं")),
+            _ => {}
+        }
+
+        // the suffix औं is written as a vowel sign after a consonant, e.g.
+        // बयालीसौं, but in full after a vowel, e.g. नब्बेऔं
+        let cardinal_word = self.to_cardinal(num)?;
+        if cardinal_word.ends_with(&VOWEL_SIGNS[..]) {
+            Ok(format!("{}औं", cardinal_word))
+        } else {
+            Ok(format!("{}ौं", cardinal_word))
+        }
+    }
+
+    fn to_ordinal_num(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let suffix = match num.to_u64() {
+            Some(1) => "लो",
+            Some(2) | Some(3) => "रो",
+            Some(4) => "थो",
+            _ => "औं",
+        };
+
+        Ok(format!("{}{}", self.to_devanagari_digits(num), suffix))
+    }
+
+    fn to_year(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if !num.frac().is_zero() {
+            return Err(Num2Err::FloatingYear);
+        }
+
+        let year = num.abs();
+        let century = (year / BigFloat::from(100)).int();
+        let mut words = vec![];
+
+        // years are read in hundreds when they do not fall on a round
+        // thousand, e.g. उन्नाइस सय एकानब्बे (1991) but दुई हजार चौबीस (2024)
+        if year > BigFloat::from(1000)
+            && year < BigFloat::from(10000)
+            && !(century % BigFloat::from(10)).is_zero()
+        {
+            let below_hundred = (year % BigFloat::from(100)).to_u64().unwrap();
+            words.push(String::from(NUMBERS[century.to_u64().unwrap() as usize]));
+            words.push(String::from("सय"));
+            if below_hundred != 0 {
+                words.push(String::from(NUMBERS[below_hundred as usize]));
+            }
+        } else {
+            words.push(self.int_to_cardinal(year)?);
+        }
+
+        if num.is_negative() {
+            words.push(String::from("ईसा पूर्व"));
+        }
+
+        Ok(words.join(" "))
+    }
+
+    fn to_currency(&self, num: BigFloat, currency: Currency) -> Result<String, Num2Err> {
+        // nouns stay in the singular after a numeral, e.g. बयालीस रुपैयाँ
+        if num.is_inf() {
+            Ok(format!(
+                "{}अनन्त {}",
+                if num.is_negative() { "ऋण " } else { "" },
+                self.currencies(currency)
+            ))
+        } else if num.frac().is_zero() {
+            let words = self.int_to_cardinal(num)?;
+            Ok(format!("{} {}", words, self.currencies(currency)))
+        } else {
+            let integral_part = num.int();
+            let cents_nb = (num * BigFloat::from(100)).int() % BigFloat::from(100);
+            let cents_words = self.int_to_cardinal(cents_nb)?;
+            let cents_suffix = self.cents(currency);
+            let integral_word = self.to_currency(integral_part, currency)?;
+
+            if cents_nb.is_zero() {
+                Ok(integral_word)
+            } else if integral_part.is_zero() && !self.zero_major {
+                Ok(format!("{} {}", cents_words, cents_suffix))
+            } else {
+                Ok(format!(
+                    "{} {} {}",
+                    integral_word, cents_words, cents_suffix
+                ))
+            }
+        }
+    }
+
+    fn to_percent(&self, num: BigFloat) -> Result<String, Num2Err> {
+        Ok(format!("{} प्रतिशत", self.to_cardinal(num)?))
+    }
+
+    fn to_unit(&self, num: BigFloat, unit: Unit) -> Result<String, Num2Err> {
+        if num.is_inf() {
+            Ok(format!(
+                "{}अनन्त {}",
+                if num.is_negative() { "ऋण " } else { "" },
+                self.units(unit)
+            ))
+        } else {
+            Ok(format!("{} {}", self.to_cardinal(num)?, self.units(unit)))
+        }
+    }
+
+    fn plural_category(&self, num: BigFloat) -> PluralCategory {
+        // CLDR: one is n = 1
+        if num.abs() == BigFloat::from(1) {
+            PluralCategory::One
+        } else {
+            PluralCategory::Other
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_cardinal() {
+        assert_eq!(
+            Num2Words::new(0).lang(Lang::Nepali).to_words(),
+            Ok(String::from("शून्य"))
+        );
+        assert_eq!(
+            Num2Words::new(42).lang(Lang::Nepali).to_words(),
+            Ok(String::from("बयालीस"))
+        );
+        assert_eq!(
+            Num2Words::new(-99).lang(Lang::Nepali).to_words(),
+            Ok(String::from("ऋण उनान्सय"))
+        );
+        assert_eq!(
+            Num2Words::new(105).lang(Lang::Nepali).to_words(),
+            Ok(String::from("एक सय पाँच"))
+        );
+        assert_eq!(
+            Num2Words::new(100_000).lang(Lang::Nepali).to_words(),
+            Ok(String::from("एक लाख"))
+        );
+        assert_eq!(
+            Num2Words::new(123_456_789).lang(Lang::Nepali).to_words(),
+            Ok(String::from("बाह्र करोड चौंतीस लाख छपन्न हजार सात सय उनान्नब्बे"))
+        );
+        assert_eq!(
+            Num2Words::new(2.25).lang(Lang::Nepali).to_words(),
+            Ok(String::from("दुई दशमलव दुई पाँच"))
+        );
+        assert_eq!(
+            Num2Words::new(-0.5).lang(Lang::Nepali).to_words(),
+            Ok(String::from("ऋण शून्य दशमलव पाँच"))
+        );
+        assert_eq!(
+            Num2Words::new(0.5).lang(Lang::Nepali).to_words(),
+            Ok(String::from("शून्य दशमलव पाँच"))
+        );
+        assert_eq!(
+            Num2Words::new(-0.25).lang(Lang::Nepali).to_words(),
+            Ok(String::from("ऋण शून्य दशमलव दुई पाँच"))
+        );
+    }
+
+    #[test]
+    fn test_ordinal() {
+        assert_eq!(
+            Num2Words::new(1).lang(Lang::Nepali).ordinal().to_words(),
+            Ok(String::from("पहिलो"))
+        );
+        assert_eq!(
+            Num2Words::new(5).lang(Lang::Nepali).ordinal().to_words(),
+            Ok(String::from("पाँचौं"))
+        );
+        assert_eq!(
+            Num2Words::new(42).lang(Lang::Nepali).ordinal().to_words(),
+            Ok(String::from("बयालीसौं"))
+        );
+        assert_eq!(
+            Num2Words::new(90).lang(Lang::Nepali).ordinal().to_words(),
+            Ok(String::from("नब्बेऔं"))
+        );
+        assert_eq!(
+            Num2Words::new(100).lang(Lang::Nepali).ordinal().to_words(),
+            Ok(String::from("एक सयौं"))
+        );
+    }
+
+    #[test]
+    fn test_ordinal_num() {
+        assert_eq!(
+            Num2Words::new(1)
+                .lang(Lang::Nepali)
+                .ordinal_num()
+                .to_words(),
+            Ok(String::from("१लो"))
+        );
+        assert_eq!(
+            Num2Words::new(42)
+                .lang(Lang::Nepali)
+                .ordinal_num()
+                .to_words(),
+            Ok(String::from("४२औं"))
+        );
+    }
+
+    #[test]
+    fn test_year() {
+        assert_eq!(
+            Num2Words::new(1991).lang(Lang::Nepali).year().to_words(),
+            Ok(String::from("उन्नाइस सय एकानब्बे"))
+        );
+        assert_eq!(
+            Num2Words::new(2024).lang(Lang::Nepali).year().to_words(),
+            Ok(String::from("दुई हजार चौबीस"))
+        );
+        assert_eq!(
+            Num2Words::new(-500).lang(Lang::Nepali).year().to_words(),
+            Ok(String::from("पाँच सय ईसा पूर्व"))
+        );
+    }
+
+    #[test]
+    fn test_currency() {
+        assert_eq!(
+            Num2Words::new(42.01)
+                .lang(Lang::Nepali)
+                .currency(Currency::NPR)
+                .to_words(),
+            Ok(String::from("बयालीस रुपैयाँ एक पैसा"))
+        );
+        assert_eq!(
+            Num2Words::new(0.5)
+                .lang(Lang::Nepali)
+                .currency(Currency::NPR)
+                .to_words(),
+            Ok(String::from("पचास पैसा"))
+        );
+        assert_eq!(
+            Num2Words::new(0.2)
+                .lang(Lang::Nepali)
+                .currency(Currency::NPR)
+                .prefer("zero-major")
+                .to_words(),
+            Ok(String::from("शून्य रुपैयाँ बीस पैसा"))
+        );
+        assert_eq!(
+            Num2Words::new(10)
+                .lang(Lang::Nepali)
+                .currency(Currency::USD)
+                .to_words(),
+            Ok(String::from("दस अमेरिकी डलर"))
+        );
+    }
+
+    #[test]
+    fn test_percent_and_unit() {
+        assert_eq!(
+            Num2Words::new(25).lang(Lang::Nepali).percent().to_words(),
+            Ok(String::from("पच्चीस प्रतिशत"))
+        );
+        assert_eq!(
+            Num2Words::new(3)
+                .lang(Lang::Nepali)
+                .unit(Unit::Kilogram)
+                .to_words(),
+            Ok(String::from("तीन किलोग्राम"))
+        );
+    }
+}
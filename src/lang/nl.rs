@@ -0,0 +1,479 @@
+use crate::{num2words::Num2Err, Currency, Language, PluralCategory, Unit};
+use num_bigfloat::BigFloat;
+
+pub struct Dutch {
+    prefer_long_suffix: bool,
+    zero_major: bool,
+}
+
+const UNITS: [&str; 10] = [
+    "nul", "een", "twee", "drie", "vier", "vijf", "zes", "zeven", "acht", "negen",
+];
+
+const TEENS: [&str; 10] = [
+    "tien",
+    "elf",
+    "twaalf",
+    "dertien",
+    "veertien",
+    "vijftien",
+    "zestien",
+    "zeventien",
+    "achttien",
+    "negentien",
+];
+
+const TENS: [&str; 8] = [
+    "twintig", "dertig", "veertig", "vijftig", "zestig", "zeventig", "tachtig", "negentig",
+];
+
+const MEGAS: [&str; 10] = [
+    "miljoen",
+    "miljard",
+    "biljoen",
+    "biljard",
+    "triljoen",
+    "triljard",
+    "quadriljoen",
+    "quadriljard",
+    "quintiljoen",
+    "quintiljard",
+];
+
+impl Dutch {
+    pub fn new(prefer_long_suffix: bool) -> Self {
+        Self {
+            prefer_long_suffix,
+            zero_major: false,
+        }
+    }
+
+    /// Keeps the major unit clause for amounts below one, e.g. `nul euro en
+    /// twintig cent`
+    pub fn zero_major(self, zero_major: bool) -> Self {
+        Self { zero_major, ..self }
+    }
+
+    // Amounts are counted in the singular, e.g. tweeënveertig euro
+    fn currencies(&self, currency: Currency) -> String {
+        match currency {
+            Currency::GBP => String::from("pond"),
+            Currency::USD => String::from("Amerikaanse dollar"),
+            _ => currency.default_string(false),
+        }
+    }
+
+    fn cents(&self, currency: Currency) -> String {
+        match currency {
+            Currency::GBP => String::from("pence"),
+            _ => currency.default_subunit_string("cent", false),
+        }
+    }
+
+    fn units(&self, unit: Unit, plural_form: bool) -> &'static str {
+        match (unit, plural_form) {
+            (Unit::Meter, _) => "meter",
+            (Unit::Kilometer, _) => "kilometer",
+            (Unit::Gram, _) => "gram",
+            (Unit::Kilogram, _) => "kilogram",
+            (Unit::Liter, _) => "liter",
+            (Unit::Second, false) => "seconde",
+            (Unit::Second, true) => "seconden",
+            (Unit::Minute, false) => "minuut",
+            (Unit::Minute, true) => "minuten",
+            (Unit::Hour, _) => "uur",
+        }
+    }
+
+    fn split_thousands(&self, mut num: BigFloat) -> Vec<u64> {
+        let mut thousands = Vec::new();
+        let bf_1000 = BigFloat::from(1000);
+
+        while !num.is_zero() {
+            thousands.push((num % bf_1000).to_u64().unwrap());
+            num /= bf_1000;
+        }
+
+        thousands
+    }
+
+    fn below_hundred(&self, num: u64) -> String {
+        let tens = (num / 10) as usize;
+        let units = (num % 10) as usize;
+
+        match (tens, units) {
+            (0, _) => String::from(UNITS[units]),
+            (1, _) => String::from(TEENS[units]),
+            (_, 0) => String::from(TENS[tens - 2]),
+            _ => {
+                // the unit comes first, and the ligature takes a diaeresis
+                // after a final e, e.g. tweeënveertig but vierenveertig
+                let unit = UNITS[units];
+                let ligature = if unit.ends_with('e') { "ën" } else { "en" };
+                format!("{}{}{}", unit, ligature, TENS[tens - 2])
+            }
+        }
+    }
+
+    // Hundreds, tens and units fused into a single word, e.g.
+    // honderddrieëntwintig
+    fn triplet_to_word(&self, triplet: u64) -> String {
+        let hundreds = triplet / 100;
+        let mut word = match hundreds {
+            0 => String::new(),
+            1 => String::from("honderd"),
+            _ => format!("{}honderd", UNITS[hundreds as usize]),
+        };
+        if !triplet.is_multiple_of(100) {
+            word.push_str(&self.below_hundred(triplet % 100));
+        }
+        word
+    }
+
+    fn int_to_cardinal(&self, mut num: BigFloat) -> Result<String, Num2Err> {
+        // special case zero
+        if num.is_zero() {
+            return Ok(String::from(UNITS[0]));
+        }
+
+        // handling negative values
+        let mut words = vec![];
+        if num.is_negative() {
+            words.push(String::from("min"));
+            num = -num;
+        }
+
+        let triplets = self.split_thousands(num);
+        for (i, triplet) in triplets.iter().enumerate().skip(2).rev() {
+            if *triplet == 0 {
+                continue;
+            }
+            if i - 2 >= MEGAS.len() {
+                return Err(Num2Err::CannotConvert);
+            }
+            words.push(format!(
+                "{} {}",
+                self.triplet_to_word(*triplet),
+                MEGAS[i - 2]
+            ));
+        }
+
+        // thousands and units are fused, e.g. tweeduizenddrie
+        let mut word = match triplets.get(1).copied().unwrap_or(0) {
+            0 => String::new(),
+            1 => String::from("duizend"),
+            thousands => format!("{}duizend", self.triplet_to_word(thousands)),
+        };
+        if triplets[0] != 0 {
+            word.push_str(&self.triplet_to_word(triplets[0]));
+        }
+        if !word.is_empty() {
+            words.push(word);
+        }
+
+        Ok(words.join(" "))
+    }
+
+    fn float_to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let integral_part = num.int();
+        let mut words: Vec<String> = vec![];
+
+        if !integral_part.is_zero() {
+            let integral_word = self.int_to_cardinal(integral_part)?;
+            words.push(integral_word);
+        } else if num.is_negative() {
+            words.push(String::from("min"));
+        }
+        if integral_part.is_zero() {
+            words.push(String::from(UNITS[0]));
+        }
+
+        let mut ordinal_part = num.frac().abs();
+        if !ordinal_part.is_zero() {
+            words.push(String::from("komma"));
+        }
+        while !ordinal_part.is_zero() {
+            let digit = (ordinal_part * BigFloat::from(10)).int();
+            ordinal_part = (ordinal_part * BigFloat::from(10)).frac();
+            words.push(String::from(UNITS[digit.to_u64().unwrap() as usize]));
+        }
+        Ok(words.join(" "))
+    }
+
+    // Ordinals end in -ste after one, eight, tens and bigger numbers, e.g.
+    // achtste, twintigste, and in -de otherwise, e.g. tweede, negentiende
+    fn ordinal_suffix(&self, num: BigFloat) -> &'static str {
+        let below_hundred = (num % BigFloat::from(100)).to_u64().unwrap();
+        if below_hundred == 1 || below_hundred == 8 || below_hundred == 0 || below_hundred >= 20 {
+            "ste"
+        } else {
+            "de"
+        }
+    }
+}
+
+impl Language for Dutch {
+    fn to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num.is_inf_pos() {
+            Ok(String::from("oneindig"))
+        } else if num.is_inf_neg() {
+            Ok(String::from("min oneindig"))
+        } else if num.frac().is_zero() {
+            self.int_to_cardinal(num)
+        } else {
+            self.float_to_cardinal(num)
+        }
+    }
+
+    fn to_ordinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let cardinal_word = self.to_cardinal(num)?;
+        // a lone million is not counted, e.g. miljoenste
+        let cardinal_word = cardinal_word.strip_prefix("een ").unwrap_or(&cardinal_word);
+
+        if let Some(stem) = cardinal_word.strip_suffix("een") {
+            // e.g. honderdeerste
+            Ok(format!("{}eerste", stem))
+        } else if let Some(stem) = cardinal_word.strip_suffix("drie") {
+            // e.g. drieëntwintig is regular, but drie becomes derde
+            Ok(format!("{}derde", stem))
+        } else {
+            Ok(format!("{}{}", cardinal_word, self.ordinal_suffix(num)))
+        }
+    }
+
+    fn to_ordinal_num(&self, num: BigFloat) -> Result<String, Num2Err> {
+        Ok(format!(
+            "{}{}",
+            num.to_u128().unwrap(),
+            if self.prefer_long_suffix {
+                self.ordinal_suffix(num)
+            } else {
+                "e"
+            }
+        ))
+    }
+
+    fn to_year(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if !num.frac().is_zero() {
+            return Err(Num2Err::FloatingYear);
+        }
+
+        let year = num.abs().to_u64().unwrap_or(0);
+        // years are read in hundreds, e.g. negentienhonderdvier, unless they
+        // fall in the first century of a millennium, e.g. tweeduizendvier
+        let words = if (1100..10_000).contains(&year) && !(year / 100).is_multiple_of(10) {
+            let mut word = format!("{}honderd", self.below_hundred(year / 100));
+            if !year.is_multiple_of(100) {
+                word.push_str(&self.below_hundred(year % 100));
+            }
+            word
+        } else {
+            self.int_to_cardinal(num.abs())?
+        };
+
+        if num.is_negative() {
+            Ok(format!("{} voor Christus", words))
+        } else {
+            Ok(words)
+        }
+    }
+
+    fn to_currency(&self, num: BigFloat, currency: Currency) -> Result<String, Num2Err> {
+        if num.is_inf() || num.frac().is_zero() {
+            Ok(format!(
+                "{} {}",
+                self.to_cardinal(num)?,
+                self.currencies(currency)
+            ))
+        } else {
+            let integral_part = num.int();
+            let cents_nb = (num * BigFloat::from(100)).int() % BigFloat::from(100);
+            let cents_words = format!(
+                "{} {}",
+                self.int_to_cardinal(cents_nb)?,
+                self.cents(currency)
+            );
+            let integral_word = self.to_currency(integral_part, currency)?;
+
+            if cents_nb.is_zero() {
+                Ok(integral_word)
+            } else if integral_part.is_zero() && !self.zero_major {
+                Ok(cents_words)
+            } else {
+                Ok(format!("{} en {}", integral_word, cents_words))
+            }
+        }
+    }
+
+    fn to_percent(&self, num: BigFloat) -> Result<String, Num2Err> {
+        Ok(format!("{} procent", self.to_cardinal(num)?))
+    }
+
+    fn to_unit(&self, num: BigFloat, unit: Unit) -> Result<String, Num2Err> {
+        Ok(format!(
+            "{} {}",
+            self.to_cardinal(num)?,
+            self.units(unit, self.plural_category(num) != PluralCategory::One)
+        ))
+    }
+
+    fn plural_category(&self, num: BigFloat) -> PluralCategory {
+        if num.abs() == BigFloat::from(1) {
+            PluralCategory::One
+        } else {
+            PluralCategory::Other
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_cardinal() {
+        assert_eq!(
+            Num2Words::new(0).lang(Lang::Dutch).to_words(),
+            Ok(String::from("nul"))
+        );
+        assert_eq!(
+            Num2Words::new(21).lang(Lang::Dutch).to_words(),
+            Ok(String::from("eenentwintig"))
+        );
+        assert_eq!(
+            Num2Words::new(42).lang(Lang::Dutch).to_words(),
+            Ok(String::from("tweeënveertig"))
+        );
+        assert_eq!(
+            Num2Words::new(44).lang(Lang::Dutch).to_words(),
+            Ok(String::from("vierenveertig"))
+        );
+        assert_eq!(
+            Num2Words::new(123).lang(Lang::Dutch).to_words(),
+            Ok(String::from("honderddrieëntwintig"))
+        );
+        assert_eq!(
+            Num2Words::new(1000).lang(Lang::Dutch).to_words(),
+            Ok(String::from("duizend"))
+        );
+        assert_eq!(
+            Num2Words::new(2_300_042).lang(Lang::Dutch).to_words(),
+            Ok(String::from("twee miljoen driehonderdduizendtweeënveertig"))
+        );
+        assert_eq!(
+            Num2Words::new(-1.5).lang(Lang::Dutch).to_words(),
+            Ok(String::from("min een komma vijf"))
+        );
+        assert_eq!(
+            Num2Words::new(0.5).lang(Lang::Dutch).to_words(),
+            Ok(String::from("nul komma vijf"))
+        );
+        assert_eq!(
+            Num2Words::new(-0.25).lang(Lang::Dutch).to_words(),
+            Ok(String::from("min nul komma twee vijf"))
+        );
+    }
+
+    #[test]
+    fn test_ordinal() {
+        assert_eq!(
+            Num2Words::new(1).lang(Lang::Dutch).ordinal().to_words(),
+            Ok(String::from("eerste"))
+        );
+        assert_eq!(
+            Num2Words::new(3).lang(Lang::Dutch).ordinal().to_words(),
+            Ok(String::from("derde"))
+        );
+        assert_eq!(
+            Num2Words::new(8).lang(Lang::Dutch).ordinal().to_words(),
+            Ok(String::from("achtste"))
+        );
+        assert_eq!(
+            Num2Words::new(19).lang(Lang::Dutch).ordinal().to_words(),
+            Ok(String::from("negentiende"))
+        );
+        assert_eq!(
+            Num2Words::new(42).lang(Lang::Dutch).ordinal().to_words(),
+            Ok(String::from("tweeënveertigste"))
+        );
+        assert_eq!(
+            Num2Words::new(101).lang(Lang::Dutch).ordinal().to_words(),
+            Ok(String::from("honderdeerste"))
+        );
+        assert_eq!(
+            Num2Words::new(1_000_000)
+                .lang(Lang::Dutch)
+                .ordinal()
+                .to_words(),
+            Ok(String::from("miljoenste"))
+        );
+        assert_eq!(
+            Num2Words::new(42)
+                .lang(Lang::Dutch)
+                .ordinal_num()
+                .to_words(),
+            Ok(String::from("42e"))
+        );
+        assert_eq!(
+            Num2Words::new(42)
+                .lang(Lang::Dutch)
+                .ordinal_num()
+                .prefer("ste")
+                .to_words(),
+            Ok(String::from("42ste"))
+        );
+        assert_eq!(
+            Num2Words::new(2)
+                .lang(Lang::Dutch)
+                .ordinal_num()
+                .prefer("ste")
+                .to_words(),
+            Ok(String::from("2de"))
+        );
+    }
+
+    #[test]
+    fn test_year() {
+        assert_eq!(
+            Num2Words::new(1904).lang(Lang::Dutch).year().to_words(),
+            Ok(String::from("negentienhonderdvier"))
+        );
+        assert_eq!(
+            Num2Words::new(1990).lang(Lang::Dutch).year().to_words(),
+            Ok(String::from("negentienhonderdnegentig"))
+        );
+        assert_eq!(
+            Num2Words::new(2024).lang(Lang::Dutch).year().to_words(),
+            Ok(String::from("tweeduizendvierentwintig"))
+        );
+        assert_eq!(
+            Num2Words::new(-44).lang(Lang::Dutch).year().to_words(),
+            Ok(String::from("vierenveertig voor Christus"))
+        );
+    }
+
+    #[test]
+    fn test_currency() {
+        assert_eq!(
+            Num2Words::new(42)
+                .lang(Lang::Dutch)
+                .currency(Currency::EUR)
+                .to_words(),
+            Ok(String::from("tweeënveertig euro"))
+        );
+        assert_eq!(
+            Num2Words::new(42.01)
+                .lang(Lang::Dutch)
+                .currency(Currency::EUR)
+                .to_words(),
+            Ok(String::from("tweeënveertig euro en een cent"))
+        );
+        assert_eq!(
+            Num2Words::new(0.5)
+                .lang(Lang::Dutch)
+                .currency(Currency::EUR)
+                .to_words(),
+            Ok(String::from("vijftig cent"))
+        );
+    }
+}
@@ -0,0 +1,1172 @@
+use crate::lang::slavic::{
+    plural_category_with_units, Declension, Gender, GrammaticalNumber, Slavic,
+};
+use crate::{num2words::Num2Err, Agreement, Currency, Language, PluralCategory, Unit};
+use num_bigfloat::BigFloat;
+
+// Source: Wielki słownik ortograficzny PWN
+// Odmiana liczebników głównych, porządkowych i ułamkowych
+
+#[derive(Default, Clone, Copy, PartialEq, Debug)]
+pub struct Polish {
+    gender: Gender,
+    number: GrammaticalNumber,
+    declension: Declension,
+    zero_major: bool,
+}
+
+const MINUS: &str = "minus";
+
+const INFINITY: [&str; 7] = [
+    "nieskończoność",
+    "nieskończoności",
+    "nieskończoności",
+    "nieskończoność",
+    "nieskończonością",
+    "nieskończoności",
+    "nieskończoności",
+];
+
+const ZERO: [&str; 7] = ["zero", "zera", "zeru", "zero", "zerem", "zerze", "zero"];
+
+const ORDINAL_ZERO_BASE: &str = "zerow";
+
+// one is invariant inside of a compound number: dwadzieścia jeden
+const ONE_INVARIANT: &str = "jeden";
+
+#[rustfmt::skip]
+const GENDERED: [[[&str; 7]; 3]; 2] = [[
+    [ "jeden", "jednego", "jednemu", "jeden", "jednym", "jednym", "jeden" ],
+    [ "jedna", "jednej",  "jednej",  "jedną", "jedną",  "jednej", "jedna" ],
+    [ "jedno", "jednego", "jednemu", "jedno", "jednym", "jednym", "jedno" ],
+],
+[
+    [ "dwa",  "dwóch", "dwóm", "dwa",  "dwoma",  "dwóch", "dwa"  ],
+    [ "dwie", "dwóch", "dwóm", "dwie", "dwiema", "dwóch", "dwie" ],
+    [ "dwa",  "dwóch", "dwóm", "dwa",  "dwoma",  "dwóch", "dwa"  ],
+]];
+
+#[rustfmt::skip]
+const UNITS: [[&str; 7]; 7] = [
+    [ "trzy",     "trzech",     "trzem",      "trzy",     "trzema",       "trzech",     "trzy"     ],
+    [ "cztery",   "czterech",   "czterem",    "cztery",   "czterema",     "czterech",   "cztery"   ],
+    [ "pięć",     "pięciu",     "pięciu",     "pięć",     "pięcioma",     "pięciu",     "pięć"     ],
+    [ "sześć",    "sześciu",    "sześciu",    "sześć",    "sześcioma",    "sześciu",    "sześć"    ],
+    [ "siedem",   "siedmiu",    "siedmiu",    "siedem",   "siedmioma",    "siedmiu",    "siedem"   ],
+    [ "osiem",    "ośmiu",      "ośmiu",      "osiem",    "ośmioma",      "ośmiu",      "osiem"    ],
+    [ "dziewięć", "dziewięciu", "dziewięciu", "dziewięć", "dziewięcioma", "dziewięciu", "dziewięć" ],
+];
+
+#[rustfmt::skip]
+const TEENS: [[&str; 7]; 10] = [
+    [ "dziesięć",       "dziesięciu",    "dziesięciu",    "dziesięć",       "dziesięcioma",    "dziesięciu",    "dziesięć"       ],
+    [ "jedenaście",     "jedenastu",     "jedenastu",     "jedenaście",     "jedenastoma",     "jedenastu",     "jedenaście"     ],
+    [ "dwanaście",      "dwunastu",      "dwunastu",      "dwanaście",      "dwunastoma",      "dwunastu",      "dwanaście"      ],
+    [ "trzynaście",     "trzynastu",     "trzynastu",     "trzynaście",     "trzynastoma",     "trzynastu",     "trzynaście"     ],
+    [ "czternaście",    "czternastu",    "czternastu",    "czternaście",    "czternastoma",    "czternastu",    "czternaście"    ],
+    [ "piętnaście",     "piętnastu",     "piętnastu",     "piętnaście",     "piętnastoma",     "piętnastu",     "piętnaście"     ],
+    [ "szesnaście",     "szesnastu",     "szesnastu",     "szesnaście",     "szesnastoma",     "szesnastu",     "szesnaście"     ],
+    [ "siedemnaście",   "siedemnastu",   "siedemnastu",   "siedemnaście",   "siedemnastoma",   "siedemnastu",   "siedemnaście"   ],
+    [ "osiemnaście",    "osiemnastu",    "osiemnastu",    "osiemnaście",    "osiemnastoma",    "osiemnastu",    "osiemnaście"    ],
+    [ "dziewiętnaście", "dziewiętnastu", "dziewiętnastu", "dziewiętnaście", "dziewiętnastoma", "dziewiętnastu", "dziewiętnaście" ],
+];
+
+#[rustfmt::skip]
+const TENS: [[&str; 7]; 8] = [
+    [ "dwadzieścia",      "dwudziestu",         "dwudziestu",         "dwadzieścia",      "dwudziestoma",         "dwudziestu",         "dwadzieścia"      ],
+    [ "trzydzieści",      "trzydziestu",        "trzydziestu",        "trzydzieści",      "trzydziestoma",        "trzydziestu",        "trzydzieści"      ],
+    [ "czterdzieści",     "czterdziestu",       "czterdziestu",       "czterdzieści",     "czterdziestoma",       "czterdziestu",       "czterdzieści"     ],
+    [ "pięćdziesiąt",     "pięćdziesięciu",     "pięćdziesięciu",     "pięćdziesiąt",     "pięćdziesięcioma",     "pięćdziesięciu",     "pięćdziesiąt"     ],
+    [ "sześćdziesiąt",    "sześćdziesięciu",    "sześćdziesięciu",    "sześćdziesiąt",    "sześćdziesięcioma",    "sześćdziesięciu",    "sześćdziesiąt"    ],
+    [ "siedemdziesiąt",   "siedemdziesięciu",   "siedemdziesięciu",   "siedemdziesiąt",   "siedemdziesięcioma",   "siedemdziesięciu",   "siedemdziesiąt"   ],
+    [ "osiemdziesiąt",    "osiemdziesięciu",    "osiemdziesięciu",    "osiemdziesiąt",    "osiemdziesięcioma",    "osiemdziesięciu",    "osiemdziesiąt"    ],
+    [ "dziewięćdziesiąt", "dziewięćdziesięciu", "dziewięćdziesięciu", "dziewięćdziesiąt", "dziewięćdziesięcioma", "dziewięćdziesięciu", "dziewięćdziesiąt" ],
+];
+
+#[rustfmt::skip]
+const HUNDREDS: [[&str; 7]; 9] = [
+    [ "sto",         "stu",           "stu",           "sto",         "stoma",         "stu",           "sto"         ],
+    [ "dwieście",    "dwustu",        "dwustu",        "dwieście",    "dwustoma",      "dwustu",        "dwieście"    ],
+    [ "trzysta",     "trzystu",       "trzystu",       "trzysta",     "trzystoma",     "trzystu",       "trzysta"     ],
+    [ "czterysta",   "czterystu",     "czterystu",     "czterysta",   "czterystoma",   "czterystu",     "czterysta"   ],
+    [ "pięćset",     "pięciuset",     "pięciuset",     "pięćset",     "pięciuset",     "pięciuset",     "pięćset"     ],
+    [ "sześćset",    "sześciuset",    "sześciuset",    "sześćset",    "sześciuset",    "sześciuset",    "sześćset"    ],
+    [ "siedemset",   "siedmiuset",    "siedmiuset",    "siedemset",   "siedmiuset",    "siedmiuset",    "siedemset"   ],
+    [ "osiemset",    "ośmiuset",      "ośmiuset",      "osiemset",    "ośmiuset",      "ośmiuset",      "osiemset"    ],
+    [ "dziewięćset", "dziewięciuset", "dziewięciuset", "dziewięćset", "dziewięciuset", "dziewięciuset", "dziewięćset" ],
+];
+
+#[rustfmt::skip]
+const THOUSANDS: [[&str; 7]; 2] = [
+    [ "tysiąc",  "tysiąca", "tysiącowi", "tysiąc",  "tysiącem",  "tysiącu",   "tysiącu" ],
+    [ "tysiące", "tysięcy", "tysiącom",  "tysiące", "tysiącami", "tysiącach", "tysiące" ],
+];
+
+const MEGA_BASES: [&str; 12] = [
+    "milion",
+    "miliard",
+    "bilion",
+    "biliard",
+    "trylion",
+    "tryliard",
+    "kwadrylion",
+    "kwadryliard",
+    "kwintylion",
+    "kwintyliard",
+    "sekstylion",
+    "sekstyliard",
+];
+
+#[rustfmt::skip]
+const MEGA_FLEXIONS: [[&str; 7]; 2] = [
+    [ "",  "a",  "owi", "",  "em",  "ie",  "ie" ],
+    [ "y", "ów", "om",  "y", "ami", "ach", "y"  ],
+];
+
+// the locative of -ard softens the stem: miliardzie
+#[rustfmt::skip]
+const MEGA_ARD_FLEXIONS: [[&str; 7]; 2] = [
+    [ "",  "a",  "owi", "",  "em",  "zie", "zie" ],
+    [ "y", "ów", "om",  "y", "ami", "ach", "y"   ],
+];
+
+// the ordinals of the powers of a thousand are built as tysięczny, milionowy
+const ORDINAL_THOUSAND_BASE: &str = "tysięczn";
+const ORDINAL_MEGA_SUFFIX: &str = "ow";
+
+const ORDINAL_UNIT_BASES: [&str; 9] = [
+    "pierwsz",
+    "drug",
+    "trzeci",
+    "czwart",
+    "piąt",
+    "szóst",
+    "siódm",
+    "ósm",
+    "dziewiąt",
+];
+
+const ORDINAL_TEENS_BASES: [&str; 10] = [
+    "dziesiąt",
+    "jedenast",
+    "dwunast",
+    "trzynast",
+    "czternast",
+    "piętnast",
+    "szesnast",
+    "siedemnast",
+    "osiemnast",
+    "dziewiętnast",
+];
+
+const ORDINAL_TENS_BASES: [&str; 8] = [
+    "dwudziest",
+    "trzydziest",
+    "czterdziest",
+    "pięćdziesiąt",
+    "sześćdziesiąt",
+    "siedemdziesiąt",
+    "osiemdziesiąt",
+    "dziewięćdziesiąt",
+];
+
+const ORDINAL_HUNDREDS_BASES: [&str; 9] = [
+    "setn",
+    "dwusetn",
+    "trzechsetn",
+    "czterechsetn",
+    "pięćsetn",
+    "sześćsetn",
+    "siedemsetn",
+    "osiemsetn",
+    "dziewięćsetn",
+];
+
+// prefixes compounding a count with a power of a thousand: dwutysięczny
+const COMBINING_UNITS: [&str; 10] = [
+    "",
+    "jedno",
+    "dwu",
+    "trzy",
+    "cztero",
+    "pięcio",
+    "sześcio",
+    "siedmio",
+    "ośmio",
+    "dziewięcio",
+];
+
+const COMBINING_TEENS: [&str; 10] = [
+    "dziesięcio",
+    "jedenasto",
+    "dwunasto",
+    "trzynasto",
+    "czternasto",
+    "piętnasto",
+    "szesnasto",
+    "siedemnasto",
+    "osiemnasto",
+    "dziewiętnasto",
+];
+
+const COMBINING_TENS: [&str; 8] = [
+    "dwudziesto",
+    "trzydziesto",
+    "czterdziesto",
+    "pięćdziesięcio",
+    "sześćdziesięcio",
+    "siedemdziesięcio",
+    "osiemdziesięcio",
+    "dziewięćdziesięcio",
+];
+
+const COMBINING_HUNDREDS: [&str; 9] = [
+    "stu",
+    "dwustu",
+    "trzystu",
+    "czterystu",
+    "pięćset",
+    "sześćset",
+    "siedemset",
+    "osiemset",
+    "dziewięćset",
+];
+
+#[rustfmt::skip]
+const ADJECTIVE_HARD_FLEXIONS_SINGULAR: [[&str; 7]; 3] = [
+    [ "y", "ego", "emu", "y", "ym", "ym", "y" ],
+    [ "a", "ej",  "ej",  "ą", "ą",  "ej", "a" ],
+    [ "e", "ego", "emu", "e", "ym", "ym", "e" ],
+];
+
+// the plural forms are the non-masculine-personal ones
+const ADJECTIVE_HARD_FLEXIONS_PLURAL: [&str; 7] = ["e", "ych", "ym", "e", "ymi", "ych", "e"];
+
+#[rustfmt::skip]
+const ADJECTIVE_VELAR_FLEXIONS_SINGULAR: [[&str; 7]; 3] = [
+    [ "i",  "iego", "iemu", "i",  "im", "im",  "i"  ],
+    [ "a",  "iej",  "iej",  "ą",  "ą",  "iej", "a"  ],
+    [ "ie", "iego", "iemu", "ie", "im", "im",  "ie" ],
+];
+
+const ADJECTIVE_VELAR_FLEXIONS_PLURAL: [&str; 7] = ["ie", "ich", "im", "ie", "imi", "ich", "ie"];
+
+#[rustfmt::skip]
+const ADJECTIVE_SOFT_FLEXIONS_SINGULAR: [[&str; 7]; 3] = [
+    [ "",  "ego", "emu", "",  "m", "m",  ""  ],
+    [ "a", "ej",  "ej",  "ą", "ą", "ej", "a" ],
+    [ "e", "ego", "emu", "e", "m", "m",  "e" ],
+];
+
+const ADJECTIVE_SOFT_FLEXIONS_PLURAL: [&str; 7] = ["e", "ch", "m", "e", "mi", "ch", "e"];
+
+#[rustfmt::skip]
+const NOUN_MASCULINE_HARD_DECLENSIONS: [[&str; 7]; 2] = [ //metr
+    [ "",  "a",  "owi", "",  "em",  "ze",  "ze" ],
+    [ "y", "ów", "om",  "y", "ami", "ach", "y"  ],
+];
+
+#[rustfmt::skip]
+const NOUN_MASCULINE_LABIAL_DECLENSIONS: [[&str; 7]; 2] = [ //gram
+    [ "",  "a",  "owi", "",  "em",  "ie",  "ie" ],
+    [ "y", "ów", "om",  "y", "ami", "ach", "y"  ],
+];
+
+#[rustfmt::skip]
+const CENTS: [[&str; 7]; 2] = [
+    [ "cent",  "centa",  "centowi", "cent",  "centem",  "cencie",  "cencie" ],
+    [ "centy", "centów", "centom",  "centy", "centami", "centach", "centy"  ],
+];
+
+#[rustfmt::skip]
+const POUNDS: [[&str; 7]; 2] = [
+    [ "funt",  "funta",  "funtowi", "funt",  "funtem",  "funcie",  "funcie" ],
+    [ "funty", "funtów", "funtom",  "funty", "funtami", "funtach", "funty"  ],
+];
+
+#[rustfmt::skip]
+const PENCE: [[&str; 7]; 2] = [
+    [ "pens",  "pensa",  "pensowi", "pens",  "pensem",  "pensie",  "pensie" ],
+    [ "pensy", "pensów", "pensom",  "pensy", "pensami", "pensach", "pensy"  ],
+];
+
+#[rustfmt::skip]
+const GROSZE: [[&str; 7]; 2] = [
+    [ "grosz",  "grosza", "groszowi", "grosz",  "groszem",  "groszu",   "groszu" ],
+    [ "grosze", "groszy", "groszom",  "grosze", "groszami", "groszach", "grosze" ],
+];
+
+#[rustfmt::skip]
+const SECONDS: [[&str; 7]; 2] = [
+    [ "sekunda", "sekundy", "sekundzie", "sekundę", "sekundą",   "sekundzie", "sekundo" ],
+    [ "sekundy", "sekund",  "sekundom",  "sekundy", "sekundami", "sekundach", "sekundy" ],
+];
+
+#[rustfmt::skip]
+const MINUTES: [[&str; 7]; 2] = [
+    [ "minuta", "minuty", "minucie", "minutę", "minutą",   "minucie", "minuto" ],
+    [ "minuty", "minut",  "minutom", "minuty", "minutami", "minutach", "minuty" ],
+];
+
+#[rustfmt::skip]
+const HOURS: [[&str; 7]; 2] = [
+    [ "godzina", "godziny", "godzinie", "godzinę", "godziną",   "godzinie", "godzino" ],
+    [ "godziny", "godzin",  "godzinom", "godziny", "godzinami", "godzinach", "godziny" ],
+];
+
+impl Slavic for Polish {
+    // a trailing one is counted like five, only one itself is singular:
+    // dwadzieścia jeden złotych
+    const TRAILING_ONE_IS_SINGULAR: bool = false;
+    const GENITIVE_GOVERNING: &'static [Declension] = &[
+        Declension::Nominative,
+        Declension::Accusative,
+        Declension::Vocative,
+    ];
+
+    const MINUS: &'static str = MINUS;
+    const ZERO: &'static [&'static str] = &ZERO;
+    const INFINITY: &'static [&'static str] = &INFINITY;
+    const ADJECTIVE_FLEXIONS_SINGULAR: [&'static [&'static str]; 3] = [
+        &ADJECTIVE_HARD_FLEXIONS_SINGULAR[0],
+        &ADJECTIVE_HARD_FLEXIONS_SINGULAR[1],
+        &ADJECTIVE_HARD_FLEXIONS_SINGULAR[2],
+    ];
+    const ADJECTIVE_FLEXIONS_PLURAL: &'static [&'static str] = &ADJECTIVE_HARD_FLEXIONS_PLURAL;
+
+    fn gender(&self) -> Gender {
+        self.gender
+    }
+    fn number(&self) -> GrammaticalNumber {
+        self.number
+    }
+    fn declension(&self) -> Declension {
+        self.declension
+    }
+    fn with(&self, gender: Gender, number: GrammaticalNumber, declension: Declension) -> Self {
+        Self {
+            gender,
+            number,
+            declension,
+            ..*self
+        }
+    }
+}
+
+impl Polish {
+    pub fn new(gender: Gender, number: GrammaticalNumber, declension: Declension) -> Self {
+        Self {
+            gender,
+            number,
+            declension,
+            zero_major: false,
+        }
+    }
+
+    /// Keeps the major unit clause for amounts below one, e.g. `zero złotych
+    /// dwadzieścia groszy`
+    pub fn zero_major(self, zero_major: bool) -> Self {
+        Self { zero_major, ..self }
+    }
+
+    fn currencies(&self, currency: Currency) -> String {
+        let number_idx = self.number.index();
+        let declension_idx = self.declension.index();
+        match currency {
+            Currency::AUD
+            | Currency::CAD
+            | Currency::DOLLAR
+            | Currency::HKD
+            | Currency::NZD
+            | Currency::SGD
+            | Currency::TWD
+            | Currency::USD => format!(
+                "dolar{}",
+                NOUN_MASCULINE_HARD_DECLENSIONS[number_idx][declension_idx]
+            ),
+            Currency::EUR => String::from("euro"),
+            Currency::GBP => String::from(POUNDS[number_idx][declension_idx]),
+            // złoty declines as an adjective: złote, złotych
+            Currency::PLN => format!("złot{}", self.adjective_flexion()),
+            _ => currency.default_string(self.is_plural()),
+        }
+    }
+
+    fn currency_properties(&self, currency: Currency) -> Polish {
+        match currency {
+            Currency::EUR => self.neuter(),
+            _ => self.masculine(),
+        }
+    }
+
+    fn currency_fraction(&self, currency: Currency) -> String {
+        let number_idx = self.number.index();
+        let declension_idx = self.declension.index();
+        match currency {
+            Currency::AUD
+            | Currency::CAD
+            | Currency::DOLLAR
+            | Currency::EUR
+            | Currency::HKD
+            | Currency::NZD
+            | Currency::SGD
+            | Currency::TWD
+            | Currency::USD => String::from(CENTS[number_idx][declension_idx]),
+            Currency::GBP => String::from(PENCE[number_idx][declension_idx]),
+            Currency::PLN => String::from(GROSZE[number_idx][declension_idx]),
+            _ => currency.default_subunit_string("cent{}", self.is_plural()),
+        }
+    }
+
+    fn currency_fraction_words(
+        &self,
+        fraction: BigFloat,
+        currency: Currency,
+    ) -> Result<String, Num2Err> {
+        let fraction = (fraction * BigFloat::from(100)).int();
+        let currency_lang = self.masculine();
+        let target_lang = currency_lang.agreement_with_num(fraction);
+        Ok(format!(
+            "{} {}",
+            currency_lang.int_to_cardinal(fraction)?,
+            target_lang.currency_fraction(currency)
+        ))
+    }
+
+    fn units(&self, unit: Unit) -> String {
+        let number_idx = self.number.index();
+        let declension_idx = self.declension.index();
+        match unit {
+            Unit::Meter => format!(
+                "metr{}",
+                NOUN_MASCULINE_HARD_DECLENSIONS[number_idx][declension_idx]
+            ),
+            Unit::Kilometer => format!(
+                "kilometr{}",
+                NOUN_MASCULINE_HARD_DECLENSIONS[number_idx][declension_idx]
+            ),
+            Unit::Gram => format!(
+                "gram{}",
+                NOUN_MASCULINE_LABIAL_DECLENSIONS[number_idx][declension_idx]
+            ),
+            Unit::Kilogram => format!(
+                "kilogram{}",
+                NOUN_MASCULINE_LABIAL_DECLENSIONS[number_idx][declension_idx]
+            ),
+            Unit::Liter => format!(
+                "litr{}",
+                NOUN_MASCULINE_HARD_DECLENSIONS[number_idx][declension_idx]
+            ),
+            Unit::Second => String::from(SECONDS[number_idx][declension_idx]),
+            Unit::Minute => String::from(MINUTES[number_idx][declension_idx]),
+            Unit::Hour => String::from(HOURS[number_idx][declension_idx]),
+        }
+    }
+
+    fn unit_properties(&self, unit: Unit) -> Polish {
+        match unit {
+            Unit::Second | Unit::Minute | Unit::Hour => self.feminine(),
+            _ => self.masculine(),
+        }
+    }
+
+    fn split_thousands(&self, mut num: BigFloat) -> Vec<u64> {
+        let mut thousands = Vec::new();
+        let bf_1000 = BigFloat::from(1000);
+
+        while !num.is_zero() {
+            thousands.push((num % bf_1000).to_u64().unwrap());
+            num /= bf_1000;
+        }
+
+        thousands
+    }
+
+    fn mega_word(&self, order: usize) -> Result<String, Num2Err> {
+        let number_idx = self.number.index();
+        let declension_idx = self.declension.index();
+        if order == 1 {
+            return Ok(String::from(THOUSANDS[number_idx][declension_idx]));
+        }
+
+        let base = MEGA_BASES.get(order - 2).ok_or(Num2Err::CannotConvert)?;
+        let flexions = if base.ends_with("ard") {
+            &MEGA_ARD_FLEXIONS
+        } else {
+            &MEGA_FLEXIONS
+        };
+        Ok(format!("{}{}", base, flexions[number_idx][declension_idx]))
+    }
+
+    fn int_to_cardinal(&self, mut num: BigFloat) -> Result<String, Num2Err> {
+        let declension_idx = self.declension.index();
+
+        // special case zero
+        if num.is_zero() {
+            return Ok(String::from(self.zero()));
+        }
+
+        // handling negative values
+        let mut words = vec![];
+        if num.is_negative() {
+            words.push(String::from(MINUS));
+            num = -num;
+        }
+        let is_one = num == BigFloat::from(1);
+
+        // iterate over thousands
+        for (order, triplet) in self.split_thousands(num).iter().enumerate().rev() {
+            if *triplet == 0 {
+                continue;
+            }
+
+            // a single thousand or million is not counted: tysiąc, milion
+            if order != 0 && *triplet == 1 {
+                words.push(self.masculine().singular().mega_word(order)?);
+                continue;
+            }
+
+            let hundreds = (triplet / 100 % 10) as usize;
+            let tens = (triplet / 10 % 10) as usize;
+            let units = (triplet % 10) as usize;
+
+            if hundreds > 0 {
+                words.push(String::from(HUNDREDS[hundreds - 1][declension_idx]));
+            }
+
+            if tens == 1 {
+                words.push(String::from(TEENS[units][declension_idx]));
+            } else {
+                if tens > 1 {
+                    words.push(String::from(TENS[tens - 2][declension_idx]));
+                }
+                // the last group agrees with target word, thousands and
+                // millions are masculine
+                let gender = if order == 0 {
+                    self.gender
+                } else {
+                    Gender::Masculine
+                };
+                match units {
+                    1 if is_one => {
+                        words.push(String::from(GENDERED[0][gender.index()][declension_idx]))
+                    }
+                    1 => words.push(String::from(ONE_INVARIANT)),
+                    2 => words.push(String::from(GENDERED[1][gender.index()][declension_idx])),
+                    3..=9 => words.push(String::from(UNITS[units - 3][declension_idx])),
+                    _ => (),
+                }
+            }
+
+            if order != 0 {
+                let properties = self
+                    .masculine()
+                    .agreement_with_category(plural_category_with_units::<Self>(tens, units));
+                words.push(properties.mega_word(order)?);
+            }
+        }
+
+        Ok(words.join(" "))
+    }
+
+    fn float_to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let whole = num.int();
+        let mut numerator = num.frac().abs();
+        if numerator.is_zero() {
+            return self.int_to_cardinal(whole);
+        }
+        let mut denominator = BigFloat::from(1);
+        while !numerator.frac().is_zero() {
+            numerator *= BigFloat::from(10);
+            denominator *= BigFloat::from(10);
+        }
+
+        // the numerals keep the requested case, only the nouns agree with
+        // them: dwie całe i pięć dziesiątych
+        let numeral_lang = self.feminine();
+        let whole_lang = numeral_lang.agreement_with_num(whole);
+        let denominator_lang = numeral_lang.agreement_with_num(numerator);
+        Ok(format!(
+            "{}{} cał{} i {} {}",
+            if whole.is_zero() && num.is_negative() {
+                "minus "
+            } else {
+                ""
+            },
+            numeral_lang.int_to_cardinal(whole)?,
+            whole_lang.adjective_flexion(),
+            numeral_lang.int_to_cardinal(numerator)?,
+            denominator_lang.to_ordinal(denominator)?,
+        ))
+    }
+
+    // drugi and trzeci are the only ordinals not declining like a hard
+    // adjective
+    fn ordinal_flexion(&self, units: usize) -> &'static str {
+        let declension_idx = self.declension.index();
+        let (singular, plural) = match units {
+            2 => (
+                &ADJECTIVE_VELAR_FLEXIONS_SINGULAR,
+                &ADJECTIVE_VELAR_FLEXIONS_PLURAL,
+            ),
+            3 => (
+                &ADJECTIVE_SOFT_FLEXIONS_SINGULAR,
+                &ADJECTIVE_SOFT_FLEXIONS_PLURAL,
+            ),
+            _ => (
+                &ADJECTIVE_HARD_FLEXIONS_SINGULAR,
+                &ADJECTIVE_HARD_FLEXIONS_PLURAL,
+            ),
+        };
+        if self.is_plural() {
+            plural[declension_idx]
+        } else {
+            singular[self.gender.index()][declension_idx]
+        }
+    }
+
+    fn combining_prefix(&self, triplet: u64) -> String {
+        let hundreds = (triplet / 100 % 10) as usize;
+        let tens = (triplet / 10 % 10) as usize;
+        let units = (triplet % 10) as usize;
+
+        let mut prefix = String::new();
+        if hundreds > 0 {
+            prefix.push_str(COMBINING_HUNDREDS[hundreds - 1]);
+        }
+        if tens == 1 {
+            prefix.push_str(COMBINING_TEENS[units]);
+        } else {
+            if tens > 1 {
+                prefix.push_str(COMBINING_TENS[tens - 2]);
+            }
+            // a single thousand takes no prefix: tysięczny
+            if triplet != 1 {
+                prefix.push_str(COMBINING_UNITS[units]);
+            }
+        }
+        prefix
+    }
+}
+
+impl Language for Polish {
+    fn to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num.is_inf_pos() {
+            Ok(self.infinity(false))
+        } else if num.is_inf_neg() {
+            Ok(self.infinity(true))
+        } else if num.frac().is_zero() {
+            self.int_to_cardinal(num)
+        } else {
+            self.float_to_cardinal(num)
+        }
+    }
+
+    fn to_ordinal(&self, mut num: BigFloat) -> Result<String, Num2Err> {
+        // special case zero
+        if num.is_zero() {
+            return Ok(format!("{ORDINAL_ZERO_BASE}{}", self.ordinal_flexion(0)));
+        }
+
+        // handling negative values
+        let mut words = vec![];
+        if num.is_negative() {
+            words.push(String::from(MINUS));
+            num = -num;
+        }
+
+        let triplets = self.split_thousands(num);
+        let last_non_empty = triplets.iter().position(|&t| t != 0).unwrap();
+
+        // only the last group is ordinal, the ones before are cardinal:
+        // dwa tysiące czterdziesty drugi
+        let mut power = BigFloat::from(1);
+        for _ in 0..=last_non_empty {
+            power *= BigFloat::from(1000);
+        }
+        let leading = (num / power).int() * power;
+        if !leading.is_zero() {
+            words.push(Polish::default().int_to_cardinal(leading)?);
+        }
+
+        let triplet = triplets[last_non_empty];
+        if last_non_empty > 0 {
+            // dwutysięczny, milionowy
+            let base = if last_non_empty == 1 {
+                String::from(ORDINAL_THOUSAND_BASE)
+            } else {
+                let base = MEGA_BASES
+                    .get(last_non_empty - 2)
+                    .ok_or(Num2Err::CannotConvert)?;
+                format!("{}{}", base, ORDINAL_MEGA_SUFFIX)
+            };
+            words.push(format!(
+                "{}{}{}",
+                self.combining_prefix(triplet),
+                base,
+                self.ordinal_flexion(0)
+            ));
+            return Ok(words.join(" "));
+        }
+
+        let hundreds = (triplet / 100 % 10) as usize;
+        let tens = (triplet / 10 % 10) as usize;
+        let units = (triplet % 10) as usize;
+
+        if tens == 0 && units == 0 {
+            words.push(format!(
+                "{}{}",
+                ORDINAL_HUNDREDS_BASES[hundreds - 1],
+                self.ordinal_flexion(0)
+            ));
+            return Ok(words.join(" "));
+        }
+
+        if hundreds > 0 {
+            words.push(String::from(
+                HUNDREDS[hundreds - 1][Declension::Nominative.index()],
+            ));
+        }
+        if tens == 1 {
+            words.push(format!(
+                "{}{}",
+                ORDINAL_TEENS_BASES[units],
+                self.ordinal_flexion(0)
+            ));
+        } else {
+            if tens > 1 {
+                words.push(format!(
+                    "{}{}",
+                    ORDINAL_TENS_BASES[tens - 2],
+                    self.ordinal_flexion(0)
+                ));
+            }
+            if units > 0 {
+                words.push(format!(
+                    "{}{}",
+                    ORDINAL_UNIT_BASES[units - 1],
+                    self.ordinal_flexion(units)
+                ));
+            }
+        }
+
+        Ok(words.join(" "))
+    }
+
+    fn to_ordinal_num(&self, num: BigFloat) -> Result<String, Num2Err> {
+        Ok(format!("{}.", num.to_u128().unwrap()))
+    }
+
+    fn to_year(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if !num.frac().is_zero() {
+            return Err(Num2Err::FloatingYear);
+        }
+        if num.is_inf() {
+            return Err(Num2Err::InfiniteYear);
+        }
+
+        // years are read as ordinals agreeing with rok
+        let year_lang = self.masculine().singular();
+        if num.is_negative() {
+            Ok(format!("{} przed naszą erą", year_lang.to_ordinal(-num)?))
+        } else {
+            year_lang.to_ordinal(num)
+        }
+    }
+
+    fn to_currency(&self, num: BigFloat, currency: Currency) -> Result<String, Num2Err> {
+        if num.is_inf() {
+            let currency_lang = self.currency_properties(currency);
+            let target_lang = currency_lang.agreement_with_num(num);
+            Ok(format!(
+                "{} {}",
+                currency_lang.to_cardinal(num)?,
+                target_lang.currencies(currency)
+            ))
+        } else {
+            let whole = num.int();
+            let fraction = num.frac();
+            if fraction.is_zero() {
+                let currency_lang = self.currency_properties(currency);
+                let target_lang = currency_lang.agreement_with_num(whole);
+                Ok(format!(
+                    "{} {}",
+                    currency_lang.int_to_cardinal(whole)?,
+                    target_lang.currencies(currency)
+                ))
+            } else if whole.is_zero() && !self.zero_major {
+                self.currency_fraction_words(fraction, currency)
+            } else {
+                Ok(format!(
+                    "{} {}",
+                    self.to_currency(whole, currency)?,
+                    self.currency_fraction_words(fraction, currency)?,
+                ))
+            }
+        }
+    }
+
+    fn to_percent(&self, num: BigFloat) -> Result<String, Num2Err> {
+        // procent does not decline after a number: dwa procent
+        Ok(format!("{} procent", self.masculine().to_cardinal(num)?))
+    }
+
+    fn to_unit(&self, num: BigFloat, unit: Unit) -> Result<String, Num2Err> {
+        let unit_lang = self.unit_properties(unit);
+        let target_lang = unit_lang.agreement_with_num(num);
+        Ok(format!(
+            "{} {}",
+            unit_lang.to_cardinal(num)?,
+            target_lang.units(unit)
+        ))
+    }
+
+    fn plural_category(&self, num: BigFloat) -> PluralCategory {
+        if num.is_inf() {
+            // same agreement as zero: nieskończoność złotych
+            return PluralCategory::Many;
+        }
+        if !num.frac().is_zero() {
+            return PluralCategory::Other;
+        }
+        if num.abs() == BigFloat::from(1) {
+            return PluralCategory::One;
+        }
+        let tail = (num.abs() % BigFloat::from(100))
+            .to_u64()
+            .unwrap_or_default();
+        plural_category_with_units::<Self>((tail / 10) as usize, (tail % 10) as usize)
+    }
+
+    fn agreement(&self, num: BigFloat) -> Agreement {
+        self.counted_agreement(num)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Lang, Num2Words};
+
+    #[test]
+    fn test_cardinal() {
+        assert_eq!(
+            Num2Words::new(0).lang(Lang::Polish).to_words(),
+            Ok(String::from("zero"))
+        );
+        assert_eq!(
+            Num2Words::new(1).lang(Lang::Polish).prefer("f").to_words(),
+            Ok(String::from("jedna"))
+        );
+        assert_eq!(
+            Num2Words::new(2).lang(Lang::Polish).prefer("ż").to_words(),
+            Ok(String::from("dwie"))
+        );
+        assert_eq!(
+            Num2Words::new(42).lang(Lang::Polish).to_words(),
+            Ok(String::from("czterdzieści dwa"))
+        );
+        assert_eq!(
+            Num2Words::new(1000).lang(Lang::Polish).to_words(),
+            Ok(String::from("tysiąc"))
+        );
+        assert_eq!(
+            Num2Words::new(2000).lang(Lang::Polish).to_words(),
+            Ok(String::from("dwa tysiące"))
+        );
+        assert_eq!(
+            Num2Words::new(5000).lang(Lang::Polish).to_words(),
+            Ok(String::from("pięć tysięcy"))
+        );
+        assert_eq!(
+            Num2Words::new(21_000).lang(Lang::Polish).to_words(),
+            Ok(String::from("dwadzieścia jeden tysięcy"))
+        );
+        assert_eq!(
+            Num2Words::new(2_000_000_000).lang(Lang::Polish).to_words(),
+            Ok(String::from("dwa miliardy"))
+        );
+        assert_eq!(
+            Num2Words::new(918_654_321).lang(Lang::Polish).to_words(),
+            Ok(String::from(
+                "dziewięćset osiemnaście milionów sześćset pięćdziesiąt cztery tysiące trzysta dwadzieścia jeden"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_declension() {
+        assert_eq!(
+            Num2Words::new(1)
+                .lang(Lang::Polish)
+                .prefer("gen")
+                .to_words(),
+            Ok(String::from("jednego"))
+        );
+        assert_eq!(
+            Num2Words::new(1)
+                .lang(Lang::Polish)
+                .prefer("f")
+                .prefer("narzędnik")
+                .to_words(),
+            Ok(String::from("jedną"))
+        );
+        assert_eq!(
+            Num2Words::new(2)
+                .lang(Lang::Polish)
+                .prefer("f")
+                .prefer("ins")
+                .to_words(),
+            Ok(String::from("dwiema"))
+        );
+        assert_eq!(
+            Num2Words::new(5432)
+                .lang(Lang::Polish)
+                .prefer("dop")
+                .to_words(),
+            Ok(String::from("pięciu tysięcy czterystu trzydziestu dwóch"))
+        );
+        assert_eq!(
+            Num2Words::new(2_000_000)
+                .lang(Lang::Polish)
+                .prefer("loc")
+                .to_words(),
+            Ok(String::from("dwóch milionach"))
+        );
+        assert_eq!(
+            Num2Words::new(3_000_000_000u64)
+                .lang(Lang::Polish)
+                .prefer("loc")
+                .to_words(),
+            Ok(String::from("trzech miliardach"))
+        );
+    }
+
+    #[test]
+    fn test_agreement() {
+        assert_eq!(
+            plural_category_with_units::<Polish>(2, 2),
+            PluralCategory::Few
+        );
+        assert_eq!(
+            plural_category_with_units::<Polish>(1, 2),
+            PluralCategory::Many
+        );
+        assert_eq!(
+            plural_category_with_units::<Polish>(2, 1),
+            PluralCategory::Many
+        );
+        assert_eq!(
+            Polish::default().plural_category(BigFloat::from(1)),
+            PluralCategory::One
+        );
+        assert_eq!(
+            Polish::default().plural_category(BigFloat::from(101)),
+            PluralCategory::Many
+        );
+        assert_eq!(
+            Polish::default().agreement_with_category(PluralCategory::Many),
+            Polish {
+                number: GrammaticalNumber::Plural,
+                declension: Declension::Genitive,
+                ..Default::default()
+            }
+        );
+        assert_eq!(
+            Polish::default()
+                .set_declension(Declension::Dative)
+                .agreement_with_category(PluralCategory::Many),
+            Polish {
+                number: GrammaticalNumber::Plural,
+                declension: Declension::Dative,
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn test_ordinal() {
+        assert_eq!(
+            Num2Words::new(0).lang(Lang::Polish).ordinal().to_words(),
+            Ok(String::from("zerowy"))
+        );
+        assert_eq!(
+            Num2Words::new(1).lang(Lang::Polish).ordinal().to_words(),
+            Ok(String::from("pierwszy"))
+        );
+        assert_eq!(
+            Num2Words::new(3)
+                .lang(Lang::Polish)
+                .prefer("f")
+                .ordinal()
+                .to_words(),
+            Ok(String::from("trzecia"))
+        );
+        assert_eq!(
+            Num2Words::new(42).lang(Lang::Polish).ordinal().to_words(),
+            Ok(String::from("czterdziesty drugi"))
+        );
+        assert_eq!(
+            Num2Words::new(42)
+                .lang(Lang::Polish)
+                .prefer("gen")
+                .ordinal()
+                .to_words(),
+            Ok(String::from("czterdziestego drugiego"))
+        );
+        assert_eq!(
+            Num2Words::new(100).lang(Lang::Polish).ordinal().to_words(),
+            Ok(String::from("setny"))
+        );
+        assert_eq!(
+            Num2Words::new(2000).lang(Lang::Polish).ordinal().to_words(),
+            Ok(String::from("dwutysięczny"))
+        );
+        assert_eq!(
+            Num2Words::new(1_000_000)
+                .lang(Lang::Polish)
+                .ordinal()
+                .to_words(),
+            Ok(String::from("milionowy"))
+        );
+        assert_eq!(
+            Num2Words::new(2113)
+                .lang(Lang::Polish)
+                .prefer("n")
+                .ordinal()
+                .to_words(),
+            Ok(String::from("dwa tysiące sto trzynaste"))
+        );
+        assert_eq!(
+            Num2Words::new(42)
+                .lang(Lang::Polish)
+                .ordinal_num()
+                .to_words(),
+            Ok(String::from("42."))
+        );
+    }
+
+    #[test]
+    fn test_year() {
+        assert_eq!(
+            Num2Words::new(1999).lang(Lang::Polish).year().to_words(),
+            Ok(String::from(
+                "tysiąc dziewięćset dziewięćdziesiąty dziewiąty"
+            ))
+        );
+        assert_eq!(
+            Num2Words::new(2023)
+                .lang(Lang::Polish)
+                .prefer("loc")
+                .year()
+                .to_words(),
+            Ok(String::from("dwa tysiące dwudziestym trzecim"))
+        );
+        assert_eq!(
+            Num2Words::new(-44).lang(Lang::Polish).year().to_words(),
+            Ok(String::from("czterdziesty czwarty przed naszą erą"))
+        );
+    }
+
+    #[test]
+    fn test_currency() {
+        assert_eq!(
+            Num2Words::new(1)
+                .lang(Lang::Polish)
+                .currency(Currency::PLN)
+                .to_words(),
+            Ok(String::from("jeden złoty"))
+        );
+        assert_eq!(
+            Num2Words::new(22)
+                .lang(Lang::Polish)
+                .currency(Currency::PLN)
+                .to_words(),
+            Ok(String::from("dwadzieścia dwa złote"))
+        );
+        assert_eq!(
+            Num2Words::new(25)
+                .lang(Lang::Polish)
+                .currency(Currency::PLN)
+                .to_words(),
+            Ok(String::from("dwadzieścia pięć złotych"))
+        );
+        assert_eq!(
+            Num2Words::new(21)
+                .lang(Lang::Polish)
+                .currency(Currency::PLN)
+                .to_words(),
+            Ok(String::from("dwadzieścia jeden złotych"))
+        );
+        assert_eq!(
+            Num2Words::new(12)
+                .lang(Lang::Polish)
+                .currency(Currency::PLN)
+                .to_words(),
+            Ok(String::from("dwanaście złotych"))
+        );
+        assert_eq!(
+            Num2Words::new(42.02)
+                .lang(Lang::Polish)
+                .currency(Currency::PLN)
+                .to_words(),
+            Ok(String::from("czterdzieści dwa złote dwa grosze"))
+        );
+        assert_eq!(
+            Num2Words::new(0.5)
+                .lang(Lang::Polish)
+                .currency(Currency::PLN)
+                .to_words(),
+            Ok(String::from("pięćdziesiąt groszy"))
+        );
+        assert_eq!(
+            Num2Words::new(5)
+                .lang(Lang::Polish)
+                .prefer("ins")
+                .currency(Currency::PLN)
+                .to_words(),
+            Ok(String::from("pięcioma złotymi"))
+        );
+        assert_eq!(
+            Num2Words::new(1)
+                .lang(Lang::Polish)
+                .currency(Currency::EUR)
+                .to_words(),
+            Ok(String::from("jedno euro"))
+        );
+    }
+
+    #[test]
+    fn test_float() {
+        assert_eq!(
+            Num2Words::new(2.5).lang(Lang::Polish).to_words(),
+            Ok(String::from("dwie całe i pięć dziesiątych"))
+        );
+        assert_eq!(
+            Num2Words::new(1.1).lang(Lang::Polish).to_words(),
+            Ok(String::from("jedna cała i jedna dziesiąta"))
+        );
+        assert_eq!(
+            Num2Words::new(-0.25).lang(Lang::Polish).to_words(),
+            Ok(String::from("minus zero całych i dwadzieścia pięć setnych"))
+        );
+    }
+
+    #[test]
+    fn test_unit() {
+        assert_eq!(
+            Num2Words::new(2)
+                .lang(Lang::Polish)
+                .unit(Unit::Hour)
+                .to_words(),
+            Ok(String::from("dwie godziny"))
+        );
+        assert_eq!(
+            Num2Words::new(5)
+                .lang(Lang::Polish)
+                .unit(Unit::Meter)
+                .to_words(),
+            Ok(String::from("pięć metrów"))
+        );
+    }
+}
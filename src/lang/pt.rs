@@ -0,0 +1,827 @@
+use crate::{num2words::Num2Err, Currency, Language, PluralCategory, Unit};
+use num_bigfloat::BigFloat;
+
+pub struct Portuguese {
+    feminine: bool,
+    region: RegionPortuguese,
+    zero_major: bool,
+}
+
+#[derive(Default, Clone, Copy, PartialEq, Debug)]
+pub enum RegionPortuguese {
+    #[default]
+    PT,
+    BR,
+}
+
+const UNITS: [&str; 9] = [
+    "um", "dois", "três", "quatro", "cinco", "seis", "sete", "oito", "nove",
+];
+
+const TEENS: [&str; 10] = [
+    "dez",
+    "onze",
+    "doze",
+    "treze",
+    "catorze",
+    "quinze",
+    "dezasseis",
+    "dezassete",
+    "dezoito",
+    "dezanove",
+];
+
+const TENS: [&str; 9] = [
+    "dez",
+    "vinte",
+    "trinta",
+    "quarenta",
+    "cinquenta",
+    "sessenta",
+    "setenta",
+    "oitenta",
+    "noventa",
+];
+
+const HUNDREDS: [&str; 9] = [
+    "cento",
+    "duzentos",
+    "trezentos",
+    "quatrocentos",
+    "quinhentos",
+    "seiscentos",
+    "setecentos",
+    "oitocentos",
+    "novecentos",
+];
+
+// long scale, every group of six digits
+const MEGAS_PT: [&str; 6] = [
+    "milhão",
+    "bilião",
+    "trilião",
+    "quatrilião",
+    "quintilião",
+    "sextilião",
+];
+
+// short scale, every group of three digits
+const MEGAS_BR: [&str; 11] = [
+    "milhão",
+    "bilhão",
+    "trilhão",
+    "quatrilhão",
+    "quintilhão",
+    "sextilhão",
+    "septilhão",
+    "octilhão",
+    "nonilhão",
+    "decilhão",
+    "undecilhão",
+];
+
+const ORDINAL_UNITS: [&str; 9] = [
+    "primeiro", "segundo", "terceiro", "quarto", "quinto", "sexto", "sétimo", "oitavo", "nono",
+];
+
+const ORDINAL_TENS: [&str; 9] = [
+    "décimo",
+    "vigésimo",
+    "trigésimo",
+    "quadragésimo",
+    "quinquagésimo",
+    "sexagésimo",
+    "septuagésimo",
+    "octogésimo",
+    "nonagésimo",
+];
+
+const ORDINAL_HUNDREDS: [&str; 9] = [
+    "centésimo",
+    "ducentésimo",
+    "trecentésimo",
+    "quadringentésimo",
+    "quingentésimo",
+    "sexcentésimo",
+    "septingentésimo",
+    "octingentésimo",
+    "nongentésimo",
+];
+
+// A group of a thousand and its scale words, e.g. duzentos mil milhões
+struct Group {
+    count: u64,
+    mil: bool,
+    mega: Option<String>,
+}
+
+impl Portuguese {
+    pub fn new(feminine: bool, region: RegionPortuguese) -> Self {
+        Self {
+            feminine,
+            region,
+            zero_major: false,
+        }
+    }
+
+    /// Keeps the major unit clause for amounts below one, e.g. `zero reais e
+    /// vinte centavos`
+    pub fn zero_major(self, zero_major: bool) -> Self {
+        Self { zero_major, ..self }
+    }
+
+    fn currencies(&self, currency: Currency, plural_form: bool) -> String {
+        match currency {
+            Currency::DOLLAR => String::from(if plural_form { "dólares" } else { "dólar" }),
+            Currency::GBP => String::from("libra{}"),
+            Currency::INR => String::from("rupia{}"),
+            Currency::USD => String::from(if plural_form {
+                "dólares americanos"
+            } else {
+                "dólar americano"
+            }),
+            _ => currency.default_string(plural_form),
+        }
+        .replace("{}", if plural_form { "s" } else { "" })
+    }
+
+    fn feminine_currency(&self, currency: Currency) -> bool {
+        matches!(currency, Currency::GBP | Currency::INR)
+    }
+
+    fn cents(&self, currency: Currency, plural_form: bool) -> String {
+        match (currency, self.region) {
+            (Currency::EUR, RegionPortuguese::PT) => String::from("cêntimo{}"),
+            (Currency::GBP, _) => String::from(if plural_form { "pence" } else { "penny" }),
+            _ => currency.default_subunit_string("centavo{}", plural_form),
+        }
+        .replace("{}", if plural_form { "s" } else { "" })
+    }
+
+    fn units(&self, unit: Unit, plural_form: bool) -> String {
+        match (unit, self.region) {
+            (Unit::Meter, _) => "metro{}",
+            (Unit::Kilometer, RegionPortuguese::PT) => "quilómetro{}",
+            (Unit::Kilometer, RegionPortuguese::BR) => "quilômetro{}",
+            (Unit::Gram, _) => "grama{}",
+            (Unit::Kilogram, _) => "quilograma{}",
+            (Unit::Liter, _) => "litro{}",
+            (Unit::Second, _) => "segundo{}",
+            (Unit::Minute, _) => "minuto{}",
+            (Unit::Hour, _) => "hora{}",
+        }
+        .replace("{}", if plural_form { "s" } else { "" })
+    }
+
+    fn teens(&self, units: usize) -> &'static str {
+        match (units, self.region) {
+            (6, RegionPortuguese::BR) => "dezesseis",
+            (7, RegionPortuguese::BR) => "dezessete",
+            (9, RegionPortuguese::BR) => "dezenove",
+            _ => TEENS[units],
+        }
+    }
+
+    fn split_thousands(&self, mut num: BigFloat) -> Vec<u64> {
+        let mut thousands = Vec::new();
+        let bf_1000 = BigFloat::from(1000);
+
+        while !num.is_zero() {
+            thousands.push((num % bf_1000).to_u64().unwrap());
+            num /= bf_1000;
+        }
+
+        thousands
+    }
+
+    // Hundreds, tens and units joined with "e", e.g. cento e quarenta e dois
+    fn triplet_to_words(&self, triplet: u64, feminine: bool) -> String {
+        let hundreds = (triplet / 100) as usize;
+        let tens = (triplet / 10 % 10) as usize;
+        let units = (triplet % 10) as usize;
+
+        let mut words = vec![];
+        if hundreds == 1 && tens == 0 && units == 0 {
+            words.push(String::from("cem"));
+        } else if hundreds > 0 {
+            let hundred = HUNDREDS[hundreds - 1];
+            words.push(if feminine && hundreds > 1 {
+                format!("{}as", hundred.strip_suffix("os").unwrap())
+            } else {
+                String::from(hundred)
+            });
+        }
+
+        let unit = |units: usize| match (units, feminine) {
+            (1, true) => String::from("uma"),
+            (2, true) => String::from("duas"),
+            _ => String::from(UNITS[units - 1]),
+        };
+        match (tens, units) {
+            (0, 0) => (),
+            (0, _) => words.push(unit(units)),
+            (1, _) => words.push(String::from(self.teens(units))),
+            (_, 0) => words.push(String::from(TENS[tens - 1])),
+            _ => words.push(format!("{} e {}", TENS[tens - 1], unit(units))),
+        }
+
+        words.join(" e ")
+    }
+
+    // Groups of a thousand from the highest, with their scale words, e.g.
+    // dois milhões trezentos mil
+    fn groups(&self, num: BigFloat) -> Result<Vec<Group>, Num2Err> {
+        let triplets = self.split_thousands(num);
+        let filled = |i: usize| triplets.get(i).is_some_and(|t| *t != 0);
+        let mut groups = vec![];
+
+        for (i, triplet) in triplets.iter().enumerate().rev() {
+            if *triplet == 0 {
+                continue;
+            }
+
+            // in the long scale, odd groups are thousands of the group below,
+            // e.g. mil milhões
+            let (mil, mega) = match self.region {
+                RegionPortuguese::PT => (i % 2 == 1, (i / 2).checked_sub(1)),
+                RegionPortuguese::BR => (i == 1, i.checked_sub(2)),
+            };
+            let megas: &[&str] = match self.region {
+                RegionPortuguese::PT => &MEGAS_PT,
+                RegionPortuguese::BR => &MEGAS_BR,
+            };
+
+            let mega = match mega {
+                // the group below names the mega for both, e.g. mil e
+                // quinhentos milhões
+                Some(_) if mil && filled(i - 1) => None,
+                Some(mega) => {
+                    let mega = megas.get(mega).ok_or(Num2Err::CannotConvert)?;
+                    // only singular after exactly one, e.g. um milhão, but
+                    // mil milhões
+                    let long_mil = self.region == RegionPortuguese::PT && i % 2 == 0;
+                    if *triplet == 1 && !mil && !(long_mil && filled(i + 1)) {
+                        Some(String::from(*mega))
+                    } else {
+                        Some(format!("{}ões", mega.strip_suffix("ão").unwrap()))
+                    }
+                }
+                None => None,
+            };
+            groups.push(Group {
+                count: *triplet,
+                mil,
+                mega,
+            });
+        }
+
+        Ok(groups)
+    }
+
+    fn int_to_words(&self, mut num: BigFloat, feminine: bool) -> Result<String, Num2Err> {
+        // special case zero
+        if num.is_zero() {
+            return Ok(String::from("zero"));
+        }
+
+        // handling negative values
+        let mut words = vec![];
+        if num.is_negative() {
+            words.push(String::from("menos"));
+            num = -num;
+        }
+
+        let groups = self.groups(num)?;
+        let last = groups.len() - 1;
+        for (i, group) in groups.iter().enumerate() {
+            // only thousands and units agree with the noun, e.g. duzentas mil
+            let feminine = feminine && group.mega.is_none();
+            let mut parts = vec![];
+            if !group.mil || group.count != 1 {
+                parts.push(self.triplet_to_words(group.count, feminine));
+            }
+            if group.mil {
+                parts.push(String::from("mil"));
+            }
+            if let Some(mega) = &group.mega {
+                parts.push(mega.clone());
+            }
+
+            // the last group is joined with "e" when it is below a hundred or
+            // round hundreds, e.g. mil e cem, mil duzentos e trinta
+            if i == last && i != 0 && (group.count < 100 || group.count % 100 == 0) {
+                parts.insert(0, String::from("e"));
+            }
+            words.push(parts.join(" "));
+        }
+
+        Ok(words.join(" "))
+    }
+
+    fn int_to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        self.int_to_words(num, self.feminine)
+    }
+
+    fn float_to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let integral_part = num.int();
+        let mut words: Vec<String> = vec![];
+
+        if !integral_part.is_zero() {
+            let integral_word = self.int_to_cardinal(integral_part)?;
+            words.push(integral_word);
+        } else if num.is_negative() {
+            words.push(String::from("menos"));
+        }
+        if integral_part.is_zero() {
+            words.push(String::from("zero"));
+        }
+
+        let mut ordinal_part = num.frac().abs();
+        if !ordinal_part.is_zero() {
+            words.push(String::from("vírgula"));
+        }
+        while !ordinal_part.is_zero() {
+            let digit = (ordinal_part * BigFloat::from(10)).int();
+            ordinal_part = (ordinal_part * BigFloat::from(10)).frac();
+            words.push(match digit.to_u64().unwrap() {
+                0 => String::from("zero"),
+                i => String::from(UNITS[i as usize - 1]),
+            });
+        }
+        Ok(words.join(" "))
+    }
+
+    // A number before a noun, e.g. duas libras, um milhão de reais
+    fn with_noun(&self, num: BigFloat, noun: &str, feminine: bool) -> Result<String, Num2Err> {
+        let words = if num.frac().is_zero() {
+            self.int_to_words(num, feminine)?
+        } else {
+            self.float_to_cardinal(num)?
+        };
+
+        if words.ends_with("ão") || words.ends_with("ões") {
+            Ok(format!("{} de {}", words, noun))
+        } else {
+            Ok(format!("{} {}", words, noun))
+        }
+    }
+
+    // A scale ordinal counted by a cardinal, e.g. dois milésimo
+    fn scale_ordinal(&self, count: u64, scale: &str) -> Result<String, Num2Err> {
+        Ok(match count {
+            1 => String::from(scale),
+            _ => format!(
+                "{} {}",
+                self.int_to_words(BigFloat::from(count), self.feminine)?,
+                scale
+            ),
+        })
+    }
+
+    fn triplet_to_ordinal(&self, triplet: u64) -> Vec<&'static str> {
+        let hundreds = (triplet / 100) as usize;
+        let tens = (triplet / 10 % 10) as usize;
+        let units = (triplet % 10) as usize;
+
+        let mut words = vec![];
+        if hundreds > 0 {
+            words.push(ORDINAL_HUNDREDS[hundreds - 1]);
+        }
+        if tens > 0 {
+            words.push(ORDINAL_TENS[tens - 1]);
+        }
+        if units > 0 {
+            words.push(ORDINAL_UNITS[units - 1]);
+        }
+        words
+    }
+}
+
+// e.g. milhão => milionésimo, bilião and bilhão => bilionésimo
+fn mega_ordinal(mega: &str) -> String {
+    let stem = mega.strip_suffix("ão").unwrap();
+    format!("{}ionésimo", stem.trim_end_matches(&['h', 'i'][..]))
+}
+
+impl Language for Portuguese {
+    fn to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num.is_inf_pos() {
+            Ok(String::from("infinito"))
+        } else if num.is_inf_neg() {
+            Ok(String::from("menos infinito"))
+        } else if num.frac().is_zero() {
+            self.int_to_cardinal(num)
+        } else {
+            self.float_to_cardinal(num)
+        }
+    }
+
+    fn to_ordinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        // there is no ordinal of zero, see `Lang::Portuguese`
+        if num.is_zero() {
+            return Err(Num2Err::CannotConvert);
+        }
+
+        let triplets = self.split_thousands(num);
+        let mut words = vec![];
+        match self.region {
+            // long scale, e.g. milésimo milionésimo for mil milhões
+            RegionPortuguese::PT => {
+                for i in (1..triplets.len().div_ceil(2)).rev() {
+                    let high = triplets.get(2 * i + 1).copied().unwrap_or(0);
+                    let low = triplets[2 * i];
+                    if high == 0 && low == 0 {
+                        continue;
+                    }
+                    let mega = mega_ordinal(MEGAS_PT.get(i - 1).ok_or(Num2Err::CannotConvert)?);
+                    words.push(if low == 0 {
+                        self.scale_ordinal(high, &format!("milésimo {}", mega))?
+                    } else {
+                        self.scale_ordinal(high * 1000 + low, &mega)?
+                    });
+                }
+            }
+            // short scale, e.g. bilionésimo
+            RegionPortuguese::BR => {
+                for i in (2..triplets.len()).rev() {
+                    if triplets[i] == 0 {
+                        continue;
+                    }
+                    let mega = mega_ordinal(MEGAS_BR.get(i - 2).ok_or(Num2Err::CannotConvert)?);
+                    words.push(self.scale_ordinal(triplets[i], &mega)?);
+                }
+            }
+        }
+        if let Some(thousands) = triplets.get(1).filter(|t| **t != 0) {
+            words.push(self.scale_ordinal(*thousands, "milésimo")?);
+        }
+        words.extend(
+            self.triplet_to_ordinal(triplets[0])
+                .into_iter()
+                .map(String::from),
+        );
+
+        if self.feminine {
+            // every ordinal word agrees, e.g. quadragésima segunda
+            words = words
+                .into_iter()
+                .map(|w| {
+                    format!(
+                        "{}a",
+                        w.replace("ésimo ", "ésima ").strip_suffix('o').unwrap()
+                    )
+                })
+                .collect();
+        }
+
+        Ok(words.join(" "))
+    }
+
+    fn to_ordinal_num(&self, num: BigFloat) -> Result<String, Num2Err> {
+        Ok(format!(
+            "{}{}",
+            num.to_u128().unwrap(),
+            if self.feminine { "ª" } else { "º" }
+        ))
+    }
+
+    fn to_year(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num.is_negative() {
+            Ok(format!("{} a.C.", self.int_to_words(-num, false)?))
+        } else {
+            self.int_to_words(num, false)
+        }
+    }
+
+    fn to_currency(&self, num: BigFloat, currency: Currency) -> Result<String, Num2Err> {
+        if num.is_inf() {
+            Ok(format!(
+                "{} {}",
+                self.to_cardinal(num)?,
+                self.currencies(currency, true)
+            ))
+        } else if num.frac().is_zero() {
+            self.with_noun(
+                num,
+                &self.currencies(currency, self.plural_category(num) != PluralCategory::One),
+                self.feminine_currency(currency),
+            )
+        } else {
+            let integral_part = num.int();
+            let cents_nb = (num * BigFloat::from(100)).int() % BigFloat::from(100);
+            let cents_words = self.with_noun(
+                cents_nb,
+                &self.cents(
+                    currency,
+                    self.plural_category(cents_nb) != PluralCategory::One,
+                ),
+                false,
+            )?;
+            let integral_word = self.to_currency(integral_part, currency)?;
+
+            if cents_nb.is_zero() {
+                Ok(integral_word)
+            } else if integral_part.is_zero() && !self.zero_major {
+                Ok(cents_words)
+            } else {
+                Ok(format!("{} e {}", integral_word, cents_words))
+            }
+        }
+    }
+
+    fn to_percent(&self, num: BigFloat) -> Result<String, Num2Err> {
+        Ok(format!("{} por cento", self.to_cardinal(num)?))
+    }
+
+    fn to_unit(&self, num: BigFloat, unit: Unit) -> Result<String, Num2Err> {
+        let noun = self.units(unit, self.plural_category(num) != PluralCategory::One);
+        if num.is_inf() {
+            Ok(format!(
+                "{} {}",
+                self.to_cardinal(num)?,
+                self.units(unit, true)
+            ))
+        } else {
+            self.with_noun(num, &noun, matches!(unit, Unit::Hour))
+        }
+    }
+
+    fn plural_category(&self, num: BigFloat) -> PluralCategory {
+        if num.abs() == BigFloat::from(1) {
+            PluralCategory::One
+        } else {
+            PluralCategory::Other
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_cardinal() {
+        assert_eq!(
+            Num2Words::new(0).lang(Lang::Portuguese).to_words(),
+            Ok(String::from("zero"))
+        );
+        assert_eq!(
+            Num2Words::new(16).lang(Lang::Portuguese).to_words(),
+            Ok(String::from("dezasseis"))
+        );
+        assert_eq!(
+            Num2Words::new(42).lang(Lang::Portuguese).to_words(),
+            Ok(String::from("quarenta e dois"))
+        );
+        assert_eq!(
+            Num2Words::new(100).lang(Lang::Portuguese).to_words(),
+            Ok(String::from("cem"))
+        );
+        assert_eq!(
+            Num2Words::new(101).lang(Lang::Portuguese).to_words(),
+            Ok(String::from("cento e um"))
+        );
+        assert_eq!(
+            Num2Words::new(142).lang(Lang::Portuguese).to_words(),
+            Ok(String::from("cento e quarenta e dois"))
+        );
+        assert_eq!(
+            Num2Words::new(-2.5).lang(Lang::Portuguese).to_words(),
+            Ok(String::from("menos dois vírgula cinco"))
+        );
+        assert_eq!(
+            Num2Words::new(0.5).lang(Lang::Portuguese).to_words(),
+            Ok(String::from("zero vírgula cinco"))
+        );
+        assert_eq!(
+            Num2Words::new(-0.25).lang(Lang::Portuguese).to_words(),
+            Ok(String::from("menos zero vírgula dois cinco"))
+        );
+    }
+
+    #[test]
+    fn test_connector() {
+        assert_eq!(
+            Num2Words::new(1000).lang(Lang::Portuguese).to_words(),
+            Ok(String::from("mil"))
+        );
+        assert_eq!(
+            Num2Words::new(1100).lang(Lang::Portuguese).to_words(),
+            Ok(String::from("mil e cem"))
+        );
+        assert_eq!(
+            Num2Words::new(1230).lang(Lang::Portuguese).to_words(),
+            Ok(String::from("mil duzentos e trinta"))
+        );
+        assert_eq!(
+            Num2Words::new(2_000_020).lang(Lang::Portuguese).to_words(),
+            Ok(String::from("dois milhões e vinte"))
+        );
+    }
+
+    #[test]
+    fn test_large() {
+        assert_eq!(
+            Num2Words::new(1_000_000).lang(Lang::Portuguese).to_words(),
+            Ok(String::from("um milhão"))
+        );
+        assert_eq!(
+            Num2Words::new(1_000_000_000)
+                .lang(Lang::Portuguese)
+                .to_words(),
+            Ok(String::from("mil milhões"))
+        );
+        assert_eq!(
+            Num2Words::new(1_500_000_000)
+                .lang(Lang::Portuguese)
+                .to_words(),
+            Ok(String::from("mil e quinhentos milhões"))
+        );
+        assert_eq!(
+            Num2Words::new(1_000_000_000)
+                .lang(Lang::Portuguese_BR)
+                .to_words(),
+            Ok(String::from("um bilhão"))
+        );
+        assert_eq!(
+            Num2Words::new(1_000_000_000)
+                .lang(Lang::Portuguese)
+                .prefer("br")
+                .to_words(),
+            Ok(String::from("um bilhão"))
+        );
+        assert_eq!(
+            Num2Words::new(2_000_000_000_000i64)
+                .lang(Lang::Portuguese)
+                .to_words(),
+            Ok(String::from("dois biliões"))
+        );
+        assert_eq!(
+            Num2Words::new(16).lang(Lang::Portuguese_BR).to_words(),
+            Ok(String::from("dezesseis"))
+        );
+    }
+
+    #[test]
+    fn test_feminine() {
+        assert_eq!(
+            Num2Words::new(200)
+                .lang(Lang::Portuguese)
+                .prefer("f")
+                .to_words(),
+            Ok(String::from("duzentas"))
+        );
+        assert_eq!(
+            Num2Words::new(222_000)
+                .lang(Lang::Portuguese)
+                .prefer("f")
+                .to_words(),
+            Ok(String::from("duzentas e vinte e duas mil"))
+        );
+        assert_eq!(
+            Num2Words::new(2_000_000)
+                .lang(Lang::Portuguese)
+                .prefer("f")
+                .to_words(),
+            Ok(String::from("dois milhões"))
+        );
+    }
+
+    #[test]
+    fn test_ordinal() {
+        assert_eq!(
+            Num2Words::new(1)
+                .lang(Lang::Portuguese)
+                .ordinal()
+                .to_words(),
+            Ok(String::from("primeiro"))
+        );
+        assert_eq!(
+            Num2Words::new(42)
+                .lang(Lang::Portuguese)
+                .ordinal()
+                .to_words(),
+            Ok(String::from("quadragésimo segundo"))
+        );
+        assert_eq!(
+            Num2Words::new(42)
+                .lang(Lang::Portuguese)
+                .ordinal()
+                .prefer("f")
+                .to_words(),
+            Ok(String::from("quadragésima segunda"))
+        );
+        assert_eq!(
+            Num2Words::new(2024)
+                .lang(Lang::Portuguese)
+                .ordinal()
+                .to_words(),
+            Ok(String::from("dois milésimo vigésimo quarto"))
+        );
+        assert_eq!(
+            Num2Words::new(1_000_000)
+                .lang(Lang::Portuguese)
+                .ordinal()
+                .to_words(),
+            Ok(String::from("milionésimo"))
+        );
+        assert_eq!(
+            Num2Words::new(1_000_000_000)
+                .lang(Lang::Portuguese)
+                .ordinal()
+                .to_words(),
+            Ok(String::from("milésimo milionésimo"))
+        );
+        assert_eq!(
+            Num2Words::new(1_000_000_000)
+                .lang(Lang::Portuguese_BR)
+                .ordinal()
+                .to_words(),
+            Ok(String::from("bilionésimo"))
+        );
+        assert_eq!(
+            Num2Words::new(2_000_000_000_000_u64)
+                .lang(Lang::Portuguese)
+                .ordinal()
+                .prefer("f")
+                .to_words(),
+            Ok(String::from("duas bilionésima"))
+        );
+        assert_eq!(
+            Num2Words::new(3_000_002_000_u64)
+                .lang(Lang::Portuguese_BR)
+                .ordinal()
+                .to_words(),
+            Ok(String::from("três bilionésimo dois milésimo"))
+        );
+        assert_eq!(
+            Num2Words::new(0)
+                .lang(Lang::Portuguese)
+                .ordinal()
+                .to_words(),
+            Err(Num2Err::CannotConvert)
+        );
+        assert_eq!(
+            Num2Words::new(42)
+                .lang(Lang::Portuguese)
+                .ordinal_num()
+                .to_words(),
+            Ok(String::from("42º"))
+        );
+    }
+
+    #[test]
+    fn test_year() {
+        assert_eq!(
+            Num2Words::new(1984)
+                .lang(Lang::Portuguese)
+                .year()
+                .to_words(),
+            Ok(String::from("mil novecentos e oitenta e quatro"))
+        );
+        assert_eq!(
+            Num2Words::new(-44).lang(Lang::Portuguese).year().to_words(),
+            Ok(String::from("quarenta e quatro a.C."))
+        );
+    }
+
+    #[test]
+    fn test_currency() {
+        assert_eq!(
+            Num2Words::new(1)
+                .lang(Lang::Portuguese_BR)
+                .currency(Currency::BRL)
+                .to_words(),
+            Ok(String::from("um real"))
+        );
+        assert_eq!(
+            Num2Words::new(42.01)
+                .lang(Lang::Portuguese_BR)
+                .currency(Currency::BRL)
+                .to_words(),
+            Ok(String::from("quarenta e dois reais e um centavo"))
+        );
+        assert_eq!(
+            Num2Words::new(1_000_000)
+                .lang(Lang::Portuguese_BR)
+                .currency(Currency::BRL)
+                .to_words(),
+            Ok(String::from("um milhão de reais"))
+        );
+        assert_eq!(
+            Num2Words::new(2)
+                .lang(Lang::Portuguese)
+                .currency(Currency::GBP)
+                .to_words(),
+            Ok(String::from("duas libras"))
+        );
+        assert_eq!(
+            Num2Words::new(0.2)
+                .lang(Lang::Portuguese)
+                .currency(Currency::EUR)
+                .prefer("zero-major")
+                .to_words(),
+            Ok(String::from("zero euros e vinte cêntimos"))
+        );
+    }
+}
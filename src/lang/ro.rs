@@ -0,0 +1,590 @@
+use crate::{num2words::Num2Err, Currency, Gender, Language, PluralCategory, Unit};
+use num_bigfloat::BigFloat;
+
+// A counted noun follows "de" when the last two digits of the number are 00
+// or from 20 onwards, e.g. "douăzeci de lei" and "o sută de lei", but "o sută
+// unu lei". This is the CLDR "other" plural category of Romanian.
+
+#[derive(Clone, Copy)]
+pub struct Romanian {
+    feminine: bool,
+    zero_major: bool,
+}
+
+const UNITS: [&str; 9] = [
+    "unu", "doi", "trei", "patru", "cinci", "șase", "șapte", "opt", "nouă",
+];
+
+const TEENS: [&str; 10] = [
+    "zece",
+    "unsprezece",
+    "doisprezece",
+    "treisprezece",
+    "paisprezece",
+    "cincisprezece",
+    "șaisprezece",
+    "șaptesprezece",
+    "optsprezece",
+    "nouăsprezece",
+];
+
+const TENS: [&str; 8] = [
+    "douăzeci",
+    "treizeci",
+    "patruzeci",
+    "cincizeci",
+    "șaizeci",
+    "șaptezeci",
+    "optzeci",
+    "nouăzeci",
+];
+
+// (singular, plural), all of them neuter
+const MEGAS: [(&str, &str); 8] = [
+    ("milion", "milioane"),
+    ("miliard", "miliarde"),
+    ("bilion", "bilioane"),
+    ("biliard", "biliarde"),
+    ("trilion", "trilioane"),
+    ("triliard", "triliarde"),
+    ("cvadrilion", "cvadrilioane"),
+    ("cvadriliard", "cvadriliarde"),
+];
+
+// (singular, plural, gender) of a counted noun
+type Noun = (&'static str, &'static str, Gender);
+
+const LEU: Noun = ("leu", "lei", Gender::Masculine);
+const BAN: Noun = ("ban", "bani", Gender::Masculine);
+const EURO: Noun = ("euro", "euro", Gender::Masculine);
+const DOLAR: Noun = ("dolar", "dolari", Gender::Masculine);
+const LIRA: Noun = ("liră", "lire", Gender::Feminine);
+const CENT: Noun = ("cent", "cenți", Gender::Masculine);
+const PENNY: Noun = ("penny", "pence", Gender::Masculine);
+const PROCENT: Noun = ("procent", "procente", Gender::Neuter);
+
+impl Romanian {
+    pub fn new(feminine: bool) -> Self {
+        Self {
+            feminine,
+            zero_major: false,
+        }
+    }
+
+    /// Keeps the major unit clause for amounts below one, e.g. `zero lei și
+    /// douăzeci de bani`
+    pub fn zero_major(self, zero_major: bool) -> Self {
+        Self { zero_major, ..self }
+    }
+
+    fn gender(&self) -> Gender {
+        if self.feminine {
+            Gender::Feminine
+        } else {
+            Gender::Masculine
+        }
+    }
+
+    fn currencies(&self, currency: Currency) -> (String, String, Gender) {
+        let (singular, plural, gender) = match currency {
+            Currency::RON => LEU,
+            Currency::EUR => EURO,
+            Currency::AUD
+            | Currency::CAD
+            | Currency::DOLLAR
+            | Currency::HKD
+            | Currency::NZD
+            | Currency::SGD
+            | Currency::TWD
+            | Currency::USD => DOLAR,
+            Currency::GBP => LIRA,
+            _ => {
+                return (
+                    currency.default_string(false),
+                    currency.default_string(true),
+                    Gender::Masculine,
+                )
+            }
+        };
+        (String::from(singular), String::from(plural), gender)
+    }
+
+    fn cents(&self, currency: Currency) -> Noun {
+        match currency {
+            Currency::RON => BAN,
+            Currency::GBP => PENNY,
+            _ => CENT,
+        }
+    }
+
+    fn units(&self, unit: Unit) -> Noun {
+        match unit {
+            Unit::Meter => ("metru", "metri", Gender::Masculine),
+            Unit::Kilometer => ("kilometru", "kilometri", Gender::Masculine),
+            Unit::Gram => ("gram", "grame", Gender::Neuter),
+            Unit::Kilogram => ("kilogram", "kilograme", Gender::Neuter),
+            Unit::Liter => ("litru", "litri", Gender::Masculine),
+            Unit::Second => ("secundă", "secunde", Gender::Feminine),
+            Unit::Minute => ("minut", "minute", Gender::Neuter),
+            Unit::Hour => ("oră", "ore", Gender::Feminine),
+        }
+    }
+
+    fn split_thousands(&self, mut num: BigFloat) -> Vec<u64> {
+        let mut thousands = Vec::new();
+        let bf_1000 = BigFloat::from(1000);
+
+        while !num.is_zero() {
+            thousands.push((num % bf_1000).to_u64().unwrap());
+            num /= bf_1000;
+        }
+
+        thousands
+    }
+
+    // `counted` tells whether a noun follows, e.g. "un leu" but "unu"
+    fn unit_word(&self, units: usize, gender: Gender, counted: bool) -> &'static str {
+        match (units, gender, counted) {
+            (1, Gender::Feminine, true) => "o",
+            (1, _, true) => "un",
+            (1, Gender::Feminine, false) => "una",
+            (2, Gender::Masculine, _) => "doi",
+            (2, _, _) => "două",
+            _ => UNITS[units - 1],
+        }
+    }
+
+    fn triplet_words(&self, triplet: u64, gender: Gender, counted: bool) -> Vec<String> {
+        let hundreds = (triplet / 100) as usize;
+        let tens = (triplet / 10 % 10) as usize;
+        let units = (triplet % 10) as usize;
+        let mut words = vec![];
+
+        match hundreds {
+            0 => (),
+            1 => words.push(String::from("o sută")),
+            _ => words.push(format!(
+                "{} sute",
+                self.unit_word(hundreds, Gender::Feminine, true)
+            )),
+        }
+
+        match tens {
+            0 if units > 0 => {
+                // only a lone one is shortened before its noun
+                let counted = counted && hundreds == 0;
+                words.push(String::from(self.unit_word(units, gender, counted)));
+            }
+            0 => (),
+            1 if units == 2 && gender != Gender::Masculine => {
+                words.push(String::from("douăsprezece"))
+            }
+            1 => words.push(String::from(TEENS[units])),
+            _ => {
+                words.push(String::from(TENS[tens - 2]));
+                if units > 0 {
+                    words.push(String::from("și"));
+                    words.push(String::from(self.unit_word(units, gender, false)));
+                }
+            }
+        }
+
+        words
+    }
+
+    fn int_to_cardinal(
+        &self,
+        num: BigFloat,
+        gender: Gender,
+        counted: bool,
+    ) -> Result<String, Num2Err> {
+        // special case zero
+        if num.is_zero() {
+            return Ok(String::from("zero"));
+        }
+
+        let mut words = vec![];
+        for (i, triplet) in self.split_thousands(num.abs()).iter().enumerate().rev() {
+            if *triplet == 0 {
+                continue;
+            }
+
+            match i {
+                0 => words.extend(self.triplet_words(*triplet, gender, counted)),
+                1 if *triplet == 1 => words.push(String::from("o mie")),
+                _ => {
+                    let (singular, plural, gender) = match i {
+                        1 => ("mie", "mii", Gender::Feminine),
+                        _ => {
+                            let (singular, plural) =
+                                MEGAS.get(i - 2).ok_or(Num2Err::CannotConvert)?;
+                            (*singular, *plural, Gender::Neuter)
+                        }
+                    };
+                    if *triplet == 1 {
+                        words.push(format!("un {}", singular));
+                        continue;
+                    }
+                    words.extend(self.triplet_words(*triplet, gender, true));
+                    if takes_de(*triplet) {
+                        words.push(String::from("de"));
+                    }
+                    words.push(String::from(plural));
+                }
+            }
+        }
+
+        // handling negative values
+        if num.is_negative() {
+            words.insert(0, String::from("minus"));
+        }
+
+        Ok(words.join(" "))
+    }
+
+    fn float_to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let mut words = vec![];
+        if num.is_negative() {
+            words.push(String::from("minus"));
+        }
+        words.push(self.int_to_cardinal(num.int().abs(), self.gender(), false)?);
+        words.push(String::from("virgulă"));
+
+        let mut ordinal_part = num.frac().abs();
+        while !ordinal_part.is_zero() {
+            let digit = (ordinal_part * BigFloat::from(10)).int();
+            ordinal_part = (ordinal_part * BigFloat::from(10)).frac();
+            words.push(self.int_to_cardinal(digit, self.gender(), false)?);
+        }
+
+        Ok(words.join(" "))
+    }
+
+    // a number followed by its noun, e.g. "un leu", "doi lei", "douăzeci de
+    // lei"
+    fn with_noun(
+        &self,
+        num: BigFloat,
+        (singular, plural, gender): (&str, &str, Gender),
+    ) -> Result<String, Num2Err> {
+        if num.is_inf() {
+            return Ok(format!(
+                "{}o infinitate de {}",
+                if num.is_negative() { "minus " } else { "" },
+                plural
+            ));
+        }
+
+        let number = if num.frac().is_zero() {
+            self.int_to_cardinal(num, gender, true)?
+        } else {
+            Self {
+                feminine: gender == Gender::Feminine,
+                ..*self
+            }
+            .float_to_cardinal(num)?
+        };
+        Ok(match self.plural_category(num) {
+            PluralCategory::One => format!("{} {}", number, singular),
+            PluralCategory::Other => format!("{} de {}", number, plural),
+            _ => format!("{} {}", number, plural),
+        })
+    }
+}
+
+fn takes_de(num: u64) -> bool {
+    num.is_multiple_of(100) || num % 100 >= 20
+}
+
+// Suffixes the last word of a masculine ordinal, e.g. "doilea", "optulea"
+fn masculine_ordinal(word: &str) -> String {
+    match word {
+        "mie" | "mii" => String::from("miilea"),
+        _ if word.ends_with(['a', 'ă', 'e', 'i', 'u']) => format!("{}lea", word),
+        _ => format!("{}ulea", word),
+    }
+}
+
+// Suffixes the last word of a feminine ordinal, e.g. "doua", "cincea"
+fn feminine_ordinal(word: &str) -> String {
+    if word == "mie" || word == "mii" {
+        String::from("mia")
+    } else if word == "trei" {
+        String::from("treia")
+    } else if word == "una" {
+        String::from("una")
+    } else if let Some(stem) = word.strip_suffix('i') {
+        format!("{}ea", stem)
+    } else if let Some(stem) = word.strip_suffix(['ă', 'u']) {
+        format!("{}a", stem)
+    } else {
+        format!("{}a", word)
+    }
+}
+
+impl Language for Romanian {
+    fn to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num.is_inf_pos() {
+            Ok(String::from("infinit"))
+        } else if num.is_inf_neg() {
+            Ok(String::from("minus infinit"))
+        } else if num.frac().is_zero() {
+            self.int_to_cardinal(num, self.gender(), false)
+        } else {
+            self.float_to_cardinal(num)
+        }
+    }
+
+    fn to_ordinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num == BigFloat::from(1) {
+            return Ok(String::from(if self.feminine { "prima" } else { "primul" }));
+        }
+
+        let cardinal_word = self.int_to_cardinal(num, self.gender(), false)?;
+        let (rest, last) = match cardinal_word.rsplit_once(' ') {
+            Some((rest, last)) => (format!("{} ", rest), last),
+            None => (String::new(), cardinal_word.as_str()),
+        };
+
+        if self.feminine {
+            Ok(format!("a {}{}", rest, feminine_ordinal(last)))
+        } else {
+            Ok(format!("al {}{}", rest, masculine_ordinal(last)))
+        }
+    }
+
+    fn to_ordinal_num(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let num = num.to_u128().unwrap();
+        Ok(match (num, self.feminine) {
+            (1, false) => String::from("1-ul"),
+            (1, true) => String::from("1-a"),
+            (_, false) => format!("al {}-lea", num),
+            (_, true) => format!("a {}-a", num),
+        })
+    }
+
+    fn to_year(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if !num.frac().is_zero() {
+            return Err(Num2Err::FloatingYear);
+        }
+
+        let year_word = self.int_to_cardinal(num.abs(), Gender::Masculine, false)?;
+        if num.is_negative() {
+            Ok(format!("{} înainte de Hristos", year_word))
+        } else {
+            Ok(year_word)
+        }
+    }
+
+    fn to_currency(&self, num: BigFloat, currency: Currency) -> Result<String, Num2Err> {
+        if num.is_inf() || num.frac().is_zero() {
+            let (singular, plural, gender) = self.currencies(currency);
+            return self.with_noun(num, (&singular, &plural, gender));
+        }
+
+        let integral_part = num.int();
+        let cents_nb = (num * BigFloat::from(100)).int() % BigFloat::from(100);
+        let cents_words = self.with_noun(cents_nb, self.cents(currency))?;
+        let integral_word = self.to_currency(integral_part, currency)?;
+
+        if cents_nb.is_zero() {
+            Ok(integral_word)
+        } else if integral_part.is_zero() && !self.zero_major {
+            Ok(cents_words)
+        } else {
+            Ok(format!("{} și {}", integral_word, cents_words))
+        }
+    }
+
+    fn to_percent(&self, num: BigFloat) -> Result<String, Num2Err> {
+        self.with_noun(num, PROCENT)
+    }
+
+    fn to_unit(&self, num: BigFloat, unit: Unit) -> Result<String, Num2Err> {
+        self.with_noun(num, self.units(unit))
+    }
+
+    fn plural_category(&self, num: BigFloat) -> PluralCategory {
+        if num.abs() == BigFloat::from(1) {
+            return PluralCategory::One;
+        }
+        if !num.frac().is_zero() || num.is_zero() || num.is_inf() {
+            return PluralCategory::Few;
+        }
+        match (num.abs() % BigFloat::from(100)).to_u64() {
+            Some(tail) if tail > 0 && tail < 20 => PluralCategory::Few,
+            _ => PluralCategory::Other,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Currency, Lang, Num2Words, Unit};
+
+    #[test]
+    fn test_cardinal() {
+        for (num, words) in [
+            (0, "zero"),
+            (1, "unu"),
+            (2, "doi"),
+            (12, "doisprezece"),
+            (14, "paisprezece"),
+            (16, "șaisprezece"),
+            (21, "douăzeci și unu"),
+            (42, "patruzeci și doi"),
+            (100, "o sută"),
+            (101, "o sută unu"),
+            (212, "două sute doisprezece"),
+            (1000, "o mie"),
+            (2000, "două mii"),
+            (12000, "douăsprezece mii"),
+            (20000, "douăzeci de mii"),
+            (101000, "o sută una mii"),
+            (1000000, "un milion"),
+            (2000000, "două milioane"),
+            (21000000, "douăzeci și unu de milioane"),
+            (1000000000, "un miliard"),
+            (-7, "minus șapte"),
+        ] {
+            assert_eq!(
+                Num2Words::new(num).lang(Lang::Romanian).to_words(),
+                Ok(String::from(words))
+            );
+        }
+        assert_eq!(
+            Num2Words::new(42)
+                .lang(Lang::Romanian)
+                .prefer("f")
+                .to_words(),
+            Ok(String::from("patruzeci și două"))
+        );
+        assert_eq!(
+            Num2Words::new(1.5).lang(Lang::Romanian).to_words(),
+            Ok(String::from("unu virgulă cinci"))
+        );
+    }
+
+    #[test]
+    fn test_ordinal() {
+        for (num, masculine, feminine) in [
+            (1, "primul", "prima"),
+            (2, "al doilea", "a doua"),
+            (3, "al treilea", "a treia"),
+            (5, "al cincilea", "a cincea"),
+            (8, "al optulea", "a opta"),
+            (10, "al zecelea", "a zecea"),
+            (21, "al douăzeci și unulea", "a douăzeci și una"),
+            (42, "al patruzeci și doilea", "a patruzeci și doua"),
+            (100, "al o sutălea", "a o suta"),
+            (1000, "al o miilea", "a o mia"),
+        ] {
+            assert_eq!(
+                Num2Words::new(num)
+                    .lang(Lang::Romanian)
+                    .ordinal()
+                    .to_words(),
+                Ok(String::from(masculine))
+            );
+            assert_eq!(
+                Num2Words::new(num)
+                    .lang(Lang::Romanian)
+                    .prefer("feminin")
+                    .ordinal()
+                    .to_words(),
+                Ok(String::from(feminine))
+            );
+        }
+    }
+
+    #[test]
+    fn test_ordinal_num() {
+        assert_eq!(
+            Num2Words::new(42)
+                .lang(Lang::Romanian)
+                .ordinal_num()
+                .to_words(),
+            Ok(String::from("al 42-lea"))
+        );
+        assert_eq!(
+            Num2Words::new(42)
+                .lang(Lang::Romanian)
+                .prefer("f")
+                .ordinal_num()
+                .to_words(),
+            Ok(String::from("a 42-a"))
+        );
+        assert_eq!(
+            Num2Words::new(1)
+                .lang(Lang::Romanian)
+                .ordinal_num()
+                .to_words(),
+            Ok(String::from("1-ul"))
+        );
+    }
+
+    #[test]
+    fn test_year() {
+        assert_eq!(
+            Num2Words::new(2024).lang(Lang::Romanian).year().to_words(),
+            Ok(String::from("două mii douăzeci și patru"))
+        );
+        assert_eq!(
+            Num2Words::new(-44).lang(Lang::Romanian).year().to_words(),
+            Ok(String::from("patruzeci și patru înainte de Hristos"))
+        );
+    }
+
+    #[test]
+    fn test_currency() {
+        for (num, words) in [
+            (1.0, "un leu"),
+            (2.0, "doi lei"),
+            (19.0, "nouăsprezece lei"),
+            (20.0, "douăzeci de lei"),
+            (101.0, "o sută unu lei"),
+            (120.0, "o sută douăzeci de lei"),
+            (1000.0, "o mie de lei"),
+            (0.01, "un ban"),
+            (0.2, "douăzeci de bani"),
+            (2.5, "doi lei și cincizeci de bani"),
+        ] {
+            assert_eq!(
+                Num2Words::new(num)
+                    .lang(Lang::Romanian)
+                    .currency(Currency::RON)
+                    .to_words(),
+                Ok(String::from(words))
+            );
+        }
+        assert_eq!(
+            Num2Words::new(2)
+                .lang(Lang::Romanian)
+                .currency(Currency::GBP)
+                .to_words(),
+            Ok(String::from("două lire"))
+        );
+        assert_eq!(
+            Num2Words::new(f64::INFINITY)
+                .lang(Lang::Romanian)
+                .currency(Currency::RON)
+                .to_words(),
+            Ok(String::from("o infinitate de lei"))
+        );
+    }
+
+    #[test]
+    fn test_percent_and_unit() {
+        assert_eq!(
+            Num2Words::new(25).lang(Lang::Romanian).percent().to_words(),
+            Ok(String::from("douăzeci și cinci de procente"))
+        );
+        assert_eq!(
+            Num2Words::new(2)
+                .lang(Lang::Romanian)
+                .unit(Unit::Hour)
+                .to_words(),
+            Ok(String::from("două ore"))
+        );
+    }
+}
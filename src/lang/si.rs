@@ -0,0 +1,547 @@
+use crate::lang::NounOrder;
+use crate::{num2words::Num2Err, Currency, Language, PluralCategory, Unit};
+use num_bigfloat::BigFloat;
+
+pub struct Sinhala {
+    prefer_vaeni: bool,
+    zero_major: bool,
+}
+
+// Sinhala numerals have an independent form, used when the number stands on
+// its own, e.g. දෙක (2), and an attributive form, used before another word,
+// e.g. දෙ in දෙසියය (200)
+struct Numeral {
+    independent: String,
+    attributive: String,
+}
+
+impl Numeral {
+    fn new(independent: &str, attributive: &str) -> Self {
+        Self {
+            independent: String::from(independent),
+            attributive: String::from(attributive),
+        }
+    }
+}
+
+// (independent, attributive)
+const UNITS: [(&str, &str); 9] = [
+    ("එක", "එක්"),
+    ("දෙක", "දෙ"),
+    ("තුන", "තුන්"),
+    ("හතර", "හාර"),
+    ("පහ", "පස්"),
+    ("හය", "හය"),
+    ("හත", "හත්"),
+    ("අට", "අට"),
+    ("නවය", "නව"),
+];
+
+const TEENS: [(&str, &str); 10] = [
+    ("දහය", "දස"),
+    ("එකොළහ", "එකොළොස්"),
+    ("දොළහ", "දොළොස්"),
+    ("දහතුන", "දහතුන්"),
+    ("දහහතර", "දහහතර"),
+    ("පහළොව", "පහළොස්"),
+    ("දහසය", "දහසය"),
+    ("දහහත", "දහහත්"),
+    ("දහඅට", "දහඅට"),
+    ("දහනවය", "දහනව"),
+];
+
+const TENS: [(&str, &str); 8] = [
+    ("විස්ස", "විසි"),
+    ("තිහ", "තිස්"),
+    ("හතළිහ", "හතළිස්"),
+    ("පනහ", "පනස්"),
+    ("හැට", "හැට"),
+    ("හැත්තෑව", "හැත්තෑ"),
+    ("අසූව", "අසූ"),
+    ("අනූව", "අනූ"),
+];
+
+// prefixed to සියය, e.g. පන්සියය (500)
+const HUNDREDS: [&str; 9] = ["එක", "දෙ", "තුන්", "හාර", "පන්", "හය", "හත්", "අට", "නව"];
+
+const MEGAS: [(&str, &str); 9] = [
+    ("මිලියනය", "මිලියන"),
+    ("බිලියනය", "බිලියන"),
+    ("ට්‍රිලියනය", "ට්‍රිලියන"),
+    ("ක්වඩ්‍රිලියනය", "ක්වඩ්‍රිලියන"),
+    ("ක්වින්ටිලියනය", "ක්වින්ටිලියන"),
+    ("සෙක්ස්ටිලියනය", "සෙක්ස්ටිලියන"),
+    ("සෙප්ටිලියනය", "සෙප්ටිලියන"),
+    ("ඔක්ටිලියනය", "ඔක්ටිලියන"),
+    ("නොනිලියනය", "නොනිලියන"),
+];
+
+impl Sinhala {
+    pub fn new(prefer_vaeni: bool) -> Self {
+        Self {
+            prefer_vaeni,
+            zero_major: false,
+        }
+    }
+
+    /// Keeps the major unit clause for amounts below one, e.g. `රුපියල්
+    /// බිංදුවයි සත විස්සයි`
+    pub fn zero_major(self, zero_major: bool) -> Self {
+        Self { zero_major, ..self }
+    }
+
+    fn currencies(&self, currency: Currency) -> String {
+        match currency {
+            Currency::AUD => String::from("ඕස්ට්‍රේලියානු ඩොලර්"),
+            Currency::CAD => String::from("කැනේඩියානු ඩොලර්"),
+            Currency::DOLLAR => String::from("ඩොලර්"),
+            Currency::EUR => String::from("යුරෝ"),
+            Currency::GBP => String::from("පවුම්"),
+            Currency::INR => String::from("ඉන්දියානු රුපියල්"),
+            Currency::JPY => String::from("යෙන්"),
+            Currency::LKR => String::from("රුපියල්"),
+            Currency::USD => String::from("ඇමරිකානු ඩොලර්"),
+            _ => currency.default_string(false),
+        }
+    }
+
+    fn cents(&self, currency: Currency) -> String {
+        match currency {
+            Currency::GBP => String::from("පෙන්ස්"),
+            Currency::INR => String::from("පයිස"),
+            Currency::LKR => String::from("සත"),
+            _ => String::from("සෙන්ට්"),
+        }
+    }
+
+    fn units(&self, unit: Unit) -> &'static str {
+        match unit {
+            Unit::Meter => "මීටර්",
+            Unit::Kilometer => "කිලෝමීටර්",
+            Unit::Gram => "ග්‍රෑම්",
+            Unit::Kilogram => "කිලෝග්‍රෑම්",
+            Unit::Liter => "ලීටර්",
+            Unit::Second => "තත්පර",
+            Unit::Minute => "මිනිත්තු",
+            Unit::Hour => "පැය",
+        }
+    }
+
+    fn below_thousand(&self, num: u64) -> Vec<Numeral> {
+        let hundreds = (num / 100) as usize;
+        let tens = (num / 10 % 10) as usize;
+        let units = (num % 10) as usize;
+        let mut numerals = vec![];
+
+        if hundreds > 0 {
+            if hundreds == 1 && tens == 0 && units == 0 {
+                // a lone hundred is simply සියය
+                numerals.push(Numeral::new("සියය", "සිය"));
+            } else {
+                let prefix = HUNDREDS[hundreds - 1];
+                numerals.push(Numeral::new(
+                    &format!("{}සියය", prefix),
+                    &format!("{}සිය", prefix),
+                ));
+            }
+        }
+
+        match tens {
+            0 => {}
+            1 => {
+                let (independent, attributive) = TEENS[units];
+                numerals.push(Numeral::new(independent, attributive));
+                return numerals;
+            }
+            _ => {
+                let (independent, attributive) = TENS[tens - 2];
+                numerals.push(Numeral::new(independent, attributive));
+            }
+        }
+
+        if units > 0 {
+            let (independent, attributive) = UNITS[units - 1];
+            numerals.push(Numeral::new(independent, attributive));
+        }
+
+        numerals
+    }
+
+    fn int_to_numerals(&self, mut num: BigFloat) -> Result<Vec<Numeral>, Num2Err> {
+        let mut thousands = Vec::new();
+        let bf_1000 = BigFloat::from(1000);
+        while !num.is_zero() {
+            thousands.push((num % bf_1000).to_u64().unwrap());
+            num = (num / bf_1000).int();
+        }
+
+        let mut numerals = vec![];
+        for (i, triplet) in thousands.iter().enumerate().rev() {
+            if triplet == &0 {
+                continue;
+            }
+
+            match i {
+                0 => numerals.extend(self.below_thousand(*triplet)),
+                1 if triplet == &1 => numerals.push(Numeral::new("දහස", "දහස්")),
+                1 => {
+                    // the multiplier is joined to දහස, e.g. හතළිස් දෙදහස (42000)
+                    let mut multiplier = self.below_thousand(*triplet);
+                    let last = multiplier.pop().unwrap();
+                    numerals.extend(multiplier);
+                    numerals.push(Numeral::new(
+                        &format!("{}දහස", last.attributive),
+                        &format!("{}දහස්", last.attributive),
+                    ));
+                }
+                _ => {
+                    if i - 2 >= MEGAS.len() {
+                        return Err(Num2Err::CannotConvert);
+                    }
+                    numerals.extend(self.below_thousand(*triplet));
+                    let (independent, attributive) = MEGAS[i - 2];
+                    numerals.push(Numeral::new(independent, attributive));
+                }
+            }
+        }
+
+        Ok(numerals)
+    }
+
+    fn join(&self, numerals: &[Numeral], attributive: bool) -> String {
+        // every numeral but the last one takes its attributive form
+        numerals
+            .iter()
+            .enumerate()
+            .map(|(i, numeral)| {
+                if attributive || i + 1 < numerals.len() {
+                    numeral.attributive.as_str()
+                } else {
+                    numeral.independent.as_str()
+                }
+            })
+            .collect::<Vec<&str>>()
+            .join(" ")
+    }
+
+    fn int_to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        // special case zero
+        if num.is_zero() {
+            return Ok(String::from("බිංදුව"));
+        }
+
+        let words = self.join(&self.int_to_numerals(num.abs())?, false);
+        if num.is_negative() {
+            Ok(format!("ඍණ {}", words))
+        } else {
+            Ok(words)
+        }
+    }
+
+    fn float_to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let integral_part = num.int();
+        let mut words: Vec<String> = vec![];
+
+        if !integral_part.is_zero() {
+            let integral_word = self.int_to_cardinal(integral_part)?;
+            words.push(integral_word);
+        } else if num.is_negative() {
+            words.push(String::from("ඍණ"));
+        }
+        if integral_part.is_zero() {
+            words.push(String::from("බිංදුව"));
+        }
+
+        let mut ordinal_part = num.frac().abs();
+        if !ordinal_part.is_zero() {
+            words.push(String::from("දශම"));
+        }
+        while !ordinal_part.is_zero() {
+            let digit = (ordinal_part * BigFloat::from(10)).int();
+            ordinal_part = (ordinal_part * BigFloat::from(10)).frac();
+            words.push(match digit.to_u64().unwrap() {
+                0 => String::from("බිංදුව"),
+                i => String::from(UNITS[i as usize - 1].0),
+            });
+        }
+        Ok(words.join(" "))
+    }
+
+    fn ordinal_suffix(&self) -> &'static str {
+        if self.prefer_vaeni {
+            "වැනි"
+        } else {
+            "වන"
+        }
+    }
+}
+
+impl Language for Sinhala {
+    fn to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num.is_inf_pos() {
+            Ok(String::from("අනන්තය"))
+        } else if num.is_inf_neg() {
+            Ok(String::from("ඍණ අනන්තය"))
+        } else if num.frac().is_zero() {
+            self.int_to_cardinal(num)
+        } else {
+            self.float_to_cardinal(num)
+        }
+    }
+
+    fn to_ordinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num == BigFloat::from(1) {
+            return Ok(format!("පළමු{}", self.ordinal_suffix()));
+        } else if num.is_zero() {
+            return Ok(format!("බිංදු{}", self.ordinal_suffix()));
+        }
+
+        // the suffix is joined to the attributive form, e.g. හතළිස් දෙවන,
+        // except for four which keeps its independent form, e.g. හතරවන
+        let words = self.join(&self.int_to_numerals(num)?, true);
+        let stem = match words.strip_suffix("හාර") {
+            Some(stem) => format!("{}හතර", stem),
+            None => words,
+        };
+
+        Ok(format!("{}{}", stem, self.ordinal_suffix()))
+    }
+
+    fn to_ordinal_num(&self, num: BigFloat) -> Result<String, Num2Err> {
+        Ok(format!(
+            "{} {}",
+            num.to_u128().unwrap(),
+            self.ordinal_suffix()
+        ))
+    }
+
+    fn to_year(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if !num.frac().is_zero() {
+            return Err(Num2Err::FloatingYear);
+        }
+
+        let words = self.int_to_cardinal(num.abs())?;
+        if num.is_negative() {
+            Ok(format!("ක්‍රි.පූ. {}", words))
+        } else {
+            Ok(words)
+        }
+    }
+
+    fn to_currency(&self, num: BigFloat, currency: Currency) -> Result<String, Num2Err> {
+        // the currency comes first, e.g. රුපියල් හතළිස් දෙක, and both amounts
+        // take the conjunctive suffix යි when they are joined
+        let order = self.noun_order();
+        if num.is_inf() {
+            Ok(order.place(&self.to_cardinal(num)?, &self.currencies(currency)))
+        } else if num.frac().is_zero() {
+            let words = self.int_to_cardinal(num)?;
+            Ok(order.place(&words, &self.currencies(currency)))
+        } else {
+            let integral_part = num.int();
+            let cents_nb = (num * BigFloat::from(100)).int() % BigFloat::from(100);
+            let cents_words = self.int_to_cardinal(cents_nb)?;
+            let cents_suffix = self.cents(currency);
+
+            if cents_nb.is_zero() {
+                self.to_currency(integral_part, currency)
+            } else if integral_part.is_zero() && !self.zero_major {
+                Ok(order.place(&cents_words, &cents_suffix))
+            } else {
+                let integral_words = self.int_to_cardinal(integral_part)?;
+                Ok(format!(
+                    "{} {}",
+                    order.place(&format!("{}යි", integral_words), &self.currencies(currency)),
+                    order.place(&format!("{}යි", cents_words), &cents_suffix)
+                ))
+            }
+        }
+    }
+
+    fn to_percent(&self, num: BigFloat) -> Result<String, Num2Err> {
+        Ok(self.noun_order().place(&self.to_cardinal(num)?, "සියයට"))
+    }
+
+    fn to_unit(&self, num: BigFloat, unit: Unit) -> Result<String, Num2Err> {
+        Ok(self
+            .noun_order()
+            .place(&self.to_cardinal(num)?, self.units(unit)))
+    }
+
+    fn plural_category(&self, num: BigFloat) -> PluralCategory {
+        // CLDR: one is n = 0,1 or i = 0 and f = 1
+        if num.abs() <= BigFloat::from(1) {
+            PluralCategory::One
+        } else {
+            PluralCategory::Other
+        }
+    }
+
+    fn noun_order(&self) -> NounOrder {
+        NounOrder::NounFirst
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_cardinal() {
+        assert_eq!(
+            Num2Words::new(0).lang(Lang::Sinhala).to_words(),
+            Ok(String::from("බිංදුව"))
+        );
+        assert_eq!(
+            Num2Words::new(2).lang(Lang::Sinhala).to_words(),
+            Ok(String::from("දෙක"))
+        );
+        assert_eq!(
+            Num2Words::new(15).lang(Lang::Sinhala).to_words(),
+            Ok(String::from("පහළොව"))
+        );
+        assert_eq!(
+            Num2Words::new(42).lang(Lang::Sinhala).to_words(),
+            Ok(String::from("හතළිස් දෙක"))
+        );
+        assert_eq!(
+            Num2Words::new(-40).lang(Lang::Sinhala).to_words(),
+            Ok(String::from("ඍණ හතළිහ"))
+        );
+        assert_eq!(
+            Num2Words::new(200).lang(Lang::Sinhala).to_words(),
+            Ok(String::from("දෙසියය"))
+        );
+        assert_eq!(
+            Num2Words::new(1000).lang(Lang::Sinhala).to_words(),
+            Ok(String::from("දහස"))
+        );
+        assert_eq!(
+            Num2Words::new(2024).lang(Lang::Sinhala).to_words(),
+            Ok(String::from("දෙදහස් විසි හතර"))
+        );
+        assert_eq!(
+            Num2Words::new(42_000).lang(Lang::Sinhala).to_words(),
+            Ok(String::from("හතළිස් දෙදහස"))
+        );
+        assert_eq!(
+            Num2Words::new(3_000_105).lang(Lang::Sinhala).to_words(),
+            Ok(String::from("තුන් මිලියන එකසිය පහ"))
+        );
+        assert_eq!(
+            Num2Words::new(2.25).lang(Lang::Sinhala).to_words(),
+            Ok(String::from("දෙක දශම දෙක පහ"))
+        );
+        assert_eq!(
+            Num2Words::new(0.5).lang(Lang::Sinhala).to_words(),
+            Ok(String::from("බිංදුව දශම පහ"))
+        );
+        assert_eq!(
+            Num2Words::new(-0.25).lang(Lang::Sinhala).to_words(),
+            Ok(String::from("ඍණ බිංදුව දශම දෙක පහ"))
+        );
+    }
+
+    #[test]
+    fn test_ordinal() {
+        assert_eq!(
+            Num2Words::new(0).lang(Lang::Sinhala).ordinal().to_words(),
+            Ok(String::from("බිංදුවන"))
+        );
+        assert_eq!(
+            Num2Words::new(1).lang(Lang::Sinhala).ordinal().to_words(),
+            Ok(String::from("පළමුවන"))
+        );
+        assert_eq!(
+            Num2Words::new(2).lang(Lang::Sinhala).ordinal().to_words(),
+            Ok(String::from("දෙවන"))
+        );
+        assert_eq!(
+            Num2Words::new(4).lang(Lang::Sinhala).ordinal().to_words(),
+            Ok(String::from("හතරවන"))
+        );
+        assert_eq!(
+            Num2Words::new(42).lang(Lang::Sinhala).ordinal().to_words(),
+            Ok(String::from("හතළිස් දෙවන"))
+        );
+        assert_eq!(
+            Num2Words::new(42)
+                .lang(Lang::Sinhala)
+                .ordinal()
+                .prefer("වැනි")
+                .to_words(),
+            Ok(String::from("හතළිස් දෙවැනි"))
+        );
+        assert_eq!(
+            Num2Words::new(100).lang(Lang::Sinhala).ordinal().to_words(),
+            Ok(String::from("සියවන"))
+        );
+    }
+
+    #[test]
+    fn test_ordinal_num() {
+        assert_eq!(
+            Num2Words::new(42)
+                .lang(Lang::Sinhala)
+                .ordinal_num()
+                .to_words(),
+            Ok(String::from("42 වන"))
+        );
+    }
+
+    #[test]
+    fn test_year() {
+        assert_eq!(
+            Num2Words::new(1991).lang(Lang::Sinhala).year().to_words(),
+            Ok(String::from("දහස් නවසිය අනූ එක"))
+        );
+    }
+
+    #[test]
+    fn test_currency() {
+        assert_eq!(
+            Num2Words::new(42)
+                .lang(Lang::Sinhala)
+                .currency(Currency::LKR)
+                .to_words(),
+            Ok(String::from("රුපියල් හතළිස් දෙක"))
+        );
+        assert_eq!(
+            Num2Words::new(42.01)
+                .lang(Lang::Sinhala)
+                .currency(Currency::LKR)
+                .to_words(),
+            Ok(String::from("රුපියල් හතළිස් දෙකයි සත එකයි"))
+        );
+        assert_eq!(
+            Num2Words::new(0.5)
+                .lang(Lang::Sinhala)
+                .currency(Currency::LKR)
+                .to_words(),
+            Ok(String::from("සත පනහ"))
+        );
+        assert_eq!(
+            Num2Words::new(0.2)
+                .lang(Lang::Sinhala)
+                .currency(Currency::LKR)
+                .prefer("zero-major")
+                .to_words(),
+            Ok(String::from("රුපියල් බිංදුවයි සත විස්සයි"))
+        );
+    }
+
+    #[test]
+    fn test_percent_and_unit() {
+        assert_eq!(
+            Num2Words::new(25).lang(Lang::Sinhala).percent().to_words(),
+            Ok(String::from("සියයට විසි පහ"))
+        );
+        assert_eq!(
+            Num2Words::new(3)
+                .lang(Lang::Sinhala)
+                .unit(Unit::Hour)
+                .to_words(),
+            Ok(String::from("පැය තුන"))
+        );
+    }
+}
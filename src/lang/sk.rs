@@ -0,0 +1,1133 @@
+use crate::lang::slavic::{Declension, Gender, GrammaticalNumber, Slavic};
+use crate::{num2words::Num2Err, Agreement, Currency, Language, PluralCategory, Unit};
+use num_bigfloat::BigFloat;
+
+// Source: Pravidlá slovenského pravopisu
+// Číslovky sa píšu spolu (štyridsaťdva, dvetisíc), dlhé číslovky sa delia
+// po skupinách (dvetisíc päťstodvadsaťjeden). The vocative is not a case of
+// Slovak, the tables only have six columns.
+
+#[derive(Default, Clone, Copy, PartialEq, Debug)]
+pub struct Slovak {
+    gender: Gender,
+    number: GrammaticalNumber,
+    declension: Declension,
+    zero_major: bool,
+}
+
+const MINUS: &str = "mínus";
+
+const INFINITY: [&str; 6] = [
+    "nekonečno",
+    "nekonečna",
+    "nekonečnu",
+    "nekonečno",
+    "nekonečnom",
+    "nekonečne",
+];
+
+const ZERO: [&str; 6] = ["nula", "nuly", "nule", "nulu", "nulou", "nule"];
+
+const ORDINAL_ZERO_BASE: &str = "nult";
+
+#[rustfmt::skip]
+const GENDERED: [[[&str; 6]; 3]; 2] = [[
+    [ "jeden", "jedného", "jednému", "jeden", "jedným", "jednom" ],
+    [ "jedna", "jednej",  "jednej",  "jednu", "jednou", "jednej" ],
+    [ "jedno", "jedného", "jednému", "jedno", "jedným", "jednom" ],
+],
+[
+    [ "dva", "dvoch", "dvom", "dva", "dvoma", "dvoch" ],
+    [ "dve", "dvoch", "dvom", "dve", "dvoma", "dvoch" ],
+    [ "dve", "dvoch", "dvom", "dve", "dvoma", "dvoch" ],
+]];
+
+// one and two do not agree inside of a compound number: dvadsaťjeden eur
+const ONE_INVARIANT: &str = "jeden";
+const TWO_INVARIANT: &str = "dva";
+
+#[rustfmt::skip]
+const UNITS: [[&str; 6]; 7] = [
+    [ "tri",    "troch",    "trom",    "tri",    "tromi",    "troch"    ],
+    [ "štyri",  "štyroch",  "štyrom",  "štyri",  "štyrmi",   "štyroch"  ],
+    [ "päť",    "piatich",  "piatim",  "päť",    "piatimi",  "piatich"  ],
+    [ "šesť",   "šiestich", "šiestim", "šesť",   "šiestimi", "šiestich" ],
+    [ "sedem",  "siedmich", "siedmim", "sedem",  "siedmimi", "siedmich" ],
+    [ "osem",   "ôsmich",   "ôsmim",   "osem",   "ôsmimi",   "ôsmich"   ],
+    [ "deväť",  "deviatich", "deviatim", "deväť", "deviatimi", "deviatich" ],
+];
+
+#[rustfmt::skip]
+const TEENS: [[&str; 6]; 10] = [
+    [ "desať",     "desiatich",    "desiatim",    "desať",     "desiatimi",    "desiatich"    ],
+    [ "jedenásť",  "jedenástich",  "jedenástim",  "jedenásť",  "jedenástimi",  "jedenástich"  ],
+    [ "dvanásť",   "dvanástich",   "dvanástim",   "dvanásť",   "dvanástimi",   "dvanástich"   ],
+    [ "trinásť",   "trinástich",   "trinástim",   "trinásť",   "trinástimi",   "trinástich"   ],
+    [ "štrnásť",   "štrnástich",   "štrnástim",   "štrnásť",   "štrnástimi",   "štrnástich"   ],
+    [ "pätnásť",   "pätnástich",   "pätnástim",   "pätnásť",   "pätnástimi",   "pätnástich"   ],
+    [ "šestnásť",  "šestnástich",  "šestnástim",  "šestnásť",  "šestnástimi",  "šestnástich"  ],
+    [ "sedemnásť", "sedemnástich", "sedemnástim", "sedemnásť", "sedemnástimi", "sedemnástich" ],
+    [ "osemnásť",  "osemnástich",  "osemnástim",  "osemnásť",  "osemnástimi",  "osemnástich"  ],
+    [ "devätnásť", "devätnástich", "devätnástim", "devätnásť", "devätnástimi", "devätnástich" ],
+];
+
+#[rustfmt::skip]
+const TENS: [[&str; 6]; 8] = [
+    [ "dvadsať",     "dvadsiatich",     "dvadsiatim",     "dvadsať",     "dvadsiatimi",     "dvadsiatich"     ],
+    [ "tridsať",     "tridsiatich",     "tridsiatim",     "tridsať",     "tridsiatimi",     "tridsiatich"     ],
+    [ "štyridsať",   "štyridsiatich",   "štyridsiatim",   "štyridsať",   "štyridsiatimi",   "štyridsiatich"   ],
+    [ "päťdesiat",   "päťdesiatich",    "päťdesiatim",    "päťdesiat",   "päťdesiatimi",    "päťdesiatich"    ],
+    [ "šesťdesiat",  "šesťdesiatich",   "šesťdesiatim",   "šesťdesiat",  "šesťdesiatimi",   "šesťdesiatich"   ],
+    [ "sedemdesiat", "sedemdesiatich",  "sedemdesiatim",  "sedemdesiat", "sedemdesiatimi",  "sedemdesiatich"  ],
+    [ "osemdesiat",  "osemdesiatich",   "osemdesiatim",   "osemdesiat",  "osemdesiatimi",   "osemdesiatich"   ],
+    [ "deväťdesiat", "deväťdesiatich",  "deväťdesiatim",  "deväťdesiat", "deväťdesiatimi",  "deväťdesiatich"  ],
+];
+
+// the hundreds and the thousands do not decline
+const HUNDREDS: [&str; 9] = [
+    "sto",
+    "dvesto",
+    "tristo",
+    "štyristo",
+    "päťsto",
+    "šesťsto",
+    "sedemsto",
+    "osemsto",
+    "deväťsto",
+];
+
+const THOUSAND: &str = "tisíc";
+
+// milión is masculine, miliarda feminine
+const MEGA_BASES: [&str; 12] = [
+    "milión",
+    "miliard",
+    "bilión",
+    "biliard",
+    "trilión",
+    "triliard",
+    "kvadrilión",
+    "kvadriliard",
+    "kvintilión",
+    "kvintiliard",
+    "sextilión",
+    "sextiliard",
+];
+
+#[rustfmt::skip]
+const MEGA_MASCULINE_FLEXIONS: [[&str; 6]; 2] = [
+    [ "",  "a",  "u",  "",  "om", "e"  ],
+    [ "y", "ov", "om", "y", "mi", "och" ],
+];
+
+// the genitive plural lengthens the stem: miliárd
+#[rustfmt::skip]
+const MEGA_FEMININE_FLEXIONS: [[&str; 6]; 2] = [
+    [ "a", "y", "e",  "u", "ou",  "e"   ],
+    [ "y", "",  "am", "y", "ami", "ach" ],
+];
+
+/// How an ordinal declines, long endings are shortened after a long
+/// syllable: druhý but piaty
+#[derive(Clone, Copy)]
+enum Flexion {
+    Hard,
+    HardShort,
+    Soft,
+    SoftShort,
+}
+
+// the ordinals of the powers of a thousand are built as tisíci, miliónty
+const ORDINAL_THOUSAND_BASE: &str = "tisíc";
+const ORDINAL_MEGA_SUFFIX: &str = "t";
+
+const ORDINAL_UNIT_BASES: [(&str, Flexion); 9] = [
+    ("prv", Flexion::Hard),
+    ("druh", Flexion::Hard),
+    ("tret", Flexion::Soft),
+    ("štvrt", Flexion::Hard),
+    ("piat", Flexion::HardShort),
+    ("šiest", Flexion::HardShort),
+    ("siedm", Flexion::HardShort),
+    ("ôsm", Flexion::HardShort),
+    ("deviat", Flexion::HardShort),
+];
+
+const ORDINAL_TEENS_BASES: [&str; 10] = [
+    "desiat",
+    "jedenást",
+    "dvanást",
+    "trinást",
+    "štrnást",
+    "pätnást",
+    "šestnást",
+    "sedemnást",
+    "osemnást",
+    "devätnást",
+];
+
+const ORDINAL_TENS_BASES: [&str; 8] = [
+    "dvadsiat",
+    "tridsiat",
+    "štyridsiat",
+    "päťdesiat",
+    "šesťdesiat",
+    "sedemdesiat",
+    "osemdesiat",
+    "deväťdesiat",
+];
+
+const ORDINAL_HUNDREDS_BASES: [&str; 9] = [
+    "st",
+    "dvojst",
+    "trojst",
+    "štvorst",
+    "päťst",
+    "šesťst",
+    "sedemst",
+    "osemst",
+    "deväťst",
+];
+
+// prefixes compounding a count with a power of a thousand: dvojtisíci
+const COMBINING_UNITS: [&str; 10] = [
+    "", "jedno", "dvoj", "troj", "štvor", "päť", "šesť", "sedem", "osem", "deväť",
+];
+
+#[rustfmt::skip]
+const ADJECTIVE_HARD_FLEXIONS_SINGULAR: [[&str; 6]; 3] = [
+    [ "ý", "ého", "ému", "ý", "ým", "om" ],
+    [ "á", "ej",  "ej",  "ú", "ou", "ej" ],
+    [ "é", "ého", "ému", "é", "ým", "om" ],
+];
+
+// the plural forms are the non-masculine-personal ones
+const ADJECTIVE_HARD_FLEXIONS_PLURAL: [&str; 6] = ["é", "ých", "ým", "é", "ými", "ých"];
+
+#[rustfmt::skip]
+const ADJECTIVE_HARD_SHORT_FLEXIONS_SINGULAR: [[&str; 6]; 3] = [
+    [ "y", "eho", "emu", "y", "ym", "om" ],
+    [ "a", "ej",  "ej",  "u", "ou", "ej" ],
+    [ "e", "eho", "emu", "e", "ym", "om" ],
+];
+
+const ADJECTIVE_HARD_SHORT_FLEXIONS_PLURAL: [&str; 6] = ["e", "ych", "ym", "e", "ymi", "ych"];
+
+#[rustfmt::skip]
+const ADJECTIVE_SOFT_FLEXIONS_SINGULAR: [[&str; 6]; 3] = [
+    [ "í",  "ieho", "iemu", "í",  "ím", "om" ],
+    [ "ia", "ej",   "ej",   "iu", "ou", "ej" ],
+    [ "ie", "ieho", "iemu", "ie", "ím", "om" ],
+];
+
+const ADJECTIVE_SOFT_FLEXIONS_PLURAL: [&str; 6] = ["ie", "ích", "ím", "ie", "ími", "ích"];
+
+#[rustfmt::skip]
+const ADJECTIVE_SOFT_SHORT_FLEXIONS_SINGULAR: [[&str; 6]; 3] = [
+    [ "i", "eho", "emu", "i", "im", "om" ],
+    [ "a", "ej",  "ej",  "u", "ou", "ej" ],
+    [ "e", "eho", "emu", "e", "im", "om" ],
+];
+
+const ADJECTIVE_SOFT_SHORT_FLEXIONS_PLURAL: [&str; 6] = ["e", "ich", "im", "e", "imi", "ich"];
+
+#[rustfmt::skip]
+const EUROS: [[&str; 6]; 2] = [
+    [ "euro", "eura", "euru",  "euro", "eurom",  "eure"  ],
+    [ "eurá", "eur",  "eurám", "eurá", "eurami", "eurách" ],
+];
+
+#[rustfmt::skip]
+const CENTS: [[&str; 6]; 2] = [
+    [ "cent",  "centu",  "centu",  "cent",  "centom",  "cente"   ],
+    [ "centy", "centov", "centom", "centy", "centami", "centoch" ],
+];
+
+#[rustfmt::skip]
+const DOLLARS: [[&str; 6]; 2] = [
+    [ "dolár",  "dolára",  "doláru",  "dolár",  "dolárom", "dolári"   ],
+    [ "doláre", "dolárov", "dolárom", "doláre", "dolármi", "dolároch" ],
+];
+
+#[rustfmt::skip]
+const CROWNS: [[&str; 6]; 2] = [
+    [ "koruna", "koruny", "korune",  "korunu", "korunou",  "korune"   ],
+    [ "koruny", "korún",  "korunám", "koruny", "korunami", "korunách" ],
+];
+
+#[rustfmt::skip]
+const HALERS: [[&str; 6]; 2] = [
+    [ "halier",  "haliera",  "halieru",  "halier",  "halierom", "halieri"   ],
+    [ "haliere", "halierov", "halierom", "haliere", "haliermi", "halieroch" ],
+];
+
+#[rustfmt::skip]
+const PERCENTS: [[&str; 6]; 2] = [
+    [ "percento", "percenta", "percentu",  "percento", "percentom",  "percente"   ],
+    [ "percentá", "percent",  "percentám", "percentá", "percentami", "percentách" ],
+];
+
+#[rustfmt::skip]
+const METERS: [[&str; 6]; 2] = [
+    [ "meter", "metra",  "metru",  "meter", "metrom",  "metri"   ],
+    [ "metre", "metrov", "metrom", "metre", "metrami", "metroch" ],
+];
+
+#[rustfmt::skip]
+const GRAMS: [[&str; 6]; 2] = [
+    [ "gram",  "gramu",  "gramu",  "gram",  "gramom",  "grame"   ],
+    [ "gramy", "gramov", "gramom", "gramy", "gramami", "gramoch" ],
+];
+
+#[rustfmt::skip]
+const LITERS: [[&str; 6]; 2] = [
+    [ "liter", "litra",  "litru",  "liter", "litrom",  "litri"   ],
+    [ "litre", "litrov", "litrom", "litre", "litrami", "litroch" ],
+];
+
+#[rustfmt::skip]
+const SECONDS: [[&str; 6]; 2] = [
+    [ "sekunda", "sekundy", "sekunde",  "sekundu", "sekundou",  "sekunde"   ],
+    [ "sekundy", "sekúnd",  "sekundám", "sekundy", "sekundami", "sekundách" ],
+];
+
+#[rustfmt::skip]
+const MINUTES: [[&str; 6]; 2] = [
+    [ "minúta", "minúty", "minúte",  "minútu", "minútou",  "minúte"   ],
+    [ "minúty", "minút",  "minútam", "minúty", "minútami", "minútach" ],
+];
+
+#[rustfmt::skip]
+const HOURS: [[&str; 6]; 2] = [
+    [ "hodina", "hodiny", "hodine",  "hodinu", "hodinou",  "hodine"   ],
+    [ "hodiny", "hodín",  "hodinám", "hodiny", "hodinami", "hodinách" ],
+];
+
+// denominators of the decimal fractions with their genitive plural:
+// desatina, päť desatín
+const FRACTION_BASES: [(&str, &str); 6] = [
+    ("desatin", "desatín"),
+    ("stotin", "stotín"),
+    ("tisícin", "tisícin"),
+    ("desaťtisícin", "desaťtisícin"),
+    ("stotisícin", "stotisícin"),
+    ("milióntin", "milióntin"),
+];
+
+#[rustfmt::skip]
+const FRACTION_FLEXIONS: [[&str; 6]; 2] = [
+    [ "a", "y", "e",  "u", "ou",  "e"   ],
+    [ "y", "",  "am", "y", "ami", "ach" ],
+];
+
+impl Slavic for Slovak {
+    // only two to four themselves are counted in the plural, compound numbers
+    // are counted like five: dve eurá, dvadsaťdva eur
+    const TRAILING_ONE_IS_SINGULAR: bool = false;
+    const GENITIVE_GOVERNING: &'static [Declension] =
+        &[Declension::Nominative, Declension::Accusative];
+
+    const MINUS: &'static str = MINUS;
+    const ZERO: &'static [&'static str] = &ZERO;
+    const INFINITY: &'static [&'static str] = &INFINITY;
+    const ADJECTIVE_FLEXIONS_SINGULAR: [&'static [&'static str]; 3] = [
+        &ADJECTIVE_HARD_FLEXIONS_SINGULAR[0],
+        &ADJECTIVE_HARD_FLEXIONS_SINGULAR[1],
+        &ADJECTIVE_HARD_FLEXIONS_SINGULAR[2],
+    ];
+    const ADJECTIVE_FLEXIONS_PLURAL: &'static [&'static str] = &ADJECTIVE_HARD_FLEXIONS_PLURAL;
+
+    fn gender(&self) -> Gender {
+        self.gender
+    }
+    fn number(&self) -> GrammaticalNumber {
+        self.number
+    }
+    fn declension(&self) -> Declension {
+        self.declension
+    }
+    fn with(&self, gender: Gender, number: GrammaticalNumber, declension: Declension) -> Self {
+        Self {
+            gender,
+            number,
+            declension,
+            ..*self
+        }
+    }
+}
+
+impl Slovak {
+    pub fn new(gender: Gender, number: GrammaticalNumber, declension: Declension) -> Self {
+        Self {
+            gender,
+            number,
+            // Slovak has no vocative
+            declension: match declension {
+                Declension::Vocative => Declension::Nominative,
+                declension => declension,
+            },
+            zero_major: false,
+        }
+    }
+
+    /// Keeps the major unit clause for amounts below one, e.g. `nula eur
+    /// dvadsať centov`
+    pub fn zero_major(self, zero_major: bool) -> Self {
+        Self { zero_major, ..self }
+    }
+
+    fn currencies(&self, currency: Currency) -> String {
+        let number_idx = self.number.index();
+        let declension_idx = self.declension.index();
+        match currency {
+            Currency::AUD
+            | Currency::CAD
+            | Currency::DOLLAR
+            | Currency::HKD
+            | Currency::NZD
+            | Currency::SGD
+            | Currency::TWD
+            | Currency::USD => String::from(DOLLARS[number_idx][declension_idx]),
+            Currency::CZK => String::from(CROWNS[number_idx][declension_idx]),
+            Currency::EUR => String::from(EUROS[number_idx][declension_idx]),
+            _ => currency.default_string(self.is_plural()),
+        }
+    }
+
+    fn currency_properties(&self, currency: Currency) -> Slovak {
+        match currency {
+            Currency::CZK => self.feminine(),
+            Currency::EUR => self.neuter(),
+            _ => self.masculine(),
+        }
+    }
+
+    fn currency_fraction(&self, currency: Currency) -> String {
+        let number_idx = self.number.index();
+        let declension_idx = self.declension.index();
+        match currency {
+            Currency::AUD
+            | Currency::CAD
+            | Currency::DOLLAR
+            | Currency::EUR
+            | Currency::HKD
+            | Currency::NZD
+            | Currency::SGD
+            | Currency::TWD
+            | Currency::USD => String::from(CENTS[number_idx][declension_idx]),
+            Currency::CZK => String::from(HALERS[number_idx][declension_idx]),
+            _ => currency.default_subunit_string("cent{}", self.is_plural()),
+        }
+    }
+
+    fn currency_fraction_words(
+        &self,
+        fraction: BigFloat,
+        currency: Currency,
+    ) -> Result<String, Num2Err> {
+        let fraction = (fraction * BigFloat::from(100)).int();
+        let currency_lang = self.masculine();
+        let target_lang = currency_lang.agreement_with_num(fraction);
+        Ok(format!(
+            "{} {}",
+            currency_lang.int_to_cardinal(fraction)?,
+            target_lang.currency_fraction(currency)
+        ))
+    }
+
+    fn units(&self, unit: Unit) -> String {
+        let number_idx = self.number.index();
+        let declension_idx = self.declension.index();
+        match unit {
+            Unit::Meter => String::from(METERS[number_idx][declension_idx]),
+            Unit::Kilometer => format!("kilo{}", METERS[number_idx][declension_idx]),
+            Unit::Gram => String::from(GRAMS[number_idx][declension_idx]),
+            Unit::Kilogram => format!("kilo{}", GRAMS[number_idx][declension_idx]),
+            Unit::Liter => String::from(LITERS[number_idx][declension_idx]),
+            Unit::Second => String::from(SECONDS[number_idx][declension_idx]),
+            Unit::Minute => String::from(MINUTES[number_idx][declension_idx]),
+            Unit::Hour => String::from(HOURS[number_idx][declension_idx]),
+        }
+    }
+
+    fn unit_properties(&self, unit: Unit) -> Slovak {
+        match unit {
+            Unit::Second | Unit::Minute | Unit::Hour => self.feminine(),
+            _ => self.masculine(),
+        }
+    }
+
+    fn split_thousands(&self, mut num: BigFloat) -> Vec<u64> {
+        let mut thousands = Vec::new();
+        let bf_1000 = BigFloat::from(1000);
+
+        while !num.is_zero() {
+            thousands.push((num % bf_1000).to_u64().unwrap());
+            num /= bf_1000;
+        }
+
+        thousands
+    }
+
+    fn plural_category_of(&self, num: u64) -> PluralCategory {
+        match num {
+            1 => PluralCategory::One,
+            2..=4 => PluralCategory::Few,
+            _ => PluralCategory::Many,
+        }
+    }
+
+    // milióny are masculine, miliardy feminine
+    fn mega_gender(&self, order: usize) -> Gender {
+        if order.is_multiple_of(2) {
+            Gender::Masculine
+        } else {
+            Gender::Feminine
+        }
+    }
+
+    fn mega_word(&self, order: usize) -> Result<String, Num2Err> {
+        let number_idx = self.number.index();
+        let declension_idx = self.declension.index();
+        let base = MEGA_BASES.get(order - 2).ok_or(Num2Err::CannotConvert)?;
+        Ok(match self.mega_gender(order) {
+            Gender::Feminine if self.is_plural() && self.declension == Declension::Genitive => {
+                base.replace("ard", "árd")
+            }
+            Gender::Feminine => format!(
+                "{}{}",
+                base, MEGA_FEMININE_FLEXIONS[number_idx][declension_idx]
+            ),
+            _ => format!(
+                "{}{}",
+                base, MEGA_MASCULINE_FLEXIONS[number_idx][declension_idx]
+            ),
+        })
+    }
+
+    // the words of a number below a thousand, written as one word in the
+    // nominative and the accusative: stodvadsaťjeden, but sto dvadsiatich
+    // jedného
+    fn triplet_to_words(&self, triplet: u64, gender: Gender) -> String {
+        let declension_idx = self.declension.index();
+        let compound = matches!(
+            self.declension,
+            Declension::Nominative | Declension::Accusative
+        );
+        let hundreds = (triplet / 100 % 10) as usize;
+        let tens = (triplet / 10 % 10) as usize;
+        let units = (triplet % 10) as usize;
+
+        let mut words = vec![];
+        if hundreds > 0 {
+            words.push(HUNDREDS[hundreds - 1]);
+        }
+        if tens == 1 {
+            words.push(TEENS[units][declension_idx]);
+        } else {
+            if tens > 1 {
+                words.push(TENS[tens - 2][declension_idx]);
+            }
+            match units {
+                1 if compound && triplet != 1 => words.push(ONE_INVARIANT),
+                2 if compound && triplet != 2 => words.push(TWO_INVARIANT),
+                1 | 2 => words.push(GENDERED[units - 1][gender.index()][declension_idx]),
+                3..=9 => words.push(UNITS[units - 3][declension_idx]),
+                _ => (),
+            }
+        }
+
+        words.join(if compound { "" } else { " " })
+    }
+
+    fn int_to_cardinal(&self, mut num: BigFloat) -> Result<String, Num2Err> {
+        // special case zero
+        if num.is_zero() {
+            return Ok(String::from(self.zero()));
+        }
+
+        // handling negative values
+        let mut words = vec![];
+        if num.is_negative() {
+            words.push(String::from(MINUS));
+            num = -num;
+        }
+
+        // iterate over thousands
+        for (order, triplet) in self.split_thousands(num).iter().enumerate().rev() {
+            if *triplet == 0 {
+                continue;
+            }
+
+            match order {
+                0 => words.push(self.triplet_to_words(*triplet, self.gender)),
+                // the thousands stay as they are written: dvetisíc
+                1 if *triplet == 1 => words.push(String::from(THOUSAND)),
+                1 => words.push(format!(
+                    "{}{}",
+                    Slovak::default().triplet_to_words(*triplet, Gender::Feminine),
+                    THOUSAND
+                )),
+                _ => {
+                    let gender = self.mega_gender(order);
+                    let mega_lang = self.with(gender, GrammaticalNumber::Singular, self.declension);
+                    words.push(mega_lang.triplet_to_words(*triplet, gender));
+                    words.push(
+                        mega_lang
+                            .agreement_with_category(self.plural_category_of(*triplet))
+                            .mega_word(order)?,
+                    );
+                }
+            }
+        }
+
+        Ok(words.join(" "))
+    }
+
+    fn float_to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let whole = num.int();
+        let mut numerator = num.frac().abs();
+        if numerator.is_zero() {
+            return self.int_to_cardinal(whole);
+        }
+        let mut decimals = 0;
+        while !numerator.frac().is_zero() {
+            numerator *= BigFloat::from(10);
+            decimals += 1;
+        }
+        let (base, genitive_plural) = FRACTION_BASES
+            .get(decimals - 1)
+            .ok_or(Num2Err::CannotConvert)?;
+
+        // the numerals keep the requested case, only the nouns agree with
+        // them: dve celé päť desatín
+        let numeral_lang = self.feminine();
+        let whole_lang = numeral_lang.agreement_with_num(whole);
+        let denominator_lang = numeral_lang.agreement_with_num(numerator);
+        let denominator = if denominator_lang.is_plural()
+            && denominator_lang.declension == Declension::Genitive
+        {
+            String::from(*genitive_plural)
+        } else {
+            format!(
+                "{}{}",
+                base,
+                FRACTION_FLEXIONS[denominator_lang.number.index()]
+                    [denominator_lang.declension.index()]
+            )
+        };
+        Ok(format!(
+            "{}{} cel{} {} {}",
+            if whole.is_zero() && num.is_negative() {
+                "mínus "
+            } else {
+                ""
+            },
+            numeral_lang.int_to_cardinal(whole)?,
+            whole_lang.adjective_flexion(),
+            numeral_lang.int_to_cardinal(numerator)?,
+            denominator,
+        ))
+    }
+
+    fn ordinal_flexion(&self, flexion: Flexion) -> &'static str {
+        let declension_idx = self.declension.index();
+        let (singular, plural) = match flexion {
+            Flexion::Hard => (
+                &ADJECTIVE_HARD_FLEXIONS_SINGULAR,
+                &ADJECTIVE_HARD_FLEXIONS_PLURAL,
+            ),
+            Flexion::HardShort => (
+                &ADJECTIVE_HARD_SHORT_FLEXIONS_SINGULAR,
+                &ADJECTIVE_HARD_SHORT_FLEXIONS_PLURAL,
+            ),
+            Flexion::Soft => (
+                &ADJECTIVE_SOFT_FLEXIONS_SINGULAR,
+                &ADJECTIVE_SOFT_FLEXIONS_PLURAL,
+            ),
+            Flexion::SoftShort => (
+                &ADJECTIVE_SOFT_SHORT_FLEXIONS_SINGULAR,
+                &ADJECTIVE_SOFT_SHORT_FLEXIONS_PLURAL,
+            ),
+        };
+        if self.is_plural() {
+            plural[declension_idx]
+        } else {
+            singular[self.gender.index()][declension_idx]
+        }
+    }
+
+    fn combining_prefix(&self, triplet: u64) -> String {
+        // a single thousand takes no prefix: tisíci
+        if triplet == 1 {
+            return String::new();
+        }
+        if triplet < 10 {
+            return String::from(COMBINING_UNITS[triplet as usize]);
+        }
+        Slovak::default().triplet_to_words(triplet, Gender::Masculine)
+    }
+}
+
+impl Language for Slovak {
+    fn to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num.is_inf_pos() {
+            Ok(self.infinity(false))
+        } else if num.is_inf_neg() {
+            Ok(self.infinity(true))
+        } else if num.frac().is_zero() {
+            self.int_to_cardinal(num)
+        } else {
+            self.float_to_cardinal(num)
+        }
+    }
+
+    fn to_ordinal(&self, mut num: BigFloat) -> Result<String, Num2Err> {
+        // special case zero
+        if num.is_zero() {
+            return Ok(format!(
+                "{ORDINAL_ZERO_BASE}{}",
+                self.ordinal_flexion(Flexion::Hard)
+            ));
+        }
+
+        // handling negative values
+        let mut words = vec![];
+        if num.is_negative() {
+            words.push(String::from(MINUS));
+            num = -num;
+        }
+
+        let triplets = self.split_thousands(num);
+        let last_non_empty = triplets.iter().position(|&t| t != 0).unwrap();
+
+        // only the last group is ordinal, the ones before are cardinal:
+        // dvetisíc štyridsiaty druhý
+        let mut power = BigFloat::from(1);
+        for _ in 0..=last_non_empty {
+            power *= BigFloat::from(1000);
+        }
+        let leading = (num / power).int() * power;
+        if !leading.is_zero() {
+            words.push(Slovak::default().int_to_cardinal(leading)?);
+        }
+
+        let triplet = triplets[last_non_empty];
+        if last_non_empty > 0 {
+            // dvojtisíci, miliónty, miliardtý
+            let (base, flexion) = if last_non_empty == 1 {
+                (String::from(ORDINAL_THOUSAND_BASE), Flexion::SoftShort)
+            } else {
+                let base = MEGA_BASES
+                    .get(last_non_empty - 2)
+                    .ok_or(Num2Err::CannotConvert)?;
+                let flexion = match self.mega_gender(last_non_empty) {
+                    Gender::Masculine => Flexion::HardShort,
+                    _ => Flexion::Hard,
+                };
+                (format!("{}{}", base, ORDINAL_MEGA_SUFFIX), flexion)
+            };
+            words.push(format!(
+                "{}{}{}",
+                self.combining_prefix(triplet),
+                base,
+                self.ordinal_flexion(flexion)
+            ));
+            return Ok(words.join(" "));
+        }
+
+        let hundreds = (triplet / 100 % 10) as usize;
+        let tens = (triplet / 10 % 10) as usize;
+        let units = (triplet % 10) as usize;
+
+        if tens == 0 && units == 0 {
+            words.push(format!(
+                "{}{}",
+                ORDINAL_HUNDREDS_BASES[hundreds - 1],
+                self.ordinal_flexion(Flexion::Hard)
+            ));
+            return Ok(words.join(" "));
+        }
+
+        if hundreds > 0 {
+            words.push(String::from(HUNDREDS[hundreds - 1]));
+        }
+        if tens == 1 {
+            words.push(format!(
+                "{}{}",
+                ORDINAL_TEENS_BASES[units],
+                self.ordinal_flexion(Flexion::HardShort)
+            ));
+        } else {
+            if tens > 1 {
+                words.push(format!(
+                    "{}{}",
+                    ORDINAL_TENS_BASES[tens - 2],
+                    self.ordinal_flexion(Flexion::HardShort)
+                ));
+            }
+            if units > 0 {
+                let (base, flexion) = ORDINAL_UNIT_BASES[units - 1];
+                words.push(format!("{}{}", base, self.ordinal_flexion(flexion)));
+            }
+        }
+
+        Ok(words.join(" "))
+    }
+
+    fn to_ordinal_num(&self, num: BigFloat) -> Result<String, Num2Err> {
+        Ok(format!("{}.", num.to_u128().unwrap()))
+    }
+
+    fn to_year(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if !num.frac().is_zero() {
+            return Err(Num2Err::FloatingYear);
+        }
+        if num.is_inf() {
+            return Err(Num2Err::InfiniteYear);
+        }
+
+        // years are read as cardinals: dvetisícdvadsaťtri
+        let year_lang = Slovak::default();
+        if num.is_negative() {
+            Ok(format!(
+                "{} pred naším letopočtom",
+                year_lang.int_to_cardinal(-num)?
+            ))
+        } else {
+            year_lang.int_to_cardinal(num)
+        }
+    }
+
+    fn to_currency(&self, num: BigFloat, currency: Currency) -> Result<String, Num2Err> {
+        if num.is_inf() {
+            let currency_lang = self.currency_properties(currency);
+            let target_lang = currency_lang.agreement_with_num(num);
+            Ok(format!(
+                "{} {}",
+                currency_lang.to_cardinal(num)?,
+                target_lang.currencies(currency)
+            ))
+        } else {
+            let whole = num.int();
+            let fraction = num.frac();
+            if fraction.is_zero() {
+                let currency_lang = self.currency_properties(currency);
+                let target_lang = currency_lang.agreement_with_num(whole);
+                Ok(format!(
+                    "{} {}",
+                    currency_lang.int_to_cardinal(whole)?,
+                    target_lang.currencies(currency)
+                ))
+            } else if whole.is_zero() && !self.zero_major {
+                self.currency_fraction_words(fraction, currency)
+            } else {
+                Ok(format!(
+                    "{} {}",
+                    self.to_currency(whole, currency)?,
+                    self.currency_fraction_words(fraction, currency)?,
+                ))
+            }
+        }
+    }
+
+    fn to_percent(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let percent_lang = self.neuter();
+        let target_lang = percent_lang.agreement_with_num(num);
+        Ok(format!(
+            "{} {}",
+            percent_lang.to_cardinal(num)?,
+            PERCENTS[target_lang.number.index()][target_lang.declension.index()]
+        ))
+    }
+
+    fn to_unit(&self, num: BigFloat, unit: Unit) -> Result<String, Num2Err> {
+        let unit_lang = self.unit_properties(unit);
+        let target_lang = unit_lang.agreement_with_num(num);
+        Ok(format!(
+            "{} {}",
+            unit_lang.to_cardinal(num)?,
+            target_lang.units(unit)
+        ))
+    }
+
+    fn plural_category(&self, num: BigFloat) -> PluralCategory {
+        if num.is_inf() {
+            // same agreement as zero: nekonečno eur
+            return PluralCategory::Many;
+        }
+        if !num.frac().is_zero() {
+            return PluralCategory::Other;
+        }
+        match num.abs().to_u64() {
+            Some(num) => self.plural_category_of(num),
+            None => PluralCategory::Many,
+        }
+    }
+
+    fn agreement(&self, num: BigFloat) -> Agreement {
+        self.counted_agreement(num)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Lang, Num2Words};
+
+    #[test]
+    fn test_cardinal() {
+        assert_eq!(
+            Num2Words::new(0).lang(Lang::Slovak).to_words(),
+            Ok(String::from("nula"))
+        );
+        assert_eq!(
+            Num2Words::new(1).lang(Lang::Slovak).prefer("f").to_words(),
+            Ok(String::from("jedna"))
+        );
+        assert_eq!(
+            Num2Words::new(2)
+                .lang(Lang::Slovak)
+                .prefer("stredný")
+                .to_words(),
+            Ok(String::from("dve"))
+        );
+        assert_eq!(
+            Num2Words::new(42).lang(Lang::Slovak).to_words(),
+            Ok(String::from("štyridsaťdva"))
+        );
+        assert_eq!(
+            Num2Words::new(121).lang(Lang::Slovak).to_words(),
+            Ok(String::from("stodvadsaťjeden"))
+        );
+        assert_eq!(
+            Num2Words::new(1000).lang(Lang::Slovak).to_words(),
+            Ok(String::from("tisíc"))
+        );
+        assert_eq!(
+            Num2Words::new(2000).lang(Lang::Slovak).to_words(),
+            Ok(String::from("dvetisíc"))
+        );
+        assert_eq!(
+            Num2Words::new(21_521).lang(Lang::Slovak).to_words(),
+            Ok(String::from("dvadsaťjedentisíc päťstodvadsaťjeden"))
+        );
+        assert_eq!(
+            Num2Words::new(2_000_000).lang(Lang::Slovak).to_words(),
+            Ok(String::from("dva milióny"))
+        );
+        assert_eq!(
+            Num2Words::new(5_000_000_000u64)
+                .lang(Lang::Slovak)
+                .to_words(),
+            Ok(String::from("päť miliárd"))
+        );
+        assert_eq!(
+            Num2Words::new(2_000_000_000).lang(Lang::Slovak).to_words(),
+            Ok(String::from("dve miliardy"))
+        );
+    }
+
+    #[test]
+    fn test_declension() {
+        assert_eq!(
+            Num2Words::new(1)
+                .lang(Lang::Slovak)
+                .prefer("gen")
+                .to_words(),
+            Ok(String::from("jedného"))
+        );
+        assert_eq!(
+            Num2Words::new(42)
+                .lang(Lang::Slovak)
+                .prefer("genitív")
+                .to_words(),
+            Ok(String::from("štyridsiatich dvoch"))
+        );
+        assert_eq!(
+            Num2Words::new(3_000_000)
+                .lang(Lang::Slovak)
+                .prefer("loc")
+                .to_words(),
+            Ok(String::from("troch miliónoch"))
+        );
+        // the vocative falls back to the nominative
+        assert_eq!(
+            Num2Words::new(5)
+                .lang(Lang::Slovak)
+                .prefer("voc")
+                .to_words(),
+            Ok(String::from("päť"))
+        );
+    }
+
+    #[test]
+    fn test_agreement() {
+        assert_eq!(
+            Slovak::default().plural_category(BigFloat::from(3)),
+            PluralCategory::Few
+        );
+        assert_eq!(
+            Slovak::default().plural_category(BigFloat::from(22)),
+            PluralCategory::Many
+        );
+        assert_eq!(
+            Slovak::default().plural_category(BigFloat::from(21)),
+            PluralCategory::Many
+        );
+    }
+
+    #[test]
+    fn test_ordinal() {
+        assert_eq!(
+            Num2Words::new(1).lang(Lang::Slovak).ordinal().to_words(),
+            Ok(String::from("prvý"))
+        );
+        assert_eq!(
+            Num2Words::new(3)
+                .lang(Lang::Slovak)
+                .prefer("f")
+                .ordinal()
+                .to_words(),
+            Ok(String::from("tretia"))
+        );
+        assert_eq!(
+            Num2Words::new(5).lang(Lang::Slovak).ordinal().to_words(),
+            Ok(String::from("piaty"))
+        );
+        assert_eq!(
+            Num2Words::new(42).lang(Lang::Slovak).ordinal().to_words(),
+            Ok(String::from("štyridsiaty druhý"))
+        );
+        assert_eq!(
+            Num2Words::new(42)
+                .lang(Lang::Slovak)
+                .prefer("gen")
+                .ordinal()
+                .to_words(),
+            Ok(String::from("štyridsiateho druhého"))
+        );
+        assert_eq!(
+            Num2Words::new(100).lang(Lang::Slovak).ordinal().to_words(),
+            Ok(String::from("stý"))
+        );
+        assert_eq!(
+            Num2Words::new(2000).lang(Lang::Slovak).ordinal().to_words(),
+            Ok(String::from("dvojtisíci"))
+        );
+        assert_eq!(
+            Num2Words::new(1_000_000)
+                .lang(Lang::Slovak)
+                .ordinal()
+                .to_words(),
+            Ok(String::from("miliónty"))
+        );
+        assert_eq!(
+            Num2Words::new(42)
+                .lang(Lang::Slovak)
+                .ordinal_num()
+                .to_words(),
+            Ok(String::from("42."))
+        );
+    }
+
+    #[test]
+    fn test_year() {
+        assert_eq!(
+            Num2Words::new(2023).lang(Lang::Slovak).year().to_words(),
+            Ok(String::from("dvetisíc dvadsaťtri"))
+        );
+        assert_eq!(
+            Num2Words::new(-44).lang(Lang::Slovak).year().to_words(),
+            Ok(String::from("štyridsaťštyri pred naším letopočtom"))
+        );
+    }
+
+    #[test]
+    fn test_currency() {
+        assert_eq!(
+            Num2Words::new(1)
+                .lang(Lang::Slovak)
+                .currency(Currency::EUR)
+                .to_words(),
+            Ok(String::from("jedno euro"))
+        );
+        assert_eq!(
+            Num2Words::new(2)
+                .lang(Lang::Slovak)
+                .currency(Currency::EUR)
+                .to_words(),
+            Ok(String::from("dve eurá"))
+        );
+        assert_eq!(
+            Num2Words::new(5)
+                .lang(Lang::Slovak)
+                .currency(Currency::EUR)
+                .to_words(),
+            Ok(String::from("päť eur"))
+        );
+        assert_eq!(
+            Num2Words::new(22)
+                .lang(Lang::Slovak)
+                .currency(Currency::EUR)
+                .to_words(),
+            Ok(String::from("dvadsaťdva eur"))
+        );
+        assert_eq!(
+            Num2Words::new(0.01)
+                .lang(Lang::Slovak)
+                .currency(Currency::EUR)
+                .to_words(),
+            Ok(String::from("jeden cent"))
+        );
+        assert_eq!(
+            Num2Words::new(0.03)
+                .lang(Lang::Slovak)
+                .currency(Currency::EUR)
+                .to_words(),
+            Ok(String::from("tri centy"))
+        );
+        assert_eq!(
+            Num2Words::new(42.50)
+                .lang(Lang::Slovak)
+                .currency(Currency::EUR)
+                .to_words(),
+            Ok(String::from("štyridsaťdva eur päťdesiat centov"))
+        );
+        assert_eq!(
+            Num2Words::new(2)
+                .lang(Lang::Slovak)
+                .prefer("ins")
+                .currency(Currency::EUR)
+                .to_words(),
+            Ok(String::from("dvoma eurami"))
+        );
+    }
+
+    #[test]
+    fn test_float() {
+        assert_eq!(
+            Num2Words::new(2.5).lang(Lang::Slovak).to_words(),
+            Ok(String::from("dve celé päť desatín"))
+        );
+        assert_eq!(
+            Num2Words::new(1.1).lang(Lang::Slovak).to_words(),
+            Ok(String::from("jedna celá jedna desatina"))
+        );
+    }
+
+    #[test]
+    fn test_percent_and_unit() {
+        assert_eq!(
+            Num2Words::new(3).lang(Lang::Slovak).percent().to_words(),
+            Ok(String::from("tri percentá"))
+        );
+        assert_eq!(
+            Num2Words::new(5)
+                .lang(Lang::Slovak)
+                .unit(Unit::Hour)
+                .to_words(),
+            Ok(String::from("päť hodín"))
+        );
+    }
+}
@@ -0,0 +1,598 @@
+use crate::{
+    num2words::Num2Err, Agreement, Currency, Declension, Gender, GrammaticalNumber, Language,
+    PluralCategory, Unit,
+};
+use num_bigfloat::BigFloat;
+
+pub struct Slovenian {
+    gender: Option<Gender>,
+    zero_major: bool,
+}
+
+// forms after one, two, three or four, and five or more, e.g. "evro", "evra",
+// "evri", "evrov"
+type Noun = ([&'static str; 4], Gender);
+
+// counting forms, also used inside compounds, e.g. "dvaindvajset"
+const UNITS: [&str; 9] = [
+    "ena", "dva", "tri", "štiri", "pet", "šest", "sedem", "osem", "devet",
+];
+
+const TEENS: [&str; 10] = [
+    "deset",
+    "enajst",
+    "dvanajst",
+    "trinajst",
+    "štirinajst",
+    "petnajst",
+    "šestnajst",
+    "sedemnajst",
+    "osemnajst",
+    "devetnajst",
+];
+
+const TENS: [&str; 9] = [
+    "deset",
+    "dvajset",
+    "trideset",
+    "štirideset",
+    "petdeset",
+    "šestdeset",
+    "sedemdeset",
+    "osemdeset",
+    "devetdeset",
+];
+
+const HUNDREDS: [&str; 9] = [
+    "sto",
+    "dvesto",
+    "tristo",
+    "štiristo",
+    "petsto",
+    "šeststo",
+    "sedemsto",
+    "osemsto",
+    "devetsto",
+];
+
+// stems of the ordinals of the units, e.g. "prv" for "prvi"
+const ORDINAL_UNITS: [&str; 9] = [
+    "prv", "drug", "tretj", "četrt", "pet", "šest", "sedm", "osm", "devet",
+];
+
+// the thousand does not change, every other power is a counted noun
+const MEGAS: [Noun; 5] = [
+    (["tisoč", "tisoč", "tisoč", "tisoč"], Gender::Masculine),
+    (
+        ["milijon", "milijona", "milijoni", "milijonov"],
+        Gender::Masculine,
+    ),
+    (
+        ["milijarda", "milijardi", "milijarde", "milijard"],
+        Gender::Feminine,
+    ),
+    (
+        ["bilijon", "bilijona", "bilijoni", "bilijonov"],
+        Gender::Masculine,
+    ),
+    (
+        ["bilijarda", "bilijardi", "bilijarde", "bilijard"],
+        Gender::Feminine,
+    ),
+];
+
+// stems of the ordinals of the powers of a thousand, e.g. "milijonti"
+const ORDINAL_MEGAS: [&str; 5] = ["tisoč", "milijont", "milijardn", "bilijont", "bilijardn"];
+
+impl Slovenian {
+    pub fn new(gender: Option<Gender>) -> Self {
+        Self {
+            gender,
+            zero_major: false,
+        }
+    }
+
+    /// Keeps the major unit clause for amounts below one, e.g. `nič evrov in
+    /// dvajset centov`
+    pub fn zero_major(self, zero_major: bool) -> Self {
+        Self { zero_major, ..self }
+    }
+
+    fn with_gender(&self, gender: Gender) -> Self {
+        Self {
+            gender: Some(gender),
+            ..*self
+        }
+    }
+
+    fn currencies(&self, currency: Currency) -> Noun {
+        match currency {
+            Currency::EUR => (["evro", "evra", "evri", "evrov"], Gender::Masculine),
+            Currency::AUD
+            | Currency::CAD
+            | Currency::DOLLAR
+            | Currency::HKD
+            | Currency::NZD
+            | Currency::SGD
+            | Currency::TWD
+            | Currency::USD => (
+                ["dolar", "dolarja", "dolarji", "dolarjev"],
+                Gender::Masculine,
+            ),
+            Currency::GBP => (["funt", "funta", "funti", "funtov"], Gender::Masculine),
+            Currency::CHF => (["frank", "franka", "franki", "frankov"], Gender::Masculine),
+            _ => (["", "", "", ""], Gender::Masculine),
+        }
+    }
+
+    fn cents(&self, currency: Currency) -> Noun {
+        match currency {
+            Currency::GBP => (["peni", "penija", "peniji", "penijev"], Gender::Masculine),
+            _ => (["cent", "centa", "centi", "centov"], Gender::Masculine),
+        }
+    }
+
+    fn units(&self, unit: Unit) -> Noun {
+        match unit {
+            Unit::Meter => (["meter", "metra", "metri", "metrov"], Gender::Masculine),
+            Unit::Kilometer => (
+                ["kilometer", "kilometra", "kilometri", "kilometrov"],
+                Gender::Masculine,
+            ),
+            Unit::Gram => (["gram", "grama", "grami", "gramov"], Gender::Masculine),
+            Unit::Kilogram => (
+                ["kilogram", "kilograma", "kilogrami", "kilogramov"],
+                Gender::Masculine,
+            ),
+            Unit::Liter => (["liter", "litra", "litri", "litrov"], Gender::Masculine),
+            Unit::Second => (
+                ["sekunda", "sekundi", "sekunde", "sekund"],
+                Gender::Feminine,
+            ),
+            Unit::Minute => (["minuta", "minuti", "minute", "minut"], Gender::Feminine),
+            Unit::Hour => (["ura", "uri", "ure", "ur"], Gender::Feminine),
+        }
+    }
+
+    // index of the form of a noun counted by `num`
+    fn form(&self, num: BigFloat) -> usize {
+        match self.plural_category(num) {
+            PluralCategory::One => 0,
+            PluralCategory::Two => 1,
+            PluralCategory::Few => 2,
+            _ => 3,
+        }
+    }
+
+    fn split_thousands(&self, mut num: BigFloat) -> Vec<u64> {
+        let mut thousands = Vec::new();
+        let bf_1000 = BigFloat::from(1000);
+
+        while !num.is_zero() {
+            thousands.push((num % bf_1000).to_u64().unwrap());
+            num /= bf_1000;
+        }
+
+        thousands
+    }
+
+    // one to four agree with the gender of what they count when they stand
+    // alone, e.g. "en evro", "dve uri", "trije evri"
+    fn unit(&self, units: usize) -> &'static str {
+        match (units, self.gender) {
+            (1, Some(Gender::Masculine)) => "en",
+            (1, Some(Gender::Neuter)) => "eno",
+            (2, Some(Gender::Feminine | Gender::Neuter)) => "dve",
+            (3, Some(Gender::Masculine)) => "trije",
+            (4, Some(Gender::Masculine)) => "štirje",
+            _ => UNITS[units - 1],
+        }
+    }
+
+    fn triplet_to_words(&self, triplet: u64) -> Vec<String> {
+        let hundreds = (triplet / 100 % 10) as usize;
+        let tens = (triplet / 10 % 10) as usize;
+        let units = (triplet % 10) as usize;
+        let mut words = vec![];
+
+        if hundreds > 0 {
+            words.push(String::from(HUNDREDS[hundreds - 1]));
+        }
+        match (tens, units) {
+            (0, 0) => (),
+            (0, _) => words.push(String::from(self.unit(units))),
+            (1, _) => words.push(String::from(TEENS[units])),
+            (_, 0) => words.push(String::from(TENS[tens - 1])),
+            // the unit comes first, e.g. "dvainštirideset"
+            _ => words.push(format!("{}in{}", UNITS[units - 1], TENS[tens - 1])),
+        }
+
+        words
+    }
+
+    fn int_to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        // special case zero
+        if num.is_zero() {
+            return Ok(String::from("nič"));
+        }
+
+        let mut words = vec![];
+        for (i, triplet) in self.split_thousands(num.abs()).iter().enumerate().rev() {
+            if *triplet == 0 {
+                continue;
+            }
+            if i == 0 {
+                words.extend(self.triplet_to_words(*triplet));
+                continue;
+            }
+
+            let (forms, gender) = MEGAS.get(i - 1).ok_or(Num2Err::CannotConvert)?;
+            // the thousand is counted with the counting forms, e.g. "tri
+            // tisoč", and "tisoč" rather than "ena tisoč", but "en milijon"
+            let counter = if i == 1 {
+                Self::new(None)
+            } else {
+                self.with_gender(*gender)
+            };
+            if i != 1 || *triplet != 1 {
+                words.extend(counter.triplet_to_words(*triplet));
+            }
+            words.push(String::from(forms[self.form(BigFloat::from(*triplet))]));
+        }
+
+        // handling negative values
+        if num.is_negative() {
+            words.insert(0, String::from("minus"));
+        }
+
+        Ok(words.join(" "))
+    }
+
+    fn float_to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let mut words = vec![];
+        if num.is_negative() {
+            words.push(String::from("minus"));
+        }
+        words.push(self.int_to_cardinal(num.int().abs())?);
+        words.push(String::from("vejica"));
+
+        let mut ordinal_part = num.frac().abs();
+        while !ordinal_part.is_zero() {
+            let digit = (ordinal_part * BigFloat::from(10)).int();
+            ordinal_part = (ordinal_part * BigFloat::from(10)).frac();
+            words.push(Self::new(None).int_to_cardinal(digit)?);
+        }
+
+        Ok(words.join(" "))
+    }
+
+    fn cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num.is_inf_pos() {
+            Ok(String::from("neskončno"))
+        } else if num.is_inf_neg() {
+            Ok(String::from("minus neskončno"))
+        } else if num.frac().is_zero() {
+            self.int_to_cardinal(num)
+        } else {
+            self.float_to_cardinal(num)
+        }
+    }
+
+    // ending of an ordinal stem in the gender, e.g. "prvi", "prva", "prvo"
+    fn ordinal_ending(&self, stem: &str) -> &'static str {
+        match self.gender {
+            Some(Gender::Feminine) => "a",
+            // soft stems take -e, e.g. "tretje"
+            Some(Gender::Neuter) if stem.ends_with('j') || stem.ends_with('č') => "e",
+            Some(Gender::Neuter) => "o",
+            _ => "i",
+        }
+    }
+
+    // only the last word is an ordinal, e.g. "sto dvainštirideseti"
+    fn ordinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num.is_zero() {
+            return Ok(format!("ničt{}", self.ordinal_ending("ničt")));
+        }
+
+        let thousands = self.split_thousands(num.abs());
+        let last = thousands
+            .iter()
+            .position(|triplet| *triplet != 0)
+            .ok_or(Num2Err::CannotConvert)?;
+        let triplet = thousands[last];
+
+        // the whole number before the last group stays a cardinal
+        let bf_1000 = BigFloat::from(1000);
+        let mut scale = BigFloat::from(1);
+        for _ in 0..last {
+            scale *= bf_1000;
+        }
+        let head = num.abs() - num.abs() % (scale * bf_1000);
+        let mut words = vec![];
+        if !head.is_zero() {
+            words.push(Self::new(None).int_to_cardinal(head)?);
+        }
+
+        let stem = if last > 0 {
+            // powers of a thousand fuse with their count, e.g. "dvatisoči"
+            let stem = ORDINAL_MEGAS.get(last - 1).ok_or(Num2Err::CannotConvert)?;
+            let count = if triplet == 1 {
+                String::new()
+            } else {
+                Self::new(None).triplet_to_words(triplet).concat()
+            };
+            format!("{}{}", count, stem)
+        } else {
+            let hundreds = (triplet / 100 % 10) as usize;
+            let tens = (triplet / 10 % 10) as usize;
+            let units = (triplet % 10) as usize;
+
+            if hundreds > 0 && (tens > 0 || units > 0) {
+                words.push(String::from(HUNDREDS[hundreds - 1]));
+            }
+            match (tens, units) {
+                // "sto" to "stot", "dvesto" to "dvestot"
+                (0, 0) => format!("{}t", HUNDREDS[hundreds - 1]),
+                (0, _) => String::from(ORDINAL_UNITS[units - 1]),
+                (1, _) => String::from(TEENS[units]),
+                (_, 0) => String::from(TENS[tens - 1]),
+                _ => format!("{}in{}", UNITS[units - 1], TENS[tens - 1]),
+            }
+        };
+
+        words.push(format!("{}{}", stem, self.ordinal_ending(&stem)));
+        Ok(words.join(" "))
+    }
+
+    fn with_noun(&self, num: BigFloat, (forms, gender): Noun) -> Result<String, Num2Err> {
+        if num.is_inf() {
+            return Ok(format!("{} {}", self.cardinal(num)?, forms[3]));
+        }
+
+        Ok(format!(
+            "{} {}",
+            self.with_gender(gender).cardinal(num)?,
+            forms[self.form(num)]
+        ))
+    }
+}
+
+impl Language for Slovenian {
+    fn to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        self.cardinal(num)
+    }
+
+    fn to_ordinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        self.ordinal(num)
+    }
+
+    fn to_ordinal_num(&self, num: BigFloat) -> Result<String, Num2Err> {
+        Ok(format!("{}.", num.to_u128().unwrap()))
+    }
+
+    fn to_year(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if !num.frac().is_zero() {
+            return Err(Num2Err::FloatingYear);
+        }
+
+        let year_word = Self::new(None).int_to_cardinal(num.abs())?;
+        if num.is_negative() {
+            Ok(format!("{} pred našim štetjem", year_word))
+        } else {
+            Ok(year_word)
+        }
+    }
+
+    fn to_currency(&self, num: BigFloat, currency: Currency) -> Result<String, Num2Err> {
+        let (forms, gender) = self.currencies(currency);
+        let forms = if forms[0].is_empty() {
+            [
+                currency.default_string(false),
+                currency.default_string(true),
+                currency.default_string(true),
+                currency.default_string(true),
+            ]
+        } else {
+            forms.map(String::from)
+        };
+        let major = |num: BigFloat| -> Result<String, Num2Err> {
+            if num.is_inf() {
+                return Ok(format!("{} {}", self.cardinal(num)?, forms[3]));
+            }
+            Ok(format!(
+                "{} {}",
+                self.with_gender(gender).cardinal(num)?,
+                forms[self.form(num)]
+            ))
+        };
+
+        if num.is_inf() || num.frac().is_zero() {
+            return major(num);
+        }
+
+        let integral_part = num.int();
+        let cents_nb = (num * BigFloat::from(100)).int() % BigFloat::from(100);
+        let cents_words = self.with_noun(cents_nb, self.cents(currency))?;
+        let integral_word = major(integral_part)?;
+
+        if cents_nb.is_zero() {
+            Ok(integral_word)
+        } else if integral_part.is_zero() && !self.zero_major {
+            Ok(cents_words)
+        } else {
+            Ok(format!("{} in {}", integral_word, cents_words))
+        }
+    }
+
+    fn to_percent(&self, num: BigFloat) -> Result<String, Num2Err> {
+        self.with_noun(
+            num,
+            (
+                ["odstotek", "odstotka", "odstotki", "odstotkov"],
+                Gender::Masculine,
+            ),
+        )
+    }
+
+    fn to_unit(&self, num: BigFloat, unit: Unit) -> Result<String, Num2Err> {
+        self.with_noun(num, self.units(unit))
+    }
+
+    fn plural_category(&self, num: BigFloat) -> PluralCategory {
+        if !num.frac().is_zero() || num.is_inf() {
+            return PluralCategory::Few;
+        }
+
+        match (num.abs() % BigFloat::from(100)).to_u64().unwrap_or(0) {
+            1 => PluralCategory::One,
+            2 => PluralCategory::Two,
+            3 | 4 => PluralCategory::Few,
+            _ => PluralCategory::Other,
+        }
+    }
+
+    fn agreement(&self, num: BigFloat) -> Agreement {
+        let category = self.plural_category(num);
+        Agreement {
+            number: match category {
+                PluralCategory::One => GrammaticalNumber::Singular,
+                PluralCategory::Two => GrammaticalNumber::Dual,
+                _ => GrammaticalNumber::Plural,
+            },
+            gender: self.gender,
+            // five and more take the genitive plural, e.g. "pet evrov"
+            case: Some(match category {
+                PluralCategory::Other => Declension::Genitive,
+                _ => Declension::Nominative,
+            }),
+            category,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Currency, Lang, Num2Words, Unit};
+
+    #[test]
+    fn test_cardinal() {
+        for (num, words) in [
+            (0, "nič"),
+            (1, "ena"),
+            (21, "enaindvajset"),
+            (42, "dvainštirideset"),
+            (142, "sto dvainštirideset"),
+            (1000, "tisoč"),
+            (2000, "dva tisoč"),
+            (3000, "tri tisoč"),
+            (1000000, "en milijon"),
+            (2000000, "dva milijona"),
+            (3000000, "trije milijoni"),
+            (5000000, "pet milijonov"),
+            (2000000000, "dve milijardi"),
+            (-5, "minus pet"),
+        ] {
+            assert_eq!(
+                Num2Words::new(num).lang(Lang::Slovenian).to_words(),
+                Ok(String::from(words))
+            );
+        }
+        assert_eq!(
+            Num2Words::new(2)
+                .lang(Lang::Slovenian)
+                .prefer("f")
+                .to_words(),
+            Ok(String::from("dve"))
+        );
+    }
+
+    #[test]
+    fn test_ordinal() {
+        let ordinal = |num: i64, gender| {
+            Num2Words::new(num)
+                .lang(Lang::Slovenian)
+                .prefer(gender)
+                .ordinal()
+                .to_words()
+        };
+
+        for (num, masculine, feminine, neuter) in [
+            (0, "ničti", "ničta", "ničto"),
+            (1, "prvi", "prva", "prvo"),
+            (3, "tretji", "tretja", "tretje"),
+            (
+                42,
+                "dvainštirideseti",
+                "dvainštirideseta",
+                "dvainštirideseto",
+            ),
+            (100, "stoti", "stota", "stoto"),
+            (1000, "tisoči", "tisoča", "tisoče"),
+            (2000, "dvatisoči", "dvatisoča", "dvatisoče"),
+        ] {
+            assert_eq!(ordinal(num, "m"), Ok(String::from(masculine)));
+            assert_eq!(ordinal(num, "f"), Ok(String::from(feminine)));
+            assert_eq!(ordinal(num, "n"), Ok(String::from(neuter)));
+        }
+        assert_eq!(
+            ordinal(1142, "m"),
+            Ok(String::from("tisoč sto dvainštirideseti"))
+        );
+        assert_eq!(
+            Num2Words::new(42)
+                .lang(Lang::Slovenian)
+                .ordinal_num()
+                .to_words(),
+            Ok(String::from("42."))
+        );
+    }
+
+    #[test]
+    fn test_year() {
+        assert_eq!(
+            Num2Words::new(2023).lang(Lang::Slovenian).year().to_words(),
+            Ok(String::from("dva tisoč triindvajset"))
+        );
+    }
+
+    #[test]
+    fn test_currency() {
+        for (num, words) in [
+            (1.0, "en evro"),
+            (2.0, "dva evra"),
+            (3.0, "trije evri"),
+            (4.0, "štirje evri"),
+            (5.0, "pet evrov"),
+            (101.0, "sto en evro"),
+            (42.5, "dvainštirideset evrov in petdeset centov"),
+            (0.02, "dva centa"),
+        ] {
+            assert_eq!(
+                Num2Words::new(num)
+                    .lang(Lang::Slovenian)
+                    .currency(Currency::EUR)
+                    .to_words(),
+                Ok(String::from(words))
+            );
+        }
+    }
+
+    #[test]
+    fn test_unit() {
+        for (num, words) in [
+            (1, "ena ura"),
+            (2, "dve uri"),
+            (3, "tri ure"),
+            (5, "pet ur"),
+        ] {
+            assert_eq!(
+                Num2Words::new(num)
+                    .lang(Lang::Slovenian)
+                    .unit(Unit::Hour)
+                    .to_words(),
+                Ok(String::from(words))
+            );
+        }
+    }
+}
@@ -0,0 +1,350 @@
+use crate::{Agreement, Language, PluralCategory};
+use num_bigfloat::BigFloat;
+use std::str::FromStr;
+
+// Grammatical categories shared by the Slavic languages. Every language keeps
+// its own string tables and implements `Slavic` to get the agreement between
+// numerals and the nouns they count.
+
+/// Grammatical case
+#[derive(Default, Clone, Copy, PartialEq, Debug)]
+pub enum Declension {
+    #[default]
+    Nominative,
+    Genitive,
+    Dative,
+    Accusative,
+    Instrumental,
+    Locative,
+    Vocative,
+    /// Finnish only, not parsed from the preferences of Slavic languages
+    Partitive,
+}
+
+impl Declension {
+    pub(crate) fn as_preference(&self) -> &'static str {
+        use Declension::*;
+        match self {
+            Nominative => "nominative",
+            Genitive => "genitive",
+            Dative => "dative",
+            Accusative => "accusative",
+            Instrumental => "instrumental",
+            Locative => "locative",
+            Vocative => "vocative",
+            Partitive => "partitive",
+        }
+    }
+
+    pub(crate) fn index(&self) -> usize {
+        use Declension::*;
+        match self {
+            Nominative => 0,
+            Genitive => 1,
+            Dative => 2,
+            Accusative => 3,
+            Instrumental => 4,
+            Locative => 5,
+            Vocative => 6,
+            Partitive => 7,
+        }
+    }
+}
+
+impl FromStr for Declension {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use Declension::*;
+
+        Ok(match s.to_lowercase().as_str() {
+            "н" | "називний" | "mian" | "mianownik" | "nominativ" | "nominatív" | "nom"
+            | "nominative" => Nominative,
+            "р" | "родовий" | "dop" | "dopełniacz" | "genitiv" | "genitív" | "gen" | "genitive" => {
+                Genitive
+            }
+            "д" | "давальний" | "cel" | "celownik" | "dativ" | "datív" | "dat" | "dative" => {
+                Dative
+            }
+            "з" | "знахідний" | "bier" | "biernik" | "akuzativ" | "akuzatív" | "acc"
+            | "accusative" => Accusative,
+            "о" | "орудний" | "narz" | "narzędnik" | "instrumentál" | "inštrumentál" | "ins"
+            | "instrumental" => Instrumental,
+            "м" | "місцевий" | "miej" | "miejscownik" | "lokál" | "loc" | "locative" => {
+                Locative
+            }
+            "woł" | "wołacz" | "vokativ" | "voc" | "vocative" => Vocative,
+            _ => return Err(()),
+        })
+    }
+}
+
+/// Grammatical gender
+#[derive(Default, Clone, Copy, PartialEq, Debug)]
+pub enum Gender {
+    #[default]
+    Masculine,
+    Feminine,
+    Neuter,
+}
+
+impl FromStr for Gender {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use Gender::*;
+        Ok(match s.to_lowercase().as_str() {
+            "ч" | "чол" | "чоловічий" | "męski" | "mužský" | "muški" | "мушки" | "moški" | "m"
+            | "masculine" => Masculine,
+            "ж" | "жін" | "жіночий" | "ż" | "żeński" | "ženský" | "ženski" | "женски" | "f"
+            | "feminine" => Feminine,
+            "с" | "сер" | "середній" | "nijaki" | "střední" | "stredný" | "srednji" | "средњи"
+            | "n" | "neuter" => Neuter,
+            _ => return Err(()),
+        })
+    }
+}
+
+impl Gender {
+    pub(crate) fn as_preference(&self) -> &'static str {
+        use Gender::*;
+        match self {
+            Masculine => "masculine",
+            Feminine => "feminine",
+            Neuter => "neuter",
+        }
+    }
+
+    pub(crate) fn index(&self) -> usize {
+        use Gender::*;
+        match self {
+            Masculine => 0,
+            Feminine => 1,
+            Neuter => 2,
+        }
+    }
+}
+
+/// Grammatical number
+#[derive(Default, Clone, Copy, PartialEq, Debug)]
+pub enum GrammaticalNumber {
+    #[default]
+    Singular,
+    Plural,
+    /// Slovenian and Arabic only, not parsed from the preferences of Slavic
+    /// languages
+    Dual,
+}
+
+impl FromStr for GrammaticalNumber {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use GrammaticalNumber::*;
+        Ok(match s.to_lowercase().as_str() {
+            "од" | "однина" | "lp" | "pojedyncza" | "j" | "jednotné" | "sing" | "singular" => {
+                Singular
+            }
+            "мн" | "множина" | "lm" | "mnoga" | "mn" | "množné" | "pl" | "plural" => {
+                Plural
+            }
+            _ => return Err(()),
+        })
+    }
+}
+
+impl GrammaticalNumber {
+    pub(crate) fn as_preference(&self) -> &'static str {
+        use GrammaticalNumber::*;
+        match self {
+            Singular => "singular",
+            Plural => "plural",
+            Dual => "dual",
+        }
+    }
+
+    pub(crate) fn index(&self) -> usize {
+        use GrammaticalNumber::*;
+        match self {
+            Singular => 0,
+            Plural => 1,
+            Dual => 2,
+        }
+    }
+}
+
+/// Looks up the form of a case in a table ordered like `Declension`
+///
+/// Tables may leave the vocative out, the nominative is used in its place.
+pub(crate) fn by_case(table: &[&'static str], declension: Declension) -> &'static str {
+    table
+        .get(declension.index())
+        .copied()
+        .unwrap_or(table[Declension::Nominative.index()])
+}
+
+/// Reads the gender, number and case from the preferences, the last one of
+/// each kind winning
+pub(crate) fn parse_preferences(preferences: &[String]) -> (Gender, GrammaticalNumber, Declension) {
+    fn last<T: FromStr + Default>(preferences: &[String]) -> T {
+        preferences
+            .iter()
+            .rev()
+            .find_map(|p| p.parse().ok())
+            .unwrap_or_default()
+    }
+
+    (last(preferences), last(preferences), last(preferences))
+}
+
+/// Grammar of a Slavic language, i.e. how numerals agree with nouns
+pub(crate) trait Slavic: Language + Copy {
+    /// Whether a compound number ending in one is counted in the singular,
+    /// e.g. двадцять одна гривня, or like five, e.g. dwadzieścia jeden złotych
+    const TRAILING_ONE_IS_SINGULAR: bool;
+    /// Cases in which a numeral from five upwards puts the noun in the
+    /// genitive plural
+    const GENITIVE_GOVERNING: &'static [Declension];
+
+    const MINUS: &'static str;
+    const ZERO: &'static [&'static str];
+    const INFINITY: &'static [&'static str];
+    /// Hard adjective flexions of the singular, by gender and case
+    const ADJECTIVE_FLEXIONS_SINGULAR: [&'static [&'static str]; 3];
+    /// Hard adjective flexions of the plural, by case
+    const ADJECTIVE_FLEXIONS_PLURAL: &'static [&'static str];
+
+    fn gender(&self) -> Gender;
+    fn number(&self) -> GrammaticalNumber;
+    fn declension(&self) -> Declension;
+    fn with(&self, gender: Gender, number: GrammaticalNumber, declension: Declension) -> Self;
+
+    fn masculine(&self) -> Self {
+        self.with(Gender::Masculine, self.number(), self.declension())
+    }
+    fn feminine(&self) -> Self {
+        self.with(Gender::Feminine, self.number(), self.declension())
+    }
+    fn neuter(&self) -> Self {
+        self.with(Gender::Neuter, self.number(), self.declension())
+    }
+    fn set_declension(&self, declension: Declension) -> Self {
+        self.with(self.gender(), self.number(), declension)
+    }
+    fn singular(&self) -> Self {
+        self.with(
+            self.gender(),
+            GrammaticalNumber::Singular,
+            self.declension(),
+        )
+    }
+    fn plural(&self) -> Self {
+        self.with(self.gender(), GrammaticalNumber::Plural, self.declension())
+    }
+    fn is_plural(&self) -> bool {
+        self.number() == GrammaticalNumber::Plural
+    }
+
+    fn agreement_with_num(&self, num: BigFloat) -> Self {
+        self.agreement_with_category(self.plural_category(num))
+    }
+
+    /// Form of a noun counted by `num`, see [`Language::agreement`]
+    fn counted_agreement(&self, num: BigFloat) -> Agreement {
+        let noun = self.agreement_with_num(num);
+        Agreement {
+            category: self.plural_category(num),
+            number: noun.number(),
+            gender: Some(noun.gender()),
+            case: Some(noun.declension()),
+        }
+    }
+
+    fn agreement_with_units(&self, tens: usize, units: usize) -> Self {
+        self.agreement_with_category(plural_category_with_units::<Self>(tens, units))
+    }
+
+    fn agreement_with_category(&self, category: PluralCategory) -> Self {
+        match category {
+            PluralCategory::One => self.singular(),
+            PluralCategory::Few => self.plural(),
+            PluralCategory::Many => {
+                if Self::GENITIVE_GOVERNING.contains(&self.declension()) {
+                    self.plural().set_declension(Declension::Genitive)
+                } else {
+                    self.plural()
+                }
+            }
+            // fractions always take the genitive singular: пів відсотка
+            _ => self.singular().set_declension(Declension::Genitive),
+        }
+    }
+
+    fn zero(&self) -> &'static str {
+        by_case(Self::ZERO, self.declension())
+    }
+
+    fn infinity(&self, negative: bool) -> String {
+        let word = by_case(Self::INFINITY, self.declension());
+        if negative {
+            format!("{} {}", Self::MINUS, word)
+        } else {
+            String::from(word)
+        }
+    }
+
+    fn adjective_flexion(&self) -> &'static str {
+        if self.is_plural() {
+            by_case(Self::ADJECTIVE_FLEXIONS_PLURAL, self.declension())
+        } else {
+            by_case(
+                Self::ADJECTIVE_FLEXIONS_SINGULAR[self.gender().index()],
+                self.declension(),
+            )
+        }
+    }
+}
+
+/// Plural category of a number from its last two digits
+///
+/// When a trailing one is counted like five, the number one itself has to be
+/// told apart by the caller.
+pub(crate) fn plural_category_with_units<L: Slavic>(tens: usize, units: usize) -> PluralCategory {
+    if units == 0 || units > 4 || tens == 1 {
+        PluralCategory::Many
+    } else if units == 1 {
+        if L::TRAILING_ONE_IS_SINGULAR {
+            PluralCategory::One
+        } else {
+            PluralCategory::Many
+        }
+    } else {
+        //units in 2..4
+        PluralCategory::Few
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("р".parse(), Ok(Declension::Genitive));
+        assert_eq!("dopełniacz".parse(), Ok(Declension::Genitive));
+        assert_eq!("voc".parse(), Ok(Declension::Vocative));
+        assert_eq!("akuzativ".parse(), Ok(Declension::Accusative));
+        assert_eq!("жін".parse(), Ok(Gender::Feminine));
+        assert_eq!("ż".parse(), Ok(Gender::Feminine));
+        assert_eq!("lm".parse(), Ok(GrammaticalNumber::Plural));
+        assert_eq!("x".parse::<Gender>(), Err(()));
+    }
+
+    #[test]
+    fn test_by_case() {
+        let table = ["нуль", "нуля", "нулю", "нуль", "нулем", "нулі"];
+        assert_eq!(by_case(&table, Declension::Locative), "нулі");
+        // tables without a vocative fall back to the nominative
+        assert_eq!(by_case(&table, Declension::Vocative), "нуль");
+    }
+}
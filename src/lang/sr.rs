@@ -0,0 +1,638 @@
+use crate::{num2words::Num2Err, Currency, Gender, Language, PluralCategory, Unit};
+use num_bigfloat::BigFloat;
+
+// Every table is written in the Latin script and transliterated letter by
+// letter when the Cyrillic one is requested.
+
+pub struct Serbian {
+    gender: Gender,
+    latin: bool,
+    zero_major: bool,
+}
+
+// forms after one, after two to four, and after five or more
+type Noun = ([&'static str; 3], Gender);
+
+const UNITS: [&str; 9] = [
+    "jedan", "dva", "tri", "četiri", "pet", "šest", "sedam", "osam", "devet",
+];
+
+const TEENS: [&str; 10] = [
+    "deset",
+    "jedanaest",
+    "dvanaest",
+    "trinaest",
+    "četrnaest",
+    "petnaest",
+    "šesnaest",
+    "sedamnaest",
+    "osamnaest",
+    "devetnaest",
+];
+
+const TENS: [&str; 9] = [
+    "deset",
+    "dvadeset",
+    "trideset",
+    "četrdeset",
+    "pedeset",
+    "šezdeset",
+    "sedamdeset",
+    "osamdeset",
+    "devedeset",
+];
+
+const HUNDREDS: [&str; 9] = [
+    "sto",
+    "dvesta",
+    "trista",
+    "četiristo",
+    "petsto",
+    "šeststo",
+    "sedamsto",
+    "osamsto",
+    "devetsto",
+];
+
+// stems of the ordinals of the units, e.g. "prv" for "prvi"
+const ORDINAL_UNITS: [&str; 9] = [
+    "prv", "drug", "treć", "četvrt", "pet", "šest", "sedm", "osm", "devet",
+];
+
+const MEGAS: [Noun; 7] = [
+    (["hiljada", "hiljade", "hiljada"], Gender::Feminine),
+    (["milion", "miliona", "miliona"], Gender::Masculine),
+    (["milijarda", "milijarde", "milijardi"], Gender::Feminine),
+    (["bilion", "biliona", "biliona"], Gender::Masculine),
+    (["bilijarda", "bilijarde", "bilijardi"], Gender::Feminine),
+    (["trilion", "triliona", "triliona"], Gender::Masculine),
+    (["trilijarda", "trilijarde", "trilijardi"], Gender::Feminine),
+];
+
+// Latin letters and digraphs with their Cyrillic counterpart, the digraphs
+// coming first so that they are matched before their letters
+const CYRILLIC: [(&str, &str); 30] = [
+    ("lj", "љ"),
+    ("nj", "њ"),
+    ("dž", "џ"),
+    ("a", "а"),
+    ("b", "б"),
+    ("c", "ц"),
+    ("č", "ч"),
+    ("ć", "ћ"),
+    ("d", "д"),
+    ("đ", "ђ"),
+    ("e", "е"),
+    ("f", "ф"),
+    ("g", "г"),
+    ("h", "х"),
+    ("i", "и"),
+    ("j", "ј"),
+    ("k", "к"),
+    ("l", "л"),
+    ("m", "м"),
+    ("n", "н"),
+    ("o", "о"),
+    ("p", "п"),
+    ("r", "р"),
+    ("s", "с"),
+    ("š", "ш"),
+    ("t", "т"),
+    ("u", "у"),
+    ("v", "в"),
+    ("z", "з"),
+    ("ž", "ж"),
+];
+
+impl Serbian {
+    pub fn new(gender: Gender, latin: bool) -> Self {
+        Self {
+            gender,
+            latin,
+            zero_major: false,
+        }
+    }
+
+    /// Keeps the major unit clause for amounts below one, e.g. `nula dinara i
+    /// dvadeset para`
+    pub fn zero_major(self, zero_major: bool) -> Self {
+        Self { zero_major, ..self }
+    }
+
+    fn with_gender(&self, gender: Gender) -> Self {
+        Self { gender, ..*self }
+    }
+
+    // writes Latin words in the requested script
+    fn script(&self, words: String) -> String {
+        if self.latin {
+            return words;
+        }
+
+        let mut cyrillic = String::new();
+        let mut rest = words.as_str();
+        while let Some(c) = rest.chars().next() {
+            match CYRILLIC.iter().find(|(latin, _)| rest.starts_with(latin)) {
+                Some((latin, letter)) => {
+                    cyrillic.push_str(letter);
+                    rest = &rest[latin.len()..];
+                }
+                None => {
+                    cyrillic.push(c);
+                    rest = &rest[c.len_utf8()..];
+                }
+            }
+        }
+        cyrillic
+    }
+
+    fn currencies(&self, currency: Currency) -> Noun {
+        match currency {
+            Currency::DINAR | Currency::DZD | Currency::KWD | Currency::RSD => {
+                (["dinar", "dinara", "dinara"], Gender::Masculine)
+            }
+            Currency::AUD
+            | Currency::CAD
+            | Currency::DOLLAR
+            | Currency::HKD
+            | Currency::NZD
+            | Currency::SGD
+            | Currency::TWD
+            | Currency::USD => (["dolar", "dolara", "dolara"], Gender::Masculine),
+            Currency::EUR => (["evro", "evra", "evra"], Gender::Masculine),
+            Currency::GBP => (["funta", "funte", "funti"], Gender::Feminine),
+            Currency::CHF => (["franak", "franka", "franaka"], Gender::Masculine),
+            Currency::RUB => (["rublja", "rublje", "rublji"], Gender::Feminine),
+            _ => (["", "", ""], Gender::Masculine),
+        }
+    }
+
+    fn cents(&self, currency: Currency) -> Noun {
+        match currency {
+            Currency::RSD => (["para", "pare", "para"], Gender::Feminine),
+            Currency::GBP => (["peni", "penija", "penija"], Gender::Masculine),
+            Currency::RUB => (["kopejka", "kopejke", "kopejki"], Gender::Feminine),
+            _ => (["cent", "centa", "centi"], Gender::Masculine),
+        }
+    }
+
+    fn units(&self, unit: Unit) -> Noun {
+        match unit {
+            Unit::Meter => (["metar", "metra", "metara"], Gender::Masculine),
+            Unit::Kilometer => (["kilometar", "kilometra", "kilometara"], Gender::Masculine),
+            Unit::Gram => (["gram", "grama", "grama"], Gender::Masculine),
+            Unit::Kilogram => (["kilogram", "kilograma", "kilograma"], Gender::Masculine),
+            Unit::Liter => (["litar", "litra", "litara"], Gender::Masculine),
+            Unit::Second => (["sekunda", "sekunde", "sekundi"], Gender::Feminine),
+            Unit::Minute => (["minut", "minuta", "minuta"], Gender::Masculine),
+            Unit::Hour => (["sat", "sata", "sati"], Gender::Masculine),
+        }
+    }
+
+    // index of the form of a noun counted by `num`, fractions taking the
+    // form used after two, e.g. "dva i po metra"
+    fn form(&self, num: BigFloat) -> usize {
+        if !num.frac().is_zero() {
+            return 1;
+        }
+        match self.plural_category(num) {
+            PluralCategory::One => 0,
+            PluralCategory::Few => 1,
+            _ => 2,
+        }
+    }
+
+    fn split_thousands(&self, mut num: BigFloat) -> Vec<u64> {
+        let mut thousands = Vec::new();
+        let bf_1000 = BigFloat::from(1000);
+
+        while !num.is_zero() {
+            thousands.push((num % bf_1000).to_u64().unwrap());
+            num /= bf_1000;
+        }
+
+        thousands
+    }
+
+    // one and two agree with the gender of what they count, e.g. "jedna",
+    // "dve" for a feminine noun
+    fn unit(&self, units: usize) -> &'static str {
+        match (units, self.gender) {
+            (1, Gender::Feminine) => "jedna",
+            (1, Gender::Neuter) => "jedno",
+            (2, Gender::Feminine) => "dve",
+            _ => UNITS[units - 1],
+        }
+    }
+
+    fn triplet_to_words(&self, triplet: u64) -> Vec<&'static str> {
+        let hundreds = (triplet / 100 % 10) as usize;
+        let tens = (triplet / 10 % 10) as usize;
+        let units = (triplet % 10) as usize;
+        let mut words = vec![];
+
+        if hundreds > 0 {
+            words.push(HUNDREDS[hundreds - 1]);
+        }
+        if tens == 1 {
+            words.push(TEENS[units]);
+        } else {
+            if tens > 1 {
+                words.push(TENS[tens - 1]);
+            }
+            if units > 0 {
+                words.push(self.unit(units));
+            }
+        }
+
+        words
+    }
+
+    fn int_to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        // special case zero
+        if num.is_zero() {
+            return Ok(String::from("nula"));
+        }
+
+        let mut words = vec![];
+        for (i, triplet) in self.split_thousands(num.abs()).iter().enumerate().rev() {
+            if *triplet == 0 {
+                continue;
+            }
+            if i == 0 {
+                words.extend(self.triplet_to_words(*triplet));
+                continue;
+            }
+
+            let (forms, gender) = MEGAS.get(i - 1).ok_or(Num2Err::CannotConvert)?;
+            // a single thousand is read in the accusative, "hiljadu"
+            if i == 1 && *triplet == 1 {
+                words.push("hiljadu");
+                continue;
+            }
+            words.extend(self.with_gender(*gender).triplet_to_words(*triplet));
+            words.push(forms[self.form(BigFloat::from(*triplet))]);
+        }
+
+        // handling negative values
+        if num.is_negative() {
+            words.insert(0, "minus");
+        }
+
+        Ok(words.join(" "))
+    }
+
+    fn float_to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let mut words = vec![];
+        if num.is_negative() {
+            words.push(String::from("minus"));
+        }
+        words.push(self.int_to_cardinal(num.int().abs())?);
+        words.push(String::from("zarez"));
+
+        let mut ordinal_part = num.frac().abs();
+        while !ordinal_part.is_zero() {
+            let digit = (ordinal_part * BigFloat::from(10)).int();
+            ordinal_part = (ordinal_part * BigFloat::from(10)).frac();
+            words.push(self.masculine_cardinal(digit)?);
+        }
+
+        Ok(words.join(" "))
+    }
+
+    fn masculine_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        self.with_gender(Gender::Masculine).int_to_cardinal(num)
+    }
+
+    fn cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num.is_inf_pos() {
+            Ok(String::from("beskonačno"))
+        } else if num.is_inf_neg() {
+            Ok(String::from("minus beskonačno"))
+        } else if num.frac().is_zero() {
+            self.int_to_cardinal(num)
+        } else {
+            self.float_to_cardinal(num)
+        }
+    }
+
+    // ending of an ordinal stem in the gender, e.g. "prvi", "prva", "prvo"
+    fn ordinal_ending(&self, stem: &str) -> &'static str {
+        match self.gender {
+            Gender::Masculine => "i",
+            Gender::Feminine => "a",
+            // soft stems take -e, e.g. "treće"
+            Gender::Neuter if stem.ends_with('ć') => "e",
+            Gender::Neuter => "o",
+        }
+    }
+
+    // only the last word is an ordinal, e.g. "četrdeset drugi"
+    fn ordinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num.is_zero() {
+            return Ok(format!("nult{}", self.ordinal_ending("nult")));
+        }
+
+        let thousands = self.split_thousands(num.abs());
+        let last = thousands
+            .iter()
+            .position(|triplet| *triplet != 0)
+            .ok_or(Num2Err::CannotConvert)?;
+        let triplet = thousands[last];
+
+        // the whole number before the last group stays a cardinal
+        let bf_1000 = BigFloat::from(1000);
+        let mut scale = BigFloat::from(1);
+        for _ in 0..last {
+            scale *= bf_1000;
+        }
+        let head = num.abs() - num.abs() % (scale * bf_1000);
+        let mut words = vec![];
+        if !head.is_zero() {
+            words.push(self.masculine_cardinal(head)?);
+        }
+
+        let stem = if last > 0 {
+            // powers of a thousand fuse with their count, e.g. "dvehiljaditi"
+            let (forms, gender) = MEGAS.get(last - 1).ok_or(Num2Err::CannotConvert)?;
+            let count = if triplet == 1 {
+                String::new()
+            } else {
+                self.with_gender(*gender).triplet_to_words(triplet).concat()
+            };
+            let name = forms[0].strip_suffix('a').unwrap_or(forms[0]);
+            format!("{}{}it", count, name)
+        } else {
+            let hundreds = (triplet / 100 % 10) as usize;
+            let tens = (triplet / 10 % 10) as usize;
+            let units = (triplet % 10) as usize;
+
+            let stem = if tens == 1 {
+                String::from(TEENS[units])
+            } else if units > 0 {
+                String::from(ORDINAL_UNITS[units - 1])
+            } else if tens > 0 {
+                String::from(TENS[tens - 1])
+            } else {
+                // "sto" to "stot", "dvesta" to "dvestot"
+                let hundred = HUNDREDS[hundreds - 1];
+                format!("{}ot", &hundred[..hundred.len() - 1])
+            };
+            if hundreds > 0 && (tens > 0 || units > 0) {
+                words.push(String::from(HUNDREDS[hundreds - 1]));
+            }
+            if tens > 1 && units > 0 {
+                words.push(String::from(TENS[tens - 1]));
+            }
+            stem
+        };
+
+        words.push(format!("{}{}", stem, self.ordinal_ending(&stem)));
+        Ok(words.join(" "))
+    }
+
+    fn with_noun(&self, num: BigFloat, (forms, gender): Noun) -> Result<String, Num2Err> {
+        if num.is_inf() {
+            return Ok(format!("{} {}", self.cardinal(num)?, forms[2]));
+        }
+
+        Ok(format!(
+            "{} {}",
+            self.with_gender(gender).cardinal(num)?,
+            forms[self.form(num.abs())]
+        ))
+    }
+}
+
+impl Language for Serbian {
+    fn to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        Ok(self.script(self.cardinal(num)?))
+    }
+
+    fn to_ordinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        Ok(self.script(self.ordinal(num)?))
+    }
+
+    fn to_ordinal_num(&self, num: BigFloat) -> Result<String, Num2Err> {
+        Ok(format!("{}.", num.to_u128().unwrap()))
+    }
+
+    fn to_year(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if !num.frac().is_zero() {
+            return Err(Num2Err::FloatingYear);
+        }
+
+        // years are ordinals agreeing with "godina", e.g. "dve hiljade
+        // dvadeset treća"
+        let year_word = self.with_gender(Gender::Feminine).ordinal(num.abs())?;
+        if num.is_negative() {
+            Ok(self.script(format!("{} pre nove ere", year_word)))
+        } else {
+            Ok(self.script(year_word))
+        }
+    }
+
+    fn to_currency(&self, num: BigFloat, currency: Currency) -> Result<String, Num2Err> {
+        let (forms, gender) = self.currencies(currency);
+        let forms = if forms[0].is_empty() {
+            [
+                currency.default_string(false),
+                currency.default_string(true),
+                currency.default_string(true),
+            ]
+        } else {
+            forms.map(String::from)
+        };
+        let major = |num: BigFloat| -> Result<String, Num2Err> {
+            let count = if num.is_inf() {
+                self.cardinal(num)?
+            } else {
+                self.with_gender(gender).cardinal(num)?
+            };
+            let form = if num.is_inf() {
+                2
+            } else {
+                self.form(num.abs())
+            };
+            Ok(format!("{} {}", count, forms[form]))
+        };
+
+        if num.is_inf() || num.frac().is_zero() {
+            return Ok(self.script(major(num)?));
+        }
+
+        let integral_part = num.int();
+        let cents_nb = (num * BigFloat::from(100)).int() % BigFloat::from(100);
+        let cents_words = self.with_noun(cents_nb, self.cents(currency))?;
+        let integral_word = major(integral_part)?;
+
+        Ok(self.script(if cents_nb.is_zero() {
+            integral_word
+        } else if integral_part.is_zero() && !self.zero_major {
+            cents_words
+        } else {
+            format!("{} i {}", integral_word, cents_words)
+        }))
+    }
+
+    fn to_percent(&self, num: BigFloat) -> Result<String, Num2Err> {
+        Ok(self.script(self.with_noun(
+            num,
+            (["procenat", "procenta", "procenata"], Gender::Masculine),
+        )?))
+    }
+
+    fn to_unit(&self, num: BigFloat, unit: Unit) -> Result<String, Num2Err> {
+        Ok(self.script(self.with_noun(num, self.units(unit))?))
+    }
+
+    fn plural_category(&self, num: BigFloat) -> PluralCategory {
+        if !num.frac().is_zero() || num.is_inf() {
+            return PluralCategory::Other;
+        }
+
+        let last_two = (num.abs() % BigFloat::from(100)).to_u64().unwrap_or(0);
+        match (last_two % 10, last_two / 10) {
+            (1, tens) if tens != 1 => PluralCategory::One,
+            (2..=4, tens) if tens != 1 => PluralCategory::Few,
+            _ => PluralCategory::Other,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Currency, Lang, Num2Words, Unit};
+
+    #[test]
+    fn test_cardinal() {
+        for (num, words) in [
+            (0, "nula"),
+            (42, "četrdeset dva"),
+            (112, "sto dvanaest"),
+            (1000, "hiljadu"),
+            (2000, "dve hiljade"),
+            (5000, "pet hiljada"),
+            (21000, "dvadeset jedna hiljada"),
+            (1000000, "jedan milion"),
+            (2000000000, "dve milijarde"),
+            (-5, "minus pet"),
+        ] {
+            assert_eq!(
+                Num2Words::new(num)
+                    .lang(Lang::Serbian)
+                    .prefer("latin")
+                    .to_words(),
+                Ok(String::from(words))
+            );
+        }
+        assert_eq!(
+            Num2Words::new(42.5)
+                .lang(Lang::Serbian)
+                .prefer("latin")
+                .to_words(),
+            Ok(String::from("četrdeset dva zarez pet"))
+        );
+    }
+
+    #[test]
+    fn test_cyrillic() {
+        assert_eq!(
+            Num2Words::new(42).lang(Lang::Serbian).to_words(),
+            Ok(String::from("четрдесет два"))
+        );
+        assert_eq!(
+            Num2Words::new(1000000000).lang(Lang::Serbian).to_words(),
+            Ok(String::from("једна милијарда"))
+        );
+        assert_eq!(
+            Num2Words::new(5).lang(Lang::Serbian).ordinal().to_words(),
+            Ok(String::from("пети"))
+        );
+    }
+
+    #[test]
+    fn test_ordinal() {
+        let ordinal = |num: i64, gender| {
+            Num2Words::new(num)
+                .lang(Lang::Serbian)
+                .prefer("latin")
+                .prefer(gender)
+                .ordinal()
+                .to_words()
+        };
+
+        for (num, masculine, feminine, neuter) in [
+            (0, "nulti", "nulta", "nulto"),
+            (1, "prvi", "prva", "prvo"),
+            (3, "treći", "treća", "treće"),
+            (42, "četrdeset drugi", "četrdeset druga", "četrdeset drugo"),
+            (100, "stoti", "stota", "stoto"),
+            (200, "dvestoti", "dvestota", "dvestoto"),
+            (2000, "dvehiljaditi", "dvehiljadita", "dvehiljadito"),
+        ] {
+            assert_eq!(ordinal(num, "m"), Ok(String::from(masculine)));
+            assert_eq!(ordinal(num, "f"), Ok(String::from(feminine)));
+            assert_eq!(ordinal(num, "n"), Ok(String::from(neuter)));
+        }
+        assert_eq!(
+            ordinal(1042, "m"),
+            Ok(String::from("hiljadu četrdeset drugi"))
+        );
+        assert_eq!(
+            Num2Words::new(42)
+                .lang(Lang::Serbian)
+                .ordinal_num()
+                .to_words(),
+            Ok(String::from("42."))
+        );
+    }
+
+    #[test]
+    fn test_year() {
+        assert_eq!(
+            Num2Words::new(2023)
+                .lang(Lang::Serbian)
+                .prefer("latin")
+                .year()
+                .to_words(),
+            Ok(String::from("dve hiljade dvadeset treća"))
+        );
+    }
+
+    #[test]
+    fn test_currency() {
+        for (num, words) in [
+            (1.0, "jedan dinar"),
+            (2.0, "dva dinara"),
+            (5.0, "pet dinara"),
+            (21.0, "dvadeset jedan dinar"),
+            (42.5, "četrdeset dva dinara i pedeset para"),
+            (0.02, "dve pare"),
+        ] {
+            assert_eq!(
+                Num2Words::new(num)
+                    .lang(Lang::Serbian)
+                    .prefer("latin")
+                    .currency(Currency::RSD)
+                    .to_words(),
+                Ok(String::from(words))
+            );
+        }
+        assert_eq!(
+            Num2Words::new(2)
+                .lang(Lang::Serbian)
+                .currency(Currency::RSD)
+                .to_words(),
+            Ok(String::from("два динара"))
+        );
+        assert_eq!(
+            Num2Words::new(2)
+                .lang(Lang::Serbian)
+                .prefer("latin")
+                .unit(Unit::Second)
+                .to_words(),
+            Ok(String::from("dve sekunde"))
+        );
+    }
+}
@@ -0,0 +1,426 @@
+use crate::{num2words::Num2Err, Currency, Language, PluralCategory, Unit};
+use num_bigfloat::BigFloat;
+
+/// Noun classes a number can agree with, named after their plural prefix
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum NounClass {
+    /// Classes 1/2, e.g. mtu/watu
+    Wa,
+    /// Classes 3/4, e.g. mti/miti
+    Mi,
+    /// Classes 5/6, e.g. jiwe/mawe
+    Ma,
+    /// Classes 7/8, e.g. kitabu/vitabu
+    Vi,
+    /// Classes 9/10, e.g. shilingi/shilingi
+    N,
+}
+
+impl NounClass {
+    // prefixes of the singular and the plural of the agreeing numbers
+    fn prefixes(self) -> (&'static str, &'static str) {
+        match self {
+            NounClass::Wa => ("m", "wa"),
+            NounClass::Mi => ("m", "mi"),
+            NounClass::Ma => ("", "ma"),
+            NounClass::Vi => ("ki", "vi"),
+            NounClass::N => ("", ""),
+        }
+    }
+}
+
+/// Reads a noun class from its plural prefix or class number, e.g. `wa` or
+/// `2`
+pub(crate) fn noun_class_from_str(input: &str) -> Option<NounClass> {
+    match input {
+        "wa" | "1" | "2" => Some(NounClass::Wa),
+        "mi" | "3" | "4" => Some(NounClass::Mi),
+        "ma" | "5" | "6" => Some(NounClass::Ma),
+        "vi" | "7" | "8" => Some(NounClass::Vi),
+        "n" | "9" | "10" => Some(NounClass::N),
+        _ => None,
+    }
+}
+
+const UNITS: [&str; 10] = [
+    "sifuri", "moja", "mbili", "tatu", "nne", "tano", "sita", "saba", "nane", "tisa",
+];
+
+// stems of the numbers agreeing with their noun, the others being invariable
+const AGREEING: [(usize, &str); 5] = [
+    (2, "wili"),
+    (3, "tatu"),
+    (4, "nne"),
+    (5, "tano"),
+    (8, "nane"),
+];
+
+const TENS: [&str; 10] = [
+    "",
+    "kumi",
+    "ishirini",
+    "thelathini",
+    "arobaini",
+    "hamsini",
+    "sitini",
+    "sabini",
+    "themanini",
+    "tisini",
+];
+
+const MEGAS: [&str; 5] = ["elfu", "milioni", "bilioni", "trilioni", "kwadrilioni"];
+
+pub struct Swahili {
+    class: Option<NounClass>,
+    zero_major: bool,
+}
+
+// joins the parts of a number, the last one being introduced by "na", e.g.
+// "mia moja arobaini na mbili"
+fn with_na(mut parts: Vec<String>) -> String {
+    match parts.pop() {
+        Some(last) if !parts.is_empty() => format!("{} na {}", parts.join(" "), last),
+        Some(last) => last,
+        None => String::new(),
+    }
+}
+
+impl Swahili {
+    pub fn new(class: Option<NounClass>) -> Self {
+        Self {
+            class,
+            zero_major: false,
+        }
+    }
+
+    /// Keeps the major unit clause for amounts below one, e.g. `shilingi
+    /// sifuri na senti ishirini`
+    pub fn zero_major(self, zero_major: bool) -> Self {
+        Self { zero_major, ..self }
+    }
+
+    fn currencies(&self, currency: Currency) -> String {
+        match currency {
+            Currency::KES | Currency::TZS => String::from("shilingi"),
+            Currency::AUD
+            | Currency::CAD
+            | Currency::DOLLAR
+            | Currency::HKD
+            | Currency::NZD
+            | Currency::SGD
+            | Currency::TWD
+            | Currency::USD => String::from("dola"),
+            Currency::EUR => String::from("yuro"),
+            Currency::GBP => String::from("pauni"),
+            Currency::JPY => String::from("yeni"),
+            _ => currency.default_string(false),
+        }
+    }
+
+    fn units(&self, unit: Unit) -> &'static str {
+        match unit {
+            Unit::Meter => "mita",
+            Unit::Kilometer => "kilomita",
+            Unit::Gram => "gramu",
+            Unit::Kilogram => "kilogramu",
+            Unit::Liter => "lita",
+            Unit::Second => "sekunde",
+            Unit::Minute => "dakika",
+            Unit::Hour => "saa",
+        }
+    }
+
+    fn split_thousands(&self, mut num: BigFloat) -> Vec<u64> {
+        let mut thousands = Vec::new();
+        let bf_1000 = BigFloat::from(1000);
+
+        while !num.is_zero() {
+            thousands.push((num % bf_1000).to_u64().unwrap());
+            num /= bf_1000;
+        }
+
+        thousands
+    }
+
+    // the parts of a triplet, the units agreeing with the given class, e.g.
+    // ["mia moja", "arobaini", "wawili"]
+    fn triplet_to_parts(&self, triplet: u64, class: Option<NounClass>) -> Vec<String> {
+        let hundreds = (triplet / 100 % 10) as usize;
+        let tens = (triplet / 10 % 10) as usize;
+        let units = (triplet % 10) as usize;
+        let mut parts = vec![];
+
+        if hundreds > 0 {
+            parts.push(format!("mia {}", UNITS[hundreds]));
+        }
+        if tens > 0 {
+            parts.push(String::from(TENS[tens]));
+        }
+        if units > 0 {
+            parts.push(agreeing(units, class));
+        }
+
+        parts
+    }
+
+    // a number agreeing with a noun of the given class, the invariable
+    // counting forms being used without one
+    fn int_to_words(&self, num: BigFloat, class: Option<NounClass>) -> Result<String, Num2Err> {
+        // special case zero
+        if num.is_zero() {
+            return Ok(String::from(UNITS[0]));
+        }
+
+        let mut parts = vec![];
+        for (i, triplet) in self.split_thousands(num.abs()).iter().enumerate().rev() {
+            if *triplet == 0 {
+                continue;
+            }
+            if i == 0 {
+                parts.extend(self.triplet_to_parts(*triplet, class));
+                continue;
+            }
+
+            // the count follows its power, e.g. "elfu mbili"
+            let mega = MEGAS.get(i - 1).ok_or(Num2Err::CannotConvert)?;
+            parts.push(format!(
+                "{} {}",
+                mega,
+                with_na(self.triplet_to_parts(*triplet, None))
+            ));
+        }
+
+        let words = with_na(parts);
+        if num.is_negative() {
+            Ok(format!("hasi {}", words))
+        } else {
+            Ok(words)
+        }
+    }
+
+    fn float_to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let mut words = vec![];
+        if num.is_negative() {
+            words.push(String::from("hasi"));
+        }
+        words.push(self.int_to_words(num.int().abs(), None)?);
+        words.push(String::from("nukta"));
+
+        let mut ordinal_part = num.frac().abs();
+        while !ordinal_part.is_zero() {
+            let digit = (ordinal_part * BigFloat::from(10)).int();
+            ordinal_part = (ordinal_part * BigFloat::from(10)).frac();
+            words.push(self.int_to_words(digit, None)?);
+        }
+
+        Ok(words.join(" "))
+    }
+
+    // the noun comes first, the number agreeing with the preferred class
+    // if any, e.g. "shilingi arobaini na mbili"
+    fn with_noun(&self, num: BigFloat, noun: &str) -> Result<String, Num2Err> {
+        if num.is_inf() || !num.frac().is_zero() {
+            Ok(format!("{} {}", noun, self.to_cardinal(num)?))
+        } else {
+            Ok(format!("{} {}", noun, self.int_to_words(num, self.class)?))
+        }
+    }
+}
+
+// units agreeing with their noun, e.g. "wawili" for people, "viwili" for
+// things
+fn agreeing(units: usize, class: Option<NounClass>) -> String {
+    let (singular, plural) = match class {
+        Some(class) if class != NounClass::N => class.prefixes(),
+        _ => return String::from(UNITS[units]),
+    };
+
+    if units == 1 {
+        return format!("{}moja", singular);
+    }
+    match AGREEING.iter().find(|(i, _)| *i == units) {
+        Some((_, stem)) => format!("{}{}", plural, stem),
+        None => String::from(UNITS[units]),
+    }
+}
+
+impl Language for Swahili {
+    fn to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num.is_inf_pos() {
+            Ok(String::from("isiyo na kikomo"))
+        } else if num.is_inf_neg() {
+            Ok(String::from("hasi isiyo na kikomo"))
+        } else if num.frac().is_zero() {
+            self.int_to_words(num, self.class)
+        } else {
+            self.float_to_cardinal(num)
+        }
+    }
+
+    fn to_ordinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        // only the first and the second have their own word
+        if num == BigFloat::from(1) {
+            Ok(String::from("wa kwanza"))
+        } else if num == BigFloat::from(2) {
+            Ok(String::from("wa pili"))
+        } else {
+            Ok(format!("wa {}", self.int_to_words(num, None)?))
+        }
+    }
+
+    fn to_ordinal_num(&self, num: BigFloat) -> Result<String, Num2Err> {
+        Ok(format!("wa {}", num.to_u128().unwrap()))
+    }
+
+    fn to_year(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if !num.frac().is_zero() {
+            return Err(Num2Err::FloatingYear);
+        }
+
+        let year_word = self.int_to_words(num.abs(), None)?;
+        if num.is_negative() {
+            Ok(format!("{} kabla ya Kristo", year_word))
+        } else {
+            Ok(year_word)
+        }
+    }
+
+    fn to_currency(&self, num: BigFloat, currency: Currency) -> Result<String, Num2Err> {
+        if num.is_inf() || num.frac().is_zero() {
+            return self.with_noun(num, &self.currencies(currency));
+        }
+
+        let integral_part = num.int();
+        let cents_nb = (num * BigFloat::from(100)).int() % BigFloat::from(100);
+        let cents_words = self.with_noun(cents_nb, "senti")?;
+        let integral_word = self.to_currency(integral_part, currency)?;
+
+        if cents_nb.is_zero() {
+            Ok(integral_word)
+        } else if integral_part.is_zero() && !self.zero_major {
+            Ok(cents_words)
+        } else {
+            Ok(format!("{} na {}", integral_word, cents_words))
+        }
+    }
+
+    fn to_percent(&self, num: BigFloat) -> Result<String, Num2Err> {
+        Ok(format!("asilimia {}", self.to_cardinal(num)?))
+    }
+
+    fn to_unit(&self, num: BigFloat, unit: Unit) -> Result<String, Num2Err> {
+        self.with_noun(num, self.units(unit))
+    }
+
+    fn plural_category(&self, num: BigFloat) -> PluralCategory {
+        if num.abs() == BigFloat::from(1) {
+            PluralCategory::One
+        } else {
+            PluralCategory::Other
+        }
+    }
+
+    fn negative_marker(&self) -> &'static str {
+        "hasi"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Currency, Lang, Num2Words};
+
+    #[test]
+    fn test_cardinal() {
+        for (num, words) in [
+            (0, "sifuri"),
+            (10, "kumi"),
+            (11, "kumi na moja"),
+            (42, "arobaini na mbili"),
+            (100, "mia moja"),
+            (140, "mia moja na arobaini"),
+            (142, "mia moja arobaini na mbili"),
+            (1000, "elfu moja"),
+            (2500, "elfu mbili na mia tano"),
+            (42000, "elfu arobaini na mbili"),
+            (1000000, "milioni moja"),
+            (-5, "hasi tano"),
+        ] {
+            assert_eq!(
+                Num2Words::new(num).lang(Lang::Swahili).to_words(),
+                Ok(String::from(words))
+            );
+        }
+        assert_eq!(
+            Num2Words::new(42.5).lang(Lang::Swahili).to_words(),
+            Ok(String::from("arobaini na mbili nukta tano"))
+        );
+    }
+
+    #[test]
+    fn test_noun_class() {
+        let words = |num: i64, class| {
+            Num2Words::new(num)
+                .lang(Lang::Swahili)
+                .prefer(class)
+                .to_words()
+        };
+
+        assert_eq!(words(1, "wa"), Ok(String::from("mmoja")));
+        assert_eq!(words(42, "wa"), Ok(String::from("arobaini na wawili")));
+        assert_eq!(words(8, "vi"), Ok(String::from("vinane")));
+        assert_eq!(words(1, "7"), Ok(String::from("kimoja")));
+        assert_eq!(words(3, "ma"), Ok(String::from("matatu")));
+        assert_eq!(words(6, "mi"), Ok(String::from("sita")));
+        // the thousands agree with "elfu" rather than the counted noun
+        assert_eq!(words(2000, "wa"), Ok(String::from("elfu mbili")));
+    }
+
+    #[test]
+    fn test_ordinal() {
+        for (num, words) in [
+            (1, "wa kwanza"),
+            (2, "wa pili"),
+            (3, "wa tatu"),
+            (42, "wa arobaini na mbili"),
+        ] {
+            assert_eq!(
+                Num2Words::new(num).lang(Lang::Swahili).ordinal().to_words(),
+                Ok(String::from(words))
+            );
+        }
+        assert_eq!(
+            Num2Words::new(42)
+                .lang(Lang::Swahili)
+                .ordinal_num()
+                .to_words(),
+            Ok(String::from("wa 42"))
+        );
+    }
+
+    #[test]
+    fn test_year_and_currency() {
+        assert_eq!(
+            Num2Words::new(2023).lang(Lang::Swahili).year().to_words(),
+            Ok(String::from("elfu mbili ishirini na tatu"))
+        );
+        assert_eq!(
+            Num2Words::new(42.5)
+                .lang(Lang::Swahili)
+                .currency(Currency::KES)
+                .to_words(),
+            Ok(String::from("shilingi arobaini na mbili na senti hamsini"))
+        );
+        assert_eq!(
+            Num2Words::new(2)
+                .lang(Lang::Swahili)
+                .prefer("vi")
+                .currency(Currency::TZS)
+                .to_words(),
+            Ok(String::from("shilingi viwili"))
+        );
+        assert_eq!(
+            Num2Words::new(42).lang(Lang::Swahili).percent().to_words(),
+            Ok(String::from("asilimia arobaini na mbili"))
+        );
+    }
+}
@@ -0,0 +1,576 @@
+use super::ne::split_indian_groups;
+use crate::{num2words::Num2Err, Currency, Language, PluralCategory, Unit};
+use num_bigfloat::BigFloat;
+
+pub struct Tamil {
+    zero_major: bool,
+}
+
+const UNITS: [&str; 9] = [
+    "ஒன்று",
+    "இரண்டு",
+    "மூன்று",
+    "நான்கு",
+    "ஐந்து",
+    "ஆறு",
+    "ஏழு",
+    "எட்டு",
+    "ஒன்பது",
+];
+
+const TEENS: [&str; 10] = [
+    "பத்து",
+    "பதினொன்று",
+    "பன்னிரண்டு",
+    "பதிமூன்று",
+    "பதினான்கு",
+    "பதினைந்து",
+    "பதினாறு",
+    "பதினேழு",
+    "பதினெட்டு",
+    "பத்தொன்பது",
+];
+
+// 20 to 90 on their own, e.g. நாற்பது
+const TENS: [&str; 8] = [
+    "இருபது",
+    "முப்பது",
+    "நாற்பது",
+    "ஐம்பது",
+    "அறுபது",
+    "எழுபது",
+    "எண்பது",
+    "தொண்ணூறு",
+];
+
+// 20 to 90 followed by units, e.g. நாற்பத்தி இரண்டு
+const TENS_COMBINING: [&str; 8] = [
+    "இருபத்தி",
+    "முப்பத்தி",
+    "நாற்பத்தி",
+    "ஐம்பத்தி",
+    "அறுபத்தி",
+    "எழுபத்தி",
+    "எண்பத்தி",
+    "தொண்ணூற்றி",
+];
+
+// one merges with the ten before it, e.g. இருபத்தொன்று (21)
+const TENS_ONE: [&str; 8] = [
+    "இருபத்தொன்று",
+    "முப்பத்தொன்று",
+    "நாற்பத்தொன்று",
+    "ஐம்பத்தொன்று",
+    "அறுபத்தொன்று",
+    "எழுபத்தொன்று",
+    "எண்பத்தொன்று",
+    "தொண்ணூற்றொன்று",
+];
+
+const HUNDREDS: [&str; 9] = [
+    "நூறு",
+    "இருநூறு",
+    "முந்நூறு",
+    "நானூறு",
+    "ஐந்நூறு",
+    "அறுநூறு",
+    "எழுநூறு",
+    "எண்ணூறு",
+    "தொள்ளாயிரம்",
+];
+
+const HUNDREDS_COMBINING: [&str; 9] = [
+    "நூற்று",
+    "இருநூற்று",
+    "முந்நூற்று",
+    "நானூற்று",
+    "ஐந்நூற்று",
+    "அறுநூற்று",
+    "எழுநூற்று",
+    "எண்ணூற்று",
+    "தொள்ளாயிரத்து",
+];
+
+const VIRAMA: char = '்';
+const VOWEL_SIGN_U: char = 'ு';
+
+// A part of a number, with the form it takes when more parts follow, e.g.
+// ஆயிரம் and ஆயிரத்து
+struct Part {
+    last: String,
+    combining: String,
+}
+
+impl Tamil {
+    pub fn new() -> Self {
+        Self { zero_major: false }
+    }
+
+    /// Keeps the major unit clause for amounts below one, e.g. `பூஜ்ஜியம்
+    /// ரூபாய் இருபது பைசா`
+    pub fn zero_major(self, zero_major: bool) -> Self {
+        Self { zero_major }
+    }
+
+    fn currencies(&self, currency: Currency) -> String {
+        match currency {
+            Currency::DOLLAR => String::from("டாலர்"),
+            Currency::EUR => String::from("யூரோ"),
+            Currency::GBP => String::from("பவுண்டு"),
+            Currency::INR | Currency::LKR => String::from("ரூபாய்"),
+            Currency::USD => String::from("அமெரிக்க டாலர்"),
+            _ => currency.default_string(false),
+        }
+    }
+
+    fn cents(&self, currency: Currency) -> String {
+        match currency {
+            Currency::GBP => String::from("பென்ஸ்"),
+            Currency::INR => String::from("பைசா"),
+            Currency::LKR => String::from("சதம்"),
+            _ => String::from("சென்ட்"),
+        }
+    }
+
+    fn units(&self, unit: Unit) -> &'static str {
+        match unit {
+            Unit::Meter => "மீட்டர்",
+            Unit::Kilometer => "கிலோமீட்டர்",
+            Unit::Gram => "கிராம்",
+            Unit::Kilogram => "கிலோகிராம்",
+            Unit::Liter => "லிட்டர்",
+            Unit::Second => "வினாடி",
+            Unit::Minute => "நிமிடம்",
+            Unit::Hour => "மணி நேரம்",
+        }
+    }
+
+    fn below_hundred(&self, num: u64) -> String {
+        let tens = (num / 10) as usize;
+        let units = (num % 10) as usize;
+        match (tens, units) {
+            (0, _) => String::from(UNITS[units - 1]),
+            (1, _) => String::from(TEENS[units]),
+            (_, 0) => String::from(TENS[tens - 2]),
+            (_, 1) => String::from(TENS_ONE[tens - 2]),
+            _ => format!("{} {}", TENS_COMBINING[tens - 2], UNITS[units - 1]),
+        }
+    }
+
+    // A count of lakhs or crores, where one is ஒரு, e.g. ஒரு லட்சம்
+    fn scaled(&self, num: BigFloat, last: &str, combining: &str) -> Result<Part, Num2Err> {
+        let count = if num == BigFloat::from(1) {
+            String::from("ஒரு")
+        } else {
+            self.int_to_cardinal(num)?
+        };
+
+        Ok(Part {
+            last: format!("{} {}", count, last),
+            combining: format!("{} {}", count, combining),
+        })
+    }
+
+    // Thousands are joined to their count, e.g. இரண்டாயிரம் (2000)
+    fn thousands(&self, num: u64) -> Part {
+        let count = match num {
+            1 => String::new(),
+            3 => String::from("மூவ"),
+            5 => String::from("ஐய"),
+            _ => {
+                let words = self.below_hundred(num);
+                if let Some(stem) = words.strip_suffix("ொன்று") {
+                    // one becomes ஓர் before a vowel, e.g. இருபத்தோராயிரம்
+                    format!("{}ோர", stem)
+                } else {
+                    words.trim_end_matches(VOWEL_SIGN_U).to_string()
+                }
+            }
+        };
+
+        if count.is_empty() {
+            Part {
+                last: String::from("ஆயிரம்"),
+                combining: String::from("ஆயிரத்து"),
+            }
+        } else {
+            Part {
+                last: format!("{}ாயிரம்", count),
+                combining: format!("{}ாயிரத்து", count),
+            }
+        }
+    }
+
+    fn int_to_cardinal(&self, mut num: BigFloat) -> Result<String, Num2Err> {
+        // special case zero
+        if num.is_zero() {
+            return Ok(String::from("பூஜ்ஜியம்"));
+        }
+
+        // handling negative values
+        let mut words = vec![];
+        if num.is_negative() {
+            words.push(String::from("கழித்தல்"));
+            num = -num;
+        }
+
+        let mut parts = vec![];
+        // crores are counted with the whole number system, e.g. நூறு கோடி
+        let bf_crore = BigFloat::from(10_000_000);
+        let crores = (num / bf_crore).int();
+        if !crores.is_zero() {
+            parts.push(self.scaled(crores, "கோடி", "கோடியே")?);
+        }
+
+        let groups = split_indian_groups(num % bf_crore);
+        if let Some(lakhs) = groups.get(2).filter(|lakhs| **lakhs != 0) {
+            parts.push(self.scaled(BigFloat::from(*lakhs), "லட்சம்", "லட்சத்து")?);
+        }
+        if let Some(thousands) = groups.get(1).filter(|thousands| **thousands != 0) {
+            parts.push(self.thousands(*thousands));
+        }
+
+        let hundreds = (groups[0] / 100) as usize;
+        if hundreds > 0 {
+            parts.push(Part {
+                last: String::from(HUNDREDS[hundreds - 1]),
+                combining: String::from(HUNDREDS_COMBINING[hundreds - 1]),
+            });
+        }
+        if !groups[0].is_multiple_of(100) {
+            let words = self.below_hundred(groups[0] % 100);
+            parts.push(Part {
+                last: words.clone(),
+                combining: words,
+            });
+        }
+
+        let last = parts.len() - 1;
+        words.extend(parts.into_iter().enumerate().map(|(i, part)| {
+            if i == last {
+                part.last
+            } else {
+                part.combining
+            }
+        }));
+
+        Ok(words.join(" "))
+    }
+
+    fn float_to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let integral_part = num.int();
+        let mut words: Vec<String> = vec![];
+
+        if !integral_part.is_zero() {
+            let integral_word = self.int_to_cardinal(integral_part)?;
+            words.push(integral_word);
+        } else if num.is_negative() {
+            words.push(String::from("கழித்தல்"));
+        }
+        if integral_part.is_zero() {
+            words.push(String::from("பூஜ்ஜியம்"));
+        }
+
+        let mut ordinal_part = num.frac().abs();
+        if !ordinal_part.is_zero() {
+            words.push(String::from("புள்ளி"));
+        }
+        while !ordinal_part.is_zero() {
+            let digit = (ordinal_part * BigFloat::from(10)).int();
+            ordinal_part = (ordinal_part * BigFloat::from(10)).frac();
+            words.push(match digit.to_u64().unwrap() {
+                0 => String::from("பூஜ்ஜியம்"),
+                i => String::from(UNITS[i as usize - 1]),
+            });
+        }
+        Ok(words.join(" "))
+    }
+
+    // A number before a noun, where one is ஒரு, or ஓர் before a vowel, e.g.
+    // ஒரு பைசா, இருபத்தொரு ரூபாய்
+    fn with_noun(&self, num: BigFloat, noun: &str) -> Result<String, Num2Err> {
+        let words = self.to_cardinal(num)?;
+        let before_vowel = noun.starts_with(|c| ('அ'..='ஔ').contains(&c));
+
+        let words = if let Some(stem) = words.strip_suffix("ஒன்று") {
+            format!(
+                "{}{}",
+                stem,
+                if before_vowel {
+                    "ஓர்"
+                } else {
+                    "ஒரு"
+                }
+            )
+        } else if let Some(stem) = words.strip_suffix("ொன்று") {
+            format!(
+                "{}{}",
+                stem,
+                if before_vowel {
+                    "ோர்"
+                } else {
+                    "ொரு"
+                }
+            )
+        } else {
+            words
+        };
+
+        Ok(format!("{} {}", words, noun))
+    }
+}
+
+impl Default for Tamil {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Language for Tamil {
+    fn to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num.is_inf_pos() {
+            Ok(String::from("முடிவிலி"))
+        } else if num.is_inf_neg() {
+            Ok(String::from("கழித்தல் முடிவிலி"))
+        } else if num.frac().is_zero() {
+            self.int_to_cardinal(num)
+        } else {
+            self.float_to_cardinal(num)
+        }
+    }
+
+    fn to_ordinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num == BigFloat::from(1) {
+            return Ok(String::from("முதலாவது"));
+        }
+
+        // ஆவது replaces a final u, e.g. இரண்டு => இரண்டாவது, or follows a
+        // final consonant, e.g. ஆயிரம் => ஆயிரமாவது
+        let cardinal_word = self.to_cardinal(num)?;
+        if let Some(stem) = cardinal_word
+            .strip_suffix(VOWEL_SIGN_U)
+            .or_else(|| cardinal_word.strip_suffix(VIRAMA))
+        {
+            Ok(format!("{}ாவது", stem))
+        } else {
+            Ok(format!("{}யாவது", cardinal_word))
+        }
+    }
+
+    fn to_ordinal_num(&self, num: BigFloat) -> Result<String, Num2Err> {
+        Ok(format!("{}-ஆவது", num.to_u128().unwrap()))
+    }
+
+    fn to_year(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if !num.frac().is_zero() {
+            return Err(Num2Err::FloatingYear);
+        }
+
+        let words = self.int_to_cardinal(num.abs())?;
+        if num.is_negative() {
+            Ok(format!("கி.மு. {}", words))
+        } else {
+            Ok(words)
+        }
+    }
+
+    fn to_currency(&self, num: BigFloat, currency: Currency) -> Result<String, Num2Err> {
+        if num.is_inf() || num.frac().is_zero() {
+            self.with_noun(num, &self.currencies(currency))
+        } else {
+            let integral_part = num.int();
+            let cents_nb = (num * BigFloat::from(100)).int() % BigFloat::from(100);
+            let cents_words = self.with_noun(cents_nb, &self.cents(currency))?;
+            let integral_word = self.with_noun(integral_part, &self.currencies(currency))?;
+
+            if cents_nb.is_zero() {
+                Ok(integral_word)
+            } else if integral_part.is_zero() && !self.zero_major {
+                Ok(cents_words)
+            } else {
+                Ok(format!("{} {}", integral_word, cents_words))
+            }
+        }
+    }
+
+    fn to_percent(&self, num: BigFloat) -> Result<String, Num2Err> {
+        self.with_noun(num, "சதவீதம்")
+    }
+
+    fn to_unit(&self, num: BigFloat, unit: Unit) -> Result<String, Num2Err> {
+        self.with_noun(num, self.units(unit))
+    }
+
+    fn plural_category(&self, num: BigFloat) -> PluralCategory {
+        // CLDR: one is n = 1
+        if num.abs() == BigFloat::from(1) {
+            PluralCategory::One
+        } else {
+            PluralCategory::Other
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_cardinal() {
+        assert_eq!(
+            Num2Words::new(0).lang(Lang::Tamil).to_words(),
+            Ok(String::from("பூஜ்ஜியம்"))
+        );
+        assert_eq!(
+            Num2Words::new(1).lang(Lang::Tamil).to_words(),
+            Ok(String::from("ஒன்று"))
+        );
+        assert_eq!(
+            Num2Words::new(19).lang(Lang::Tamil).to_words(),
+            Ok(String::from("பத்தொன்பது"))
+        );
+        assert_eq!(
+            Num2Words::new(42).lang(Lang::Tamil).to_words(),
+            Ok(String::from("நாற்பத்தி இரண்டு"))
+        );
+        assert_eq!(
+            Num2Words::new(-90).lang(Lang::Tamil).to_words(),
+            Ok(String::from("கழித்தல் தொண்ணூறு"))
+        );
+        assert_eq!(
+            Num2Words::new(2.25).lang(Lang::Tamil).to_words(),
+            Ok(String::from("இரண்டு புள்ளி இரண்டு ஐந்து"))
+        );
+        assert_eq!(
+            Num2Words::new(0.5).lang(Lang::Tamil).to_words(),
+            Ok(String::from("பூஜ்ஜியம் புள்ளி ஐந்து"))
+        );
+        assert_eq!(
+            Num2Words::new(-0.25).lang(Lang::Tamil).to_words(),
+            Ok(String::from("கழித்தல் பூஜ்ஜியம் புள்ளி இரண்டு ஐந்து"))
+        );
+    }
+
+    #[test]
+    fn test_sandhi() {
+        assert_eq!(
+            Num2Words::new(21).lang(Lang::Tamil).to_words(),
+            Ok(String::from("இருபத்தொன்று"))
+        );
+        assert_eq!(
+            Num2Words::new(31).lang(Lang::Tamil).to_words(),
+            Ok(String::from("முப்பத்தொன்று"))
+        );
+        assert_eq!(
+            Num2Words::new(41).lang(Lang::Tamil).to_words(),
+            Ok(String::from("நாற்பத்தொன்று"))
+        );
+        assert_eq!(
+            Num2Words::new(91).lang(Lang::Tamil).to_words(),
+            Ok(String::from("தொண்ணூற்றொன்று"))
+        );
+        assert_eq!(
+            Num2Words::new(21_000).lang(Lang::Tamil).to_words(),
+            Ok(String::from("இருபத்தோராயிரம்"))
+        );
+        assert_eq!(
+            Num2Words::new(21)
+                .lang(Lang::Tamil)
+                .currency(Currency::INR)
+                .to_words(),
+            Ok(String::from("இருபத்தொரு ரூபாய்"))
+        );
+        assert_eq!(
+            Num2Words::new(1)
+                .lang(Lang::Tamil)
+                .unit(Unit::Hour)
+                .to_words(),
+            Ok(String::from("ஒரு மணி நேரம்"))
+        );
+    }
+
+    #[test]
+    fn test_grouping() {
+        assert_eq!(
+            Num2Words::new(1000).lang(Lang::Tamil).to_words(),
+            Ok(String::from("ஆயிரம்"))
+        );
+        assert_eq!(
+            Num2Words::new(2024).lang(Lang::Tamil).to_words(),
+            Ok(String::from("இரண்டாயிரத்து இருபத்தி நான்கு"))
+        );
+        assert_eq!(
+            Num2Words::new(1991).lang(Lang::Tamil).to_words(),
+            Ok(String::from("ஆயிரத்து தொள்ளாயிரத்து தொண்ணூற்றொன்று"))
+        );
+        assert_eq!(
+            Num2Words::new(100_000).lang(Lang::Tamil).to_words(),
+            Ok(String::from("ஒரு லட்சம்"))
+        );
+        assert_eq!(
+            Num2Words::new(15_000_105).lang(Lang::Tamil).to_words(),
+            Ok(String::from("ஒரு கோடியே ஐம்பது லட்சத்து நூற்று ஐந்து"))
+        );
+    }
+
+    #[test]
+    fn test_ordinal() {
+        assert_eq!(
+            Num2Words::new(1).lang(Lang::Tamil).ordinal().to_words(),
+            Ok(String::from("முதலாவது"))
+        );
+        assert_eq!(
+            Num2Words::new(2).lang(Lang::Tamil).ordinal().to_words(),
+            Ok(String::from("இரண்டாவது"))
+        );
+        assert_eq!(
+            Num2Words::new(42).lang(Lang::Tamil).ordinal().to_words(),
+            Ok(String::from("நாற்பத்தி இரண்டாவது"))
+        );
+        assert_eq!(
+            Num2Words::new(1000).lang(Lang::Tamil).ordinal().to_words(),
+            Ok(String::from("ஆயிரமாவது"))
+        );
+        assert_eq!(
+            Num2Words::new(42)
+                .lang(Lang::Tamil)
+                .ordinal_num()
+                .to_words(),
+            Ok(String::from("42-ஆவது"))
+        );
+    }
+
+    #[test]
+    fn test_currency() {
+        assert_eq!(
+            Num2Words::new(42.01)
+                .lang(Lang::Tamil)
+                .currency(Currency::INR)
+                .to_words(),
+            Ok(String::from("நாற்பத்தி இரண்டு ரூபாய் ஒரு பைசா"))
+        );
+        assert_eq!(
+            Num2Words::new(42.5)
+                .lang(Lang::Tamil)
+                .currency(Currency::LKR)
+                .to_words(),
+            Ok(String::from("நாற்பத்தி இரண்டு ரூபாய் ஐம்பது சதம்"))
+        );
+        assert_eq!(
+            Num2Words::new(0.2)
+                .lang(Lang::Tamil)
+                .currency(Currency::INR)
+                .prefer("zero-major")
+                .to_words(),
+            Ok(String::from("பூஜ்ஜியம் ரூபாய் இருபது பைசா"))
+        );
+    }
+
+    #[test]
+    fn test_percent() {
+        assert_eq!(
+            Num2Words::new(42).lang(Lang::Tamil).percent().to_words(),
+            Ok(String::from("நாற்பத்தி இரண்டு சதவீதம்"))
+        );
+    }
+}
@@ -0,0 +1,536 @@
+use super::ne::split_indian_groups;
+use crate::{num2words::Num2Err, Currency, Language, PluralCategory, Unit};
+use num_bigfloat::BigFloat;
+
+pub struct Telugu {
+    zero_major: bool,
+}
+
+const NUMBERS: [&str; 100] = [
+    "సున్నా",
+    "ఒకటి",
+    "రెండు",
+    "మూడు",
+    "నాలుగు",
+    "ఐదు",
+    "ఆరు",
+    "ఏడు",
+    "ఎనిమిది",
+    "తొమ్మిది",
+    "పది",
+    "పదకొండు",
+    "పన్నెండు",
+    "పదమూడు",
+    "పద్నాలుగు",
+    "పదిహేను",
+    "పదహారు",
+    "పదిహేడు",
+    "పద్దెనిమిది",
+    "పందొమ్మిది",
+    "ఇరవై",
+    "ఇరవై ఒకటి",
+    "ఇరవై రెండు",
+    "ఇరవై మూడు",
+    "ఇరవై నాలుగు",
+    "ఇరవై ఐదు",
+    "ఇరవై ఆరు",
+    "ఇరవై ఏడు",
+    "ఇరవై ఎనిమిది",
+    "ఇరవై తొమ్మిది",
+    "ముప్పై",
+    "ముప్పై ఒకటి",
+    "ముప్పై రెండు",
+    "ముప్పై మూడు",
+    "ముప్పై నాలుగు",
+    "ముప్పై ఐదు",
+    "ముప్పై ఆరు",
+    "ముప్పై ఏడు",
+    "ముప్పై ఎనిమిది",
+    "ముప్పై తొమ్మిది",
+    "నలభై",
+    "నలభై ఒకటి",
+    "నలభై రెండు",
+    "నలభై మూడు",
+    "నలభై నాలుగు",
+    "నలభై ఐదు",
+    "నలభై ఆరు",
+    "నలభై ఏడు",
+    "నలభై ఎనిమిది",
+    "నలభై తొమ్మిది",
+    "యాభై",
+    "యాభై ఒకటి",
+    "యాభై రెండు",
+    "యాభై మూడు",
+    "యాభై నాలుగు",
+    "యాభై ఐదు",
+    "యాభై ఆరు",
+    "యాభై ఏడు",
+    "యాభై ఎనిమిది",
+    "యాభై తొమ్మిది",
+    "అరవై",
+    "అరవై ఒకటి",
+    "అరవై రెండు",
+    "అరవై మూడు",
+    "అరవై నాలుగు",
+    "అరవై ఐదు",
+    "అరవై ఆరు",
+    "అరవై ఏడు",
+    "అరవై ఎనిమిది",
+    "అరవై తొమ్మిది",
+    "డెబ్బై",
+    "డెబ్బై ఒకటి",
+    "డెబ్బై రెండు",
+    "డెబ్బై మూడు",
+    "డెబ్బై నాలుగు",
+    "డెబ్బై ఐదు",
+    "డెబ్బై ఆరు",
+    "డెబ్బై ఏడు",
+    "డెబ్బై ఎనిమిది",
+    "డెబ్బై తొమ్మిది",
+    "ఎనభై",
+    "ఎనభై ఒకటి",
+    "ఎనభై రెండు",
+    "ఎనభై మూడు",
+    "ఎనభై నాలుగు",
+    "ఎనభై ఐదు",
+    "ఎనభై ఆరు",
+    "ఎనభై ఏడు",
+    "ఎనభై ఎనిమిది",
+    "ఎనభై తొమ్మిది",
+    "తొంభై",
+    "తొంభై ఒకటి",
+    "తొంభై రెండు",
+    "తొంభై మూడు",
+    "తొంభై నాలుగు",
+    "తొంభై ఐదు",
+    "తొంభై ఆరు",
+    "తొంభై ఏడు",
+    "తొంభై ఎనిమిది",
+    "తొంభై తొమ్మిది",
+];
+
+// A part of a number, with the form it takes when more parts follow, e.g.
+// రెండు వేలు and రెండు వేల
+struct Part {
+    last: String,
+    combining: String,
+}
+
+impl Telugu {
+    pub fn new() -> Self {
+        Self { zero_major: false }
+    }
+
+    /// Keeps the major unit clause for amounts below one, e.g. `సున్నా
+    /// రూపాయలు ఇరవై పైసలు`
+    pub fn zero_major(self, zero_major: bool) -> Self {
+        Self { zero_major }
+    }
+
+    fn currencies(&self, currency: Currency, plural_form: bool) -> String {
+        match currency {
+            Currency::DOLLAR => String::from("డాలర్{}"),
+            Currency::EUR => String::from("యూరో{}"),
+            Currency::GBP => String::from("పౌండ్{}"),
+            Currency::INR => String::from(if plural_form {
+                "రూపాయలు"
+            } else {
+                "రూపాయి"
+            }),
+            Currency::USD => String::from("అమెరికన్ డాలర్{}"),
+            _ => currency.default_string(plural_form),
+        }
+        .replace("{}", if plural_form { "లు" } else { "" })
+    }
+
+    fn cents(&self, currency: Currency, plural_form: bool) -> String {
+        match currency {
+            Currency::GBP => String::from(if plural_form {
+                "పెన్స్"
+            } else {
+                "పెన్నీ"
+            }),
+            Currency::INR => String::from(if plural_form {
+                "పైసలు"
+            } else {
+                "పైసా"
+            }),
+            _ => String::from("సెంట్{}"),
+        }
+        .replace("{}", if plural_form { "లు" } else { "" })
+    }
+
+    fn units(&self, unit: Unit, plural_form: bool) -> &'static str {
+        match (unit, plural_form) {
+            (Unit::Meter, false) => "మీటర్",
+            (Unit::Meter, true) => "మీటర్లు",
+            (Unit::Kilometer, false) => "కిలోమీటర్",
+            (Unit::Kilometer, true) => "కిలోమీటర్లు",
+            (Unit::Gram, false) => "గ్రాము",
+            (Unit::Gram, true) => "గ్రాములు",
+            (Unit::Kilogram, false) => "కిలోగ్రాము",
+            (Unit::Kilogram, true) => "కిలోగ్రాములు",
+            (Unit::Liter, false) => "లీటర్",
+            (Unit::Liter, true) => "లీటర్లు",
+            (Unit::Second, false) => "సెకను",
+            (Unit::Second, true) => "సెకన్లు",
+            (Unit::Minute, false) => "నిమిషం",
+            (Unit::Minute, true) => "నిమిషాలు",
+            (Unit::Hour, false) => "గంట",
+            (Unit::Hour, true) => "గంటలు",
+        }
+    }
+
+    // A count of thousands, lakhs or crores, where one is ఒక and the scale
+    // word is plural from two on, e.g. ఒక లక్ష, రెండు లక్షలు
+    fn scaled(&self, num: BigFloat, singular: &str, plurals: [&str; 2]) -> Result<Part, Num2Err> {
+        if num == BigFloat::from(1) {
+            let last = if singular == "వెయ్యి" {
+                String::from(singular)
+            } else {
+                format!("ఒక {}", singular)
+            };
+            return Ok(Part {
+                last: last.clone(),
+                combining: last,
+            });
+        }
+
+        let count = self.attributive(self.int_to_cardinal(num)?);
+        Ok(Part {
+            last: format!("{} {}", count, plurals[0]),
+            combining: format!("{} {}", count, plurals[1]),
+        })
+    }
+
+    // One before a noun is ఒక, e.g. ఒక పైసా
+    fn attributive(&self, words: String) -> String {
+        match words.strip_suffix("ఒకటి") {
+            Some(stem) => format!("{}ఒక", stem),
+            None => words,
+        }
+    }
+
+    fn int_to_cardinal(&self, mut num: BigFloat) -> Result<String, Num2Err> {
+        // special case zero
+        if num.is_zero() {
+            return Ok(String::from(NUMBERS[0]));
+        }
+
+        // handling negative values
+        let mut words = vec![];
+        if num.is_negative() {
+            words.push(String::from("మైనస్"));
+            num = -num;
+        }
+
+        let mut parts = vec![];
+        // crores are counted with the whole number system, e.g. వంద కోట్లు
+        let bf_crore = BigFloat::from(10_000_000);
+        let crores = (num / bf_crore).int();
+        if !crores.is_zero() {
+            parts.push(self.scaled(crores, "కోటి", ["కోట్లు", "కోట్ల"])?);
+        }
+
+        let groups = split_indian_groups(num % bf_crore);
+        if let Some(lakhs) = groups.get(2).filter(|lakhs| **lakhs != 0) {
+            parts.push(self.scaled(BigFloat::from(*lakhs), "లక్ష", ["లక్షలు", "లక్షల"])?);
+        }
+        if let Some(thousands) = groups.get(1).filter(|thousands| **thousands != 0) {
+            parts.push(self.scaled(BigFloat::from(*thousands), "వెయ్యి", ["వేలు", "వేల"])?);
+        }
+
+        let hundreds = (groups[0] / 100) as usize;
+        if hundreds == 1 {
+            parts.push(Part {
+                last: String::from("వంద"),
+                combining: String::from("నూట"),
+            });
+        } else if hundreds > 1 {
+            parts.push(Part {
+                last: format!("{} వందలు", NUMBERS[hundreds]),
+                combining: format!("{} వందల", NUMBERS[hundreds]),
+            });
+        }
+        if !groups[0].is_multiple_of(100) {
+            let words = String::from(NUMBERS[(groups[0] % 100) as usize]);
+            parts.push(Part {
+                last: words.clone(),
+                combining: words,
+            });
+        }
+
+        let last = parts.len() - 1;
+        words.extend(parts.into_iter().enumerate().map(|(i, part)| {
+            if i == last {
+                part.last
+            } else {
+                part.combining
+            }
+        }));
+
+        Ok(words.join(" "))
+    }
+
+    fn float_to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let integral_part = num.int();
+        let mut words: Vec<String> = vec![];
+
+        if !integral_part.is_zero() {
+            let integral_word = self.int_to_cardinal(integral_part)?;
+            words.push(integral_word);
+        } else if num.is_negative() {
+            words.push(String::from("మైనస్"));
+        }
+        if integral_part.is_zero() {
+            words.push(String::from(NUMBERS[0]));
+        }
+
+        let mut ordinal_part = num.frac().abs();
+        if !ordinal_part.is_zero() {
+            words.push(String::from("పాయింట్"));
+        }
+        while !ordinal_part.is_zero() {
+            let digit = (ordinal_part * BigFloat::from(10)).int();
+            ordinal_part = (ordinal_part * BigFloat::from(10)).frac();
+            words.push(String::from(NUMBERS[digit.to_u64().unwrap() as usize]));
+        }
+        Ok(words.join(" "))
+    }
+
+    // A number before a noun, which is singular only for one, e.g. ఒక
+    // రూపాయి, నలభై రెండు రూపాయలు
+    fn with_noun(&self, num: BigFloat, noun: &str) -> Result<String, Num2Err> {
+        let words = self.to_cardinal(num)?;
+        if num.frac().is_zero() {
+            Ok(format!("{} {}", self.attributive(words), noun))
+        } else {
+            Ok(format!("{} {}", words, noun))
+        }
+    }
+}
+
+impl Default for Telugu {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Language for Telugu {
+    fn to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num.is_inf_pos() {
+            Ok(String::from("అనంతం"))
+        } else if num.is_inf_neg() {
+            Ok(String::from("మైనస్ అనంతం"))
+        } else if num.frac().is_zero() {
+            self.int_to_cardinal(num)
+        } else {
+            self.float_to_cardinal(num)
+        }
+    }
+
+    fn to_ordinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num == BigFloat::from(1) {
+            return Ok(String::from("మొదటి"));
+        }
+
+        // వ replaces a final u or i, e.g. రెండు => రెండవ, ఒకటి => ఒకటవ, and
+        // follows anything else, e.g. నలభై => నలభైవ
+        let cardinal_word = self.to_cardinal(num)?;
+        let stem = cardinal_word
+            .strip_suffix('ు')
+            .or_else(|| cardinal_word.strip_suffix('ి'))
+            .unwrap_or(&cardinal_word);
+        Ok(format!("{}వ", stem))
+    }
+
+    fn to_ordinal_num(&self, num: BigFloat) -> Result<String, Num2Err> {
+        Ok(format!("{}వ", num.to_u128().unwrap()))
+    }
+
+    fn to_year(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if !num.frac().is_zero() {
+            return Err(Num2Err::FloatingYear);
+        }
+
+        let words = self.int_to_cardinal(num.abs())?;
+        if num.is_negative() {
+            Ok(format!("క్రీ.పూ. {}", words))
+        } else {
+            Ok(words)
+        }
+    }
+
+    fn to_currency(&self, num: BigFloat, currency: Currency) -> Result<String, Num2Err> {
+        if num.is_inf() {
+            Ok(format!(
+                "{} {}",
+                self.to_cardinal(num)?,
+                self.currencies(currency, true)
+            ))
+        } else if num.frac().is_zero() {
+            self.with_noun(
+                num,
+                &self.currencies(currency, self.plural_category(num) != PluralCategory::One),
+            )
+        } else {
+            let integral_part = num.int();
+            let cents_nb = (num * BigFloat::from(100)).int() % BigFloat::from(100);
+            let cents_words = self.with_noun(
+                cents_nb,
+                &self.cents(
+                    currency,
+                    self.plural_category(cents_nb) != PluralCategory::One,
+                ),
+            )?;
+            let integral_word = self.to_currency(integral_part, currency)?;
+
+            if cents_nb.is_zero() {
+                Ok(integral_word)
+            } else if integral_part.is_zero() && !self.zero_major {
+                Ok(cents_words)
+            } else {
+                Ok(format!("{} {}", integral_word, cents_words))
+            }
+        }
+    }
+
+    fn to_percent(&self, num: BigFloat) -> Result<String, Num2Err> {
+        Ok(format!("{} శాతం", self.to_cardinal(num)?))
+    }
+
+    fn to_unit(&self, num: BigFloat, unit: Unit) -> Result<String, Num2Err> {
+        self.with_noun(
+            num,
+            self.units(unit, self.plural_category(num) != PluralCategory::One),
+        )
+    }
+
+    fn plural_category(&self, num: BigFloat) -> PluralCategory {
+        // CLDR: one is n = 1
+        if num.abs() == BigFloat::from(1) {
+            PluralCategory::One
+        } else {
+            PluralCategory::Other
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_cardinal() {
+        assert_eq!(
+            Num2Words::new(1).lang(Lang::Telugu).to_words(),
+            Ok(String::from("ఒకటి"))
+        );
+        assert_eq!(
+            Num2Words::new(2).lang(Lang::Telugu).to_words(),
+            Ok(String::from("రెండు"))
+        );
+        assert_eq!(
+            Num2Words::new(42).lang(Lang::Telugu).to_words(),
+            Ok(String::from("నలభై రెండు"))
+        );
+        assert_eq!(
+            Num2Words::new(-0.5).lang(Lang::Telugu).to_words(),
+            Ok(String::from("మైనస్ సున్నా పాయింట్ ఐదు"))
+        );
+        assert_eq!(
+            Num2Words::new(0.5).lang(Lang::Telugu).to_words(),
+            Ok(String::from("సున్నా పాయింట్ ఐదు"))
+        );
+        assert_eq!(
+            Num2Words::new(-0.25).lang(Lang::Telugu).to_words(),
+            Ok(String::from("మైనస్ సున్నా పాయింట్ రెండు ఐదు"))
+        );
+    }
+
+    #[test]
+    fn test_grouping() {
+        assert_eq!(
+            Num2Words::new(101).lang(Lang::Telugu).to_words(),
+            Ok(String::from("నూట ఒకటి"))
+        );
+        assert_eq!(
+            Num2Words::new(2024).lang(Lang::Telugu).to_words(),
+            Ok(String::from("రెండు వేల ఇరవై నాలుగు"))
+        );
+        assert_eq!(
+            Num2Words::new(100_000).lang(Lang::Telugu).to_words(),
+            Ok(String::from("ఒక లక్ష"))
+        );
+        assert_eq!(
+            Num2Words::new(4_200_000).lang(Lang::Telugu).to_words(),
+            Ok(String::from("నలభై రెండు లక్షలు"))
+        );
+        assert_eq!(
+            Num2Words::new(20_000_300).lang(Lang::Telugu).to_words(),
+            Ok(String::from("రెండు కోట్ల మూడు వందలు"))
+        );
+    }
+
+    #[test]
+    fn test_ordinal() {
+        assert_eq!(
+            Num2Words::new(1).lang(Lang::Telugu).ordinal().to_words(),
+            Ok(String::from("మొదటి"))
+        );
+        assert_eq!(
+            Num2Words::new(42).lang(Lang::Telugu).ordinal().to_words(),
+            Ok(String::from("నలభై రెండవ"))
+        );
+        assert_eq!(
+            Num2Words::new(40).lang(Lang::Telugu).ordinal().to_words(),
+            Ok(String::from("నలభైవ"))
+        );
+        assert_eq!(
+            Num2Words::new(42)
+                .lang(Lang::Telugu)
+                .ordinal_num()
+                .to_words(),
+            Ok(String::from("42వ"))
+        );
+    }
+
+    #[test]
+    fn test_currency() {
+        assert_eq!(
+            Num2Words::new(1)
+                .lang(Lang::Telugu)
+                .currency(Currency::INR)
+                .to_words(),
+            Ok(String::from("ఒక రూపాయి"))
+        );
+        assert_eq!(
+            Num2Words::new(2)
+                .lang(Lang::Telugu)
+                .currency(Currency::INR)
+                .to_words(),
+            Ok(String::from("రెండు రూపాయలు"))
+        );
+        assert_eq!(
+            Num2Words::new(1.01)
+                .lang(Lang::Telugu)
+                .currency(Currency::INR)
+                .to_words(),
+            Ok(String::from("ఒక రూపాయి ఒక పైసా"))
+        );
+        assert_eq!(
+            Num2Words::new(42.01)
+                .lang(Lang::Telugu)
+                .currency(Currency::INR)
+                .to_words(),
+            Ok(String::from("నలభై రెండు రూపాయలు ఒక పైసా"))
+        );
+        assert_eq!(
+            Num2Words::new(100_000)
+                .lang(Lang::Telugu)
+                .currency(Currency::INR)
+                .to_words(),
+            Ok(String::from("ఒక లక్ష రూపాయలు"))
+        );
+    }
+}
@@ -0,0 +1,504 @@
+use crate::{
+    num2words::Num2Err, Agreement, Currency, GrammaticalNumber, Language, PluralCategory, Unit,
+};
+use num_bigfloat::BigFloat;
+
+const UNITS: [&str; 10] = [
+    "sero", "isa", "dalawa", "tatlo", "apat", "lima", "anim", "pito", "walo", "siyam",
+];
+
+const TEENS: [&str; 10] = [
+    "sampu",
+    "labing-isa",
+    "labindalawa",
+    "labintatlo",
+    "labing-apat",
+    "labinlima",
+    "labing-anim",
+    "labimpito",
+    "labingwalo",
+    "labinsiyam",
+];
+
+const TENS: [&str; 10] = [
+    "",
+    "",
+    "dalawampu",
+    "tatlumpu",
+    "apatnapu",
+    "limampu",
+    "animnapu",
+    "pitumpu",
+    "walumpu",
+    "siyamnapu",
+];
+
+const MEGAS: [&str; 4] = ["libo", "milyon", "bilyon", "trilyon"];
+
+// numerals borrowed from Spanish, as used for money and time
+const SPANISH_UNITS: [&str; 10] = [
+    "sero", "uno", "dos", "tres", "kuwatro", "singko", "sais", "siyete", "otso", "nuwebe",
+];
+
+const SPANISH_TEENS: [&str; 10] = [
+    "diyes",
+    "onse",
+    "dose",
+    "trese",
+    "katorse",
+    "kinse",
+    "disisais",
+    "disisiyete",
+    "disiotso",
+    "disinuwebe",
+];
+
+const SPANISH_TENS: [&str; 10] = [
+    "",
+    "",
+    "beynte",
+    "trenta",
+    "kuwarenta",
+    "singkuwenta",
+    "sesenta",
+    "setenta",
+    "otsenta",
+    "nobenta",
+];
+
+const SPANISH_HUNDREDS: [&str; 10] = [
+    "",
+    "siyento",
+    "dos siyentos",
+    "tres siyentos",
+    "kuwatro siyentos",
+    "kinyentos",
+    "sais siyentos",
+    "siyete siyentos",
+    "otso siyentos",
+    "nuwebe siyentos",
+];
+
+const SPANISH_MEGAS: [&str; 4] = ["mil", "milyon", "bilyon", "trilyon"];
+
+pub struct Tagalog {
+    spanish: bool,
+    zero_major: bool,
+}
+
+// links a number to the noun it counts, e.g. "dalawang piso", "apat na raan"
+fn ligature(number: &str, noun: &str) -> String {
+    match number.chars().last() {
+        Some('a' | 'e' | 'i' | 'o' | 'u') => format!("{}ng {}", number, noun),
+        Some('n') => format!("{}g {}", number, noun),
+        // the d of "daan" turns into an r between vowels
+        _ if noun == "daan" => format!("{} na raan", number),
+        _ => format!("{} na {}", number, noun),
+    }
+}
+
+// joins the parts of a number, the last one being introduced by "at", e.g.
+// "isang daan at apatnapu't dalawa"
+fn with_at(mut parts: Vec<String>) -> String {
+    match parts.pop() {
+        Some(last) if !parts.is_empty() => format!("{} at {}", parts.join(" "), last),
+        Some(last) => last,
+        None => String::new(),
+    }
+}
+
+impl Tagalog {
+    pub fn new(spanish: bool) -> Self {
+        Self {
+            spanish,
+            zero_major: false,
+        }
+    }
+
+    /// Keeps the major unit clause for amounts below one, e.g. `serong piso
+    /// at dalawampung sentimo`
+    pub fn zero_major(self, zero_major: bool) -> Self {
+        Self { zero_major, ..self }
+    }
+
+    // singular and plural, the native names not changing with the number
+    fn currencies(&self, currency: Currency) -> (String, String) {
+        match currency {
+            Currency::PHP | Currency::PESO if self.spanish => {
+                (String::from("peso"), String::from("pesos"))
+            }
+            Currency::PHP | Currency::PESO => (String::from("piso"), String::from("piso")),
+            Currency::AUD
+            | Currency::CAD
+            | Currency::DOLLAR
+            | Currency::HKD
+            | Currency::NZD
+            | Currency::SGD
+            | Currency::TWD
+            | Currency::USD => (String::from("dolyar"), String::from("dolyar")),
+            Currency::EUR => (String::from("euro"), String::from("euro")),
+            Currency::GBP => (String::from("libra"), String::from("libra")),
+            Currency::JPY => (String::from("yen"), String::from("yen")),
+            _ => (
+                currency.default_string(false),
+                currency.default_string(true),
+            ),
+        }
+    }
+
+    fn cents(&self) -> (&'static str, &'static str) {
+        if self.spanish {
+            ("sentimo", "sentimos")
+        } else {
+            ("sentimo", "sentimo")
+        }
+    }
+
+    fn units(&self, unit: Unit) -> &'static str {
+        match unit {
+            Unit::Meter => "metro",
+            Unit::Kilometer => "kilometro",
+            Unit::Gram => "gramo",
+            Unit::Kilogram => "kilo",
+            Unit::Liter => "litro",
+            Unit::Second => "segundo",
+            Unit::Minute => "minuto",
+            Unit::Hour => "oras",
+        }
+    }
+
+    fn split_thousands(&self, mut num: BigFloat) -> Vec<u64> {
+        let mut thousands = Vec::new();
+        let bf_1000 = BigFloat::from(1000);
+
+        while !num.is_zero() {
+            thousands.push((num % bf_1000).to_u64().unwrap());
+            num /= bf_1000;
+        }
+
+        thousands
+    }
+
+    fn native_triplet(&self, triplet: u64) -> Vec<String> {
+        let hundreds = (triplet / 100 % 10) as usize;
+        let tens = (triplet / 10 % 10) as usize;
+        let units = (triplet % 10) as usize;
+        let mut parts = vec![];
+
+        if hundreds > 0 {
+            parts.push(ligature(UNITS[hundreds], "daan"));
+        }
+        match (tens, units) {
+            (0, 0) => (),
+            (0, _) => parts.push(String::from(UNITS[units])),
+            (1, _) => parts.push(String::from(TEENS[units])),
+            (_, 0) => parts.push(String::from(TENS[tens])),
+            _ => parts.push(format!("{}'t {}", TENS[tens], UNITS[units])),
+        }
+
+        parts
+    }
+
+    fn spanish_triplet(&self, triplet: u64) -> Vec<String> {
+        let hundreds = (triplet / 100 % 10) as usize;
+        let tens = (triplet / 10 % 10) as usize;
+        let units = (triplet % 10) as usize;
+        let mut parts = vec![];
+
+        if hundreds > 0 {
+            parts.push(String::from(SPANISH_HUNDREDS[hundreds]));
+        }
+        match (tens, units) {
+            (0, 0) => (),
+            (0, _) => parts.push(String::from(SPANISH_UNITS[units])),
+            (1, _) => parts.push(String::from(SPANISH_TEENS[units])),
+            (_, 0) => parts.push(String::from(SPANISH_TENS[tens])),
+            _ => parts.push(format!("{}'y {}", SPANISH_TENS[tens], SPANISH_UNITS[units])),
+        }
+
+        parts
+    }
+
+    fn native_to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let mut parts = vec![];
+        for (i, triplet) in self.split_thousands(num).iter().enumerate().rev() {
+            if *triplet == 0 {
+                continue;
+            }
+            if i == 0 {
+                parts.extend(self.native_triplet(*triplet));
+                continue;
+            }
+
+            let mega = MEGAS.get(i - 1).ok_or(Num2Err::CannotConvert)?;
+            parts.push(ligature(&with_at(self.native_triplet(*triplet)), mega));
+        }
+
+        Ok(with_at(parts))
+    }
+
+    fn spanish_to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let mut words = vec![];
+        for (i, triplet) in self.split_thousands(num).iter().enumerate().rev() {
+            if *triplet == 0 {
+                continue;
+            }
+
+            // "mil" rather than "uno mil", but "un milyon"
+            match i {
+                0 => words.extend(self.spanish_triplet(*triplet)),
+                1 if *triplet == 1 => (),
+                _ if *triplet == 1 => words.push(String::from("un")),
+                _ => words.extend(self.spanish_triplet(*triplet)),
+            }
+            if i != 0 {
+                let mega = SPANISH_MEGAS.get(i - 1).ok_or(Num2Err::CannotConvert)?;
+                words.push(String::from(*mega));
+            }
+        }
+
+        Ok(words.join(" "))
+    }
+
+    fn int_to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        // special case zero
+        if num.is_zero() {
+            return Ok(String::from(UNITS[0]));
+        }
+
+        let words = if self.spanish {
+            self.spanish_to_cardinal(num.abs())?
+        } else {
+            self.native_to_cardinal(num.abs())?
+        };
+
+        if num.is_negative() {
+            Ok(format!("negatibo {}", words))
+        } else {
+            Ok(words)
+        }
+    }
+
+    fn float_to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let mut words = vec![];
+        if num.is_negative() {
+            words.push(String::from("negatibo"));
+        }
+        words.push(self.int_to_cardinal(num.int().abs())?);
+        words.push(String::from("punto"));
+
+        let mut ordinal_part = num.frac().abs();
+        while !ordinal_part.is_zero() {
+            let digit = (ordinal_part * BigFloat::from(10)).int();
+            ordinal_part = (ordinal_part * BigFloat::from(10)).frac();
+            words.push(self.int_to_cardinal(digit)?);
+        }
+
+        Ok(words.join(" "))
+    }
+
+    // native numbers are linked to their noun, e.g. "apatnapu't dalawang
+    // piso", borrowed ones are not, e.g. "kuwarenta'y dos pesos"
+    fn with_noun(&self, num: BigFloat, noun: (&str, &str)) -> Result<String, Num2Err> {
+        if !self.spanish {
+            return Ok(ligature(&self.to_cardinal(num)?, noun.0));
+        }
+
+        if num == BigFloat::from(1) {
+            Ok(format!("un {}", noun.0))
+        } else {
+            Ok(format!("{} {}", self.to_cardinal(num)?, noun.1))
+        }
+    }
+}
+
+impl Language for Tagalog {
+    fn to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num.is_inf_pos() {
+            Ok(String::from("walang hanggan"))
+        } else if num.is_inf_neg() {
+            Ok(String::from("negatibong walang hanggan"))
+        } else if num.frac().is_zero() {
+            self.int_to_cardinal(num)
+        } else {
+            self.float_to_cardinal(num)
+        }
+    }
+
+    fn to_ordinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        // ordinals are always native, with their own words up to the third
+        let ordinal = match num.to_u64() {
+            Some(1) => String::from("una"),
+            Some(2) => String::from("ikalawa"),
+            Some(3) => String::from("ikatlo"),
+            _ => format!("ika{}", Tagalog::new(false).int_to_cardinal(num)?),
+        };
+        Ok(ordinal)
+    }
+
+    fn to_ordinal_num(&self, num: BigFloat) -> Result<String, Num2Err> {
+        Ok(format!("ika-{}", num.to_u128().unwrap()))
+    }
+
+    fn to_year(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if !num.frac().is_zero() {
+            return Err(Num2Err::FloatingYear);
+        }
+
+        let year_word = self.int_to_cardinal(num.abs())?;
+        if num.is_negative() {
+            Ok(format!("{} bago si Kristo", year_word))
+        } else {
+            Ok(year_word)
+        }
+    }
+
+    fn to_currency(&self, num: BigFloat, currency: Currency) -> Result<String, Num2Err> {
+        let (singular, plural) = self.currencies(currency);
+        if num.is_inf() || num.frac().is_zero() {
+            return self.with_noun(num, (&singular, &plural));
+        }
+
+        let integral_part = num.int();
+        let cents_nb = (num * BigFloat::from(100)).int() % BigFloat::from(100);
+        let cents_words = self.with_noun(cents_nb, self.cents())?;
+        let integral_word = self.to_currency(integral_part, currency)?;
+
+        if cents_nb.is_zero() {
+            Ok(integral_word)
+        } else if integral_part.is_zero() && !self.zero_major {
+            Ok(cents_words)
+        } else {
+            Ok(format!("{} at {}", integral_word, cents_words))
+        }
+    }
+
+    fn to_percent(&self, num: BigFloat) -> Result<String, Num2Err> {
+        self.with_noun(num, ("porsiyento", "porsiyento"))
+    }
+
+    fn to_unit(&self, num: BigFloat, unit: Unit) -> Result<String, Num2Err> {
+        let unit = self.units(unit);
+        self.with_noun(num, (unit, unit))
+    }
+
+    fn plural_category(&self, num: BigFloat) -> PluralCategory {
+        // integers ending in 4, 6 or 9 are the only ones in the other category
+        match num.abs().to_u64() {
+            Some(i) if num.frac().is_zero() && ![4, 6, 9].contains(&(i % 10)) => {
+                PluralCategory::One
+            }
+            _ => PluralCategory::Other,
+        }
+    }
+
+    fn agreement(&self, num: BigFloat) -> Agreement {
+        Agreement {
+            number: GrammaticalNumber::Singular,
+            ..Agreement::from_category(self.plural_category(num))
+        }
+    }
+
+    fn negative_marker(&self) -> &'static str {
+        "negatibo"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Currency, Lang, Num2Words};
+
+    #[test]
+    fn test_cardinal() {
+        for (num, words) in [
+            (0, "sero"),
+            (11, "labing-isa"),
+            (21, "dalawampu't isa"),
+            (42, "apatnapu't dalawa"),
+            (100, "isang daan"),
+            (101, "isang daan at isa"),
+            (400, "apat na raan"),
+            (1234, "isang libo dalawang daan at tatlumpu't apat"),
+            (4000, "apat na libo"),
+            (142000, "isang daan at apatnapu't dalawang libo"),
+            (2000000, "dalawang milyon"),
+            (-5, "negatibo lima"),
+        ] {
+            assert_eq!(
+                Num2Words::new(num).lang(Lang::Tagalog).to_words(),
+                Ok(String::from(words))
+            );
+        }
+    }
+
+    #[test]
+    fn test_spanish() {
+        for (num, words) in [
+            (1, "uno"),
+            (15, "kinse"),
+            (42, "kuwarenta'y dos"),
+            (1234, "mil dos siyentos trenta'y kuwatro"),
+            (1000000, "un milyon"),
+        ] {
+            assert_eq!(
+                Num2Words::new(num)
+                    .lang(Lang::Tagalog)
+                    .prefer("spanish")
+                    .to_words(),
+                Ok(String::from(words))
+            );
+        }
+        assert_eq!(
+            Num2Words::new(42.5)
+                .lang(Lang::Tagalog)
+                .prefer("spanish")
+                .currency(Currency::PHP)
+                .to_words(),
+            Ok(String::from(
+                "kuwarenta'y dos pesos at singkuwenta sentimos"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_ordinal() {
+        for (num, words) in [
+            (1, "una"),
+            (2, "ikalawa"),
+            (4, "ikaapat"),
+            (42, "ikaapatnapu't dalawa"),
+        ] {
+            assert_eq!(
+                Num2Words::new(num).lang(Lang::Tagalog).ordinal().to_words(),
+                Ok(String::from(words))
+            );
+        }
+        assert_eq!(
+            Num2Words::new(42)
+                .lang(Lang::Tagalog)
+                .ordinal_num()
+                .to_words(),
+            Ok(String::from("ika-42"))
+        );
+    }
+
+    #[test]
+    fn test_currency() {
+        for (num, words) in [
+            (1.0, "isang piso"),
+            (4.0, "apat na piso"),
+            (10.0, "sampung piso"),
+            (42.0, "apatnapu't dalawang piso"),
+            (100.0, "isang daang piso"),
+            (42.5, "apatnapu't dalawang piso at limampung sentimo"),
+        ] {
+            assert_eq!(
+                Num2Words::new(num)
+                    .lang(Lang::Tagalog)
+                    .currency(Currency::PHP)
+                    .to_words(),
+                Ok(String::from(words))
+            );
+        }
+    }
+}
@@ -0,0 +1,424 @@
+use crate::{
+    num2words::Num2Err, Agreement, Currency, GrammaticalNumber, Language, PluralCategory, Unit,
+};
+use num_bigfloat::BigFloat;
+
+pub struct Turkish {
+    suffixed_ordinal_num: bool,
+    zero_major: bool,
+}
+
+const UNITS: [&str; 9] = [
+    "bir", "iki", "üç", "dört", "beş", "altı", "yedi", "sekiz", "dokuz",
+];
+
+const TENS: [&str; 9] = [
+    "on", "yirmi", "otuz", "kırk", "elli", "altmış", "yetmiş", "seksen", "doksan",
+];
+
+const MEGAS: [&str; 5] = ["milyon", "milyar", "trilyon", "katrilyon", "kentilyon"];
+
+const BACK_VOWELS: [char; 4] = ['a', 'ı', 'o', 'u'];
+const FRONT_VOWELS: [char; 4] = ['e', 'i', 'ö', 'ü'];
+
+impl Turkish {
+    pub fn new(suffixed_ordinal_num: bool) -> Self {
+        Self {
+            suffixed_ordinal_num,
+            zero_major: false,
+        }
+    }
+
+    /// Keeps the major unit clause for amounts below one, e.g. `sıfır lira
+    /// yirmi kuruş`
+    pub fn zero_major(self, zero_major: bool) -> Self {
+        Self { zero_major, ..self }
+    }
+
+    fn currencies(&self, currency: Currency) -> String {
+        match currency {
+            Currency::TRY => String::from("lira"),
+            Currency::EUR => String::from("avro"),
+            Currency::AUD
+            | Currency::CAD
+            | Currency::DOLLAR
+            | Currency::HKD
+            | Currency::NZD
+            | Currency::SGD
+            | Currency::TWD
+            | Currency::USD => String::from("dolar"),
+            Currency::GBP => String::from("sterlin"),
+            Currency::JPY => String::from("yen"),
+            _ => currency.default_string(false),
+        }
+    }
+
+    fn cents(&self, currency: Currency) -> String {
+        match currency {
+            Currency::TRY => String::from("kuruş"),
+            Currency::GBP => String::from("peni"),
+            _ => String::from("sent"),
+        }
+    }
+
+    fn units(&self, unit: Unit) -> &'static str {
+        match unit {
+            Unit::Meter => "metre",
+            Unit::Kilometer => "kilometre",
+            Unit::Gram => "gram",
+            Unit::Kilogram => "kilogram",
+            Unit::Liter => "litre",
+            Unit::Second => "saniye",
+            Unit::Minute => "dakika",
+            Unit::Hour => "saat",
+        }
+    }
+
+    fn split_thousands(&self, mut num: BigFloat) -> Vec<u64> {
+        let mut thousands = Vec::new();
+        let bf_1000 = BigFloat::from(1000);
+
+        while !num.is_zero() {
+            thousands.push((num % bf_1000).to_u64().unwrap());
+            num /= bf_1000;
+        }
+
+        thousands
+    }
+
+    fn int_to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        // special case zero
+        if num.is_zero() {
+            return Ok(String::from("sıfır"));
+        }
+
+        let mut words = vec![];
+        for (i, triplet) in self.split_thousands(num.abs()).iter().enumerate().rev() {
+            let hundreds = (triplet / 100 % 10) as usize;
+            let tens = (triplet / 10 % 10) as usize;
+            let units = (triplet % 10) as usize;
+
+            // "bin" rather than "bir bin", but "bir milyon"
+            if i == 1 && *triplet == 1 {
+                words.push(String::from("bin"));
+                continue;
+            }
+
+            if hundreds > 1 {
+                words.push(String::from(UNITS[hundreds - 1]));
+            }
+            if hundreds > 0 {
+                words.push(String::from("yüz"));
+            }
+            if tens > 0 {
+                words.push(String::from(TENS[tens - 1]));
+            }
+            if units > 0 {
+                words.push(String::from(UNITS[units - 1]));
+            }
+
+            if i != 0 && *triplet != 0 {
+                if i == 1 {
+                    words.push(String::from("bin"));
+                } else {
+                    let mega = MEGAS.get(i - 2).ok_or(Num2Err::CannotConvert)?;
+                    words.push(String::from(*mega));
+                }
+            }
+        }
+
+        // handling negative values
+        if num.is_negative() {
+            words.insert(0, String::from("eksi"));
+        }
+
+        Ok(words.join(" "))
+    }
+
+    fn float_to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let mut words = vec![];
+        if num.is_negative() {
+            words.push(String::from("eksi"));
+        }
+        words.push(self.int_to_cardinal(num.int().abs())?);
+        words.push(String::from("virgül"));
+
+        let mut ordinal_part = num.frac().abs();
+        while !ordinal_part.is_zero() {
+            let digit = (ordinal_part * BigFloat::from(10)).int();
+            ordinal_part = (ordinal_part * BigFloat::from(10)).frac();
+            words.push(self.int_to_cardinal(digit)?);
+        }
+
+        Ok(words.join(" "))
+    }
+
+    // nouns stay in the singular after a number, e.g. "kırk iki lira"
+    fn with_noun(&self, num: BigFloat, noun: &str) -> Result<String, Num2Err> {
+        if num.is_inf() {
+            return Ok(format!(
+                "{}sonsuz {}",
+                if num.is_negative() { "eksi " } else { "" },
+                noun
+            ));
+        }
+
+        Ok(format!("{} {}", self.to_cardinal(num)?, noun))
+    }
+}
+
+// Vowel of the ordinal suffix, following the last vowel of the word: -inci
+// after e/i, -ıncı after a/ı, -üncü after ö/ü and -uncu after o/u
+fn harmonic_vowel(word: &str) -> char {
+    let last_vowel = word
+        .chars()
+        .rev()
+        .find(|c| BACK_VOWELS.contains(c) || FRONT_VOWELS.contains(c))
+        .unwrap_or('i');
+    match last_vowel {
+        'a' | 'ı' => 'ı',
+        'o' | 'u' => 'u',
+        'ö' | 'ü' => 'ü',
+        _ => 'i',
+    }
+}
+
+// Ordinal suffix of a word, e.g. "nci" for "iki" and "üncü" for "üç"
+fn ordinal_suffix(word: &str) -> String {
+    let vowel = harmonic_vowel(word);
+    let ends_with_vowel = word
+        .chars()
+        .last()
+        .is_some_and(|c| BACK_VOWELS.contains(&c) || FRONT_VOWELS.contains(&c));
+
+    if ends_with_vowel {
+        format!("nc{}", vowel)
+    } else {
+        format!("{}nc{}", vowel, vowel)
+    }
+}
+
+// Adds the ordinal suffix to a word, e.g. "iki" to "ikinci"
+fn ordinal_word(word: &str) -> String {
+    match word.strip_suffix('t') {
+        // the final consonant softens before a vowel, e.g. "dördüncü"
+        Some(stem) => format!("{}d{}", stem, ordinal_suffix(word)),
+        None => format!("{}{}", word, ordinal_suffix(word)),
+    }
+}
+
+impl Language for Turkish {
+    fn to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num.is_inf_pos() {
+            Ok(String::from("sonsuz"))
+        } else if num.is_inf_neg() {
+            Ok(String::from("eksi sonsuz"))
+        } else if num.frac().is_zero() {
+            self.int_to_cardinal(num)
+        } else {
+            self.float_to_cardinal(num)
+        }
+    }
+
+    fn to_ordinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let cardinal_word = self.int_to_cardinal(num)?;
+        Ok(match cardinal_word.rsplit_once(' ') {
+            Some((rest, last)) => format!("{} {}", rest, ordinal_word(last)),
+            None => ordinal_word(&cardinal_word),
+        })
+    }
+
+    fn to_ordinal_num(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if !self.suffixed_ordinal_num {
+            return Ok(format!("{}.", num.to_u128().unwrap()));
+        }
+
+        // the suffix follows the last word of the number, e.g. "42'nci"
+        let cardinal_word = self.int_to_cardinal(num)?;
+        let last_word = cardinal_word.rsplit(' ').next().unwrap_or_default();
+        Ok(format!(
+            "{}'{}",
+            num.to_u128().unwrap(),
+            ordinal_suffix(last_word)
+        ))
+    }
+
+    fn to_year(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if !num.frac().is_zero() {
+            return Err(Num2Err::FloatingYear);
+        }
+
+        let year_word = self.int_to_cardinal(num.abs())?;
+        if num.is_negative() {
+            Ok(format!("milattan önce {}", year_word))
+        } else {
+            Ok(year_word)
+        }
+    }
+
+    fn to_currency(&self, num: BigFloat, currency: Currency) -> Result<String, Num2Err> {
+        if num.is_inf() || num.frac().is_zero() {
+            return self.with_noun(num, &self.currencies(currency));
+        }
+
+        let integral_part = num.int();
+        let cents_nb = (num * BigFloat::from(100)).int() % BigFloat::from(100);
+        let cents_words = self.with_noun(cents_nb, &self.cents(currency))?;
+        let integral_word = self.to_currency(integral_part, currency)?;
+
+        if cents_nb.is_zero() {
+            Ok(integral_word)
+        } else if integral_part.is_zero() && !self.zero_major {
+            Ok(cents_words)
+        } else {
+            Ok(format!("{} {}", integral_word, cents_words))
+        }
+    }
+
+    fn to_percent(&self, num: BigFloat) -> Result<String, Num2Err> {
+        // the percent sign is read first, e.g. "yüzde kırk iki"
+        Ok(format!("yüzde {}", self.to_cardinal(num)?))
+    }
+
+    fn to_unit(&self, num: BigFloat, unit: Unit) -> Result<String, Num2Err> {
+        self.with_noun(num, self.units(unit))
+    }
+
+    fn plural_category(&self, num: BigFloat) -> PluralCategory {
+        if num.abs() == BigFloat::from(1) {
+            PluralCategory::One
+        } else {
+            PluralCategory::Other
+        }
+    }
+
+    fn agreement(&self, num: BigFloat) -> Agreement {
+        Agreement {
+            number: GrammaticalNumber::Singular,
+            ..Agreement::from_category(self.plural_category(num))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Currency, Lang, Num2Words, Unit};
+
+    #[test]
+    fn test_cardinal() {
+        for (num, words) in [
+            (0, "sıfır"),
+            (42, "kırk iki"),
+            (100, "yüz"),
+            (200, "iki yüz"),
+            (1000, "bin"),
+            (1999, "bin dokuz yüz doksan dokuz"),
+            (2000, "iki bin"),
+            (101000, "yüz bir bin"),
+            (1000000, "bir milyon"),
+            (-5, "eksi beş"),
+        ] {
+            assert_eq!(
+                Num2Words::new(num).lang(Lang::Turkish).to_words(),
+                Ok(String::from(words))
+            );
+        }
+        assert_eq!(
+            Num2Words::new(42.5).lang(Lang::Turkish).to_words(),
+            Ok(String::from("kırk iki virgül beş"))
+        );
+    }
+
+    #[test]
+    fn test_ordinal() {
+        for (num, words) in [
+            (1, "birinci"),
+            (2, "ikinci"),
+            (3, "üçüncü"),
+            (4, "dördüncü"),
+            (6, "altıncı"),
+            (9, "dokuzuncu"),
+            (10, "onuncu"),
+            (40, "kırkıncı"),
+            (42, "kırk ikinci"),
+            (50, "ellinci"),
+            (60, "altmışıncı"),
+            (100, "yüzüncü"),
+            (1000, "bininci"),
+            (1000000, "bir milyonuncu"),
+        ] {
+            assert_eq!(
+                Num2Words::new(num).lang(Lang::Turkish).ordinal().to_words(),
+                Ok(String::from(words))
+            );
+        }
+    }
+
+    #[test]
+    fn test_ordinal_num() {
+        assert_eq!(
+            Num2Words::new(42)
+                .lang(Lang::Turkish)
+                .ordinal_num()
+                .to_words(),
+            Ok(String::from("42."))
+        );
+        for (num, words) in [
+            (42, "42'nci"),
+            (3, "3'üncü"),
+            (4, "4'üncü"),
+            (6, "6'ncı"),
+            (10, "10'uncu"),
+        ] {
+            assert_eq!(
+                Num2Words::new(num)
+                    .lang(Lang::Turkish)
+                    .prefer("suffix")
+                    .ordinal_num()
+                    .to_words(),
+                Ok(String::from(words))
+            );
+        }
+    }
+
+    #[test]
+    fn test_year() {
+        assert_eq!(
+            Num2Words::new(2024).lang(Lang::Turkish).year().to_words(),
+            Ok(String::from("iki bin yirmi dört"))
+        );
+        assert_eq!(
+            Num2Words::new(-44).lang(Lang::Turkish).year().to_words(),
+            Ok(String::from("milattan önce kırk dört"))
+        );
+    }
+
+    #[test]
+    fn test_currency() {
+        assert_eq!(
+            Num2Words::new(42.5)
+                .lang(Lang::Turkish)
+                .currency(Currency::TRY)
+                .to_words(),
+            Ok(String::from("kırk iki lira elli kuruş"))
+        );
+        assert_eq!(
+            Num2Words::new(0.01)
+                .lang(Lang::Turkish)
+                .currency(Currency::TRY)
+                .to_words(),
+            Ok(String::from("bir kuruş"))
+        );
+        assert_eq!(
+            Num2Words::new(42).lang(Lang::Turkish).percent().to_words(),
+            Ok(String::from("yüzde kırk iki"))
+        );
+        assert_eq!(
+            Num2Words::new(3)
+                .lang(Lang::Turkish)
+                .unit(Unit::Hour)
+                .to_words(),
+            Ok(String::from("üç saat"))
+        );
+    }
+}
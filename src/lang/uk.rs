@@ -1,6 +1,10 @@
-use crate::{num2words::Num2Err, Currency, Language};
+use crate::lang::group_digits;
+use crate::lang::slavic::{
+    plural_category_with_units, Declension, Gender, GrammaticalNumber, Slavic,
+};
+use crate::rounding::{quantize, Rounding};
+use crate::{num2words::Num2Err, Agreement, Currency, Language, PluralCategory, Unit};
 use num_bigfloat::BigFloat;
-use std::str::FromStr;
 
 // Source: Ukrainian Orthography 2019 / Український Правопис 2019
 // § 38. Constructed numerals / Складні числівники
@@ -8,176 +12,17 @@ use std::str::FromStr;
 // § 106. Ordinal numerals declension / Відмінювання порядкових числівників
 // § 107. Fractional numerals declension / Відмінювання дробових числівників
 
-#[derive(Default, Clone, Copy, PartialEq, Debug)]
-pub enum Declension {
-    #[default]
-    Nominative,
-    Genitive,
-    Dative,
-    Accusative,
-    Instrumental,
-    Locative,
-}
-
-impl Declension {
-    fn index(&self) -> usize {
-        use Declension::*;
-        match self {
-            Nominative => 0,
-            Genitive => 1,
-            Dative => 2,
-            Accusative => 3,
-            Instrumental => 4,
-            Locative => 5,
-        }
-    }
-}
-
-impl FromStr for Declension {
-    type Err = ();
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        use Declension::*;
-
-        Ok(match s.to_lowercase().as_str() {
-            "н" | "називний" | "nom" | "nominative" => Nominative,
-            "р" | "родовий" | "gen" | "genitive" => Genitive,
-            "д" | "давальний" | "dat" | "dative" => Dative,
-            "з" | "знахідний" | "acc" | "accusative" => Accusative,
-            "о" | "орудний" | "ins" | "instrumental" => Instrumental,
-            "м" | "місцевий" | "loc" | "locative" => Locative,
-            _ => return Err(()),
-        })
-    }
-}
-
-#[derive(Default, Clone, Copy, PartialEq, Debug)]
-pub enum Gender {
-    #[default]
-    Masculine,
-    Feminine,
-    Neuter,
-}
-
-impl FromStr for Gender {
-    type Err = ();
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        use Gender::*;
-        Ok(match s.to_lowercase().as_str() {
-            "ч" | "чол" | "чоловічий" | "m" | "masculine" => Masculine,
-            "ж" | "жін" | "жіночий" | "f" | "feminine" => Feminine,
-            "с" | "сер" | "середній" | "n" | "neuter" => Neuter,
-            _ => return Err(()),
-        })
-    }
-}
-
-impl Gender {
-    fn index(&self) -> usize {
-        use Gender::*;
-        match self {
-            Masculine => 0,
-            Feminine => 1,
-            Neuter => 2,
-        }
-    }
-}
-
-#[derive(Default, Clone, Copy, PartialEq, Debug)]
-pub enum GrammaticalNumber {
-    #[default]
-    Singular,
-    Plural,
-}
-
-impl FromStr for GrammaticalNumber {
-    type Err = ();
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        use GrammaticalNumber::*;
-        Ok(match s.to_lowercase().as_str() {
-            "од" | "однина" | "sing" | "singular" => Singular,
-            "мн" | "множина" | "pl" | "plural" => Plural,
-            _ => return Err(()),
-        })
-    }
-}
-
-impl GrammaticalNumber {
-    fn index(&self) -> usize {
-        use GrammaticalNumber::*;
-        match self {
-            Singular => 0,
-            Plural => 1,
-        }
-    }
-}
-
 #[derive(Default, Clone, Copy, PartialEq, Debug)]
 pub struct Ukrainian {
     gender: Gender,
     number: GrammaticalNumber,
     declension: Declension,
-}
-
-impl Ukrainian {
-    fn masculine(&self) -> Self {
-        Self {
-            gender: Gender::Masculine,
-            ..*self
-        }
-    }
-    fn feminine(&self) -> Self {
-        Self {
-            gender: Gender::Feminine,
-            ..*self
-        }
-    }
-    fn set_declension(&self, declension: Declension) -> Self {
-        Self {
-            declension,
-            ..*self
-        }
-    }
-    fn singular(&self) -> Self {
-        Self {
-            number: GrammaticalNumber::Singular,
-            ..*self
-        }
-    }
-    fn plural(&self) -> Self {
-        Self {
-            number: GrammaticalNumber::Plural,
-            ..*self
-        }
-    }
-    fn is_plural(&self) -> bool {
-        self.number == GrammaticalNumber::Plural
-    }
-
-    fn agreement_with_num(&self, num: BigFloat) -> Ukrainian {
-        let num = num.to_u64().unwrap_or_default(); //0 and inf has the same plural properties
-        let tail = num % 100;
-        let units = tail % 10;
-        let tens = tail / 10;
-        self.agreement_with_units(tens as usize, units as usize)
-    }
-
-    fn agreement_with_units(&self, tens: usize, units: usize) -> Ukrainian {
-        if units == 0 || units > 4 || tens == 1 {
-            if self.declension == Declension::Nominative {
-                self.plural().set_declension(Declension::Genitive)
-            } else {
-                self.plural()
-            }
-        } else if units == 1 {
-            self.singular()
-        } else {
-            //units in 2..4
-            self.plural()
-        }
-    }
+    zero_major: bool,
+    always_cents: bool,
+    group_digits: bool,
+    collective: bool,
+    comma: bool,
+    animate: bool,
 }
 
 const MINUS: &str = "мінус";
@@ -247,6 +92,22 @@ const TEENS_BASES: [&str; 10] = [
 
 const TEENS_FLEXIONS: [&str; 6] = ["ь", "и", "и", "ь", "ьма", "и"];
 
+// collective numerals of people and paired nouns: двоє дітей, троє друзів
+#[rustfmt::skip]
+const COLLECTIVE_UNITS: [[&str; 6]; 8] = [
+    [ "двоє",     "двох",     "двом",     "двоє",     "двома",     "двох"     ],
+    [ "троє",     "трьох",    "трьом",    "троє",     "трьома",    "трьох"    ],
+    [ "четверо",  "чотирьох", "чотирьом", "четверо",  "чотирма",   "чотирьох" ],
+    [ "пʼятеро",  "пʼятьох",  "пʼятьом",  "пʼятеро",  "пʼятьома",  "пʼятьох"  ],
+    [ "шестеро",  "шістьох",  "шістьом",  "шестеро",  "шістьома",  "шістьох"  ],
+    [ "семеро",   "сімох",    "сімом",    "семеро",   "сімома",    "сімох"    ],
+    [ "восьмеро", "вісьмох",  "вісьмом",  "восьмеро", "вісьмома",  "вісьмох"  ],
+    [ "девʼятеро", "девʼятьох", "девʼятьом", "девʼятеро", "девʼятьома", "девʼятьох" ],
+];
+
+// collective flexions of десят-, одинадцят-... and двадцят-: десятеро, двадцятьох
+const COLLECTIVE_FLEXIONS: [&str; 6] = ["еро", "ьох", "ьом", "еро", "ьома", "ьох"];
+
 #[rustfmt::skip]
 const TENS: [[&str; 6]; 8] = [
     [ "двадцять",   "двадцяти",    "двадцяти",    "двадцять",   "двадцятьма",     "двадцяти"    ],
@@ -292,8 +153,8 @@ const THOUSAND_FLEXIONS: [[&str; 6]; 2] = [
     [ "і", "",  "ам", "і", "ами", "ах" ],
 ];
 
-// Number names by "rule n-1" from https://uk.wikipedia.org/wiki/Іменні_назви_степенів_тисячі
-const MEGA_BASES: [&str; 21] = [
+// Number names by "rule n-1" up to 10^123 from https://uk.wikipedia.org/wiki/Іменні_назви_степенів_тисячі
+const MEGA_BASES: [&str; 41] = [
     "тисяч",
     "мільйон",
     "мільярд",
@@ -315,6 +176,26 @@ const MEGA_BASES: [&str; 21] = [
     "дуодевігінтильйон",
     "ундевігінтильйон",
     "вігінтильйон",
+    "унвігінтильйон",
+    "дуовігінтильйон",
+    "тревігінтильйон",
+    "кваттуорвігінтильйон",
+    "квінвігінтильйон",
+    "сексвігінтильйон",
+    "септенвігінтильйон",
+    "дуодетригінтильйон",
+    "ундетригінтильйон",
+    "тригінтильйон",
+    "унтригінтильйон",
+    "дуотригінтильйон",
+    "третригінтильйон",
+    "кваттуортригінтильйон",
+    "квінтригінтильйон",
+    "секстригінтильйон",
+    "септентригінтильйон",
+    "дуодеквадрагінтильйон",
+    "ундеквадрагінтильйон",
+    "квадрагінтильйон",
 ];
 
 #[rustfmt::skip]
@@ -330,7 +211,8 @@ const ADJECTIVE_HARD_FLEXIONS_SINGULAR: [[&str; 6]; 3] = [
     ["е",  "ого", "ому", "е",  "им",  "ому" ], 
 ];
 
-const ADJECTIVE_HARD_FLEXIONS_PLURAL: [&str; 6] = ["і", "их", "им", "их", "ими", "их"];
+// accusative is the inanimate one, like in the singular tables
+const ADJECTIVE_HARD_FLEXIONS_PLURAL: [&str; 6] = ["і", "их", "им", "і", "ими", "их"];
 
 #[rustfmt::skip]
 const ADJECTIVE_SOFT_FLEXIONS_SINGULAR: [[&str; 6]; 3] = [
@@ -339,7 +221,7 @@ const ADJECTIVE_SOFT_FLEXIONS_SINGULAR: [[&str; 6]; 3] = [
     ["є",  "ього", "ьому", "є",  "ім",  "ьому" ], 
 ];
 
-const ADJECTIVE_SOFT_FLEXIONS_PLURAL: [&str; 6] = ["і", "іх", "ім", "іх", "іми", "іх"];
+const ADJECTIVE_SOFT_FLEXIONS_PLURAL: [&str; 6] = ["і", "іх", "ім", "і", "іми", "іх"];
 
 #[rustfmt::skip]
 const ORDINAL_HARD_FLEXIONS_SINGULAR_SHORT: [[&str; 6]; 3] = [
@@ -355,29 +237,29 @@ const ORDINAL_SOFT_FLEXIONS_SINGULAR_SHORT: [[&str; 6]; 3] = [
     ["є", "го", "му", "є", "м",  "му" ], 
 ];
 
-const ORDINAL_FLEXIONS_PLURAL_SHORT: [&str; 6] = ["і", "х", "м", "х", "ми", "х"];
+const ORDINAL_FLEXIONS_PLURAL_SHORT: [&str; 6] = ["і", "х", "м", "і", "ми", "х"];
 
 #[rustfmt::skip]
 const NOUN_2ST_GROUP_HARD_DECLENSIONS: [[&str; 6]; 2] = [ //долар
-    [ "",  "а",  "у",  "а", "ом",  "і"  ],
+    [ "",  "а",  "у",  "",  "ом",  "і"  ],
     [ "и", "ів", "ам", "и", "ами", "ах" ],
 ];
 
 #[rustfmt::skip]
 const NOUN_2ST_GROUP_SOFT_DECLENSIONS: [[&str; 6]; 2] = [ //юань
-    [ "ь",  "я",  "ю", "я", "єм",  "і"  ],
+    [ "ь",  "я",  "ю", "ь", "єм",  "і"  ],
     [ "і", "ів", "ям", "і", "ями", "ях" ],
 ];
 
 #[rustfmt::skip]
 const NOUN_1ST_GROUP_SOFT_DECLENSIONS_VOWEL: [[&str; 6]; 2] = [ //рупія
-    [ "я", "ї", "ї",  "я", "єю",  "ї"  ],
+    [ "я", "ї", "ї",  "ю", "єю",  "ї"  ],
     [ "ї", "й", "ям", "ї", "ями", "ях" ],
 ];
 
 #[rustfmt::skip]
 const NOUN_1ST_GROUP_HARD_DECLENSIONS: [[&str; 6]; 2] = [ //єна
-    [ "а", "и", "і",  "а", "ою",  "і"  ],
+    [ "а", "и", "і",  "у", "ою",  "і"  ],
     [ "и", "",  "ам", "и", "ами", "ах" ],
 ];
 
@@ -393,21 +275,178 @@ const KOPIYKAS: [[&str; 6]; 2] = [
     [ "копійки", "копійок", "копійкам", "копійки", "копійками", "копійках" ],
 ];
 
+#[rustfmt::skip]
+const RUBLES: [[&str; 6]; 2] = [
+    [ "рубль", "рубля",  "рублю",  "рубль", "рублем",  "рублі"  ],
+    [ "рублі", "рублів", "рублям", "рублі", "рублями", "рублях" ],
+];
+
+#[rustfmt::skip]
+const KURUSHES: [[&str; 6]; 2] = [
+    [ "куруш",  "куруша",  "курушу",  "куруш",  "курушем",  "куруші"  ],
+    [ "куруші", "курушів", "курушам", "куруші", "курушами", "курушах" ],
+];
+
+#[rustfmt::skip]
+const LEI: [[&str; 6]; 2] = [
+    [ "лей", "лея",  "лею", "лей", "леєм",  "леї"  ],
+    [ "леї", "леїв", "леям", "леї", "леями", "леях" ],
+];
+
+#[rustfmt::skip]
+const PERCENTS: [[&str; 6]; 2] = [
+    [ "відсоток", "відсотка",  "відсотку",  "відсоток", "відсотком",  "відсотку"  ],
+    [ "відсотки", "відсотків", "відсоткам", "відсотки", "відсотками", "відсотках" ],
+];
+
 #[rustfmt::skip]
 const YEAR: [[&str; 6]; 2] = [
     [ "рік",  "року",  "року",  "рік",  "роком",  "році" ],
     [ "роки", "років", "рокам", "роки", "роками", "роках" ],
 ];
 
+impl Slavic for Ukrainian {
+    const TRAILING_ONE_IS_SINGULAR: bool = true;
+    const GENITIVE_GOVERNING: &'static [Declension] = &[Declension::Nominative];
+
+    const MINUS: &'static str = MINUS;
+    const ZERO: &'static [&'static str] = &ZERO;
+    const INFINITY: &'static [&'static str] = &INFINITY;
+    const ADJECTIVE_FLEXIONS_SINGULAR: [&'static [&'static str]; 3] = [
+        &ADJECTIVE_HARD_FLEXIONS_SINGULAR[0],
+        &ADJECTIVE_HARD_FLEXIONS_SINGULAR[1],
+        &ADJECTIVE_HARD_FLEXIONS_SINGULAR[2],
+    ];
+    const ADJECTIVE_FLEXIONS_PLURAL: &'static [&'static str] = &ADJECTIVE_HARD_FLEXIONS_PLURAL;
+
+    fn gender(&self) -> Gender {
+        self.gender
+    }
+    fn number(&self) -> GrammaticalNumber {
+        self.number
+    }
+    fn declension(&self) -> Declension {
+        self.declension
+    }
+    fn with(&self, gender: Gender, number: GrammaticalNumber, declension: Declension) -> Self {
+        Self {
+            gender,
+            number,
+            declension,
+            ..*self
+        }
+    }
+}
+
 impl Ukrainian {
     pub fn new(gender: Gender, number: GrammaticalNumber, declension: Declension) -> Self {
         Self {
             gender,
             number,
-            declension,
+            // numerals have no vocative of their own
+            declension: match declension {
+                Declension::Vocative => Declension::Nominative,
+                _ => declension,
+            },
+            zero_major: false,
+            always_cents: false,
+            group_digits: false,
+            collective: false,
+            comma: false,
+            animate: false,
+        }
+    }
+
+    /// Keeps the major unit clause for amounts below one, e.g. `нуль гривень
+    /// двадцять копійок`
+    pub fn zero_major(self, zero_major: bool) -> Self {
+        Self { zero_major, ..self }
+    }
+
+    /// Keeps the kopiyka clause of whole amounts, e.g. `сорок дві гривні нуль
+    /// копійок`
+    pub fn always_cents(self, always_cents: bool) -> Self {
+        Self {
+            always_cents,
+            ..self
+        }
+    }
+
+    /// Separates the thousands of the ordinal numbers with spaces, e.g.
+    /// `1 000 000-й`
+    pub fn group_digits(self, group_digits: bool) -> Self {
+        Self {
+            group_digits,
+            ..self
+        }
+    }
+
+    /// Reads the cardinals from two to twenty as collective numerals, e.g.
+    /// `двоє`, `пʼятеро`, `одинадцятеро`
+    pub fn collective(self, collective: bool) -> Self {
+        Self { collective, ..self }
+    }
+
+    /// Reads the decimals digit by digit after кома rather than as a fraction,
+    /// e.g. `одна кома один`
+    pub fn comma(self, comma: bool) -> Self {
+        Self { comma, ..self }
+    }
+
+    /// Counts an animate noun, whose accusative takes the genitive of one to
+    /// four, e.g. `бачу двох котів`
+    pub fn animate(self, animate: bool) -> Self {
+        Self { animate, ..self }
+    }
+
+    // the currencies, units and percents are inanimate nouns counted with the
+    // cardinal numerals
+    fn counting(&self) -> Self {
+        Self {
+            collective: false,
+            animate: false,
+            ..*self
         }
     }
 
+    // collective numeral of `num`, if it has one
+    fn collective_word(&self, num: BigFloat) -> Option<String> {
+        if !self.collective || !num.frac().is_zero() {
+            return None;
+        }
+        let declension = self.declension.index();
+        match num.to_u64()? {
+            n @ 2..=9 => Some(String::from(COLLECTIVE_UNITS[n as usize - 2][declension])),
+            n @ 10..=19 => Some(format!(
+                "{}{}",
+                TEENS_BASES[n as usize - 10],
+                COLLECTIVE_FLEXIONS[declension]
+            )),
+            20 => Some(format!("двадцят{}", COLLECTIVE_FLEXIONS[declension])),
+            _ => None,
+        }
+    }
+
+    // digits of an ordinal number
+    fn digits(&self, num: BigFloat) -> String {
+        let num = num.to_u128().unwrap();
+        if self.group_digits {
+            group_digits(num, " ")
+        } else {
+            num.to_string()
+        }
+    }
+
+    fn whole_currency(&self, whole: BigFloat, currency: Currency) -> Result<String, Num2Err> {
+        let currency_lang = self.currency_properties(currency);
+        let target_lang = currency_lang.agreement_with_num(whole);
+        Ok(format!(
+            "{} {}",
+            currency_lang.int_to_cardinal(whole)?,
+            target_lang.currencies(currency)
+        ))
+    }
+
     fn currencies(&self, currency: Currency) -> String {
         let number_idx = self.number.index();
         let declension_idx = self.declension.index();
@@ -416,6 +455,19 @@ impl Ukrainian {
                 "дирхам{}",
                 NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
             ),
+            Currency::AMD => format!(
+                "драм{}",
+                NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
+            ),
+            Currency::LAK => String::from("кіп"),
+            Currency::MMK => format!(
+                "кʼят{}",
+                NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
+            ),
+            Currency::MNT => format!(
+                "тугрик{}",
+                NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
+            ),
             Currency::ARS
             | Currency::CLP
             | Currency::COP
@@ -423,6 +475,14 @@ impl Ukrainian {
             | Currency::PESO
             | Currency::PHP
             | Currency::UYU => String::from("песо"),
+            Currency::DKK => format!(
+                "крон{}",
+                NOUN_1ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
+            ),
+            Currency::SEK => format!(
+                "крон{}",
+                NOUN_1ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
+            ),
             Currency::AUD
             | Currency::CAD
             | Currency::DOLLAR
@@ -434,6 +494,11 @@ impl Ukrainian {
                 "долар{}",
                 NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
             ),
+            Currency::BDT => String::from("така"),
+            Currency::BHD => format!(
+                "динар{}",
+                NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
+            ),
             Currency::BRL => format!(
                 "реал{}",
                 NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
@@ -450,39 +515,55 @@ impl Ukrainian {
                 "колон{}",
                 NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
             ),
+            Currency::CZK => format!(
+                "крон{}",
+                NOUN_1ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
+            ),
             Currency::DINAR | Currency::DZD | Currency::KWD => format!(
                 "динар{}",
                 NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
             ),
+            Currency::ETB => format!(
+                "бир{}",
+                NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
+            ),
             Currency::EUR => String::from("євро"),
             Currency::GBP => format!(
                 "фунт{}",
                 NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
             ),
-            Currency::IDR | Currency::INR => format!(
+            Currency::HUF => format!(
+                "форинт{}",
+                NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
+            ),
+            Currency::IDR | Currency::INR | Currency::LKR | Currency::NPR => format!(
                 "рупі{}",
                 NOUN_1ST_GROUP_SOFT_DECLENSIONS_VOWEL[number_idx][declension_idx]
             ),
             Currency::ILS => {
-                let adjective_flextion = if self.number == GrammaticalNumber::Plural {
-                    ADJECTIVE_HARD_FLEXIONS_PLURAL
-                } else {
-                    ADJECTIVE_HARD_FLEXIONS_SINGULAR[Gender::Masculine.index()]
-                }[self.declension.index()];
+                let adjective_flextion = self.masculine().adjective_flexion();
                 format!(
                     "нов{} шекел{}",
                     adjective_flextion, NOUN_2ST_GROUP_SOFT_DECLENSIONS[number_idx][declension_idx]
                 )
             }
+            Currency::ISK => format!(
+                "крон{}",
+                NOUN_1ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
+            ),
             Currency::JPY => format!(
                 "єн{}",
                 NOUN_1ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
             ),
+            Currency::KES => format!(
+                "шилінг{}",
+                NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
+            ),
             Currency::KRW => format!(
                 "вон{}",
                 NOUN_1ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
             ),
-            Currency::KZT => String::from("tenge"),
+            Currency::KZT => String::from("тенге"),
             Currency::MYR => format!(
                 "рингіт{}",
                 NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
@@ -496,30 +577,35 @@ impl Ukrainian {
                 NOUN_2ST_GROUP_SOFT_DECLENSIONS[number_idx][declension_idx]
             ),
             Currency::PLN => {
-                let flextion = if self.number == GrammaticalNumber::Plural {
-                    ADJECTIVE_HARD_FLEXIONS_PLURAL
-                } else {
-                    ADJECTIVE_HARD_FLEXIONS_SINGULAR[Gender::Masculine.index()]
-                }[declension_idx];
-                format!("злот{}", flextion)
+                format!("злот{}", self.masculine().adjective_flexion())
             }
             Currency::QAR | Currency::RIYAL | Currency::SAR => format!(
                 "ріал{}",
                 NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
             ),
-            Currency::RUB => format!(
-                "рубл{}",
-                NOUN_1ST_GROUP_SOFT_DECLENSIONS_VOWEL[number_idx][declension_idx]
+            Currency::RON => String::from(LEI[number_idx][declension_idx]),
+            Currency::RSD => format!(
+                "динар{}",
+                NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
             ),
+            Currency::RUB => String::from(RUBLES[number_idx][declension_idx]),
             Currency::THB => format!(
                 "бат{}",
                 NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
             ),
             Currency::TRY => format!(
-                "куруш{}",
+                "лір{}",
                 NOUN_1ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
             ),
+            Currency::TZS => format!(
+                "шилінг{}",
+                NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
+            ),
             Currency::UAH => String::from(HRYVNIAS[number_idx][declension_idx]),
+            Currency::UZS => format!(
+                "сум{}",
+                NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
+            ),
             Currency::VND => format!(
                 "донг{}",
                 NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
@@ -533,13 +619,21 @@ impl Ukrainian {
 
     fn currency_properties(&self, currency: Currency) -> Ukrainian {
         match currency {
-            Currency::INR
+            Currency::BDT
+            | Currency::DKK
+            | Currency::IDR
+            | Currency::INR
+            | Currency::ISK
             | Currency::JPY
             | Currency::KRW
+            | Currency::LKR
             | Currency::NOK
+            | Currency::NPR
+            | Currency::SEK
             | Currency::TRY
-            | Currency::UAH => self.feminine(),
-            _ => self.masculine(),
+            | Currency::UAH
+            | Currency::CZK => self.counting().feminine(),
+            _ => self.counting().masculine(),
         }
     }
 
@@ -551,6 +645,17 @@ impl Ukrainian {
                 "філс{}",
                 NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
             ),
+            Currency::AMD => format!(
+                "лум{}",
+                NOUN_1ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
+            ),
+            Currency::MMK => String::from("пʼя"),
+            Currency::MNT => String::from("мунгу"),
+            Currency::BDT => String::from("пойша"),
+            Currency::BHD => format!(
+                "філс{}",
+                NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
+            ),
             Currency::ARS
             | Currency::CLP
             | Currency::COP
@@ -559,10 +664,14 @@ impl Ukrainian {
             | Currency::PHP
             | Currency::UYU
             | Currency::BRL => String::from("сентаво"),
+            Currency::LAK => String::from("ат"),
+            Currency::DKK => String::from("ере"),
+            Currency::SEK => String::from("ере"),
             Currency::AUD
             | Currency::CAD
             | Currency::DOLLAR
             | Currency::HKD
+            | Currency::LKR
             | Currency::NZD
             | Currency::SGD
             | Currency::TWD
@@ -579,10 +688,18 @@ impl Ukrainian {
                 NOUN_2ST_GROUP_SOFT_DECLENSIONS[number_idx][declension_idx]
             ),
             Currency::CRC => String::from("сантимо"),
+            Currency::CZK => format!(
+                "гелер{}",
+                NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
+            ),
             Currency::DINAR | Currency::DZD | Currency::KWD => format!(
                 "філс{}",
                 NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
             ),
+            Currency::ETB => format!(
+                "сантим{}",
+                NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
+            ),
             Currency::EUR => format!(
                 "євроцент{}",
                 NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
@@ -591,11 +708,15 @@ impl Ukrainian {
                 "пенс{}",
                 NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
             ),
+            Currency::HUF => format!(
+                "філер{}",
+                NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
+            ),
             Currency::IDR => format!(
                 "сен{}",
                 NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
             ),
-            Currency::INR => format!(
+            Currency::INR | Currency::NPR => format!(
                 "пайс{}",
                 NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
             ),
@@ -603,10 +724,15 @@ impl Ukrainian {
                 "агор{}",
                 NOUN_1ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
             ),
+            Currency::ISK => String::from("ейре"),
             Currency::JPY => format!(
                 "сен{}",
                 NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
             ),
+            Currency::KES => format!(
+                "цент{}",
+                NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
+            ),
             Currency::KRW => format!(
                 "чон{}",
                 NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
@@ -629,19 +755,29 @@ impl Ukrainian {
                 "філс{}",
                 NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
             ),
-            Currency::RUB => format!(
-                "копійк{}",
+            Currency::RON => format!(
+                "бан{}",
+                NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
+            ),
+            Currency::RSD => format!(
+                "пар{}",
                 NOUN_1ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
             ),
+            Currency::RUB => String::from(KOPIYKAS[number_idx][declension_idx]),
             Currency::THB => format!(
-                "cатанг{}",
+                "сатанг{}",
                 NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
             ),
-            Currency::TRY => format!(
-                "лір{}",
-                NOUN_1ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
+            Currency::TRY => String::from(KURUSHES[number_idx][declension_idx]),
+            Currency::TZS => format!(
+                "цент{}",
+                NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
             ),
             Currency::UAH => String::from(KOPIYKAS[number_idx][declension_idx]),
+            Currency::UZS => format!(
+                "тийин{}",
+                NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
+            ),
             Currency::VND => String::from("су"),
             Currency::ZAR => format!(
                 "цент{}",
@@ -650,13 +786,76 @@ impl Ukrainian {
         }
     }
 
+    fn units(&self, unit: Unit) -> String {
+        let number_idx = self.number.index();
+        let declension_idx = self.declension.index();
+        match unit {
+            Unit::Meter => format!(
+                "метр{}",
+                NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
+            ),
+            Unit::Kilometer => format!(
+                "кілометр{}",
+                NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
+            ),
+            Unit::Gram => format!(
+                "грам{}",
+                NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
+            ),
+            Unit::Kilogram => format!(
+                "кілограм{}",
+                NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
+            ),
+            Unit::Liter => format!(
+                "літр{}",
+                NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
+            ),
+            Unit::Second => format!(
+                "секунд{}",
+                NOUN_1ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
+            ),
+            Unit::Minute => format!(
+                "хвилин{}",
+                NOUN_1ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
+            ),
+            Unit::Hour => format!(
+                "годин{}",
+                NOUN_1ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
+            ),
+        }
+    }
+
+    fn unit_properties(&self, unit: Unit) -> Ukrainian {
+        match unit {
+            Unit::Second | Unit::Minute | Unit::Hour => self.counting().feminine(),
+            _ => self.counting().masculine(),
+        }
+    }
+
     fn currency_fraction_properties(&self, currency: Currency) -> Ukrainian {
         match currency {
-            Currency::ILS | Currency::TRY | Currency::RUB | Currency::UAH => self.feminine(),
-            _ => self.masculine(),
+            Currency::ILS | Currency::RSD | Currency::RUB | Currency::UAH => {
+                self.counting().feminine()
+            }
+            _ => self.counting().masculine(),
         }
     }
 
+    fn currency_fraction_words(
+        &self,
+        fraction: BigFloat,
+        currency: Currency,
+    ) -> Result<String, Num2Err> {
+        let fraction = (fraction * BigFloat::from(100)).int();
+        let currency_lang = self.currency_fraction_properties(currency);
+        let target_lang = currency_lang.agreement_with_num(fraction);
+        Ok(format!(
+            "{} {}",
+            currency_lang.int_to_cardinal(fraction)?,
+            target_lang.currency_fraction(currency)
+        ))
+    }
+
     fn split_thousands(&self, mut num: BigFloat) -> Vec<u64> {
         let mut thousands = Vec::new();
         let bf_1000 = BigFloat::from(1000);
@@ -672,7 +871,7 @@ impl Ukrainian {
     fn int_to_cardinal(&self, mut num: BigFloat) -> Result<String, Num2Err> {
         // special case zero
         if num.is_zero() {
-            return Ok(String::from(ZERO[self.declension.index()]));
+            return Ok(String::from(self.zero()));
         }
 
         // handling negative values
@@ -701,6 +900,23 @@ impl Ukrainian {
             }
             .agreement_with_units(tens, units);
 
+            // the accusative of an animate noun takes the genitive of one to
+            // four: бачу двох котів, двадцять одного кота
+            let animate_genitive = order == 0
+                && self.animate
+                && self.declension == Declension::Accusative
+                && tens != 1
+                && match units {
+                    1 => self.gender == Gender::Masculine,
+                    2..=4 => true,
+                    _ => false,
+                };
+            let unit_declension = if animate_genitive {
+                Declension::Genitive
+            } else {
+                self.declension
+            };
+
             if tens == 1 {
                 words.push(format!(
                     "{}{}",
@@ -712,12 +928,16 @@ impl Ukrainian {
                     words.push(String::from(TENS[tens - 2][self.declension.index()]));
                 }
                 if units == 1 || units == 2 {
-                    let props = if order == 0 { self } else { &properties };
+                    let (gender, declension) = if order == 0 {
+                        (self.gender, unit_declension)
+                    } else {
+                        (properties.gender, properties.declension)
+                    };
                     words.push(String::from(
-                        GENDERED[units - 1][props.gender.index()][props.declension.index()],
+                        GENDERED[units - 1][gender.index()][declension.index()],
                     ));
                 } else if units > 0 {
-                    words.push(String::from(UNITS[units - 3][self.declension.index()]));
+                    words.push(String::from(UNITS[units - 3][unit_declension.index()]));
                 }
             }
 
@@ -743,31 +963,59 @@ impl Ukrainian {
         if numerator.is_zero() {
             return self.int_to_cardinal(whole);
         }
+        if self.comma {
+            return self.float_to_comma(num);
+        }
         let mut denominator = BigFloat::from(1);
         while !numerator.frac().is_zero() {
             //TODO: we should use non-floating point format because of limited precision
             numerator *= BigFloat::from(10);
             denominator *= BigFloat::from(10);
         }
-        let whole_properties = self.agreement_with_num(whole);
-        let whole_flexion = if whole_properties.number == GrammaticalNumber::Plural {
-            ADJECTIVE_HARD_FLEXIONS_PLURAL
-        } else {
-            ADJECTIVE_HARD_FLEXIONS_SINGULAR[Gender::Feminine.index()]
-        }[whole_properties.declension.index()];
-
-        let whole_lang = whole_properties.feminine();
-        let numerator_properties = self.agreement_with_num(numerator);
-        let numerator_lang = numerator_properties.feminine();
+        let whole_flexion = self
+            .agreement_with_num(whole)
+            .feminine()
+            .adjective_flexion();
+
+        // the numerals keep the requested case, only the nouns agree with them
+        let numeral_lang = self.feminine();
+        let denominator_lang = self.agreement_with_num(numerator).feminine();
         Ok(format!(
-            "{} ціл{} {} {}",
-            whole_lang.int_to_cardinal(whole)?,
+            "{}{} ціл{} {} {}",
+            if whole.is_zero() && num.is_negative() {
+                "мінус "
+            } else {
+                ""
+            },
+            numeral_lang.int_to_cardinal(whole)?,
             whole_flexion,
-            numerator_lang.int_to_cardinal(numerator)?,
-            numerator_lang.to_ordinal(denominator)?,
+            numeral_lang.int_to_cardinal(numerator)?,
+            denominator_lang.to_ordinal(denominator)?,
         ))
     }
 
+    // e.g. 1.05 => одна кома нуль пʼять
+    fn float_to_comma(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let whole = num.int();
+        // the integral part agrees with the implied ціла, the digits are
+        // named on their own
+        let mut words = vec![];
+        if whole.is_zero() && num.is_negative() {
+            words.push(String::from(MINUS));
+        }
+        words.push(self.feminine().int_to_cardinal(whole)?);
+        words.push(String::from("кома"));
+
+        let digit_lang = self.masculine();
+        let mut decimals = num.frac().abs();
+        while !decimals.is_zero() {
+            let digit = (decimals * BigFloat::from(10)).int();
+            decimals = (decimals * BigFloat::from(10)).frac();
+            words.push(digit_lang.int_to_cardinal(digit)?);
+        }
+        Ok(words.join(" "))
+    }
+
     fn ordinal_flexion(&self, num: BigFloat) -> &'static str {
         let tail = (num % BigFloat::from(100)).to_u64().unwrap();
         let is_soft = tail % 10 == 3 && tail != 13; //третій - the only soft adjective in numbers
@@ -799,9 +1047,11 @@ impl Ukrainian {
 impl Language for Ukrainian {
     fn to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
         if num.is_inf_pos() {
-            Ok(String::from(INFINITY[self.declension.index()]))
+            Ok(self.infinity(false))
         } else if num.is_inf_neg() {
-            Ok(format!("{MINUS} {}", INFINITY[self.declension.index()]))
+            Ok(self.infinity(true))
+        } else if let Some(word) = self.collective_word(num) {
+            Ok(word)
         } else if num.frac().is_zero() {
             self.int_to_cardinal(num)
         } else {
@@ -826,6 +1076,9 @@ impl Language for Ukrainian {
 
         let triplets = self.split_thousands(num);
         let last_non_empty = triplets.iter().position(|&t| t != 0).unwrap();
+        if triplets.iter().rposition(|&t| t != 0).unwrap_or(0) > MEGA_BASES.len() {
+            return Err(Num2Err::CannotConvert);
+        }
 
         //special case: one unit (thousand, million etc.) in the number
         if last_non_empty > 0
@@ -943,7 +1196,7 @@ impl Language for Ukrainian {
 
     fn to_ordinal_num(&self, num: BigFloat) -> Result<String, Num2Err> {
         let flexion = self.ordinal_flexion_short(num);
-        Ok(format!("{}-{flexion}", num.to_u128().unwrap()))
+        Ok(format!("{}-{flexion}", self.digits(num)))
     }
 
     fn to_year(&self, num: BigFloat) -> Result<String, Num2Err> {
@@ -969,7 +1222,31 @@ impl Language for Ukrainian {
         })
     }
 
+    fn to_year_range(&self, from: BigFloat, to: BigFloat) -> Result<String, Num2Err> {
+        if from.is_negative() != to.is_negative() {
+            return Ok(format!("{} – {}", self.to_year(from)?, self.to_year(to)?));
+        }
+        // both ordinals stay singular and share the plural noun, e.g.
+        // "двадцятому – двадцять третьому роках"
+        let year_lang = self.masculine().singular();
+        let words = format!(
+            "{} – {} {}",
+            year_lang.to_ordinal(from.abs())?,
+            year_lang.to_ordinal(to.abs())?,
+            YEAR[GrammaticalNumber::Plural.index()][self.declension.index()]
+        );
+        if from.is_negative() {
+            Ok(format!("{} до н.е.", words))
+        } else {
+            Ok(words)
+        }
+    }
+
     fn to_currency(&self, num: BigFloat, currency: Currency) -> Result<String, Num2Err> {
+        // rounded half-up to the nearest kopiyka, once the noise of binary
+        // floats is dropped, e.g. 934.425 has forty-three kopiyky
+        let num = quantize(quantize(num, 9, Rounding::HalfUp), 2, Rounding::HalfUp);
+
         if num.is_inf() {
             let currency_lang = self.currency_properties(currency);
             let target_lang = currency_lang.agreement_with_num(num);
@@ -978,35 +1255,94 @@ impl Language for Ukrainian {
                 currency_lang.to_cardinal(num)?,
                 target_lang.currencies(currency)
             ))
+        } else if num.is_negative() {
+            Ok(format!("{} {}", MINUS, self.to_currency(-num, currency)?))
         } else {
             let whole = num.int();
             let fraction = num.frac();
-            if fraction.is_zero() || num.is_inf() {
-                let currency_lang = self.currency_properties(currency);
-                let target_lang = currency_lang.agreement_with_num(whole);
-                Ok(format!(
-                    "{} {}",
-                    currency_lang.int_to_cardinal(whole)?,
-                    target_lang.currencies(currency)
-                ))
-            } else if whole.is_zero() {
-                let fraction = fraction * BigFloat::from(100).int();
-                let currency_lang = self.currency_fraction_properties(currency);
-                let target_lang = currency_lang.agreement_with_num(fraction);
+            if fraction.is_zero() && self.always_cents {
                 Ok(format!(
                     "{} {}",
-                    currency_lang.int_to_cardinal(fraction)?,
-                    target_lang.currency_fraction(currency)
+                    self.whole_currency(whole, currency)?,
+                    self.currency_fraction_words(fraction, currency)?
                 ))
+            } else if fraction.is_zero() {
+                self.whole_currency(whole, currency)
+            } else if whole.is_zero() && !self.zero_major {
+                self.currency_fraction_words(fraction, currency)
             } else {
                 Ok(format!(
                     "{} {}",
-                    self.to_currency(whole, currency)?,
-                    self.to_currency(fraction, currency)?,
+                    self.whole_currency(whole, currency)?,
+                    self.currency_fraction_words(fraction, currency)?,
                 ))
             }
         }
     }
+
+    fn to_percent(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let percent_lang = self.counting().masculine();
+        let target_lang = percent_lang.agreement_with_num(num);
+        Ok(format!(
+            "{} {}",
+            percent_lang.to_cardinal(num)?,
+            PERCENTS[target_lang.number.index()][target_lang.declension.index()]
+        ))
+    }
+
+    fn to_unit(&self, num: BigFloat, unit: Unit) -> Result<String, Num2Err> {
+        let unit_lang = self.unit_properties(unit);
+        let target_lang = unit_lang.agreement_with_num(num);
+        Ok(format!(
+            "{} {}",
+            unit_lang.to_cardinal(num)?,
+            target_lang.units(unit)
+        ))
+    }
+
+    fn plural_category(&self, num: BigFloat) -> PluralCategory {
+        if num.is_inf() {
+            // same agreement as zero: нескінченність доларів
+            return PluralCategory::Many;
+        }
+        if !num.frac().is_zero() {
+            return PluralCategory::Other;
+        }
+        let tail = (num.abs() % BigFloat::from(100))
+            .to_u64()
+            .unwrap_or_default();
+        plural_category_with_units::<Self>((tail / 10) as usize, (tail % 10) as usize)
+    }
+
+    fn agreement(&self, num: BigFloat) -> Agreement {
+        let category = self.plural_category(num);
+        if self.animate
+            && self.declension == Declension::Accusative
+            && category == PluralCategory::Few
+        {
+            // бачу двох котів
+            return Agreement {
+                case: Some(Declension::Genitive),
+                ..self.counted_agreement(num)
+            };
+        }
+        if self.collective_word(num).is_some() {
+            // a collective numeral counts like пʼять, with the noun in the
+            // genitive plural: двоє дітей
+            let noun = self.agreement_with_units(0, 5);
+            return Agreement {
+                category: PluralCategory::Many,
+                number: noun.number(),
+                gender: Some(noun.gender()),
+                case: Some(noun.declension()),
+            };
+        }
+        self.counted_agreement(num)
+    }
+
+    fn negative_marker(&self) -> &'static str {
+        "відʼємне"
+    }
 }
 
 #[cfg(test)]
@@ -1097,6 +1433,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_group_digits() {
+        assert_eq!(
+            Num2Words::new(1_000_000)
+                .lang(Lang::Ukrainian)
+                .ordinal_num()
+                .group_digits()
+                .to_words(),
+            Ok(String::from("1 000 000-й"))
+        );
+        assert_eq!(
+            Num2Words::new(23_000)
+                .lang(Lang::Ukrainian)
+                .ordinal_num()
+                .group_digits()
+                .prefer("ж")
+                .to_words(),
+            Ok(String::from("23 000-а"))
+        );
+    }
+
     #[test]
     fn test_ordinal_num() {
         assert_eq!(
@@ -1149,13 +1506,15 @@ mod tests {
             Ukrainian {
                 gender: Gender::Masculine,
                 number: GrammaticalNumber::Singular,
-                declension: Declension::Nominative
+                declension: Declension::Nominative,
+                ..Default::default()
             }
             .agreement_with_units(0, 0),
             Ukrainian {
                 gender: Gender::Masculine,
                 number: GrammaticalNumber::Plural,
-                declension: Declension::Genitive
+                declension: Declension::Genitive,
+                ..Default::default()
             },
             "failed agreement: 0"
         );
@@ -1163,13 +1522,15 @@ mod tests {
             Ukrainian {
                 gender: Gender::Masculine,
                 number: GrammaticalNumber::Singular,
-                declension: Declension::Nominative
+                declension: Declension::Nominative,
+                ..Default::default()
             }
             .agreement_with_units(0, 1),
             Ukrainian {
                 gender: Gender::Masculine,
                 number: GrammaticalNumber::Singular,
-                declension: Declension::Nominative
+                declension: Declension::Nominative,
+                ..Default::default()
             },
             "failed agreement: 1"
         );
@@ -1177,13 +1538,15 @@ mod tests {
             Ukrainian {
                 gender: Gender::Feminine,
                 number: GrammaticalNumber::Singular,
-                declension: Declension::Nominative
+                declension: Declension::Nominative,
+                ..Default::default()
             }
             .agreement_with_units(8, 2),
             Ukrainian {
                 gender: Gender::Feminine,
                 number: GrammaticalNumber::Plural,
-                declension: Declension::Nominative
+                declension: Declension::Nominative,
+                ..Default::default()
             },
             "failed agreement: 82"
         );
@@ -1191,13 +1554,15 @@ mod tests {
             Ukrainian {
                 gender: Gender::Masculine,
                 number: GrammaticalNumber::Singular,
-                declension: Declension::Dative
+                declension: Declension::Dative,
+                ..Default::default()
             }
             .agreement_with_units(1, 1),
             Ukrainian {
                 gender: Gender::Masculine,
                 number: GrammaticalNumber::Plural,
-                declension: Declension::Dative
+                declension: Declension::Dative,
+                ..Default::default()
             },
             "failed agreement: 11"
         );
@@ -1205,13 +1570,15 @@ mod tests {
             Ukrainian {
                 gender: Gender::Feminine,
                 number: GrammaticalNumber::Singular,
-                declension: Declension::Instrumental
+                declension: Declension::Instrumental,
+                ..Default::default()
             }
             .agreement_with_units(5, 4),
             Ukrainian {
                 gender: Gender::Feminine,
                 number: GrammaticalNumber::Plural,
-                declension: Declension::Instrumental
+                declension: Declension::Instrumental,
+                ..Default::default()
             },
             "failed agreement: 54"
         );
@@ -1219,13 +1586,15 @@ mod tests {
             Ukrainian {
                 gender: Gender::Masculine,
                 number: GrammaticalNumber::Singular,
-                declension: Declension::Nominative
+                declension: Declension::Nominative,
+                ..Default::default()
             }
             .agreement_with_units(1, 8),
             Ukrainian {
                 gender: Gender::Masculine,
                 number: GrammaticalNumber::Plural,
-                declension: Declension::Genitive
+                declension: Declension::Genitive,
+                ..Default::default()
             },
             "failed agreement: 18"
         );
@@ -1233,13 +1602,15 @@ mod tests {
             Ukrainian {
                 gender: Gender::Feminine,
                 number: GrammaticalNumber::Singular,
-                declension: Declension::Nominative
+                declension: Declension::Nominative,
+                ..Default::default()
             }
             .agreement_with_units(0, 1),
             Ukrainian {
                 gender: Gender::Feminine,
                 number: GrammaticalNumber::Singular,
-                declension: Declension::Nominative
+                declension: Declension::Nominative,
+                ..Default::default()
             },
             "failed agreement: 1 feminine"
         );
@@ -1327,6 +1698,76 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_large_orders() {
+        let parse = |num: &str| BigFloat::parse(num).unwrap();
+        let uk = Ukrainian::default();
+        assert_eq!(
+            uk.to_cardinal(parse("1e66")),
+            Ok(String::from("один унвігінтильйон"))
+        );
+        assert_eq!(
+            uk.to_cardinal(parse("2e120")),
+            Ok(String::from("два ундеквадрагінтильйони"))
+        );
+        assert_eq!(
+            uk.to_cardinal(parse("1e123")),
+            Ok(String::from("один квадрагінтильйон"))
+        );
+        assert_eq!(
+            uk.to_ordinal(parse("1e66")),
+            Ok(String::from("унвігінтильйонний"))
+        );
+        assert_eq!(
+            uk.to_ordinal(parse("1e120")),
+            Ok(String::from("ундеквадрагінтильйонний"))
+        );
+        assert_eq!(
+            uk.to_ordinal(parse("3e93")),
+            Ok(String::from("трьохтригінтильйонний"))
+        );
+        assert_eq!(uk.to_cardinal(parse("1e126")), Err(Num2Err::CannotConvert));
+        assert_eq!(uk.to_ordinal(parse("1e126")), Err(Num2Err::CannotConvert));
+    }
+
+    #[test]
+    fn test_ordinal_plural() {
+        let plural = |n: i64, case: &str| {
+            Num2Words::new(n)
+                .lang(Lang::Ukrainian)
+                .prefer("множина")
+                .prefer(case)
+                .ordinal()
+                .to_words()
+                .unwrap()
+        };
+        assert_eq!(plural(2, "н"), "другі");
+        assert_eq!(plural(2, "р"), "других");
+        assert_eq!(plural(2, "з"), "другі");
+        assert_eq!(plural(13, "н"), "тринадцяті");
+        assert_eq!(plural(13, "о"), "тринадцятими");
+        assert_eq!(plural(42, "н"), "сорок другі");
+        assert_eq!(plural(42, "д"), "сорок другим");
+        assert_eq!(plural(42, "м"), "сорок других");
+        assert_eq!(plural(23, "д"), "двадцять третім");
+        assert_eq!(plural(100, "н"), "соті");
+        assert_eq!(plural(100, "р"), "сотих");
+        assert_eq!(plural(1000, "н"), "тисячні");
+        assert_eq!(plural(1000, "о"), "тисячними");
+        assert_eq!(plural(456_000, "н"), "чотирьохсотпʼятдесятишеститисячні");
+        assert_eq!(plural(456_000, "д"), "чотирьохсотпʼятдесятишеститисячним");
+        assert_eq!(plural(456_000, "з"), "чотирьохсотпʼятдесятишеститисячні");
+        assert_eq!(
+            Num2Words::new(42)
+                .lang(Lang::Ukrainian)
+                .prefer("множина")
+                .prefer("з")
+                .ordinal_num()
+                .to_words(),
+            Ok(String::from("42-і"))
+        );
+    }
+
     #[test]
     fn test_currency() {
         assert_eq!(
@@ -1350,6 +1791,14 @@ mod tests {
                 .to_words(),
             Ok(String::from("триста тридцять три нові шекелі дві агори"))
         );
+        assert_eq!(
+            Num2Words::new(3)
+                .lang(Lang::Ukrainian)
+                .currency(Currency::ILS)
+                .prefer("з")
+                .to_words(),
+            Ok(String::from("три нові шекелі"))
+        );
         assert_eq!(
             Num2Words::new(934.42)
                 .lang(Lang::Ukrainian)
@@ -1362,6 +1811,303 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_negative_currency() {
+        assert_eq!(
+            Num2Words::new(-0.25)
+                .lang(Lang::Ukrainian)
+                .currency(Currency::UAH)
+                .to_words(),
+            Ok(String::from("мінус двадцять пʼять копійок"))
+        );
+        assert_eq!(
+            Num2Words::new(-1.00)
+                .lang(Lang::Ukrainian)
+                .currency(Currency::UAH)
+                .to_words(),
+            Ok(String::from("мінус одна гривня"))
+        );
+        assert_eq!(
+            Num2Words::new(-5.10)
+                .lang(Lang::Ukrainian)
+                .currency(Currency::UAH)
+                .to_words(),
+            Ok(String::from("мінус пʼять гривень десять копійок"))
+        );
+    }
+
+    #[test]
+    fn test_always_cents() {
+        assert_eq!(
+            Num2Words::new(42)
+                .lang(Lang::Ukrainian)
+                .currency(Currency::UAH)
+                .prefer("always-cents")
+                .to_words(),
+            Ok(String::from("сорок дві гривні нуль копійок"))
+        );
+    }
+
+    #[test]
+    fn test_currency_rounding() {
+        let uah = |num: f64| {
+            Num2Words::new(num)
+                .lang(Lang::Ukrainian)
+                .currency(Currency::UAH)
+                .to_words()
+        };
+        assert_eq!(uah(10.005), Ok(String::from("десять гривень одна копійка")));
+        assert_eq!(
+            uah(934.425),
+            Ok(String::from(
+                "девʼятсот тридцять чотири гривні сорок три копійки"
+            ))
+        );
+        assert_eq!(uah(0.999), Ok(String::from("одна гривня")));
+        assert_eq!(uah(0.994), Ok(String::from("девʼяносто девʼять копійок")));
+        assert_eq!(
+            uah(-2.675),
+            Ok(String::from("мінус дві гривні шістдесят вісім копійок"))
+        );
+    }
+
+    #[test]
+    fn test_comma() {
+        let comma = |num: f64| {
+            Num2Words::new(num)
+                .lang(Lang::Ukrainian)
+                .prefer("кома")
+                .to_words()
+        };
+        assert_eq!(comma(1.1), Ok(String::from("одна кома один")));
+        assert_eq!(comma(2.05), Ok(String::from("дві кома нуль пʼять")));
+        assert_eq!(
+            comma(21.12),
+            Ok(String::from("двадцять одна кома один два"))
+        );
+        assert_eq!(comma(-0.5), Ok(String::from("мінус нуль кома пʼять")));
+        assert_eq!(comma(42.0), Ok(String::from("сорок два")));
+        assert_eq!(
+            Num2Words::new(1.1)
+                .lang(Lang::Ukrainian)
+                .prefer("comma")
+                .prefer("родовий")
+                .to_words(),
+            Ok(String::from("одної кома одного"))
+        );
+        assert_eq!(
+            Num2Words::new(1.1).lang(Lang::Ukrainian).to_words(),
+            Ok(String::from("одна ціла одна десята"))
+        );
+    }
+
+    #[test]
+    fn test_animate_accusative() {
+        let accusative = |num: i64, preferences: &[&str]| {
+            preferences
+                .iter()
+                .fold(
+                    Num2Words::new(num)
+                        .lang(Lang::Ukrainian)
+                        .prefer("знахідний"),
+                    |n2w, p| n2w.prefer(*p),
+                )
+                .to_words()
+        };
+        for (num, inanimate, animate) in [
+            (2, "два", "двох"),
+            (3, "три", "трьох"),
+            (22, "двадцять два", "двадцять двох"),
+            (34, "тридцять чотири", "тридцять чотирьох"),
+            (21, "двадцять один", "двадцять одного"),
+            (5, "пʼять", "пʼять"),
+            (12, "дванадцять", "дванадцять"),
+            (2002, "дві тисячі два", "дві тисячі двох"),
+        ] {
+            assert_eq!(accusative(num, &[]), Ok(String::from(inanimate)));
+            assert_eq!(accusative(num, &["істота"]), Ok(String::from(animate)));
+        }
+        assert_eq!(accusative(2, &["animate", "ж"]), Ok(String::from("двох")));
+        assert_eq!(accusative(1, &["animate", "ж"]), Ok(String::from("одну")));
+        assert_eq!(
+            Num2Words::new(2)
+                .lang(Lang::Ukrainian)
+                .prefer("знахідний")
+                .prefer("animate")
+                .agreement()
+                .unwrap()
+                .case,
+            Some(Declension::Genitive)
+        );
+        // the currencies are inanimate
+        assert_eq!(
+            Num2Words::new(2)
+                .lang(Lang::Ukrainian)
+                .prefer("знахідний")
+                .prefer("animate")
+                .currency(Currency::USD)
+                .to_words(),
+            Ok(String::from("два долари"))
+        );
+    }
+
+    #[test]
+    fn test_kzt() {
+        let kzt = |num: f64| {
+            Num2Words::new(num)
+                .lang(Lang::Ukrainian)
+                .currency(Currency::KZT)
+                .to_words()
+        };
+        assert_eq!(kzt(1.01), Ok(String::from("один тенге один тиїн")));
+        assert_eq!(kzt(2.02), Ok(String::from("два тенге два тиїни")));
+        assert_eq!(kzt(5.05), Ok(String::from("пʼять тенге пʼять тиїнів")));
+    }
+
+    #[test]
+    fn test_collective() {
+        let cardinal = |num: i64, preferences: &[&str]| {
+            preferences
+                .iter()
+                .fold(Num2Words::new(num).lang(Lang::Ukrainian), |n2w, p| {
+                    n2w.prefer(*p)
+                })
+                .to_words()
+        };
+        assert_eq!(cardinal(2, &[]), Ok(String::from("два")));
+        assert_eq!(cardinal(2, &["collective"]), Ok(String::from("двоє")));
+        assert_eq!(cardinal(5, &[]), Ok(String::from("пʼять")));
+        assert_eq!(cardinal(5, &["collective"]), Ok(String::from("пʼятеро")));
+        assert_eq!(cardinal(7, &[]), Ok(String::from("сім")));
+        assert_eq!(cardinal(7, &["collective"]), Ok(String::from("семеро")));
+        assert_eq!(
+            cardinal(11, &["collective"]),
+            Ok(String::from("одинадцятеро"))
+        );
+        assert_eq!(
+            cardinal(20, &["collective"]),
+            Ok(String::from("двадцятеро"))
+        );
+        assert_eq!(
+            cardinal(7, &["collective", "родовий"]),
+            Ok(String::from("сімох"))
+        );
+        // only two to twenty have collective forms
+        assert_eq!(cardinal(1, &["collective"]), Ok(String::from("один")));
+        assert_eq!(
+            cardinal(22, &["collective"]),
+            Ok(String::from("двадцять два"))
+        );
+        assert_eq!(
+            Num2Words::new(2)
+                .lang(Lang::Ukrainian)
+                .prefer("collective")
+                .unit(Unit::Meter)
+                .to_words(),
+            Ok(String::from("два метри"))
+        );
+
+        for num in [2, 5, 7] {
+            let agreement = |preferences: &[&str]| {
+                preferences
+                    .iter()
+                    .fold(Num2Words::new(num).lang(Lang::Ukrainian), |n2w, p| {
+                        n2w.prefer(*p)
+                    })
+                    .agreement()
+                    .unwrap()
+            };
+            let collective = agreement(&["collective"]);
+            assert_eq!(collective.number, GrammaticalNumber::Plural);
+            assert_eq!(collective.case, Some(Declension::Genitive));
+            assert_eq!(
+                agreement(&["collective", "давальний"]).case,
+                Some(Declension::Dative)
+            );
+        }
+        assert_eq!(
+            Num2Words::new(2)
+                .lang(Lang::Ukrainian)
+                .agreement()
+                .unwrap()
+                .case,
+            Some(Declension::Nominative)
+        );
+    }
+
+    #[test]
+    fn test_currency_cyrillic() {
+        for currency in Currency::ALL {
+            for num in [1.01, 2.02, 5.05, 21.21] {
+                let words = Num2Words::new(num)
+                    .lang(Lang::Ukrainian)
+                    .currency(currency)
+                    .to_words()
+                    .unwrap();
+                assert!(
+                    words
+                        .chars()
+                        .all(|c| ('\u{400}'..='\u{4ff}').contains(&c) || c == ' ' || c == 'ʼ'),
+                    "{}: {}",
+                    currency.code(),
+                    words
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_rub() {
+        let rub = |num: f64| {
+            Num2Words::new(num)
+                .lang(Lang::Ukrainian)
+                .currency(Currency::RUB)
+                .to_words()
+        };
+        assert_eq!(rub(1.0), Ok(String::from("один рубль")));
+        assert_eq!(rub(2.02), Ok(String::from("два рублі дві копійки")));
+        assert_eq!(rub(5.05), Ok(String::from("пʼять рублів пʼять копійок")));
+        assert_eq!(
+            rub(21.01),
+            Ok(String::from("двадцять один рубль одна копійка"))
+        );
+    }
+
+    #[test]
+    fn test_currency_zero_major() {
+        assert_eq!(
+            Num2Words::new(0.20)
+                .lang(Lang::Ukrainian)
+                .currency(Currency::UAH)
+                .prefer("zero-major")
+                .to_words(),
+            Ok(String::from("нуль гривень двадцять копійок"))
+        );
+        assert_eq!(
+            Num2Words::new(0.01)
+                .lang(Lang::Ukrainian)
+                .currency(Currency::UAH)
+                .prefer("zero-major")
+                .to_words(),
+            Ok(String::from("нуль гривень одна копійка"))
+        );
+        assert_eq!(
+            Num2Words::new(0.00)
+                .lang(Lang::Ukrainian)
+                .currency(Currency::UAH)
+                .prefer("zero-major")
+                .to_words(),
+            Ok(String::from("нуль гривень"))
+        );
+        assert_eq!(
+            Num2Words::new(0.20)
+                .lang(Lang::Ukrainian)
+                .currency(Currency::UAH)
+                .to_words(),
+            Ok(String::from("двадцять копійок"))
+        );
+    }
+
     #[test]
     fn test_year() {
         assert_eq!(
@@ -1378,6 +2124,44 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_year_range() {
+        assert_eq!(
+            Num2Words::new(2020)
+                .lang(Lang::Ukrainian)
+                .year_range(2023)
+                .to_words(),
+            Ok(String::from(
+                "дві тисячі двадцятий – дві тисячі двадцять третій роки"
+            ))
+        );
+        assert_eq!(
+            Num2Words::new(2020)
+                .lang(Lang::Ukrainian)
+                .prefer("місцевий")
+                .year_range(2023)
+                .to_words(),
+            Ok(String::from(
+                "дві тисячі двадцятому – дві тисячі двадцять третьому роках"
+            ))
+        );
+        assert_eq!(
+            Num2Words::new(2020)
+                .lang(Lang::Ukrainian)
+                .prefer("місцевий")
+                .year_range(2020)
+                .to_words(),
+            Ok(String::from("дві тисячі двадцятому році"))
+        );
+        assert_eq!(
+            Num2Words::new(-67)
+                .lang(Lang::Ukrainian)
+                .year_range(-60)
+                .to_words(),
+            Ok(String::from("шістдесят сьомий – шістдесятий роки до н.е."))
+        );
+    }
+
     #[test]
     fn test_float() {
         assert_eq!(
@@ -1467,4 +2251,73 @@ mod tests {
             Ok(String::from("нескінченність доларів"))
         );
     }
+
+    #[test]
+    fn test_agreement_matrix() {
+        let matrix = [
+            (
+                -0.5,
+                "мінус пʼятдесят євроцентів",
+                "мінус нуль цілих пʼять десятих відсотка",
+                "мінус нуль цілих пʼять десятих години",
+            ),
+            (
+                -1.0,
+                "мінус один євро",
+                "мінус один відсоток",
+                "мінус одна година",
+            ),
+            (0.0, "нуль євро", "нуль відсотків", "нуль годин"),
+            (
+                0.5,
+                "пʼятдесят євроцентів",
+                "нуль цілих пʼять десятих відсотка",
+                "нуль цілих пʼять десятих години",
+            ),
+            (1.0, "один євро", "один відсоток", "одна година"),
+            (2.0, "два євро", "два відсотки", "дві години"),
+            (5.0, "пʼять євро", "пʼять відсотків", "пʼять годин"),
+            (
+                21.0,
+                "двадцять один євро",
+                "двадцять один відсоток",
+                "двадцять одна година",
+            ),
+            (
+                22.0,
+                "двадцять два євро",
+                "двадцять два відсотки",
+                "двадцять дві години",
+            ),
+            (
+                25.0,
+                "двадцять пʼять євро",
+                "двадцять пʼять відсотків",
+                "двадцять пʼять годин",
+            ),
+        ];
+        for (num, currency, percent, unit) in matrix.iter() {
+            assert_eq!(
+                Num2Words::new(*num)
+                    .lang(Lang::Ukrainian)
+                    .currency(Currency::EUR)
+                    .to_words(),
+                Ok(String::from(*currency))
+            );
+            assert_eq!(
+                Num2Words::new(*num)
+                    .lang(Lang::Ukrainian)
+                    .percent()
+                    .to_words(),
+                Ok(String::from(*percent))
+            );
+            assert_eq!(
+                Num2Words::new(*num)
+                    .lang(Lang::Ukrainian)
+                    .unit(Unit::Hour)
+                    .to_words(),
+                Ok(String::from(*unit))
+            );
+        }
+    }
 }
@@ -0,0 +1,449 @@
+use crate::{num2words::Num2Err, Currency, Language, PluralCategory, Unit};
+use num_bigfloat::BigFloat;
+
+pub struct Uzbek {
+    cyrillic: bool,
+    zero_major: bool,
+}
+
+// every word is given in the Latin script first, then in the Cyrillic script
+const UNITS: [[&str; 10]; 2] = [
+    [
+        "nol",
+        "bir",
+        "ikki",
+        "uch",
+        "to‘rt",
+        "besh",
+        "olti",
+        "yetti",
+        "sakkiz",
+        "to‘qqiz",
+    ],
+    [
+        "нол",
+        "бир",
+        "икки",
+        "уч",
+        "тўрт",
+        "беш",
+        "олти",
+        "етти",
+        "саккиз",
+        "тўққиз",
+    ],
+];
+
+const TENS: [[&str; 10]; 2] = [
+    [
+        "",
+        "o‘n",
+        "yigirma",
+        "o‘ttiz",
+        "qirq",
+        "ellik",
+        "oltmish",
+        "yetmish",
+        "sakson",
+        "to‘qson",
+    ],
+    [
+        "",
+        "ўн",
+        "йигирма",
+        "ўттиз",
+        "қирқ",
+        "эллик",
+        "олтмиш",
+        "етмиш",
+        "саксон",
+        "тўқсон",
+    ],
+];
+
+const MEGAS: [[&str; 5]; 2] = [
+    ["ming", "million", "milliard", "trillion", "kvadrillion"],
+    ["минг", "миллион", "миллиард", "триллион", "квадриллион"],
+];
+
+impl Uzbek {
+    pub fn new(cyrillic: bool) -> Self {
+        Self {
+            cyrillic,
+            zero_major: false,
+        }
+    }
+
+    /// Keeps the major unit clause for amounts below one, e.g. `nol so‘m
+    /// yigirma tiyin`
+    pub fn zero_major(self, zero_major: bool) -> Self {
+        Self { zero_major, ..self }
+    }
+
+    fn script(&self) -> usize {
+        self.cyrillic as usize
+    }
+
+    fn pick(&self, latin: &'static str, cyrillic: &'static str) -> &'static str {
+        if self.cyrillic {
+            cyrillic
+        } else {
+            latin
+        }
+    }
+
+    fn currencies(&self, currency: Currency) -> String {
+        match currency {
+            Currency::DOLLAR | Currency::USD => String::from(self.pick("dollar", "доллар")),
+            Currency::EUR => String::from(self.pick("yevro", "евро")),
+            Currency::RUB => String::from(self.pick("rubl", "рубль")),
+            Currency::UZS => String::from(self.pick("so‘m", "сўм")),
+            _ => currency.default_string(false),
+        }
+    }
+
+    fn cents(&self, currency: Currency) -> String {
+        match currency {
+            Currency::RUB | Currency::UZS => String::from(self.pick("tiyin", "тийин")),
+            _ => String::from(self.pick("sent", "цент")),
+        }
+    }
+
+    fn units(&self, unit: Unit) -> &'static str {
+        match unit {
+            Unit::Meter => self.pick("metr", "метр"),
+            Unit::Kilometer => self.pick("kilometr", "километр"),
+            Unit::Gram => self.pick("gramm", "грамм"),
+            Unit::Kilogram => self.pick("kilogramm", "килограмм"),
+            Unit::Liter => self.pick("litr", "литр"),
+            Unit::Second => self.pick("soniya", "сония"),
+            Unit::Minute => self.pick("daqiqa", "дақиқа"),
+            Unit::Hour => self.pick("soat", "соат"),
+        }
+    }
+
+    fn split_thousands(&self, mut num: BigFloat) -> Vec<u64> {
+        let mut thousands = Vec::new();
+        let bf_1000 = BigFloat::from(1000);
+
+        while !num.is_zero() {
+            thousands.push((num % bf_1000).to_u64().unwrap());
+            num /= bf_1000;
+        }
+
+        thousands
+    }
+
+    fn triplet_to_words(&self, triplet: u64) -> Vec<&'static str> {
+        let units = &UNITS[self.script()];
+        let mut words = vec![];
+
+        let hundreds = (triplet / 100) as usize;
+        if hundreds > 1 {
+            words.push(units[hundreds]);
+        }
+        if hundreds > 0 {
+            words.push(self.pick("yuz", "юз"));
+        }
+
+        let tens = (triplet / 10 % 10) as usize;
+        if tens > 0 {
+            words.push(TENS[self.script()][tens]);
+        }
+
+        let unit = (triplet % 10) as usize;
+        if unit > 0 {
+            words.push(units[unit]);
+        }
+
+        words
+    }
+
+    fn int_to_cardinal(&self, mut num: BigFloat) -> Result<String, Num2Err> {
+        // special case zero
+        if num.is_zero() {
+            return Ok(String::from(UNITS[self.script()][0]));
+        }
+
+        // handling negative values
+        let mut words = vec![];
+        if num.is_negative() {
+            words.push(self.pick("minus", "минус"));
+            num = -num;
+        }
+
+        let triplets = self.split_thousands(num);
+        for (i, triplet) in triplets.iter().enumerate().rev() {
+            if *triplet == 0 {
+                continue;
+            }
+            if i > MEGAS[0].len() {
+                return Err(Num2Err::CannotConvert);
+            }
+
+            match i {
+                0 => words.extend(self.triplet_to_words(*triplet)),
+                // a lone thousand is not counted, e.g. ming ikki
+                1 if *triplet == 1 => words.push(MEGAS[self.script()][0]),
+                _ => {
+                    words.extend(self.triplet_to_words(*triplet));
+                    words.push(MEGAS[self.script()][i - 1]);
+                }
+            }
+        }
+
+        Ok(words.join(" "))
+    }
+
+    fn float_to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let integral_part = num.int();
+        let mut words: Vec<String> = vec![];
+
+        if !integral_part.is_zero() {
+            let integral_word = self.int_to_cardinal(integral_part)?;
+            words.push(integral_word);
+        } else if num.is_negative() {
+            words.push(String::from(self.pick("minus", "минус")));
+        }
+        if integral_part.is_zero() {
+            words.push(String::from(UNITS[self.script()][0]));
+        }
+
+        let mut ordinal_part = num.frac().abs();
+        if !ordinal_part.is_zero() {
+            words.push(String::from(self.pick("butun", "бутун")));
+        }
+        while !ordinal_part.is_zero() {
+            let digit = (ordinal_part * BigFloat::from(10)).int();
+            ordinal_part = (ordinal_part * BigFloat::from(10)).frac();
+            words.push(String::from(
+                UNITS[self.script()][digit.to_u64().unwrap() as usize],
+            ));
+        }
+        Ok(words.join(" "))
+    }
+
+    // Ordinals take -nchi after a vowel, e.g. ikkinchi, and -inchi after a
+    // consonant, e.g. uchinchi
+    fn ordinal_suffix(&self, word: &str) -> &'static str {
+        let vowels = self.pick("aeiou", "аеиоуўэюяё");
+        match word.chars().last() {
+            Some(c) if vowels.contains(c) => self.pick("nchi", "нчи"),
+            _ => self.pick("inchi", "инчи"),
+        }
+    }
+}
+
+impl Language for Uzbek {
+    fn to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num.is_inf_pos() {
+            Ok(String::from(self.pick("cheksizlik", "чексизлик")))
+        } else if num.is_inf_neg() {
+            Ok(String::from(
+                self.pick("minus cheksizlik", "минус чексизлик"),
+            ))
+        } else if num.frac().is_zero() {
+            self.int_to_cardinal(num)
+        } else {
+            self.float_to_cardinal(num)
+        }
+    }
+
+    fn to_ordinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let cardinal_word = self.to_cardinal(num)?;
+        let suffix = self.ordinal_suffix(&cardinal_word);
+        Ok(format!("{}{}", cardinal_word, suffix))
+    }
+
+    fn to_ordinal_num(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let cardinal_word = self.to_cardinal(num)?;
+        Ok(format!(
+            "{}-{}",
+            num.to_u128().unwrap(),
+            self.ordinal_suffix(&cardinal_word)
+        ))
+    }
+
+    fn to_year(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if !num.frac().is_zero() {
+            return Err(Num2Err::FloatingYear);
+        }
+
+        let words = self.int_to_cardinal(num.abs())?;
+        if num.is_negative() {
+            Ok(format!(
+                "{} {}",
+                self.pick("miloddan avvalgi", "милоддан аввалги"),
+                words
+            ))
+        } else {
+            Ok(words)
+        }
+    }
+
+    fn to_currency(&self, num: BigFloat, currency: Currency) -> Result<String, Num2Err> {
+        if num.is_inf() || num.frac().is_zero() {
+            Ok(format!(
+                "{} {}",
+                self.to_cardinal(num)?,
+                self.currencies(currency)
+            ))
+        } else {
+            let integral_part = num.int();
+            let cents_nb = (num * BigFloat::from(100)).int() % BigFloat::from(100);
+            let cents_words = format!(
+                "{} {}",
+                self.int_to_cardinal(cents_nb)?,
+                self.cents(currency)
+            );
+            let integral_word = self.to_currency(integral_part, currency)?;
+
+            if cents_nb.is_zero() {
+                Ok(integral_word)
+            } else if integral_part.is_zero() && !self.zero_major {
+                Ok(cents_words)
+            } else {
+                Ok(format!("{} {}", integral_word, cents_words))
+            }
+        }
+    }
+
+    fn to_percent(&self, num: BigFloat) -> Result<String, Num2Err> {
+        Ok(format!(
+            "{} {}",
+            self.to_cardinal(num)?,
+            self.pick("foiz", "фоиз")
+        ))
+    }
+
+    fn to_unit(&self, num: BigFloat, unit: Unit) -> Result<String, Num2Err> {
+        // nouns stay in the singular after a number, e.g. ikki soat
+        Ok(format!("{} {}", self.to_cardinal(num)?, self.units(unit)))
+    }
+
+    fn plural_category(&self, num: BigFloat) -> PluralCategory {
+        if num.abs() == BigFloat::from(1) {
+            PluralCategory::One
+        } else {
+            PluralCategory::Other
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_cardinal() {
+        assert_eq!(
+            Num2Words::new(0).lang(Lang::Uzbek).to_words(),
+            Ok(String::from("nol"))
+        );
+        assert_eq!(
+            Num2Words::new(42).lang(Lang::Uzbek).to_words(),
+            Ok(String::from("qirq ikki"))
+        );
+        assert_eq!(
+            Num2Words::new(100).lang(Lang::Uzbek).to_words(),
+            Ok(String::from("yuz"))
+        );
+        assert_eq!(
+            Num2Words::new(1234).lang(Lang::Uzbek).to_words(),
+            Ok(String::from("ming ikki yuz o‘ttiz to‘rt"))
+        );
+        assert_eq!(
+            Num2Words::new(2_000_014).lang(Lang::Uzbek).to_words(),
+            Ok(String::from("ikki million o‘n to‘rt"))
+        );
+        assert_eq!(
+            Num2Words::new(-2.5).lang(Lang::Uzbek).to_words(),
+            Ok(String::from("minus ikki butun besh"))
+        );
+        assert_eq!(
+            Num2Words::new(0.5).lang(Lang::Uzbek).to_words(),
+            Ok(String::from("nol butun besh"))
+        );
+        assert_eq!(
+            Num2Words::new(-0.25).lang(Lang::Uzbek).to_words(),
+            Ok(String::from("minus nol butun ikki besh"))
+        );
+    }
+
+    #[test]
+    fn test_cyrillic() {
+        assert_eq!(
+            Num2Words::new(42)
+                .lang(Lang::Uzbek)
+                .prefer("cyrillic")
+                .to_words(),
+            Ok(String::from("қирқ икки"))
+        );
+        assert_eq!(
+            Num2Words::new(6)
+                .lang(Lang::Uzbek)
+                .prefer("cyrillic")
+                .ordinal()
+                .to_words(),
+            Ok(String::from("олтинчи"))
+        );
+        assert_eq!(
+            Num2Words::new(42.01)
+                .lang(Lang::Uzbek)
+                .prefer("cyrillic")
+                .currency(Currency::UZS)
+                .to_words(),
+            Ok(String::from("қирқ икки сўм бир тийин"))
+        );
+    }
+
+    #[test]
+    fn test_ordinal() {
+        assert_eq!(
+            Num2Words::new(2).lang(Lang::Uzbek).ordinal().to_words(),
+            Ok(String::from("ikkinchi"))
+        );
+        assert_eq!(
+            Num2Words::new(6).lang(Lang::Uzbek).ordinal().to_words(),
+            Ok(String::from("oltinchi"))
+        );
+        assert_eq!(
+            Num2Words::new(3).lang(Lang::Uzbek).ordinal().to_words(),
+            Ok(String::from("uchinchi"))
+        );
+        assert_eq!(
+            Num2Words::new(40).lang(Lang::Uzbek).ordinal().to_words(),
+            Ok(String::from("qirqinchi"))
+        );
+        assert_eq!(
+            Num2Words::new(42)
+                .lang(Lang::Uzbek)
+                .ordinal_num()
+                .to_words(),
+            Ok(String::from("42-nchi"))
+        );
+        assert_eq!(
+            Num2Words::new(1).lang(Lang::Uzbek).ordinal_num().to_words(),
+            Ok(String::from("1-inchi"))
+        );
+    }
+
+    #[test]
+    fn test_currency() {
+        assert_eq!(
+            Num2Words::new(42.01)
+                .lang(Lang::Uzbek)
+                .currency(Currency::UZS)
+                .to_words(),
+            Ok(String::from("qirq ikki so‘m bir tiyin"))
+        );
+        assert_eq!(
+            Num2Words::new(0.2)
+                .lang(Lang::Uzbek)
+                .currency(Currency::UZS)
+                .prefer("zero-major")
+                .to_words(),
+            Ok(String::from("nol so‘m yigirma tiyin"))
+        );
+    }
+}
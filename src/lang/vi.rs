@@ -0,0 +1,376 @@
+use crate::{
+    num2words::Num2Err, Agreement, Currency, GrammaticalNumber, Language, PluralCategory, Unit,
+};
+use num_bigfloat::BigFloat;
+
+pub struct Vietnamese {
+    prefer_linh: bool,
+    prefer_tu: bool,
+    prefer_ngan: bool,
+    zero_major: bool,
+}
+
+const UNITS: [&str; 10] = [
+    "không", "một", "hai", "ba", "bốn", "năm", "sáu", "bảy", "tám", "chín",
+];
+
+impl Vietnamese {
+    pub fn new(prefer_linh: bool, prefer_tu: bool, prefer_ngan: bool) -> Self {
+        Self {
+            prefer_linh,
+            prefer_tu,
+            prefer_ngan,
+            zero_major: false,
+        }
+    }
+
+    /// Keeps the major unit clause for amounts below one, e.g. `không đô la
+    /// hai mươi xu`
+    pub fn zero_major(self, zero_major: bool) -> Self {
+        Self { zero_major, ..self }
+    }
+
+    fn currencies(&self, currency: Currency) -> String {
+        match currency {
+            Currency::VND => String::from("đồng"),
+            Currency::AUD
+            | Currency::CAD
+            | Currency::DOLLAR
+            | Currency::HKD
+            | Currency::NZD
+            | Currency::SGD
+            | Currency::TWD
+            | Currency::USD => String::from("đô la"),
+            Currency::EUR => String::from("euro"),
+            Currency::GBP => String::from("bảng Anh"),
+            Currency::JPY => String::from("yên"),
+            Currency::CNY => String::from("nhân dân tệ"),
+            _ => currency.default_string(false),
+        }
+    }
+
+    fn units(&self, unit: Unit) -> &'static str {
+        match unit {
+            Unit::Meter => "mét",
+            Unit::Kilometer => "ki-lô-mét",
+            Unit::Gram => "gam",
+            Unit::Kilogram => "ki-lô-gam",
+            Unit::Liter => "lít",
+            Unit::Second => "giây",
+            Unit::Minute => "phút",
+            Unit::Hour => "giờ",
+        }
+    }
+
+    // name of the power of a thousand, the billion being repeated past it,
+    // e.g. "nghìn tỷ" for a trillion
+    fn mega(&self, i: usize) -> String {
+        let thousand = if self.prefer_ngan { "ngàn" } else { "nghìn" };
+        let mut words = vec![];
+        match i % 3 {
+            1 => words.push(thousand),
+            2 => words.push("triệu"),
+            _ => (),
+        }
+        words.extend(std::iter::repeat_n("tỷ", i / 3));
+        words.join(" ")
+    }
+
+    fn split_thousands(&self, mut num: BigFloat) -> Vec<u64> {
+        let mut thousands = Vec::new();
+        let bf_1000 = BigFloat::from(1000);
+
+        while !num.is_zero() {
+            thousands.push((num % bf_1000).to_u64().unwrap());
+            num /= bf_1000;
+        }
+
+        thousands
+    }
+
+    // a triplet after a higher group is read in full, e.g. "không trăm lẻ
+    // một" in "một nghìn không trăm lẻ một"
+    fn triplet_to_words(&self, triplet: u64, full: bool) -> Vec<&'static str> {
+        let hundreds = (triplet / 100 % 10) as usize;
+        let tens = (triplet / 10 % 10) as usize;
+        let units = (triplet % 10) as usize;
+        let mut words = vec![];
+
+        if hundreds > 0 || full {
+            words.push(UNITS[hundreds]);
+            words.push("trăm");
+        }
+
+        match tens {
+            // a skipped ten is marked before the unit, e.g. "một trăm lẻ một"
+            0 if units > 0 && !words.is_empty() => {
+                words.push(if self.prefer_linh { "linh" } else { "lẻ" })
+            }
+            0 => (),
+            1 => words.push("mười"),
+            _ => {
+                words.push(UNITS[tens]);
+                words.push("mươi");
+            }
+        }
+
+        // a unit after a ten changes form, e.g. "hai mươi mốt", "mười lăm"
+        match units {
+            0 => (),
+            1 if tens > 1 => words.push("mốt"),
+            4 if tens > 1 && self.prefer_tu => words.push("tư"),
+            5 if tens > 0 => words.push("lăm"),
+            _ => words.push(UNITS[units]),
+        }
+
+        words
+    }
+
+    fn int_to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        // special case zero
+        if num.is_zero() {
+            return Ok(String::from(UNITS[0]));
+        }
+
+        let mut words = vec![];
+        for (i, triplet) in self.split_thousands(num.abs()).iter().enumerate().rev() {
+            if *triplet == 0 {
+                continue;
+            }
+
+            let full = !words.is_empty();
+            words.extend(
+                self.triplet_to_words(*triplet, full)
+                    .into_iter()
+                    .map(String::from),
+            );
+            if i != 0 {
+                words.push(self.mega(i));
+            }
+        }
+
+        // handling negative values
+        if num.is_negative() {
+            words.insert(0, String::from("âm"));
+        }
+
+        Ok(words.join(" "))
+    }
+
+    fn float_to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let mut words = vec![];
+        if num.is_negative() {
+            words.push(String::from("âm"));
+        }
+        words.push(self.int_to_cardinal(num.int().abs())?);
+        words.push(String::from("phẩy"));
+
+        let mut ordinal_part = num.frac().abs();
+        while !ordinal_part.is_zero() {
+            let digit = (ordinal_part * BigFloat::from(10)).int();
+            ordinal_part = (ordinal_part * BigFloat::from(10)).frac();
+            words.push(self.int_to_cardinal(digit)?);
+        }
+
+        Ok(words.join(" "))
+    }
+
+    // nouns do not change after a number, e.g. "bốn mươi hai đồng"
+    fn with_noun(&self, num: BigFloat, noun: &str) -> Result<String, Num2Err> {
+        Ok(format!("{} {}", self.to_cardinal(num)?, noun))
+    }
+}
+
+impl Language for Vietnamese {
+    fn to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num.is_inf_pos() {
+            Ok(String::from("vô cực"))
+        } else if num.is_inf_neg() {
+            Ok(String::from("âm vô cực"))
+        } else if num.frac().is_zero() {
+            self.int_to_cardinal(num)
+        } else {
+            self.float_to_cardinal(num)
+        }
+    }
+
+    fn to_ordinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        // only the first and the fourth have their own word
+        if num == BigFloat::from(1) {
+            Ok(String::from("thứ nhất"))
+        } else if num == BigFloat::from(4) {
+            Ok(String::from("thứ tư"))
+        } else {
+            Ok(format!("thứ {}", self.int_to_cardinal(num)?))
+        }
+    }
+
+    fn to_ordinal_num(&self, num: BigFloat) -> Result<String, Num2Err> {
+        Ok(format!("thứ {}", num.to_u128().unwrap()))
+    }
+
+    fn to_year(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if !num.frac().is_zero() {
+            return Err(Num2Err::FloatingYear);
+        }
+
+        let year_word = self.int_to_cardinal(num.abs())?;
+        if num.is_negative() {
+            Ok(format!("{} trước Công nguyên", year_word))
+        } else {
+            Ok(year_word)
+        }
+    }
+
+    fn to_currency(&self, num: BigFloat, currency: Currency) -> Result<String, Num2Err> {
+        if num.is_inf() || num.frac().is_zero() {
+            return self.with_noun(num, &self.currencies(currency));
+        }
+        // the xu is no longer in use, amounts are read in whole đồng
+        if let Currency::VND = currency {
+            return self.with_noun(num.int(), &self.currencies(currency));
+        }
+
+        let integral_part = num.int();
+        let cents_nb = (num * BigFloat::from(100)).int() % BigFloat::from(100);
+        let cents_words = self.with_noun(cents_nb, "xu")?;
+        let integral_word = self.to_currency(integral_part, currency)?;
+
+        if cents_nb.is_zero() {
+            Ok(integral_word)
+        } else if integral_part.is_zero() && !self.zero_major {
+            Ok(cents_words)
+        } else {
+            Ok(format!("{} {}", integral_word, cents_words))
+        }
+    }
+
+    fn to_percent(&self, num: BigFloat) -> Result<String, Num2Err> {
+        self.with_noun(num, "phần trăm")
+    }
+
+    fn to_unit(&self, num: BigFloat, unit: Unit) -> Result<String, Num2Err> {
+        self.with_noun(num, self.units(unit))
+    }
+
+    fn plural_category(&self, _num: BigFloat) -> PluralCategory {
+        PluralCategory::Other
+    }
+
+    fn agreement(&self, num: BigFloat) -> Agreement {
+        Agreement {
+            number: GrammaticalNumber::Singular,
+            ..Agreement::from_category(self.plural_category(num))
+        }
+    }
+
+    fn negative_marker(&self) -> &'static str {
+        "âm"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Currency, Lang, Num2Words};
+
+    #[test]
+    fn test_cardinal() {
+        for (num, words) in [
+            (0_i64, "không"),
+            (10, "mười"),
+            (11, "mười một"),
+            (15, "mười lăm"),
+            (21, "hai mươi mốt"),
+            (24, "hai mươi bốn"),
+            (25, "hai mươi lăm"),
+            (42, "bốn mươi hai"),
+            (55, "năm mươi lăm"),
+            (101, "một trăm lẻ một"),
+            (105, "một trăm lẻ năm"),
+            (110, "một trăm mười"),
+            (1001, "một nghìn không trăm lẻ một"),
+            (1500, "một nghìn năm trăm"),
+            (1001000, "một triệu không trăm lẻ một nghìn"),
+            (1000000000000, "một nghìn tỷ"),
+            (-5, "âm năm"),
+        ] {
+            assert_eq!(
+                Num2Words::new(num).lang(Lang::Vietnamese).to_words(),
+                Ok(String::from(words))
+            );
+        }
+        assert_eq!(
+            Num2Words::new(42.5).lang(Lang::Vietnamese).to_words(),
+            Ok(String::from("bốn mươi hai phẩy năm"))
+        );
+    }
+
+    #[test]
+    fn test_preferences() {
+        let words = |num: i64, preference| {
+            Num2Words::new(num)
+                .lang(Lang::Vietnamese)
+                .prefer(preference)
+                .to_words()
+        };
+
+        assert_eq!(words(101, "linh"), Ok(String::from("một trăm linh một")));
+        assert_eq!(words(24, "tư"), Ok(String::from("hai mươi tư")));
+        assert_eq!(words(14, "tư"), Ok(String::from("mười bốn")));
+        assert_eq!(words(2000, "ngàn"), Ok(String::from("hai ngàn")));
+    }
+
+    #[test]
+    fn test_ordinal() {
+        for (num, words) in [
+            (1, "thứ nhất"),
+            (2, "thứ hai"),
+            (4, "thứ tư"),
+            (42, "thứ bốn mươi hai"),
+        ] {
+            assert_eq!(
+                Num2Words::new(num)
+                    .lang(Lang::Vietnamese)
+                    .ordinal()
+                    .to_words(),
+                Ok(String::from(words))
+            );
+        }
+        assert_eq!(
+            Num2Words::new(42)
+                .lang(Lang::Vietnamese)
+                .ordinal_num()
+                .to_words(),
+            Ok(String::from("thứ 42"))
+        );
+    }
+
+    #[test]
+    fn test_year_and_currency() {
+        assert_eq!(
+            Num2Words::new(2023)
+                .lang(Lang::Vietnamese)
+                .year()
+                .to_words(),
+            Ok(String::from("hai nghìn không trăm hai mươi ba"))
+        );
+        assert_eq!(
+            Num2Words::new(-42).lang(Lang::Vietnamese).year().to_words(),
+            Ok(String::from("bốn mươi hai trước Công nguyên"))
+        );
+        assert_eq!(
+            Num2Words::new(25000.5)
+                .lang(Lang::Vietnamese)
+                .currency(Currency::VND)
+                .to_words(),
+            Ok(String::from("hai mươi lăm nghìn đồng"))
+        );
+        assert_eq!(
+            Num2Words::new(42.21)
+                .lang(Lang::Vietnamese)
+                .currency(Currency::USD)
+                .to_words(),
+            Ok(String::from("bốn mươi hai đô la hai mươi mốt xu"))
+        );
+    }
+}
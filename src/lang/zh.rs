@@ -0,0 +1,472 @@
+use crate::{
+    num2words::Num2Err, Agreement, Currency, GrammaticalNumber, Language, PluralCategory, Unit,
+};
+use num_bigfloat::BigFloat;
+
+pub struct Chinese {
+    traditional: bool,
+    zero_major: bool,
+}
+
+const DIGITS: [&str; 10] = ["零", "一", "二", "三", "四", "五", "六", "七", "八", "九"];
+
+// digits of a year, read one by one with the circle zero, e.g. "二〇二三"
+const YEAR_DIGITS: [&str; 10] = ["〇", "一", "二", "三", "四", "五", "六", "七", "八", "九"];
+
+// powers of ten thousand, each one ten thousand times the previous one
+const MEGAS: [&str; 11] = [
+    "万", "亿", "兆", "京", "垓", "秭", "穰", "沟", "涧", "正", "载",
+];
+
+// characters that differ between the simplified and traditional scripts
+const TRADITIONAL: [(char, char); 17] = [
+    ('万', '萬'),
+    ('亿', '億'),
+    ('沟', '溝'),
+    ('涧', '澗'),
+    ('载', '載'),
+    ('两', '兩'),
+    ('负', '負'),
+    ('点', '點'),
+    ('无', '無'),
+    ('穷', '窮'),
+    ('欧', '歐'),
+    ('镑', '鎊'),
+    ('币', '幣'),
+    ('卢', '盧'),
+    ('时', '時'),
+    ('钟', '鐘'),
+    ('韩', '韓'),
+];
+
+impl Chinese {
+    pub fn new(traditional: bool) -> Self {
+        Self {
+            traditional,
+            zero_major: false,
+        }
+    }
+
+    /// Keeps the major unit for amounts below one, e.g. `零元五角`
+    pub fn zero_major(self, zero_major: bool) -> Self {
+        Self { zero_major, ..self }
+    }
+
+    // writes simplified characters in the requested script
+    fn script(&self, words: String) -> String {
+        if !self.traditional {
+            return words;
+        }
+
+        words
+            .chars()
+            .map(|c| {
+                TRADITIONAL
+                    .iter()
+                    .find(|(simplified, _)| *simplified == c)
+                    .map_or(c, |(_, traditional)| *traditional)
+            })
+            .collect()
+    }
+
+    fn currencies(&self, currency: Currency) -> &'static str {
+        match currency {
+            Currency::CNY => "元",
+            Currency::AUD => "澳元",
+            Currency::CAD => "加元",
+            Currency::CHF => "瑞士法郎",
+            Currency::DOLLAR | Currency::USD => "美元",
+            Currency::EUR => "欧元",
+            Currency::GBP => "英镑",
+            Currency::HKD => "港元",
+            Currency::INR => "印度卢比",
+            Currency::JPY => "日元",
+            Currency::KRW => "韩元",
+            Currency::TWD => "新台币",
+            _ => "元",
+        }
+    }
+
+    fn cents(&self, currency: Currency) -> &'static str {
+        match currency {
+            Currency::DOLLAR | Currency::USD => "美分",
+            Currency::EUR => "欧分",
+            Currency::GBP => "便士",
+            _ => "分",
+        }
+    }
+
+    fn units(&self, unit: Unit) -> &'static str {
+        match unit {
+            Unit::Meter => "米",
+            Unit::Kilometer => "公里",
+            Unit::Gram => "克",
+            Unit::Kilogram => "公斤",
+            Unit::Liter => "升",
+            Unit::Second => "秒",
+            Unit::Minute => "分钟",
+            Unit::Hour => "小时",
+        }
+    }
+
+    fn split_ten_thousands(&self, mut num: BigFloat) -> Vec<u64> {
+        let mut groups = Vec::new();
+        let bf_10000 = BigFloat::from(10000);
+
+        while !num.is_zero() {
+            groups.push((num % bf_10000).to_u64().unwrap());
+            num /= bf_10000;
+        }
+
+        groups
+    }
+
+    // group of four digits, a single zero standing for any run of inner
+    // zeros, e.g. "一千零一"; "一十" loses its "一" when it leads the number
+    fn group_to_words(&self, group: u64, leading: bool) -> String {
+        let mut words = String::new();
+        let mut started = false;
+        let mut pending_zero = false;
+
+        for (digit, unit) in [
+            (group / 1000 % 10, "千"),
+            (group / 100 % 10, "百"),
+            (group / 10 % 10, "十"),
+            (group % 10, ""),
+        ] {
+            if digit == 0 {
+                pending_zero = started;
+                continue;
+            }
+
+            if pending_zero {
+                words.push_str(DIGITS[0]);
+                pending_zero = false;
+            }
+            if !(leading && !started && digit == 1 && unit == "十") {
+                words.push_str(DIGITS[digit as usize]);
+            }
+            words.push_str(unit);
+            started = true;
+        }
+
+        words
+    }
+
+    fn int_to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        // special case zero
+        if num.is_zero() {
+            return Ok(String::from(DIGITS[0]));
+        }
+
+        let mut words = String::new();
+        let mut skipped_group = false;
+        for (i, group) in self.split_ten_thousands(num.abs()).iter().enumerate().rev() {
+            if *group == 0 {
+                skipped_group = !words.is_empty();
+                continue;
+            }
+
+            // a zero marks any gap with the previous group, e.g. "一万零一"
+            if !words.is_empty() && (*group < 1000 || skipped_group) {
+                words.push_str(DIGITS[0]);
+            }
+            words.push_str(&self.group_to_words(*group, words.is_empty()));
+            if i != 0 {
+                words.push_str(MEGAS.get(i - 1).ok_or(Num2Err::CannotConvert)?);
+            }
+            skipped_group = false;
+        }
+
+        // handling negative values
+        if num.is_negative() {
+            words.insert(0, '负');
+        }
+
+        Ok(words)
+    }
+
+    fn float_to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let mut words = String::new();
+        if num.is_negative() {
+            words.push('负');
+        }
+        words.push_str(&self.int_to_cardinal(num.int().abs())?);
+        words.push('点');
+
+        let mut ordinal_part = num.frac().abs();
+        while !ordinal_part.is_zero() {
+            let digit = (ordinal_part * BigFloat::from(10)).int();
+            ordinal_part = (ordinal_part * BigFloat::from(10)).frac();
+            words.push_str(DIGITS[digit.to_u64().unwrap() as usize]);
+        }
+
+        Ok(words)
+    }
+
+    fn cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num.is_inf_pos() {
+            Ok(String::from("无穷大"))
+        } else if num.is_inf_neg() {
+            Ok(String::from("负无穷大"))
+        } else if num.frac().is_zero() {
+            self.int_to_cardinal(num)
+        } else {
+            self.float_to_cardinal(num)
+        }
+    }
+
+    // number counting a measure word, a leading two being "两" rather than
+    // "二" unless it is part of a multiple of ten, e.g. "两千元" but "二十元"
+    fn with_noun(&self, num: BigFloat, noun: &str) -> Result<String, Num2Err> {
+        let cardinal = self.cardinal(num)?;
+        let count = match cardinal.strip_prefix("二") {
+            Some(rest) if !rest.starts_with('十') && !rest.starts_with('点') => {
+                format!("两{}", rest)
+            }
+            _ => cardinal,
+        };
+
+        Ok(format!("{}{}", count, noun))
+    }
+}
+
+impl Language for Chinese {
+    fn to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        Ok(self.script(self.cardinal(num)?))
+    }
+
+    fn to_ordinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        Ok(self.script(format!("第{}", self.int_to_cardinal(num)?)))
+    }
+
+    fn to_ordinal_num(&self, num: BigFloat) -> Result<String, Num2Err> {
+        Ok(format!("第{}", num.to_u128().unwrap()))
+    }
+
+    fn to_year(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if !num.frac().is_zero() {
+            return Err(Num2Err::FloatingYear);
+        }
+
+        let digits: String = num
+            .abs()
+            .to_i64()
+            .ok_or(Num2Err::CannotConvert)?
+            .to_string()
+            .bytes()
+            .map(|digit| YEAR_DIGITS[(digit - b'0') as usize])
+            .collect();
+        if num.is_negative() {
+            Ok(format!("公元前{}年", digits))
+        } else {
+            Ok(format!("{}年", digits))
+        }
+    }
+
+    fn to_year_range(&self, from: BigFloat, to: BigFloat) -> Result<String, Num2Err> {
+        Ok(format!("{}至{}", self.to_year(from)?, self.to_year(to)?))
+    }
+
+    fn to_currency(&self, num: BigFloat, currency: Currency) -> Result<String, Num2Err> {
+        let major = self.currencies(currency);
+        if num.is_inf() || num.frac().is_zero() {
+            return Ok(self.script(self.with_noun(num, major)?));
+        }
+
+        let integral_part = num.int();
+        let cents_nb = (num * BigFloat::from(100)).int() % BigFloat::from(100);
+        let cents_words = match currency {
+            // the yuan splits into ten jiao of ten fen each, a missing jiao
+            // being read as a zero, e.g. "四十二元零五分"
+            Currency::CNY => {
+                let jiao = (cents_nb / BigFloat::from(10)).int();
+                let fen = cents_nb % BigFloat::from(10);
+                let mut words = String::new();
+                if !jiao.is_zero() {
+                    words.push_str(&self.with_noun(jiao, "角")?);
+                } else if !integral_part.is_zero() || self.zero_major {
+                    words.push_str(DIGITS[0]);
+                }
+                if !fen.is_zero() {
+                    words.push_str(&self.with_noun(fen, "分")?);
+                }
+                words
+            }
+            _ => self.with_noun(cents_nb, self.cents(currency))?,
+        };
+        let integral_word = self.with_noun(integral_part, major)?;
+
+        Ok(self.script(if cents_nb.is_zero() {
+            integral_word
+        } else if integral_part.is_zero() && !self.zero_major {
+            cents_words
+        } else {
+            format!("{}{}", integral_word, cents_words)
+        }))
+    }
+
+    fn to_percent(&self, num: BigFloat) -> Result<String, Num2Err> {
+        // the hundred is read first, e.g. "百分之四十二"
+        Ok(self.script(format!("百分之{}", self.cardinal(num)?)))
+    }
+
+    fn to_unit(&self, num: BigFloat, unit: Unit) -> Result<String, Num2Err> {
+        Ok(self.script(self.with_noun(num, self.units(unit))?))
+    }
+
+    fn plural_category(&self, _num: BigFloat) -> PluralCategory {
+        PluralCategory::Other
+    }
+
+    fn agreement(&self, num: BigFloat) -> Agreement {
+        Agreement {
+            number: GrammaticalNumber::Singular,
+            ..Agreement::from_category(self.plural_category(num))
+        }
+    }
+
+    fn negative_marker(&self) -> &'static str {
+        if self.traditional {
+            "負"
+        } else {
+            "负"
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Currency, Lang, Num2Words, Unit};
+
+    #[test]
+    fn test_cardinal() {
+        for (num, words) in [
+            (0, "零"),
+            (2, "二"),
+            (10, "十"),
+            (15, "十五"),
+            (42, "四十二"),
+            (110, "一百一十"),
+            (1001, "一千零一"),
+            (1010, "一千零一十"),
+            (10000, "一万"),
+            (10001, "一万零一"),
+            (100000, "十万"),
+            (100010, "十万零一十"),
+            (101000, "十万一千"),
+            (100001000, "一亿零一千"),
+            (-5, "负五"),
+        ] {
+            assert_eq!(
+                Num2Words::new(num).lang(Lang::Chinese).to_words(),
+                Ok(String::from(words))
+            );
+        }
+        assert_eq!(
+            Num2Words::new(3.25).lang(Lang::Chinese).to_words(),
+            Ok(String::from("三点二五"))
+        );
+        assert_eq!(
+            Num2Words::new(20000)
+                .lang(Lang::Chinese)
+                .prefer("traditional")
+                .to_words(),
+            Ok(String::from("二萬"))
+        );
+    }
+
+    #[test]
+    fn test_ordinal() {
+        assert_eq!(
+            Num2Words::new(42).lang(Lang::Chinese).ordinal().to_words(),
+            Ok(String::from("第四十二"))
+        );
+        assert_eq!(
+            Num2Words::new(42)
+                .lang(Lang::Chinese)
+                .ordinal_num()
+                .to_words(),
+            Ok(String::from("第42"))
+        );
+    }
+
+    #[test]
+    fn test_year() {
+        assert_eq!(
+            Num2Words::new(2023).lang(Lang::Chinese).year().to_words(),
+            Ok(String::from("二〇二三年"))
+        );
+        assert_eq!(
+            Num2Words::new(-221).lang(Lang::Chinese).year().to_words(),
+            Ok(String::from("公元前二二一年"))
+        );
+    }
+
+    #[test]
+    fn test_currency() {
+        for (num, words) in [
+            (2.0, "两元"),
+            (42.0, "四十二元"),
+            (2000.0, "两千元"),
+            (42.5, "四十二元五角"),
+            (42.57, "四十二元五角七分"),
+            (42.05, "四十二元零五分"),
+            (0.2, "两角"),
+        ] {
+            assert_eq!(
+                Num2Words::new(num)
+                    .lang(Lang::Chinese)
+                    .currency(Currency::CNY)
+                    .to_words(),
+                Ok(String::from(words))
+            );
+        }
+        assert_eq!(
+            Num2Words::new(0.5)
+                .lang(Lang::Chinese)
+                .currency(Currency::CNY)
+                .prefer("zero-major")
+                .to_words(),
+            Ok(String::from("零元五角"))
+        );
+        assert_eq!(
+            Num2Words::new(42.5)
+                .lang(Lang::Chinese)
+                .currency(Currency::USD)
+                .to_words(),
+            Ok(String::from("四十二美元五十美分"))
+        );
+        assert_eq!(
+            Num2Words::new(2)
+                .lang(Lang::Chinese)
+                .currency(Currency::EUR)
+                .prefer("traditional")
+                .to_words(),
+            Ok(String::from("兩歐元"))
+        );
+    }
+
+    #[test]
+    fn test_percent_and_unit() {
+        assert_eq!(
+            Num2Words::new(42).lang(Lang::Chinese).percent().to_words(),
+            Ok(String::from("百分之四十二"))
+        );
+        assert_eq!(
+            Num2Words::new(2)
+                .lang(Lang::Chinese)
+                .unit(Unit::Kilogram)
+                .to_words(),
+            Ok(String::from("两公斤"))
+        );
+        assert_eq!(
+            Num2Words::new(2)
+                .lang(Lang::Chinese)
+                .unit(Unit::Hour)
+                .prefer("traditional")
+                .to_words(),
+            Ok(String::from("兩小時"))
+        );
+    }
+}
@@ -0,0 +1,590 @@
+use crate::lang::NounOrder;
+use crate::{num2words::Num2Err, Currency, Language, PluralCategory, Unit};
+use num_bigfloat::BigFloat;
+
+/// Noun classes a number can agree with, named after their plural prefix
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum NounClass {
+    /// Classes 1/2, e.g. umuntu/abantu
+    Aba,
+    /// Classes 3/4, e.g. umuzi/imizi
+    Imi,
+    /// Classes 5/6, e.g. irandi/amarandi
+    Ama,
+    /// Classes 7/8, e.g. isigidi/izigidi
+    Izi,
+    /// Classes 9/10, e.g. inkulungwane/izinkulungwane
+    Izin,
+}
+
+impl NounClass {
+    fn concord(self) -> &'static Concord {
+        &CONCORDS[self as usize]
+    }
+}
+
+/// Reads a noun class from its plural prefix or class number, e.g. `ama` or
+/// `6`
+pub(crate) fn noun_class_from_str(input: &str) -> Option<NounClass> {
+    match input {
+        "aba" | "1" | "2" => Some(NounClass::Aba),
+        "imi" | "3" | "4" => Some(NounClass::Imi),
+        "ama" | "5" | "6" => Some(NounClass::Ama),
+        "izi" | "7" | "8" => Some(NounClass::Izi),
+        "izin" | "9" | "10" => Some(NounClass::Izin),
+        _ => None,
+    }
+}
+
+// Concords a number takes when it agrees with a noun of a given class
+struct Concord {
+    // 1 with the singular noun, e.g. irandi elilodwa
+    one: &'static str,
+    // 2 to 5 are adjectives, e.g. amarandi amabili
+    adjectives: [&'static str; 4],
+    // other numbers are nouns, introduced by the relative concord and a
+    // copula, e.g. amarandi angamashumi amane (40), amarandi ayisithupha (6)
+    relative: &'static str,
+}
+
+const CONCORDS: [Concord; 5] = [
+    Concord {
+        one: "oyedwa",
+        adjectives: ["ababili", "abathathu", "abane", "abahlanu"],
+        relative: "aba",
+    },
+    Concord {
+        one: "owodwa",
+        adjectives: ["emibili", "emithathu", "emine", "emihlanu"],
+        relative: "e",
+    },
+    Concord {
+        one: "elilodwa",
+        adjectives: ["amabili", "amathathu", "amane", "amahlanu"],
+        relative: "a",
+    },
+    Concord {
+        one: "esisodwa",
+        adjectives: ["ezimbili", "ezintathu", "ezine", "ezinhlanu"],
+        relative: "ezi",
+    },
+    Concord {
+        one: "eyodwa",
+        adjectives: ["ezimbili", "ezintathu", "ezine", "ezinhlanu"],
+        relative: "ezi",
+    },
+];
+
+struct Noun {
+    singular: &'static str,
+    plural: &'static str,
+    class: NounClass,
+}
+
+const fn noun(singular: &'static str, plural: &'static str, class: NounClass) -> Noun {
+    Noun {
+        singular,
+        plural,
+        class,
+    }
+}
+
+// forms used when counting, e.g. kubili (2)
+const COUNTING: [&str; 9] = [
+    "kunye",
+    "kubili",
+    "kuthathu",
+    "kune",
+    "kuhlanu",
+    "isithupha",
+    "isikhombisa",
+    "isishiyagalombili",
+    "isishiyagalolunye",
+];
+
+// forms used after na- in compounds, e.g. amashumi amane nambili (42)
+const UNITS: [&str; 9] = [
+    "nye",
+    "mbili",
+    "ntathu",
+    "ne",
+    "nhlanu",
+    "isithupha",
+    "isikhombisa",
+    "isishiyagalombili",
+    "isishiyagalolunye",
+];
+
+const TEN: Noun = noun("ishumi", "amashumi", NounClass::Ama);
+const HUNDRED: Noun = noun("ikhulu", "amakhulu", NounClass::Ama);
+
+const MEGAS: [Noun; 4] = [
+    noun("inkulungwane", "izinkulungwane", NounClass::Izin),
+    noun("isigidi", "izigidi", NounClass::Izi),
+    noun("ibhiliyoni", "amabhiliyoni", NounClass::Ama),
+    noun("ithriliyoni", "amathriliyoni", NounClass::Ama),
+];
+
+const CENT: Noun = noun("isenti", "amasenti", NounClass::Ama);
+
+pub struct Zulu {
+    class: Option<NounClass>,
+    zero_major: bool,
+}
+
+// na- (and, with) merges with the initial vowel of the next word, e.g.
+// na + amashumi => namashumi, na + isithupha => nesithupha
+fn with_na(word: &str) -> String {
+    match word.chars().next() {
+        Some('a') => format!("n{}", word),
+        Some('i') => format!("ne{}", &word[1..]),
+        Some('u') => format!("no{}", &word[1..]),
+        _ => format!("na{}", word),
+    }
+}
+
+impl Zulu {
+    pub fn new(class: Option<NounClass>) -> Self {
+        Self {
+            class,
+            zero_major: false,
+        }
+    }
+
+    /// Keeps the major unit clause for amounts below one, e.g. `amarandi
+    /// ayiqanda namasenti angamashumi amabili`
+    pub fn zero_major(self, zero_major: bool) -> Self {
+        Self { zero_major, ..self }
+    }
+
+    fn currencies(&self, currency: Currency) -> Noun {
+        match currency {
+            Currency::DOLLAR | Currency::USD => noun("idola", "amadola", NounClass::Ama),
+            Currency::EUR => noun("i-euro", "ama-euro", NounClass::Ama),
+            Currency::GBP => noun("iphawundi", "amaphawundi", NounClass::Ama),
+            _ => noun("irandi", "amarandi", NounClass::Ama),
+        }
+    }
+
+    fn units(&self, unit: Unit) -> Noun {
+        match unit {
+            Unit::Meter => noun("imitha", "amamitha", NounClass::Ama),
+            Unit::Kilometer => noun("ikhilomitha", "amakhilomitha", NounClass::Ama),
+            Unit::Gram => noun("igremu", "amagremu", NounClass::Ama),
+            Unit::Kilogram => noun("ikhilogremu", "amakhilogremu", NounClass::Ama),
+            Unit::Liter => noun("ilitha", "amalitha", NounClass::Ama),
+            Unit::Second => noun("umzuzwana", "imizuzwana", NounClass::Imi),
+            Unit::Minute => noun("umzuzu", "imizuzu", NounClass::Imi),
+            Unit::Hour => noun("ihora", "amahora", NounClass::Ama),
+        }
+    }
+
+    // Parts of a whole number, joined with na-, e.g. ["amashumi amane", "mbili"]
+    fn parts(&self, num: BigFloat) -> Result<Vec<String>, Num2Err> {
+        let mut thousands = vec![];
+        let mut num = num;
+        let bf_1000 = BigFloat::from(1000);
+        while !num.is_zero() {
+            thousands.push((num % bf_1000).to_u64().unwrap());
+            num = (num / bf_1000).int();
+        }
+
+        let mut parts = vec![];
+        for (i, triplet) in thousands.iter().enumerate().rev() {
+            if triplet == &0 {
+                continue;
+            }
+            if i > MEGAS.len() {
+                return Err(Num2Err::CannotConvert);
+            }
+            if i > 0 {
+                parts.push(self.counted(*triplet, &MEGAS[i - 1])?);
+                continue;
+            }
+
+            let hundreds = triplet / 100;
+            let tens = triplet / 10 % 10;
+            let units = (triplet % 10) as usize;
+
+            if hundreds > 0 {
+                parts.push(self.counted(hundreds, &HUNDRED)?);
+            }
+            if tens > 0 {
+                parts.push(self.counted(tens, &TEN)?);
+            }
+            if units > 0 {
+                parts.push(String::from(if parts.is_empty() {
+                    COUNTING[units - 1]
+                } else {
+                    UNITS[units - 1]
+                }));
+            }
+        }
+
+        Ok(parts)
+    }
+
+    fn counting(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num.is_zero() {
+            return Ok(String::from("iqanda"));
+        }
+
+        let parts = self.parts(num)?;
+        let mut words = vec![parts[0].clone()];
+        words.extend(parts[1..].iter().map(|part| with_na(part)));
+        Ok(words.join(" "))
+    }
+
+    // A number agreeing with a noun of the given class, e.g. amabili (2),
+    // angamashumi amane nambili (42)
+    fn agreeing(&self, num: BigFloat, class: NounClass) -> Result<String, Num2Err> {
+        let concord = class.concord();
+        match num.to_u64() {
+            Some(1) => return Ok(String::from(concord.one)),
+            Some(i @ 2..=5) => return Ok(String::from(concord.adjectives[i as usize - 2])),
+            _ => {}
+        }
+
+        let words = self.counting(num)?;
+        Ok(match words.chars().next() {
+            Some('a') => format!("{}nga{}", concord.relative, &words[1..]),
+            Some('i') => format!("{}yi{}", concord.relative, &words[1..]),
+            _ => format!("{}{}", concord.relative, words),
+        })
+    }
+
+    // A counted noun, e.g. amakhulu amabili (200), but ikhulu (100)
+    fn counted(&self, num: u64, noun: &Noun) -> Result<String, Num2Err> {
+        if num == 1 {
+            Ok(String::from(noun.singular))
+        } else {
+            Ok(format!(
+                "{} {}",
+                noun.plural,
+                self.agreeing(BigFloat::from(num), noun.class)?
+            ))
+        }
+    }
+
+    // A noun with its number, e.g. amarandi amabili, irandi elilodwa
+    fn with_noun(&self, num: BigFloat, noun: &Noun) -> Result<String, Num2Err> {
+        let order = self.noun_order();
+        let sign = if num.is_negative() { "susa " } else { "" };
+        let num = num.abs();
+
+        if num.is_inf() {
+            Ok(format!(
+                "{}{}",
+                sign,
+                order.place("okungapheli", noun.plural)
+            ))
+        } else if !num.frac().is_zero() {
+            // fractions do not agree with the noun
+            Ok(format!(
+                "{}{}",
+                sign,
+                order.place(&self.to_cardinal(num)?, noun.plural)
+            ))
+        } else if self.plural_category(num) == PluralCategory::One {
+            Ok(format!(
+                "{}{}",
+                sign,
+                order.place(noun.class.concord().one, noun.singular)
+            ))
+        } else {
+            Ok(format!(
+                "{}{}",
+                sign,
+                order.place(&self.agreeing(num, noun.class)?, noun.plural)
+            ))
+        }
+    }
+
+    fn int_to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let words = match self.class {
+            Some(class) if !num.is_zero() => self.agreeing(num.abs(), class)?,
+            _ => self.counting(num.abs())?,
+        };
+
+        if num.is_negative() {
+            Ok(format!("susa {}", words))
+        } else {
+            Ok(words)
+        }
+    }
+
+    fn float_to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let integral_part = num.int();
+        let mut words: Vec<String> = vec![];
+
+        if !integral_part.is_zero() {
+            let integral_word = self.int_to_cardinal(integral_part)?;
+            words.push(integral_word);
+        } else if num.is_negative() {
+            words.push(String::from("susa"));
+        }
+        if integral_part.is_zero() {
+            words.push(String::from("iqanda"));
+        }
+
+        let mut ordinal_part = num.frac().abs();
+        if !ordinal_part.is_zero() {
+            words.push(String::from("iphoyinti"));
+        }
+        while !ordinal_part.is_zero() {
+            let digit = (ordinal_part * BigFloat::from(10)).int();
+            ordinal_part = (ordinal_part * BigFloat::from(10)).frac();
+            words.push(match digit.to_u64().unwrap() {
+                0 => String::from("iqanda"),
+                i => String::from(COUNTING[i as usize - 1]),
+            });
+        }
+        Ok(words.join(" "))
+    }
+}
+
+impl Language for Zulu {
+    fn to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num.is_inf_pos() {
+            Ok(String::from("okungapheli"))
+        } else if num.is_inf_neg() {
+            Ok(String::from("susa okungapheli"))
+        } else if num.frac().is_zero() {
+            self.int_to_cardinal(num)
+        } else {
+            self.float_to_cardinal(num)
+        }
+    }
+
+    fn to_ordinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        // ordinals are possessives of the number, e.g. okwesibili (second)
+        let words = match num.to_u64() {
+            Some(1) => return Ok(String::from("okokuqala")),
+            Some(2) => String::from("isibili"),
+            Some(3) => String::from("isithathu"),
+            Some(4) => String::from("isine"),
+            Some(5) => String::from("isihlanu"),
+            _ => self.counting(num)?,
+        };
+
+        Ok(match words.chars().next() {
+            Some('i') => format!("okwe{}", &words[1..]),
+            _ => format!("okw{}", words),
+        })
+    }
+
+    fn to_ordinal_num(&self, num: BigFloat) -> Result<String, Num2Err> {
+        Ok(format!("okwe-{}", num.to_u128().unwrap()))
+    }
+
+    fn to_year(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if !num.frac().is_zero() {
+            return Err(Num2Err::FloatingYear);
+        }
+
+        let words = self.counting(num.abs())?;
+        if num.is_negative() {
+            Ok(format!("{} ngaphambi kukaKristu", words))
+        } else {
+            Ok(words)
+        }
+    }
+
+    fn to_currency(&self, num: BigFloat, currency: Currency) -> Result<String, Num2Err> {
+        let noun = self.currencies(currency);
+        if num.is_inf() || num.frac().is_zero() {
+            self.with_noun(num, &noun)
+        } else {
+            let integral_part = num.int();
+            let cents_nb = (num * BigFloat::from(100)).int() % BigFloat::from(100);
+            let cents_words = self.with_noun(cents_nb, &CENT)?;
+            let integral_word = self.with_noun(integral_part, &noun)?;
+
+            if cents_nb.is_zero() {
+                Ok(integral_word)
+            } else if integral_part.is_zero() && !self.zero_major {
+                Ok(cents_words)
+            } else {
+                Ok(format!("{} {}", integral_word, with_na(&cents_words)))
+            }
+        }
+    }
+
+    fn to_percent(&self, num: BigFloat) -> Result<String, Num2Err> {
+        self.with_noun(num, &noun("iphesenti", "amaphesenti", NounClass::Ama))
+    }
+
+    fn to_unit(&self, num: BigFloat, unit: Unit) -> Result<String, Num2Err> {
+        self.with_noun(num, &self.units(unit))
+    }
+
+    fn plural_category(&self, num: BigFloat) -> PluralCategory {
+        // CLDR: one is i = 0 or n = 1, but only one itself takes the singular
+        // noun and its concord
+        if num.abs() == BigFloat::from(1) {
+            PluralCategory::One
+        } else {
+            PluralCategory::Other
+        }
+    }
+
+    fn noun_order(&self) -> NounOrder {
+        NounOrder::NounFirst
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_cardinal() {
+        let expected = [
+            "kunye",
+            "kubili",
+            "kuthathu",
+            "kune",
+            "kuhlanu",
+            "isithupha",
+            "isikhombisa",
+            "isishiyagalombili",
+            "isishiyagalolunye",
+            "ishumi",
+        ];
+        for (i, words) in expected.iter().enumerate() {
+            assert_eq!(
+                Num2Words::new(i as i64 + 1).lang(Lang::Zulu).to_words(),
+                Ok(String::from(*words))
+            );
+        }
+
+        assert_eq!(
+            Num2Words::new(0).lang(Lang::Zulu).to_words(),
+            Ok(String::from("iqanda"))
+        );
+        assert_eq!(
+            Num2Words::new(42).lang(Lang::Zulu).to_words(),
+            Ok(String::from("amashumi amane nambili"))
+        );
+        assert_eq!(
+            Num2Words::new(16).lang(Lang::Zulu).to_words(),
+            Ok(String::from("ishumi nesithupha"))
+        );
+        assert_eq!(
+            Num2Words::new(142).lang(Lang::Zulu).to_words(),
+            Ok(String::from("ikhulu namashumi amane nambili"))
+        );
+        assert_eq!(
+            Num2Words::new(2005).lang(Lang::Zulu).to_words(),
+            Ok(String::from("izinkulungwane ezimbili nanhlanu"))
+        );
+        assert_eq!(
+            Num2Words::new(-60).lang(Lang::Zulu).to_words(),
+            Ok(String::from("susa amashumi ayisithupha"))
+        );
+        assert_eq!(
+            Num2Words::new(0.5).lang(Lang::Zulu).to_words(),
+            Ok(String::from("iqanda iphoyinti kuhlanu"))
+        );
+        assert_eq!(
+            Num2Words::new(-0.25).lang(Lang::Zulu).to_words(),
+            Ok(String::from("susa iqanda iphoyinti kubili kuhlanu"))
+        );
+    }
+
+    #[test]
+    fn test_noun_class() {
+        assert_eq!(
+            Num2Words::new(2).lang(Lang::Zulu).prefer("aba").to_words(),
+            Ok(String::from("ababili"))
+        );
+        assert_eq!(
+            Num2Words::new(1).lang(Lang::Zulu).prefer("izin").to_words(),
+            Ok(String::from("eyodwa"))
+        );
+        assert_eq!(
+            Num2Words::new(42).lang(Lang::Zulu).prefer("6").to_words(),
+            Ok(String::from("angamashumi amane nambili"))
+        );
+        assert_eq!(
+            Num2Words::new(6).lang(Lang::Zulu).prefer("imi").to_words(),
+            Ok(String::from("eyisithupha"))
+        );
+    }
+
+    #[test]
+    fn test_ordinal() {
+        assert_eq!(
+            Num2Words::new(1).lang(Lang::Zulu).ordinal().to_words(),
+            Ok(String::from("okokuqala"))
+        );
+        assert_eq!(
+            Num2Words::new(2).lang(Lang::Zulu).ordinal().to_words(),
+            Ok(String::from("okwesibili"))
+        );
+        assert_eq!(
+            Num2Words::new(10).lang(Lang::Zulu).ordinal().to_words(),
+            Ok(String::from("okweshumi"))
+        );
+        assert_eq!(
+            Num2Words::new(42).lang(Lang::Zulu).ordinal().to_words(),
+            Ok(String::from("okwamashumi amane nambili"))
+        );
+    }
+
+    #[test]
+    fn test_currency() {
+        assert_eq!(
+            Num2Words::new(1)
+                .lang(Lang::Zulu)
+                .currency(Currency::ZAR)
+                .to_words(),
+            Ok(String::from("irandi elilodwa"))
+        );
+        assert_eq!(
+            Num2Words::new(2)
+                .lang(Lang::Zulu)
+                .currency(Currency::ZAR)
+                .to_words(),
+            Ok(String::from("amarandi amabili"))
+        );
+        assert_eq!(
+            Num2Words::new(42)
+                .lang(Lang::Zulu)
+                .currency(Currency::ZAR)
+                .to_words(),
+            Ok(String::from("amarandi angamashumi amane nambili"))
+        );
+        assert_eq!(
+            Num2Words::new(42.50)
+                .lang(Lang::Zulu)
+                .currency(Currency::ZAR)
+                .to_words(),
+            Ok(String::from(
+                "amarandi angamashumi amane nambili namasenti angamashumi amahlanu"
+            ))
+        );
+        assert_eq!(
+            Num2Words::new(0.06)
+                .lang(Lang::Zulu)
+                .currency(Currency::ZAR)
+                .to_words(),
+            Ok(String::from("amasenti ayisithupha"))
+        );
+    }
+
+    #[test]
+    fn test_percent_and_unit() {
+        assert_eq!(
+            Num2Words::new(3).lang(Lang::Zulu).percent().to_words(),
+            Ok(String::from("amaphesenti amathathu"))
+        );
+        assert_eq!(
+            Num2Words::new(2)
+                .lang(Lang::Zulu)
+                .unit(Unit::Minute)
+                .to_words(),
+            Ok(String::from("imizuzu emibili"))
+        );
+    }
+}
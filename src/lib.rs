@@ -18,7 +18,7 @@
  * ```
  *
  * The builder `Num2Words` can take any of these methods: `lang`, `cardinal`,
- * `ordinal`, `ordinal_num`, `year`, and `currency`.
+ * `ordinal`, `ordinal_num`, `year`, `currency`, `percent` and `unit`.
  *
  * ```rust
  * use num2words::*;
@@ -60,7 +60,7 @@
  *
  * You can also change the language via the CLI argument `--lang [locale]` and
  * provide a specific output type or a currency with the argument
- * `--to [cardinal|ordinal|ordinal_num|year|ISO 4217]`.
+ * `--to [cardinal|ordinal|ordinal_num|year|percent|ISO 4217|unit symbol]`.
  *
  * For more information about the usage of `num2words` please refer to the docs
  * or via the following command:
@@ -76,11 +76,48 @@
  *
  * | Flag | Code              | Locale    | Language    | 42            |
  * | ---- | ----------------- | --------- | ----------- | ------------- |
+ * | 🇪🇹   | `Lang::Amharic`   | `am`      | Amharic     | አርባ ሁለት       |
+ * | 🇸🇦   | `Lang::Arabic`    | `ar`      | Arabic      | اثنان وأربعون |
+ * | 🇦🇲   | `Lang::Armenian`  | `hy`      | Armenian    | քառասուներկու |
+ * | 🇪🇸   | `Lang::Basque`    | `eu`      | Basque      | berrogeita bi |
+ * | 🇧🇩   | `Lang::Bengali`   | `bn`      | Bengali     | বিয়াল্লিশ    |
+ * | 🇲🇲   | `Lang::Burmese`   | `my`      | Burmese     | လေးဆယ့်နှစ်   |
+ * | 🇨🇳   | `Lang::Chinese`   | `zh`      | Chinese     | 四十二           |
+ * | 🇨🇿   | `Lang::Czech`     | `cs`      | Czech       | čtyřicet dva  |
+ * | 🇳🇱   | `Lang::Dutch`     | `nl`      | Dutch       | tweeënveertig |
  * | 🇺🇸🇬🇧 | `Lang::English`   | `en`      | English     | forty-two     |
- * | 🇫🇷🇨🇦 | `Lang::French`    | `fr`      | French      | quarante-deux |
+ * | 🇫🇮   | `Lang::Finnish`   | `fi`      | Finnish     | neljäkymmentäkaksi |
+ * | 🇫🇷   | `Lang::French`    | `fr`      | French      | quarante-deux |
  * | 🇧🇪🇨🇩 | `Lang::French_BE` | `fr_BE`   | French (BE) | quarante-deux |
  * | 🇨🇭   | `Lang::French_CH` | `fr_CH`   | French (CH) | quarante-deux |
+ * | 🇨🇦   | `Lang::French_CA` | `fr_CA`   | French (CA) | quarante-deux |
+ * | 🇭🇺   | `Lang::Hungarian` | `hu`      | Hungarian   | negyvenkettő  |
+ * | 🇮🇸   | `Lang::Icelandic` | `is`      | Icelandic   | fjörutíu og tveir |
+ * | 🇮🇹   | `Lang::Italian`   | `it`      | Italian     | quarantadue   |
+ * | 🇱🇦   | `Lang::Lao`       | `lo`      | Lao         | ສີ່ສິບສອງ     |
+ * | 🇻🇦   | `Lang::Latin`     | `la`      | Latin       | quadraginta duo |
+ * | 🇱🇻   | `Lang::Latvian`   | `lv`      | Latvian     | četrdesmit divi |
+ * | 🇲🇹   | `Lang::Maltese`   | `mt`      | Maltese     | tnejn u erbgħin |
+ * | 🇲🇳   | `Lang::Mongolian` | `mn`      | Mongolian   | дөчин хоёр    |
+ * | 🇳🇵   | `Lang::Nepali`    | `ne`      | Nepali      | बयालीस        |
+ * | 🇵🇱   | `Lang::Polish`    | `pl`      | Polish      | czterdzieści dwa |
+ * | 🇵🇹   | `Lang::Portuguese` | `pt`      | Portuguese  | quarenta e dois |
+ * | 🇧🇷   | `Lang::Portuguese_BR` | `pt_BR` | Portuguese (BR) | quarenta e dois |
+ * | 🇷🇴   | `Lang::Romanian`  | `ro`      | Romanian    | patruzeci și doi |
+ * | 🇷🇸   | `Lang::Serbian`   | `sr`      | Serbian     | четрдесет два |
+ * | 🇱🇰   | `Lang::Sinhala`   | `si`      | Sinhala     | හතළිස් දෙක    |
+ * | 🇸🇰   | `Lang::Slovak`    | `sk`      | Slovak      | štyridsaťdva  |
+ * | 🇸🇮   | `Lang::Slovenian` | `sl`      | Slovenian   | dvainštirideset |
+ * | 🇪🇸   | `Lang::Spanish`   | `es`      | Spanish     | cuarenta y dos |
+ * | 🇰🇪   | `Lang::Swahili`   | `sw`      | Swahili     | arobaini na mbili |
+ * | 🇵🇭   | `Lang::Tagalog`   | `tl`      | Tagalog     | apatnapu't dalawa |
+ * | 🇮🇳   | `Lang::Tamil`     | `ta`      | Tamil       | நாற்பத்தி இரண்டு |
+ * | 🇮🇳   | `Lang::Telugu`    | `te`      | Telugu      | నలభై రెండు    |
+ * | 🇹🇷   | `Lang::Turkish`   | `tr`      | Turkish     | kırk iki      |
  * | 🇺🇦   | `Lang::Ukrainian` | `uk`      | Ukrainian   | сорок два     |
+ * | 🇺🇿   | `Lang::Uzbek`     | `uz`      | Uzbek       | qirq ikki     |
+ * | 🇻🇳   | `Lang::Vietnamese` | `vi`      | Vietnamese  | bốn mươi hai  |
+ * | 🇿🇦   | `Lang::Zulu`      | `zu`      | Zulu        | amashumi amane nambili |
  *
  * This list can be expanded! Contributions are welcomed.
  *
@@ -96,6 +133,8 @@
  * | `.ordinal_num()` | `ordinal_num` | 42nd (42)                              |
  * | `.year()`        | `year`        | nineteen oh-one (1901)                 |
  * | `.currency(cur)` | ISO 4217 code | forty-two dollars and one cent (42.01) |
+ * | `.percent()`     | `percent`     | forty-two percent (42)                 |
+ * | `.unit(unit)`    | unit symbol   | forty-two meters (42)                  |
  *
  * ### Supported currencies
  *
@@ -113,12 +152,27 @@
  */
 mod num2words;
 
+mod agreement;
 mod currency;
 mod lang;
+mod lint;
+mod measure;
 mod output;
+mod reference;
+mod report;
+mod rounding;
+mod slot;
+mod unit;
 
 pub use crate::num2words::{Num2Err, Num2Words};
+pub use agreement::Agreement;
 pub use currency::Currency;
-pub use lang::Lang;
 use lang::Language;
-use output::Output;
+pub use lang::{Declension, Gender, GrammaticalNumber, Lang, PluralCategory};
+pub use lint::ConfigLint;
+pub use measure::Measure;
+pub use output::Output;
+pub use report::Report;
+pub use rounding::Rounding;
+pub use slot::{slot, SentenceSlot};
+pub use unit::Unit;
@@ -0,0 +1,19 @@
+use crate::Output;
+
+/// Soft conflict in the configuration of a builder
+///
+/// Built by [`Num2Words::lints`](crate::Num2Words::lints). None of them
+/// prevents the conversion, but each one is a setting without any effect.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ConfigLint {
+    /// An output was replaced by another one, e.g. `.year().currency(...)`
+    ///
+    /// With `prefer("strict-config")`, this fails the conversion with
+    /// [`Num2Err::ConflictingOutputs`](crate::Num2Err::ConflictingOutputs).
+    OverriddenOutput { previous: Output, current: Output },
+    /// A preference the language does not know, e.g. `oh` in French
+    UnusedPreference(String),
+    /// A preference of the language that has no effect on the output, e.g.
+    /// `reformed` on a French numbered ordinal
+    IgnoredPreference { preference: String, output: Output },
+}
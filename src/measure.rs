@@ -0,0 +1,71 @@
+/// Size of a built output, for layout engines
+///
+/// Built by [`Num2Words::measure`](crate::Num2Words::measure) from the very
+/// output [`Num2Words::to_words`](crate::Num2Words::to_words) gives.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Measure {
+    /// Length in characters (not bytes), e.g. 9 for `сорок два`
+    pub char_len: usize,
+    /// Number of words separated by whitespace, e.g. 1 for `forty-two`
+    pub word_count: usize,
+}
+
+impl Measure {
+    pub(crate) fn of(words: &str) -> Self {
+        Self {
+            char_len: words.chars().count(),
+            word_count: words.split_whitespace().count(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_measure_matches_output() {
+        for lang in [Lang::English, Lang::French, Lang::Ukrainian, Lang::Romanian] {
+            for num in [0.0, 1.0, 42.0, 1234.5, -21.0, 1e12] {
+                let words = Num2Words::new(num).lang(lang).to_words().unwrap();
+                assert_eq!(
+                    Num2Words::new(num).lang(lang).measure(),
+                    Ok(Measure {
+                        char_len: words.chars().count(),
+                        word_count: words.split_whitespace().count(),
+                    })
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_multibyte() {
+        // сорок два is 9 characters but 17 bytes
+        assert_eq!(
+            Num2Words::new(42).lang(Lang::Ukrainian).measure(),
+            Ok(Measure {
+                char_len: 9,
+                word_count: 2
+            })
+        );
+        assert_eq!(
+            Num2Words::new(42)
+                .lang(Lang::Ukrainian)
+                .currency(Currency::UAH)
+                .measure()
+                .map(|measure| measure.word_count),
+            Ok(3)
+        );
+    }
+
+    #[test]
+    fn test_fits_within() {
+        assert_eq!(Num2Words::new(42).fits_within(9), Ok(true));
+        assert_eq!(Num2Words::new(42).fits_within(8), Ok(false));
+        assert_eq!(
+            Num2Words::new(1.5).ordinal().fits_within(100),
+            Err(Num2Err::FloatingOrdinal)
+        );
+    }
+}
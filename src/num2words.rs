@@ -1,8 +1,15 @@
-use crate::{lang, Currency, Lang, Output};
+use crate::reference::{self, ReferenceStyle};
+use crate::rounding::{quantize, Rounding};
+use crate::{lang, Agreement, ConfigLint, Currency, Lang, Language, Measure, Output, Report, Unit};
 use num_bigfloat::BigFloat;
 
+// decimal places read when no precision is set, binary floats such as
+// 0.1 + 0.2 having a long tail of spurious digits
+const DEFAULT_PRECISION: usize = 12;
+
 /// Error type returned by the builder
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum Num2Err {
     /// General error, language cannot convert number
     ///
@@ -73,6 +80,21 @@ pub enum Num2Err {
     /// );
     /// ```
     InfiniteYear,
+    /// Request of two different outputs with `prefer("strict-config")`
+    ///
+    /// Example:
+    /// ```
+    /// use num2words::{Currency, Num2Err, Num2Words};
+    /// assert_eq!(
+    ///     Num2Words::new(2024)
+    ///         .year()
+    ///         .currency(Currency::EUR)
+    ///         .prefer("strict-config")
+    ///         .to_words(),
+    ///     Err(Num2Err::ConflictingOutputs)
+    /// );
+    /// ```
+    ConflictingOutputs,
 }
 
 impl std::fmt::Display for Num2Err {
@@ -87,6 +109,7 @@ impl std::fmt::Display for Num2Err {
                 Num2Err::FloatingYear => "cannot treat float as year",
                 Num2Err::InfiniteOrdinal => "cannot treat infinity as ordinal",
                 Num2Err::InfiniteYear => "cannot treat infinity as year",
+                Num2Err::ConflictingOutputs => "cannot build two different outputs",
             }
         )
     }
@@ -97,7 +120,16 @@ pub struct Num2Words {
     num: BigFloat,
     lang: Lang,
     output: Output,
+    // every output requested, in order
+    outputs: Vec<Output>,
     currency: Currency,
+    unit: Unit,
+    reference: String,
+    // last year of a range, the first one being `num`
+    range_end: BigFloat,
+    // decimal places kept by the cardinal, percent and unit outputs
+    precision: Option<usize>,
+    rounding: Option<Rounding>,
     preferences: Vec<String>,
 }
 
@@ -124,7 +156,13 @@ impl Num2Words {
             num: num.into(),
             lang: Lang::English,
             output: Output::Cardinal,
+            outputs: vec![],
             currency: Currency::DOLLAR,
+            unit: Unit::Meter,
+            reference: String::new(),
+            range_end: BigFloat::from(0),
+            precision: None,
+            rounding: None,
             preferences: vec![],
         }
     }
@@ -144,17 +182,48 @@ impl Num2Words {
     /// );
     /// ```
     pub fn parse(num: &str) -> Option<Self> {
-        let num = BigFloat::parse(num)?;
+        match Self::parse_prefix(num) {
+            Some((n2w, len)) if len == num.len() => Some(n2w),
+            _ => None,
+        }
+    }
+
+    /// Creates a new builder from the number at the start of a string
+    ///
+    /// Returns the builder along with the number of bytes consumed. The
+    /// longest valid number is read (sign, digits, `_` separators, decimal
+    /// point and exponent) and the rest of the string is left untouched.
+    ///
+    /// Example:
+    /// ```
+    /// use num2words::Num2Words;
+    /// let (n2w, len) = Num2Words::parse_prefix("42 apples").unwrap();
+    /// assert_eq!(n2w.to_words(), Ok(String::from("forty-two")));
+    /// assert_eq!(len, 2);
+    /// assert!(Num2Words::parse_prefix("apples").is_none());
+    /// ```
+    pub fn parse_prefix(num: &str) -> Option<(Self, usize)> {
+        let len = scan_number(num)?;
+        let num = BigFloat::parse(&num[..len].replace('_', "").to_lowercase())?;
         if num.is_nan() {
             return None;
         }
-        Some(Self {
-            num,
-            lang: Lang::English,
-            output: Output::Cardinal,
-            currency: Currency::DOLLAR,
-            preferences: vec![],
-        })
+        Some((
+            Self {
+                num,
+                lang: Lang::English,
+                output: Output::Cardinal,
+                outputs: vec![],
+                currency: Currency::DOLLAR,
+                unit: Unit::Meter,
+                reference: String::new(),
+                range_end: BigFloat::from(0),
+                precision: None,
+                rounding: None,
+                preferences: vec![],
+            },
+            len,
+        ))
     }
 
     /// Sets the language of the output
@@ -185,12 +254,16 @@ impl Num2Words {
     /// );
     /// ```
     pub fn cardinal(mut self) -> Self {
-        self.output = Output::Cardinal;
+        self.set_output(Output::Cardinal);
         self
     }
 
     /// Sets the type of output to ordinal (`forty-second`)
     ///
+    /// Zero has an ordinal (`zeroth`) in every language that has a standard
+    /// form for it; the others, e.g. `Lang::Spanish`, return
+    /// `Num2Err::CannotConvert` and say so in their documentation.
+    ///
     /// Example:
     /// ```
     /// use num2words::Num2Words;
@@ -200,7 +273,7 @@ impl Num2Words {
     /// );
     /// ```
     pub fn ordinal(mut self) -> Self {
-        self.output = Output::Ordinal;
+        self.set_output(Output::Ordinal);
         self
     }
 
@@ -215,7 +288,7 @@ impl Num2Words {
     /// );
     /// ```
     pub fn ordinal_num(mut self) -> Self {
-        self.output = Output::OrdinalNum;
+        self.set_output(Output::OrdinalNum);
         self
     }
 
@@ -230,7 +303,32 @@ impl Num2Words {
     /// );
     /// ```
     pub fn year(mut self) -> Self {
-        self.output = Output::Year;
+        self.set_output(Output::Year);
+        self
+    }
+
+    /// Sets the output to a range of years, from the number to `end`
+    ///
+    /// A range of a single year is the year output.
+    ///
+    /// Example:
+    /// ```
+    /// use num2words::{Lang, Num2Words};
+    /// assert_eq!(
+    ///     Num2Words::new(2020).year_range(2023).to_words(),
+    ///     Ok(String::from("from twenty twenty to twenty twenty-three"))
+    /// );
+    /// assert_eq!(
+    ///     Num2Words::new(2020).year_range(2020).to_words(),
+    ///     Ok(String::from("twenty twenty"))
+    /// );
+    /// ```
+    pub fn year_range<T>(mut self, end: T) -> Self
+    where
+        T: Into<BigFloat>,
+    {
+        self.set_output(Output::YearRange);
+        self.range_end = end.into();
         self
     }
 
@@ -247,21 +345,361 @@ impl Num2Words {
     /// );
     /// ```
     pub fn currency(mut self, currency: Currency) -> Self {
-        self.output = Output::Currency;
+        self.set_output(Output::Currency);
         self.currency = currency;
         self
     }
 
+    /// Sets the type of output to percent (`forty-two percent`)
+    ///
+    /// Example:
+    /// ```
+    /// use num2words::Num2Words;
+    /// assert_eq!(
+    ///     Num2Words::new(42).percent().to_words(),
+    ///     Ok(String::from("forty-two percent"))
+    /// );
+    /// ```
+    pub fn percent(mut self) -> Self {
+        self.set_output(Output::Percent);
+        self
+    }
+
+    /// Sets the output to the unit of measurement it has been given
+    ///
+    /// For all of the available units, see [`Unit`].
+    ///
+    /// Example:
+    /// ```
+    /// use num2words::{Num2Words, Unit};
+    /// assert_eq!(
+    ///     Num2Words::new(42).unit(Unit::Kilogram).to_words(),
+    ///     Ok(String::from("forty-two kilograms"))
+    /// );
+    /// ```
+    pub fn unit(mut self, unit: Unit) -> Self {
+        self.set_output(Output::Unit);
+        self.unit = unit;
+        self
+    }
+
+    /// Rounds the cardinal, percent and unit outputs to `precision` decimal
+    /// places, 12 by default
+    ///
+    /// Ties are rounded away from zero unless another mode is set with
+    /// [`Num2Words::rounding`].
+    ///
+    /// Example:
+    /// ```
+    /// use num2words::Num2Words;
+    /// assert_eq!(
+    ///     Num2Words::new(3.14159).precision(2).to_words(),
+    ///     Ok(String::from("three point one four"))
+    /// );
+    /// ```
+    pub fn precision(mut self, precision: usize) -> Self {
+        self.precision = Some(precision);
+        self
+    }
+
+    /// Sets how fractional values are rounded
+    ///
+    /// The mode applies to the decimal places set with
+    /// [`Num2Words::precision`] and to the cents of the currency output,
+    /// which are otherwise truncated, or rounded half-up in English.
+    /// [`Rounding::Truncate`] keeps English cents truncated.
+    ///
+    /// Example:
+    /// ```
+    /// use num2words::{Currency, Num2Words, Rounding};
+    /// let amount = Num2Words::parse("2.675").unwrap();
+    /// assert_eq!(
+    ///     amount.currency(Currency::DOLLAR).rounding(Rounding::HalfEven).to_words(),
+    ///     Ok(String::from("two dollars and sixty-eight cents"))
+    /// );
+    /// assert_eq!(
+    ///     Num2Words::new(2.5).precision(0).rounding(Rounding::HalfEven).to_words(),
+    ///     Ok(String::from("two"))
+    /// );
+    /// ```
+    pub fn rounding(mut self, rounding: Rounding) -> Self {
+        self.rounding = Some(rounding);
+        self
+    }
+
+    /// Separates the thousands of ordinal numbers
+    ///
+    /// The separator is the one of the language: a comma in English and a
+    /// space in French and Ukrainian. This is the same as
+    /// `prefer("group-digits")`.
+    ///
+    /// Example:
+    /// ```
+    /// use num2words::{Lang, Num2Words};
+    /// assert_eq!(
+    ///     Num2Words::new(1_000_000).ordinal_num().group_digits().to_words(),
+    ///     Ok(String::from("1,000,000th"))
+    /// );
+    /// assert_eq!(
+    ///     Num2Words::new(1_000_000)
+    ///         .lang(Lang::French)
+    ///         .ordinal_num()
+    ///         .group_digits()
+    ///         .to_words(),
+    ///     Ok(String::from("1 000 000ème"))
+    /// );
+    /// ```
+    pub fn group_digits(self) -> Self {
+        self.prefer("group-digits")
+    }
+
+    /// Sets the output to an alphanumeric reference read aloud, e.g., an IBAN
+    ///
+    /// The number of the builder is ignored. Characters are read by groups of
+    /// four, letters as letters and digits one by one, except for groups of
+    /// two digits read as a number. Lowercase letters are uppercased and
+    /// whitespace is ignored.
+    ///
+    /// `prefer("nato")` reads the letters with the NATO phonetic alphabet, and
+    /// `prefer("pairs")` reads every run of digits in pairs.
+    ///
+    /// Example:
+    /// ```
+    /// use num2words::Num2Words;
+    /// assert_eq!(
+    ///     Num2Words::new(0).reference("DE89 3704").to_words(),
+    ///     Ok(String::from("D E eighty-nine, three seven zero four"))
+    /// );
+    /// assert_eq!(
+    ///     Num2Words::new(0)
+    ///         .reference("de89 3704")
+    ///         .prefer("nato")
+    ///         .prefer("pairs")
+    ///         .to_words(),
+    ///     Ok(String::from("Delta Echo eighty-nine, thirty-seven zero four"))
+    /// );
+    /// ```
+    pub fn reference<T>(mut self, reference: T) -> Self
+    where
+        T: Into<String>,
+    {
+        self.set_output(Output::Reference);
+        self.reference = reference.into();
+        self
+    }
+
     /// Adds a preference parameter
     ///
+    /// # All languages accept:
+    /// strict-config to fail with [`Num2Err::ConflictingOutputs`] when two
+    /// different outputs are requested, see also [`Num2Words::lints`]
+    ///
+    /// zero-major to keep the major currency unit for amounts below one
+    /// (`zero dollars and twenty cents`)
+    ///
+    /// always-cents/zero-cents to keep the cents clause of whole amounts in
+    /// English, French and Ukrainian (`forty-two dollars and zero cents`),
+    /// or no-cents to read it with "no" in English (`and no cents`)
+    ///
+    /// group-digits to separate the thousands of ordinal numbers in English,
+    /// French and Ukrainian (`1,000,000th`), see also
+    /// [`Num2Words::group_digits`]
+    ///
+    /// no-leading-zero to leave out the zero before the decimal point of
+    /// numbers below one in English and French (`point five`)
+    ///
+    /// accounting-negative to write negative currency amounts with a trailing
+    /// marker rather than a leading minus (`forty-two dollars (negative)`),
+    /// along with parentheses to wrap them instead (`(forty-two dollars)`)
+    ///
+    /// nato and/or pairs to read references, see [`Num2Words::reference`]
+    ///
+    /// The languages with a grammatical gender or number all understand
+    /// f/feminine, m/masculine and pl/plural besides their native words; the
+    /// last gender given wins.
+    ///
+    /// # Arabic language accepts:
+    /// **masculine/m/مذكر**, feminine/f/مؤنث for the gender of the counted noun
+    ///
+    /// eastern-digits for numbered ordinals in Arabic-Indic digits (`الـ٤٢`)
+    ///
+    /// # Armenian language accepts:
+    /// թվական/tvakan to suffix years with "թվական"
+    ///
+    /// # Chinese language accepts:
+    /// traditional/繁體/繁体 for the traditional script (`四十二萬`)
+    ///
+    /// # Czech language supports grammatical categories (bold - default):
+    /// Number: **singular/sing/jednotné/j**, plural/pl/množné/mn
+    ///
+    /// Gender: **masculine/m/mužský**, feminine/f/ženský, neuter/n/střední
+    ///
+    /// Declension: **nominative/nom/nominativ**, genitive/gen/genitiv, dative/dat/dativ,
+    /// accusative/acc/akuzativ, instrumental/ins/instrumentál, locative/loc/lokál,
+    /// vocative/voc/vokativ
+    ///
+    /// # Dutch language accepts:
+    /// ste for full numeric ordinal suffixes (`42ste` rather than `42e`)
+    ///
     /// # English language accepts:
-    /// oh and/or nil as replacements for "zero"
-    /// 
+    /// oh, nil or nought/naught as replacements for "zero", the leading zero
+    /// of decimals being read alike (`nought point nought five`)
+    ///
+    /// us/american for years beyond 9999 read without "and" (`twelve
+    /// thousand twenty-four`)
+    ///
+    /// uk/gb/british for "and" after every hundred (`one hundred and
+    /// twenty-three thousand four hundred and fifty-six`)
+    ///
+    /// no-and/plain-cardinal for no "and" within numbers, as in US check
+    /// writing (`one thousand nine hundred thirty-two`), the "and" joining
+    /// dollars and cents being kept
+    ///
+    /// long-scale for the long scale, where a billion is a million millions
+    /// (`one milliard` for 10^9), or thousand-million to read the milliards
+    /// as thousands of millions (`one thousand million`)
+    ///
+    /// lakh/indian for the Indian grouping in lakhs and crores (`twelve crore
+    /// thirty-four lakh fifty-six thousand seven hundred and eighty-nine`),
+    /// round lakhs and crores having no ordinal
+    ///
+    /// a/informal for a leading "a" rather than "one" before a hundred or a
+    /// higher power (`a thousand and two`, `a thousand dollars`)
+    ///
+    /// negative for negative numbers read with "negative" rather than "minus"
+    /// (`negative ten`)
+    ///
+    /// bce for years marked BCE and CE rather than BC and nothing (`forty-four
+    /// BCE`, `nineteen ninety CE`), ad for years of the first millennium
+    /// prefixed with AD (`AD seventy-nine`)
+    ///
+    /// formal-year for years read as cardinals rather than in pairs of digits
+    /// (`two thousand and twenty-two`, or `two thousand twenty-two` with us),
+    /// or oh-years for the years X001 to X009 read in pairs of digits
+    /// (`twenty oh-one`)
+    ///
+    /// no-hyphen for the tens and the units separated with a space (`forty
+    /// two`), or hyphen-all for every word of a number joined with hyphens
+    /// (`one-hundred-twenty-three`)
+    ///
+    /// fraction for decimals read as common fractions rather than digit by
+    /// digit (`three and two tenths`, `seventy-five hundredths`)
+    ///
+    /// hundreds for the numbers from 1100 to 9999 read in hundreds unless
+    /// their hundreds digit is zero (`twenty-five hundred`, `nineteen hundred
+    /// eighty-four`)
+    ///
+    /// check for currency amounts written as on US checks (`One thousand
+    /// forty-two and 50/100 dollars`)
+    ///
+    /// # Finnish language supports grammatical cases (bold - default):
+    /// Declension: **nominative/nom/nominatiivi**, genitive/gen/genetiivi,
+    /// partitive/part/partitiivi
+    ///
     /// # French language accepts:
-    /// feminine/f/féminin/feminin
-    /// 
+    /// **masculine/m/masculin**, feminine/f/féminin/feminin
+    ///
     /// reformed/1990/rectifié/rectification
     ///
+    /// point for decimals read with "point" rather than "virgule" (`douze
+    /// point cinq`), grouped-decimals for decimals read as a number (`douze
+    /// virgule cinquante et un`), fraction for decimals read as a common
+    /// fraction (`trois et deux dixièmes`, `trois quarts`)
+    ///
+    /// For currencies: cent for the subunit of the euro named as in the legal
+    /// texts rather than centime (`quarante-deux cents`)
+    ///
+    /// For ordinal numbers: pl/plural/pluriel (`1ers`, `42èmes`),
+    /// second/seconde for two (`2d`, `2de` in the feminine) and short/e for
+    /// the suffix recommended by the Imprimerie nationale (`42e`)
+    ///
+    /// For years: hundreds for the years 1100 to 1999 read as hundreds
+    /// (`dix-neuf cent quatre-vingt-dix`), abrégé/abrege for the era written
+    /// `av. J.-C.` rather than `avant JC`, notre-ère/notre-ere for `avant
+    /// notre ère`, and ère/ere for the era of positive years too (`après JC`)
+    ///
+    /// In Swiss French: octante for eighty rather than huitante
+    ///
+    /// In Canadian French, where years are read as hundreds and the era is
+    /// abbreviated by default:
+    /// formal-year for years read as cardinals (`mille neuf cent
+    /// quatre-vingt-dix`), sous for the cents of dollars
+    ///
+    /// # Icelandic language supports grammatical categories (bold - default):
+    /// Gender: **masculine/m/kk/karlkyn**, feminine/f/kvk/kvenkyn, neuter/n/hk/hvorugkyn
+    ///
+    /// Case: **nominative/nom/nf/nefnifall**, accusative/acc/þf/þolfall,
+    /// dative/dat/þgf/þágufall, genitive/gen/ef/eignarfall
+    ///
+    /// # Italian language accepts:
+    /// **masculine/m/maschile**, feminine/f/femminile
+    ///
+    /// # Latin language supports grammatical categories (bold - default):
+    /// Gender: **masculine/m/masculinum**, feminine/f/femininum, neuter/n/neutrum
+    ///
+    /// Case: **nominative/nom/nominativus**, genitive/gen/genetivus, dative/dat/dativus,
+    /// accusative/acc/accusativus, ablative/abl/ablativus
+    ///
+    /// Series: distributive/bini (`bini`), adverbial/bis (`bis`)
+    ///
+    /// # Latvian language accepts:
+    /// **masculine/m/vīriešu**, feminine/f/sieviešu
+    ///
+    /// # Mongolian language accepts:
+    /// short/р for abbreviated numeric ordinals (`42-р`)
+    ///
+    /// # Polish language supports grammatical categories (bold - default):
+    /// Number: **singular/sing/pojedyncza/lp**, plural/pl/mnoga/lm
+    ///
+    /// Gender: **masculine/m/męski**, feminine/f/żeński/ż, neuter/n/nijaki
+    ///
+    /// Declension: **nominative/nom/mianownik/mian**, genitive/gen/dopełniacz/dop,
+    /// dative/dat/celownik/cel, accusative/acc/biernik/bier, instrumental/ins/narzędnik/narz,
+    /// locative/loc/miejscownik/miej, vocative/voc/wołacz/woł
+    ///
+    /// # Portuguese language accepts:
+    /// **masculine/m/masculino**, feminine/f/feminino
+    ///
+    /// br/pt_BR for Brazilian Portuguese
+    ///
+    /// # Romanian language accepts:
+    /// **masculine/m/masculin**, feminine/f/feminin
+    ///
+    /// # Serbian language accepts:
+    /// latin/latinica/латиница for the Latin script rather than the Cyrillic one
+    ///
+    /// Gender: **masculine/m/muški/мушки**, feminine/f/ženski/женски,
+    /// neuter/n/srednji/средњи
+    ///
+    /// # Sinhala language accepts:
+    /// වැනි/vaeni for ordinals in වැනි rather than වන (`හතළිස් දෙවැනි`)
+    ///
+    /// # Slovak language supports grammatical categories (bold - default):
+    /// Number: **singular/sing/jednotné/j**, plural/pl/množné/mn
+    ///
+    /// Gender: **masculine/m/mužský**, feminine/f/ženský, neuter/n/stredný
+    ///
+    /// Declension: **nominative/nom/nominatív**, genitive/gen/genitív, dative/dat/datív,
+    /// accusative/acc/akuzatív, instrumental/ins/inštrumentál, locative/loc/lokál
+    ///
+    /// # Slovenian language accepts:
+    /// Gender: masculine/m/moški, feminine/f/ženski, neuter/n/srednji, the
+    /// counting forms being used when none is given (`ena`, `dva`, `tri`)
+    ///
+    /// # Spanish language accepts:
+    /// **masculine/m/masculino**, feminine/f/femenino
+    ///
+    /// # Swahili language accepts:
+    /// a noun class for cardinals, currencies and units to agree with, by
+    /// plural prefix or class number: wa/1/2, mi/3/4, ma/5/6, vi/7/8, n/9/10
+    ///
+    /// # Tagalog language accepts:
+    /// spanish/kastila for the numerals borrowed from Spanish, as used for
+    /// money and time (`kuwarenta'y dos pesos`), ordinals staying native
+    ///
+    /// # Turkish language accepts:
+    /// suffix/ek for numbered ordinals with a suffix rather than a dot (`42'nci`)
+    ///
     /// # Ukrainian language supports grammatical categories (bold - default):
     /// Number: **singular/sing/однина/од**, plural/pl/множина/мн
     ///
@@ -270,12 +708,35 @@ impl Num2Words {
     /// Declension: **nominative/nom/називний/н**, genitive/gen/родовий/р, dative/dat/давальний/д,
     /// accusative/acc/знахідний/з, instrumental/inc/орудний/о, locative/loc/місцевий/м
     ///
+    /// collective/збірний for the collective numerals from two to twenty
+    /// (`двоє`, `пʼятеро`), which count their noun in the genitive plural
+    ///
+    /// істота/animate for the accusative of an animate noun, which takes the
+    /// genitive of one to four (`двох`)
+    ///
+    /// кома/comma for decimals read digit by digit (`одна кома один`) rather
+    /// than as a fraction (`одна ціла одна десята`)
+    ///
+    /// # Uzbek language accepts:
+    /// cyrillic/kirill for the Cyrillic script
+    ///
+    /// # Vietnamese language accepts:
+    /// linh rather than lẻ for a skipped ten (`một trăm linh một`)
+    ///
+    /// tư/tu rather than bốn for a four after a ten (`hai mươi tư`)
+    ///
+    /// ngàn/ngan rather than nghìn for a thousand
+    ///
+    /// # Zulu language accepts:
+    /// a noun class for plain cardinals to agree with, by plural prefix or
+    /// class number: aba/1/2, imi/3/4, ama/5/6, izi/7/8, izin/9/10
+    ///
     /// Examples:
     /// ```
     /// use num2words::{Num2Words, Lang};
     /// assert_eq!(
     ///     Num2Words::new(0.05).prefer("oh").to_words(),
-    ///     Ok(String::from("point oh five"))
+    ///     Ok(String::from("oh point oh five"))
     /// );
     /// assert_eq!(
     ///     Num2Words::new(161).lang(Lang::French).prefer("f").prefer("reformed").to_words(),
@@ -294,49 +755,400 @@ impl Num2Words {
         self
     }
 
+    /// Lists the settings of the builder that have no effect
+    ///
+    /// Example:
+    /// ```
+    /// use num2words::{ConfigLint, Currency, Lang, Num2Words, Output};
+    /// assert_eq!(
+    ///     Num2Words::new(2024)
+    ///         .lang(Lang::French)
+    ///         .year()
+    ///         .currency(Currency::EUR)
+    ///         .prefer("oh")
+    ///         .lints(),
+    ///     vec![
+    ///         ConfigLint::OverriddenOutput {
+    ///             previous: Output::Year,
+    ///             current: Output::Currency,
+    ///         },
+    ///         ConfigLint::UnusedPreference(String::from("oh")),
+    ///     ]
+    /// );
+    /// ```
+    pub fn lints(&self) -> Vec<ConfigLint> {
+        let mut lints: Vec<ConfigLint> = self
+            .outputs
+            .windows(2)
+            .map(|outputs| ConfigLint::OverriddenOutput {
+                previous: outputs[0],
+                current: outputs[1],
+            })
+            .collect();
+
+        for preference in &self.preferences {
+            match lang::preference_outputs(self.lang, preference) {
+                None => lints.push(ConfigLint::UnusedPreference(preference.clone())),
+                Some(outputs) if !outputs.contains(&self.output) => {
+                    lints.push(ConfigLint::IgnoredPreference {
+                        preference: preference.clone(),
+                        output: self.output,
+                    })
+                }
+                Some(_) => (),
+            }
+        }
+
+        lints
+    }
+
     /// Builds the output
+    ///
+    /// Magnitudes below the smallest normal `BigFloat` (e.g., `1e-5000` or
+    /// `1e-100`) cannot be represented faithfully and are treated as an exact
+    /// zero for every output type.
+    ///
+    /// Example:
+    /// ```
+    /// use num2words::Num2Words;
+    /// assert_eq!(
+    ///     Num2Words::parse("-1e-5000").unwrap().to_words(),
+    ///     Ok(String::from("zero"))
+    /// );
+    /// ```
     pub fn to_words(self) -> Result<String, Num2Err> {
+        if self.outputs.len() > 1 && self.preferences.iter().any(|p| p == "strict-config") {
+            return Err(Num2Err::ConflictingOutputs);
+        }
+        let negative = NegativeStyle::from_preferences(&self.preferences);
+        let style = ReferenceStyle::from_preferences(&self.preferences);
+        let num = self.quantized_num();
         let lang = lang::to_language(self.lang, self.preferences);
-        match self.output {
-            Output::Cardinal => lang.to_cardinal(self.num),
-            Output::Currency => lang.to_currency(self.num, self.currency),
-            Output::Ordinal => {
-                if self.num.is_inf() {
-                    return Err(Num2Err::InfiniteOrdinal);
-                }
-                if !self.num.frac().is_zero() {
-                    return Err(Num2Err::FloatingOrdinal);
-                }
-                if self.num.is_negative() {
-                    return Err(Num2Err::NegativeOrdinal);
-                }
-                lang.to_ordinal(self.num)
+        if self.output == Output::Reference {
+            return reference::spell(&*lang, &self.reference, style);
+        }
+        if self.output == Output::YearRange {
+            return year_range(&*lang, num, self.range_end);
+        }
+        convert(&*lang, num, self.output, self.currency, self.unit, negative)
+    }
+
+    /// Measures the output, as built by [`Num2Words::to_words`]
+    ///
+    /// The length is counted in characters rather than bytes.
+    ///
+    /// Example:
+    /// ```
+    /// use num2words::{Measure, Num2Words};
+    /// assert_eq!(
+    ///     Num2Words::new(1042).measure(),
+    ///     Ok(Measure {
+    ///         char_len: 26,
+    ///         word_count: 4
+    ///     })
+    /// );
+    /// ```
+    pub fn measure(self) -> Result<Measure, Num2Err> {
+        self.to_words().map(|words| Measure::of(&words))
+    }
+
+    /// Tells whether the output holds in at most `chars` characters
+    ///
+    /// Example:
+    /// ```
+    /// use num2words::Num2Words;
+    /// assert_eq!(Num2Words::new(42).fits_within(10), Ok(true));
+    /// assert_eq!(Num2Words::new(77).fits_within(10), Ok(false));
+    /// ```
+    pub fn fits_within(self, chars: usize) -> Result<bool, Num2Err> {
+        self.measure().map(|measure| measure.char_len <= chars)
+    }
+
+    /// Gives the form of a noun counted by the number
+    ///
+    /// This is the agreement used by the currency and unit outputs, for
+    /// applications that spell their own nouns. The gender and case set with
+    /// [`Num2Words::prefer`] are the ones of the counted noun. Fails like
+    /// [`Num2Words::to_words`] when the language cannot convert the number.
+    ///
+    /// Example:
+    /// ```
+    /// use num2words::{GrammaticalNumber, Lang, Num2Words, PluralCategory};
+    /// let agreement = Num2Words::new(42).lang(Lang::English).agreement().unwrap();
+    /// assert_eq!(agreement.category, PluralCategory::Other);
+    /// assert_eq!(agreement.number, GrammaticalNumber::Plural);
+    /// assert_eq!(agreement.case, None);
+    /// ```
+    pub fn agreement(self) -> Result<Agreement, Num2Err> {
+        let lang = lang::to_language(self.lang, self.preferences);
+        let mut num = self.num;
+        if num.is_nan() {
+            return Err(Num2Err::CannotConvert);
+        }
+        if num.is_subnormal() || num.is_zero() {
+            num = BigFloat::from(0);
+        }
+        lang.to_cardinal(num)?;
+        Ok(lang.agreement(num))
+    }
+
+    // the number as rounded for the output
+    fn quantized_num(&self) -> BigFloat {
+        let mode = self.rounding.unwrap_or(Rounding::HalfUp);
+        match (self.output, self.precision) {
+            (Output::Currency, _) if self.rounding.is_some() => quantize(self.num, 2, mode),
+            (Output::Cardinal | Output::Percent | Output::Unit, precision) => {
+                quantize(self.num, precision.unwrap_or(DEFAULT_PRECISION), mode)
             }
-            Output::OrdinalNum => {
-                if self.num.is_inf() {
-                    return Err(Num2Err::InfiniteOrdinal);
-                }
-                if !self.num.frac().is_zero() {
-                    return Err(Num2Err::FloatingOrdinal);
-                }
-                if self.num.is_negative() {
-                    return Err(Num2Err::NegativeOrdinal);
-                }
-                lang.to_ordinal_num(self.num)
+            _ => self.num,
+        }
+    }
+
+    fn set_output(&mut self, output: Output) {
+        if self.outputs.last() != Some(&output) {
+            self.outputs.push(output);
+        }
+        self.output = output;
+    }
+
+    /// Builds every output at once for a given number
+    ///
+    /// The language is only built once, and each output keeps its own result
+    /// so that unsupported combinations do not hide the others.
+    ///
+    /// Example:
+    /// ```
+    /// use num2words::{Lang, Num2Err, Num2Words};
+    /// let report = Num2Words::report(42, Lang::English, &["oh"]);
+    /// assert_eq!(report.cardinal, Ok(String::from("forty-two")));
+    /// assert_eq!(report.ordinal_num, Ok(String::from("42nd")));
+    /// assert_eq!(report.currencies["EUR"], Ok(String::from("forty-two euros")));
+    ///
+    /// let report = Num2Words::report(-1.5, Lang::English, &[] as &[&str]);
+    /// assert_eq!(report.ordinal, Err(Num2Err::FloatingOrdinal));
+    /// ```
+    pub fn report<T, P>(num: T, lang: Lang, preferences: &[P]) -> Report
+    where
+        T: Into<BigFloat>,
+        P: AsRef<str>,
+    {
+        let num = num.into();
+        let preferences = preferences
+            .iter()
+            .map(|p| String::from(p.as_ref()))
+            .collect::<Vec<String>>();
+        let negative = NegativeStyle::from_preferences(&preferences);
+        let lang = lang::to_language(lang, preferences);
+        let output =
+            |num, output| convert(&*lang, num, output, Currency::DOLLAR, Unit::Meter, negative);
+        let quantized = quantize(num, DEFAULT_PRECISION, Rounding::HalfUp);
+
+        Report {
+            cardinal: output(quantized, Output::Cardinal),
+            ordinal: output(num, Output::Ordinal),
+            ordinal_num: output(num, Output::OrdinalNum),
+            year: output(num, Output::Year),
+            percent: output(quantized, Output::Percent),
+            currencies: Currency::ALL
+                .iter()
+                .map(|&currency| {
+                    (
+                        String::from(currency.code()),
+                        convert(
+                            &*lang,
+                            num,
+                            Output::Currency,
+                            currency,
+                            Unit::Meter,
+                            negative,
+                        ),
+                    )
+                })
+                .collect(),
+        }
+    }
+}
+
+/// How a negative currency amount is written
+#[derive(Clone, Copy, PartialEq)]
+enum NegativeStyle {
+    /// Leading minus word, e.g. `minus forty-two dollars`
+    Minus,
+    /// Trailing marker, e.g. `forty-two dollars (negative)`
+    Marker,
+    /// Parentheses, e.g. `(forty-two dollars)`
+    Parentheses,
+}
+
+impl NegativeStyle {
+    fn from_preferences(preferences: &[String]) -> Self {
+        let prefers = |name: &str| preferences.iter().any(|p| p == name);
+        if !prefers("accounting-negative") {
+            NegativeStyle::Minus
+        } else if prefers("parentheses") {
+            NegativeStyle::Parentheses
+        } else {
+            NegativeStyle::Marker
+        }
+    }
+}
+
+fn convert(
+    lang: &dyn Language,
+    mut num: BigFloat,
+    output: Output,
+    currency: Currency,
+    unit: Unit,
+    negative: NegativeStyle,
+) -> Result<String, Num2Err> {
+    // not a number, e.g. `f64::NAN`
+    if num.is_nan() {
+        return Err(Num2Err::CannotConvert);
+    }
+    if num.is_subnormal() || num.is_zero() {
+        // also drops the sign of negative zero
+        num = BigFloat::from(0);
+    }
+
+    match output {
+        Output::Cardinal => lang.to_cardinal(num),
+        Output::Currency if num.is_negative() && negative != NegativeStyle::Minus => {
+            let words = lang.to_currency(-num, currency)?;
+            Ok(match negative {
+                NegativeStyle::Parentheses => format!("({})", words),
+                _ => format!("{} ({})", words, lang.negative_marker()),
+            })
+        }
+        Output::Currency => lang.to_currency(num, currency),
+        Output::Percent => lang.to_percent(num),
+        Output::Unit => lang.to_unit(num, unit),
+        Output::Ordinal => {
+            if num.is_inf() {
+                return Err(Num2Err::InfiniteOrdinal);
             }
-            Output::Year => {
-                if self.num.is_inf() {
-                    return Err(Num2Err::InfiniteYear);
-                }
-                if !self.num.frac().is_zero() {
-                    return Err(Num2Err::FloatingYear);
-                }
-                lang.to_year(self.num)
+            if !num.frac().is_zero() {
+                return Err(Num2Err::FloatingOrdinal);
+            }
+            if num.is_negative() {
+                return Err(Num2Err::NegativeOrdinal);
+            }
+            lang.to_ordinal(num)
+        }
+        Output::OrdinalNum => {
+            if num.is_inf() {
+                return Err(Num2Err::InfiniteOrdinal);
             }
+            if !num.frac().is_zero() {
+                return Err(Num2Err::FloatingOrdinal);
+            }
+            if num.is_negative() {
+                return Err(Num2Err::NegativeOrdinal);
+            }
+            // numbered ordinals are written from a machine integer
+            if num.to_u128().is_none() {
+                return Err(Num2Err::CannotConvert);
+            }
+            lang.to_ordinal_num(num)
         }
+        Output::Year => {
+            if num.is_inf() {
+                return Err(Num2Err::InfiniteYear);
+            }
+            if !num.frac().is_zero() {
+                return Err(Num2Err::FloatingYear);
+            }
+            if num.to_i64().is_none() {
+                return Err(Num2Err::CannotConvert);
+            }
+            lang.to_year(num)
+        }
+        // neither a reference nor a range is a single number, they are built
+        // by `Num2Words::to_words`
+        Output::Reference | Output::YearRange => Err(Num2Err::CannotConvert),
     }
 }
 
+fn year_range(lang: &dyn Language, from: BigFloat, to: BigFloat) -> Result<String, Num2Err> {
+    for year in [from, to] {
+        if year.is_nan() {
+            return Err(Num2Err::CannotConvert);
+        }
+        if year.is_inf() {
+            return Err(Num2Err::InfiniteYear);
+        }
+        if !year.frac().is_zero() {
+            return Err(Num2Err::FloatingYear);
+        }
+        if year.to_i64().is_none() {
+            return Err(Num2Err::CannotConvert);
+        }
+    }
+
+    if from == to {
+        lang.to_year(from)
+    } else {
+        lang.to_year_range(from, to)
+    }
+}
+
+/// Returns the length in bytes of the longest number at the start of `s`
+fn scan_number(s: &str) -> Option<usize> {
+    let bytes = s.as_bytes();
+    let digits_from = |mut i: usize| {
+        // digits, with `_` only allowed between two digits
+        while i < bytes.len()
+            && (bytes[i].is_ascii_digit()
+                || (bytes[i] == b'_'
+                    && i + 1 < bytes.len()
+                    && bytes[i + 1].is_ascii_digit()
+                    && i > 0
+                    && bytes[i - 1].is_ascii_digit()))
+        {
+            i += 1;
+        }
+        i
+    };
+
+    let mut i = 0;
+    if i < bytes.len() && (bytes[i] == b'+' || bytes[i] == b'-') {
+        i += 1;
+    }
+
+    for word in ["infinity", "inf"] {
+        if s[i..]
+            .get(..word.len())
+            .is_some_and(|w| w.eq_ignore_ascii_case(word))
+        {
+            return Some(i + word.len());
+        }
+    }
+
+    let int_end = digits_from(i);
+    let mut end = int_end;
+    if end < bytes.len() && bytes[end] == b'.' {
+        let frac_end = digits_from(end + 1);
+        if frac_end > end + 1 {
+            end = frac_end;
+        }
+    }
+    if end == i {
+        return None;
+    }
+
+    if end < bytes.len() && (bytes[end] == b'e' || bytes[end] == b'E') {
+        let mut j = end + 1;
+        if j < bytes.len() && (bytes[j] == b'+' || bytes[j] == b'-') {
+            j += 1;
+        }
+        let exp_end = digits_from(j);
+        if exp_end > j {
+            end = exp_end;
+        }
+    }
+
+    Some(end)
+}
+
 #[cfg(test)]
 mod tests {
     use crate::*;
@@ -345,5 +1157,428 @@ mod tests {
     fn test_string_not_valid() {
         assert!(Num2Words::parse("not a number").is_none());
         assert!(Num2Words::parse("NAN").is_none());
+        assert!(Num2Words::parse("42 apples").is_none());
+        assert!(Num2Words::parse("1,000").is_none());
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            Num2Words::parse("1_000").unwrap().to_words(),
+            Ok(String::from("one thousand"))
+        );
+        assert_eq!(
+            Num2Words::parse("-2.5E2").unwrap().to_words(),
+            Ok(String::from("minus two hundred fifty"))
+        );
+        assert_eq!(
+            Num2Words::parse("-Infinity").unwrap().to_words(),
+            Ok(String::from("minus infinity"))
+        );
+    }
+
+    #[test]
+    fn test_lints() {
+        assert_eq!(Num2Words::new(42).lints(), vec![]);
+        assert_eq!(
+            Num2Words::new(42)
+                .lang(Lang::French)
+                .ordinal_num()
+                .prefer("reformed")
+                .prefer("feminine")
+                .lints(),
+            // the feminine agrees with 1re
+            vec![ConfigLint::IgnoredPreference {
+                preference: String::from("reformed"),
+                output: Output::OrdinalNum,
+            }]
+        );
+        assert_eq!(
+            Num2Words::new(42)
+                .lang(Lang::Ukrainian)
+                .ordinal_num()
+                .prefer("жіночий")
+                .prefer("zero-major")
+                .lints(),
+            vec![ConfigLint::IgnoredPreference {
+                preference: String::from("zero-major"),
+                output: Output::OrdinalNum,
+            }]
+        );
+        // setting the same output twice is harmless
+        assert_eq!(
+            Num2Words::new(42)
+                .year()
+                .year()
+                .prefer("us")
+                .prefer("strict-config")
+                .lints(),
+            vec![]
+        );
+        assert_eq!(
+            Num2Words::new(42).ordinal().cardinal().ordinal().lints(),
+            vec![
+                ConfigLint::OverriddenOutput {
+                    previous: Output::Ordinal,
+                    current: Output::Cardinal,
+                },
+                ConfigLint::OverriddenOutput {
+                    previous: Output::Cardinal,
+                    current: Output::Ordinal,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_strict_config() {
+        assert_eq!(
+            Num2Words::new(2024)
+                .year()
+                .currency(Currency::EUR)
+                .to_words(),
+            Ok(String::from("two thousand and twenty-four euros"))
+        );
+        assert_eq!(
+            Num2Words::new(2024)
+                .year()
+                .currency(Currency::EUR)
+                .prefer("strict-config")
+                .to_words(),
+            Err(Num2Err::ConflictingOutputs)
+        );
+        assert_eq!(
+            Num2Words::new(2024)
+                .year()
+                .year()
+                .prefer("strict-config")
+                .to_words(),
+            Ok(String::from("twenty twenty-four"))
+        );
+    }
+
+    #[test]
+    fn test_accounting_negative() {
+        let accounting = |lang, currency, parentheses| {
+            let mut n2w = Num2Words::new(-42.01)
+                .lang(lang)
+                .currency(currency)
+                .prefer("accounting-negative");
+            if parentheses {
+                n2w = n2w.prefer("parentheses");
+            }
+            n2w.to_words()
+        };
+        assert_eq!(
+            accounting(Lang::English, Currency::DOLLAR, false),
+            Ok(String::from("forty-two dollars and one cent (negative)"))
+        );
+        assert_eq!(
+            accounting(Lang::English, Currency::DOLLAR, true),
+            Ok(String::from("(forty-two dollars and one cent)"))
+        );
+        assert_eq!(
+            accounting(Lang::French, Currency::EUR, false),
+            Ok(String::from("quarante-deux euros et un centime (négatif)"))
+        );
+        assert_eq!(
+            accounting(Lang::French, Currency::EUR, true),
+            Ok(String::from("(quarante-deux euros et un centime)"))
+        );
+        assert_eq!(
+            accounting(Lang::Ukrainian, Currency::UAH, false),
+            Ok(String::from("сорок дві гривні одна копійка (відʼємне)"))
+        );
+        assert_eq!(
+            accounting(Lang::Ukrainian, Currency::UAH, true),
+            Ok(String::from("(сорок дві гривні одна копійка)"))
+        );
+        // positive amounts and other outputs are left untouched
+        assert_eq!(
+            Num2Words::new(42)
+                .currency(Currency::DOLLAR)
+                .prefer("accounting-negative")
+                .to_words(),
+            Ok(String::from("forty-two dollars"))
+        );
+        assert_eq!(
+            Num2Words::new(-42).prefer("accounting-negative").to_words(),
+            Ok(String::from("minus forty-two"))
+        );
+    }
+
+    #[test]
+    fn test_report() {
+        let report = Num2Words::report(42, Lang::Ukrainian, &["ж"]);
+        assert_eq!(report.cardinal, Ok(String::from("сорок дві")));
+        assert_eq!(report.ordinal, Ok(String::from("сорок друга")));
+        assert_eq!(report.ordinal_num, Ok(String::from("42-а")));
+        assert_eq!(report.year, Ok(String::from("сорок другий рік")));
+        assert_eq!(
+            report.currencies["UAH"],
+            Ok(String::from("сорок дві гривні"))
+        );
+        assert_eq!(report.currencies.len(), Currency::ALL.len());
+
+        let report = Num2Words::report(f64::INFINITY, Lang::English, &[] as &[&str]);
+        assert_eq!(report.cardinal, Ok(String::from("infinity")));
+        assert_eq!(report.ordinal, Err(Num2Err::InfiniteOrdinal));
+        assert_eq!(report.year, Err(Num2Err::InfiniteYear));
+    }
+
+    #[test]
+    fn test_parse_prefix() {
+        let (n2w, len) = Num2Words::parse_prefix("42 apples").unwrap();
+        assert_eq!(n2w.to_words(), Ok(String::from("forty-two")));
+        assert_eq!(len, 2);
+
+        let (n2w, len) = Num2Words::parse_prefix("-3.5kg").unwrap();
+        assert_eq!(n2w.to_words(), Ok(String::from("minus three point five")));
+        assert_eq!(len, 4);
+
+        let (n2w, len) = Num2Words::parse_prefix("1e3x").unwrap();
+        assert_eq!(n2w.to_words(), Ok(String::from("one thousand")));
+        assert_eq!(len, 3);
+
+        let (n2w, len) = Num2Words::parse_prefix("7.e").unwrap();
+        assert_eq!(n2w.to_words(), Ok(String::from("seven")));
+        assert_eq!(len, 1);
+
+        assert!(Num2Words::parse_prefix("apples 42").is_none());
+        assert!(Num2Words::parse_prefix("-").is_none());
+        assert!(Num2Words::parse_prefix("nan").is_none());
+    }
+
+    #[test]
+    fn test_below_min_magnitude() {
+        for s in ["1e-5000", "-1e-5000", "1e-100", "-1e-100"] {
+            assert_eq!(
+                Num2Words::parse(s).unwrap().to_words(),
+                Ok(String::from("zero")),
+                "cardinal {}",
+                s
+            );
+            assert_eq!(
+                Num2Words::parse(s)
+                    .unwrap()
+                    .currency(Currency::DOLLAR)
+                    .to_words(),
+                Ok(String::from("zero dollars")),
+                "currency {}",
+                s
+            );
+            assert_eq!(
+                Num2Words::parse(s).unwrap().ordinal().to_words(),
+                Ok(String::from("zeroth")),
+                "ordinal {}",
+                s
+            );
+            for (lang, words) in [
+                (Lang::English, "zero percent"),
+                (Lang::French, "zéro pour cent"),
+                (Lang::Ukrainian, "нуль відсотків"),
+            ] {
+                assert_eq!(
+                    Num2Words::parse(s).unwrap().lang(lang).percent().to_words(),
+                    Ok(String::from(words)),
+                    "percent {} {:?}",
+                    s,
+                    lang
+                );
+            }
+            assert_eq!(
+                Num2Words::parse(s)
+                    .unwrap()
+                    .lang(Lang::French)
+                    .currency(Currency::EUR)
+                    .to_words(),
+                Ok(String::from("zéro euro")),
+                "french currency {}",
+                s
+            );
+            assert_eq!(
+                Num2Words::parse(s)
+                    .unwrap()
+                    .lang(Lang::Ukrainian)
+                    .currency(Currency::UAH)
+                    .to_words(),
+                Ok(String::from("нуль гривень")),
+                "ukrainian currency {}",
+                s
+            );
+        }
+    }
+
+    #[test]
+    fn test_default_precision() {
+        assert_eq!(
+            Num2Words::new(0.1 + 0.2).to_words(),
+            Ok(String::from("zero point three"))
+        );
+        assert_eq!(
+            Num2Words::new(0.1 + 0.2).lang(Lang::French).to_words(),
+            Ok(String::from("zéro virgule trois"))
+        );
+        let report = Num2Words::report(0.1 + 0.2, Lang::English, &[] as &[&str]);
+        assert_eq!(report.cardinal, Ok(String::from("zero point three")));
+        assert_eq!(report.percent, Ok(String::from("zero point three percent")));
+        // the last digit is rounded rather than truncated
+        assert_eq!(
+            Num2Words::parse("0.1234567890125").unwrap().to_words(),
+            Ok(String::from(
+                "zero point one two three four five six seven eight nine zero one three"
+            ))
+        );
+        assert_eq!(
+            Num2Words::new(0.1 + 0.2).precision(17).to_words(),
+            Ok(String::from(
+                "zero point three zero zero zero zero zero zero zero zero zero zero zero zero zero zero zero four"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_rounding() {
+        let rounded = |num: &str, output: Output, rounding| {
+            let n2w = Num2Words::parse(num).unwrap().rounding(rounding);
+            match output {
+                Output::Currency => n2w.currency(Currency::DOLLAR),
+                Output::Percent => n2w.percent().precision(2),
+                Output::Unit => n2w.unit(Unit::Meter).precision(2),
+                _ => n2w.precision(2),
+            }
+            .to_words()
+        };
+
+        // 2.665 tells the modes apart where 2.675 would not
+        for (output, half_up, half_even) in [
+            (Output::Cardinal, "two point six seven", "two point six six"),
+            (
+                Output::Percent,
+                "two point six seven percent",
+                "two point six six percent",
+            ),
+            (
+                Output::Unit,
+                "two point six seven meters",
+                "two point six six meters",
+            ),
+            (
+                Output::Currency,
+                "two dollars and sixty-seven cents",
+                "two dollars and sixty-six cents",
+            ),
+        ] {
+            assert_eq!(
+                rounded("2.665", output, Rounding::HalfUp),
+                Ok(String::from(half_up))
+            );
+            assert_eq!(
+                rounded("2.665", output, Rounding::HalfEven),
+                Ok(String::from(half_even))
+            );
+        }
+        assert_eq!(
+            rounded("2.679", Output::Currency, Rounding::Truncate),
+            Ok(String::from("two dollars and sixty-seven cents"))
+        );
+
+        for (num, half_up, half_even) in [("2.5", "three", "two"), ("3.5", "four", "four")] {
+            let rounded = |rounding| {
+                Num2Words::parse(num)
+                    .unwrap()
+                    .precision(0)
+                    .rounding(rounding)
+            };
+            assert_eq!(
+                rounded(Rounding::HalfUp).to_words(),
+                Ok(String::from(half_up))
+            );
+            assert_eq!(
+                rounded(Rounding::HalfEven).to_words(),
+                Ok(String::from(half_even))
+            );
+            assert_eq!(
+                rounded(Rounding::HalfEven).percent().to_words(),
+                Ok(format!("{} percent", half_even))
+            );
+            assert_eq!(
+                rounded(Rounding::HalfEven).unit(Unit::Meter).to_words(),
+                Ok(format!("{} meters", half_even))
+            );
+        }
+
+        // English rounds the cents half-up by default
+        assert_eq!(
+            Num2Words::parse("2.679")
+                .unwrap()
+                .currency(Currency::DOLLAR)
+                .to_words(),
+            Ok(String::from("two dollars and sixty-eight cents"))
+        );
+    }
+
+    #[test]
+    fn test_unconvertible_input() {
+        assert_eq!(
+            Num2Words::new(f64::NAN).to_words(),
+            Err(Num2Err::CannotConvert)
+        );
+        assert_eq!(
+            Num2Words::new(f64::NAN).agreement(),
+            Err(Num2Err::CannotConvert)
+        );
+        assert_eq!(
+            Num2Words::parse("1e60").unwrap().ordinal_num().to_words(),
+            Err(Num2Err::CannotConvert)
+        );
+        assert_eq!(
+            Num2Words::parse("1e60").unwrap().year().to_words(),
+            Err(Num2Err::CannotConvert)
+        );
+    }
+
+    #[test]
+    fn test_shared_gender_aliases() {
+        let words = |lang, num: i64, preferences: &[&str]| {
+            preferences
+                .iter()
+                .fold(Num2Words::new(num).lang(lang), |n2w, p| n2w.prefer(*p))
+                .to_words()
+        };
+
+        for (lang, num, masculine, feminine) in [
+            (Lang::French, 21, "vingt et un", "vingt et une"),
+            (Lang::Ukrainian, 42, "сорок два", "сорок дві"),
+            (Lang::Spanish, 21, "veintiuno", "veintiuna"),
+        ] {
+            assert_eq!(words(lang, num, &[]), Ok(String::from(masculine)));
+            assert_eq!(words(lang, num, &["f"]), Ok(String::from(feminine)));
+            assert_eq!(words(lang, num, &["feminine"]), Ok(String::from(feminine)));
+            assert_eq!(words(lang, num, &["f", "m"]), Ok(String::from(masculine)));
+            assert_eq!(
+                words(lang, num, &["f", "masculine"]),
+                Ok(String::from(masculine))
+            );
+        }
+
+        // native words still work
+        assert_eq!(
+            words(Lang::Spanish, 21, &["femenino"]),
+            Ok(String::from("veintiuna"))
+        );
+        assert_eq!(words(Lang::Ukrainian, 2, &["ж"]), Ok(String::from("дві")));
+        assert_eq!(
+            Num2Words::new(2)
+                .lang(Lang::Ukrainian)
+                .prefer("pl")
+                .ordinal()
+                .to_words(),
+            Num2Words::new(2)
+                .lang(Lang::Ukrainian)
+                .prefer("множина")
+                .ordinal()
+                .to_words()
+        );
     }
 }
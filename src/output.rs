@@ -1,6 +1,7 @@
 use std::str::FromStr;
 
 /// Type of the output `num2words` give
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub enum Output {
     /// Number in cardinal form, e.g., `forty-two`
     Cardinal,
@@ -12,6 +13,15 @@ pub enum Output {
     OrdinalNum,
     /// Number in year form, e.g., `nineteen oh-one`
     Year,
+    /// Range of years, e.g., `from twenty twenty to twenty twenty-three`
+    YearRange,
+    /// Number in percent form, e.g., `forty-two percent`
+    Percent,
+    /// Number with a unit of measurement, e.g., `forty-two meters`
+    Unit,
+    /// Alphanumeric reference read by groups of four, e.g., `D E eighty-nine,
+    /// three seven zero four`
+    Reference,
 }
 
 impl FromStr for Output {
@@ -27,6 +37,8 @@ impl FromStr for Output {
     /// | `ordinal`     | `Output::Ordinal`    |
     /// | `ordinal_num` | `Output::OrdinalNum` |
     /// | `year`        | `Output::Year`       |
+    /// | `percent`     | `Output::Percent`    |
+    /// | `unit`        | `Output::Unit`       |
     fn from_str(input: &str) -> Result<Self, Self::Err> {
         match input {
             "cardinal" => Ok(Output::Cardinal),
@@ -34,6 +46,8 @@ impl FromStr for Output {
             "ordinal" => Ok(Output::Ordinal),
             "ordinal_num" => Ok(Output::OrdinalNum),
             "year" => Ok(Output::Year),
+            "percent" => Ok(Output::Percent),
+            "unit" => Ok(Output::Unit),
             _ => Err(()),
         }
     }
@@ -0,0 +1,164 @@
+use crate::{Language, Num2Err};
+use num_bigfloat::BigFloat;
+
+const NATO: [&str; 26] = [
+    "Alfa", "Bravo", "Charlie", "Delta", "Echo", "Foxtrot", "Golf", "Hotel", "India", "Juliett",
+    "Kilo", "Lima", "Mike", "November", "Oscar", "Papa", "Quebec", "Romeo", "Sierra", "Tango",
+    "Uniform", "Victor", "Whiskey", "X-ray", "Yankee", "Zulu",
+];
+
+/// How the characters of a reference are read
+#[derive(Clone, Copy, Default)]
+pub(crate) struct ReferenceStyle {
+    /// Letters are read with the NATO phonetic alphabet, e.g. `Delta Echo`
+    pub nato: bool,
+    /// Every run of digits is read in pairs, e.g. `thirty-seven zero four`
+    pub pairs: bool,
+}
+
+impl ReferenceStyle {
+    pub fn from_preferences(preferences: &[String]) -> Self {
+        let prefers = |name: &str| preferences.iter().any(|p| p == name);
+        Self {
+            nato: prefers("nato"),
+            pairs: prefers("pairs"),
+        }
+    }
+}
+
+/// Spells an alphanumeric reference (e.g., an IBAN) by groups of four
+///
+/// Whitespace is ignored and letters are uppercased. Digits are read one by
+/// one, except for runs of exactly two digits which are read as a number
+/// (e.g. the check digits of an IBAN), or for every run with `pairs`. A pair
+/// starting with a zero is always read digit by digit.
+pub(crate) fn spell(
+    lang: &dyn Language,
+    reference: &str,
+    style: ReferenceStyle,
+) -> Result<String, Num2Err> {
+    let chars: Vec<char> = reference
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .map(|c| c.to_ascii_uppercase())
+        .collect();
+    if chars.is_empty() || !chars.iter().all(char::is_ascii_alphanumeric) {
+        return Err(Num2Err::CannotConvert);
+    }
+
+    let mut groups = vec![];
+    for group in chars.chunks(4) {
+        let mut words = vec![];
+        let mut digits = vec![];
+        for c in group {
+            match c.to_digit(10) {
+                Some(digit) => digits.push(digit),
+                None => {
+                    spell_digits(lang, &digits, style, &mut words)?;
+                    digits.clear();
+                    words.push(spell_letter(lang, *c, style));
+                }
+            }
+        }
+        spell_digits(lang, &digits, style, &mut words)?;
+        groups.push(words.join(" "));
+    }
+
+    Ok(groups.join(", "))
+}
+
+fn spell_letter(lang: &dyn Language, letter: char, style: ReferenceStyle) -> String {
+    if style.nato {
+        String::from(NATO[(letter as u8 - b'A') as usize])
+    } else {
+        lang.letter_name(letter)
+    }
+}
+
+fn spell_digits(
+    lang: &dyn Language,
+    digits: &[u32],
+    style: ReferenceStyle,
+    words: &mut Vec<String>,
+) -> Result<(), Num2Err> {
+    let size = if style.pairs || digits.len() == 2 {
+        2
+    } else {
+        1
+    };
+    for chunk in digits.chunks(size) {
+        if chunk.len() == 2 && chunk[0] != 0 {
+            words.push(lang.to_cardinal(BigFloat::from(chunk[0] * 10 + chunk[1]))?);
+        } else {
+            for digit in chunk {
+                words.push(lang.to_cardinal(BigFloat::from(*digit))?);
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_iban() {
+        assert_eq!(
+            Num2Words::new(0)
+                .reference("DE89370400440532013000")
+                .to_words(),
+            Ok(String::from(
+                "D E eighty-nine, three seven zero four, zero zero four four, \
+                 zero five three two, zero one three zero, zero zero"
+            ))
+        );
+        assert_eq!(
+            Num2Words::new(0)
+                .reference("DE89370400440532013000")
+                .prefer("pairs")
+                .to_words(),
+            Ok(String::from(
+                "D E eighty-nine, thirty-seven zero four, zero zero forty-four, \
+                 zero five thirty-two, zero one thirty, zero zero"
+            ))
+        );
+        assert_eq!(
+            Num2Words::new(0)
+                .lang(Lang::French)
+                .reference("FR76 3000 6000 0112 3456 7890 189")
+                .to_words(),
+            Ok(String::from(
+                "F R soixante-seize, trois zéro zéro zéro, six zéro zéro zéro, \
+                 zéro un un deux, trois quatre cinq six, sept huit neuf zéro, un huit neuf"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_mixed_reference() {
+        assert_eq!(
+            Num2Words::new(0)
+                .reference("gb29 nwbk 6016 1331 19")
+                .to_words(),
+            Ok(String::from(
+                "G B twenty-nine, N W B K, six zero one six, one three three one, nineteen"
+            ))
+        );
+        assert_eq!(
+            Num2Words::new(0)
+                .reference("ab1c")
+                .prefer("nato")
+                .to_words(),
+            Ok(String::from("Alfa Bravo one Charlie"))
+        );
+        assert_eq!(
+            Num2Words::new(0).reference("x-42").to_words(),
+            Err(Num2Err::CannotConvert)
+        );
+        assert_eq!(
+            Num2Words::new(0).reference(" ").to_words(),
+            Err(Num2Err::CannotConvert)
+        );
+    }
+}
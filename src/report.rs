@@ -0,0 +1,26 @@
+use crate::Num2Err;
+use std::collections::BTreeMap;
+
+/// Every output of a number in a given language
+///
+/// Built by [`Num2Words::report`](crate::Num2Words::report). Each output keeps
+/// its own result so that unsupported combinations (e.g., the ordinal of a
+/// float) are visible without making the whole report fail.
+///
+/// With the `serde` feature, the report can be serialized directly.
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Report {
+    /// Cardinal form, e.g., `forty-two`
+    pub cardinal: Result<String, Num2Err>,
+    /// Ordinal form, e.g., `forty-second`
+    pub ordinal: Result<String, Num2Err>,
+    /// Ordinal form written in number, e.g., `42nd`
+    pub ordinal_num: Result<String, Num2Err>,
+    /// Year form, e.g., `forty-two`
+    pub year: Result<String, Num2Err>,
+    /// Percent form, e.g., `forty-two percent`
+    pub percent: Result<String, Num2Err>,
+    /// Currency forms, keyed by currency code (e.g., `USD`)
+    pub currencies: BTreeMap<String, Result<String, Num2Err>>,
+}
@@ -0,0 +1,83 @@
+use num_bigfloat::BigFloat;
+
+/// How a number is rounded to a given count of decimal places
+///
+/// Set with [`Num2Words::rounding`](crate::Num2Words::rounding). Ties are
+/// values exactly halfway between two results, e.g. `2.675` to two decimal
+/// places.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Rounding {
+    /// Extra decimals are dropped, e.g. `2.679` becomes `2.67`
+    Truncate,
+    /// Ties are rounded away from zero, e.g. `2.5` becomes `3`
+    HalfUp,
+    /// Ties are rounded to the even neighbour (banker's rounding), e.g. `2.5`
+    /// becomes `2` and `3.5` becomes `4`
+    HalfEven,
+}
+
+/// Rounds `num` to `scale` decimal places
+pub(crate) fn quantize(num: BigFloat, scale: usize, mode: Rounding) -> BigFloat {
+    if num.is_inf() || num.is_nan() {
+        return num;
+    }
+
+    let factor = (0..scale).fold(BigFloat::from(1), |factor, _| factor * BigFloat::from(10));
+    let scaled = num.abs() * factor;
+    let mut quantized = scaled.int();
+    let rest = scaled.frac();
+    let half = BigFloat::from(0.5);
+
+    let round_up = match mode {
+        Rounding::Truncate => false,
+        Rounding::HalfUp => rest >= half,
+        Rounding::HalfEven => {
+            rest > half || (rest == half && !(quantized % BigFloat::from(2)).is_zero())
+        }
+    };
+    if round_up {
+        quantized += BigFloat::from(1);
+    }
+
+    quantized /= factor;
+    if num.is_negative() {
+        -quantized
+    } else {
+        quantized
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn quantized(num: &str, scale: usize, mode: Rounding) -> BigFloat {
+        quantize(BigFloat::parse(num).unwrap(), scale, mode)
+    }
+
+    #[test]
+    fn test_quantize() {
+        for (num, scale, truncate, half_up, half_even) in [
+            ("2.675", 2, "2.67", "2.68", "2.68"),
+            ("2.665", 2, "2.66", "2.67", "2.66"),
+            ("2.5", 0, "2", "3", "2"),
+            ("3.5", 0, "3", "4", "4"),
+            ("-2.5", 0, "-2", "-3", "-2"),
+            ("2.6751", 2, "2.67", "2.68", "2.68"),
+            ("42", 2, "42", "42", "42"),
+        ] {
+            assert_eq!(
+                quantized(num, scale, Rounding::Truncate),
+                BigFloat::parse(truncate).unwrap()
+            );
+            assert_eq!(
+                quantized(num, scale, Rounding::HalfUp),
+                BigFloat::parse(half_up).unwrap()
+            );
+            assert_eq!(
+                quantized(num, scale, Rounding::HalfEven),
+                BigFloat::parse(half_even).unwrap()
+            );
+        }
+    }
+}
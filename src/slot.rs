@@ -0,0 +1,140 @@
+use crate::{Currency, Declension, Gender, GrammaticalNumber, Lang, Num2Err, Num2Words};
+use num_bigfloat::BigFloat;
+
+/// Creates a [`SentenceSlot`] for the given language
+///
+/// Example:
+/// ```
+/// use num2words::{slot, Declension, Gender, Lang};
+/// assert_eq!(
+///     format!(
+///         "звіт за {} квартал",
+///         slot(Lang::Ukrainian)
+///             .case(Declension::Accusative)
+///             .gender(Gender::Masculine)
+///             .ordinal(3)
+///             .unwrap()
+///     ),
+///     "звіт за третій квартал"
+/// );
+/// ```
+pub fn slot(lang: Lang) -> SentenceSlot {
+    SentenceSlot {
+        lang,
+        gender: None,
+        number: None,
+        case: None,
+    }
+}
+
+/// Typed helper to place a number inside a sentence
+///
+/// The grammatical categories are the ones of the surrounding words, e.g., the
+/// case required by a preposition or the gender of the counted noun. Languages
+/// without such a category simply ignore it, so the same template code can be
+/// used for every language.
+///
+/// Example:
+/// ```
+/// use num2words::{slot, Currency, Declension, Gender, Lang};
+/// assert_eq!(
+///     format!(
+///         "Сплачено {}",
+///         slot(Lang::Ukrainian)
+///             .case(Declension::Accusative)
+///             .currency(21, Currency::UAH)
+///             .unwrap()
+///     ),
+///     "Сплачено двадцять одну гривню"
+/// );
+/// assert_eq!(
+///     format!(
+///         "la {} place",
+///         slot(Lang::French).gender(Gender::Feminine).cardinal(21).unwrap()
+///     ),
+///     "la vingt et une place"
+/// );
+/// assert_eq!(
+///     slot(Lang::English)
+///         .case(Declension::Accusative)
+///         .gender(Gender::Feminine)
+///         .ordinal(3),
+///     Ok(String::from("third"))
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SentenceSlot {
+    lang: Lang,
+    gender: Option<Gender>,
+    number: Option<GrammaticalNumber>,
+    case: Option<Declension>,
+}
+
+impl SentenceSlot {
+    /// Sets the grammatical gender the number agrees with
+    pub fn gender(self, gender: Gender) -> Self {
+        Self {
+            gender: Some(gender),
+            ..self
+        }
+    }
+
+    /// Sets the grammatical number the number agrees with
+    pub fn number(self, number: GrammaticalNumber) -> Self {
+        Self {
+            number: Some(number),
+            ..self
+        }
+    }
+
+    /// Sets the grammatical case required by the sentence
+    pub fn case(self, case: Declension) -> Self {
+        Self {
+            case: Some(case),
+            ..self
+        }
+    }
+
+    /// Builds the cardinal form (`forty-two`)
+    pub fn cardinal<T: Into<BigFloat>>(&self, num: T) -> Result<String, Num2Err> {
+        self.builder(num).cardinal().to_words()
+    }
+
+    /// Builds the ordinal form (`forty-second`)
+    pub fn ordinal<T: Into<BigFloat>>(&self, num: T) -> Result<String, Num2Err> {
+        self.builder(num).ordinal().to_words()
+    }
+
+    /// Builds the numbered ordinal form (`42nd`)
+    pub fn ordinal_num<T: Into<BigFloat>>(&self, num: T) -> Result<String, Num2Err> {
+        self.builder(num).ordinal_num().to_words()
+    }
+
+    /// Builds the year form (`nineteen oh-one`)
+    pub fn year<T: Into<BigFloat>>(&self, num: T) -> Result<String, Num2Err> {
+        self.builder(num).year().to_words()
+    }
+
+    /// Builds the currency form (`forty-two dollars and one cent`)
+    pub fn currency<T: Into<BigFloat>>(
+        &self,
+        num: T,
+        currency: Currency,
+    ) -> Result<String, Num2Err> {
+        self.builder(num).currency(currency).to_words()
+    }
+
+    fn builder<T: Into<BigFloat>>(&self, num: T) -> Num2Words {
+        let mut builder = Num2Words::new(num).lang(self.lang);
+        if let Some(gender) = self.gender {
+            builder = builder.prefer(gender.as_preference());
+        }
+        if let Some(number) = self.number {
+            builder = builder.prefer(number.as_preference());
+        }
+        if let Some(case) = self.case {
+            builder = builder.prefer(case.as_preference());
+        }
+        builder
+    }
+}
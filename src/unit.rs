@@ -0,0 +1,63 @@
+use std::str::FromStr;
+
+/// Defines units of measurement
+///
+/// Every variant can be parsed from its SI (or SI-accepted) symbol.
+#[derive(Clone, Copy)]
+#[non_exhaustive]
+pub enum Unit {
+    /// Metre (`m`)
+    Meter,
+    /// Kilometre (`km`)
+    Kilometer,
+    /// Gram (`g`)
+    Gram,
+    /// Kilogram (`kg`)
+    Kilogram,
+    /// Litre (`l`)
+    Liter,
+    /// Second (`s`)
+    Second,
+    /// Minute (`min`)
+    Minute,
+    /// Hour (`h`)
+    Hour,
+}
+
+impl Unit {
+    /// Returns a default string representation for the unit
+    ///
+    /// Like for [`Currency`](crate::Currency), this is the English name of
+    /// the unit.
+    pub fn default_string(&self, plural_form: bool) -> String {
+        match self {
+            Unit::Meter => "meter{}",
+            Unit::Kilometer => "kilometer{}",
+            Unit::Gram => "gram{}",
+            Unit::Kilogram => "kilogram{}",
+            Unit::Liter => "liter{}",
+            Unit::Second => "second{}",
+            Unit::Minute => "minute{}",
+            Unit::Hour => "hour{}",
+        }
+        .replace("{}", if plural_form { "s" } else { "" })
+    }
+}
+
+impl FromStr for Unit {
+    type Err = ();
+
+    fn from_str(unit: &str) -> Result<Self, Self::Err> {
+        match unit {
+            "m" => Ok(Unit::Meter),
+            "km" => Ok(Unit::Kilometer),
+            "g" => Ok(Unit::Gram),
+            "kg" => Ok(Unit::Kilogram),
+            "l" => Ok(Unit::Liter),
+            "s" => Ok(Unit::Second),
+            "min" => Ok(Unit::Minute),
+            "h" => Ok(Unit::Hour),
+            _ => Err(()),
+        }
+    }
+}
@@ -0,0 +1,127 @@
+//! Runs every expectation of the `tests/corpus` directory through `Num2Words`
+//!
+//! See `tests/corpus/README.md` for the format of the files.
+
+use num2words::{Currency, Lang, Num2Words, Unit};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+struct Entry {
+    location: String,
+    number: String,
+    lang: String,
+    output: String,
+    expected: String,
+    preferences: Vec<String>,
+}
+
+fn corpus_files() -> Vec<PathBuf> {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("corpus");
+    let mut files: Vec<PathBuf> = fs::read_dir(&dir)
+        .unwrap_or_else(|e| panic!("cannot read {}: {}", dir.display(), e))
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "tsv"))
+        .collect();
+    files.sort();
+    files
+}
+
+fn parse_file(path: &Path) -> Vec<Entry> {
+    let name = path.file_name().unwrap().to_string_lossy().into_owned();
+    let content = fs::read_to_string(path)
+        .unwrap_or_else(|e| panic!("cannot read {}: {}", path.display(), e));
+
+    let mut entries = vec![];
+    for (i, line) in content.lines().enumerate() {
+        if line.trim().is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let location = format!("{}:{}", name, i + 1);
+        let columns: Vec<&str> = line.split('\t').collect();
+        if columns.len() != 4 && columns.len() != 5 {
+            panic!("{}: expected 4 or 5 tab-separated columns", location);
+        }
+        entries.push(Entry {
+            location,
+            number: columns[0].to_string(),
+            lang: columns[1].to_string(),
+            output: columns[2].to_string(),
+            expected: columns[3].to_string(),
+            preferences: columns
+                .get(4)
+                .map(|p| p.split(',').map(|p| p.trim().to_string()).collect())
+                .unwrap_or_default(),
+        });
+    }
+    entries
+}
+
+fn convert(entry: &Entry) -> Result<String, String> {
+    let lang: Lang = entry
+        .lang
+        .parse()
+        .map_err(|_| format!("unknown language `{}`", entry.lang))?;
+    let mut n2w = Num2Words::parse(&entry.number)
+        .ok_or_else(|| format!("cannot parse number `{}`", entry.number))?
+        .lang(lang);
+    for preference in &entry.preferences {
+        n2w = n2w.prefer(preference.as_str());
+    }
+
+    let (output, argument) = match entry.output.split_once(':') {
+        Some((output, argument)) => (output, Some(argument)),
+        None => (entry.output.as_str(), None),
+    };
+    n2w = match (output, argument) {
+        ("cardinal", None) => n2w.cardinal(),
+        ("ordinal", None) => n2w.ordinal(),
+        ("ordinal_num", None) => n2w.ordinal_num(),
+        ("year", None) => n2w.year(),
+        ("percent", None) => n2w.percent(),
+        ("currency", Some(currency)) => n2w.currency(
+            currency
+                .parse::<Currency>()
+                .map_err(|_| format!("unknown currency `{}`", currency))?,
+        ),
+        ("unit", Some(unit)) => n2w.unit(
+            unit.parse::<Unit>()
+                .map_err(|_| format!("unknown unit `{}`", unit))?,
+        ),
+        _ => return Err(format!("unknown output `{}`", entry.output)),
+    };
+
+    n2w.to_words().map_err(|e| format!("error `{}`", e))
+}
+
+#[test]
+fn corpus() {
+    let mut total = 0;
+    let mut failures = vec![];
+    for path in corpus_files() {
+        for entry in parse_file(&path) {
+            total += 1;
+            match convert(&entry) {
+                Ok(words) if words == entry.expected => (),
+                Ok(words) => failures.push(format!(
+                    "{}: {} ({}, {}): expected `{}`, got `{}`",
+                    entry.location, entry.number, entry.lang, entry.output, entry.expected, words
+                )),
+                Err(e) => failures.push(format!(
+                    "{}: {} ({}, {}): {}",
+                    entry.location, entry.number, entry.lang, entry.output, e
+                )),
+            }
+        }
+    }
+
+    assert!(total > 0, "the corpus is empty");
+    assert!(
+        failures.is_empty(),
+        "{} of {} corpus entries failed:\n{}",
+        failures.len(),
+        total,
+        failures.join("\n")
+    );
+}
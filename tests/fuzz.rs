@@ -0,0 +1,74 @@
+//! Runs the fuzz harness of `fuzz/` over a fixed seed corpus
+//!
+//! The corpus is generated from a constant seed, so that every run checks the
+//! same inputs. New fuzzing crashes belong in `SEEDS`.
+
+use std::panic;
+
+#[path = "../fuzz/harness.rs"]
+mod harness;
+
+// inputs which used to panic
+const SEEDS: &[&[u8]] = &[
+    // NaN in English and Telugu (stack overflow)
+    &[0, 7, 0],
+    &[0, 28, 0],
+    // NaN currency, ordinal and year
+    &[0, 7, 4, 0],
+    &[0, 7, 1],
+    &[0, 7, 3],
+    // smallest subnormal as ordinals
+    &[3, 0, 0, 0, 0, 0, 0, 0, 1, 7, 1],
+    &[3, 0, 0, 0, 0, 0, 0, 0, 1, 7, 2],
+    // 1e60 as ordinals in English and Ukrainian, and as a year
+    &[5, 0, 0, 0, 0, 0, 0, 0, 1, 0, 60, 7, 1],
+    &[5, 0, 0, 0, 0, 0, 0, 0, 1, 0, 60, 7, 2],
+    &[5, 0, 0, 0, 0, 0, 0, 0, 1, 0, 60, 30, 1],
+    &[5, 0, 0, 0, 0, 0, 0, 0, 1, 0, 60, 7, 3],
+    // 8 in Italian
+    &[7, 136, 14, 0],
+    // zeroth in Latin (empty output)
+    &[7, 128, 16, 1],
+];
+
+// xorshift, only meant to be reproducible
+fn generated_corpus(count: usize) -> Vec<Vec<u8>> {
+    let mut state: u64 = 0x9E37_79B9_7F4A_7C15;
+    (0..count)
+        .map(|_| {
+            let mut input = vec![];
+            for _ in 0..8 {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                input.extend_from_slice(&state.to_le_bytes());
+            }
+            input
+        })
+        .collect()
+}
+
+#[test]
+fn seed_corpus() {
+    let corpus = SEEDS
+        .iter()
+        .map(|seed| seed.to_vec())
+        .chain(generated_corpus(20_000));
+
+    let mut failures = vec![];
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| ()));
+    for input in corpus {
+        if panic::catch_unwind(|| harness::run(&input)).is_err() {
+            failures.push(input);
+        }
+    }
+    panic::set_hook(hook);
+
+    assert!(
+        failures.is_empty(),
+        "{} inputs panicked, e.g. {:?}",
+        failures.len(),
+        &failures[..failures.len().min(5)]
+    );
+}